@@ -0,0 +1,20 @@
+// Benchmarks for the library hot paths. Run with `cargo bench`, optionally followed by the number
+// of iterations, e.g. `cargo bench -- 1000000`.
+
+use intspector::bench;
+
+
+fn main() {
+    let iterations = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse::<u64>().ok())
+        .unwrap_or(1_000_000);
+    for measurement in bench::library(iterations) {
+        println!(
+            "{:<16}{:>12.1} ns/iter{:>16.0} iter/s",
+            measurement.name,
+            measurement.nanos_per_iter(),
+            measurement.iters_per_sec()
+        );
+    }
+}
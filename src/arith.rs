@@ -0,0 +1,82 @@
+//! Arithmetic coding by exact interval narrowing, for demonstration rather than compression.
+
+use crate::ratio::gcd_u128;
+
+
+/// Intervals are not narrowed past this denominator.
+pub const MAX_DENOMINATOR: u128 = 1 << 120;
+
+
+/// A half-open subinterval of the unit interval, `[low/den, high/den)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub low: u128,
+    pub high: u128,
+    pub den: u128,
+}
+
+
+/// Narrows the unit interval by each symbol of a message in turn. Symbol `i` takes the slice of
+/// the current interval between the cumulative frequencies of the symbols before it and up to
+/// it, so its share of the interval is its frequency over the total. Returns the unit interval
+/// followed by the interval after each symbol, with exact bounds in lowest terms, or `None` if a
+/// denominator would exceed `MAX_DENOMINATOR`. Will panic if a symbol is out of range.
+pub fn narrow(frequencies: &[u64], message: &[usize]) -> Option<Vec<Interval>> {
+    let total: u128 = frequencies.iter().map(|f| *f as u128).sum();
+    let mut interval = Interval { low: 0, high: 1, den: 1 };
+    let mut intervals = vec![interval];
+    for symbol in message {
+        let below: u128 = frequencies[..*symbol].iter().map(|f| *f as u128).sum();
+        let frequency = frequencies[*symbol] as u128;
+        let den = interval.den.checked_mul(total).filter(|den| *den <= MAX_DENOMINATOR)?;
+        let width = interval.high - interval.low;
+        let low = interval.low * total + width * below;
+        let high = low + width * frequency;
+        let divisor = gcd_u128(gcd_u128(low, high), den);
+        interval = Interval { low: low / divisor, high: high / divisor, den: den / divisor };
+        intervals.push(interval);
+    }
+    Some(intervals)
+}
+
+
+/// Returns the shortest binary fraction `m/2^k` in the interval, as the codeword `m` and its
+/// length `k` in bits. At least one bit is used. Returns `None` for an empty interval.
+pub fn shortest_code(interval: Interval) -> Option<(u128, u32)> {
+    if interval.low >= interval.high || interval.den > MAX_DENOMINATOR {
+        return None;
+    }
+    // Track floor(low × 2^k / den) and floor(high × 2^k / den) with their remainders.
+    let (mut low_q, mut low_r) = (interval.low / interval.den, interval.low % interval.den);
+    let (mut high_q, mut high_r) = (interval.high / interval.den, interval.high % interval.den);
+    for k in 1..=127 {
+        low_r *= 2;
+        low_q = low_q * 2 + low_r / interval.den;
+        low_r %= interval.den;
+        high_r *= 2;
+        high_q = high_q * 2 + high_r / interval.den;
+        high_r %= interval.den;
+        // The smallest m with m/2^k >= low, checked against m/2^k < high.
+        let m = low_q + (low_r != 0) as u128;
+        if m < high_q || (m == high_q && high_r != 0) {
+            return Some((m, k));
+        }
+    }
+    None
+}
+
+
+/// Formats the fraction `num/den` in binary with the specified number of digits after the
+/// binary point, truncating, e.g. `0.0110` for 2/5 with 4 digits. Will panic if `den` is zero or
+/// exceeds 2^127.
+pub fn binary_fraction(num: u128, den: u128, digits: usize) -> String {
+    assert!(den != 0 && den <= 1 << 127);
+    let mut output = format!("{:b}.", num / den);
+    let mut remainder = num % den;
+    for _ in 0..digits {
+        remainder *= 2;
+        output.push(if remainder >= den { '1' } else { '0' });
+        remainder %= den;
+    }
+    output
+}
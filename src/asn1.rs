@@ -0,0 +1,300 @@
+//! Decoding and encoding of ASN.1 BER/DER length octets and object identifiers, and decoding of
+//! the X.509 key usage extensions and serial numbers.
+
+
+/// The length field of a BER-encoded value.
+#[derive(Debug, PartialEq)]
+pub enum Length {
+    /// A definite length in bytes, with the number of octets used to encode it.
+    Definite(u64, usize),
+    /// The indefinite form, `0x80`, terminated by end-of-contents octets. BER only.
+    Indefinite,
+}
+
+
+/// Decodes the length octets at the start of `bytes`. Lengths below 128 use the single-octet
+/// short form; longer lengths use the long form, where the first octet is `0x80` plus the number
+/// of big-endian length octets that follow. Returns `None` if the octets are truncated, use the
+/// reserved first octet `0xFF`, or encode a length wider than 64 bits.
+pub fn decode_length(bytes: &[u8]) -> Option<Length> {
+    let first = *bytes.first()?;
+    if first < 0x80 {
+        return Some(Length::Definite(first as u64, 1));
+    }
+    if first == 0x80 {
+        return Some(Length::Indefinite);
+    }
+    let count = (first & 0x7F) as usize;
+    if first == 0xFF || count > 8 || bytes.len() < 1 + count {
+        return None;
+    }
+    let value = bytes[1..=count].iter().fold(0u64, |acc, byte| acc << 8 | *byte as u64);
+    Some(Length::Definite(value, 1 + count))
+}
+
+
+/// Returns true if the length octets at the start of `bytes` use the minimal encoding required
+/// by DER: the short form where possible, and no leading zero octets in the long form.
+pub fn is_der_length(bytes: &[u8]) -> bool {
+    match decode_length(bytes) {
+        Some(Length::Definite(value, size)) => encode_length(value).len() == size,
+        _ => false,
+    }
+}
+
+
+/// Encodes a length in the minimal DER form.
+pub fn encode_length(length: u64) -> Vec<u8> {
+    if length < 0x80 {
+        return vec![length as u8];
+    }
+    let octets: Vec<u8> = length.to_be_bytes().iter().copied().skip_while(|b| *b == 0).collect();
+    let mut bytes = vec![0x80 | octets.len() as u8];
+    bytes.extend(octets);
+    bytes
+}
+
+
+/// Decodes the content octets of an OBJECT IDENTIFIER into its arcs. Each subidentifier is a
+/// base-128 big-endian number with the high bit of each octet set on all but its last octet. The
+/// first subidentifier packs the first two arcs as `40 * X + Y`. Returns `None` if the input is
+/// empty, truncated, uses a non-minimal `0x80` padding octet, or has an arc wider than 64 bits.
+pub fn decode_oid(bytes: &[u8]) -> Option<Vec<u64>> {
+    if bytes.is_empty() || bytes[bytes.len() - 1] & 0x80 != 0 {
+        return None;
+    }
+    let mut subids = Vec::new();
+    let mut value: u64 = 0;
+    let mut start = true;
+    for byte in bytes {
+        if start && *byte == 0x80 {
+            return None;
+        }
+        if value >> 57 != 0 {
+            return None;
+        }
+        value = value << 7 | (byte & 0x7F) as u64;
+        start = byte & 0x80 == 0;
+        if start {
+            subids.push(value);
+            value = 0;
+        }
+    }
+    let first = subids[0];
+    let (x, y) = if first < 80 { (first / 40, first % 40) } else { (2, first - 80) };
+    let mut arcs = vec![x, y];
+    arcs.extend(&subids[1..]);
+    Some(arcs)
+}
+
+
+/// Encodes the arcs of an object identifier as OBJECT IDENTIFIER content octets. Returns `None`
+/// if there are fewer than two arcs, the first arc is greater than 2, or the second arc is
+/// greater than 39 under a first arc of 0 or 1.
+pub fn encode_oid(arcs: &[u64]) -> Option<Vec<u8>> {
+    if arcs.len() < 2 || arcs[0] > 2 || (arcs[0] < 2 && arcs[1] > 39) {
+        return None;
+    }
+    let first = (arcs[0] * 40).checked_add(arcs[1])?;
+    let mut bytes = Vec::new();
+    for subid in std::iter::once(first).chain(arcs[2..].iter().copied()) {
+        let mut groups = vec![(subid & 0x7F) as u8];
+        let mut rest = subid >> 7;
+        while rest != 0 {
+            groups.push(0x80 | (rest & 0x7F) as u8);
+            rest >>= 7;
+        }
+        bytes.extend(groups.iter().rev());
+    }
+    Some(bytes)
+}
+
+
+/// Parses an object identifier in dotted notation, e.g. `1.2.840.113549`.
+pub fn parse_oid(text: &str) -> Option<Vec<u64>> {
+    text.split('.').map(|arc| arc.parse::<u64>().ok()).collect()
+}
+
+
+/// Formats the arcs of an object identifier in dotted notation.
+pub fn format_oid(arcs: &[u64]) -> String {
+    arcs.iter().map(|arc| arc.to_string()).collect::<Vec<String>>().join(".")
+}
+
+
+/// Well-known object identifiers from X.509 certificates and PKCS.
+pub const OID_NAMES: &[(&str, &str)] = &[
+    ("1.2.840.10045.2.1", "ecPublicKey"),
+    ("1.2.840.10045.3.1.7", "prime256v1"),
+    ("1.2.840.10045.4.3.2", "ecdsa-with-SHA256"),
+    ("1.2.840.10045.4.3.3", "ecdsa-with-SHA384"),
+    ("1.2.840.113549.1.1.1", "rsaEncryption"),
+    ("1.2.840.113549.1.1.5", "sha1WithRSAEncryption"),
+    ("1.2.840.113549.1.1.10", "RSASSA-PSS"),
+    ("1.2.840.113549.1.1.11", "sha256WithRSAEncryption"),
+    ("1.2.840.113549.1.1.12", "sha384WithRSAEncryption"),
+    ("1.2.840.113549.1.7.1", "data"),
+    ("1.2.840.113549.1.7.2", "signedData"),
+    ("1.2.840.113549.1.9.1", "emailAddress"),
+    ("1.3.101.112", "Ed25519"),
+    ("1.3.132.0.34", "secp384r1"),
+    ("1.3.6.1.5.5.7.1.1", "authorityInfoAccess"),
+    ("1.3.6.1.5.5.7.3.1", "serverAuth"),
+    ("1.3.6.1.5.5.7.3.2", "clientAuth"),
+    ("1.3.6.1.5.5.7.3.3", "codeSigning"),
+    ("1.3.6.1.5.5.7.3.4", "emailProtection"),
+    ("1.3.6.1.5.5.7.3.8", "timeStamping"),
+    ("1.3.6.1.5.5.7.3.9", "OCSPSigning"),
+    ("2.16.840.1.101.3.4.2.1", "sha256"),
+    ("2.5.29.14", "subjectKeyIdentifier"),
+    ("2.5.29.15", "keyUsage"),
+    ("2.5.29.17", "subjectAltName"),
+    ("2.5.29.19", "basicConstraints"),
+    ("2.5.29.31", "cRLDistributionPoints"),
+    ("2.5.29.32", "certificatePolicies"),
+    ("2.5.29.35", "authorityKeyIdentifier"),
+    ("2.5.29.37", "extKeyUsage"),
+    ("2.5.29.37.0", "anyExtendedKeyUsage"),
+    ("2.5.4.10", "organizationName"),
+    ("2.5.4.11", "organizationalUnitName"),
+    ("2.5.4.3", "commonName"),
+    ("2.5.4.6", "countryName"),
+    ("2.5.4.7", "localityName"),
+    ("2.5.4.8", "stateOrProvinceName"),
+];
+
+
+/// Returns the name of a well-known object identifier in dotted notation.
+pub fn oid_name(dotted: &str) -> Option<&'static str> {
+    OID_NAMES.iter().find(|(oid, _)| *oid == dotted).map(|(_, name)| *name)
+}
+
+
+/// Splits a BER value with a single-octet tag and a definite length off the front of `bytes`,
+/// returning the tag, the content octets, and the remaining bytes.
+pub fn decode_tlv(bytes: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let tag = *bytes.first()?;
+    match decode_length(&bytes[1..])? {
+        Length::Definite(length, size) => {
+            let start = 1 + size;
+            if length > (bytes.len() - start) as u64 {
+                return None;
+            }
+            let end = start + length as usize;
+            Some((tag, &bytes[start..end], &bytes[end..]))
+        },
+        Length::Indefinite => None,
+    }
+}
+
+
+/// Names of the bits of the X.509 keyUsage extension, in bit order.
+pub const KEY_USAGE_BITS: [&str; 9] = [
+    "digitalSignature",
+    "nonRepudiation",
+    "keyEncipherment",
+    "dataEncipherment",
+    "keyAgreement",
+    "keyCertSign",
+    "cRLSign",
+    "encipherOnly",
+    "decipherOnly",
+];
+
+
+/// Returns the indices of the set bits in the content octets of a BIT STRING. Bit 0 is the most
+/// significant bit of the first data octet. The first content octet gives the number of unused
+/// bits in the final octet. Returns `None` if that count is greater than 7, or is non-zero for an
+/// empty bit string.
+pub fn bit_string_bits(content: &[u8]) -> Option<Vec<usize>> {
+    let (unused, data) = content.split_first()?;
+    if *unused > 7 || (data.is_empty() && *unused != 0) {
+        return None;
+    }
+    let num_bits = data.len() * 8 - *unused as usize;
+    Some((0..num_bits).filter(|i| data[i / 8] & (0x80 >> (i % 8)) != 0).collect())
+}
+
+
+/// Returns the names of the usages set in the BIT STRING content octets of a keyUsage
+/// extension. Bits beyond the defined usages are reported as `bit N`.
+pub fn key_usage(content: &[u8]) -> Option<Vec<String>> {
+    let bits = bit_string_bits(content)?;
+    Some(
+        bits.iter()
+            .map(|bit| match KEY_USAGE_BITS.get(*bit) {
+                Some(name) => name.to_string(),
+                None => format!("bit {}", bit),
+            })
+            .collect(),
+    )
+}
+
+
+/// Returns the purpose OIDs listed in an extKeyUsage extension value, a SEQUENCE of OBJECT
+/// IDENTIFIERs. Returns `None` if the input is not a well-formed sequence of OIDs.
+pub fn ext_key_usage(bytes: &[u8]) -> Option<Vec<Vec<u64>>> {
+    let (tag, mut content, rest) = decode_tlv(bytes)?;
+    if tag != 0x30 || !rest.is_empty() {
+        return None;
+    }
+    let mut oids = Vec::new();
+    while !content.is_empty() {
+        let (tag, oid, rest) = decode_tlv(content)?;
+        if tag != 0x06 {
+            return None;
+        }
+        oids.push(decode_oid(oid)?);
+        content = rest;
+    }
+    Some(oids)
+}
+
+
+/// Problems with the content octets of a certificate serial number, an INTEGER, per RFC 5280.
+#[derive(Debug, PartialEq)]
+pub struct SerialIssues {
+    /// The serial number is negative.
+    pub negative: bool,
+    /// The serial number is zero, which RFC 5280 rules out by requiring a positive integer.
+    pub zero: bool,
+    /// The serial number is longer than the 20 octets conforming CAs may use.
+    pub too_long: bool,
+    /// The INTEGER has a redundant leading `0x00` or `0xFF` octet, which DER forbids.
+    pub non_minimal: bool,
+}
+
+
+/// Checks the content octets of a certificate serial number against RFC 5280.
+pub fn serial_issues(content: &[u8]) -> SerialIssues {
+    let redundant = match content {
+        [0x00, next, ..] => next & 0x80 == 0,
+        [0xFF, next, ..] => next & 0x80 != 0,
+        _ => false,
+    };
+    SerialIssues {
+        negative: content.first().is_some_and(|byte| byte & 0x80 != 0),
+        zero: content.iter().all(|byte| *byte == 0),
+        too_long: content.len() > 20,
+        non_minimal: redundant,
+    }
+}
+
+
+/// Returns the sign and the big-endian magnitude of the two's complement content octets of an
+/// INTEGER, e.g. `(true, [0x01])` for `FF`, which is -1.
+pub fn integer_magnitude(content: &[u8]) -> (bool, Vec<u8>) {
+    if content.first().is_none_or(|byte| byte & 0x80 == 0) {
+        return (false, content.to_vec());
+    }
+    // Negate by inverting the octets and adding 1, carrying from the least significant octet.
+    let mut magnitude: Vec<u8> = content.iter().map(|byte| !byte).collect();
+    for byte in magnitude.iter_mut().rev() {
+        let (sum, carry) = byte.overflowing_add(1);
+        *byte = sum;
+        if !carry {
+            break;
+        }
+    }
+    (true, magnitude)
+}
@@ -0,0 +1,69 @@
+//! Encoding of decimal digits as QR code numeric-mode groups and Code 128 symbols.
+
+
+/// Splits a string of decimal digits into QR code numeric-mode groups of up to three digits,
+/// returning each group with its encoded value and width in bits: 10 bits for three digits, 7
+/// for two, 4 for one. Returns `None` if the string contains a non-digit character.
+pub fn qr_numeric_groups(digits: &str) -> Option<Vec<(&str, u32, u32)>> {
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let groups = (0..digits.len())
+        .step_by(3)
+        .map(|i| {
+            let group = &digits[i..digits.len().min(i + 3)];
+            let width = match group.len() {
+                3 => 10,
+                2 => 7,
+                _ => 4,
+            };
+            (group, group.parse().unwrap(), width)
+        })
+        .collect();
+    Some(groups)
+}
+
+
+/// Returns the width in bits of the character count indicator for a QR code numeric-mode segment
+/// at the specified symbol version (1-40).
+pub fn qr_numeric_count_bits(version: u32) -> u32 {
+    match version {
+        0..=9 => 10,
+        10..=26 => 12,
+        _ => 14,
+    }
+}
+
+
+/// Returns the sequence of Code 128 symbol values encoding a string of decimal digits, starting
+/// with Start C and packing digit pairs into code set C. An odd final digit is encoded by
+/// switching to code set B. The checksum and stop symbols are not included. Returns `None` if the
+/// string is empty or contains a non-digit character.
+pub fn code128_digit_symbols(digits: &str) -> Option<Vec<u32>> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let bytes = digits.as_bytes();
+    let mut symbols = vec![105];
+    for pair in bytes.chunks(2) {
+        if pair.len() == 2 {
+            symbols.push((pair[0] - b'0') as u32 * 10 + (pair[1] - b'0') as u32);
+        } else {
+            symbols.push(100);
+            symbols.push(pair[0] as u32 - 32);
+        }
+    }
+    Some(symbols)
+}
+
+
+/// Returns the Code 128 checksum symbol value for a sequence of symbol values beginning with a
+/// start symbol: the start value plus each subsequent value weighted by its position, modulo 103.
+pub fn code128_checksum(symbols: &[u32]) -> u32 {
+    symbols
+        .iter()
+        .enumerate()
+        .map(|(i, value)| value * (i.max(1) as u32))
+        .sum::<u32>()
+        % 103
+}
@@ -0,0 +1,65 @@
+//! A minimal benchmark harness for the formatting hot paths, shared by `cargo bench` and the
+//! `intspector bench` command. Timings are wall-clock means over a fixed number of iterations.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+
+/// The timing of a benchmark.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    pub iterations: u64,
+    pub total: Duration,
+}
+
+
+impl Measurement {
+    /// Returns the mean time per iteration in nanoseconds.
+    pub fn nanos_per_iter(&self) -> f64 {
+        self.total.as_nanos() as f64 / self.iterations.max(1) as f64
+    }
+
+    /// Returns the number of iterations per second.
+    pub fn iters_per_sec(&self) -> f64 {
+        match self.total.as_secs_f64() {
+            secs if secs > 0.0 => self.iterations as f64 / secs,
+            _ => f64::INFINITY,
+        }
+    }
+}
+
+
+/// Times `iterations` calls of `f`, after a warm-up of a tenth as many calls. The result of each
+/// call is passed through `black_box` so the work isn't optimized away.
+pub fn measure<T>(name: &str, iterations: u64, mut f: impl FnMut() -> T) -> Measurement {
+    for _ in 0..iterations / 10 {
+        black_box(f());
+    }
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    Measurement { name: name.to_string(), iterations, total: start.elapsed() }
+}
+
+
+/// Times the library hot paths: parsing, binary formatting, and digit grouping, each over a
+/// spread of small and large inputs.
+pub fn library(iterations: u64) -> Vec<Measurement> {
+    let inputs = ["42", "0xDEADBEEF", "-0b1010_1010", "0o777", "0xFFFF_FFFF_FFFF_FFFF"];
+    let values = [42, 0xDEADBEEF, u64::MAX as u128, u128::MAX];
+    let decimals: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+    let mut cycle = 0;
+    let mut next = move |len: usize| {
+        cycle = (cycle + 1) % len;
+        cycle
+    };
+    vec![
+        measure("parse_int", iterations, || crate::parse_int(inputs[next(inputs.len())])),
+        measure("bin_string", iterations, || crate::bin_string(values[next(values.len())], 128)),
+        measure("add_spacers", iterations, || {
+            crate::add_spacers(&decimals[next(decimals.len())], ',', 3)
+        }),
+    ]
+}
@@ -0,0 +1,209 @@
+//! A minimal arbitrary-precision unsigned integer for values wider than 128 bits, e.g.
+//! certificate serial numbers and proof-of-work targets.
+
+
+/// An arbitrary-precision unsigned integer, stored as little-endian 32-bit limbs with no
+/// high-order zero limbs.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+
+impl BigUint {
+    /// Returns zero.
+    pub fn zero() -> BigUint {
+        BigUint { limbs: Vec::new() }
+    }
+
+    /// Creates an integer from a `u128`.
+    pub fn from_u128(mut value: u128) -> BigUint {
+        let mut limbs = Vec::new();
+        while value != 0 {
+            limbs.push(value as u32);
+            value >>= 32;
+        }
+        BigUint { limbs }
+    }
+
+    /// Creates an integer from big-endian bytes.
+    pub fn from_bytes_be(bytes: &[u8]) -> BigUint {
+        let mut limbs: Vec<u32> = bytes
+            .rchunks(4)
+            .map(|chunk| chunk.iter().fold(0, |acc, byte| acc << 8 | *byte as u32))
+            .collect();
+        normalize(&mut limbs);
+        BigUint { limbs }
+    }
+
+    /// Returns the integer as big-endian bytes with no leading zero bytes. Zero is returned as
+    /// an empty vector.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let bytes: Vec<u8> = self.limbs.iter().rev().flat_map(|limb| limb.to_be_bytes()).collect();
+        bytes.into_iter().skip_while(|byte| *byte == 0).collect()
+    }
+
+    /// Parses a string of hex digits, with an optional `0x` prefix. Returns `None` if the string
+    /// is empty or contains a non-hex character.
+    pub fn from_hex(hex: &str) -> Option<BigUint> {
+        let hex = hex.strip_prefix("0x").unwrap_or(hex);
+        if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let digits = hex.as_bytes();
+        let mut limbs: Vec<u32> = digits
+            .rchunks(8)
+            .map(|chunk| u32::from_str_radix(std::str::from_utf8(chunk).unwrap(), 16).unwrap())
+            .collect();
+        normalize(&mut limbs);
+        Some(BigUint { limbs })
+    }
+
+    /// Returns the low-order 128 bits of the integer.
+    pub fn low_u128(&self) -> u128 {
+        self.limbs.iter().take(4).rev().fold(0, |acc, limb| acc << 32 | *limb as u128)
+    }
+
+    /// Returns the integer shifted left by the specified number of bits.
+    pub fn shl(&self, bits: u32) -> BigUint {
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+        let (limb_shift, bit_shift) = ((bits / 32) as usize, bits % 32);
+        let mut limbs = vec![0; limb_shift];
+        let mut carry = 0;
+        for limb in &self.limbs {
+            let shifted = (*limb as u64) << bit_shift | carry;
+            limbs.push(shifted as u32);
+            carry = shifted >> 32;
+        }
+        limbs.push(carry as u32);
+        normalize(&mut limbs);
+        BigUint { limbs }
+    }
+
+    /// Returns the integer shifted right by the specified number of bits.
+    pub fn shr(&self, bits: u32) -> BigUint {
+        let (limb_shift, bit_shift) = ((bits / 32) as usize, bits % 32);
+        if limb_shift >= self.limbs.len() {
+            return BigUint::zero();
+        }
+        let high = &self.limbs[limb_shift..];
+        let mut limbs: Vec<u32> = (0..high.len())
+            .map(|i| {
+                let pair = (*high.get(i + 1).unwrap_or(&0) as u64) << 32 | high[i] as u64;
+                (pair >> bit_shift) as u32
+            })
+            .collect();
+        normalize(&mut limbs);
+        BigUint { limbs }
+    }
+
+    /// Returns the integer as the nearest `f64`. Integers too large for an `f64` round to
+    /// infinity.
+    pub fn to_f64(&self) -> f64 {
+        self.limbs.iter().rev().fold(0.0, |acc, limb| acc * 4294967296.0 + *limb as f64)
+    }
+
+    /// Returns true if the integer is zero.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Returns the number of significant bits, or 0 for zero.
+    pub fn bits(&self) -> u32 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u32 * 32 - top.leading_zeros(),
+            None => 0,
+        }
+    }
+
+    /// Multiplies the integer by a small factor.
+    pub fn mul_small(&self, factor: u32) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len() + 1);
+        let mut carry: u64 = 0;
+        for limb in &self.limbs {
+            let product = *limb as u64 * factor as u64 + carry;
+            limbs.push(product as u32);
+            carry = product >> 32;
+        }
+        limbs.push(carry as u32);
+        normalize(&mut limbs);
+        BigUint { limbs }
+    }
+
+    /// Multiplies the integer by another integer, using schoolbook multiplication.
+    pub fn mul(&self, other: &BigUint) -> BigUint {
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry: u64 = 0;
+            for (j, b) in other.limbs.iter().enumerate() {
+                let current = limbs[i + j] as u64 + *a as u64 * *b as u64 + carry;
+                limbs[i + j] = current as u32;
+                carry = current >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        normalize(&mut limbs);
+        BigUint { limbs }
+    }
+
+    /// Divides the integer by a small divisor, returning the quotient and remainder. Will panic
+    /// if the divisor is zero.
+    pub fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        assert!(divisor != 0);
+        let mut limbs = self.limbs.clone();
+        let mut remainder: u64 = 0;
+        for limb in limbs.iter_mut().rev() {
+            let current = remainder << 32 | *limb as u64;
+            *limb = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        normalize(&mut limbs);
+        (BigUint { limbs }, remainder as u32)
+    }
+
+    /// Formats the integer as uppercase hex digits with no leading zeros.
+    pub fn to_hex(&self) -> String {
+        match self.limbs.split_last() {
+            Some((top, rest)) => {
+                let mut hex = format!("{:X}", top);
+                for limb in rest.iter().rev() {
+                    hex += &format!("{:08X}", limb);
+                }
+                hex
+            },
+            None => "0".to_string(),
+        }
+    }
+}
+
+
+impl std::fmt::Display for BigUint {
+    /// Formats the integer in decimal.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        // Peel off nine decimal digits at a time.
+        let mut chunks = Vec::new();
+        let mut value = self.clone();
+        while !value.is_zero() {
+            let (quotient, remainder) = value.div_rem_small(1_000_000_000);
+            chunks.push(remainder);
+            value = quotient;
+        }
+        let mut text = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            text += &format!("{:09}", chunk);
+        }
+        write!(f, "{}", text)
+    }
+}
+
+
+fn normalize(limbs: &mut Vec<u32>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
@@ -0,0 +1,71 @@
+//! Renderings of the bits of an integer as a picture, in Braille dots or half-block pixels.
+
+
+// Arranges the specified number of low-order bits of `value` into a grid with one byte per row,
+// most significant byte at the top and most significant bit on the left. A partial high-order
+// byte is padded out with zeros.
+fn bit_grid(value: u128, num_bits: u32) -> Vec<[bool; 8]> {
+    let num_rows = num_bits.div_ceil(8);
+    (0..num_rows)
+        .rev()
+        .map(|byte| {
+            let mut row = [false; 8];
+            for (col, cell) in row.iter_mut().enumerate() {
+                let bit = byte * 8 + 7 - col as u32;
+                *cell = bit < num_bits && (value >> bit) & 1 == 1;
+            }
+            row
+        })
+        .collect()
+}
+
+
+/// Renders the specified number of low-order bits of `value` as a grid of Unicode Braille
+/// characters, one byte per row of dots, with the most significant byte at the top. Each
+/// character holds a 2x4 block of dots so 64 bits render as two lines of four characters.
+pub fn braille(value: u128, num_bits: u32) -> String {
+    // Dot offsets within a Braille cell, indexed by [row][column].
+    const DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+    let grid = bit_grid(value, num_bits);
+    let mut lines: Vec<String> = Vec::new();
+    for rows in grid.chunks(4) {
+        let mut line = String::new();
+        for col in (0..8).step_by(2) {
+            let mut code = 0x2800;
+            for (r, row) in rows.iter().enumerate() {
+                for c in 0..2 {
+                    if row[col + c] {
+                        code |= DOTS[r][c];
+                    }
+                }
+            }
+            line.push(std::char::from_u32(code).unwrap());
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+
+/// Renders the specified number of low-order bits of `value` as a grid of Unicode half-block
+/// characters, one byte per row of pixels, with the most significant byte at the top. Each
+/// character holds two vertically stacked pixels so 64 bits render as four lines of eight
+/// characters.
+pub fn blocks(value: u128, num_bits: u32) -> String {
+    let grid = bit_grid(value, num_bits);
+    let mut lines: Vec<String> = Vec::new();
+    for rows in grid.chunks(2) {
+        let upper = rows[0];
+        let lower = rows.get(1).copied().unwrap_or([false; 8]);
+        let line = (0..8)
+            .map(|col| match (upper[col], lower[col]) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => '·',
+            })
+            .collect();
+        lines.push(line);
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,150 @@
+//! Check digit algorithms and issuer tables for account and card numbers.
+
+
+/// IBAN lengths by country code, from the SWIFT IBAN registry.
+pub const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24), ("AE", 23), ("AL", 28), ("AT", 20), ("AZ", 28), ("BA", 20), ("BE", 16),
+    ("BG", 22), ("BH", 22), ("BR", 29), ("CH", 21), ("CR", 22), ("CY", 28), ("CZ", 24),
+    ("DE", 22), ("DK", 18), ("DO", 28), ("EE", 20), ("EG", 29), ("ES", 24), ("FI", 18),
+    ("FO", 18), ("FR", 27), ("GB", 22), ("GE", 22), ("GI", 23), ("GL", 18), ("GR", 27),
+    ("GT", 28), ("HR", 21), ("HU", 28), ("IE", 22), ("IL", 23), ("IQ", 23), ("IS", 26),
+    ("IT", 27), ("JO", 30), ("KW", 30), ("KZ", 20), ("LB", 28), ("LC", 32), ("LI", 21),
+    ("LT", 20), ("LU", 20), ("LV", 21), ("MC", 27), ("MD", 24), ("ME", 22), ("MK", 19),
+    ("MR", 27), ("MT", 31), ("MU", 30), ("NL", 18), ("NO", 15), ("PK", 24), ("PL", 28),
+    ("PS", 29), ("PT", 25), ("QA", 29), ("RO", 24), ("RS", 22), ("SA", 24), ("SC", 31),
+    ("SE", 24), ("SI", 19), ("SK", 24), ("SM", 27), ("ST", 25), ("SV", 28), ("TL", 23),
+    ("TN", 24), ("TR", 26), ("UA", 29), ("VA", 22), ("VG", 24), ("XK", 20),
+];
+
+
+/// Returns the expected IBAN length for the specified two-letter country code.
+pub fn iban_length(country: &str) -> Option<usize> {
+    IBAN_LENGTHS.iter().find(|(code, _)| code.eq_ignore_ascii_case(country)).map(|(_, n)| *n)
+}
+
+
+/// Strips spaces from an IBAN and converts it to uppercase. Returns `None` if the result is not
+/// a two-letter country code and two check digits followed by up to 30 alphanumeric characters.
+pub fn iban_normalize(iban: &str) -> Option<String> {
+    let iban: String = iban.chars().filter(|c| *c != ' ').collect::<String>().to_uppercase();
+    let bytes = iban.as_bytes();
+    if iban.len() < 5 || iban.len() > 34 || !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    if !bytes[..2].iter().all(u8::is_ascii_alphabetic) {
+        return None;
+    }
+    if !bytes[2..4].iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some(iban)
+}
+
+
+/// Returns the big integer used for IBAN validation, as a string of decimal digits: the first
+/// four characters are moved to the end and each letter is replaced by two digits, A = 10 to
+/// Z = 35. Expects a normalized IBAN.
+pub fn iban_numeric(iban: &str) -> String {
+    let rearranged = format!("{}{}", &iban[4..], &iban[..4]);
+    rearranged.chars().map(|c| c.to_digit(36).unwrap().to_string()).collect()
+}
+
+
+/// Returns the remainder of a string of decimal digits of any length modulo 97, processing seven
+/// digits at a time. Returns `None` if the string contains a non-digit.
+pub fn mod97(digits: &str) -> Option<u32> {
+    let mut remainder: u64 = 0;
+    for chunk in digits.as_bytes().chunks(7) {
+        let chunk = std::str::from_utf8(chunk).ok()?;
+        if !chunk.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        remainder = format!("{}{}", remainder, chunk).parse::<u64>().ok()? % 97;
+    }
+    Some(remainder as u32)
+}
+
+
+/// Returns the correct check digits for a normalized IBAN, ignoring its current check digits.
+pub fn iban_check_digits(iban: &str) -> u32 {
+    let zeroed = format!("{}00{}", &iban[..2], &iban[4..]);
+    98 - mod97(&iban_numeric(&zeroed)).unwrap()
+}
+
+
+/// Returns the Luhn sum of a string of decimal digits modulo 10: every second digit from the
+/// right is doubled, with 9 subtracted from doubled values above 9, and all digits are summed. A
+/// number with a valid check digit has a Luhn sum of 0. Returns `None` if the string is empty or
+/// contains a non-digit.
+pub fn luhn_sum(digits: &str) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+    let mut sum = 0;
+    for (i, c) in digits.chars().rev().enumerate() {
+        let mut digit = c.to_digit(10)?;
+        if i % 2 == 1 {
+            digit *= 2;
+            if digit > 9 {
+                digit -= 9;
+            }
+        }
+        sum += digit;
+    }
+    Some(sum % 10)
+}
+
+
+/// Returns the Luhn check digit that should be appended to the specified string of decimal
+/// digits.
+pub fn luhn_check_digit(payload: &str) -> Option<u32> {
+    let sum = luhn_sum(&format!("{}0", payload))?;
+    Some((10 - sum) % 10)
+}
+
+
+/// Issuer identification number ranges for the major card networks, as inclusive prefix ranges
+/// with the valid card number lengths. Both ends of a range have the same number of digits.
+pub const CARD_RANGES: &[(u32, u32, &str, &[usize])] = &[
+    (4, 4, "Visa", &[13, 16, 19]),
+    (51, 55, "Mastercard", &[16]),
+    (2221, 2720, "Mastercard", &[16]),
+    (34, 34, "American Express", &[15]),
+    (37, 37, "American Express", &[15]),
+    (6011, 6011, "Discover", &[16, 17, 18, 19]),
+    (644, 649, "Discover", &[16, 17, 18, 19]),
+    (65, 65, "Discover", &[16, 17, 18, 19]),
+    (622126, 622925, "Discover", &[16, 17, 18, 19]),
+    (300, 305, "Diners Club", &[14, 15, 16, 17, 18, 19]),
+    (36, 36, "Diners Club", &[14, 15, 16, 17, 18, 19]),
+    (38, 39, "Diners Club", &[14, 15, 16, 17, 18, 19]),
+    (3528, 3589, "JCB", &[16, 17, 18, 19]),
+    (62, 62, "UnionPay", &[16, 17, 18, 19]),
+    (5018, 5018, "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    (5020, 5020, "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    (5038, 5038, "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    (5893, 5893, "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    (6304, 6304, "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    (6759, 6759, "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    (6761, 6763, "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+];
+
+
+/// Returns the card network whose issuer range matches the leading digits of a card number,
+/// along with the network's valid card number lengths. The longest matching prefix wins, e.g.
+/// 622126 is Discover rather than UnionPay.
+pub fn card_network(digits: &str) -> Option<(&'static str, &'static [usize])> {
+    let mut best: Option<(usize, &'static str, &'static [usize])> = None;
+    for (lo, hi, name, lengths) in CARD_RANGES {
+        let len = lo.to_string().len();
+        let prefix = match digits.get(..len).and_then(|p| p.parse::<u32>().ok()) {
+            Some(prefix) => prefix,
+            None => continue,
+        };
+        let is_longer = best.is_none_or(|(best_len, _, _)| len > best_len);
+        if (*lo..=*hi).contains(&prefix) && is_longer {
+            best = Some((len, *name, *lengths));
+        }
+    }
+    best.map(|(_, name, lengths)| (name, lengths))
+}
@@ -0,0 +1,300 @@
+//! CRC and checksum algorithms, with a catalogue of common variants for identifying the algorithm
+//! that produced a value.
+
+
+/// A CRC algorithm, in the parameter model of Ross Williams' "A Painless Guide to CRC Error
+/// Detection Algorithms" as used by the CRC RevEng catalogue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crc {
+    pub name: &'static str,
+    /// Width in bits, from 8 to 64.
+    pub width: u32,
+    /// Generator polynomial, without the leading term.
+    pub poly: u64,
+    /// Initial register value.
+    pub init: u64,
+    /// True if each input byte is reflected, i.e. processed least significant bit first.
+    pub refin: bool,
+    /// True if the final register value is reflected.
+    pub refout: bool,
+    /// Value XORed into the final register value.
+    pub xorout: u64,
+    /// The CRC of the ASCII string `123456789`.
+    pub check: u64,
+}
+
+
+impl Crc {
+    /// Returns the CRC of the bytes. Will panic if the width is not from 8 to 64.
+    pub fn compute(&self, bytes: &[u8]) -> u64 {
+        assert!((8..=64).contains(&self.width));
+        let mask = u64::MAX >> (64 - self.width);
+        let top = 1 << (self.width - 1);
+        let mut crc = self.init & mask;
+        for byte in bytes {
+            let byte = if self.refin { byte.reverse_bits() } else { *byte };
+            crc ^= (byte as u64) << (self.width - 8);
+            for _ in 0..8 {
+                crc = if crc & top != 0 { crc << 1 ^ self.poly } else { crc << 1 };
+            }
+            crc &= mask;
+        }
+        if self.refout {
+            crc = crc.reverse_bits() >> (64 - self.width);
+        }
+        (crc ^ self.xorout) & mask
+    }
+}
+
+
+/// Common CRC variants, by their names in the CRC RevEng catalogue.
+pub const CRC_PRESETS: &[Crc] = &[
+    Crc {
+        name: "CRC-8/SMBUS",
+        width: 8, poly: 0x07, init: 0x00, refin: false, refout: false, xorout: 0x00, check: 0xF4,
+    },
+    Crc {
+        name: "CRC-8/MAXIM-DOW",
+        width: 8, poly: 0x31, init: 0x00, refin: true, refout: true, xorout: 0x00, check: 0xA1,
+    },
+    Crc {
+        name: "CRC-8/AUTOSAR",
+        width: 8, poly: 0x2F, init: 0xFF, refin: false, refout: false, xorout: 0xFF, check: 0xDF,
+    },
+    Crc {
+        name: "CRC-8/BLUETOOTH",
+        width: 8, poly: 0xA7, init: 0x00, refin: true, refout: true, xorout: 0x00, check: 0x26,
+    },
+    Crc {
+        name: "CRC-8/I-432-1",
+        width: 8, poly: 0x07, init: 0x00, refin: false, refout: false, xorout: 0x55, check: 0xA1,
+    },
+    Crc {
+        name: "CRC-16/ARC",
+        width: 16, poly: 0x8005, init: 0x0000, refin: true, refout: true, xorout: 0x0000,
+        check: 0xBB3D,
+    },
+    Crc {
+        name: "CRC-16/IBM-3740",
+        width: 16, poly: 0x1021, init: 0xFFFF, refin: false, refout: false, xorout: 0x0000,
+        check: 0x29B1,
+    },
+    Crc {
+        name: "CRC-16/XMODEM",
+        width: 16, poly: 0x1021, init: 0x0000, refin: false, refout: false, xorout: 0x0000,
+        check: 0x31C3,
+    },
+    Crc {
+        name: "CRC-16/KERMIT",
+        width: 16, poly: 0x1021, init: 0x0000, refin: true, refout: true, xorout: 0x0000,
+        check: 0x2189,
+    },
+    Crc {
+        name: "CRC-16/MODBUS",
+        width: 16, poly: 0x8005, init: 0xFFFF, refin: true, refout: true, xorout: 0x0000,
+        check: 0x4B37,
+    },
+    Crc {
+        name: "CRC-16/IBM-SDLC",
+        width: 16, poly: 0x1021, init: 0xFFFF, refin: true, refout: true, xorout: 0xFFFF,
+        check: 0x906E,
+    },
+    Crc {
+        name: "CRC-16/USB",
+        width: 16, poly: 0x8005, init: 0xFFFF, refin: true, refout: true, xorout: 0xFFFF,
+        check: 0xB4C8,
+    },
+    Crc {
+        name: "CRC-16/GENIBUS",
+        width: 16, poly: 0x1021, init: 0xFFFF, refin: false, refout: false, xorout: 0xFFFF,
+        check: 0xD64E,
+    },
+    Crc {
+        name: "CRC-16/DNP",
+        width: 16, poly: 0x3D65, init: 0x0000, refin: true, refout: true, xorout: 0xFFFF,
+        check: 0xEA82,
+    },
+    Crc {
+        name: "CRC-32/ISO-HDLC",
+        width: 32, poly: 0x04C11DB7, init: 0xFFFFFFFF, refin: true, refout: true,
+        xorout: 0xFFFFFFFF, check: 0xCBF43926,
+    },
+    Crc {
+        name: "CRC-32/ISCSI",
+        width: 32, poly: 0x1EDC6F41, init: 0xFFFFFFFF, refin: true, refout: true,
+        xorout: 0xFFFFFFFF, check: 0xE3069283,
+    },
+    Crc {
+        name: "CRC-32/BZIP2",
+        width: 32, poly: 0x04C11DB7, init: 0xFFFFFFFF, refin: false, refout: false,
+        xorout: 0xFFFFFFFF, check: 0xFC891918,
+    },
+    Crc {
+        name: "CRC-32/MPEG-2",
+        width: 32, poly: 0x04C11DB7, init: 0xFFFFFFFF, refin: false, refout: false,
+        xorout: 0x00000000, check: 0x0376E6E7,
+    },
+    Crc {
+        name: "CRC-32/CKSUM",
+        width: 32, poly: 0x04C11DB7, init: 0x00000000, refin: false, refout: false,
+        xorout: 0xFFFFFFFF, check: 0x765E7680,
+    },
+    Crc {
+        name: "CRC-32/JAMCRC",
+        width: 32, poly: 0x04C11DB7, init: 0xFFFFFFFF, refin: true, refout: true,
+        xorout: 0x00000000, check: 0x340BC6D9,
+    },
+    Crc {
+        name: "CRC-32/AIXM",
+        width: 32, poly: 0x814141AB, init: 0x00000000, refin: false, refout: false,
+        xorout: 0x00000000, check: 0x3010BF7F,
+    },
+    Crc {
+        name: "CRC-64/XZ",
+        width: 64, poly: 0x42F0E1EBA9EA3693, init: u64::MAX, refin: true, refout: true,
+        xorout: u64::MAX, check: 0x995DC9BBDF1939FA,
+    },
+    Crc {
+        name: "CRC-64/ECMA-182",
+        width: 64, poly: 0x42F0E1EBA9EA3693, init: 0, refin: false, refout: false, xorout: 0,
+        check: 0x6C40DF5F0B497347,
+    },
+    Crc {
+        name: "CRC-64/GO-ISO",
+        width: 64, poly: 0x1B, init: u64::MAX, refin: true, refout: true, xorout: u64::MAX,
+        check: 0xB90956C775A41001,
+    },
+];
+
+
+/// A checksum algorithm that isn't a CRC.
+#[derive(Clone, Copy)]
+pub struct Checksum {
+    pub name: &'static str,
+    /// Width of the result in bits.
+    pub width: u32,
+    pub compute: fn(&[u8]) -> u64,
+}
+
+
+/// Simple sums and checksums used by file formats and protocols.
+pub const CHECKSUMS: &[Checksum] = &[
+    Checksum { name: "SUM-8", width: 8, compute: |bytes| byte_sum(bytes) & 0xFF },
+    Checksum {
+        name: "SUM-8/TWOS",
+        width: 8,
+        compute: |bytes| byte_sum(bytes).wrapping_neg() & 0xFF,
+    },
+    Checksum { name: "SUM-8/ONES", width: 8, compute: |bytes| !byte_sum(bytes) & 0xFF },
+    Checksum { name: "SUM-16", width: 16, compute: |bytes| byte_sum(bytes) & 0xFFFF },
+    Checksum { name: "SUM-32", width: 32, compute: |bytes| byte_sum(bytes) & 0xFFFFFFFF },
+    Checksum { name: "XOR-8", width: 8, compute: |bytes| xor_bytes(bytes) as u64 },
+    Checksum { name: "INTERNET", width: 16, compute: |bytes| internet_checksum(bytes) as u64 },
+    Checksum { name: "FLETCHER-16", width: 16, compute: |bytes| fletcher16(bytes) as u64 },
+    Checksum { name: "ADLER-32", width: 32, compute: |bytes| adler32(bytes) as u64 },
+];
+
+
+// Returns the sum of the bytes, modulo 2^64.
+fn byte_sum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0u64, |acc, byte| acc.wrapping_add(*byte as u64))
+}
+
+
+/// Returns the XOR of the bytes, the longitudinal redundancy check used by many serial
+/// protocols.
+pub fn xor_bytes(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |acc, byte| acc ^ byte)
+}
+
+
+/// Returns the Internet checksum of RFC 1071, as used by IPv4, TCP, and UDP: the ones' complement
+/// of the ones' complement sum of the bytes as big-endian 16-bit words. An odd final byte is
+/// padded with zero.
+pub fn internet_checksum(bytes: &[u8]) -> u16 {
+    let mut sum: u64 = bytes
+        .chunks(2)
+        .map(|pair| (pair[0] as u64) << 8 | pair.get(1).copied().unwrap_or(0) as u64)
+        .sum();
+    while sum > 0xFFFF {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+
+/// Returns the Fletcher-16 checksum of the bytes.
+pub fn fletcher16(bytes: &[u8]) -> u16 {
+    let (mut sum1, mut sum2) = (0u32, 0u32);
+    for byte in bytes {
+        sum1 = (sum1 + *byte as u32) % 255;
+        sum2 = (sum2 + sum1) % 255;
+    }
+    (sum2 << 8 | sum1) as u16
+}
+
+
+/// Returns the Adler-32 checksum of the bytes, as used by zlib.
+pub fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in bytes {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    b << 16 | a
+}
+
+
+/// Returns the name, width, and value of every catalogued CRC and checksum of the bytes.
+pub fn all_checksums(bytes: &[u8]) -> Vec<(&'static str, u32, u64)> {
+    let crcs = CRC_PRESETS.iter().map(|crc| (crc.name, crc.width, crc.compute(bytes)));
+    let sums = CHECKSUMS.iter().map(|sum| (sum.name, sum.width, (sum.compute)(bytes)));
+    crcs.chain(sums).collect()
+}
+
+
+/// Short names for the most common algorithms, paired with their catalogue names.
+pub const ALIASES: &[(&str, &str)] = &[
+    ("crc8", "CRC-8/SMBUS"),
+    ("crc16", "CRC-16/ARC"),
+    ("crc32", "CRC-32/ISO-HDLC"),
+    ("crc32c", "CRC-32/ISCSI"),
+    ("crc64", "CRC-64/XZ"),
+    ("sum8", "SUM-8"),
+    ("xor", "XOR-8"),
+    ("fletcher16", "FLETCHER-16"),
+    ("adler32", "ADLER-32"),
+];
+
+
+/// Computes the CRC or checksum with the specified short name or catalogue name, ignoring case.
+/// Returns its catalogue name, width, and value, or `None` if the name is unknown.
+pub fn compute_named(name: &str, bytes: &[u8]) -> Option<(&'static str, u32, u64)> {
+    let name = ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, full)| *full);
+    if let Some(crc) = CRC_PRESETS.iter().find(|crc| crc.name.eq_ignore_ascii_case(name)) {
+        return Some((crc.name, crc.width, crc.compute(bytes)));
+    }
+    CHECKSUMS
+        .iter()
+        .find(|sum| sum.name.eq_ignore_ascii_case(name))
+        .map(|sum| (sum.name, sum.width, (sum.compute)(bytes)))
+}
+
+
+/// Returns the names of the catalogued CRCs and checksums that give the target value for the
+/// bytes, each with a flag that's true if the match is with the bytes of the value in reverse
+/// order, i.e. the value was stored with the other endianness.
+pub fn find_matches(bytes: &[u8], target: u64) -> Vec<(&'static str, bool)> {
+    let mut matches = Vec::new();
+    for (name, width, value) in all_checksums(bytes) {
+        if value == target {
+            matches.push((name, false));
+        } else if width > 8 && value.swap_bytes() >> (64 - width) == target {
+            matches.push((name, true));
+        }
+    }
+    matches
+}
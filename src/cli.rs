@@ -0,0 +1,1953 @@
+// The command line interface: run(), which runs it with an output and an environment, the
+// default command, and the helpers shared by the subcommands in the commands module.
+
+use arguably::ArgParser;
+use crate::commands::{self, Decoder, Opt};
+use crate::interrupt;
+use crate::locale::{self, Locale};
+use crate::options;
+use crate::output::{self, Style};
+use crate::report::{self, Align, Report};
+use crate::stream;
+use std::io::BufRead;
+use std::io::IsTerminal;
+use std::io::Write;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::min_bits;
+use crate::std_bits;
+use crate::popcount;
+use crate::clz;
+use crate::ctz;
+use crate::parity;
+use crate::bit_runs;
+use crate::set_bits;
+use crate::field_mask;
+use crate::common_prefix_len;
+use crate::add_spacers;
+use crate::bin_string;
+use crate::bin_string_with;
+use crate::to_base;
+use crate::parse_int;
+use crate::eval_expr_with;
+use crate::IntspectorError;
+use crate::analyze;
+use crate::IntInfo;
+use crate::utf8_bytes;
+use crate::utf16_units;
+use crate::parse_u128;
+use crate::common_alignment;
+use crate::common_stride;
+use crate::is_power_of_two;
+use crate::next_power_of_two;
+use crate::prev_power_of_two;
+use crate::bitmap;
+use crate::codec;
+use crate::coding;
+use crate::datetime;
+use crate::json;
+use crate::magic;
+use crate::poison;
+use crate::random;
+use crate::ratio;
+
+
+const HELP: &str = "
+Usage: intspector [integers]
+
+  Integer conversion utility. Accepts integer input in [b]inary, [o]ctal,
+  [d]ecimal, or he[x]adecimal base, then displays the number in all four bases.
+
+  Use a single letter prefix to declare the base of the input, e.g. b1010.
+  The base defaults to decimal if the prefix is omitted.
+
+  This utility:
+
+  - Accepts integer literals with a leading zero, e.g. 0x123.
+  - Accepts digit separators, e.g. 0x1234_5678, 1'000'000, or 1,000,000.
+  - Accepts an explicit sign in any base, e.g. -0x10 or +b1010.
+  - Accepts any base from 2 to 36 with a base: prefix, e.g. 36:zz.
+  - Accepts multiple arguments.
+  - Reads integers from stdin when piped input is given without arguments,
+    streaming input of any size. Ctrl-C stops reading and prints the
+    results for the input read so far.
+  - Evaluates expressions, e.g. '(x3C | 0o17) + 1' or '1 << 20', using the
+    operators * / % + - << >> & ^ | ~ and parentheses. (Quote expressions
+    to protect them from the shell.)
+  - Accepts input in the signed 128-bit integer range, and unsigned input up
+    to 2^128 - 1.
+  - Displays the two's complement value for negative integers.
+  - Counts the set bits, leading and trailing zeros, and parity at the
+    displayed width.
+  - Flags well-known poison and sentinel values, e.g. 0xDEADBEEF.
+  - Summarizes batches of integers with the --histogram and --stats flags,
+    e.g. for values scraped from logs or exported ID lists.
+";
+
+
+// The default command's options and flags, including the global options, which are read from the
+// front of the command line before the rest is parsed. (See the options module.)
+const OPTIONS: &[Opt] = &[
+    Opt {
+        names: "bits b",
+        value: Some("n"),
+        help: "Number of binary digits to display. (Determines the two's complement value for \
+            negative integers.)",
+    },
+    Opt {
+        names: "base",
+        value: Some("n"),
+        help: "Also display the integer in the specified base, from 2 to 36.",
+    },
+    Opt {
+        names: "bins",
+        value: Some("n"),
+        help: "Number of equal-width bins for --histogram. (Bins are powers of two by default.)",
+    },
+    Opt {
+        names: "color",
+        value: Some("when"),
+        help: "Color the output: always, never, or auto. Auto, the default, uses color only if \
+            stdout is a terminal and NO_COLOR isn't set. Works with any command, given before \
+            the command name.",
+    },
+    Opt {
+        names: "fold",
+        value: Some("op"),
+        help: "Also combine all the inputs with a bitwise operator: and, or, or xor. E.g. or a \
+            set of flag words to find the bits that are ever set.",
+    },
+    Opt {
+        names: "format",
+        value: Some("name"),
+        help: "Output format: text (the default), json, csv, or markdown. See --json for the \
+            JSON members. CSV rows start with their input, under a header row. Works with any \
+            command, given before the command name.",
+    },
+    Opt {
+        names: "group",
+        value: Some("n"),
+        help: "Number of binary digits between spacers. Defaults to 4 if --spacer is set. (Use \
+            0 for no spacers.)",
+    },
+    Opt {
+        names: "lang",
+        value: Some("code"),
+        help: "Language of the output labels and help headings: en (the default), de, es, or \
+            fr. The values and messages stay in English. Defaults to the INTSPECTOR_LANG \
+            environment variable. Works with any command, given before the command name.",
+    },
+    Opt {
+        names: "sort",
+        value: Some("order"),
+        help: "Sort the inputs by value before displaying them: asc or signed, desc, or \
+            unsigned (negative values last, in two's complement order).",
+    },
+    Opt {
+        names: "spacer",
+        value: Some("char"),
+        help: "Character to insert between groups of binary digits. Defaults to '_' if --group \
+            is set.",
+    },
+    Opt {
+        names: "theme",
+        value: Some("name"),
+        help: "Color theme for dark or light terminals: dark (the default) or light. Works with \
+            any command, given before the command name.",
+    },
+    Opt {
+        names: "blocks",
+        value: None,
+        help: "Also render the bits as a grid of block characters.",
+    },
+    Opt {
+        names: "braille",
+        value: None,
+        help: "Also render the bits as a grid of Braille dots.",
+    },
+    Opt {
+        names: "dedup",
+        value: None,
+        help: "Skip inputs with the same value as an earlier input.",
+    },
+    Opt {
+        names: "delta",
+        value: None,
+        help: "Print a table of the differences between consecutive inputs, in all four bases, \
+            instead of the info blocks, and check for a constant stride.",
+    },
+    Opt {
+        names: "histogram",
+        value: None,
+        help: "Print a histogram and summary statistics of all the inputs instead of the info \
+            blocks.",
+    },
+    Opt {
+        names: "interactive i",
+        value: None,
+        help: "Start an interactive prompt, as the repl command.",
+    },
+    Opt {
+        names: "json",
+        value: None,
+        help: "Print a JSON object for each input instead of the info block, as --format json. \
+            Its members are 'input', then one for each label of the info block, with numbers as \
+            JSON numbers. An input that can't be parsed gives the members 'error' (the \
+            message), 'code' (a stable identifier such as 'invalid_digit' or 'overflow'), and \
+            'position' (the byte offset of the offending character in the input, or null). \
+            Works with any command, given before the command name.",
+    },
+    Opt {
+        names: "no-color",
+        value: None,
+        help: "Don't color the output, as --color never. Works with any command, given before \
+            the command name.",
+    },
+    Opt {
+        names: "no-hints",
+        value: None,
+        help: "Don't show hints for values that look like timestamps, packed dates, ASCII text, \
+            or well-known constants.",
+    },
+    Opt {
+        names: "prefix",
+        value: None,
+        help: "Also show the longest binary prefix common to all the inputs, with the mask and \
+            range of the aligned block covering them, e.g. to infer a CIDR block.",
+    },
+    Opt {
+        names: "stats",
+        value: None,
+        help: "Print the count, distinct values, duplicates, and range of all the inputs \
+            instead of the info blocks, with any values too wide for --bits.",
+    },
+    Opt {
+        names: "stride",
+        value: None,
+        help: "Also show the largest power-of-two alignment shared by all the inputs, and their \
+            stride: the GCD of their differences, e.g. to infer an array layout from a list of \
+            pointers.",
+    },
+    Opt {
+        names: "stdin",
+        value: None,
+        help: "Read whitespace-separated integers from stdin. (Automatic if no integers are \
+            given and stdin is not a terminal.)",
+    },
+    Opt {
+        names: "verbose",
+        value: None,
+        help: "Also show the nearest powers of two, the base-2 logarithm, and the alignment of \
+            each input.",
+    },
+    Opt {
+        names: "version v",
+        value: None,
+        help: "Print the application's version number.",
+    },
+];
+
+
+// The default command, which runs when no command name is given. Its parser and the options part
+// of the main help text come from its option table, as for the subcommands.
+struct DefaultCommand;
+
+
+impl Decoder for DefaultCommand {
+    fn names(&self) -> &'static str {
+        "intspector"
+    }
+
+    fn summary(&self) -> &'static str {
+        "Displays integers in all four bases."
+    }
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to convert.")]
+    }
+
+    fn options(&self) -> &'static [Opt] {
+        OPTIONS
+    }
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        default_action(ctx, parser)
+    }
+}
+
+
+const HELP_COMMAND_HELP: &str = "
+Command Help:
+  help <command>        Print the specified command's help text.
+
+Plugins:
+  Any other command runs an executable named intspector-<command> on the
+  PATH, e.g. intspector-regs for 'intspector regs'. The plugin gets the
+  arguments on its command line and as a JSON object on stdin:
+
+    {\"command\": \"regs\", \"args\": [...], \"version\": \"...\"}
+
+  It should print a JSON object with a \"blocks\" array, or an \"error\"
+  string. Each block is displayed as an info block: a string is shown line
+  by line, and an object as a labelled field for each member.
+";
+
+
+// Lists the subcommands with their summaries for the main help text.
+fn command_list() -> String {
+    let mut output = String::new();
+    for decoder in commands::DECODERS {
+        let names = decoder.names().split(' ').collect::<Vec<&str>>().join(", ");
+        output += &format!("  {:<30}{}\n", names, decoder.summary());
+    }
+    output
+}
+
+
+// Returns the main help text, before translation.
+pub fn helptext() -> String {
+    format!("{}\nCommands:\n{}{}", DefaultCommand.helptext(), command_list(), HELP_COMMAND_HELP)
+}
+
+
+/// An error that stops the command line interface.
+#[derive(Debug)]
+pub enum Error {
+    /// The arguments or the input are invalid, or a file can't be read. The message explains why.
+    Invalid(String),
+    /// Writing the output failed.
+    Io(std::io::Error),
+    /// A Ctrl-C stopped the command after it printed the results for the input read so far.
+    Interrupted,
+    /// A plugin exited with this status after its output was printed.
+    Plugin(i32),
+}
+
+
+impl Error {
+    /// Returns the binary's exit status for the error: 130 for a command stopped by Ctrl-C, as
+    /// shells report a process stopped by SIGINT, a failed plugin's own status, and otherwise 1.
+    pub fn exit_status(&self) -> i32 {
+        match self {
+            Error::Interrupted => interrupt::EXIT_STATUS,
+            Error::Plugin(status) => *status,
+            _ => 1,
+        }
+    }
+}
+
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Invalid(msg) => write!(f, "{}", msg),
+            Error::Io(err) => write!(f, "cannot write the output: {}.", err),
+            Error::Interrupted => write!(f, "interrupted."),
+            Error::Plugin(status) => write!(f, "the plugin exited with status {}.", status),
+        }
+    }
+}
+
+
+impl std::error::Error for Error {}
+
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+
+// The parts of the process environment the commands can use. run() leaves them all out.
+#[derive(Default)]
+pub struct Environment<'a> {
+    // Standard input, which the default command and dump read if it's piped, and the REPL reads
+    // line by line, and the size of the file it's redirected from, if it's a regular file.
+    pub stdin: Option<Box<dyn BufRead + 'a>>,
+    pub stdin_terminal: bool,
+    pub stdin_size: Option<u64>,
+    // Whether stdout and stderr are terminals, for the auto color mode and the progress line.
+    pub stdout_terminal: bool,
+    pub stderr_terminal: bool,
+    // The width of the terminal, for the rules between info blocks.
+    pub width: Option<usize>,
+    // The INTSPECTOR_LANG environment variable, and whether NO_COLOR is set and not empty.
+    pub lang: Option<String>,
+    pub no_color: bool,
+    // Whether to catch Ctrl-C, and whether to look for plugins on the PATH.
+    pub signals: bool,
+    pub plugins: bool,
+    // The current Unix time, for the times that time and jwt show relative to now, and the seed
+    // for rand's generator if --seed isn't given.
+    pub now: i64,
+    pub seed: u64,
+}
+
+
+// What a command reads and writes besides its arguments: the output, the style of its reports,
+// and the environment. Warnings that don't stop the command, e.g. about an input that can't be
+// read, go to `err`, or to the output if there's none.
+pub struct Context<'a> {
+    pub out: &'a mut dyn Write,
+    err: Option<&'a mut dyn Write>,
+    pub style: Style,
+    pub env: Environment<'a>,
+    // The last CSV header row written, so a batch of reports shares one.
+    pub csv_header: Option<Vec<String>>,
+    interrupted: &'a AtomicBool,
+}
+
+
+impl<'a> Context<'a> {
+    // Writes a rule between info blocks, if the style has them.
+    pub fn termline(&mut self) -> Result<(), Error> {
+        if let Some(line) = self.style.termline() {
+            writeln!(self.out, "{}", line)?;
+        }
+        Ok(())
+    }
+
+
+    pub fn warn(&mut self, msg: &str) -> Result<(), Error> {
+        match &mut self.err {
+            Some(err) => writeln!(err, "{}", msg)?,
+            None => writeln!(self.out, "{}", msg)?,
+        }
+        Ok(())
+    }
+
+
+    // Catches Ctrl-C until the guard is dropped, if the environment allows it.
+    pub fn catch(&self) -> Option<interrupt::Guard> {
+        match self.env.signals {
+            true => Some(interrupt::catch()),
+            false => None,
+        }
+    }
+
+
+    // The flag set by a caught Ctrl-C.
+    pub fn interrupt_flag(&self) -> &'a AtomicBool {
+        self.interrupted
+    }
+
+
+    pub fn interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::SeqCst)
+    }
+
+
+    // Whether to show a progress line on stderr while reading, which is only shown on a terminal
+    // and only if the output goes elsewhere or is `deferred` until the input has been read.
+    pub fn progress(&self, deferred: bool) -> bool {
+        self.env.stderr_terminal && (deferred || !self.env.stdout_terminal)
+    }
+}
+
+
+/// Runs the command line interface with the specified arguments, not including the program
+/// name, and writes its output to `out`.
+///
+/// Unlike the binary, it doesn't read stdin, the environment, the terminal, or the clock, catch
+/// Ctrl-C, run plugins, or seed rand randomly, so the output depends only on the arguments: plain
+/// text in English unless the arguments specify otherwise, without color or rules between the
+/// info blocks. Relative times are measured from the Unix epoch, and rand's generator is seeded
+/// with 0 unless `--seed` is given. Warnings that the binary writes to stderr are written to
+/// `out`, and the errors that stop the binary with a message are returned.
+pub fn run(args: &[&str], out: &mut impl Write) -> Result<(), Error> {
+    let args = args.iter().map(|arg| arg.to_string()).collect();
+    let interrupted = AtomicBool::new(false);
+    run_with(args, out, None, Environment::default(), &interrupted)
+}
+
+
+// Runs the command line interface as run() does, with `stdin` piped to standard input, for the
+// self-test vectors of the commands that read it and the batch mode benchmark.
+pub fn run_with_stdin(args: &[&str], stdin: &[u8], out: &mut impl Write) -> Result<(), Error> {
+    let args = args.iter().map(|arg| arg.to_string()).collect();
+    let interrupted = AtomicBool::new(false);
+    let env = Environment {
+        stdin: Some(Box::new(stdin)),
+        stdin_size: Some(stdin.len() as u64),
+        ..Environment::default()
+    };
+    run_with(args, out, None, env, &interrupted)
+}
+
+
+/// Runs the command line interface for the binary, with the process's stdin, stdout, stderr,
+/// and environment, and returns the exit status.
+#[doc(hidden)]
+pub fn main(args: Vec<String>) -> i32 {
+    let (stdin, stdout) = (std::io::stdin(), std::io::stdout());
+    let env = Environment {
+        stdin_terminal: stdin.is_terminal(),
+        stdin_size: stdin_file_size(),
+        stdin: Some(Box::new(stdin.lock())),
+        stdout_terminal: stdout.is_terminal(),
+        stderr_terminal: std::io::stderr().is_terminal(),
+        width: term_size::dimensions().map(|(width, _)| width),
+        lang: std::env::var("INTSPECTOR_LANG").ok(),
+        no_color: std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()),
+        signals: true,
+        plugins: true,
+        now: datetime::now(),
+        seed: random::SplitMix64::from_entropy().next_u64(),
+    };
+    let mut out = stdout.lock();
+    let mut err = std::io::stderr();
+    let result = run_with(args, &mut out, Some(&mut err), env, interrupt::flag());
+    let _ = out.flush();
+    match result {
+        Ok(()) => 0,
+        // The output was cut short on purpose, e.g. by `head`.
+        Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::BrokenPipe => 0,
+        // A command stopped by Ctrl-C has printed its partial results, and a failed plugin its
+        // own messages.
+        Err(err @ Error::Interrupted) | Err(err @ Error::Plugin(_)) => err.exit_status(),
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            err.exit_status()
+        },
+    }
+}
+
+
+fn run_with<'a>(
+    args: Vec<String>,
+    out: &'a mut dyn Write,
+    err: Option<&'a mut dyn Write>,
+    env: Environment<'a>,
+    interrupted: &'a AtomicBool,
+) -> Result<(), Error> {
+    let plugins = env.plugins;
+    let is_command = |name: &str| {
+        is_builtin_command(name) || (plugins && find_plugin(name).is_some())
+    };
+    let (options, args) = options::parse(args, is_command).map_err(Error::Invalid)?;
+    let (locale, warning) = locale::select(options.lang.as_deref(), env.lang.as_deref())
+        .map_err(Error::Invalid)?;
+    let style = select_style(&options, &env, locale).map_err(Error::Invalid)?;
+    let mut ctx = Context { out, err, style, env, csv_header: None, interrupted };
+    if let Some(msg) = warning {
+        ctx.warn(&format!("Warning: {}", msg))?;
+    }
+    run_command(&mut ctx, args)?;
+    match ctx.interrupted() {
+        true => Err(Error::Interrupted),
+        false => Ok(()),
+    }
+}
+
+
+// Returns the style for the global options. Color is on in auto mode only on a terminal.
+fn select_style(
+    options: &options::GlobalOptions,
+    env: &Environment,
+    locale: &'static Locale,
+) -> Result<Style, String> {
+    let auto = env.stdout_terminal && !env.no_color;
+    Ok(Style {
+        format: report::format(options.format.as_deref())?,
+        theme: output::theme(options.color.as_deref(), options.theme.as_deref(), auto)?,
+        locale,
+        command: "intspector",
+        width: env.width.filter(|_| env.stdout_terminal),
+    })
+}
+
+
+// Runs a command, which is a plugin, the help command, a subcommand, or the default command.
+fn run_command(ctx: &mut Context, args: Vec<String>) -> Result<(), Error> {
+    if ctx.env.plugins {
+        if let Some((name, path)) = args.first().and_then(|n| find_plugin(n).map(|p| (n, p))) {
+            // A plugin's labels have no translations of their own.
+            ctx.style.command = "";
+            return run_plugin(ctx, name, &path, &args[1..]);
+        }
+    }
+    if args.first().map(String::as_str) == Some("help") {
+        let decoder = match args.get(1) {
+            Some(name) => commands::find(name).ok_or_else(|| {
+                Error::Invalid(format!("'{}' is not a recognised command name.", name))
+            })?,
+            None => return Err(Error::Invalid("missing argument for the help command.".into())),
+        };
+        return print_help(ctx, &decoder.helptext());
+    }
+    let mut parser = DefaultCommand.parser();
+    for decoder in commands::DECODERS.iter().chain(commands::DEVELOPER_DECODERS) {
+        parser = parser.command(decoder.names(), decoder.parser());
+    }
+    if let Err(err) = parser.parse_args(args.iter().map(String::as_str).collect()) {
+        // The parser's messages start with `Error: `, which the binary adds itself.
+        let msg = err.to_string();
+        return Err(Error::Invalid(format!("{}.", msg.trim_start_matches("Error: "))));
+    }
+    match parser.cmd_name.as_deref().and_then(commands::find) {
+        Some(decoder) => {
+            let cmd_parser = parser.cmd_parser.as_ref().unwrap();
+            ctx.style.command = decoder.name();
+            match cmd_parser.found("help") {
+                true => print_help(ctx, &decoder.helptext()),
+                false => decoder.run(ctx, cmd_parser),
+            }
+        },
+        None if parser.found("help") => print_help(ctx, &helptext()),
+        None if parser.found("version") => {
+            writeln!(ctx.out, "{}", env!("CARGO_PKG_VERSION"))?;
+            Ok(())
+        },
+        None => DefaultCommand.run(ctx, &parser),
+    }
+}
+
+
+fn print_help(ctx: &mut Context, helptext: &str) -> Result<(), Error> {
+    writeln!(ctx.out, "{}", ctx.style.locale.help(helptext).trim())?;
+    Ok(())
+}
+
+
+fn is_builtin_command(name: &str) -> bool {
+    name == "help" || commands::find(name).is_some()
+}
+
+
+// Returns the path of the plugin executable for an unknown command, `intspector-<name>` on the
+// PATH. Command names start with a letter and contain only lowercase letters, digits, and
+// hyphens, so integer arguments to the default command are never mistaken for them unless a
+// plugin with that name exists.
+fn find_plugin(name: &str) -> Option<std::path::PathBuf> {
+    let known = is_builtin_command(name);
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if known || !valid {
+        return None;
+    }
+    let filename = format!("intspector-{}{}", name, std::env::consts::EXE_SUFFIX);
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|dir| dir.join(&filename))
+        .find(|path| is_executable(path))
+}
+
+
+#[cfg(unix)]
+fn is_executable(path: &std::path::Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata().is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+
+#[cfg(not(unix))]
+fn is_executable(path: &std::path::Path) -> bool {
+    path.is_file()
+}
+
+
+// Runs a plugin, writing the request to its stdin as JSON and printing the info blocks from its
+// JSON response. Fails if the plugin fails or its response can't be read.
+fn run_plugin(
+    ctx: &mut Context,
+    name: &str,
+    path: &std::path::Path,
+    args: &[String],
+) -> Result<(), Error> {
+    let fail = |msg: String| Error::Invalid(format!("plugin '{}' {}.", name, msg));
+    let request = json::Value::Object(vec![
+        ("command".to_string(), json::Value::String(name.to_string())),
+        (
+            "args".to_string(),
+            json::Value::Array(args.iter().map(|arg| json::Value::String(arg.clone())).collect()),
+        ),
+        ("version".to_string(), json::Value::String(env!("CARGO_PKG_VERSION").to_string())),
+    ]);
+    let mut child = std::process::Command::new(path)
+        .args(args)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|err| fail(format!("cannot run: {}", err)))?;
+    // A plugin that doesn't read its stdin may close it early, which isn't an error.
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = writeln!(stdin, "{}", request);
+    }
+    let output = child.wait_with_output().map_err(|err| fail(format!("cannot run: {}", err)))?;
+    let response = String::from_utf8(output.stdout).ok().and_then(|text| json::parse(text.trim()));
+    let response = match response {
+        Some(response) => response,
+        None if !output.status.success() => {
+            return Err(fail(format!("failed with {}", output.status)))
+        },
+        None => return Err(fail("returned invalid JSON".to_string())),
+    };
+    if let Some(msg) = response.get("error").and_then(json::Value::as_str) {
+        return Err(Error::Invalid(msg.to_string()));
+    }
+    let blocks = match response.get("blocks") {
+        Some(json::Value::Array(blocks)) => blocks,
+        _ => return Err(fail("returned no \"blocks\" array".to_string())),
+    };
+    if !blocks.is_empty() {
+        ctx.termline()?;
+    }
+    for block in blocks {
+        plugin_block(block).print(ctx)?;
+        ctx.termline()?;
+    }
+    match output.status.success() {
+        true => Ok(()),
+        false => Err(Error::Plugin(output.status.code().unwrap_or(1))),
+    }
+}
+
+
+// A plugin block is either a string, shown line by line, or an object, shown as a field for each
+// member. Members that aren't strings are shown as JSON.
+fn plugin_block(block: &json::Value) -> Report {
+    match block {
+        json::Value::String(text) => text.lines().fold(Report::new(), Report::line),
+        json::Value::Object(members) => {
+            let mut report = Report::new();
+            for (label, value) in members {
+                report = match value.as_str() {
+                    Some(value) => report.field(label, value),
+                    None => report.typed(label, value, value.clone()),
+                };
+            }
+            report
+        },
+        _ => Report::error("the plugin returned a block that isn't a string or an object."),
+    }
+}
+
+
+fn default_action(ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+    let bits_arg = parse_u32_option(parser, "bits")?;
+    let group = parse_u32_option(parser, "group")?;
+    let spacer = match parser.value("spacer") {
+        Some(arg) if arg.chars().count() == 1 => arg.chars().next(),
+        Some(arg) => {
+            return Err(Error::Invalid(format!("the spacer '{}' must be a single character.", arg)));
+        },
+        None => None,
+    };
+    let grouping = match (group, spacer) {
+        (None, None) => None,
+        (group, spacer) => Some((group.unwrap_or(4), spacer.unwrap_or('_'))),
+    };
+    let base = parse_u32_option(parser, "base")?;
+    if base.is_some_and(|base| !(2..=36).contains(&base)) {
+        return Err(Error::Invalid("the base must be an integer from 2 to 36.".to_string()));
+    }
+    let opts = InfoOpts {
+        bits: bits_arg,
+        grouping,
+        base,
+        braille: parser.found("braille"),
+        blocks: parser.found("blocks"),
+        verbose: parser.found("verbose"),
+        hints: !parser.found("no-hints"),
+    };
+    if parser.found("interactive") {
+        return run_repl(ctx, opts);
+    }
+    let histogram = parser.found("histogram") || parser.value("bins").is_some();
+    if histogram || parser.found("stats") {
+        let bins = parse_u32_option(parser, "bins")?;
+        if bins == Some(0) {
+            let msg = "the number of bins must be a positive integer.";
+            return Err(Error::Invalid(msg.to_string()));
+        }
+        let mut values = Vec::new();
+        let mut errors = 0;
+        for_each_input(ctx, parser, true, |ctx, arg| {
+            match eval_arg(arg) {
+                Ok(value) => values.push(value),
+                Err(msg) => {
+                    ctx.warn(&format!("Error: {}", msg))?;
+                    errors += 1;
+                }
+            }
+            Ok(())
+        })?;
+        if values.is_empty() {
+            return Ok(());
+        }
+        ctx.termline()?;
+        if parser.found("stats") {
+            stats_info(&values, errors, bits_arg).print(ctx)?;
+            ctx.termline()?;
+        }
+        if histogram {
+            histogram_info(&mut values, bins).print(ctx)?;
+            ctx.termline()?;
+        }
+        return Ok(());
+    }
+    let order = match parser.value("sort").as_deref() {
+        Some("asc") | Some("signed") => Some(SortOrder::Ascending),
+        Some("desc") => Some(SortOrder::Descending),
+        Some("unsigned") => Some(SortOrder::Unsigned),
+        Some(arg) => {
+            let msg = format!("expected asc, desc, signed, or unsigned, found '{}'.", arg);
+            return Err(Error::Invalid(msg));
+        },
+        None => None,
+    };
+    let fold = match parser.value("fold").as_deref() {
+        Some(op) if ["and", "or", "xor"].contains(&op) => Some(op.to_string()),
+        Some(arg) => {
+            let msg = format!("expected and, or, or xor for --fold, found '{}'.", arg);
+            return Err(Error::Invalid(msg));
+        },
+        None => None,
+    };
+    let dedup = parser.found("dedup");
+    let prefix = parser.found("prefix");
+    let stride = parser.found("stride");
+    let mut printed = false;
+    let batch = fold.is_some() || prefix || stride;
+    if order.is_none() && !dedup && !parser.found("delta") && !batch {
+        return for_each_input(ctx, parser, false, |ctx, arg| {
+            print_int_arg(ctx, arg, &opts, &mut printed)
+        });
+    }
+    let mut args = Vec::new();
+    for_each_input(ctx, parser, true, |_, arg| {
+        args.push(arg.to_string());
+        Ok(())
+    })?;
+    let args = order_args(args, order, dedup);
+    if parser.found("delta") {
+        if !args.is_empty() {
+            ctx.termline()?;
+            delta_info(&args).print(ctx)?;
+            ctx.termline()?;
+        }
+        return Ok(());
+    }
+    for arg in &args {
+        print_int_arg(ctx, arg, &opts, &mut printed)?;
+    }
+    if args.is_empty() {
+        return Ok(());
+    }
+    if let Some(op) = fold {
+        print_result(ctx, &format!("--fold {}", op), fold_info(&args, &op, &opts))?;
+    }
+    if prefix {
+        print_result(ctx, "--prefix", prefix_info(&args, &opts))?;
+    }
+    if stride {
+        print_result(ctx, "--stride", stride_info(&args, &opts))?;
+    }
+    Ok(())
+}
+
+
+// Returns the bit patterns of all the inputs at the width of the widest value, with the width
+// and the number of inputs skipped. Inputs that can't be parsed are skipped, as their errors are
+// shown in their own blocks.
+fn batch_patterns(args: &[String], opts: &InfoOpts) -> Result<(Vec<u128>, u32, usize), String> {
+    // Values above the signed range are held as unsigned patterns, as in print_int_arg().
+    let mut values: Vec<Result<i128, u128>> = Vec::new();
+    for arg in args {
+        match parse_u128(arg).ok().filter(|value| *value > i128::MAX as u128) {
+            Some(value) => values.push(Err(value)),
+            None => values.extend(eval_arg(arg).ok().map(Ok)),
+        }
+    }
+    if values.is_empty() {
+        return Err("no valid inputs.".to_string());
+    }
+    let default_bits = |value: &Result<i128, u128>| match value {
+        Ok(value) if *value < 0 => std_bits(*value),
+        Ok(value) => min_bits(*value),
+        Err(_) => 128,
+    };
+    let num_bits = opts.bits.unwrap_or_else(|| values.iter().map(default_bits).max().unwrap());
+    let mut patterns = Vec::new();
+    for value in &values {
+        patterns.push(match value {
+            Ok(value) => display_bits(*value, Some(num_bits))?.0,
+            Err(value) => check_u128_bits(*value, Some(num_bits)).map(|_| *value)?,
+        });
+    }
+    Ok((patterns, num_bits, args.len() - values.len()))
+}
+
+
+// Combines the bit patterns of all the inputs with a bitwise operator.
+fn fold_info(args: &[String], op: &str, opts: &InfoOpts) -> Result<Report, String> {
+    let (values, num_bits, skipped) = batch_patterns(args, opts)?;
+    let result = match op {
+        "and" => values.iter().fold(u128::MAX, |acc, value| acc & value),
+        "or" => values.iter().fold(0, |acc, value| acc | value),
+        _ => values.iter().fold(0, |acc, value| acc ^ value),
+    };
+    let mut aggregate = format!(
+        "{} of {} value{}",
+        op,
+        values.len(),
+        if values.len() == 1 { "" } else { "s" }
+    );
+    if skipped > 0 {
+        aggregate += &format!(", {} skipped", skipped);
+    }
+    let report = Report::new().field("agg", aggregate).append(uint_info(result, num_bits, opts));
+
+    // List the set bits from the top down, with runs written as `high..low`.
+    let mut spans = Vec::new();
+    let mut top = num_bits;
+    for (bit, length) in bit_runs(result, num_bits) {
+        match (bit, length) {
+            (1, 1) => spans.push(format!("{}", top - 1)),
+            (1, _) => spans.push(format!("{}..{}", top - 1, top - length)),
+            _ => {},
+        }
+        top -= length;
+    }
+    let set = match spans.as_slice() {
+        [] => "none".to_string(),
+        [span] if !span.contains("..") => format!("bit {}", span),
+        _ => format!("bits {}", spans.join(", ")),
+    };
+    Ok(report.field("set", set))
+}
+
+
+// Shows the longest binary prefix shared by all the inputs, and the block of values with that
+// prefix, which is the smallest aligned block covering them all.
+fn prefix_info(args: &[String], opts: &InfoOpts) -> Result<Report, String> {
+    let (values, num_bits, skipped) = batch_patterns(args, opts)?;
+    let length = common_prefix_len(&values, num_bits);
+    let host_bits = num_bits - length;
+    let mask = match length {
+        0 => 0,
+        _ => field_mask(num_bits - 1, host_bits),
+    };
+    let base = values[0] & mask;
+    let last = base | (field_mask(num_bits - 1, 0) & !mask);
+    let mut prefix = format!(
+        "{} of {} bits in common across {} value{}",
+        length,
+        num_bits,
+        values.len(),
+        if values.len() == 1 { "" } else { "s" }
+    );
+    if skipped > 0 {
+        prefix += &format!(", {} skipped", skipped);
+    }
+    let count = match host_bits {
+        0 => "1 value".to_string(),
+        128 => "2^128 values".to_string(),
+        _ => format!("{} values", 1u128 << host_bits),
+    };
+    Ok(Report::new()
+        .field("pfx", prefix)
+        .append(uint_info(base, num_bits, opts))
+        .field("msk", format!("{} (/{})", add_spacers(&format!("{:X}", mask), ' ', 2), length))
+        .field("rng", format!("0x{:X} to 0x{:X} ({})", base, last, count)))
+}
+
+
+// Shows the alignment shared by all the inputs and the stride between them, with the number of
+// slots of that stride spanning the inputs.
+fn stride_info(args: &[String], opts: &InfoOpts) -> Result<Report, String> {
+    let (values, _, skipped) = batch_patterns(args, opts)?;
+    let plural = |count: usize| if count == 1 { "" } else { "s" };
+    let mut alignment = match common_alignment(&values) {
+        Some(align) => format!(
+            "{} (2^{}) shared by {} value{}",
+            align,
+            align.trailing_zeros(),
+            values.len(),
+            plural(values.len())
+        ),
+        None => "none, all the values are zero".to_string(),
+    };
+    if skipped > 0 {
+        alignment += &format!(", {} skipped", skipped);
+    }
+    let report = Report::new().field("aln", alignment);
+    let stride = common_stride(&values);
+    if stride == 0 {
+        return Ok(report.field("str", "none, all the values are equal"));
+    }
+    let mut summary = format!("{} (0x{:X}), the GCD of the differences", stride, stride);
+    if stride.is_power_of_two() {
+        summary += &format!(" (2^{})", stride.trailing_zeros());
+    }
+    let min = values.iter().min().unwrap();
+    let max = values.iter().max().unwrap();
+    let mut distinct = values.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+    let slots = match ((max - min) / stride).checked_add(1) {
+        Some(1) => "1 slot".to_string(),
+        Some(slots) => format!("{} slots", slots),
+        None => "2^128 slots".to_string(),
+    };
+    let slots = format!("{} from 0x{:X} to 0x{:X}, {} filled", slots, min, max, distinct.len());
+    Ok(report.field("str", summary).field("slt", slots))
+}
+
+
+// Shows each value's difference from the previous value in all four bases, then whether the
+// values are evenly spaced.
+fn delta_info(args: &[String]) -> Report {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut deltas: Vec<i128> = Vec::new();
+    let blank = String::new;
+    let mut prev: Option<i128> = None;
+    for arg in args {
+        let value = match eval_arg(arg) {
+            Ok(value) => value,
+            Err(msg) => {
+                // The short row lets the message run on past the delta columns.
+                rows.push(vec![arg.clone(), msg]);
+                prev = None;
+                continue;
+            }
+        };
+        let delta = prev.map(|prev| value.checked_sub(prev));
+        prev = Some(value);
+        let row = match delta {
+            None => vec![value.to_string(), blank(), blank(), blank(), blank()],
+            Some(None) => vec![value.to_string(), "overflow".into(), blank(), blank(), blank()],
+            Some(Some(delta)) => {
+                deltas.push(delta);
+                let sign = if delta < 0 { "-" } else { "+" };
+                let magnitude = delta.unsigned_abs();
+                vec![
+                    value.to_string(),
+                    format!("{}{}", sign, magnitude),
+                    format!("{}0x{:X}", sign, magnitude),
+                    format!("{}0o{:o}", sign, magnitude),
+                    format!("{}0b{:b}", sign, magnitude),
+                ]
+            },
+        };
+        rows.push(row);
+    }
+
+    let summary = match deltas.split_first() {
+        None => "no deltas".to_string(),
+        Some((first, rest)) if rest.iter().all(|delta| delta == first) => {
+            let sign = if *first < 0 { "-" } else { "" };
+            format!("constant stride {} ({}0x{:X})", first, sign, first.unsigned_abs())
+        },
+        Some(_) => {
+            let min = deltas.iter().min().unwrap();
+            let max = deltas.iter().max().unwrap();
+            let gcd = deltas
+                .iter()
+                .fold(0, |acc, delta| ratio::gcd_u128(acc, delta.unsigned_abs()));
+            let mut summary = format!("uneven, deltas from {} to {}, gcd {}", min, max, gcd);
+            if gcd > 1 && gcd.is_power_of_two() {
+                summary += &format!(" (2^{})", gcd.trailing_zeros());
+            }
+            summary
+        },
+    };
+    Report::new()
+        .aligned_table(&["value", "dec", "hex", "oct", "bin"], &[Align::Right; 5], rows)
+        .field("str", summary)
+}
+
+
+#[derive(Clone, Copy, PartialEq)]
+enum SortOrder {
+    // By signed value, smallest first.
+    Ascending,
+    // By signed value, largest first.
+    Descending,
+    // By 128-bit unsigned value, so negative values follow the positive ones in two's complement
+    // order.
+    Unsigned,
+}
+
+
+// Sorts integer arguments by value and drops arguments with the value of an earlier argument.
+// Arguments that can't be evaluated are kept, after the others if sorting, in their original
+// order.
+fn order_args(args: Vec<String>, order: Option<SortOrder>, dedup: bool) -> Vec<String> {
+    // Each value is its 128-bit pattern, flagged if it's above the signed range.
+    let mut values: Vec<(Option<(bool, u128)>, String)> = Vec::new();
+    for arg in args {
+        let value = match parse_u128(&arg).ok().filter(|value| *value > i128::MAX as u128) {
+            Some(value) => Some((true, value)),
+            None => eval_arg(&arg).ok().map(|value| (false, value as u128)),
+        };
+        if dedup && value.is_some() && values.iter().any(|(seen, _)| *seen == value) {
+            continue;
+        }
+        values.push((value, arg));
+    }
+    // Flipping the sign bit of a signed value orders it as an unsigned one. Sorting on
+    // `None`-ness first puts the errors last.
+    let signed_key = |(above, bits): (bool, u128)| {
+        (above, if above { bits } else { bits ^ 1 << 127 })
+    };
+    match order {
+        Some(SortOrder::Ascending) => {
+            values.sort_by_key(|(value, _)| (value.is_none(), value.map(signed_key)))
+        },
+        Some(SortOrder::Descending) => values.sort_by_key(|(value, _)| {
+            (value.is_none(), std::cmp::Reverse(value.map(signed_key)))
+        }),
+        Some(SortOrder::Unsigned) => {
+            values.sort_by_key(|(value, _)| (value.is_none(), value.map(|(_, bits)| bits)))
+        },
+        None => {},
+    }
+    values.into_iter().map(|(_, arg)| arg).collect()
+}
+
+
+// Calls `f` for each integer argument, then for each whitespace-separated integer on stdin if
+// reading from stdin. Stdin is streamed in chunks, so inputs of any size can be read. A progress
+// line is shown on a terminal's stderr if the output goes elsewhere or is `deferred` until the
+// input has been read. A Ctrl-C stops reading, leaving the caller to finish with the inputs read
+// so far.
+fn for_each_input(
+    ctx: &mut Context,
+    parser: &ArgParser,
+    deferred: bool,
+    mut f: impl FnMut(&mut Context, &str) -> Result<(), Error>,
+) -> Result<(), Error> {
+    for arg in &parser.args {
+        f(ctx, arg)?;
+    }
+    let piped = parser.args.is_empty() && !ctx.env.stdin_terminal;
+    if !parser.found("stdin") && !piped {
+        return Ok(());
+    }
+    let stdin = match ctx.env.stdin.take() {
+        Some(stdin) => stdin,
+        None => return Ok(()),
+    };
+    let progress = ctx.progress(deferred);
+    let reader = stream::Progress::new(stdin, ctx.env.stdin_size, progress);
+    let _guard = ctx.catch();
+    let interrupted = ctx.interrupt_flag();
+    let mut count = 0;
+    let result = stream::for_each_token(reader, interrupted, |arg| {
+        count += 1;
+        f(ctx, arg)
+    });
+    match result {
+        Ok(()) => {},
+        Err(stream::Error::Read(err)) => {
+            return Err(Error::Invalid(format!("cannot read from stdin: {}", err)));
+        },
+        Err(stream::Error::Stopped(err)) => return Err(err),
+    }
+    if ctx.interrupted() {
+        ctx.warn(&format!("Interrupted: stopped reading stdin after {} inputs.", count))?;
+    }
+    Ok(())
+}
+
+
+// Returns the size of the file redirected to stdin, if stdin is a regular file.
+pub fn stdin_file_size() -> Option<u64> {
+    let metadata = std::fs::metadata("/dev/stdin").ok()?;
+    Some(metadata.len()).filter(|_| metadata.is_file())
+}
+
+
+// Reports the count, distinct values, duplicates, and range of a batch of values, and any values
+// too wide for the expected number of bits.
+fn stats_info(values: &[i128], errors: usize, bits: Option<u32>) -> Report {
+    let mut counts: HashMap<i128, usize> = HashMap::new();
+    for value in values {
+        *counts.entry(*value).or_insert(0) += 1;
+    }
+    let mut duplicates: Vec<(i128, usize)> = counts
+        .iter()
+        .filter(|(_, count)| **count > 1)
+        .map(|(value, count)| (*value, *count))
+        .collect();
+    duplicates.sort_by_key(|(value, count)| (std::cmp::Reverse(*count), *value));
+
+    let mut count = format!("{} values", values.len());
+    if errors > 0 {
+        count += &format!(", {} unparseable", errors);
+    }
+    let listed: Vec<String> = duplicates
+        .iter()
+        .take(10)
+        .map(|(value, count)| format!("{}×{}", value, count))
+        .collect();
+    let repeated = match duplicates.len() {
+        0 => "none".to_string(),
+        n if n > 10 => format!("{} values repeated, {} ...", n, listed.join(" ")),
+        1 => format!("1 value repeated, {}", listed.join(" ")),
+        n => format!("{} values repeated, {}", n, listed.join(" ")),
+    };
+
+    let widest = values.iter().map(|value| min_bits(*value)).max().unwrap();
+    let width = match bits {
+        Some(bits) => {
+            let outliers: Vec<String> = values
+                .iter()
+                .filter(|value| min_bits(**value) > bits)
+                .map(|value| value.to_string())
+                .collect();
+            match outliers.len() {
+                0 => format!("all fit in {} bits", bits),
+                n => {
+                    let shown = outliers[..n.min(10)].join(" ");
+                    let more = if n > 10 { " ..." } else { "" };
+                    format!("{} too wide for {} bits, {}{}", n, bits, shown, more)
+                },
+            }
+        },
+        None => format!("all fit in {} bits", widest),
+    };
+    Report::new()
+        .field("cnt", count)
+        .field("dst", format!("{} distinct", counts.len()))
+        .field("dup", repeated)
+        .field("min", values.iter().min().unwrap())
+        .field("max", values.iter().max().unwrap())
+        .field("wid", width)
+}
+
+
+// Summarizes a batch of values with their range, mean, and median, and a histogram. Values are
+// binned by bit length, with negative values binned by magnitude, unless a number of equal-width
+// bins is specified. Sorts the values.
+fn histogram_info(values: &mut [i128], bins: Option<u32>) -> Report {
+    values.sort_unstable();
+    let (min, max) = (values[0], values[values.len() - 1]);
+    let count = values.len();
+    let mean = values.iter().map(|value| *value as f64).sum::<f64>() / count as f64;
+    let median = if count % 2 == 1 {
+        values[count / 2] as f64
+    } else {
+        (values[count / 2 - 1] as f64 + values[count / 2] as f64) / 2.0
+    };
+    let report = Report::new()
+        .field("cnt", format!("{} values", count))
+        .field("min", min)
+        .field("max", max)
+        .field("avg", mean)
+        .field("med", median);
+
+    // Each bin is an inclusive range with a count.
+    let mut ranges: Vec<(i128, i128, usize)> = Vec::new();
+    match bins {
+        Some(bins) => {
+            let span = max.abs_diff(min);
+            let width = span / bins as u128 + 1;
+            let mut lo = min;
+            while ranges.len() < bins as usize {
+                let hi = lo.checked_add_unsigned(width - 1).map_or(max, |hi| hi.min(max));
+                ranges.push((lo, hi, 0));
+                match hi.checked_add(1) {
+                    Some(next) if hi < max => lo = next,
+                    _ => break,
+                }
+            }
+            for value in values.iter() {
+                let index = (value.abs_diff(min) / width) as usize;
+                ranges[index].2 += 1;
+            }
+        },
+        None => {
+            // Bin keys run from -128 to 128: the negated bit length of the magnitude for
+            // negative values, 0 for zero, and the bit length for positive values.
+            let key = |value: i128| -> i32 {
+                let bits = 128 - value.unsigned_abs().leading_zeros() as i32;
+                if value < 0 { -bits } else { bits }
+            };
+            let magnitude = |bits: i32| -> (u128, u128) {
+                match bits {
+                    0 => (0, 0),
+                    _ => (1 << (bits - 1), u128::MAX >> (128 - bits)),
+                }
+            };
+            for k in key(min)..=key(max) {
+                let (lo, hi) = magnitude(k.abs());
+                let range = if k < 0 {
+                    (0i128.saturating_sub_unsigned(hi), 0i128.saturating_sub_unsigned(lo))
+                } else {
+                    (lo as i128, hi.min(i128::MAX as u128) as i128)
+                };
+                ranges.push((range.0, range.1, 0));
+            }
+            for value in values.iter() {
+                ranges[(key(*value) - key(min)) as usize].2 += 1;
+            }
+        },
+    }
+
+    let largest = ranges.iter().map(|(_, _, n)| *n).max().unwrap();
+    let rows = ranges
+        .iter()
+        .map(|(lo, hi, n)| {
+            let range = if lo == hi { lo.to_string() } else { format!("{}..{}", lo, hi) };
+            let bar = "█".repeat((*n as f64 * 40.0 / largest as f64).round() as usize);
+            vec![range, n.to_string(), bar]
+        })
+        .collect();
+    report.aligned_table(&["range", "count", "bar"], &[Align::Right, Align::Right], rows)
+}
+
+
+// Prints the info block for a single integer argument. The `printed` flag tracks whether the
+// opening termline has been printed yet.
+fn print_int_arg(
+    ctx: &mut Context,
+    arg: &str,
+    opts: &InfoOpts,
+    printed: &mut bool,
+) -> Result<(), Error> {
+    // Values above the signed range can only be written as literals.
+    let unsigned = parse_u128(arg).ok().filter(|value| *value > i128::MAX as u128);
+    if !*printed {
+        ctx.termline()?;
+        *printed = true;
+    }
+    let report = match (unsigned, eval_input(arg)) {
+        (Some(value), _) => u128_info(value, opts),
+        (None, Ok(value)) => int_info(value, opts),
+        (None, Err(report)) => report,
+    };
+    report.input(arg).print(ctx)?;
+    ctx.termline()
+}
+
+
+// Parses a decimal 32-bit unsigned integer, e.g. an option's width in bits.
+pub fn parse_u32(arg: &str) -> Result<u32, String> {
+    let msg = || format!("cannot parse '{}' as a 32-bit unsigned integer.", arg);
+    arg.parse::<u32>().map_err(|_| msg())
+}
+
+
+// Parses the value of the named option as a decimal 32-bit unsigned integer, if it was given.
+pub fn parse_u32_option(parser: &ArgParser, name: &str) -> Result<Option<u32>, Error> {
+    match parser.value(name) {
+        Some(arg) => parse_u32(&arg).map(Some).map_err(Error::Invalid),
+        None => Ok(None),
+    }
+}
+
+
+// Parses an integer literal or evaluates an expression.
+pub fn eval_arg(arg: &str) -> Result<i128, String> {
+    eval_arg_with(arg, &HashMap::new())
+}
+
+
+// As eval_arg(), but returns an error report with the code and position of the error.
+pub fn eval_input(arg: &str) -> Result<i128, Report> {
+    try_eval_arg(arg, &HashMap::new())
+        .map_err(|(msg, err)| Report::error_at(&msg, err.code(), err.position(arg)))
+}
+
+
+// Parses an integer literal or evaluates an expression, looking up names in `vars` first.
+fn eval_arg_with(arg: &str, vars: &HashMap<String, i128>) -> Result<i128, String> {
+    try_eval_arg(arg, vars).map_err(|(msg, _)| msg)
+}
+
+
+// As eval_arg_with(), but returns the underlying error along with the message, for its code.
+pub fn try_eval_arg(
+    arg: &str,
+    vars: &HashMap<String, i128>,
+) -> Result<i128, (String, IntspectorError)> {
+    if let Some(value) = vars.get(arg) {
+        return Ok(*value);
+    }
+    let err = match parse_int(arg) {
+        Ok(value) => return Ok(value),
+        // Report overflow against the unsigned range if the value is too large even for that.
+        Err(err @ IntspectorError::Overflow { .. }) => parse_u128(arg).err().unwrap_or(err),
+        Err(err) => err,
+    };
+    if !arg.contains(|c: char| "+-*/%&^|~()<> ".contains(c)) {
+        return Err((format!("cannot parse '{}': {}.", arg, err), err));
+    }
+    eval_expr_with(arg, vars).map_err(|err| (format!("cannot evaluate '{}': {}.", arg, err), err))
+}
+
+
+// Reads lines from stdin until end of input or a quit directive, printing the info block for
+// each integer or expression. History and variables last for the session.
+pub fn run_repl(ctx: &mut Context, mut opts: InfoOpts) -> Result<(), Error> {
+    let mut vars: HashMap<String, i128> = HashMap::new();
+    let mut history: Vec<String> = Vec::new();
+    let mut lines = match ctx.env.stdin.take() {
+        Some(stdin) => stdin.lines(),
+        None => return Ok(()),
+    };
+    // The prompt and echoed history entries would break up structured output.
+    let text = ctx.style.format == report::Format::Text;
+    loop {
+        if text {
+            write!(ctx.out, "> ")?;
+            ctx.out.flush()?;
+        }
+        let mut line = match lines.next() {
+            Some(Ok(line)) => line.trim().to_string(),
+            Some(Err(err)) => {
+                return Err(Error::Invalid(format!("cannot read from stdin: {}", err)));
+            },
+            None => {
+                if text {
+                    writeln!(ctx.out)?;
+                }
+                break;
+            }
+        };
+        if let Some(index) = line.strip_prefix('!') {
+            let entry = match index {
+                "!" => history.last(),
+                _ => index.parse::<usize>().ok().and_then(|n| history.get(n.wrapping_sub(1))),
+            };
+            match entry {
+                Some(entry) => line = entry.clone(),
+                None => {
+                    Report::error(&format!("no history entry '{}'.", line)).print(ctx)?;
+                    continue;
+                }
+            }
+            if text {
+                writeln!(ctx.out, "{}", line)?;
+            }
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [] => continue,
+            ["quit"] | ["exit"] => break,
+            ["help"] => write!(ctx.out, "{}", REPL_DIRECTIVES)?,
+            ["history"] => {
+                let rows = history
+                    .iter()
+                    .enumerate()
+                    .map(|(i, entry)| vec![(i + 1).to_string(), entry.clone()])
+                    .collect();
+                Report::new().aligned_table(&["line", "entry"], &[Align::Right], rows).print(ctx)?;
+            },
+            ["vars"] => {
+                let mut names: Vec<&String> = vars.keys().collect();
+                names.sort();
+                let rows = names.iter().map(|name| vec![name.to_string(), vars[*name].to_string()]);
+                Report::new().table(&["variable", "value"], rows.collect()).print(ctx)?;
+            },
+            ["bits"] => opts.bits = None,
+            // The width is checked here rather than when the next value is shown.
+            ["bits", arg] => match parse_u32(arg) {
+                Ok(value @ 1..=128) => opts.bits = Some(value),
+                Ok(_) => Report::error("the width must be from 1 to 128 bits.").print(ctx)?,
+                Err(msg) => Report::error(&msg).print(ctx)?,
+            },
+            _ => match repl_eval(&line, &mut vars) {
+                Ok(Ok(value)) => print_repl_block(ctx, &int_info(value, &opts))?,
+                Ok(Err(value)) => print_repl_block(ctx, &u128_info(value, &opts))?,
+                Err(msg) => Report::error(&msg).print(ctx)?,
+            },
+        }
+        if !line.starts_with('!') {
+            history.push(line);
+        }
+    }
+    Ok(())
+}
+
+
+const REPL_DIRECTIVES: &str = "\
+bits <n>     Set the number of binary digits to display.
+bits         Go back to the default number of binary digits.
+history      List the lines entered so far.
+!<n>         Re-run line n from the history.
+!!           Re-run the previous line.
+vars         List the variables.
+help         List the directives.
+quit, exit   Leave the prompt.
+";
+
+
+// Evaluates a REPL line, an expression or an assignment `name = expression`, and stores the
+// result in `_` and the assigned variable. Values above the signed range are returned as the
+// inner `Err` variant and aren't stored.
+fn repl_eval(line: &str, vars: &mut HashMap<String, i128>) -> Result<Result<i128, u128>, String> {
+    let (name, expr) = match line.split_once('=') {
+        Some((name, expr)) => (Some(name.trim()), expr.trim()),
+        None => (None, line),
+    };
+    if let Some(name) = name {
+        let valid = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && parse_int(name).is_err();
+        if !valid {
+            return Err(format!("'{}' is not a valid variable name.", name));
+        }
+    }
+    if let Some(value) = parse_u128(expr).ok().filter(|value| *value > i128::MAX as u128) {
+        return Ok(Err(value));
+    }
+    let value = eval_arg_with(expr, vars)?;
+    vars.insert("_".to_string(), value);
+    if let Some(name) = name {
+        vars.insert(name.to_string(), value);
+    }
+    Ok(Ok(value))
+}
+
+
+fn print_repl_block(ctx: &mut Context, report: &Report) -> Result<(), Error> {
+    ctx.termline()?;
+    report.print(ctx)?;
+    ctx.termline()
+}
+
+
+// Returns a line of carets under the bits at the specified positions, lined up with the output
+// of bin_string() for the same width.
+pub fn bit_marks(positions: &[u32], num_bits: u32) -> String {
+    let marked = set_bits(0, positions);
+    let digits = bin_string(marked, num_bits);
+    let marks: String = digits
+        .chars()
+        .map(|c| match c {
+            '1' => '^',
+            _ => ' ',
+        })
+        .collect();
+    marks.trim_end().to_string()
+}
+
+
+// Returns the big-endian bytes of an integer at a width in bits, a multiple of 8, in two's
+// complement if it's negative.
+pub fn be_bytes(value: i128, bits: u32) -> Result<Vec<u8>, String> {
+    let (disp_value, _) = display_bits(value, Some(bits))?;
+    Ok(disp_value.to_be_bytes()[16 - bits as usize / 8..].to_vec())
+}
+
+
+// The character's UTF-8 bytes and UTF-16 code units.
+pub fn char_encodings(c: char) -> Report {
+    let units: Vec<String> = utf16_units(c).iter().map(|unit| format!("{:04X}", unit)).collect();
+    Report::new().field("u8", hex_bytes(&utf8_bytes(c))).field("u16", units.join(" "))
+}
+
+
+// The character's Unicode name, general category, and block, where known.
+#[cfg(feature = "unicode")]
+pub fn char_properties(c: char) -> Report {
+    let mut report = Report::new();
+    if let Some(name) = crate::unicode::name(c) {
+        report = report.field("nam", name);
+    }
+    let category = crate::unicode::category(c);
+    report = match crate::unicode::category_name(category) {
+        Some(name) => report.field("cat", format!("{} ({})", category, name)),
+        None => report.field("cat", category),
+    };
+    if let Some(block) = crate::unicode::block(c) {
+        report = report.field("blk", block);
+    }
+    report
+}
+
+
+#[cfg(not(feature = "unicode"))]
+pub fn char_properties(_c: char) -> Report {
+    Report::new()
+}
+
+
+// Formats a code given as its two parts, with its length and its bytes on a second line.
+pub fn code_info((head, tail): (String, String)) -> String {
+    let bits = head.clone() + &tail;
+    let plural = if bits.len() == 1 { "" } else { "s" };
+    let bytes = hex_bytes(&coding::pack_bits(&bits));
+    let code = format!("{} {}", head, tail);
+    format!("{}\n{} bit{}, packed as {}", code.trim(), bits.len(), plural, bytes)
+}
+
+
+// Decodes a stream of binary digits with the specified decoder, which returns the value at the
+// start of the stream and the number of digits read. A tail made up of the padding digit is
+// ignored.
+pub fn decode_stream(
+    args: &[String],
+    decode: &dyn Fn(&str) -> Option<(u128, usize)>,
+    padding: char,
+) -> Result<Report, String> {
+    let bits: String = args.concat().chars().filter(|c| *c != '_' && *c != ' ').collect();
+    if let Some(c) = bits.chars().find(|c| *c != '0' && *c != '1') {
+        return Err(format!("'{}' is not a binary digit.", c));
+    }
+    let mut report = Report::new();
+    let (mut pos, mut count) = (0, 0);
+    while pos < bits.len() {
+        let rest = &bits[pos..];
+        match decode(rest) {
+            Some((value, read)) => {
+                report = report.field("val", format!("{} ({})", value, &rest[..read]));
+                pos += read;
+                count += 1;
+            },
+            None if rest.chars().all(|c| c == padding) => {
+                report = report.field("pad", rest);
+                break;
+            },
+            None => return Err(format!("invalid or incomplete code at bit {}.", pos)),
+        }
+    }
+    let plural = if count == 1 { "" } else { "s" };
+    Ok(report.field("cnt", format!("{} value{} in {} bits", count, plural, bits.len())))
+}
+
+
+// Describes an offset in seconds from the present time, e.g. "3 days ago" or "in 2 hours".
+pub fn relative_time(offset: i64) -> String {
+    let secs = offset.unsigned_abs();
+    let (count, unit) = match secs {
+        0..=59 => (secs, "second"),
+        60..=3599 => (secs / 60, "minute"),
+        3600..=86399 => (secs / 3600, "hour"),
+        86400..=31_556_951 => (secs / 86400, "day"),
+        _ => (secs / 31_556_952, "year"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    if offset < 0 {
+        format!("{} {}{} ago", count, unit, plural)
+    } else {
+        format!("in {} {}{}", count, unit, plural)
+    }
+}
+
+
+// Display options for the default command's info block.
+#[derive(Default)]
+pub struct InfoOpts {
+    pub bits: Option<u32>,
+    pub grouping: Option<(u32, char)>,
+    pub base: Option<u32>,
+    pub braille: bool,
+    pub blocks: bool,
+    pub verbose: bool,
+    pub hints: bool,
+}
+
+
+// Formats a byte slice as space-separated pairs of hex digits.
+pub fn hex_bytes(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect::<Vec<String>>().join(" ")
+}
+
+
+pub fn int_info(value: i128, opts: &InfoOpts) -> Report {
+    let info = match analyze(value, opts.bits) {
+        Ok(info) => info,
+        Err(err) => return Report::error_at(&format!("{}.", err), err.code(), None),
+    };
+
+    let plural = if info.min_bits == 1 { "" } else { "s" };
+    let requires = if value >= 0 {
+        format!("{} bit{} (unsigned)", info.min_bits, plural)
+    } else {
+        format!(
+            "{} bit{} (signed), showing {}-bit two's complement",
+            info.min_bits, plural, info.num_bits
+        )
+    };
+
+    let mut report = Report::new()
+        .append(value_json(&info))
+        .typed("req", requires, requires_json(info.min_bits, value < 0, info.num_bits))
+        .append(uint_info(info.bits, info.num_bits, opts));
+    if let Some(ascii) = &info.ascii {
+        report = report.field("asc", ascii);
+    }
+    report = report.append(extra_info(info.bits, info.num_bits, opts));
+    if opts.hints {
+        for hint in hints(value) {
+            report = report.field("hnt", hint);
+        }
+    }
+    report
+}
+
+
+// Guesses at what a value might be, for values that look like a Unix timestamp from this century
+// or the next, a packed date, a few bytes of ASCII text, or a well-known constant. The ranges are
+// kept narrow so that ordinary values don't get hints.
+fn hints(value: i128) -> Vec<String> {
+    let mut hints = Vec::new();
+    let (start, end) = (946684800, 4102444800);
+    let units = [
+        (1, "seconds"),
+        (1_000, "milliseconds"),
+        (1_000_000, "microseconds"),
+        (1_000_000_000, "nanoseconds"),
+    ];
+    for (scale, unit) in units {
+        if (start * scale..end * scale).contains(&value) {
+            let datetime = match scale {
+                1 => datetime::format_utc(value as i64),
+                _ => datetime::format_utc_millis((value / (scale / 1000)) as i64),
+            };
+            hints.push(format!("Unix time in {}, {}, see the time command", unit, datetime));
+        }
+    }
+    if !(0..=u64::MAX as i128).contains(&value) {
+        return hints;
+    }
+    let packed = value as u64;
+    let plausible = |year: i64| (1900..=2100).contains(&year);
+    if let Some((year, month, day)) = datetime::unpack_date(packed).filter(|d| plausible(d.0)) {
+        let date = format!("{:04}-{:02}-{:02}", year, month, day);
+        hints.push(format!("packed YYYYMMDD date {}, see the date command", date));
+    }
+    if let Some((year, month, day)) = datetime::unpack_ordinal(packed).filter(|d| plausible(d.0)) {
+        let date = format!("{:04}-{:02}-{:02}", year, month, day);
+        hints.push(format!("packed YYYYDDD date {}, see the date command", date));
+    }
+    let bytes = &packed.to_be_bytes()[packed.leading_zeros() as usize / 8..];
+    let printable = bytes.iter().all(|byte| (0x20..0x7F).contains(byte));
+    if bytes.len() >= 3 && printable && bytes.iter().any(u8::is_ascii_alphabetic) {
+        let text: String = bytes.iter().map(|byte| *byte as char).collect();
+        let reversed: String = text.chars().rev().collect();
+        hints.push(format!("ASCII text '{}', or '{}' in little-endian order", text, reversed));
+    }
+    if packed > 0xFFFF && poison::describe(packed).is_none() {
+        if let Some(found) = magic::identify(packed).first() {
+            let note = if found.swapped { " with its bytes reversed" } else { "" };
+            hints.push(format!("{}{}, see the ident command", found.name, note));
+        }
+    }
+    hints
+}
+
+
+// Returns the bit pattern to display for `value` and its width, as computed by analyze().
+pub fn display_bits(value: i128, user_bits: Option<u32>) -> Result<(u128, u32), String> {
+    match analyze(value, user_bits) {
+        Ok(info) => Ok((info.bits, info.num_bits)),
+        Err(err) => Err(format!("{}.", err)),
+    }
+}
+
+
+// Info for unsigned values above the signed 128-bit range, which always require 128 bits.
+pub fn u128_info(value: u128, opts: &InfoOpts) -> Report {
+    if let Err(msg) = check_u128_bits(value, opts.bits) {
+        let code = match opts.bits {
+            Some(bits) if bits > 128 => IntspectorError::UnsupportedBits(bits).code(),
+            _ => "too_few_bits",
+        };
+        return Report::error_at(&msg, code, None);
+    }
+    Report::new()
+        .data("value", json_number(value))
+        .data("min_bits", json_number(128))
+        .data("std_bits", json_number(128))
+        .data("twos_complement", json::Value::Null)
+        .typed("req", "128 bits (unsigned)", requires_json(128, false, 128))
+        .append(uint_info(value, 128, opts))
+        .append(extra_info(value, 128, opts))
+}
+
+
+// The JSON-only members for the value itself, its minimum and standard widths, and its two's
+// complement bit pattern if it's negative.
+fn value_json(info: &IntInfo) -> Report {
+    Report::new()
+        .data("value", json_number(info.value))
+        .data("min_bits", json_number(info.min_bits))
+        .data("std_bits", json_number(info.std_bits))
+        .data("twos_complement", info.twos_complement.map_or(json::Value::Null, json_number))
+}
+
+
+// The JSON value for the "req" field: the minimum bits, whether they're for a signed value, and
+// the width of the bit pattern shown.
+fn requires_json(min_bits: u32, signed: bool, num_bits: u32) -> json::Value {
+    json::Value::Object(vec![
+        ("bits".to_string(), json_number(min_bits)),
+        ("signed".to_string(), json::Value::Bool(signed)),
+        ("width".to_string(), json_number(num_bits)),
+    ])
+}
+
+
+fn check_u128_bits(value: u128, user_bits: Option<u32>) -> Result<(), String> {
+    match user_bits {
+        Some(bits) if bits > 128 => Err("unsupported bit size.".to_string()),
+        Some(bits) if bits < 128 => Err(format!("{} requires at least 128 bits.", value)),
+        _ => Ok(()),
+    }
+}
+
+
+// Lines appended to the info block for the displayed bit pattern: the poison-value check and the
+// optional bit renderings.
+fn extra_info(disp_value: u128, num_bits: u32, opts: &InfoOpts) -> Report {
+    let mut report = Report::new();
+    if disp_value <= u64::MAX as u128 {
+        if let Some(desc) = poison::describe(disp_value as u64) {
+            report = report.field("psn", desc);
+        }
+    }
+    if opts.braille {
+        report = report.field("brl", bitmap::braille(disp_value, num_bits));
+    }
+    if opts.blocks {
+        report = report.field("blk", bitmap::blocks(disp_value, num_bits));
+    }
+    if opts.verbose {
+        report = report.append(power_info(disp_value));
+    }
+    report
+}
+
+
+// Lines for --verbose: whether the value is a power of two, or the powers either side, its
+// base-2 logarithm, and its alignment.
+fn power_info(value: u128) -> Report {
+    let power = |p: u128| {
+        format!("2^{} ({})", p.trailing_zeros(), add_spacers(&p.to_string(), ',', 3))
+    };
+    if value == 0 {
+        return Report::new()
+            .field("pw2", "no, the next is 2^0 (1)")
+            .field("lg2", "undefined")
+            .field("aln", "any");
+    }
+    let floor = 127 - value.leading_zeros();
+    let aln = power(common_alignment(&[value]).unwrap());
+    if is_power_of_two(value) {
+        return Report::new()
+            .field("pw2", format!("yes, {}", power(value)))
+            .field("lg2", format!("{} (exact)", floor))
+            .field("aln", aln);
+    }
+    let prev = power(prev_power_of_two(value).unwrap());
+    let next = next_power_of_two(value).map_or("2^128".to_string(), power);
+    // Truncate rather than round so that e.g. 2^64 - 1 doesn't show as 64.000.
+    let log = ((value as f64).log2() * 1000.0).floor() / 1000.0;
+    let log = log.clamp(floor as f64, floor as f64 + 0.999);
+    Report::new()
+        .field("pw2", format!("no, between {} and {}", prev, next))
+        .field("lg2", format!("{:.3} (floor {})", log, floor))
+        .field("aln", aln)
+}
+
+
+pub fn uint_info(value: u128, num_bits: u32, opts: &InfoOpts) -> Report {
+    let bin = match opts.grouping {
+        Some((group, spacer)) => bin_string_with(value, num_bits, group, spacer),
+        None => bin_string(value, num_bits),
+    };
+    let ones = popcount(value, num_bits);
+    let plural = |count: u32| if count == 1 { "" } else { "s" };
+    let (leading, trailing) = (clz(value, num_bits), ctz(value, num_bits));
+    let hex = format!("{:X}", value);
+    let raw_bin = format!("{:0width$b}", value, width = num_bits as usize);
+    let mut report = Report::new()
+        .typed("hex", add_spacers(&hex, ' ', 2), json::Value::String(hex))
+        .typed("dec", add_spacers(&value.to_string(), ',', 3), json_number(value))
+        .field("oct", format!("{:o}", value))
+        .typed("bin", bin, json::Value::String(raw_bin));
+    if let Some(base) = opts.base {
+        let digits = to_base(value, base);
+        let text = format!("{} (base {})", digits, base);
+        let base_json = json::Value::Object(vec![
+            ("base".to_string(), json_number(base)),
+            ("digits".to_string(), json::Value::String(digits)),
+        ]);
+        report = report.typed("bas", text, base_json);
+    }
+    let parity = if parity(value, num_bits) == 1 { "odd" } else { "even" };
+    let text = format!(
+        "{} set bit{}, {} leading zero{}, {} trailing zero{}, {} parity",
+        ones,
+        plural(ones),
+        leading,
+        plural(leading),
+        trailing,
+        plural(trailing),
+        parity,
+    );
+    let pop_json = json::Value::Object(vec![
+        ("set".to_string(), json_number(ones)),
+        ("leading_zeros".to_string(), json_number(leading)),
+        ("trailing_zeros".to_string(), json_number(trailing)),
+        ("parity".to_string(), json::Value::String(parity.to_string())),
+    ]);
+    report.typed("pop", text, pop_json)
+}
+
+
+fn json_number(value: impl ToString) -> json::Value {
+    json::Value::Number(value.to_string())
+}
+
+
+// Prints a command's info block or error for a single input, which JSON shows as its first
+// member. The error is a message, or an error report with a more specific code.
+pub fn print_result(
+    ctx: &mut Context,
+    arg: &str,
+    output: Result<Report, impl Into<Report>>,
+) -> Result<(), Error> {
+    output.unwrap_or_else(Into::into).input(arg).print(ctx)?;
+    ctx.termline()
+}
+
+
+// An input's label and its bytes, or an error message if they can't be gathered.
+pub type ByteInput = (String, Result<stream::Bytes, String>);
+
+
+// Gathers the bytes to inspect for a command with `--file` and `--hex` options: the file's
+// contents, then each argument's text, or with `--hex`, its decoded bytes. Each input comes with
+// its label, a path or an argument, and a file that can't be read is an error for the command.
+pub fn byte_inputs(
+    ctx: &mut Context,
+    parser: &ArgParser,
+) -> Result<Vec<ByteInput>, Error> {
+    let mut inputs = Vec::new();
+    if let Some(path) = parser.value("file") {
+        match stream::read_file(&path, ctx.progress(true)) {
+            Ok(bytes) => inputs.push((path, Ok(bytes))),
+            Err(err) => {
+                return Err(Error::Invalid(format!("cannot read '{}': {}.", path, err)));
+            }
+        }
+    }
+    for arg in &parser.args {
+        if parser.found("hex") {
+            let bytes = codec::hex_decode(arg).map(stream::Bytes::from);
+            inputs.push((arg.clone(), bytes.ok_or(format!("cannot parse '{}' as hex.", arg))));
+        } else {
+            inputs.push((arg.clone(), Ok(arg.as_bytes().to_vec().into())));
+        }
+    }
+    Ok(inputs)
+}
+
+
+// The error report for an argument that isn't a valid integer literal, with the code of the
+// error and the position of the offending character.
+pub fn parse_error(arg: &str, err: &IntspectorError) -> Report {
+    Report::error_at(&format!("cannot parse '{}': {}.", arg, err), err.code(), err.position(arg))
+}
+
+
@@ -0,0 +1,229 @@
+//! Binary-to-text codecs, and a UTF-8 decoder that reports where sequences go wrong.
+
+
+/// Decodes a base64 string. Accepts both the standard and the URL-safe alphabets, with or
+/// without padding. Returns `None` if the input is not valid base64.
+pub fn base64_decode(input: &str) -> Option<Vec<u8>> {
+    let input = input.trim_end_matches('=');
+    let mut bytes = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut num_bits = 0;
+
+    for c in input.chars() {
+        let value = match c {
+            'A'..='Z' => c as u32 - 'A' as u32,
+            'a'..='z' => c as u32 - 'a' as u32 + 26,
+            '0'..='9' => c as u32 - '0' as u32 + 52,
+            '+' | '-' => 62,
+            '/' | '_' => 63,
+            _ => return None,
+        };
+        buffer = buffer << 6 | value;
+        num_bits += 6;
+        if num_bits >= 8 {
+            num_bits -= 8;
+            bytes.push((buffer >> num_bits) as u8);
+            buffer &= (1 << num_bits) - 1;
+        }
+    }
+
+    // A single leftover character can't encode a complete byte.
+    if num_bits >= 6 {
+        return None;
+    }
+    Some(bytes)
+}
+
+
+/// Decodes a base64 string in one alphabet only: the URL-safe alphabet if `url_safe` is true,
+/// otherwise the standard one. Padding is optional in both. Returns `None` if the input is not
+/// valid base64 in that alphabet.
+pub fn base64_decode_with(input: &str, url_safe: bool) -> Option<Vec<u8>> {
+    let foreign: &[char] = if url_safe { &['+', '/'] } else { &['-', '_'] };
+    match input.contains(foreign) {
+        true => None,
+        false => base64_decode(input),
+    }
+}
+
+
+/// Encodes bytes as base64. The standard alphabet is padded with `=` to a multiple of four
+/// characters, while the URL-safe alphabet, which has `-` and `_` in place of `+` and `/`, is
+/// left unpadded, as in JWTs.
+pub fn base64_encode(bytes: &[u8], url_safe: bool) -> String {
+    let alphabet: &[u8] = match url_safe {
+        true => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        false => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    };
+    let mut output = encode_groups(bytes, 6, alphabet);
+    if !url_safe {
+        while !output.len().is_multiple_of(4) {
+            output.push('=');
+        }
+    }
+    output
+}
+
+
+/// Encodes bytes as base32 with the RFC 4648 alphabet, padded with `=` to a multiple of eight
+/// characters.
+pub fn base32_encode(bytes: &[u8]) -> String {
+    let mut output = encode_groups(bytes, 5, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+    while !output.len().is_multiple_of(8) {
+        output.push('=');
+    }
+    output
+}
+
+
+// Splits bytes into groups of `bits` bits, zero-filling the last group, and maps each group to a
+// character of the alphabet.
+fn encode_groups(bytes: &[u8], bits: u32, alphabet: &[u8]) -> String {
+    let mut output = String::with_capacity(bytes.len() * 8 / bits as usize + 1);
+    let mut buffer: u32 = 0;
+    let mut num_bits = 0;
+    for byte in bytes {
+        buffer = buffer << 8 | *byte as u32;
+        num_bits += 8;
+        while num_bits >= bits {
+            num_bits -= bits;
+            output.push(alphabet[(buffer >> num_bits) as usize] as char);
+            buffer &= (1 << num_bits) - 1;
+        }
+    }
+    if num_bits > 0 {
+        output.push(alphabet[(buffer << (bits - num_bits)) as usize] as char);
+    }
+    output
+}
+
+
+/// Decodes a base32 string with the RFC 4648 alphabet in either case, with or without padding.
+/// Returns `None` if the input is not valid base32.
+pub fn base32_decode(input: &str) -> Option<Vec<u8>> {
+    let input = input.trim_end_matches('=');
+    let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut num_bits = 0;
+
+    for c in input.chars() {
+        let value = match c.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u32 - 'A' as u32,
+            c @ '2'..='7' => c as u32 - '2' as u32 + 26,
+            _ => return None,
+        };
+        buffer = buffer << 5 | value;
+        num_bits += 5;
+        if num_bits >= 8 {
+            num_bits -= 8;
+            bytes.push((buffer >> num_bits) as u8);
+            buffer &= (1 << num_bits) - 1;
+        }
+    }
+
+    // Leftover characters must pad out a byte: 1, 3, or 6 characters can't end a valid string.
+    if num_bits >= 5 {
+        return None;
+    }
+    Some(bytes)
+}
+
+
+/// Decodes a string of hex digits into bytes, e.g. `2A864886` or `0x2a 86 48 86`. Spaces, colons,
+/// and underscores between digits are ignored, as is a leading `0x`. Returns `None` if the input
+/// is empty, contains a non-hex character, or has an odd number of digits.
+pub fn hex_decode(input: &str) -> Option<Vec<u8>> {
+    let input = input.strip_prefix("0x").unwrap_or(input);
+    let digits: Vec<u32> = input
+        .chars()
+        .filter(|c| !matches!(c, ' ' | ':' | '_'))
+        .map(|c| c.to_digit(16))
+        .collect::<Option<Vec<u32>>>()?;
+    if digits.is_empty() || !digits.len().is_multiple_of(2) {
+        return None;
+    }
+    Some(digits.chunks(2).map(|pair| (pair[0] << 4 | pair[1]) as u8).collect())
+}
+
+
+/// A reason a byte sequence is not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Utf8Error {
+    /// A continuation byte, `10xxxxxx`, with no lead byte before it.
+    UnexpectedContinuation,
+    /// A byte that never appears in UTF-8: `C0`, `C1`, or `F5` to `FF`.
+    InvalidByte,
+    /// A lead byte followed by too few continuation bytes.
+    Truncated,
+    /// A code point encoded with more bytes than it needs.
+    Overlong,
+    /// A UTF-16 surrogate, U+D800 to U+DFFF, which can't be encoded.
+    Surrogate,
+    /// A value above U+10FFFF, the last code point.
+    OutOfRange,
+}
+
+
+impl std::fmt::Display for Utf8Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let description = match self {
+            Utf8Error::UnexpectedContinuation => "unexpected continuation byte",
+            Utf8Error::InvalidByte => "invalid byte",
+            Utf8Error::Truncated => "truncated sequence",
+            Utf8Error::Overlong => "overlong encoding",
+            Utf8Error::Surrogate => "encoded surrogate",
+            Utf8Error::OutOfRange => "code point above U+10FFFF",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+
+/// Decodes bytes as UTF-8, returning the offset and length in bytes of each sequence along with
+/// the character or the reason it's invalid. An invalid lead or continuation byte is reported on
+/// its own, and a truncated sequence covers the bytes up to the first missing continuation byte,
+/// so decoding resumes as soon as possible.
+pub fn decode_utf8(bytes: &[u8]) -> Vec<(usize, usize, Result<char, Utf8Error>)> {
+    let mut items = Vec::new();
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let lead = bytes[offset];
+        let (length, min, initial) = match lead {
+            0x00..=0x7F => (1, 0, lead as u32),
+            0x80..=0xBF => {
+                items.push((offset, 1, Err(Utf8Error::UnexpectedContinuation)));
+                offset += 1;
+                continue;
+            },
+            0xC2..=0xDF => (2, 0x80, lead as u32 & 0x1F),
+            0xE0..=0xEF => (3, 0x800, lead as u32 & 0x0F),
+            0xF0..=0xF4 => (4, 0x10000, lead as u32 & 0x07),
+            _ => {
+                items.push((offset, 1, Err(Utf8Error::InvalidByte)));
+                offset += 1;
+                continue;
+            },
+        };
+        let mut code = initial;
+        let mut read = 1;
+        while read < length {
+            match bytes.get(offset + read) {
+                Some(byte) if byte & 0xC0 == 0x80 => code = code << 6 | (byte & 0x3F) as u32,
+                _ => break,
+            }
+            read += 1;
+        }
+        let result = if read < length {
+            Err(Utf8Error::Truncated)
+        } else if code < min {
+            Err(Utf8Error::Overlong)
+        } else if (0xD800..=0xDFFF).contains(&code) {
+            Err(Utf8Error::Surrogate)
+        } else {
+            std::char::from_u32(code).ok_or(Utf8Error::OutOfRange)
+        };
+        items.push((offset, read, result));
+        offset += read;
+    }
+    items
+}
@@ -0,0 +1,153 @@
+//! Sizes of integers under fixed-width, variable-length, and universal encodings, and encoders
+//! and decoders for the Elias gamma, Elias delta, and Golomb-Rice codes.
+
+
+/// Returns the number of significant bits in `value`, or 0 for zero.
+fn bit_length(value: u128) -> u32 {
+    128 - value.leading_zeros()
+}
+
+
+/// Returns the zigzag encoding of a signed integer, which maps 0, -1, 1, -2, ... to 0, 1, 2,
+/// 3, ... so that small negative values have small encodings.
+pub fn zigzag(value: i128) -> u128 {
+    crate::zigzag_encode(value)
+}
+
+
+/// Returns the length in bytes of the unsigned LEB128 (varint) encoding of `value`, which
+/// stores 7 bits per byte.
+pub fn varint_len(value: u128) -> u32 {
+    bit_length(value).max(1).div_ceil(7)
+}
+
+
+/// Returns the length in bits of the Elias gamma code for `value`: the binary digits of the
+/// value preceded by one fewer zeros. Returns `None` for zero, which has no code.
+pub fn elias_gamma_bits(value: u128) -> Option<u32> {
+    match value {
+        0 => None,
+        _ => Some(2 * bit_length(value) - 1),
+    }
+}
+
+
+/// Returns the length in bits of the Elias delta code for `value`: the gamma code of the value's
+/// bit length followed by the value's binary digits without the leading 1. Returns `None` for
+/// zero, which has no code.
+pub fn elias_delta_bits(value: u128) -> Option<u32> {
+    let length = bit_length(value);
+    Some(elias_gamma_bits(length as u128)? + length - 1)
+}
+
+
+/// Returns the length in bits of the Golomb-Rice code for `value` with parameter `k`: the
+/// quotient `value >> k` in unary, a terminating bit, and the low `k` bits of the value.
+pub fn rice_bits(value: u128, k: u32) -> u128 {
+    value.checked_shr(k).unwrap_or(0).saturating_add(1 + k as u128)
+}
+
+
+/// Returns the Golomb-Rice parameter, from 0 to 127, that gives the shortest code for `value`.
+pub fn best_rice_parameter(value: u128) -> u32 {
+    (0..128).min_by_key(|k| rice_bits(value, *k)).unwrap()
+}
+
+
+/// Codes longer than this many bits are not generated.
+pub const MAX_CODE_BITS: u128 = 1 << 16;
+
+
+/// Returns the Elias gamma code for `value` as a string of binary digits, split into the zero
+/// prefix and the value's binary digits. Returns `None` for zero, which has no code.
+pub fn elias_gamma(value: u128) -> Option<(String, String)> {
+    if value == 0 {
+        return None;
+    }
+    let binary = format!("{:b}", value);
+    Some(("0".repeat(binary.len() - 1), binary))
+}
+
+
+/// Returns the Elias delta code for `value` as a string of binary digits, split into the gamma
+/// code of the value's bit length and the value's binary digits without the leading 1. Returns
+/// `None` for zero, which has no code.
+pub fn elias_delta(value: u128) -> Option<(String, String)> {
+    if value == 0 {
+        return None;
+    }
+    let binary = format!("{:b}", value);
+    let (zeros, length) = elias_gamma(binary.len() as u128)?;
+    Some((zeros + &length, binary[1..].to_string()))
+}
+
+
+/// Returns the Golomb-Rice code for `value` with parameter `k` as a string of binary digits,
+/// split into the quotient `value >> k` in unary, written as ones terminated by a zero, and the
+/// low `k` bits of the value. Returns `None` if the code would be longer than `MAX_CODE_BITS`.
+pub fn rice(value: u128, k: u32) -> Option<(String, String)> {
+    if rice_bits(value, k) > MAX_CODE_BITS {
+        return None;
+    }
+    let quotient = value.checked_shr(k).unwrap_or(0) as usize;
+    let low = match k {
+        0 => String::new(),
+        _ => format!("{:0width$b}", value & (u128::MAX >> (128 - k)), width = k as usize),
+    };
+    Some(("1".repeat(quotient) + "0", low))
+}
+
+
+/// Decodes an Elias gamma code from the start of a string of binary digits, returning the value
+/// and the number of digits read. Returns `None` if the string ends before the code does or the
+/// value overflows 128 bits.
+pub fn decode_elias_gamma(bits: &str) -> Option<(u128, usize)> {
+    let zeros = bits.find('1')?;
+    if zeros >= 128 {
+        return None;
+    }
+    let binary = bits.get(zeros..2 * zeros + 1)?;
+    Some((u128::from_str_radix(binary, 2).ok()?, 2 * zeros + 1))
+}
+
+
+/// Decodes an Elias delta code from the start of a string of binary digits, returning the value
+/// and the number of digits read. Returns `None` if the string ends before the code does or the
+/// value overflows 128 bits.
+pub fn decode_elias_delta(bits: &str) -> Option<(u128, usize)> {
+    let (length, read) = decode_elias_gamma(bits)?;
+    if length > 128 {
+        return None;
+    }
+    let end = read + length as usize - 1;
+    let rest = bits.get(read..end)?;
+    Some((u128::from_str_radix(&format!("1{}", rest), 2).ok()?, end))
+}
+
+
+/// Decodes a Golomb-Rice code with parameter `k` from the start of a string of binary digits,
+/// returning the value and the number of digits read. Returns `None` if the string ends before
+/// the code does or the value overflows 128 bits.
+pub fn decode_rice(bits: &str, k: u32) -> Option<(u128, usize)> {
+    let quotient = bits.find('0')?;
+    let end = quotient + 1 + k as usize;
+    let low = bits.get(quotient + 1..end)?;
+    let low = if low.is_empty() { 0 } else { u128::from_str_radix(low, 2).ok()? };
+    if k < 128 && (quotient as u128).checked_shr(128 - k).unwrap_or(0) != 0 {
+        return None;
+    }
+    Some(((quotient as u128).checked_shl(k).unwrap_or(0) | low, end))
+}
+
+
+/// Packs a string of binary digits into bytes, most significant bit first, padding the last byte
+/// with zeros.
+pub fn pack_bits(bits: &str) -> Vec<u8> {
+    bits.as_bytes()
+        .chunks(8)
+        .map(|chunk| {
+            let byte = chunk.iter().fold(0u8, |acc, digit| acc << 1 | (digit - b'0'));
+            byte << (8 - chunk.len())
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+//! Packed color values: decoding RGB888, RGB565, and ARGB8888 pixels and finding the nearest
+//! color in the xterm 256-color palette.
+
+
+/// The pixel layouts supported by `decode()`, each with its width in bits.
+pub const LAYOUTS: &[(&str, u32)] = &[("rgb888", 24), ("rgb565", 16), ("argb8888", 32)];
+
+
+/// A color decoded from a pixel, with 8-bit channels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: Option<u8>,
+}
+
+
+/// A color channel's field in a pixel: its raw value and width in bits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Channel {
+    pub raw: u32,
+    pub bits: u32,
+}
+
+
+impl Channel {
+    /// Scales the raw value to 8 bits, replicating the high bits into the low bits so that the
+    /// maximum value maps to 255.
+    pub fn to_u8(self) -> u8 {
+        match self.bits {
+            8 => self.raw as u8,
+            bits => (self.raw << (8 - bits) | self.raw >> (2 * bits - 8)) as u8,
+        }
+    }
+}
+
+
+/// Splits a pixel into its red, green, blue, and alpha channels. Returns an error if the layout
+/// is unknown or the value is too wide for it.
+pub fn channels(value: u32, layout: &str) -> Result<[Option<Channel>; 4], String> {
+    let (name, bits) = LAYOUTS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(layout))
+        .ok_or_else(|| format!("unknown layout '{}'", layout))?;
+    if *bits < 32 && value >> bits != 0 {
+        return Err(format!("{:#X} is too wide for {}, which has {} bits", value, name, bits));
+    }
+    let field = |shift: u32, bits: u32| {
+        Some(Channel { raw: value >> shift & ((1 << bits) - 1), bits })
+    };
+    Ok(match *name {
+        "rgb565" => [field(11, 5), field(5, 6), field(0, 5), None],
+        "argb8888" => [field(16, 8), field(8, 8), field(0, 8), field(24, 8)],
+        _ => [field(16, 8), field(8, 8), field(0, 8), None],
+    })
+}
+
+
+/// Decodes a pixel in the named layout into a color with 8-bit channels.
+pub fn decode(value: u32, layout: &str) -> Result<Color, String> {
+    let [r, g, b, a] = channels(value, layout)?;
+    let byte = |channel: Option<Channel>| channel.map_or(0, Channel::to_u8);
+    Ok(Color { r: byte(r), g: byte(g), b: byte(b), a: a.map(Channel::to_u8) })
+}
+
+
+/// Encodes a color as an RGB565 pixel, rounding each channel to the nearest level.
+pub fn to_rgb565(color: Color) -> u16 {
+    let scale = |value: u8, max: u32| ((value as u32 * max + 127) / 255) as u16;
+    scale(color.r, 31) << 11 | scale(color.g, 63) << 5 | scale(color.b, 31)
+}
+
+
+/// Returns the RGB value of a color in the xterm 256-color palette: the 16 system colors, a
+/// 6x6x6 color cube, and a 24-step grayscale ramp.
+pub fn ansi256_rgb(index: u8) -> (u8, u8, u8) {
+    const SYSTEM: [(u8, u8, u8); 16] = [
+        (0, 0, 0), (128, 0, 0), (0, 128, 0), (128, 128, 0),
+        (0, 0, 128), (128, 0, 128), (0, 128, 128), (192, 192, 192),
+        (128, 128, 128), (255, 0, 0), (0, 255, 0), (255, 255, 0),
+        (0, 0, 255), (255, 0, 255), (0, 255, 255), (255, 255, 255),
+    ];
+    let level = |step: u8| if step == 0 { 0 } else { 55 + step * 40 };
+    match index {
+        0..=15 => SYSTEM[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        },
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        },
+    }
+}
+
+
+/// Returns the index of the color in the xterm 256-color cube or grayscale ramp nearest to the
+/// color, by squared distance in RGB space. The system colors are skipped, as terminals often
+/// redefine them.
+pub fn nearest_ansi256(color: Color) -> u8 {
+    let distance = |index: u8| {
+        let (r, g, b) = ansi256_rgb(index);
+        let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2);
+        d(r, color.r) + d(g, color.g) + d(b, color.b)
+    };
+    (16..=255).min_by_key(|index| distance(*index)).unwrap()
+}
@@ -0,0 +1,40 @@
+//! Binomial coefficients, permutation counts, and multinomial coefficients as arbitrary-precision
+//! integers.
+
+use crate::bigint::BigUint;
+
+
+/// Returns the binomial coefficient C(n, k), the number of ways to choose `k` items from `n`.
+/// Returns zero if `k > n`.
+pub fn binomial(n: u32, k: u32) -> BigUint {
+    if k > n {
+        return BigUint::zero();
+    }
+    // Each partial product C(n - k + i, i) is an integer, so every division is exact.
+    let k = k.min(n - k);
+    (1..=k).fold(BigUint::from_u128(1), |acc, i| acc.mul_small(n - k + i).div_rem_small(i).0)
+}
+
+
+/// Returns the number of permutations P(n, k) = n! / (n - k)!, the number of ordered
+/// arrangements of `k` items from `n`. Returns zero if `k > n`.
+pub fn permutations(n: u32, k: u32) -> BigUint {
+    if k > n {
+        return BigUint::zero();
+    }
+    (n - k + 1..=n).fold(BigUint::from_u128(1), |acc, i| acc.mul_small(i))
+}
+
+
+/// Returns the multinomial coefficient (k1 + k2 + ...)! / (k1! k2! ...), the number of ways to
+/// split a set into groups of the specified sizes, computed as a product of binomial
+/// coefficients. Returns `None` if the total overflows 32 bits.
+pub fn multinomial(groups: &[u32]) -> Option<BigUint> {
+    let mut result = BigUint::from_u128(1);
+    let mut total: u32 = 0;
+    for k in groups {
+        total = total.checked_add(*k)?;
+        result = result.mul(&binomial(total, *k));
+    }
+    Some(result)
+}
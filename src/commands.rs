@@ -0,0 +1,279 @@
+// The subcommands, each a Decoder in a module of its own. A new command needs a module here and
+// an entry in DECODERS, which registers it with the parser and adds it to the command list, the
+// help, and the man pages.
+
+mod arith;
+mod asn1;
+mod barcode;
+mod bench;
+mod bit;
+mod bswap;
+mod bytes;
+mod channel;
+mod check;
+mod checksum;
+mod chmod;
+mod choose;
+mod color;
+mod cost;
+mod country;
+mod cp2l;
+mod crc;
+mod date;
+mod decimal;
+mod diff;
+mod digits;
+mod dns;
+mod dump;
+mod duration;
+mod egyptian;
+mod elias;
+mod encode;
+mod entropy;
+mod exif;
+mod factor;
+mod factoradic;
+mod field;
+mod float;
+mod gen_man;
+mod geo;
+mod gray;
+#[cfg(feature = "hashes")]
+mod hash;
+mod huffman;
+mod ident;
+mod ip;
+mod jwt;
+mod ksuid;
+mod l2cp;
+mod mac;
+mod midi;
+mod nbits;
+mod numerals;
+mod objectid;
+mod ops;
+mod ptr;
+mod rand;
+mod repl;
+mod rice;
+mod rns;
+mod runs;
+mod selftest;
+mod seq;
+mod shift;
+mod signed_digits;
+mod snowflake;
+mod stern_brocot;
+mod synchsafe;
+#[cfg(feature = "syscalls")]
+mod syscall;
+mod time;
+mod ulid;
+mod utf8;
+mod varint;
+
+use arguably::ArgParser;
+use crate::cli::{Context, Error};
+
+
+// A subcommand: its names for ArgParser::command(), a summary for the command list in the main
+// help text, the usage and description part of its help text, its arguments and options, and the
+// function that runs it, writing to the context. The rest of the help text is generated from the
+// arguments and options, which also register the parser's options and flags, so the help always
+// matches what the parser accepts.
+pub trait Decoder {
+    fn names(&self) -> &'static str;
+
+    fn summary(&self) -> &'static str;
+
+    fn help(&self) -> &'static str;
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+
+    fn options(&self) -> &'static [Opt] {
+        &[]
+    }
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error>;
+
+
+    fn name(&self) -> &'static str {
+        self.names().split(' ').next().unwrap()
+    }
+
+
+    fn parser(&self) -> ArgParser {
+        let mut parser = ArgParser::new().flag(HELP_OPT.names);
+        for opt in self.options() {
+            parser = match opt.value {
+                Some(_) => parser.option(opt.names),
+                None => parser.flag(opt.names),
+            };
+        }
+        parser
+    }
+
+
+    // Returns the full help text, with the options and flags, including --help, each listed in
+    // alphabetical order and the descriptions aligned in a column wide enough for every signature.
+    fn helptext(&self) -> String {
+        let arguments: Vec<(String, &str)> = self
+            .arguments()
+            .iter()
+            .map(|(name, help)| (format!("  {}", name), *help))
+            .collect();
+        let signature = |opt: &Opt| {
+            let mut names = opt.names.split(' ');
+            let long = names.next().unwrap();
+            let mut signature = match names.next() {
+                Some(short) => format!("  -{}, --{}", short, long),
+                None => format!("      --{}", long),
+            };
+            if let Some(value) = opt.value {
+                signature += &format!(" <{}>", value);
+            }
+            (signature, opt.help)
+        };
+        let mut opts: Vec<&Opt> = self.options().iter().chain([&HELP_OPT]).collect();
+        opts.sort_by_key(|opt| opt.names);
+        let (options, flags): (Vec<&Opt>, Vec<&Opt>) =
+            opts.into_iter().partition(|opt| opt.value.is_some());
+        let options: Vec<(String, &str)> = options.into_iter().map(signature).collect();
+        let flags: Vec<(String, &str)> = flags.into_iter().map(signature).collect();
+
+        let widest = arguments.iter().chain(&options).chain(&flags).map(|row| row.0.len()).max();
+        let column = widest.map_or(24, |width| (width + 5) / 4 * 4).max(24);
+        let mut output = self.help().to_string();
+        for (heading, rows) in [("Arguments", arguments), ("Options", options), ("Flags", flags)] {
+            if rows.is_empty() {
+                continue;
+            }
+            output += &format!("\n{}:\n", heading);
+            for (signature, help) in rows {
+                for (i, line) in wrap(help, 77 - column).iter().enumerate() {
+                    let lead = if i == 0 { signature.as_str() } else { "" };
+                    output += &format!("{:<column$}{}\n", lead, line, column = column);
+                }
+            }
+        }
+        output
+    }
+}
+
+
+// An option or flag: its names for ArgParser::option() or flag(), the placeholder for its value,
+// or None for a flag, and its description.
+pub struct Opt {
+    pub names: &'static str,
+    pub value: Option<&'static str>,
+    pub help: &'static str,
+}
+
+
+const HELP_OPT: Opt = Opt { names: "help h", value: None, help: "Print this help text." };
+
+
+// The subcommands, in alphabetical order. Those built on optional data tables are only included
+// with the matching cargo feature.
+pub const DECODERS: &[&dyn Decoder] = &[
+    &arith::Command,
+    &asn1::Command,
+    &barcode::Command,
+    &bit::Command,
+    &bswap::Command,
+    &bytes::Command,
+    &channel::Command,
+    &check::Command,
+    &checksum::Command,
+    &chmod::Command,
+    &choose::Command,
+    &color::Command,
+    &cost::Command,
+    &country::Command,
+    &cp2l::Command,
+    &crc::Command,
+    &date::Command,
+    &decimal::Command,
+    &diff::Command,
+    &digits::Command,
+    &dns::Command,
+    &dump::Command,
+    &duration::Command,
+    &egyptian::Command,
+    &elias::Command,
+    &encode::Command,
+    &entropy::Command,
+    &exif::Command,
+    &factor::Command,
+    &factoradic::Command,
+    &field::Command,
+    &float::Command,
+    &geo::Command,
+    &gray::Command,
+    #[cfg(feature = "hashes")]
+    &hash::Command,
+    &huffman::Command,
+    &ident::Command,
+    &ip::Command,
+    &jwt::Command,
+    &ksuid::Command,
+    &l2cp::Command,
+    &mac::Command,
+    &midi::Command,
+    &nbits::Command,
+    &numerals::Command,
+    &objectid::Command,
+    &ops::Command,
+    &ptr::Command,
+    &rand::Command,
+    &repl::Command,
+    &rice::Command,
+    &rns::Command,
+    &runs::Command,
+    &signed_digits::Command,
+    &selftest::Command,
+    &seq::Command,
+    &shift::Command,
+    &snowflake::Command,
+    &stern_brocot::Command,
+    &synchsafe::Command,
+    #[cfg(feature = "syscalls")]
+    &syscall::Command,
+    &time::Command,
+    &ulid::Command,
+    &utf8::Command,
+    &varint::Command,
+];
+
+
+// Developer commands, which are left out of the command list and the man pages.
+pub const DEVELOPER_DECODERS: &[&dyn Decoder] = &[&bench::Command, &gen_man::Command];
+
+
+// Returns the command with the specified name or alias, including the developer commands.
+pub fn find(name: &str) -> Option<&'static dyn Decoder> {
+    DECODERS
+        .iter()
+        .chain(DEVELOPER_DECODERS)
+        .find(|decoder| decoder.names().split(' ').any(|n| n == name))
+        .copied()
+}
+
+
+// Splits text into lines of at most `width` characters, breaking at spaces. Words longer than
+// the width get a line of their own.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for word in text.split(' ') {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= width => {
+                line.push(' ');
+                line.push_str(word);
+            },
+            _ => lines.push(word.to_string()),
+        }
+    }
+    lines
+}
@@ -0,0 +1,169 @@
+use arguably::ArgParser;
+use crate::arith;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_uint;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector arith -m <symbols> [symbol:frequency]
+
+  Shows how arithmetic coding narrows the unit interval for a message.
+  Each symbol takes a slice of the current interval proportional to its
+  frequency, in the order the symbols are listed. The bounds after each
+  symbol are shown as exact fractions and as truncated binary fractions.
+
+  The codeword is the shortest binary fraction inside the final interval.
+  It is at most two bits longer than the message's information content,
+  the negative log of the final interval's width. (A decoder also needs the
+  message length to know where to stop.)
+
+  Symbols are single characters. Frequencies can be specified in binary,
+  octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "arith"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Show the interval narrowing of arithmetic coding."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[symbol:frequency]", "List of symbols and their frequencies, e.g. a:3.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "message m",
+                value: Some("symbols"),
+                help: "Message to encode, e.g. abaca.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_arith(ctx, parser)
+    }
+}
+
+
+fn cmd_arith(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let mut symbols: Vec<char> = Vec::new();
+    let mut frequencies = Vec::new();
+    for arg in &cmd_parser.args {
+        let mut chars = arg.chars();
+        let (symbol, frequency) = match (chars.next(), chars.next()) {
+            (Some(symbol), Some(':')) => (symbol, chars.as_str()),
+            _ => {
+                return Err(Error::Invalid(format!("expected symbol:frequency, found '{}'.", arg)));
+            }
+        };
+        match parse_uint(frequency) {
+            Ok(0) => {
+                let msg = format!("the frequency of '{}' must be at least 1.", symbol);
+                return Err(Error::Invalid(msg));
+            },
+            Ok(frequency) => frequencies.push(frequency),
+            Err(err) => {
+                return Err(Error::Invalid(format!("cannot parse '{}': {}.", frequency, err)));
+            }
+        }
+        if symbols.contains(&symbol) {
+            return Err(Error::Invalid(format!("the symbol '{}' is listed twice.", symbol)));
+        }
+        symbols.push(symbol);
+    }
+    let message = match cmd_parser.value("message") {
+        Some(message) => message,
+        None => {
+            return Err(Error::Invalid("the --message option is required.".to_string()));
+        }
+    };
+    let mut indices = Vec::new();
+    for c in message.chars() {
+        match symbols.iter().position(|symbol| *symbol == c) {
+            Some(index) => indices.push(index),
+            None => {
+                return Err(Error::Invalid(format!("the symbol '{}' has no frequency.", c)));
+            }
+        }
+    }
+    let intervals = match arith::narrow(&frequencies, &indices) {
+        Some(intervals) => intervals,
+        None => {
+            return Err(Error::Invalid("the message is too long for exact intervals.".to_string()));
+        }
+    };
+    let last = *intervals.last().unwrap();
+    let code = arith::shortest_code(last);
+
+    // Show a few more binary digits than the codeword so the bounds can be told apart.
+    let digits = code.map_or(0, |(_, length)| length as usize) + 4;
+    let rows: Vec<(String, String, String)> = intervals
+        .iter()
+        .zip(std::iter::once(' ').chain(message.chars()))
+        .map(|(interval, c)| {
+            let low = format!("{}/{}", interval.low, interval.den);
+            (c.to_string(), low, format!("{}/{}", interval.high, interval.den))
+        })
+        .collect();
+    let low_width = rows.iter().map(|row| row.1.len()).max().unwrap();
+    let high_width = rows.iter().map(|row| row.2.len()).max().unwrap();
+
+    let rows = rows
+        .iter()
+        .zip(&intervals)
+        .map(|((c, low, high), interval)| {
+            vec![
+                c.clone(),
+                format!("[{:>lw$}, {:>hw$})", low, high, lw = low_width, hw = high_width),
+                format!(
+                    "{} to {}",
+                    arith::binary_fraction(interval.low, interval.den, digits),
+                    arith::binary_fraction(interval.high, interval.den, digits),
+                ),
+            ]
+        })
+        .collect();
+    ctx.termline()?;
+    Report::new().table(&["symbol", "interval", "binary"], rows).print(ctx)?;
+    ctx.termline()?;
+    let information = (last.den as f64).log2() - ((last.high - last.low) as f64).log2();
+    let report = match code {
+        Some((code, length)) => Report::new().field(
+            "cod",
+            format!(
+                "{:0width$b} ({} {})",
+                code,
+                length,
+                if length == 1 { "bit" } else { "bits" },
+                width = length as usize,
+            ),
+        ),
+        None => Report::new().field("cod", "none"),
+    };
+    let symbols = message.chars().count();
+    report.field("inf", format!("{:.4} bits for {} symbols", information, symbols)).print(ctx)?;
+    ctx.termline()?;
+    Ok(())
+}
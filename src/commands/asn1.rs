@@ -0,0 +1,261 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::asn1;
+use crate::bigint::BigUint;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::hex_bytes;
+use crate::cli::print_result;
+use crate::codec;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_uint;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector asn1 [values]
+
+  Decodes and encodes ASN.1 BER/DER object identifiers and length octets.
+
+  OIDs written as hex content octets, e.g. 2A864886F70D01010B, are decoded
+  into dotted notation, showing each base-128 subidentifier. A complete
+  OBJECT IDENTIFIER encoding including the 06 tag and length is also
+  accepted. OIDs written in dotted notation, e.g. 1.2.840.113549.1.1.11, are
+  encoded. Well-known OIDs are labelled with their names.
+
+  With the --length flag, decodes hex length octets, e.g. 82012C, reporting
+  whether they use the minimal DER encoding. Add the --encode flag to encode
+  integer lengths instead.
+
+  The X.509 flags decode certificate fields written in hex:
+
+  - --key-usage: a keyUsage BIT STRING, e.g. 03020186, or its content
+    octets, e.g. 0186.
+  - --ext-key-usage: an extKeyUsage SEQUENCE of OIDs, e.g.
+    301406082B0601050507030106082B06010505070302.
+  - --serial: a serial number INTEGER, or its content octets, displayed in
+    hex and decimal and checked against the RFC 5280 rules.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "asn1"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode ASN.1 lengths, OIDs, and X.509 fields."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[values]", "List of OIDs, lengths, or certificate fields.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "encode e", value: None, help: "Encode integer lengths." },
+            Opt { names: "ext-key-usage x", value: None, help: "Decode extKeyUsage values." },
+            Opt { names: "key-usage k", value: None, help: "Decode keyUsage bit strings." },
+            Opt { names: "length l", value: None, help: "Decode or encode length octets." },
+            Opt { names: "serial s", value: None, help: "Decode certificate serial numbers." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_asn1(ctx, parser)
+    }
+}
+
+
+fn cmd_asn1(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let bytes = codec::hex_decode(arg);
+        let output = if cmd_parser.found("key-usage") {
+            match bytes.and_then(|bytes| key_usage_info(&bytes)) {
+                Some(info) => Ok(info),
+                None => Err(format!("'{}' is not a valid keyUsage bit string.", arg)),
+            }
+        } else if cmd_parser.found("ext-key-usage") {
+            match bytes.and_then(|bytes| asn1::ext_key_usage(&bytes)) {
+                Some(oids) => Ok(ext_key_usage_info(&oids)),
+                None => Err(format!("'{}' is not a valid extKeyUsage sequence.", arg)),
+            }
+        } else if cmd_parser.found("serial") {
+            match bytes {
+                Some(bytes) => Ok(serial_info(&strip_tlv_header(&bytes, 0x02))),
+                None => Err(format!("cannot parse '{}' as hex octets.", arg)),
+            }
+        } else if cmd_parser.found("length") && cmd_parser.found("encode") {
+            match parse_uint(arg) {
+                Ok(length) => Ok(asn1_length_info(&asn1::encode_length(length))),
+                Err(err) => Err(format!("cannot parse '{}': {}.", arg, err)),
+            }
+        } else if cmd_parser.found("length") {
+            match codec::hex_decode(arg) {
+                Some(bytes) if asn1::decode_length(&bytes).is_some() => {
+                    Ok(asn1_length_info(&bytes))
+                },
+                _ => Err(format!("'{}' is not a valid BER length.", arg)),
+            }
+        } else if arg.contains('.') {
+            match asn1::parse_oid(arg).and_then(|arcs| asn1::encode_oid(&arcs)) {
+                Some(bytes) => Ok(oid_info(&bytes)),
+                None => Err(format!("'{}' is not a valid object identifier.", arg)),
+            }
+        } else {
+            match codec::hex_decode(arg).map(|bytes| strip_tlv_header(&bytes, 0x06)) {
+                Some(bytes) if asn1::decode_oid(&bytes).is_some() => Ok(oid_info(&bytes)),
+                _ => Err(format!("'{}' is not a valid OID encoding.", arg)),
+            }
+        };
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+// Strips the tag and length from a complete encoding with the specified tag, leaving the content
+// octets. Returns the input unchanged if it isn't a single value with that tag.
+fn strip_tlv_header(bytes: &[u8], tag: u8) -> Vec<u8> {
+    match asn1::decode_tlv(bytes) {
+        Some((found, content, rest)) if found == tag && rest.is_empty() => content.to_vec(),
+        _ => bytes.to_vec(),
+    }
+}
+
+
+fn key_usage_info(bytes: &[u8]) -> Option<Report> {
+    let content = strip_tlv_header(bytes, 0x03);
+    let usages = asn1::key_usage(&content)?;
+    let bits = asn1::bit_string_bits(&content)?;
+    let data_bits = (content.len() - 1) * 8 - content[0] as usize;
+    let pattern: String = (0..data_bits)
+        .map(|i| if bits.contains(&i) { '1' } else { '0' })
+        .collect();
+    let mut report = Report::new()
+        .field("hex", hex_bytes(&content))
+        .field("bit", format!("{} ({} unused)", pattern, content[0]));
+    if usages.is_empty() {
+        report = report.field("use", "none");
+    }
+    for usage in usages {
+        report = report.field("use", usage);
+    }
+    Some(report)
+}
+
+
+fn ext_key_usage_info(oids: &[Vec<u64>]) -> Report {
+    if oids.is_empty() {
+        return Report::new().field("eku", "none");
+    }
+    let mut report = Report::new();
+    for arcs in oids {
+        let dotted = asn1::format_oid(arcs);
+        report = match asn1::oid_name(&dotted) {
+            Some(name) => report.field("eku", format!("{} ({})", dotted, name)),
+            None => report.field("eku", dotted),
+        };
+    }
+    report
+}
+
+
+fn serial_info(content: &[u8]) -> Report {
+    let hex: Vec<String> = content.iter().map(|byte| format!("{:02X}", byte)).collect();
+    let issues = asn1::serial_issues(content);
+    let (negative, magnitude) = asn1::integer_magnitude(content);
+    let value = add_spacers(&BigUint::from_bytes_be(&magnitude).to_string(), ',', 3);
+    let plural = if content.len() == 1 { "" } else { "s" };
+    let report = Report::new()
+        .field("hex", hex.join(":"))
+        .field("dec", format!("{}{}", if negative { "-" } else { "" }, value))
+        .field("len", format!("{} octet{}", content.len(), plural));
+    let mut problems = Vec::new();
+    if issues.negative {
+        problems.push("negative");
+    }
+    if issues.zero {
+        problems.push("zero, not positive");
+    }
+    if issues.too_long {
+        problems.push("longer than 20 octets");
+    }
+    if issues.non_minimal {
+        problems.push("redundant leading octet");
+    }
+    match problems.is_empty() {
+        true => report.field("rfc", "conforms to RFC 5280"),
+        false => report.field("rfc", problems.join(", ")),
+    }
+}
+
+
+fn oid_info(bytes: &[u8]) -> Report {
+    let arcs = asn1::decode_oid(bytes).unwrap();
+    let dotted = asn1::format_oid(&arcs);
+    let mut report = match asn1::oid_name(&dotted) {
+        Some(name) => Report::new().field("oid", format!("{} ({})", dotted, name)),
+        None => Report::new().field("oid", &dotted),
+    };
+    let mut tlv = vec![0x06];
+    tlv.extend(asn1::encode_length(bytes.len() as u64));
+    tlv.extend(bytes);
+    report = report.field("der", hex_bytes(&tlv));
+    let mut start = 0;
+    for (i, byte) in bytes.iter().enumerate() {
+        if byte & 0x80 != 0 {
+            continue;
+        }
+        let group = &bytes[start..=i];
+        let value = group.iter().fold(0u64, |acc, byte| acc << 7 | (byte & 0x7F) as u64);
+        let sub = format!("{} = {}", hex_bytes(group), value);
+        report = match start {
+            0 => report.field("sub", format!("{} = 40 × {} + {}", sub, arcs[0], arcs[1])),
+            _ => report.field("sub", sub),
+        };
+        start = i + 1;
+    }
+    report
+}
+
+
+fn asn1_length_info(bytes: &[u8]) -> Report {
+    match asn1::decode_length(bytes) {
+        Some(asn1::Length::Definite(length, size)) => {
+            let form = if size == 1 { "short form" } else { "long form" };
+            let octets = hex_bytes(&bytes[..size]);
+            let plural = if size == 1 { "" } else { "s" };
+            let report = Report::new()
+                .field("len", format!("{} ({}, {} octet{})", length, form, size, plural))
+                .field("hex", octets);
+            if asn1::is_der_length(bytes) {
+                report.field("der", "yes")
+            } else {
+                let minimal = hex_bytes(&asn1::encode_length(length));
+                report.field("der", format!("no, minimal encoding is {}", minimal))
+            }
+        },
+        Some(asn1::Length::Indefinite) => {
+            let desc = "indefinite (terminated by end-of-contents octets, not valid in DER)";
+            Report::new().field("len", desc).field("hex", "80")
+        },
+        None => unreachable!(),
+    }
+}
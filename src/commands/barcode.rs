@@ -0,0 +1,122 @@
+use arguably::ArgParser;
+use crate::barcode;
+use crate::bin_string;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector barcode [digits]
+
+  Shows how each string of decimal digits is packed in a QR code numeric-mode
+  segment (groups of three digits encoded in 10 bits, with shorter trailing
+  groups in 7 or 4 bits) and computes the Code 128 checksum for the digits
+  encoded in code set C.
+
+  Leading zeros are significant, so the input is treated as a string of
+  digits rather than as an integer.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "barcode"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Show QR numeric packing and Code 128 checksums."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[digits]", "List of digit strings.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "qr-version q",
+                value: Some("n"),
+                help: "QR symbol version (1-40), which determines the width of the character \
+                      count. Defaults to 1.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_barcode(ctx, parser)
+    }
+}
+
+
+fn cmd_barcode(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let version = match cmd_parser.value("qr-version") {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value) if (1..=40).contains(&value) => value,
+            _ => {
+                return Err(Error::Invalid(format!("invalid QR version '{}'.", arg)));
+            }
+        },
+        None => 1,
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = match (barcode::qr_numeric_groups(arg), barcode::code128_digit_symbols(arg)) {
+            (Some(groups), Some(symbols)) => Ok(barcode_info(arg, &groups, &symbols, version)),
+            _ => Err(format!("'{}' is not a string of decimal digits.", arg)),
+        };
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn barcode_info(
+    digits: &str,
+    groups: &[(&str, u32, u32)],
+    symbols: &[u32],
+    version: u32,
+) -> Report {
+    let count_bits = barcode::qr_numeric_count_bits(version);
+    let count = format!(
+        "{} ({} digit{}, {}-bit count at version {})",
+        bin_string(digits.len() as u128, count_bits),
+        digits.len(),
+        if digits.len() == 1 { "" } else { "s" },
+        count_bits,
+        version,
+    );
+    let mut report = Report::new()
+        .field("mod", format!("{} (numeric)", bin_string(0b0001, 4)))
+        .field("cnt", count);
+    let mut total = 4 + count_bits;
+    for (group, value, width) in groups {
+        let bits = bin_string(*value as u128, *width);
+        report = report.field("grp", format!("{:<3} -> {} ({} bits)", group, bits, width));
+        total += width;
+    }
+    report = report.field("tot", format!("{} bits before the terminator", total));
+
+    let checksum = barcode::code128_checksum(symbols);
+    let values: Vec<String> = symbols.iter().map(|value| value.to_string()).collect();
+    report
+        .field("sym", format!("{} (Code 128, start C)", values.join(" ")))
+        .field("chk", format!("{} (weighted sum mod 103)", checksum))
+}
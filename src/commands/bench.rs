@@ -0,0 +1,110 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::bench::Measurement;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::run_with_stdin;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+use std::time::Instant;
+
+
+const HELP: &str = "
+Usage: intspector bench
+
+  Times the formatting hot paths and prints the mean time per iteration: the
+  library's integer parsing, binary formatting, and digit grouping, then the
+  batch mode's streaming of a generated buffer of integers, as if piped to
+  stdin, into info blocks, which are written to a sink rather than the
+  terminal. For developers checking that new formatting options don't slow
+  the batch mode down.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "bench"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Time the parsing and formatting hot paths."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "iterations n",
+                value: Some("n"),
+                help: "Number of iterations of each benchmark. Defaults to 100,000.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_bench(ctx, parser)
+    }
+}
+
+
+fn cmd_bench(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let iterations = match cmd_parser.value("iterations") {
+        Some(arg) => match arg.parse::<u64>() {
+            Ok(value) if value > 0 => value,
+            _ => {
+                let msg = "the number of iterations must be a positive integer.";
+                return Err(Error::Invalid(msg.to_string()));
+            }
+        },
+        None => 100_000,
+    };
+    let mut measurements = crate::bench::library(iterations);
+    measurements.push(bench_streaming(iterations)?);
+    let rows = measurements
+        .iter()
+        .map(|measurement| {
+            let rate = format!("{:.0}", measurement.iters_per_sec());
+            vec![
+                measurement.name.clone(),
+                format!("{:.1} ns/iter", measurement.nanos_per_iter()),
+                format!("{} iter/s", add_spacers(&rate, ',', 3)),
+            ]
+        })
+        .collect();
+    ctx.termline()?;
+    Report::new()
+        .field("itr", add_spacers(&iterations.to_string(), ',', 3))
+        .table(&["benchmark", "time", "rate"], rows)
+        .print(ctx)?;
+    ctx.termline()?;
+    Ok(())
+}
+
+
+// Times the default command's batch mode, streaming a generated buffer of integers, one per
+// iteration, through stream::for_each_token() and printing their info blocks to a sink, as for
+// piped input. A tenth as many integers are streamed first to warm up.
+fn bench_streaming(iterations: u64) -> Result<Measurement, Error> {
+    let inputs = ["42", "-1", "0xDEADBEEF", "0b1010_1010", "1<<40", "0x7FFF_FFFF_FFFF_FFFF"];
+    let buffer = |count: u64| {
+        let tokens: Vec<&str> = (0..count).map(|i| inputs[i as usize % inputs.len()]).collect();
+        tokens.join("\n").into_bytes()
+    };
+    let args = ["--group", "4"];
+    run_with_stdin(&args, &buffer(iterations / 10), &mut std::io::sink())?;
+    let buffer = buffer(iterations);
+    let start = Instant::now();
+    run_with_stdin(&args, &buffer, &mut std::io::sink())?;
+    Ok(Measurement { name: "batch streaming".to_string(), iterations, total: start.elapsed() })
+}
@@ -0,0 +1,172 @@
+use arguably::ArgParser;
+use crate::bin_string;
+use crate::clear_bits;
+use crate::cli::bit_marks;
+use crate::cli::Context;
+use crate::cli::display_bits;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::cli::parse_u32_option;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::layout;
+use crate::min_bits;
+use crate::report::Report;
+use crate::set_bits;
+use crate::std_bits;
+use crate::toggle_bits;
+
+
+const HELP: &str = "
+Usage: intspector bit <operation> <integer> <positions>
+
+  Sets, clears, toggles, or tests bits of an integer, showing the value
+  before and after in binary with the affected bits marked underneath.
+
+  Operations:
+
+  - set: Set the bits to 1.
+  - clear: Clear the bits to 0.
+  - toggle: Flip the bits.
+  - test: Report whether each bit is set, without changing the value.
+
+  Positions are numbered from 0, the least significant bit, up to 127. A
+  range of positions can be written <lo>-<hi> or <hi>:<lo>.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions. Negative integers are read as two's complement values.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "bit"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Set, clear, toggle, or test bits of integers."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("<operation>", "Operation to apply: set, clear, toggle, or test."),
+            ("<integer>", "Integer to operate on."),
+            ("<positions>", "List of bit positions or ranges, e.g. 0 3 8:15."),
+        ]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "bits b",
+                value: Some("n"),
+                help: "Width of the value in bits. Defaults to the width of the integer, or \
+                      enough to hold the highest position.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_bit(ctx, parser)
+    }
+}
+
+
+fn cmd_bit(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if cmd_parser.args.len() < 3 {
+        let msg = "expected an operation, an integer, and one or more bit positions.";
+        return Err(Error::Invalid(msg.to_string()));
+    }
+    let op = cmd_parser.args[0].to_lowercase();
+    if !["set", "clear", "toggle", "test"].contains(&op.as_str()) {
+        let msg = format!("unknown operation '{}', expected set, clear, toggle, or test.", op);
+        return Err(Error::Invalid(msg));
+    }
+    let mut positions = Vec::new();
+    for arg in &cmd_parser.args[2..] {
+        match layout::parse_range(arg) {
+            Ok((high, low)) => positions.extend(low..=high),
+            Err(msg) => {
+                return Err(Error::Invalid(format!("{}.", msg)));
+            }
+        }
+    }
+    let user_bits = parse_u32_option(cmd_parser, "bits")?;
+    ctx.termline()?;
+    match bit_info(&op, &cmd_parser.args[1], &positions, user_bits) {
+        Ok(report) => report.print(ctx)?,
+        Err(msg) => Report::error(&msg).print(ctx)?,
+    }
+    ctx.termline()?;
+    Ok(())
+}
+
+
+fn bit_info(
+    op: &str,
+    arg: &str,
+    positions: &[u32],
+    user_bits: Option<u32>,
+) -> Result<Report, String> {
+    let value = eval_arg(arg)?;
+    let top = positions.iter().max().unwrap() + 1;
+    let default_bits = if value < 0 { std_bits(value) } else { min_bits(value) };
+    let bits = user_bits.unwrap_or_else(|| default_bits.max(top));
+    if top > bits {
+        return Err(format!("bit {} is outside the {}-bit value.", top - 1, bits));
+    }
+    let (before, _) = display_bits(value, Some(bits))?;
+    let row = |value: u128| {
+        format!(
+            "{}  hex {:0width$X}  dec {}",
+            bin_string(value, bits),
+            value,
+            value,
+            width = bits.div_ceil(4) as usize,
+        )
+    };
+    let marks = bit_marks(positions, bits);
+    let after = match op {
+        "set" => set_bits(before, positions),
+        "clear" => clear_bits(before, positions),
+        "toggle" => toggle_bits(before, positions),
+        _ => {
+            let mut sorted = positions.to_vec();
+            sorted.sort_unstable_by(|a, b| b.cmp(a));
+            sorted.dedup();
+            let states: Vec<String> = sorted
+                .iter()
+                .map(|bit| {
+                    let state = if before >> bit & 1 == 1 { "set" } else { "clear" };
+                    format!("bit {} {}", bit, state)
+                })
+                .collect();
+            let count = sorted.iter().filter(|bit| before >> *bit & 1 == 1).count();
+            return Ok(Report::new()
+                .field("val", row(before))
+                .field("sel", marks)
+                .field("tst", states.join(", "))
+                .field("res", format!("{} of {} set", count, sorted.len())));
+        },
+    };
+    let changed = (before ^ after).count_ones();
+    let plural = |count: u32| if count == 1 { "" } else { "s" };
+    Ok(Report::new()
+        .field("bef", row(before))
+        .field("sel", marks)
+        .field("aft", row(after))
+        .field("chg", format!("{} bit{} changed", changed, plural(changed)))
+        .field("wid", format!("{} bit{}", bits, plural(bits))))
+}
@@ -0,0 +1,107 @@
+use arguably::ArgParser;
+use crate::byte_swap;
+use crate::cli::Context;
+use crate::cli::display_bits;
+use crate::cli::Error;
+use crate::cli::eval_input;
+use crate::cli::InfoOpts;
+use crate::cli::int_info;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+use crate::std_bits;
+
+
+const HELP: &str = "
+Usage: intspector bswap [integers]
+
+  Reverses the byte order of integers at a width of 16, 32, or 64 bits,
+  converting between big-endian and little-endian values, then displays
+  the result in all four bases, e.g. 0x12345678 becomes 0x78563412.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions. Negative integers are swapped as two's complement
+  values.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "bswap"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Swap the byte order of integers."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to swap.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "bits b",
+                value: Some("n"),
+                help: "Width in bits: 16, 32, or 64. Defaults to the smallest of these that holds \
+                      the integer.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_bswap(ctx, parser)
+    }
+}
+
+
+fn cmd_bswap(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let user_bits = match cmd_parser.value("bits") {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value @ (16 | 32 | 64)) => Some(value),
+            _ => {
+                return Err(Error::Invalid("the width must be 16, 32, or 64 bits.".to_string()));
+            }
+        },
+        None => None,
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = eval_input(arg).and_then(|value| Ok(bswap_info(value, user_bits)?));
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn bswap_info(value: i128, user_bits: Option<u32>) -> Result<Report, String> {
+    let bits = match user_bits {
+        Some(bits) => bits,
+        None => match std_bits(value) {
+            8 | 16 => 16,
+            bits if bits <= 64 => bits,
+            _ => return Err(format!("{} requires more than 64 bits.", value)),
+        },
+    };
+    let (disp_value, _) = display_bits(value, Some(bits))?;
+    let swapped = byte_swap(disp_value as u64, bits);
+    let hex_width = bits as usize / 4;
+    let opts = InfoOpts { bits: Some(bits), ..Default::default() };
+    let swap = format!("{:0w$X} → {:0w$X}", disp_value, swapped, w = hex_width);
+    Ok(Report::new().field("swp", swap).append(int_info(swapped as i128, &opts)))
+}
@@ -0,0 +1,103 @@
+use arguably::ArgParser;
+use crate::cli::be_bytes;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_input;
+use crate::cli::hex_bytes;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+use crate::std_bits;
+
+
+const HELP: &str = "
+Usage: intspector bytes [integers]
+
+  Shows the bytes of integers in big-endian and little-endian order, as a
+  hex dump and as escape sequences, e.g. DE AD BE EF and \\xde\\xad\\xbe\\xef
+  for 0xDEADBEEF in big-endian order.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions. Negative integers are shown as two's complement values.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "bytes"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Show the byte layout of integers in both byte orders."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to display.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "bits b",
+                value: Some("n"),
+                help: "Width in bits, a multiple of 8. Defaults to the smallest standard width, \
+                      8, 16, 32, 64, or 128 bits, that holds the integer.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_bytes(ctx, parser)
+    }
+}
+
+
+fn cmd_bytes(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let user_bits = match cmd_parser.value("bits") {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value) if value.is_multiple_of(8) && (8..=128).contains(&value) => Some(value),
+            _ => {
+                let msg = "the width must be a multiple of 8 from 8 to 128 bits.";
+                return Err(Error::Invalid(msg.to_string()));
+            }
+        },
+        None => None,
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = eval_input(arg).and_then(|value| Ok(bytes_info(value, user_bits)?));
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn bytes_info(value: i128, user_bits: Option<u32>) -> Result<Report, String> {
+    let bits = user_bits.unwrap_or_else(|| std_bits(value));
+    let big_endian = be_bytes(value, bits)?;
+    let little_endian: Vec<u8> = big_endian.iter().rev().copied().collect();
+    let escape = |bytes: &[u8]| -> String {
+        bytes.iter().map(|byte| format!("\\x{:02x}", byte)).collect()
+    };
+    let plural = if bits == 8 { "" } else { "s" };
+    Ok(Report::new()
+        .field("int", value)
+        .field("wid", format!("{} bits, {} byte{}", bits, bits / 8, plural))
+        .field("be", format!("{}   {}", hex_bytes(&big_endian), escape(&big_endian)))
+        .field("le", format!("{}   {}", hex_bytes(&little_endian), escape(&little_endian))))
+}
@@ -0,0 +1,119 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::radio;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector channel [channels]
+
+  Converts radio channel numbers to centre frequencies, showing the
+  arithmetic used. With the --freq flag, converts frequencies in MHz back to
+  channel numbers. By default, each value is converted for every band that
+  defines it.
+
+  Bands:
+
+  - wifi2g: Wi-Fi 2.4 GHz, channels 1-14.
+  - wifi5g: Wi-Fi 5 GHz, channels 1-196.
+  - wifi6g: Wi-Fi 6 GHz, channels 1-233.
+  - bt: Bluetooth BR/EDR, channels 0-78.
+  - ble: Bluetooth Low Energy, channel indices 0-39.
+  - us915: LoRaWAN US915 uplink, channels 0-71.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "channel"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert radio channels to frequencies."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[channels]", "List of channel numbers or frequencies.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "band b",
+                value: Some("name"),
+                help: "Restrict conversions to the named band.",
+            },
+            Opt { names: "freq f", value: None, help: "Convert frequencies in MHz to channels." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_channel(ctx, parser)
+    }
+}
+
+
+fn cmd_channel(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let bands: Vec<radio::Band> = match cmd_parser.value("band") {
+        Some(arg) => match radio::Band::from_name(&arg) {
+            Some(band) => vec![band],
+            None => {
+                return Err(Error::Invalid(format!("unknown band '{}'.", arg)));
+            }
+        },
+        None => radio::BANDS.to_vec(),
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        print_result(ctx, arg, channel_info(arg, &bands, cmd_parser.found("freq")))?;
+    }
+    Ok(())
+}
+
+
+// Lists the matching channel in each band, labelled with the band's name.
+fn channel_info(arg: &str, bands: &[radio::Band], freq: bool) -> Result<Report, String> {
+    let mut report = Report::new();
+    if freq {
+        let khz = radio::parse_mhz(arg)
+            .ok_or_else(|| format!("cannot parse '{}' as a frequency in MHz.", arg))?;
+        for band in bands {
+            if let Some(channel) = radio::khz_to_channel(*band, khz) {
+                let mhz = radio::format_mhz(khz);
+                report = report.field(band.name(), format!("{} MHz is channel {}", mhz, channel));
+            }
+        }
+    } else {
+        let channel = arg
+            .parse::<u32>()
+            .map_err(|_| format!("cannot parse '{}' as a channel number.", arg))?;
+        for band in bands {
+            if let Some((khz, formula)) = radio::channel_to_khz(*band, channel) {
+                let mhz = radio::format_mhz(khz);
+                report = report.field(band.name(), format!("{} = {} MHz", formula, mhz));
+            }
+        }
+    }
+    match report.items.is_empty() {
+        true => Err(format!("no matching channel for '{}'.", arg)),
+        false => Ok(report),
+    }
+}
@@ -0,0 +1,143 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::check;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector check [numbers]
+
+  Validates check digits. IBANs are validated using the ISO 7064 mod-97
+  algorithm: the first four characters are moved to the end, letters are
+  replaced by the numbers 10 to 35, and the resulting big integer must leave
+  a remainder of 1 when divided by 97. The intermediate integer is displayed
+  along with the correct check digits and the expected length for the
+  country.
+
+  Card numbers and other purely numeric input are validated using the Luhn
+  algorithm. The card network is identified from the leading digits using
+  the issuer ranges of Visa, Mastercard, American Express, Discover, Diners
+  Club, JCB, UnionPay, and Maestro.
+
+  Numbers can be written with or without spaces. The algorithm is chosen
+  automatically unless one of the flags below is specified.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "check"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Validate IBAN and Luhn check digits."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[numbers]", "List of numbers to validate.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "iban i", value: None, help: "Validate all arguments as IBANs." },
+            Opt {
+                names: "luhn l",
+                value: None,
+                help: "Validate all arguments using the Luhn algorithm.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_check(ctx, parser)
+    }
+}
+
+
+fn cmd_check(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let digits: String = arg.chars().filter(|c| *c != ' ' && *c != '-').collect();
+        let is_numeric = !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit());
+        let output = if cmd_parser.found("iban") {
+            iban_info(arg)
+        } else if cmd_parser.found("luhn") || is_numeric {
+            luhn_info(arg, &digits)
+        } else {
+            iban_info(arg)
+        };
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn iban_info(arg: &str) -> Result<Report, String> {
+    let iban = match check::iban_normalize(arg) {
+        Some(iban) => iban,
+        None => return Err(format!("'{}' is not a valid IBAN.", arg)),
+    };
+    let numeric = check::iban_numeric(&iban);
+    let remainder = check::mod97(&numeric).unwrap();
+    let check_digits = check::iban_check_digits(&iban);
+    let groups: Vec<&str> = iban
+        .as_bytes()
+        .chunks(4)
+        .map(|group| std::str::from_utf8(group).unwrap())
+        .collect();
+    let length = match check::iban_length(&iban[..2]) {
+        Some(length) if length == iban.len() => length.to_string(),
+        Some(length) => format!("{} (expected {})", iban.len(), length),
+        None => format!("{} (unknown country {})", iban.len(), &iban[..2]),
+    };
+    Ok(Report::new()
+        .field("ibn", groups.join(" "))
+        .field("num", add_spacers(&numeric, ',', 3))
+        .field("m97", format!(
+            "{} ({})",
+            remainder,
+            if remainder == 1 { "valid" } else { "invalid" }
+        ))
+        .field("chk", format!("{:02}", check_digits))
+        .field("len", length))
+}
+
+
+fn luhn_info(arg: &str, digits: &str) -> Result<Report, String> {
+    let sum = match check::luhn_sum(digits) {
+        Some(sum) => sum,
+        None => return Err(format!("cannot parse '{}' as a string of digits.", arg)),
+    };
+    let payload = &digits[..digits.len() - 1];
+    let mut report = Report::new()
+        .field("num", digits)
+        .field("lnh", format!("{} ({})", sum, if sum == 0 { "valid" } else { "invalid" }));
+    if let Some(check_digit) = check::luhn_check_digit(payload) {
+        report = report.field("chk", check_digit);
+    }
+    let network = match check::card_network(digits) {
+        Some((name, lengths)) if lengths.contains(&digits.len()) => name.to_string(),
+        Some((name, _)) => format!("{} (invalid length {})", name, digits.len()),
+        None => "unknown".to_string(),
+    };
+    Ok(report.field("net", network))
+}
@@ -0,0 +1,124 @@
+use arguably::ArgParser;
+use crate::checksum;
+use crate::cli::byte_inputs;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector checksum [strings]
+       intspector checksum --file <path>
+
+  Computes a battery of common CRCs and checksums of each string's UTF-8
+  bytes, or of a file's contents. With the --target option, reports which
+  of the algorithms produce the target value instead, including matches
+  with the target's bytes in reverse order.
+
+  The CRCs are the common 8-, 16-, 32-, and 64-bit variants from the CRC
+  RevEng catalogue, e.g. CRC-32/ISO-HDLC as used by zlib and Ethernet. The
+  checksums are byte sums with their two's and ones' complements, XOR, the
+  Internet checksum, Fletcher-16, and Adler-32.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "checksum"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Identify the checksum algorithm for a value."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[strings]", "List of strings to check.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "file f", value: Some("path"), help: "Check the contents of a file." },
+            Opt {
+                names: "target t",
+                value: Some("n"),
+                help: "Value to match, e.g. a checksum from a packet capture.",
+            },
+            Opt { names: "hex x", value: None, help: "Read the strings as hex-encoded bytes." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_checksum(ctx, parser)
+    }
+}
+
+
+fn cmd_checksum(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let target = match cmd_parser.value("target") {
+        Some(arg) => match eval_arg(&arg) {
+            Ok(value) if (0..=u64::MAX as i128).contains(&value) => Some(value as u64),
+            Ok(_) => {
+                return Err(Error::Invalid("the target must be from 0 to 2^64 - 1.".to_string()));
+            },
+            Err(msg) => {
+                return Err(Error::Invalid(msg));
+            }
+        },
+        None => None,
+    };
+    let inputs = byte_inputs(ctx, cmd_parser)?;
+    if !inputs.is_empty() {
+        ctx.termline()?;
+    }
+    for (arg, input) in inputs {
+        print_result(ctx, &arg, input.map(|bytes| checksum_info(&bytes, target)))?;
+    }
+    Ok(())
+}
+
+
+// Lists every catalogued checksum of the bytes, or with a target, the ones that match it.
+fn checksum_info(bytes: &[u8], target: Option<u64>) -> Report {
+    let plural = if bytes.len() == 1 { "" } else { "s" };
+    let report = Report::new().field("len", format!("{} byte{}", bytes.len(), plural));
+    let target = match target {
+        Some(target) => target,
+        None => {
+            let rows = checksum::all_checksums(bytes)
+                .into_iter()
+                .map(|(name, width, value)| {
+                    let digits = (width / 4) as usize;
+                    vec![name.to_string(), format!("{:0digits$X}", value, digits = digits)]
+                })
+                .collect();
+            return report.table(&["algorithm", "value"], rows);
+        },
+    };
+    let mut report = report.field("tgt", format!("0x{:X}", target));
+    let matches = checksum::find_matches(bytes, target);
+    if matches.is_empty() {
+        let count = checksum::CRC_PRESETS.len() + checksum::CHECKSUMS.len();
+        return report.field("mat", format!("none of the {} algorithms", count));
+    }
+    for (name, swapped) in matches {
+        let note = if swapped { " (bytes reversed)" } else { "" };
+        report = report.field("mat", format!("{}{}", name, note));
+    }
+    report
+}
@@ -0,0 +1,111 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::mode;
+use crate::parse_uint;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector chmod [modes]
+
+  Converts Unix file modes between octal and the symbolic notation shown by
+  ls -l, e.g. 755 and rwxr-xr-x. Lists the permissions of the owner, group,
+  and others, decodes the setuid, setgid, and sticky bits, and shows the
+  binary layout of the mode word. Full st_mode values with file type bits,
+  e.g. 100644 or drwxr-xr-x, also show the file type.
+
+  In symbolic notation, s in an execute position means setuid or setgid
+  with execute permission, and t means sticky with execute permission. The
+  uppercase S and T mean the special bit without execute permission.
+
+  Digits are read as octal, with or without a leading 0 or 0o. Modes can
+  also be specified as binary or hexadecimal integers.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "chmod"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert between octal and symbolic file modes."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[modes]", "List of octal or symbolic modes.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_chmod(ctx, parser)
+    }
+}
+
+
+fn cmd_chmod(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = parse_mode(arg).map(mode_info);
+        print_result(ctx, arg, output.ok_or(format!("cannot parse '{}' as a file mode.", arg)))?;
+    }
+    Ok(())
+}
+
+
+fn parse_mode(arg: &str) -> Option<u32> {
+    if arg.starts_with("0x") || arg.starts_with("0b") {
+        let value = parse_uint(arg).ok()?;
+        return Some(value as u32).filter(|mode| *mode as u64 == value && *mode <= 0o177777);
+    }
+    mode::parse_octal(arg).or_else(|| mode::parse_symbolic(arg))
+}
+
+
+fn mode_info(value: u32) -> Report {
+    let file_type = mode::file_type(value);
+    let list = |names: Vec<&str>| match names.is_empty() {
+        true => "none".to_string(),
+        false => names.join(", "),
+    };
+    let mut report = match file_type {
+        Some(_) => Report::new().field("oct", format!("{:06o}", value)),
+        None => Report::new().field("oct", format!("{:04o}", value)),
+    };
+    report = report.field("sym", mode::symbolic(value));
+    if let Some((_, _, description)) = file_type {
+        report = report.field("typ", description);
+    } else if value & mode::S_IFMT != 0 {
+        report = report.field("typ", format!("unknown ({:o})", value & mode::S_IFMT));
+    }
+    report = report
+        .field("usr", list(mode::permissions(value >> 6 & 0o7)))
+        .field("grp", list(mode::permissions(value >> 3 & 0o7)))
+        .field("oth", list(mode::permissions(value & 0o7)))
+        .field("spc", list(mode::specials(value)));
+    let groups: Vec<String> =
+        (0..4).rev().map(|i| format!("{:03b}", value >> (i * 3) & 0o7)).collect();
+    // The legend under the digits is a continuation line of the binary value.
+    match value & mode::S_IFMT != 0 {
+        true => report.field(
+            "bin",
+            format!("{:04b} {}\ntype sst rwx rwx rwx", value >> 12, groups.join(" ")),
+        ),
+        false => report.field("bin", format!("{}\nsst rwx rwx rwx", groups.join(" "))),
+    }
+}
@@ -0,0 +1,143 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::bigint::BigUint;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::combinatorics;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_uint;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector choose <n> <k>
+
+  Computes the binomial coefficient C(n, k), the number of ways to choose k
+  items from n, and the number of permutations P(n, k) = n! / (n - k)!, the
+  number of ordered arrangements of k items from n. Results are computed
+  exactly, with no overflow, and displayed in decimal and hexadecimal.
+
+  With the --multi flag, the arguments are read as group sizes k1, k2, ...
+  and the multinomial coefficient (k1 + k2 + ...)! / (k1! k2! ...) is
+  computed instead.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "choose"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Compute binomial and multinomial coefficients."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("<n>", "Number of items."), ("<k>", "Number of items to choose.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "multi m", value: None, help: "Compute a multinomial coefficient." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_choose(ctx, parser)
+    }
+}
+
+
+// The largest number of factors computed for a single result, which keeps results to tens of
+// thousands of digits.
+const MAX_FACTORS: u32 = 10_000;
+
+
+fn cmd_choose(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let mut values = Vec::new();
+    for arg in &cmd_parser.args {
+        match parse_uint(arg) {
+            Ok(value) if value <= u32::MAX as u64 => values.push(value as u32),
+            Ok(_) => {
+                let msg = format!("'{}' is out of range for a 32-bit unsigned integer.", arg);
+                return Err(Error::Invalid(msg));
+            },
+            Err(err) => {
+                return Err(Error::Invalid(format!("cannot parse '{}': {}.", arg, err)));
+            }
+        }
+    }
+    if cmd_parser.found("multi") {
+        if values.is_empty() {
+            return Ok(());
+        }
+        let total: u64 = values.iter().map(|k| *k as u64).sum();
+        let list: Vec<String> = values.iter().map(|k| k.to_string()).collect();
+        ctx.termline()?;
+        let report = Report::new().field("grp", format!("{} (n = {})", list.join(", "), total));
+        let largest = values.iter().max().copied().unwrap_or(0) as u64;
+        let report = if total - largest > MAX_FACTORS as u64 {
+            report.and_error("the result is too large to compute.")
+        } else {
+            match combinatorics::multinomial(&values) {
+                Some(result) => report.field("mul", big_info(&result)),
+                None => report.and_error("the total overflows 32 bits."),
+            }
+        };
+        report.print(ctx)?;
+        ctx.termline()?;
+        return Ok(());
+    }
+    if values.len() != 2 {
+        return Err(Error::Invalid("expected two integers, n and k.".to_string()));
+    }
+    let (n, k) = (values[0], values[1]);
+    ctx.termline()?;
+    let report = Report::new().field("inp", format!("n = {}, k = {}", n, k));
+    let too_large = "too large to compute";
+    let report = match k.min(n.saturating_sub(k)) > MAX_FACTORS {
+        true => report.field("cmb", too_large),
+        false => report.field("cmb", big_info(&combinatorics::binomial(n, k))),
+    };
+    let report = match k > MAX_FACTORS {
+        true => report.field("per", too_large),
+        false => report.field("per", big_info(&combinatorics::permutations(n, k))),
+    };
+    report.print(ctx)?;
+    ctx.termline()?;
+    Ok(())
+}
+
+
+// Formats a big integer in decimal and hex, with its size, on three lines.
+fn big_info(value: &BigUint) -> String {
+    let dec = value.to_string();
+    let digits = dec.len();
+    let bits = value.bits().max(1);
+    let digits_plural = if digits == 1 { "" } else { "s" };
+    let bits_plural = if bits == 1 { "" } else { "s" };
+    format!(
+        "{}\n0x{}\n{} digit{}, {} bit{}",
+        add_spacers(&dec, ',', 3),
+        value.to_hex(),
+        digits,
+        digits_plural,
+        bits,
+        bits_plural
+    )
+}
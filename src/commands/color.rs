@@ -0,0 +1,135 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_input;
+use crate::cli::print_result;
+use crate::color;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector color [colors]
+
+  Interprets integers as packed color values, showing the red, green, blue,
+  and alpha components in decimal and binary, the color as a CSS hex code,
+  the nearest color in the xterm 256-color palette, and the RGB565 encoding
+  used by many embedded displays. With the --swatch flag, also renders the
+  color as a swatch using 24-bit terminal colors.
+
+  The --layout option selects the pixel layout:
+
+  - rgb888: 24 bits, 8 bits per channel. (Default.)
+  - rgb565: 16 bits, with 5 bits of red, 6 of green, and 5 of blue.
+  - argb8888: 32 bits, with 8 bits of alpha above the color channels.
+
+  Channels narrower than 8 bits are scaled to 8 bits by replicating their
+  high bits, so that e.g. the 5-bit value 31 becomes 255.
+
+  Colors can be written as CSS hex codes, e.g. #FF8000, or as integers in
+  binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "color"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode RGB888, RGB565, and ARGB8888 color values."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[colors]", "List of colors.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "layout l",
+                value: Some("name"),
+                help: "Pixel layout: rgb888, rgb565, or argb8888. Defaults to rgb888.",
+            },
+            Opt { names: "swatch s", value: None, help: "Render a swatch of each color." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_color(ctx, parser)
+    }
+}
+
+
+fn cmd_color(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let layout = cmd_parser.value("layout").unwrap_or_else(|| "rgb888".to_string());
+    if !color::LAYOUTS.iter().any(|(name, _)| name.eq_ignore_ascii_case(&layout)) {
+        let msg = format!("expected rgb888, rgb565, or argb8888 for --layout, found '{}'.", layout);
+        return Err(Error::Invalid(msg));
+    }
+    // Swatches are escape codes, so they're only shown in text output.
+    let swatch = cmd_parser.found("swatch") && ctx.style.format == report::Format::Text;
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let value = match arg.strip_prefix('#') {
+            Some(digits) => u32::from_str_radix(digits, 16)
+                .map_err(|_| Report::from(format!("cannot parse '{}' as a hex color code.", arg))),
+            None => eval_input(arg).and_then(|value| match value {
+                0..=0xFFFF_FFFF => Ok(value as u32),
+                _ => Err(Report::from(format!("{} is out of range for a color.", value))),
+            }),
+        };
+        print_result(ctx, arg, value.and_then(|value| Ok(color_info(value, &layout, swatch)?)))?;
+    }
+    Ok(())
+}
+
+
+fn color_info(value: u32, layout: &str, swatch: bool) -> Result<Report, String> {
+    let channels = color::channels(value, layout).map_err(|msg| format!("{}.", msg))?;
+    let decoded = color::decode(value, layout)?;
+    let mut report = Report::new();
+    for (label, channel) in ["red", "grn", "blu", "alp"].iter().zip(channels) {
+        if let Some(channel) = channel {
+            let bits = format!("{:0width$b}", channel.raw, width = channel.bits as usize);
+            // Narrow channels show their raw value alongside the scaled value.
+            let value = match channel.bits {
+                8 => format!("{} ({})", channel.raw, bits),
+                n => format!("{} ({}, {} of {})", channel.to_u8(), bits, channel.raw, (1 << n) - 1),
+            };
+            report = report.field(label, value);
+        }
+    }
+    let css = format!("#{:02X}{:02X}{:02X}", decoded.r, decoded.g, decoded.b);
+    report = match decoded.a {
+        Some(alpha) => report.field("css", format!("{}{:02X}", css, alpha)),
+        None => report.field("css", css),
+    };
+    let ansi = color::nearest_ansi256(decoded);
+    let (r, g, b) = color::ansi256_rgb(ansi);
+    report = report
+        .field("ans", format!("{} (#{:02X}{:02X}{:02X})", ansi, r, g, b))
+        .field("enc", format!("0x{:04X} (rgb565)", color::to_rgb565(decoded)));
+    if swatch {
+        let (r, g, b) = (decoded.r, decoded.g, decoded.b);
+        let block = " ".repeat(12);
+        report = report.field("swt", format!("\x1B[48;2;{};{};{}m{}\x1B[0m", r, g, b, block));
+    }
+    Ok(report)
+}
@@ -0,0 +1,154 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::cli::print_result;
+use crate::coding;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_int;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector cost [integers]
+
+  Reports the size of each integer under a range of encodings, to help
+  choose an encoding for a protocol or file format:
+
+  - fix: The smallest fixed width of 1, 2, 4, or 8 bytes that holds the
+    integer, signed if the integer is negative.
+  - var: Unsigned LEB128, the varint used by protobuf, WebAssembly, and
+    DWARF, which stores 7 bits per byte. Negative integers are
+    sign-extended to 64 bits, as protobuf does for int64 fields.
+  - zzv: Zigzag encoding followed by a varint, as used for protobuf sint
+    fields, which maps 0, -1, 1, -2, ... to 0, 1, 2, 3, ...
+  - gam, dlt: The Elias gamma and delta universal codes, which encode
+    integers from 1 upwards.
+  - ric: The Golomb-Rice code with parameter k, which writes the integer
+    shifted right by k bits in unary, then its low k bits. Defaults to the
+    parameter that gives the shortest code for the integer.
+
+  Bit-oriented codes are shown in bits and in whole bytes. Integers can be
+  specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "cost"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Compare the encoded sizes of integers."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to measure.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "rice k",
+                value: Some("k"),
+                help: "Golomb-Rice parameter, from 0 to 127.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_cost(ctx, parser)
+    }
+}
+
+
+fn cmd_cost(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let rice = match cmd_parser.value("rice") {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value) if value < 128 => Some(value),
+            _ => {
+                let msg = "the Rice parameter must be an integer from 0 to 127.";
+                return Err(Error::Invalid(msg.to_string()));
+            }
+        },
+        None => None,
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = parse_int(arg).map_err(|err| parse_error(arg, &err));
+        print_result(ctx, arg, output.map(|value| cost_info(value, rice)))?;
+    }
+    Ok(())
+}
+
+
+fn cost_info(value: i128, rice: Option<u32>) -> Report {
+    let plural = |count: u128| if count == 1 { "" } else { "s" };
+    let bits_and_bytes = |bits: u128| {
+        let bytes = bits.div_ceil(8);
+        format!("{} bit{}, {} byte{}", bits, plural(bits), bytes, plural(bytes))
+    };
+    let report = Report::new().field("int", value);
+
+    let fixed = [1u32, 2, 4, 8].iter().copied().find(|bytes| {
+        let bits = bytes * 8;
+        if value < 0 {
+            value >= -(1i128 << (bits - 1))
+        } else {
+            value < 1i128 << bits
+        }
+    });
+    let report = match fixed {
+        Some(bytes) => {
+            let kind = if value < 0 { 'i' } else { 'u' };
+            let fix = format!("{} byte{} ({}{})", bytes, plural(bytes as u128), kind, bytes * 8);
+            report.field("fix", fix)
+        },
+        None => report.field("fix", "does not fit in 8 bytes"),
+    };
+
+    let report = if value >= 0 {
+        let len = coding::varint_len(value as u128);
+        report.field("var", format!("{} byte{}", len, plural(len as u128)))
+    } else if value >= i64::MIN as i128 {
+        let len = coding::varint_len(value as i64 as u64 as u128);
+        report.field("var", format!("{} bytes (sign-extended to 64 bits)", len))
+    } else {
+        report.field("var", "not encodable, below the 64-bit range")
+    };
+
+    let zigzag = coding::zigzag(value);
+    let len = coding::varint_len(zigzag);
+    let zigzag = format!("{} byte{} (zigzag {})", len, plural(len as u128), zigzag);
+    let report = report.field("zzv", zigzag);
+
+    let report = match coding::elias_gamma_bits(value.max(0) as u128) {
+        Some(bits) => report.field("gam", bits_and_bytes(bits as u128)),
+        None => report.field("gam", "not encodable, the code starts at 1"),
+    };
+    let report = match coding::elias_delta_bits(value.max(0) as u128) {
+        Some(bits) => report.field("dlt", bits_and_bytes(bits as u128)),
+        None => report.field("dlt", "not encodable, the code starts at 1"),
+    };
+    if value < 0 {
+        return report.field("ric", "not encodable, the code starts at 0");
+    }
+    let k = rice.unwrap_or_else(|| coding::best_rice_parameter(value as u128));
+    let bits = coding::rice_bits(value as u128, k);
+    report.field("ric", format!("{} (k = {})", bits_and_bytes(bits), k))
+}
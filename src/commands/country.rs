@@ -0,0 +1,131 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::country;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector country [codes]
+
+  Looks up ISO 3166-1 country codes. Accepts numeric codes, e.g. 826,
+  alpha-2 and alpha-3 codes, e.g. GB or GBR, or country names. Displays all
+  three codes and the country's mobile country codes (MCCs).
+
+  With the --mcc flag, looks up ITU E.212 mobile country codes instead, e.g.
+  234. MCC-MNC pairs, written as 234-15 or 23415, are looked up in a table
+  of common mobile networks and are recognised without the flag.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "country"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Look up country codes and mobile network codes."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[codes]", "List of country codes or MCC-MNC pairs.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "mcc m",
+                value: None,
+                help: "Interpret 3-digit codes as mobile country codes.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_country(ctx, parser)
+    }
+}
+
+
+fn cmd_country(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let is_numeric = arg.chars().all(|c| c.is_ascii_digit());
+        let output = if let Some((mcc, mnc)) = country::parse_network(arg) {
+            network_info(mcc, mnc)
+        } else if cmd_parser.found("mcc") && is_numeric {
+            match arg.parse::<u16>().ok().and_then(country::mcc_country) {
+                Some(alpha2) => Ok(mcc_info(arg, alpha2)),
+                None => Err(format!("unknown mobile country code '{}'.", arg)),
+            }
+        } else {
+            let found = if is_numeric {
+                arg.parse::<u16>().ok().and_then(country::country_by_numeric)
+            } else {
+                country::country_by_code(arg)
+            };
+            match found {
+                Some(country) => Ok(country_info(country)),
+                None => Err(format!("unknown country code '{}'.", arg)),
+            }
+        };
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn country_info((numeric, alpha2, alpha3, name): country::Country) -> Report {
+    let report = Report::new()
+        .field("num", format!("{:03}", numeric))
+        .field("al2", alpha2)
+        .field("al3", alpha3)
+        .field("nam", name);
+    let mccs: Vec<String> = country::country_mccs(alpha2).iter().map(u16::to_string).collect();
+    match mccs.is_empty() {
+        true => report,
+        false => report.field("mcc", mccs.join(", ")),
+    }
+}
+
+
+fn mcc_info(mcc: &str, alpha2: &str) -> Report {
+    let name = country::country_by_code(alpha2).map_or("", |country| country.3);
+    let mut report = Report::new().field("mcc", mcc).field("cty", format!("{} ({})", alpha2, name));
+    for (_, mnc, operator) in country::NETWORKS.iter().filter(|(m, _, _)| *m == mcc) {
+        report = report.field("net", format!("{}-{} {}", mcc, mnc, operator));
+    }
+    report
+}
+
+
+fn network_info(mcc: &str, mnc: &str) -> Result<Report, String> {
+    let alpha2 = mcc.parse::<u16>().ok().and_then(country::mcc_country);
+    let operator = country::network(mcc, mnc);
+    if alpha2.is_none() && operator.is_none() {
+        return Err(format!("unknown mobile network '{}-{}'.", mcc, mnc));
+    }
+    let mut report = Report::new().field("mcc", mcc);
+    if let Some(alpha2) = alpha2 {
+        let name = country::country_by_code(alpha2).map_or("", |country| country.3);
+        report = report.field("cty", format!("{} ({})", alpha2, name));
+    }
+    Ok(report.field("mnc", mnc).field("net", operator.unwrap_or("unknown")))
+}
@@ -0,0 +1,86 @@
+use arguably::ArgParser;
+use crate::ascii;
+use crate::cli::char_encodings;
+use crate::cli::char_properties;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::parse_int;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector cp2l|codepoint-to-literal [integers]
+
+  Converts unicode code points to character literals. Code points can be
+  specified in binary, octal, decimal, or hexadecimal base.
+
+  Also shows each character's UTF-8 bytes and UTF-16 code units, its
+  general category and block, and its name for the common blocks, CJK
+  unified ideographs, and Hangul syllables.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "cp2l codepoint-to-literal"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert code points to character literals."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of unicode code points.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_cp2l(ctx, parser)
+    }
+}
+
+
+fn cmd_cp2l(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        print_result(ctx, arg, cp2l_info(arg))?;
+    }
+    Ok(())
+}
+
+
+fn cp2l_info(arg: &str) -> Result<Report, String> {
+    let arg_as_i64 = parse_int(arg).map_err(|err| format!("cannot parse '{}': {}.", arg, err))?;
+    if !(0..=0xFFFF_FFFF).contains(&arg_as_i64) {
+        return Err(format!("invalid input '{}'.", arg));
+    }
+    if let Some(ascii) = ascii(arg_as_i64) {
+        return Ok(Report::new()
+            .field("uni", format!("U+{:04X}", arg_as_i64))
+            .field("lit", ascii)
+            .append(char_encodings(arg_as_i64 as u8 as char))
+            .append(char_properties(arg_as_i64 as u8 as char)));
+    }
+    let arg_as_u32 = arg_as_i64 as u32;
+    let arg_as_char = std::char::from_u32(arg_as_u32)
+        .ok_or_else(|| format!("{} is not a valid unicode scalar value.", arg_as_u32))?;
+    Ok(Report::new()
+        .field("uni", format!("U+{:04X}", arg_as_u32))
+        .field("lit", arg_as_char)
+        .append(char_encodings(arg_as_char))
+        .append(char_properties(arg_as_char)))
+}
@@ -0,0 +1,94 @@
+use arguably::ArgParser;
+use crate::checksum;
+use crate::cli::byte_inputs;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::InfoOpts;
+use crate::cli::print_result;
+use crate::cli::uint_info;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector crc [strings]
+       intspector crc --file <path>
+
+  Computes a CRC or checksum of each string's UTF-8 bytes, or of a file's
+  contents, and displays the result in all four bases.
+
+  The algorithm can be one of the short names crc8, crc16, crc32, crc32c,
+  crc64, sum8, xor, fletcher16, or adler32, or any algorithm listed by the
+  checksum command, e.g. CRC-16/MODBUS. The short names stand for
+  CRC-8/SMBUS, CRC-16/ARC, CRC-32/ISO-HDLC, CRC-32/ISCSI, CRC-64/XZ, SUM-8,
+  XOR-8, FLETCHER-16, and ADLER-32.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "crc"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Compute a CRC or checksum of byte strings and files."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[strings]", "List of strings to check.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "algo a",
+                value: Some("name"),
+                help: "Algorithm to compute. Defaults to crc32.",
+            },
+            Opt { names: "file f", value: Some("path"), help: "Check the contents of a file." },
+            Opt { names: "hex x", value: None, help: "Read the strings as hex-encoded bytes." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_crc(ctx, parser)
+    }
+}
+
+
+fn cmd_crc(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let algo = cmd_parser.value("algo").unwrap_or_else(|| "crc32".to_string());
+    if checksum::compute_named(&algo, &[]).is_none() {
+        return Err(Error::Invalid(format!("unknown algorithm '{}'.", algo)));
+    }
+    let inputs = byte_inputs(ctx, cmd_parser)?;
+    if !inputs.is_empty() {
+        ctx.termline()?;
+    }
+    let opts = InfoOpts::default();
+    for (arg, input) in inputs {
+        let output = input.map(|bytes| {
+            let (name, width, value) = checksum::compute_named(&algo, &bytes).unwrap();
+            let plural = if bytes.len() == 1 { "" } else { "s" };
+            Report::new()
+                .field("alg", name)
+                .field("len", format!("{} byte{}", bytes.len(), plural))
+                .append(uint_info(value as u128, width, &opts))
+        });
+        print_result(ctx, &arg, output)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::datetime;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector date [dates]
+
+  Decodes dates packed into integers, either as YYYYMMDD calendar dates, e.g.
+  20240315, or as YYYYDDD ordinal dates, e.g. 2024075. Displays the date with
+  its day of the week, day of the year, ISO 8601 week, and the number of days
+  since the Unix epoch.
+
+  With the --between flag, also displays the number of days between each
+  pair of consecutive dates.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "date"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode packed YYYYMMDD and YYYYDDD dates."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[dates]", "List of packed dates.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "between b",
+                value: None,
+                help: "Display the days between consecutive dates.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_date(ctx, parser)
+    }
+}
+
+
+fn cmd_date(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    let mut previous: Option<(i64, String)> = None;
+    for arg in &cmd_parser.args {
+        let date = arg.parse::<u64>().ok().and_then(|packed| match arg.len() {
+            8 => datetime::unpack_date(packed),
+            7 => datetime::unpack_ordinal(packed),
+            _ => None,
+        });
+        let (year, month, day) = match date {
+            Some(date) => date,
+            None => {
+                Report::error(&format!("'{}' is not a valid YYYYMMDD or YYYYDDD date.", arg))
+                    .input(arg)
+                    .print(ctx)?;
+                ctx.termline()?;
+                continue;
+            }
+        };
+        let days = datetime::days_from_civil(year, month, day);
+        let weekday = datetime::iso_weekday(days);
+        let ordinal = datetime::day_of_year(year, month, day);
+        let (week_year, week) = datetime::iso_week(year, month, day);
+        let formatted = format!("{:04}-{:02}-{:02}", year, month, day);
+        let length = if datetime::is_leap_year(year) { 366 } else { 365 };
+        let mut report = Report::new()
+            .field("dat", format!("{} ({})", formatted, datetime::weekday_name(weekday)))
+            .field("doy", format!("{} of {} ({:04}{:03})", ordinal, length, year, ordinal))
+            .field("iso", format!("{:04}-W{:02}-{}", week_year, week, weekday))
+            .field("unx", format!("{} days since 1970-01-01", days));
+        if cmd_parser.found("between") {
+            if let Some((prev_days, prev_formatted)) = &previous {
+                let since = format!("{} days since {}", days - prev_days, prev_formatted);
+                report = report.field("btw", since);
+            }
+        }
+        report.input(arg).print(ctx)?;
+        previous = Some((days, formatted));
+        ctx.termline()?;
+    }
+    Ok(())
+}
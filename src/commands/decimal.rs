@@ -0,0 +1,158 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::decimal;
+use crate::min_bits_unsigned;
+use crate::parse_u128;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector decimal [values]
+
+  Decodes IEEE 754 decimal floating-point values from their bit patterns,
+  breaking out the sign, combination field, exponent continuation, and
+  coefficient continuation. By default, each value is decoded using both the
+  densely packed decimal (DPD) and binary integer decimal (BID) encodings.
+
+  Bit patterns can be specified in binary, octal, decimal, or hexadecimal
+  base, e.g. 0x2238000000000001.
+
+  Formats:
+
+  - d32: decimal32, 7 digits.
+  - d64: decimal64, 16 digits.
+  - d128: decimal128, 34 digits.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "decimal"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode IEEE 754 decimal floating-point values."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[values]", "List of bit patterns to decode.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "format f",
+                value: Some("name"),
+                help: "Decimal format. Defaults to d64.",
+            },
+            Opt { names: "bid", value: None, help: "Decode using the BID encoding only." },
+            Opt { names: "dpd", value: None, help: "Decode using the DPD encoding only." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_decimal(ctx, parser)
+    }
+}
+
+
+fn cmd_decimal(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let format = match cmd_parser.value("format") {
+        Some(arg) => match decimal::Format::from_name(&arg) {
+            Some(format) => format,
+            None => {
+                return Err(Error::Invalid(format!("unknown decimal format '{}'.", arg)));
+            }
+        },
+        None => decimal::Format::Decimal64,
+    };
+    let encodings = match (cmd_parser.found("dpd"), cmd_parser.found("bid")) {
+        (true, false) => vec![decimal::Encoding::Dpd],
+        (false, true) => vec![decimal::Encoding::Bid],
+        _ => vec![decimal::Encoding::Dpd, decimal::Encoding::Bid],
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = match parse_u128(arg) {
+            Ok(bits) if min_bits_unsigned(bits) <= format.bits() => {
+                let report = decimal_fields_info(bits, format);
+                Ok(encodings.iter().fold(report, |report, encoding| {
+                    report.append(decimal_info(bits, format, *encoding))
+                }))
+            },
+            _ => Err(format!("cannot parse '{}' as a {}-bit pattern.", arg, format.bits())),
+        };
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+// Returns the raw field breakdown of a decimal floating-point value.
+fn decimal_fields_info(bits: u128, format: decimal::Format) -> Report {
+    let (w, t) = (format.continuation_bits(), format.trailing_bits());
+    let fields = decimal::fields(bits, format);
+    let bin = format!("{:0width$b}", bits, width = format.bits() as usize);
+    let (head, tail) = bin.split_at(6 + w as usize);
+    let layout = format!("{} {} {} {}", &head[..1], &head[1..6], &head[6..], tail);
+    let name = format!("{} ({} digits, bias {})", format.name(), format.precision(), format.bias());
+    let width = t.div_ceil(4) as usize;
+    Report::new()
+        .field("fmt", name)
+        .field("hex", format!("0x{:0width$X}", bits, width = format.bits() as usize / 4))
+        .field("bin", layout)
+        .field("sgn", fields.sign)
+        .field("cmb", format!("{:05b}", fields.combination))
+        .field("ecn", format!("{:0width$b}", fields.continuation, width = w as usize))
+        .field("ccn", format!("{:0width$X} ({} bits)", fields.trailing, t, width = width))
+}
+
+
+// Returns the decoded value of a decimal floating-point value in the specified encoding.
+fn decimal_info(bits: u128, format: decimal::Format, encoding: decimal::Encoding) -> Report {
+    let value = decimal::decode(bits, format, encoding);
+    let name = match encoding {
+        decimal::Encoding::Dpd => "DPD",
+        decimal::Encoding::Bid => "BID",
+    };
+    let class = match value.class {
+        decimal::Class::Finite => "finite",
+        decimal::Class::Infinite => "infinity",
+        decimal::Class::QuietNan => "quiet NaN",
+        decimal::Class::SignalingNan => "signaling NaN",
+    };
+    let canonical = if value.canonical { "canonical" } else { "non-canonical" };
+    let report =
+        Report::new().field("enc", name).field("cls", format!("{} ({})", class, canonical));
+    let report = match value.class {
+        decimal::Class::Finite => {
+            let biased = value.exponent + format.bias();
+            report
+                .field("exp", format!("{} (biased {})", value.exponent, biased))
+                .field("cof", value.coefficient)
+        },
+        decimal::Class::QuietNan | decimal::Class::SignalingNan => {
+            report.field("pay", value.coefficient)
+        },
+        decimal::Class::Infinite => report,
+    };
+    report.field("val", value.to_sci_string())
+}
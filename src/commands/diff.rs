@@ -0,0 +1,108 @@
+use arguably::ArgParser;
+use crate::bin_string;
+use crate::cli::Context;
+use crate::cli::display_bits;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::cli::parse_u32_option;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::min_bits;
+use crate::report::Report;
+use crate::std_bits;
+
+
+const HELP: &str = "
+Usage: intspector diff <a> <b>
+
+  Compares two integers bit by bit, showing both values in aligned binary
+  with their XOR underneath, the positions of the bits that differ, and the
+  number of differing bits.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions. Negative integers are compared as two's complement
+  values.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "diff"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Compare two integers bit by bit."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("<a>", "First integer."), ("<b>", "Second integer.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "bits b",
+                value: Some("n"),
+                help: "Number of binary digits to compare. Defaults to the width of the wider \
+                      value.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_diff(ctx, parser)
+    }
+}
+
+
+fn cmd_diff(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if cmd_parser.args.len() != 2 {
+        return Err(Error::Invalid("expected two integers to compare.".to_string()));
+    }
+    let user_bits = parse_u32_option(cmd_parser, "bits")?;
+    ctx.termline()?;
+    match diff_info(&cmd_parser.args[0], &cmd_parser.args[1], user_bits) {
+        Ok(report) => report.print(ctx)?,
+        Err(msg) => Report::error(&msg).print(ctx)?,
+    }
+    ctx.termline()?;
+    Ok(())
+}
+
+
+// Returns the bitwise comparison of two integers.
+fn diff_info(lhs: &str, rhs: &str, user_bits: Option<u32>) -> Result<Report, String> {
+    let lhs = eval_arg(lhs)?;
+    let rhs = eval_arg(rhs)?;
+    let default_bits = |value: i128| if value < 0 { std_bits(value) } else { min_bits(value) };
+    let bits = user_bits.unwrap_or_else(|| default_bits(lhs).max(default_bits(rhs)));
+    let (lhs_bits, _) = display_bits(lhs, Some(bits))?;
+    let (rhs_bits, _) = display_bits(rhs, Some(bits))?;
+    let xor = lhs_bits ^ rhs_bits;
+    let positions: Vec<String> = (0..bits)
+        .filter(|i| xor >> i & 1 == 1)
+        .map(|i| i.to_string())
+        .collect();
+    let report = Report::new()
+        .field("lhs", format!("{} ({})", bin_string(lhs_bits, bits), lhs))
+        .field("rhs", format!("{} ({})", bin_string(rhs_bits, bits), rhs))
+        .field("xor", format!("{} (0x{:X})", bin_string(xor, bits), xor));
+    let report = match positions.is_empty() {
+        true => report.field("pos", "none, the values are identical"),
+        false => report.field("pos", positions.join(", ")),
+    };
+    let plural = if positions.len() == 1 { "" } else { "s" };
+    Ok(report.field("cnt", format!("{} bit{} differ", positions.len(), plural)))
+}
@@ -0,0 +1,97 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::numbering;
+use crate::parse_int;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector digits [integers]
+
+  Transliterates the decimal digits of each integer into other numbering
+  systems, e.g. Arabic-Indic, Devanagari, or fullwidth digits. Input can be
+  written using the digits of any supported numbering system, so this
+  command also converts back to ASCII.
+
+  Numbering systems are identified by their CLDR names: latn, arab, arabext,
+  deva, beng, guru, gujr, orya, tamldec, telu, knda, mlym, thai, laoo, tibt,
+  mymr, khmr, mong, fullwide.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "digits"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Transliterate digits into other numbering systems."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to transliterate.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "system s",
+                value: Some("name"),
+                help: "Numbering system to display. Defaults to all.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_digits(ctx, parser)
+    }
+}
+
+
+fn cmd_digits(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let systems: Vec<&(&str, u32)> = match cmd_parser.value("system") {
+        Some(arg) => match numbering::SYSTEMS.iter().find(|(name, _)| *name == arg.to_lowercase()) {
+            Some(system) => vec![system],
+            None => {
+                return Err(Error::Invalid(format!("unknown numbering system '{}'.", arg)));
+            }
+        },
+        None => numbering::SYSTEMS.iter().collect(),
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let value = match parse_int(&numbering::to_ascii(arg)) {
+            Ok(value) => value,
+            Err(err) => {
+                parse_error(arg, &err).input(arg).print(ctx)?;
+                ctx.termline()?;
+                continue;
+            }
+        };
+        let mut report = Report::new();
+        for (name, zero) in &systems {
+            report = report.field(name, numbering::to_native(&value.to_string(), *zero));
+        }
+        report.input(arg).print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::bin_string;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::dns;
+use crate::parse_int;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector dns [values]
+
+  Maps numeric DNS resource record types to their mnemonics (e.g. 28 is
+  AAAA) and mnemonics back to numbers. Use the --class flag to look up
+  record classes instead of types, or the --flags flag to decode values as
+  16-bit DNS header flags words.
+
+  Numbers can be specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "dns"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Look up DNS record types and decode flags."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[values]", "List of numbers or mnemonics.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "class c", value: None, help: "Look up record classes, e.g. 1 is IN." },
+            Opt { names: "flags f", value: None, help: "Decode values as DNS header flags words." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_dns(ctx, parser)
+    }
+}
+
+
+fn cmd_dns(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let (label, table) = if cmd_parser.found("class") {
+        ("cls", dns::CLASSES)
+    } else {
+        ("typ", dns::TYPES)
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let value = parse_int(arg).ok();
+        if let Some(value) = value {
+            if !(0..=0xFFFF).contains(&value) {
+                let msg = format!("'{}' is out of range for a 16-bit field.", arg);
+                Report::error(&msg).input(arg).print(ctx)?;
+                ctx.termline()?;
+                continue;
+            }
+        }
+        if cmd_parser.found("flags") {
+            let report = match value {
+                Some(value) => dns_flags_info(value as u16),
+                None => Report::error(&format!("cannot parse '{}' as an integer.", arg)),
+            };
+            report.input(arg).print(ctx)?;
+        } else {
+            let found = match value {
+                Some(value) => dns::name(table, value as u16).map(|name| (value as u16, name)),
+                None => dns::number(table, arg)
+                    .and_then(|number| dns::name(table, number).map(|name| (number, name))),
+            };
+            let report = match found {
+                Some((number, name)) => {
+                    Report::new().field(label, format!("{} (0x{:04X}) {}", number, number, name))
+                },
+                None => Report::new().field(label, format!("unknown value '{}'", arg)),
+            };
+            report.input(arg).print(ctx)?;
+        }
+        ctx.termline()?;
+    }
+    Ok(())
+}
+
+
+fn dns_flags_info(word: u16) -> Report {
+    let flags = dns::decode_flags(word);
+    let bit = |set: bool| if set { 1 } else { 0 };
+    let kind = if flags.qr { "response" } else { "query" };
+    let opcode = dns::name(dns::OPCODES, flags.opcode).unwrap_or("unassigned");
+    let rcode = dns::name(dns::RCODES, flags.rcode).unwrap_or("unassigned");
+    let flag_bits = format!(
+        "aa={} tc={} rd={} ra={} z={} ad={} cd={}",
+        bit(flags.aa),
+        bit(flags.tc),
+        bit(flags.rd),
+        bit(flags.ra),
+        bit(flags.z),
+        bit(flags.ad),
+        bit(flags.cd),
+    );
+    Report::new()
+        .field("hex", add_spacers(&format!("{:04X}", word), ' ', 2))
+        .field("bin", bin_string(word as u128, 16))
+        .field("qr", format!("{} ({})", bit(flags.qr), kind))
+        .field("opc", format!("{} ({})", flags.opcode, opcode))
+        .field("flg", flag_bits)
+        .field("rcd", format!("{} ({})", flags.rcode, rcode))
+}
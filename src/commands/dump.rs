@@ -0,0 +1,282 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::codec;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report;
+use crate::report::Report;
+use crate::stream;
+
+
+const HELP: &str = "
+Usage: intspector dump [files]
+
+  Dumps the bytes of files, or stdin if no files are given or a file is
+  '-', in rows of offset, hex, binary, and ASCII columns. Bytes outside the
+  printable ASCII range are shown as '.' in the ASCII column. Files are
+  mapped into memory where possible, and pipes and stdin are read a chunk
+  at a time, so inputs of any size can be dumped. A progress line is shown
+  on a terminal if the output is redirected.
+
+  The offset and length can be specified in binary, octal, decimal, or
+  hexadecimal base, e.g. --offset 0x40.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "dump"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Dump bytes in hex, binary, and ASCII columns."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[files]", "List of files to dump.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "length n",
+                value: Some("n"),
+                help: "Number of bytes to dump. Defaults to all of them.",
+            },
+            Opt {
+                names: "offset o",
+                value: Some("n"),
+                help: "Offset of the first byte to dump. Defaults to 0.",
+            },
+            Opt {
+                names: "width w",
+                value: Some("n"),
+                help: "Number of bytes per row, from 1 to 64. Defaults to 6.",
+            },
+            Opt {
+                names: "hex x",
+                value: None,
+                help: "Read the arguments as hex-encoded bytes instead of file names.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_dump(ctx, parser)
+    }
+}
+
+
+fn cmd_dump(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let width = match cmd_parser.value("width") {
+        Some(arg) => match arg.parse::<usize>() {
+            Ok(value) if (1..=64).contains(&value) => value,
+            _ => {
+                return Err(Error::Invalid("the width must be from 1 to 64 bytes.".to_string()));
+            }
+        },
+        None => 6,
+    };
+    let parse_count = |name: &str| match cmd_parser.value(name) {
+        Some(arg) => match eval_arg(&arg) {
+            Ok(value) if (0..=u64::MAX as i128).contains(&value) => Ok(Some(value as u64)),
+            Ok(_) => Err(Error::Invalid(format!("the {} must be from 0 to 2^64 - 1.", name))),
+            Err(msg) => Err(Error::Invalid(msg)),
+        },
+        None => Ok(None),
+    };
+    let offset = parse_count("offset")?.unwrap_or(0);
+    let length = parse_count("length")?;
+    let args = if cmd_parser.args.is_empty() {
+        vec!["-".to_string()]
+    } else {
+        cmd_parser.args.clone()
+    };
+    ctx.termline()?;
+    for arg in &args {
+        // Text output is streamed, so files of any size can be dumped.
+        if !cmd_parser.found("hex") && ctx.style.format == report::Format::Text {
+            let _guard = ctx.catch();
+            match stream_dump(ctx, arg, offset, length, width) {
+                Ok(0) => Report::error(&format!("no bytes at offset {}.", offset)).print(ctx)?,
+                Ok(count) if ctx.interrupted() => {
+                    Report::error(&format!("interrupted after {} bytes.", count)).print(ctx)?;
+                    ctx.termline()?;
+                    break;
+                },
+                Ok(_) => {},
+                Err(stream::Error::Read(err)) => {
+                    Report::error(&format!("cannot read '{}': {}.", arg, err)).print(ctx)?
+                },
+                Err(stream::Error::Stopped(err)) => return Err(err),
+            }
+            ctx.termline()?;
+            continue;
+        }
+        let bytes = if cmd_parser.found("hex") {
+            codec::hex_decode(arg).ok_or(format!("cannot parse '{}' as hex.", arg)).map(|bytes| {
+                let len = bytes.len() as u64;
+                let start = offset.min(len);
+                let end = length.map_or(len, |n| start.saturating_add(n).min(len));
+                bytes[start as usize..end as usize].to_vec()
+            })
+        } else {
+            read_bytes(ctx, arg, offset, length)
+        };
+        match bytes {
+            Ok(bytes) if bytes.is_empty() => {
+                Report::error(&format!("no bytes at offset {}.", offset)).print(ctx)?
+            },
+            Ok(bytes) => {
+                let digits = offset_digits(offset + bytes.len() as u64 - 1);
+                dump_rows(&bytes, offset, width, digits, false).print(ctx)?;
+            },
+            Err(msg) => Report::error(&msg).print(ctx)?,
+        }
+        ctx.termline()?;
+    }
+    Ok(())
+}
+
+
+// Prints the dump rows of up to `length` bytes from the file, or from stdin for `-`, starting at
+// `offset`, reading and printing a chunk at a time. Returns the number of bytes dumped.
+fn stream_dump(
+    ctx: &mut Context,
+    path: &str,
+    offset: u64,
+    length: Option<u64>,
+    width: usize,
+) -> Result<u64, stream::Error<Error>> {
+    use std::io::{Read, Seek, SeekFrom};
+    let (reader, size): (Box<dyn Read + '_>, Option<u64>) = if path == "-" {
+        let mut stdin = ctx.env.stdin.take().unwrap_or_else(|| Box::new(std::io::empty()));
+        std::io::copy(&mut stdin.by_ref().take(offset), &mut std::io::sink())?;
+        (Box::new(stdin), ctx.env.stdin_size.map(|size| size.saturating_sub(offset)))
+    } else {
+        let mut file = std::fs::File::open(path)?;
+        if let Some(map) = stream::Mmap::map(&file) {
+            return dump_slice(ctx, &map, offset, length, width).map_err(stream::Error::Stopped);
+        }
+        let metadata = file.metadata()?;
+        if offset > 0 {
+            file.seek(SeekFrom::Start(offset))?;
+        }
+        // Pipes and devices have no size.
+        let size = Some(metadata.len().saturating_sub(offset)).filter(|_| metadata.is_file());
+        (Box::new(file), size)
+    };
+    let total = match (size, length) {
+        (Some(size), Some(length)) => Some(size.min(length)),
+        (size, length) => size.or(length),
+    };
+    // Without a known size, the offset column is widened as needed past 4 GiB.
+    let digits = offset_digits(offset + total.unwrap_or(1).max(1) - 1);
+    let progress = ctx.progress(false);
+    let reader = stream::Progress::new(reader.take(length.unwrap_or(u64::MAX)), total, progress);
+    let interrupted = ctx.interrupt_flag();
+    let mut count = 0;
+    stream::for_each_chunk(reader, width, interrupted, |chunk, start| {
+        dump_rows(chunk, offset + start, width, digits, true).print(ctx)?;
+        count += chunk.len() as u64;
+        Ok(())
+    })?;
+    Ok(count)
+}
+
+
+// Prints the dump rows of up to `length` bytes from `bytes`, e.g. a mapped file, starting at
+// `offset`, a chunk at a time. Returns the number of bytes dumped.
+fn dump_slice(
+    ctx: &mut Context,
+    bytes: &[u8],
+    offset: u64,
+    length: Option<u64>,
+    width: usize,
+) -> Result<u64, Error> {
+    let start = offset.min(bytes.len() as u64) as usize;
+    let end = length.map_or(bytes.len(), |n| n.min((bytes.len() - start) as u64) as usize + start);
+    let digits = offset_digits(offset + (end - start).max(1) as u64 - 1);
+    let progress = ctx.progress(false);
+    let interrupted = ctx.interrupt_flag();
+    let mut count = 0;
+    stream::for_each_slice(&bytes[start..end], width, progress, interrupted, |chunk, from| {
+        count += chunk.len() as u64;
+        dump_rows(chunk, offset + from, width, digits, true).print(ctx)
+    })?;
+    Ok(count)
+}
+
+
+// Reads up to `length` bytes from the file, or from stdin for `-`, starting at `offset`.
+fn read_bytes(
+    ctx: &mut Context,
+    path: &str,
+    offset: u64,
+    length: Option<u64>,
+) -> Result<Vec<u8>, String> {
+    use std::io::{Read, Seek, SeekFrom};
+    let mut bytes = Vec::new();
+    let result = if path == "-" {
+        let mut stdin = ctx.env.stdin.take().unwrap_or_else(|| Box::new(std::io::empty()));
+        std::io::copy(&mut stdin.by_ref().take(offset), &mut std::io::sink())
+            .and_then(|_| stdin.take(length.unwrap_or(u64::MAX)).read_to_end(&mut bytes))
+    } else {
+        std::fs::File::open(path).and_then(|mut file| {
+            file.seek(SeekFrom::Start(offset))?;
+            file.take(length.unwrap_or(u64::MAX)).read_to_end(&mut bytes)
+        })
+    };
+    match result {
+        Ok(_) => Ok(bytes),
+        Err(err) => Err(format!("cannot read '{}': {}.", path, err)),
+    }
+}
+
+
+// Formats the bytes as a table of rows of `width` bytes, each with its offset, padded to `digits`
+// hex digits, and its bytes in hex, binary, and ASCII. Offsets start from `offset`. A dump
+// streamed a chunk at a time pads the cells of a short last row to the width of a full row, as
+// the table only lines up the rows of one chunk.
+fn dump_rows(bytes: &[u8], offset: u64, width: usize, digits: usize, pad: bool) -> Report {
+    let (hex_width, bin_width) = if pad { (width * 3 - 1, width * 9 - 1) } else { (0, 0) };
+    let rows: Vec<Vec<String>> = bytes
+        .chunks(width)
+        .enumerate()
+        .map(|(index, row)| {
+            let hex: Vec<String> = row.iter().map(|byte| format!("{:02X}", byte)).collect();
+            let bin: Vec<String> = row.iter().map(|byte| format!("{:08b}", byte)).collect();
+            let ascii: String = row
+                .iter()
+                .map(|byte| if (0x20..0x7F).contains(byte) { *byte as char } else { '.' })
+                .collect();
+            vec![
+                format!("{:0digits$X}", offset + (index * width) as u64, digits = digits),
+                format!("{:<width$}", hex.join(" "), width = hex_width),
+                format!("{:<width$}", bin.join(" "), width = bin_width),
+                ascii,
+            ]
+        })
+        .collect();
+    Report::new().table(&["offset", "hex", "bin", "ascii"], rows)
+}
+
+
+// Returns the number of hex digits for the offset column of a dump ending at offset `last`.
+fn offset_digits(last: u64) -> usize {
+    format!("{:X}", last).len().max(8)
+}
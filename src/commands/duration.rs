@@ -0,0 +1,119 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::cli::try_eval_arg;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::datetime;
+use crate::IntspectorError;
+use crate::parse_u128;
+use crate::report::Report;
+use std::collections::HashMap;
+
+
+const HELP: &str = "
+Usage: intspector duration [durations]
+
+  Breaks durations down into days, hours, minutes, seconds, and fractions of
+  a second, and displays their totals in seconds, milliseconds, microseconds,
+  and nanoseconds.
+
+  A duration can be an integer, in the unit given by --unit, or a sequence of
+  integers with unit symbols, e.g. 2h30m or '1d 12h'. The symbols are w, d,
+  h, m, s, ms, us (or µs), and ns.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "duration"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Break durations down into units and totals."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[durations]", "List of integers or durations, e.g. 2h30m.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "unit u",
+                value: Some("unit"),
+                help: "Unit of integer durations: s, ms, us, or ns. Defaults to s.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_duration(ctx, parser)
+    }
+}
+
+
+fn cmd_duration(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let unit = cmd_parser.value("unit").unwrap_or_else(|| "s".to_string());
+    let scale = match datetime::duration_unit(&unit) {
+        Some(scale) if scale <= 1_000_000_000 => scale,
+        _ => {
+            return Err(Error::Invalid("the unit must be s, ms, us, or ns.".to_string()));
+        }
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let nanos = match try_eval_arg(arg, &HashMap::new()) {
+            Ok(value) if value < 0 => Err("durations can't be negative.".to_string()),
+            Ok(value) => (value as u128)
+                .checked_mul(scale)
+                .ok_or_else(|| "duration too large.".to_string()),
+            // A count too large for the parser is still a count, not a duration missing its unit.
+            Err((_, IntspectorError::Overflow { .. })) => parse_u128(arg)
+                .ok()
+                .and_then(|value| value.checked_mul(scale))
+                .ok_or_else(|| "duration too large.".to_string()),
+            Err((msg, IntspectorError::ArithmeticOverflow(_))) => Err(msg),
+            Err(_) => datetime::parse_duration(arg)
+                .map_err(|msg| format!("cannot parse '{}' as a duration: {}.", arg, msg)),
+        };
+        print_result(ctx, arg, nanos.map(duration_info))?;
+    }
+    Ok(())
+}
+
+
+fn duration_info(nanos: u128) -> Report {
+    let total = |scale: u128, digits: usize| {
+        let whole = add_spacers(&(nanos / scale).to_string(), ',', 3);
+        match nanos % scale {
+            0 => whole,
+            frac => {
+                let frac = format!("{:0digits$}", frac, digits = digits);
+                format!("{}.{}", whole, frac.trim_end_matches('0'))
+            },
+        }
+    };
+    Report::new()
+        .field("dur", datetime::format_duration(nanos))
+        .field("sec", total(1_000_000_000, 9))
+        .field("mil", total(1_000_000, 6))
+        .field("mic", total(1_000, 3))
+        .field("nan", total(1, 0))
+}
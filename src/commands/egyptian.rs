@@ -0,0 +1,92 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::ratio;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector egyptian [fractions]
+
+  Decomposes non-negative fractions into an integer part and a sum of
+  distinct unit fractions, e.g. 4/13 = 1/4 + 1/18 + 1/468, using the greedy
+  algorithm, which repeatedly takes the largest unit fraction that does not
+  exceed the remainder.
+
+  Greedy denominators can grow very quickly, e.g. 5/121 needs a 25-digit
+  denominator. Denominators are limited to 128 bits.
+
+  Fractions are written as num/den, e.g. 4/13, or as exact decimals, e.g.
+  0.75. Numerators and denominators can be specified in binary, octal,
+  decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "egyptian"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decompose fractions into unit fractions."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[fractions]", "List of fractions or decimals.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_egyptian(ctx, parser)
+    }
+}
+
+
+fn cmd_egyptian(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        print_result(ctx, arg, egyptian_info(arg))?;
+    }
+    Ok(())
+}
+
+
+fn egyptian_info(arg: &str) -> Result<Report, String> {
+    let value = match ratio::parse_decimal(arg) {
+        Some((digits, places)) => match 10u64.checked_pow(places) {
+            Some(den) => ratio::Ratio { num: digits, den }.reduce(),
+            None => return Err(format!("'{}' has too many digits.", arg)),
+        },
+        None => match ratio::parse_ratio(arg) {
+            Some(value) => value.reduce(),
+            None => return Err(format!("cannot parse '{}' as a fraction.", arg)),
+        },
+    };
+    let (whole, denominators) = match ratio::egyptian(value) {
+        Some(terms) => terms,
+        None => return Err(format!("a denominator for '{}' overflows 128 bits.", arg)),
+    };
+    let mut terms: Vec<String> = denominators.iter().map(|den| format!("1/{}", den)).collect();
+    if whole != 0 || terms.is_empty() {
+        terms.insert(0, whole.to_string());
+    }
+    let plural = if denominators.len() == 1 { "" } else { "s" };
+    Ok(Report::new()
+        .field("val", value.to_string())
+        .field("egy", terms.join(" + "))
+        .field("trm", format!("{} unit fraction{}", denominators.len(), plural)))
+}
@@ -0,0 +1,116 @@
+use arguably::ArgParser;
+use crate::cli::code_info;
+use crate::cli::Context;
+use crate::cli::decode_stream;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::coding;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_u128;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector elias [integers]
+       intspector elias --decode [--delta] <bits>
+
+  Encodes positive integers with the Elias gamma and delta universal codes,
+  which need no agreed width, showing each code with its parts separated
+  and packed into bytes, most significant bit first.
+
+  - Gamma: n in binary, preceded by one zero for each digit after the
+    first, e.g. 0000 10001 for 17.
+  - Delta: the gamma code of the number of binary digits in n, followed
+    by n in binary without its leading 1, e.g. 00101 0001 for 17. Shorter
+    than gamma for large integers.
+
+  With the --decode flag, the arguments are read as a single stream of
+  binary digits, which can be grouped with spaces or underscores, and
+  decoded into a sequence of integers. Trailing zeros are treated as
+  padding.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "elias"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Encode and decode Elias gamma and delta codes."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to encode, or binary digits to decode.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "decode d", value: None, help: "Decode a stream of binary digits." },
+            Opt { names: "delta", value: None, help: "Decode delta codes instead of gamma codes." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_elias(ctx, parser)
+    }
+}
+
+
+fn cmd_elias(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if cmd_parser.args.is_empty() {
+        return Ok(());
+    }
+    ctx.termline()?;
+    if cmd_parser.found("decode") {
+        let decode: &dyn Fn(&str) -> Option<(u128, usize)> = if cmd_parser.found("delta") {
+            &coding::decode_elias_delta
+        } else {
+            &coding::decode_elias_gamma
+        };
+        match decode_stream(&cmd_parser.args, decode, '0') {
+            Ok(report) => report.print(ctx)?,
+            Err(msg) => Report::error(&msg).print(ctx)?,
+        }
+        ctx.termline()?;
+        return Ok(());
+    }
+    for arg in &cmd_parser.args {
+        let value = match parse_u128(arg) {
+            Ok(0) => {
+                Report::error("Elias codes start at 1.").input(arg).print(ctx)?;
+                ctx.termline()?;
+                continue;
+            },
+            Ok(value) => value,
+            Err(err) => {
+                parse_error(arg, &err).input(arg).print(ctx)?;
+                ctx.termline()?;
+                continue;
+            }
+        };
+        Report::new()
+            .field("int", value.to_string())
+            .field("gam", code_info(coding::elias_gamma(value).unwrap()))
+            .field("dlt", code_info(coding::elias_delta(value).unwrap()))
+            .input(arg)
+            .print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
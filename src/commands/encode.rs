@@ -0,0 +1,174 @@
+use arguably::ArgParser;
+use crate::cli::be_bytes;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_input;
+use crate::cli::hex_bytes;
+use crate::cli::print_result;
+use crate::codec;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+use crate::std_bits;
+
+
+const HELP: &str = "
+Usage: intspector encode [integers]
+
+  Encodes the bytes of integers as base64, URL-safe base64, base32, and hex
+  strings, e.g. 3q2+7w==, 3q2-7w, 32W353Y=, and deadbeef for 0xDEADBEEF. The
+  bytes are big-endian by default, in the smallest standard width that holds
+  the integer. URL-safe base64 is unpadded, as in JWTs.
+
+  With the --hex flag, encodes the arguments as hex-encoded bytes instead,
+  e.g. a key or a hash. With the --decode flag, reads the arguments as
+  encoded strings and shows the bytes and integer value of each reading of
+  the string as base64 (b64), URL-safe base64 (url), base32, or hex, as a
+  string can be valid in more than one, e.g. deadbeef. Strings without any
+  of the characters + / - _ read the same in both base64 alphabets and are
+  shown as b64.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions. Negative integers are encoded as two's complement
+  values.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "encode"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Encode the bytes of integers as base64, base32, and hex."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers, or encoded strings with --decode.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "bits b",
+                value: Some("n"),
+                help: "Width in bits, a multiple of 8. Defaults to the smallest standard width, \
+                      8, 16, 32, 64, or 128 bits, that holds the integer.",
+            },
+            Opt { names: "little l", value: None, help: "Use little-endian byte order." },
+            Opt {
+                names: "hex x",
+                value: None,
+                help: "Read the arguments as hex-encoded bytes instead of integers.",
+            },
+            Opt { names: "decode d", value: None, help: "Decode strings back to integers." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_encode(ctx, parser)
+    }
+}
+
+
+fn cmd_encode(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let user_bits = match cmd_parser.value("bits") {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value) if value.is_multiple_of(8) && (8..=128).contains(&value) => Some(value),
+            _ => {
+                let msg = "the width must be a multiple of 8 from 8 to 128 bits.";
+                return Err(Error::Invalid(msg.to_string()));
+            }
+        },
+        None => None,
+    };
+    let little = cmd_parser.found("little");
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let report = if cmd_parser.found("decode") {
+            decode_strings_info(arg, little).map_err(Report::from)
+        } else if cmd_parser.found("hex") {
+            codec::hex_decode(arg)
+                .ok_or_else(|| Report::from(format!("cannot parse '{}' as hex.", arg)))
+                .map(|bytes| encode_info(Report::new(), &bytes))
+        } else {
+            eval_input(arg).and_then(|value| {
+                let bits = user_bits.unwrap_or_else(|| std_bits(value));
+                let mut bytes = be_bytes(value, bits)?;
+                let order = if little { "little-endian" } else { "big-endian" };
+                if little {
+                    bytes.reverse();
+                }
+                let report = Report::new()
+                    .field("int", value.to_string())
+                    .field("wid", format!("{} bits, {}", bits, order));
+                Ok(encode_info(report, &bytes))
+            })
+        };
+        print_result(ctx, arg, report)?;
+    }
+    Ok(())
+}
+
+
+fn encode_info(report: Report, bytes: &[u8]) -> Report {
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    report
+        .field("byt", hex_bytes(bytes))
+        .field("b64", codec::base64_encode(bytes, false))
+        .field("url", codec::base64_encode(bytes, true))
+        .field("b32", codec::base32_encode(bytes))
+        .field("hex", hex)
+}
+
+
+// Decodes a string as each of base64, URL-safe base64, base32, and hex in turn, showing the
+// integer value and bytes of every decoding that succeeds.
+fn decode_strings_info(arg: &str, little: bool) -> Result<Report, String> {
+    // A string without any of the four characters the base64 alphabets differ in reads the
+    // same in both, so it's shown once, as standard base64.
+    let base64 = codec::base64_decode_with(arg, false);
+    let url = if base64.is_none() { codec::base64_decode_with(arg, true) } else { None };
+    let decodings = [
+        ("b64", base64),
+        ("url", url),
+        ("b32", codec::base32_decode(arg)),
+        ("hex", codec::hex_decode(arg)),
+    ];
+    let mut report = Report::new();
+    for (label, bytes) in decodings {
+        let mut bytes = match bytes {
+            Some(bytes) if !bytes.is_empty() => bytes,
+            _ => continue,
+        };
+        let hex = hex_bytes(&bytes);
+        if little {
+            bytes.reverse();
+        }
+        report = match bytes.len() {
+            0..=16 => {
+                let value = bytes.iter().fold(0u128, |value, byte| value << 8 | *byte as u128);
+                report.field(label, format!("{} ({})", value, hex))
+            },
+            len => report.field(label, format!("{} bytes, too wide for 128 bits ({})", len, hex)),
+        };
+    }
+    match report.items.is_empty() {
+        true => Err(format!("cannot decode '{}' as base64, base32, or hex.", arg)),
+        false => Ok(report),
+    }
+}
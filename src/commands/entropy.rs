@@ -0,0 +1,126 @@
+use arguably::ArgParser;
+use crate::cli::byte_inputs;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Align;
+use crate::report::Report;
+use crate::shannon_entropy;
+
+
+const HELP: &str = "
+Usage: intspector entropy [strings]
+       intspector entropy --file <path>
+
+  Computes the Shannon entropy of each string's UTF-8 bytes, or of a file's
+  contents, in bits per byte, and shows a histogram of the byte values
+  grouped by their high nibble along with the most common bytes.
+
+  Compressed and encrypted data is close to the maximum of 8 bits per byte,
+  while text and machine code are usually well below 7. Inputs shorter than
+  256 bytes can't reach the maximum, so they aren't classified.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "entropy"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Estimate the entropy of byte strings and files."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[strings]", "List of strings to analyze.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "file f", value: Some("path"), help: "Analyze the contents of a file." },
+            Opt { names: "hex x", value: None, help: "Read the strings as hex-encoded bytes." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_entropy(ctx, parser)
+    }
+}
+
+
+fn cmd_entropy(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let inputs = byte_inputs(ctx, cmd_parser)?;
+    if !inputs.is_empty() {
+        ctx.termline()?;
+    }
+    for (arg, input) in inputs {
+        print_result(ctx, &arg, input.and_then(|bytes| entropy_info(&bytes)))?;
+    }
+    Ok(())
+}
+
+
+fn entropy_info(bytes: &[u8]) -> Result<Report, String> {
+    if bytes.is_empty() {
+        return Err("no bytes to analyze.".to_string());
+    }
+    let mut counts = [0u64; 256];
+    for byte in bytes {
+        counts[*byte as usize] += 1;
+    }
+    let distinct = counts.iter().filter(|count| **count > 0).count();
+    let entropy = shannon_entropy(&counts);
+    let report = Report::new()
+        .field("len", format!("{} bytes, {} distinct values", bytes.len(), distinct))
+        .field("ent", format!("{:.4} bits per byte ({:.1}% of 8)", entropy, entropy / 8.0 * 100.0));
+    let verdict = if bytes.len() < 256 {
+        "too short to classify"
+    } else if entropy >= 7.5 {
+        "high entropy, likely compressed or encrypted"
+    } else if entropy >= 6.0 {
+        "moderate entropy, e.g. binary data or machine code"
+    } else {
+        "low entropy, e.g. text or structured data"
+    };
+    let report = report.field("est", verdict);
+
+    // The most common bytes, breaking ties by byte value.
+    let mut common: Vec<usize> = (0..256).filter(|byte| counts[*byte] > 0).collect();
+    common.sort_by_key(|byte| (std::cmp::Reverse(counts[*byte]), *byte));
+    let top: Vec<String> = common
+        .iter()
+        .take(5)
+        .map(|byte| format!("{:02X}×{}", byte, counts[*byte]))
+        .collect();
+    let report = report.field("top", top.join(" "));
+
+    let mut nibbles = [0u64; 16];
+    for (byte, count) in counts.iter().enumerate() {
+        nibbles[byte >> 4] += count;
+    }
+    let largest = *nibbles.iter().max().unwrap();
+    let rows = nibbles
+        .iter()
+        .enumerate()
+        .map(|(nibble, count)| {
+            let bar = "█".repeat(((count * 40) as f64 / largest as f64).round() as usize);
+            let share = *count as f64 / bytes.len() as f64 * 100.0;
+            vec![format!("{:X}x", nibble), format!("{:.1}%", share), bar]
+        })
+        .collect();
+    Ok(report.aligned_table(&["nibble", "share", "bar"], &[Align::Left, Align::Right], rows))
+}
@@ -0,0 +1,146 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::exif;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector exif [values]
+
+  Decodes EXIF metadata values. Integers from 1 to 8 are decoded as
+  orientation values, showing the position of the image's first row and
+  column and the transform needed to display it upright. Values written as
+  num/den, e.g. 1/250, are decoded as RATIONAL or SRATIONAL values, showing
+  the reduced fraction and its decimal value.
+
+  Use the --tag option to interpret values as a particular tag:
+
+  - orientation: Orientation (0x0112).
+  - exposure: ExposureTime (0x829A), displayed in seconds.
+  - fnumber: FNumber (0x829D), displayed as an f-stop.
+  - focal: FocalLength (0x920A), displayed in millimetres.
+  - bias: ExposureBiasValue (0x9204), displayed in EV.
+  - gps: GPSLatitude or GPSLongitude, written as three comma-separated
+    rationals for degrees, minutes, and seconds, e.g. 51/1,30/1,2616/100.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "exif"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode EXIF orientations and rationals."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[values]", "List of EXIF values.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "tag t", value: Some("name"), help: "Interpret values as the named tag." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_exif(ctx, parser)
+    }
+}
+
+
+fn cmd_exif(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let tag = cmd_parser.value("tag").map(|tag| tag.to_lowercase());
+    let tags = ["orientation", "exposure", "fnumber", "focal", "bias", "gps"];
+    if let Some(tag) = &tag {
+        if !tags.contains(&tag.as_str()) {
+            return Err(Error::Invalid(format!("unknown tag '{}'.", tag)));
+        }
+    }
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = match tag.as_deref() {
+            Some("gps") => gps_info(arg),
+            Some("orientation") => orientation_info(arg),
+            None if !arg.contains('/') => orientation_info(arg),
+            _ => rational_info(arg, tag.as_deref()),
+        };
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn orientation_info(arg: &str) -> Result<Report, String> {
+    let value = arg.parse::<u32>().ok();
+    let (position, transform) = match value.and_then(exif::orientation) {
+        Some(description) => description,
+        None => return Err(format!("'{}' is not a valid EXIF orientation (1-8).", arg)),
+    };
+    let report =
+        Report::new().field("ori", format!("{} ({})", arg, position)).field("fix", transform);
+    match exif::orientation_swaps_axes(value.unwrap()) {
+        true => Ok(report.field("dim", "width and height are swapped")),
+        false => Ok(report),
+    }
+}
+
+
+fn rational_info(arg: &str, tag: Option<&str>) -> Result<Report, String> {
+    let rational = match exif::parse_rational(arg) {
+        Some(rational) => rational,
+        None => return Err(format!("cannot parse '{}' as an EXIF rational.", arg)),
+    };
+    let reduced = rational.reduce();
+    let report = Report::new()
+        .field("rat", format!("{}/{}", rational.num, rational.den))
+        .field("red", format!("{}/{}", reduced.num, reduced.den));
+    let value = match rational.value() {
+        Some(value) => value,
+        None => return Ok(report.field("dec", "unknown (zero denominator)")),
+    };
+    let report = report.field("dec", exif::trim_float(value));
+    Ok(match tag {
+        Some("exposure") => report.field("exp", exif::format_exposure(value)),
+        Some("fnumber") => report.field("fno", format!("f/{:.1}", value)),
+        Some("focal") => report.field("foc", format!("{} mm", exif::trim_float(value))),
+        Some("bias") => report.field("bia", format!("{:+.2} EV", value)),
+        _ => report,
+    })
+}
+
+
+fn gps_info(arg: &str) -> Result<Report, String> {
+    let parts: Vec<Option<f64>> = arg
+        .split(',')
+        .map(|part| exif::parse_rational(part).and_then(|rational| rational.value()))
+        .collect();
+    match parts.as_slice() {
+        [Some(degrees), Some(minutes), Some(seconds)] => {
+            let decimal = exif::dms_to_degrees(*degrees, *minutes, *seconds);
+            Ok(Report::new()
+                .field("dms", format!("{}° {}' {}\"", degrees, minutes, seconds))
+                .field("deg", format!("{:.6}°", decimal)))
+        },
+        _ => Err(format!("cannot parse '{}' as three GPS rationals.", arg)),
+    }
+}
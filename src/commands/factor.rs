@@ -0,0 +1,105 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_input;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::factor;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector factor [integers]
+
+  Displays the prime factorization of integers, whether they're prime, and
+  the number and sum of their divisors. Uses Pollard's rho algorithm and a
+  deterministic Miller-Rabin test, so any 64-bit integer factors quickly.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions. Negative integers are factored as -1 times their
+  magnitude.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "factor"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Factor integers into primes."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to factor.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_factor(ctx, parser)
+    }
+}
+
+
+fn cmd_factor(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        print_result(ctx, arg, eval_input(arg).and_then(|value| Ok(factor_info(value)?)))?;
+    }
+    Ok(())
+}
+
+
+fn factor_info(value: i128) -> Result<Report, String> {
+    let magnitude = value.unsigned_abs();
+    if magnitude > u64::MAX as u128 {
+        return Err(format!("{} is too large to factor, the limit is 2^64 - 1.", value));
+    }
+    if value == 0 {
+        return Err("0 has no prime factorization.".to_string());
+    }
+    let factors = factor::factorize(magnitude as u64);
+    let mut terms: Vec<String> = factors
+        .iter()
+        .map(|(p, exp)| match exp {
+            1 => p.to_string(),
+            _ => format!("{}^{}", p, exp),
+        })
+        .collect();
+    if value < 0 {
+        terms.insert(0, "-1".to_string());
+    }
+    let product = if terms.is_empty() { "1".to_string() } else { terms.join(" × ") };
+    let prime = match magnitude {
+        1 => "no, a unit".to_string(),
+        _ if factor::is_prime(magnitude as u64) && value > 0 => "yes".to_string(),
+        _ if factor::is_prime(magnitude as u64) => format!("no, but {} is", magnitude),
+        _ => {
+            let count: u32 = factors.iter().map(|(_, exp)| exp).sum();
+            let plural = if count == 1 { "" } else { "s" };
+            format!("no, {} prime factor{} with multiplicity", count, plural)
+        },
+    };
+    let count = factor::divisor_count(&factors);
+    let sum = factor::divisor_sum(&factors)
+        .map_or("overflows 128 bits".to_string(), |sum| add_spacers(&sum.to_string(), ',', 3));
+    let plural = if count == 1 { "" } else { "s" };
+    Ok(Report::new()
+        .field("val", add_spacers(&value.to_string(), ',', 3))
+        .field("fac", product)
+        .field("pri", prime)
+        .field("div", format!("{} positive divisor{}, summing to {}", count, plural, sum)))
+}
@@ -0,0 +1,156 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::factoradic;
+use crate::from_factoradic;
+use crate::lehmer_permutation;
+use crate::parse_u128;
+use crate::permutation_lehmer;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector factoradic [values]
+
+  Converts integers to the factorial number system, in which digit i has
+  weight i! and is at most i, and to the permutation with that rank in
+  lexicographic order. The factoradic digits, most significant first, are
+  the Lehmer code of the permutation: each digit counts the later elements
+  that are smaller than the corresponding element.
+
+  Factoradic digits are written most significant first and separated by
+  colons, e.g. 3:4:1:0:1:0. Permutations are written as comma-separated
+  lists of the elements 0 to n - 1, or 1 to n, e.g. 3,5,1,0,4,2.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "factoradic"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert integers to factoradic and permutations."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[values]", "List of integers, factoradic digits, or permutations.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "size n",
+                value: Some("n"),
+                help: "Number of elements to permute. Defaults to the number of factoradic \
+                      digits.",
+            },
+            Opt { names: "digits d", value: None, help: "Read arguments as factoradic digits." },
+            Opt { names: "perm p", value: None, help: "Read arguments as permutations." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_factoradic(ctx, parser)
+    }
+}
+
+
+fn cmd_factoradic(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let size = match cmd_parser.value("size") {
+        Some(arg) => match arg.parse::<usize>() {
+            Ok(value) if value <= 35 => Some(value),
+            _ => {
+                return Err(Error::Invalid("the size must be an integer from 0 to 35.".to_string()));
+            }
+        },
+        None => None,
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let result = if cmd_parser.found("perm") {
+            parse_permutation(arg)
+                .and_then(|perm| permutation_lehmer(&perm))
+                .map(|code| code.into_iter().rev().collect::<Vec<u32>>())
+                .and_then(|digits| from_factoradic(&digits))
+                .ok_or_else(|| Report::from(format!("'{}' is not a permutation.", arg)))
+        } else if cmd_parser.found("digits") {
+            arg.split(':')
+                .map(|digit| digit.trim().parse::<u32>().ok())
+                .rev()
+                .collect::<Option<Vec<u32>>>()
+                .and_then(|digits| from_factoradic(&digits))
+                .ok_or_else(|| {
+                    Report::from(format!("'{}' is not a valid 128-bit factoradic number.", arg))
+                })
+        } else {
+            parse_u128(arg).map_err(|err| parse_error(arg, &err))
+        };
+        print_result(ctx, arg, result.and_then(|value| Ok(factoradic_info(value, size)?)))?;
+    }
+    Ok(())
+}
+
+
+// Parses a comma-separated permutation of 0 to n - 1 or 1 to n, returning it 0-based.
+fn parse_permutation(arg: &str) -> Option<Vec<u32>> {
+    let elements: Vec<u32> = arg
+        .split(',')
+        .map(|element| element.trim().parse::<u32>().ok())
+        .collect::<Option<Vec<u32>>>()?;
+    if elements.contains(&0) {
+        Some(elements)
+    } else {
+        Some(elements.iter().map(|element| element - 1).collect())
+    }
+}
+
+
+// Returns the factoradic digits of an integer and the permutation with that rank.
+fn factoradic_info(value: u128, size: Option<usize>) -> Result<Report, String> {
+    let mut digits = factoradic(value);
+    if let Some(size) = size {
+        if size < digits.len() && value != 0 {
+            return Err(format!("{} requires at least {} elements.", value, digits.len()));
+        }
+        digits.resize(size.max(1), 0);
+    }
+    let code: Vec<u32> = digits.iter().rev().copied().collect();
+    let text: Vec<String> = code.iter().map(|digit| digit.to_string()).collect();
+    let terms: Vec<String> = digits
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, digit)| format!("{}×{}!", digit, i))
+        .collect();
+    let permutation: Vec<String> = lehmer_permutation(&code)
+        .unwrap()
+        .iter()
+        .map(|element| element.to_string())
+        .collect();
+    Ok(Report::new()
+        .field("int", value)
+        .field("fac", format!("{}\n{}", text.join(":"), terms.join(" + ")))
+        .field("lmr", text.join(", "))
+        .field("per", permutation.join(", ")))
+}
@@ -0,0 +1,242 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::bin_string;
+use crate::cli::Context;
+use crate::cli::display_bits;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::extract_field;
+use crate::field_mask;
+use crate::layout;
+use crate::min_bits;
+use crate::report::Align;
+use crate::report::Report;
+use crate::std_bits;
+
+
+const HELP: &str = "
+Usage: intspector field <integer> <ranges>
+       intspector field --layout <spec> [integers]
+
+  Extracts bit fields from an integer, for decoding instruction encodings
+  and hardware registers. Each range is written <hi>:<lo>, e.g. 31:28 for
+  the top four bits of a 32-bit word, or as a single bit number. For each
+  range, shows the extracted value in each base, the mask that selects the
+  field, and the shift that moves it down to bit 0.
+
+  With the --layout option, each integer is decoded into a table of named
+  fields, with any bits that no field covers shown as unused. A layout is
+  a list of name:range pairs, e.g. mode:0-2,enable:3,count:8-15, where a
+  range is a single bit, <lo>-<hi>, or <hi>:<lo>. It can also be read from
+  a file, in a subset of TOML, with a line for each field:
+
+    [timer]
+    mode = \"0-2\"
+    enable = 3
+    count = \"8-15\"
+
+  Bits are numbered from 0, the least significant bit, up to 127. Negative
+  integers are sign-extended, so fields above the top bit of the two's
+  complement value are all ones.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "field"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Extract bit fields from integers."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("<integer>", "Integer to extract fields from."),
+            ("<ranges>", "List of bit ranges, e.g. 31:28 27:20 19:0."),
+            ("[integers]", "List of integers to decode with the layout."),
+        ]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "layout l",
+                value: Some("spec"),
+                help: "Layout of named fields, or a file containing one.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_field(ctx, parser)
+    }
+}
+
+
+fn cmd_field(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if let Some(arg) = cmd_parser.value("layout") {
+        let fields = match load_layout(&arg) {
+            Ok(fields) => fields,
+            Err(msg) => {
+                return Err(Error::Invalid(msg));
+            }
+        };
+        if cmd_parser.args.is_empty() {
+            return Ok(());
+        }
+        ctx.termline()?;
+        for arg in &cmd_parser.args {
+            match eval_arg(arg) {
+                Ok(value) => layout_info(value, &fields).print(ctx)?,
+                Err(msg) => Report::error(&msg).print(ctx)?,
+            }
+            ctx.termline()?;
+        }
+        return Ok(());
+    }
+    if cmd_parser.args.len() < 2 {
+        let msg = "expected an integer followed by one or more bit ranges.";
+        return Err(Error::Invalid(msg.to_string()));
+    }
+    let mut ranges = Vec::new();
+    for arg in &cmd_parser.args[1..] {
+        match parse_bit_range(arg) {
+            Ok(range) => ranges.push(range),
+            Err(msg) => {
+                return Err(Error::Invalid(msg));
+            }
+        }
+    }
+    ctx.termline()?;
+    let arg = &cmd_parser.args[0];
+    let value = match eval_arg(arg) {
+        Ok(value) => value,
+        Err(msg) => {
+            Report::error(&msg).print(ctx)?;
+            ctx.termline()?;
+            return Ok(());
+        }
+    };
+    // Show the value wide enough to cover every field.
+    let top = ranges.iter().map(|(high, _)| high + 1).max().unwrap();
+    let default_bits = if value < 0 { std_bits(value) } else { min_bits(value) };
+    let num_bits = default_bits.max(top);
+    match display_bits(value, Some(num_bits)) {
+        Ok((disp_value, _)) => Report::new()
+            .field("int", value)
+            .field("bin", bin_string(disp_value, num_bits))
+            .print(ctx)?,
+        Err(msg) => Report::error(&msg).print(ctx)?,
+    }
+    ctx.termline()?;
+    for (high, low) in ranges {
+        field_info(value as u128, high, low).print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
+
+
+// Reads a layout from a file if the argument names one, or parses it as a layout spec.
+fn load_layout(arg: &str) -> Result<Vec<layout::Field>, String> {
+    if !std::path::Path::new(arg).is_file() {
+        return layout::parse(arg).map_err(|err| format!("{}.", err));
+    }
+    match std::fs::read_to_string(arg) {
+        Ok(text) => layout::parse_file(&text).map_err(|err| format!("{}: {}.", arg, err)),
+        Err(err) => Err(format!("cannot read '{}': {}.", arg, err)),
+    }
+}
+
+
+// Decodes a value into a table of its named fields, from the highest bit down, with a row for
+// each run of bits that no field covers.
+fn layout_info(value: i128, fields: &[layout::Field]) -> Report {
+    // Negative values are sign-extended, as for the ranges.
+    let pattern = value as u128;
+    let mut rows: Vec<(u32, Vec<String>)> = Vec::new();
+    let range = |high: u32, low: u32| match high == low {
+        true => high.to_string(),
+        false => format!("{}:{}", high, low),
+    };
+    let row = |name: &str, high: u32, low: u32, field: u128| {
+        vec![
+            name.to_string(),
+            range(high, low),
+            format!("0x{:X}", field),
+            field.to_string(),
+            bin_string(field, high - low + 1),
+        ]
+    };
+    for (field, bits) in fields.iter().zip(layout::decode(pattern, fields)) {
+        rows.push((field.high, row(&field.name, field.high, field.low, bits)));
+    }
+    for (high, low) in layout::gaps(fields) {
+        let bits = extract_field(pattern, high, low);
+        rows.push((high, row("(unused)", high, low, bits)));
+    }
+    rows.sort_by_key(|(high, _)| std::cmp::Reverse(*high));
+
+    let num_bits = fields[0].high + 1;
+    let bin = bin_string(pattern & field_mask(num_bits - 1, 0), num_bits);
+    let headers = ["field", "bits", "hex", "dec", "bin"];
+    let align = [Align::Left, Align::Right, Align::Right, Align::Right];
+    let rows = rows.into_iter().map(|(_, row)| row).collect();
+    Report::new().field("int", value).field("bin", bin).aligned_table(&headers, &align, rows)
+}
+
+
+// Parses a bit range written as `hi:lo` or as a single bit number.
+fn parse_bit_range(arg: &str) -> Result<(u32, u32), String> {
+    let parse = |text: &str| {
+        text.trim()
+            .parse::<u32>()
+            .map_err(|_| format!("cannot parse '{}' as a bit range, expected <hi>:<lo>.", arg))
+    };
+    let (high, low) = match arg.split_once(':') {
+        Some((high, low)) => (parse(high)?, parse(low)?),
+        None => (parse(arg)?, parse(arg)?),
+    };
+    if high > 127 {
+        return Err(format!("bit {} is out of range, expected 0 to 127.", high));
+    }
+    if high < low {
+        return Err(format!("invalid bit range '{}', the high bit comes first.", arg));
+    }
+    Ok((high, low))
+}
+
+
+// Returns the extracted value, mask, and shift for the field at bits `high` down to `low`. The
+// value is the integer's bit pattern, sign-extended to 128 bits.
+fn field_info(value: u128, high: u32, low: u32) -> Report {
+    let width = high - low + 1;
+    let field = extract_field(value, high, low);
+    let plural = if width == 1 { "" } else { "s" };
+    Report::new()
+        .field("fld", format!("bits {}:{} ({} bit{})", high, low, width, plural))
+        .field("msk", add_spacers(&format!("{:X}", field_mask(high, low)), ' ', 2))
+        .field("shr", low)
+        .field("hex", add_spacers(&format!("{:X}", field), ' ', 2))
+        .field("dec", add_spacers(&field.to_string(), ',', 3))
+        .field("oct", format!("{:o}", field))
+        .field("bin", bin_string(field, width))
+}
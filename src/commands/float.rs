@@ -0,0 +1,190 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::float;
+use crate::min_bits_unsigned;
+use crate::parse_u128;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector float [values]
+
+  Decodes IEEE 754 binary floating-point values, showing the sign, exponent,
+  and mantissa fields, the binary layout, the exact decimal value of the
+  stored number, and its classification.
+
+  Values can be specified as decimal floats, e.g. 0.1, -1e-40, inf, or nan,
+  or as bit patterns in prefixed binary, octal, or hexadecimal base, e.g.
+  0x3FB999999999999A.
+
+  Without a width flag, a hexadecimal pattern of 4, 8, or 16 digits is
+  decoded as an f16, f32, or f64 respectively, e.g. 0x7fc00000 as an f32.
+  Other values are decoded as f64.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "float"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode IEEE 754 f16, f32, and f64 values."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[values]", "List of floats or bit patterns.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "f16",
+                value: None,
+                help: "Decode as half precision.",
+            },
+            Opt {
+                names: "f32",
+                value: None,
+                help: "Decode as single precision.",
+            },
+            Opt {
+                names: "f64",
+                value: None,
+                help: "Decode as double precision.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_float(ctx, parser)
+    }
+}
+
+
+fn cmd_float(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let flag = [float::Width::F16, float::Width::F32, float::Width::F64]
+        .iter()
+        .copied()
+        .find(|width| cmd_parser.found(width.name()));
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let width = flag.unwrap_or_else(|| inferred_width(arg));
+        let output = float_bits(arg, width)
+            .map(|bits| float_info(bits, width))
+            .ok_or_else(|| format!("cannot parse '{}' as an {} value.", arg, width.name()));
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+// Returns the width matching the number of digits of a hexadecimal pattern, e.g. f32 for
+// 0x7fc00000, or f64 for any other value.
+fn inferred_width(arg: &str) -> float::Width {
+    let digits = arg
+        .strip_prefix("0x")
+        .or_else(|| arg.strip_prefix('x'))
+        .map(|hex| hex.chars().filter(|c| c.is_ascii_hexdigit()).count());
+    match digits {
+        Some(4) => float::Width::F16,
+        Some(8) => float::Width::F32,
+        _ => float::Width::F64,
+    }
+}
+
+
+// Parses a decimal float or a prefixed bit pattern into the bits of a float of the specified width.
+fn float_bits(arg: &str, width: float::Width) -> Option<u64> {
+    let is_pattern = ["0x", "0o", "0b", "x", "o", "b"].iter().any(|p| arg.starts_with(p));
+    if is_pattern {
+        let bits = parse_u128(arg).ok()?;
+        if min_bits_unsigned(bits) > width.bits() {
+            return None;
+        }
+        return Some(bits as u64);
+    }
+    let value = float::parse_float(arg)?;
+    match width {
+        float::Width::F16 => Some(float::f16_bits(value) as u64),
+        float::Width::F32 => Some((value as f32).to_bits() as u64),
+        float::Width::F64 => Some(value.to_bits()),
+    }
+}
+
+
+// Returns the info block for the bits of a float.
+fn float_info(bits: u64, width: float::Width) -> Report {
+    let fields = float::fields(bits, width);
+    let bin = format!("{:0width$b}", bits, width = width.bits() as usize);
+    let (sign, rest) = bin.split_at(1);
+    let (exponent, mantissa) = rest.split_at(width.exponent_bits() as usize);
+    let class = fields.class(width);
+    let mut report = Report::new()
+        .field("typ", width.name())
+        .field("hex", format!("0x{:0width$X}", bits, width = width.bits() as usize / 4))
+        .field("bin", format!("{} {} {}", sign, exponent, mantissa))
+        .field("sgn", format!("{} ({})", fields.sign, if fields.sign == 1 { "-" } else { "+" }));
+    report = match class {
+        float::Class::Normal | float::Class::Subnormal => {
+            let unbiased = fields.unbiased_exponent(width);
+            report.field("exp", format!("{} (unbiased {})", fields.exponent, unbiased))
+        },
+        _ => report.field("exp", fields.exponent),
+    };
+    let digits = width.mantissa_bits().div_ceil(4) as usize;
+    report = report.field("man", format!("0x{:0width$X}", fields.mantissa, width = digits));
+    let class_name = match class {
+        float::Class::Zero => "zero",
+        float::Class::Subnormal => "subnormal",
+        float::Class::Normal => "normal",
+        float::Class::Infinite => "infinity",
+        float::Class::QuietNan => "quiet NaN",
+        float::Class::SignalingNan => "signaling NaN",
+    };
+    report = report.field("cls", class_name);
+    let value = match width {
+        float::Width::F16 => float::f16_value(bits as u16),
+        float::Width::F32 => f32::from_bits(bits as u32) as f64,
+        float::Width::F64 => f64::from_bits(bits),
+    };
+    if value.is_finite() {
+        // The shortest string that round-trips, as printed by Rust.
+        let shortest = match width {
+            float::Width::F16 => format_float(float::f16_shortest(bits as u16), value),
+            float::Width::F32 => format_float(f32::from_bits(bits as u32), value),
+            float::Width::F64 => format_float(value, value),
+        };
+        report = report.field("val", shortest).field("ext", fields.exact_decimal(width).unwrap());
+    }
+    report
+}
+
+
+// Formats a float in plain or scientific notation depending on its magnitude.
+fn format_float<T: std::fmt::Display + std::fmt::LowerExp>(value: T, magnitude: f64) -> String {
+    let magnitude = magnitude.abs();
+    if magnitude == 0.0 || (1e-5..1e16).contains(&magnitude) {
+        format!("{}", value)
+    } else {
+        format!("{:e}", value)
+    }
+}
@@ -0,0 +1,96 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::helptext;
+use crate::commands::Decoder;
+use crate::commands::DECODERS;
+use crate::commands::Opt;
+use crate::man;
+
+
+const HELP: &str = "
+Usage: intspector gen-man [command]
+       intspector gen-man --dir <path>
+
+  Prints the roff man page for intspector, or for the specified command,
+  generated from the same metadata as the help text. With --dir, writes
+  intspector.1 and a page intspector-<command>.1 for every command to the
+  directory instead.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "gen-man"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Generate roff man pages from the help texts."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[command]", "Command to document. Defaults to intspector itself.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "dir d", value: Some("path"), help: "Write every page to a directory." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_gen_man(ctx, parser)
+    }
+}
+
+
+fn cmd_gen_man(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let root_help = helptext();
+    let root_page = || {
+        let see_also: Vec<String> = DECODERS
+            .iter()
+            .map(|decoder| format!("intspector-{}", decoder.name()))
+            .collect();
+        man::page("intspector", "integer conversion utility", &root_help, &see_also)
+    };
+    let command_page = |decoder: &dyn Decoder| {
+        let name = format!("intspector-{}", decoder.name());
+        man::page(&name, decoder.summary(), &decoder.helptext(), &["intspector".to_string()])
+    };
+
+    if let Some(dir) = cmd_parser.value("dir") {
+        let mut pages = vec![("intspector.1".to_string(), root_page())];
+        for decoder in DECODERS {
+            pages.push((format!("intspector-{}.1", decoder.name()), command_page(*decoder)));
+        }
+        for (filename, page) in pages {
+            let path = std::path::Path::new(&dir).join(filename);
+            if let Err(err) = std::fs::write(&path, page) {
+                return Err(Error::Invalid(format!("cannot write '{}': {}.", path.display(), err)));
+            }
+        }
+        return Ok(());
+    }
+    match cmd_parser.args.first() {
+        Some(name) => match DECODERS.iter().find(|d| d.names().split(' ').any(|n| n == name)) {
+            Some(decoder) => write!(ctx.out, "{}", command_page(*decoder))?,
+            None => {
+                return Err(Error::Invalid(format!("unknown command '{}'.", name)));
+            }
+        },
+        None => write!(ctx.out, "{}", root_page())?,
+    }
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use arguably::ArgParser;
+use crate::bin_string;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::geo;
+use crate::parse_uint;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector geo [indices]
+
+  Decodes geospatial indices. Geohash strings are decoded into their bit
+  pattern and the latitude/longitude bounds of the cell. Uber H3 64-bit
+  indices are decoded into their mode, resolution, base cell, and per-
+  resolution digits, along with the latitude and longitude of the cell's
+  center and the average cell size at that resolution.
+
+  H3 indices are recognised as strings of 15 or 16 hex digits, e.g.
+  8928308280fffff, or as integer literals with a base prefix. Use the
+  --geohash or --h3 flags to override the detection.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "geo"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode geohashes and H3 cell indices."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[indices]", "List of geohashes or H3 indices.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "geohash g", value: None, help: "Decode all arguments as geohashes." },
+            Opt { names: "h3", value: None, help: "Decode all arguments as H3 indices." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_geo(ctx, parser)
+    }
+}
+
+
+fn cmd_geo(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let h3_value = if arg.len() >= 15 && arg.len() <= 16 {
+            u64::from_str_radix(arg, 16).ok()
+        } else if arg.starts_with("0x") || arg.starts_with('x') {
+            parse_uint(arg).ok()
+        } else {
+            None
+        };
+        let output = if cmd_parser.found("geohash") {
+            geohash_info(arg)
+        } else if cmd_parser.found("h3") {
+            match h3_value.or_else(|| parse_uint(arg).ok()) {
+                Some(index) => h3_info(index),
+                None => Err(format!("cannot parse '{}' as a 64-bit H3 index.", arg)),
+            }
+        } else {
+            match h3_value {
+                Some(index) if geo::h3_decode(index).is_some() => h3_info(index),
+                _ => geohash_info(arg),
+            }
+        };
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn geohash_info(hash: &str) -> Result<Report, String> {
+    let (value, num_bits) = match geo::geohash_bits(hash) {
+        Some(result) => result,
+        None => return Err(format!("'{}' is not a valid geohash.", hash)),
+    };
+    let bounds = geo::geohash_decode(hash).unwrap();
+    let lat = (bounds.lat_min + bounds.lat_max) / 2.0;
+    let lon = (bounds.lon_min + bounds.lon_max) / 2.0;
+    let range = |mid: f64, min: f64, max: f64| {
+        format!("{:.6} ± {:.6} [{:.6}, {:.6}]", mid, (max - min) / 2.0, min, max)
+    };
+    let size = format!("{} ({} characters, {} bits)", hash.to_lowercase(), hash.len(), num_bits);
+    Ok(Report::new()
+        .field("ghs", size)
+        .field("bin", bin_string(value as u128, num_bits))
+        .field("lat", range(lat, bounds.lat_min, bounds.lat_max))
+        .field("lon", range(lon, bounds.lon_min, bounds.lon_max)))
+}
+
+
+fn h3_info(index: u64) -> Result<Report, String> {
+    let h3 = match geo::h3_decode(index) {
+        Some(h3) => h3,
+        None => return Err(format!("0x{:x} is not a valid H3 index.", index)),
+    };
+    let mode = match h3.mode {
+        1 => "cell".to_string(),
+        2 => format!("directed edge, edge {}", h3.mode_bits),
+        3 => "undirected edge".to_string(),
+        _ => format!("vertex, vertex {}", h3.mode_bits),
+    };
+    let digits: Vec<String> = h3.digits.iter().map(|digit| digit.to_string()).collect();
+    let (edge, area) = geo::H3_CELL_SIZES[h3.resolution as usize];
+    let pentagon = if geo::H3_PENTAGONS.contains(&h3.base_cell) { " (pentagon)" } else { "" };
+    let mut report = Report::new()
+        .field("h3", format!("{:x}", index))
+        .field("bin", bin_string(index as u128, 64))
+        .field("mod", format!("{} ({})", h3.mode, mode))
+        .field("res", h3.resolution)
+        .field("bas", format!("{}{}", h3.base_cell, pentagon))
+        .field("dig", if digits.is_empty() { "none".to_string() } else { digits.join(" ") });
+    if let Some((lat, lon)) = geo::h3_center(index) {
+        report = report.field("lat", format!("{:.6} (cell center)", lat));
+        report = report.field("lon", format!("{:.6}", lon));
+    }
+    Ok(report.field("siz", format!("average hexagon edge {} km, area {} km²", edge, area)))
+}
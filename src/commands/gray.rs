@@ -0,0 +1,94 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::from_gray;
+use crate::parse_uint;
+use crate::report::Report;
+use crate::to_gray;
+
+
+const HELP: &str = "
+Usage: intspector gray [integers]
+
+  Converts integers to the binary-reflected Gray code, in which consecutive
+  integers differ in exactly one bit, as used for rotary encoders, Karnaugh
+  maps, and FIFO pointers that cross clock domains. With the --decode flag,
+  converts Gray codes back to integers.
+
+  The binary and Gray code bit patterns are printed one above the other, so
+  the bits that differ line up.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  e.g. 0b1101, and are read as 64-bit unsigned values.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "gray"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert integers to and from binary-reflected Gray code."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to convert.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "decode d", value: None, help: "Decode Gray codes to integers." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_gray(ctx, parser)
+    }
+}
+
+
+fn cmd_gray(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = match parse_uint(arg) {
+            Ok(value) if cmd_parser.found("decode") => Ok(gray_info(from_gray(value))),
+            Ok(value) => Ok(gray_info(value)),
+            Err(err) => Err(parse_error(arg, &err)),
+        };
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+// Shows an integer and its Gray code, with the two bit patterns aligned one above the other.
+fn gray_info(value: u64) -> Report {
+    let gray = to_gray(value);
+    // The Gray code has the same highest set bit as the integer, so both fit the same width.
+    let width = (64 - value.leading_zeros()).max(1) as usize;
+    let bits = |value: u64| add_spacers(&format!("{:0width$b}", value, width = width), ' ', 4);
+    Report::new()
+        .field("int", format!("{} (0x{:X})", value, value))
+        .field("gry", format!("{} (0x{:X})", gray, gray))
+        .field("bin", format!("{}  binary\n{}  gray", bits(value), bits(gray)))
+}
@@ -0,0 +1,181 @@
+use arguably::ArgParser;
+use crate::analyze;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::cli::InfoOpts;
+use crate::cli::print_result;
+use crate::cli::uint_info;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::hash;
+use crate::report::Report;
+use crate::std_bits;
+
+
+const HELP: &str = "
+Usage: intspector hash [values]
+
+  Hashes each value with a non-cryptographic hash function and displays the
+  hash in all four bases, e.g. for checking hash table bucket assignments.
+
+  The algorithm can be fnv1a (FNV-1a), djb2, murmur3 (MurmurHash3_x86_32),
+  or crc32. FNV-1a and djb2 have 32- and 64-bit variants; murmur3 and crc32
+  are 32-bit only.
+
+  Arguments that parse as integers or expressions are hashed as their
+  little-endian bytes, by default in the smallest of 32, 64, or 128 bits
+  that fits the value; negative integers are hashed as two's complement.
+  Other arguments are hashed as their UTF-8 bytes.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "hash"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Hash integers and strings with hash table functions."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[values]", "List of integers or strings to hash.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "algo a",
+                value: Some("name"),
+                help: "Algorithm to compute. Defaults to fnv1a.",
+            },
+            Opt {
+                names: "buckets n",
+                value: Some("n"),
+                help: "Also show the bucket index for a table of n buckets.",
+            },
+            Opt {
+                names: "key-bits k",
+                value: Some("n"),
+                help: "Width of integer keys: 8, 16, 32, 64, or 128 bits.",
+            },
+            Opt { names: "seed", value: Some("n"), help: "Seed for murmur3. Defaults to 0." },
+            Opt {
+                names: "width w",
+                value: Some("bits"),
+                help: "Width of the hash: 32 or 64 bits. Defaults to 32.",
+            },
+            Opt { names: "string s", value: None, help: "Hash every argument as a string." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_hash(ctx, parser)
+    }
+}
+
+
+fn cmd_hash(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let algo = cmd_parser.value("algo").unwrap_or_else(|| "fnv1a".to_string());
+    let width = match cmd_parser.value("width") {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value) => value,
+            Err(_) => {
+                return Err(Error::Invalid(format!("cannot parse '{}' as a width.", arg)));
+            }
+        },
+        None => 32,
+    };
+    if let Err(msg) = hash::hash(&algo, &[], width, 0) {
+        return Err(Error::Invalid(format!("{}.", msg)));
+    }
+    let key_bits = match cmd_parser.value("key-bits") {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value) if [8, 16, 32, 64, 128].contains(&value) => Some(value),
+            _ => {
+                let msg = "the key width must be 8, 16, 32, 64, or 128 bits.";
+                return Err(Error::Invalid(msg.to_string()));
+            }
+        },
+        None => None,
+    };
+    let seed = match cmd_parser.value("seed") {
+        Some(arg) => match eval_arg(&arg) {
+            Ok(value) if (0..=u32::MAX as i128).contains(&value) => value as u32,
+            Ok(_) => {
+                return Err(Error::Invalid("the seed must be from 0 to 2^32 - 1.".to_string()));
+            },
+            Err(msg) => {
+                return Err(Error::Invalid(msg));
+            }
+        },
+        None => 0,
+    };
+    let buckets = match cmd_parser.value("buckets") {
+        Some(arg) => match eval_arg(&arg) {
+            Ok(value) if (1..=u64::MAX as i128).contains(&value) => Some(value as u64),
+            Ok(_) => {
+                let msg = "the number of buckets must be from 1 to 2^64 - 1.";
+                return Err(Error::Invalid(msg.to_string()));
+            },
+            Err(msg) => {
+                return Err(Error::Invalid(msg));
+            }
+        },
+        None => None,
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let key = match eval_arg(arg) {
+            Ok(value) if !cmd_parser.found("string") => int_key(value, key_bits),
+            _ => Ok(arg.as_bytes().to_vec()),
+        };
+        let output = key.map(|bytes| {
+            let value = hash::hash(&algo, &bytes, width, seed).unwrap();
+            hash_info(&bytes, value, width, buckets)
+        });
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+// Returns the little-endian bytes of an integer key, in two's complement if it's negative.
+fn int_key(value: i128, key_bits: Option<u32>) -> Result<Vec<u8>, String> {
+    let key_bits = key_bits.unwrap_or_else(|| std_bits(value).max(32));
+    let info = analyze(value, Some(key_bits)).map_err(|err| format!("{}.", err))?;
+    Ok(info.bits.to_le_bytes()[..key_bits as usize / 8].to_vec())
+}
+
+
+fn hash_info(bytes: &[u8], value: u64, width: u32, buckets: Option<u64>) -> Report {
+    let opts = InfoOpts::default();
+    let plural = if bytes.len() == 1 { "" } else { "s" };
+    let mut key: Vec<String> = bytes.iter().take(16).map(|byte| format!("{:02X}", byte)).collect();
+    if bytes.len() > 16 {
+        key.push("...".to_string());
+    }
+    let mut report = Report::new()
+        .field("len", format!("{} byte{}", bytes.len(), plural))
+        .field("key", if key.is_empty() { "(empty)".to_string() } else { key.join(" ") });
+    report = report.append(uint_info(value as u128, width, &opts));
+    if let Some(buckets) = buckets {
+        report = report.field("bkt", format!("{} of {}", value % buckets, buckets));
+    }
+    report
+}
@@ -0,0 +1,121 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::commands::Decoder;
+use crate::huffman;
+use crate::parse_uint;
+use crate::report::Align;
+use crate::report::Report;
+use crate::shannon_entropy;
+
+
+const HELP: &str = "
+Usage: intspector huffman [symbol:frequency]
+
+  Builds a Huffman code for a set of symbols with the specified
+  frequencies, then assigns canonical codewords: symbols are sorted by code
+  length, then in the order given, and numbered consecutively, so the code
+  can be rebuilt from the code lengths alone, as in DEFLATE and JPEG.
+
+  Shows each symbol's codeword, the average code length in bits per symbol
+  compared with the entropy of the frequencies, and the total length of a
+  message with the specified symbol counts.
+
+  Frequencies can be specified in binary, octal, decimal, or hexadecimal
+  base. The symbol is everything before the last colon.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "huffman"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Build canonical Huffman codes from frequencies."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[symbol:frequency]", "List of symbols and their frequencies, e.g. a:45.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_huffman(ctx, parser)
+    }
+}
+
+
+fn cmd_huffman(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let mut symbols: Vec<&str> = Vec::new();
+    let mut frequencies = Vec::new();
+    for arg in &cmd_parser.args {
+        let (symbol, frequency) = match arg.rsplit_once(':') {
+            Some((symbol, frequency)) if !symbol.is_empty() => (symbol, frequency),
+            _ => {
+                return Err(Error::Invalid(format!("expected symbol:frequency, found '{}'.", arg)));
+            }
+        };
+        match parse_uint(frequency) {
+            Ok(0) => {
+                let msg = format!("the frequency of '{}' must be at least 1.", symbol);
+                return Err(Error::Invalid(msg));
+            },
+            Ok(frequency) => frequencies.push(frequency),
+            Err(err) => {
+                return Err(Error::Invalid(format!("cannot parse '{}': {}.", frequency, err)));
+            }
+        }
+        if symbols.contains(&symbol) {
+            return Err(Error::Invalid(format!("the symbol '{}' is listed twice.", symbol)));
+        }
+        symbols.push(symbol);
+    }
+    if symbols.is_empty() {
+        return Ok(());
+    }
+    let lengths = huffman::code_lengths(&frequencies);
+    let codes = match huffman::canonical_codes(&lengths) {
+        Some(codes) => codes,
+        None => {
+            return Err(Error::Invalid("a code is longer than 128 bits.".to_string()));
+        }
+    };
+    let total_frequency: u128 = frequencies.iter().map(|f| *f as u128).sum();
+    let mut total_bits: u128 = 0;
+    let mut rows = Vec::new();
+    for i in 0..symbols.len() {
+        let code = format!("{:0width$b}", codes[i], width = lengths[i] as usize);
+        rows.push(vec![symbols[i].to_string(), code, frequencies[i].to_string()]);
+        total_bits += frequencies[i] as u128 * lengths[i] as u128;
+    }
+    ctx.termline()?;
+    let align = [Align::Right, Align::Left, Align::Right];
+    Report::new().aligned_table(&["symbol", "code", "frequency"], &align, rows).print(ctx)?;
+    ctx.termline()?;
+    let average = total_bits as f64 / total_frequency as f64;
+    let entropy = shannon_entropy(&frequencies);
+    let total = format!(
+        "{} bit{} for {} symbol{}",
+        total_bits,
+        if total_bits == 1 { "" } else { "s" },
+        total_frequency,
+        if total_frequency == 1 { "" } else { "s" },
+    );
+    Report::new()
+        .field("avg", format!("{:.4} bits per symbol, entropy {:.4}", average, entropy))
+        .field("tot", total)
+        .print(ctx)?;
+    ctx.termline()?;
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_input;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::magic;
+use crate::poison;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector ident [integers]
+
+  Names well-known constants: hash and PRNG constants, e.g. 0x9E3779B9,
+  CRC polynomials in normal and reflected form, byte order marks, file
+  signatures read as big-endian integers, e.g. 0x7F454C46 for ELF, special
+  float bit patterns, integer limits, and poison values, e.g. 0xDEADBEEF.
+
+  If nothing matches exactly, also tries the integer's bytes in reverse
+  order, for constants stored with the other endianness. Negative integers
+  are matched as 32- and 64-bit two's complement values.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "ident"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Identify well-known magic constants."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to identify.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_ident(ctx, parser)
+    }
+}
+
+
+fn cmd_ident(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        print_result(ctx, arg, eval_input(arg).and_then(|value| Ok(ident_info(value)?)))?;
+    }
+    Ok(())
+}
+
+
+// Lists the constants matching the value, or for a negative value, its two's complement patterns.
+fn ident_info(value: i128) -> Result<Report, String> {
+    let patterns: Vec<u64> = if value < 0 {
+        let mut patterns = Vec::new();
+        if value >= i32::MIN as i128 {
+            patterns.push(value as u32 as u64);
+        }
+        if value >= i64::MIN as i128 {
+            patterns.push(value as u64);
+        }
+        patterns
+    } else if value <= u64::MAX as i128 {
+        vec![value as u64]
+    } else {
+        Vec::new()
+    };
+    if patterns.is_empty() {
+        return Err(format!("{} doesn't fit in 64 bits.", value));
+    }
+    let mut report = Report::new();
+    for pattern in patterns {
+        report = report.field("val", format!("0x{:X}", pattern));
+        let matches = magic::identify(pattern);
+        for found in &matches {
+            let note = if found.swapped { " (bytes reversed)" } else { "" };
+            report = report.field("mat", format!("{}{}\n{}", found.name, note, found.description));
+        }
+        let poison = poison::describe(pattern);
+        if let Some(desc) = poison {
+            report = report.field("psn", desc);
+        }
+        if matches.is_empty() && poison.is_none() {
+            report = report.field("mat", "no known constant");
+        }
+    }
+    Ok(report)
+}
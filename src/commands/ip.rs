@@ -0,0 +1,133 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::cli::InfoOpts;
+use crate::cli::print_result;
+use crate::cli::uint_info;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::ip;
+use crate::parse_u128;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector ip [addresses]
+
+  Interprets integers as IP addresses and IP addresses as integers. A 32-bit
+  value is shown as an IPv4 address in dotted-quad notation with its
+  classful network class, and a wider value as an IPv6 address in the
+  canonical compressed form of RFC 5952 and in full. Each address is
+  classified by the special-purpose block containing it, e.g. private,
+  loopback, or documentation, or as public or global unicast.
+
+  The address's integer value is shown in hexadecimal, decimal, octal, and
+  binary, at 32 bits for IPv4 and 128 bits for IPv6.
+
+  Addresses can be written in dotted-quad or IPv6 notation, including
+  IPv4-mapped addresses like ::ffff:192.168.1.1, or as integers in binary,
+  octal, decimal, or hexadecimal base. Integers are read as IPv4 addresses
+  if they fit in 32 bits, unless the --v6 flag is specified.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "ip"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert between integers and IPv4 or IPv6 addresses."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[addresses]", "List of addresses or integers.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "v6 6", value: None, help: "Read all integers as IPv6 addresses." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_ip(ctx, parser)
+    }
+}
+
+
+fn cmd_ip(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = parse_ip(arg, cmd_parser.found("v6")).map(|(addr, v6)| match v6 {
+            false => ipv4_info(addr as u32),
+            true => ipv6_info(addr),
+        });
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+// Parses an address or an integer, returning its value and true if it's an IPv6 address.
+fn parse_ip(arg: &str, v6: bool) -> Result<(u128, bool), String> {
+    if arg.contains(':') {
+        return match ip::parse_v6(arg) {
+            Some(addr) => Ok((addr, true)),
+            None => Err(format!("cannot parse '{}' as an IPv6 address.", arg)),
+        };
+    }
+    if arg.contains('.') {
+        return match ip::parse_v4(arg) {
+            Some(addr) => Ok((addr as u128, false)),
+            None => Err(format!("cannot parse '{}' as an IPv4 address.", arg)),
+        };
+    }
+    let value = match parse_u128(arg) {
+        Ok(value) => value,
+        Err(_) => match eval_arg(arg)? {
+            value if value < 0 => return Err(format!("'{}' is negative.", arg)),
+            value => value as u128,
+        },
+    };
+    Ok((value, v6 || value > u32::MAX as u128))
+}
+
+
+fn ipv4_info(addr: u32) -> Report {
+    let mut report = Report::new()
+        .field("ip4", ip::format_v4(addr))
+        .field("cls", ip::v4_class(addr))
+        .field("typ", ip::v4_scope(addr))
+        .field("ip6", ip::format_v6(0xFFFF << 32 | addr as u128));
+    report = report.append(uint_info(addr as u128, 32, &InfoOpts::default()));
+    report
+}
+
+
+fn ipv6_info(addr: u128) -> Report {
+    let mut report = Report::new()
+        .field("ip6", ip::format_v6(addr))
+        .field("exp", ip::format_v6_full(addr))
+        .field("typ", ip::v6_scope(addr));
+    if let Some(v4) = ip::mapped_v4(addr) {
+        report = report.field("ip4", ip::format_v4(v4));
+    }
+    report = report.append(uint_info(addr, 128, &InfoOpts::default()));
+    report
+}
@@ -0,0 +1,123 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::cli::relative_time;
+use crate::codec;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::datetime;
+use crate::json;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector jwt [tokens]
+
+  Decodes the header and payload of each JSON web token and displays the
+  numeric date claims (iat, nbf, exp, auth_time) as UTC datetimes.
+
+  The token's signature is not verified.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "jwt"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode JSON web tokens."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[tokens]", "List of JSON web tokens.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_jwt(ctx, parser)
+    }
+}
+
+
+fn cmd_jwt(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        print_result(ctx, arg, jwt_info(arg, ctx.env.now))?;
+    }
+    Ok(())
+}
+
+
+fn jwt_info(token: &str, now: i64) -> Result<Report, String> {
+    let parts: Vec<&str> = token.trim().split('.').collect();
+    if parts.len() != 3 {
+        return Err(format!("expected 3 dot-separated segments, found {}.", parts.len()));
+    }
+
+    let decode = |segment: &str, name: &str| -> Result<(String, json::Value), String> {
+        let bytes = codec::base64_decode(segment)
+            .ok_or_else(|| format!("the {} is not valid base64url.", name))?;
+        let text = String::from_utf8(bytes)
+            .map_err(|_| format!("the {} is not valid UTF-8.", name))?;
+        let value = json::parse(&text)
+            .ok_or_else(|| format!("the {} is not valid JSON.", name))?;
+        Ok((text, value))
+    };
+    let (header_text, header) = decode(parts[0], "header")?;
+    let (payload_text, payload) = decode(parts[1], "payload")?;
+    let signature = codec::base64_decode(parts[2])
+        .ok_or_else(|| "the signature is not valid base64url.".to_string())?;
+
+    let mut report = Report::new();
+    for key in &["alg", "typ", "kid"] {
+        if let Some(value) = header.get(key).and_then(|v| v.as_str()) {
+            report = report.field(key, value);
+        }
+    }
+    report = report.field("hdr", header_text).field("pld", payload_text);
+
+    for key in &["iat", "nbf", "exp", "auth_time"] {
+        let value = match payload.get(key) {
+            Some(value) => value,
+            None => continue,
+        };
+        // NumericDate values can have a fraction, e.g. 1500000000.5, which is truncated.
+        let secs = value.as_i64().or_else(|| {
+            value.as_f64().filter(|secs| secs.abs() < 1e17).map(|secs| secs.trunc() as i64)
+        });
+        report = match secs {
+            Some(secs) => report.field(
+                key,
+                format!(
+                    "{} ({}, {})",
+                    value,
+                    datetime::format_utc(secs),
+                    relative_time(secs - now),
+                ),
+            ),
+            None => report.field(key, format!("unparseable, {} is not a NumericDate", value)),
+        };
+    }
+
+    Ok(report.field("sig", format!("{} bytes (not verified)", signature.len())))
+}
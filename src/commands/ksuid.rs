@@ -0,0 +1,72 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::hex_bytes;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::datetime;
+use crate::ids;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector ksuid [ksuids]
+
+  Decodes KSUIDs (27-character base 62 strings) into their 20 bytes, their
+  32-bit timestamp in seconds since the KSUID epoch (1400000000) displayed
+  as a UTC datetime, and their 16-byte random payload.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "ksuid"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode KSUIDs."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[ksuids]", "List of KSUIDs.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_ksuid(ctx, parser)
+    }
+}
+
+
+fn cmd_ksuid(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = ids::ksuid_decode(arg).map(|bytes| {
+            let (timestamp, payload) = ids::ksuid_fields(&bytes);
+            let time = format!(
+                "{} ({}, offset {} from the KSUID epoch)",
+                timestamp,
+                datetime::format_utc(timestamp),
+                timestamp - ids::KSUID_EPOCH,
+            );
+            Report::new()
+                .field("hex", hex_bytes(&bytes))
+                .field("tim", time)
+                .field("pld", hex_bytes(&payload))
+        });
+        print_result(ctx, arg, output.ok_or(format!("'{}' is not a valid KSUID.", arg)))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use arguably::ArgParser;
+use crate::cli::char_encodings;
+use crate::cli::char_properties;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::commands::Decoder;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector l2cp|literal-to-codepoint [characters]
+
+  Converts character literals to unicode code points, i.e. takes a list of
+  chacters literals as input and prints out the unicode code point for each
+  character in the list.
+
+  Also shows each character's UTF-8 bytes and UTF-16 code units, its
+  general category and block, and its name for the common blocks, CJK
+  unified ideographs, and Hangul syllables.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "l2cp literal-to-codepoint"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert character literals to code points."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[characters]", "List of character literals.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_l2cp(ctx, parser)
+    }
+}
+
+
+fn cmd_l2cp(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let mut argstring = String::new();
+    for arg in &cmd_parser.args {
+        argstring.push_str(arg);
+    }
+    if !argstring.is_empty() {
+        ctx.termline()?;
+    }
+    for c in argstring.chars() {
+        Report::new()
+            .field("lit", c)
+            .field("uni", format!("U+{:04X}", c as u32))
+            .append(char_encodings(c))
+            .append(char_properties(c))
+            .print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::mac;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector mac [addresses]
+
+  Inspects 48-bit MAC addresses, showing the address in common notations,
+  as an integer, the meaning of the I/G (multicast) and U/L (locally
+  administered) bits, and the modified EUI-64 interface identifier used for
+  IPv6 autoconfiguration. Identifies the vendor from the OUI prefix where
+  the embedded OUI table includes it.
+
+  Addresses can be written as colon- or hyphen-separated octets, in Cisco
+  dotted notation, as 12 bare hex digits, or as integer literals.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "mac"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Inspect MAC addresses."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[addresses]", "List of MAC addresses.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_mac(ctx, parser)
+    }
+}
+
+
+fn cmd_mac(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = mac::parse(arg).map(mac_info);
+        print_result(ctx, arg, output.ok_or(format!("cannot parse '{}' as a MAC address.", arg)))?;
+    }
+    Ok(())
+}
+
+
+fn mac_info(addr: u64) -> Report {
+    let kind = format!(
+        "{}, {}",
+        if mac::is_multicast(addr) { "multicast (I/G bit set)" } else { "unicast" },
+        if mac::is_local(addr) { "locally administered (U/L bit set)" } else { "universal" },
+    );
+    let oui = mac::format(addr, ':')[..8].to_string();
+    let oui = match mac_vendor(addr) {
+        Some(vendor) => format!("{} ({})", oui, vendor),
+        None => oui,
+    };
+    let eui64 = format!("{:016x}", mac::modified_eui64(addr));
+    Report::new()
+        .field("mac", mac::format(addr, ':'))
+        .field("alt", format!("{}, {}", mac::format(addr, '-'), mac::format_cisco(addr)))
+        .field("hex", add_spacers(&format!("{:012X}", addr), ' ', 2))
+        .field("dec", add_spacers(&addr.to_string(), ',', 3))
+        .field("typ", kind)
+        .field("oui", oui)
+        .field("e64", add_spacers(&eui64, ':', 4))
+}
+
+
+#[cfg(feature = "oui")]
+fn mac_vendor(addr: u64) -> Option<&'static str> {
+    crate::oui::vendor(addr)
+}
+
+
+#[cfg(not(feature = "oui"))]
+fn mac_vendor(_addr: u64) -> Option<&'static str> {
+    None
+}
@@ -0,0 +1,104 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::midi;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector midi [notes]
+
+  Converts MIDI note numbers (0-127) or note names in scientific pitch
+  notation, e.g. A4, C#3, or Bb-1, to note numbers, names, and frequencies in
+  twelve-tone equal temperament. Middle C (C4) is note 60.
+
+  With the --freq flag, converts frequencies in Hz to the nearest note and
+  reports the offset in cents.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "midi"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert MIDI note numbers, names, and frequencies."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[notes]", "List of note numbers, note names, or frequencies.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "tuning t",
+                value: Some("hz"),
+                help: "Frequency of A4 in Hz. Defaults to 440.",
+            },
+            Opt { names: "freq f", value: None, help: "Convert frequencies in Hz to notes." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_midi(ctx, parser)
+    }
+}
+
+
+fn cmd_midi(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let a4 = match cmd_parser.value("tuning") {
+        Some(arg) => match arg.parse::<f64>() {
+            Ok(hz) if hz > 0.0 && hz.is_finite() => hz,
+            _ => {
+                return Err(Error::Invalid(format!("cannot parse '{}' as a frequency in Hz.", arg)));
+            }
+        },
+        None => midi::A440,
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        print_result(ctx, arg, midi_info(arg, a4, cmd_parser.found("freq")))?;
+    }
+    Ok(())
+}
+
+
+fn midi_info(arg: &str, a4: f64, freq: bool) -> Result<Report, String> {
+    let note_info = |note: u32| {
+        Report::new()
+            .field("num", note)
+            .field("not", midi::note_name(note).unwrap_or_default())
+            .field("frq", format!("{:.2} Hz", midi::frequency(note, a4)))
+    };
+    if freq {
+        let freq = arg.trim_end_matches("Hz").trim_end_matches("hz");
+        return match freq.parse::<f64>().ok().and_then(|freq| midi::nearest_note(freq, a4)) {
+            Some((note, cents)) => Ok(note_info(note).field("off", format!("{:+.1} cents", cents))),
+            None => Err(format!("no MIDI note near '{}' Hz.", arg)),
+        };
+    }
+    let note = match arg.parse::<u32>() {
+        Ok(note) => Some(note).filter(|note| *note <= 127),
+        Err(_) => midi::parse_note(arg),
+    };
+    note.map(note_info).ok_or_else(|| format!("'{}' is not a valid MIDI note.", arg))
+}
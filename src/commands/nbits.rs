@@ -0,0 +1,129 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::bigint::BigUint;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_uint;
+use crate::pow;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector nbits [values]
+
+  Expands Bitcoin's 32-bit compact target encoding (the nBits field of a
+  block header) into the full 256-bit target, showing the exponent/mantissa
+  split and the corresponding difficulty. The high byte is a base-256
+  exponent giving the length of the target in bytes and the low three bytes
+  are the mantissa, so the target is mantissa × 256^(exponent - 3).
+
+  Compact values can be specified in binary, octal, decimal, or hexadecimal
+  base, e.g. 0x1d00ffff. With the --target flag, converts targets written
+  as hex digits back to compact form.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "nbits"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Expand Bitcoin compact difficulty targets."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[values]", "List of compact values or targets.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "target t", value: None, help: "Convert hex targets to compact form." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_nbits(ctx, parser)
+    }
+}
+
+
+fn cmd_nbits(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let bits = if cmd_parser.found("target") {
+            match BigUint::from_hex(arg) {
+                Some(target) if target.bits() <= 256 => pow::target_to_compact(&target),
+                _ => {
+                    Report::error(&format!("cannot parse '{}' as a 256-bit hex target.", arg))
+                        .input(arg)
+                        .print(ctx)?;
+                    ctx.termline()?;
+                    continue;
+                }
+            }
+        } else {
+            match parse_uint(arg).ok().filter(|value| *value <= u32::MAX as u64) {
+                Some(value) => value as u32,
+                None => {
+                    Report::error(&format!("cannot parse '{}' as a 32-bit compact value.", arg))
+                        .input(arg)
+                        .print(ctx)?;
+                    ctx.termline()?;
+                    continue;
+                }
+            }
+        };
+        let fields = pow::compact_fields(bits);
+        let sign = if fields.negative { " (negative)" } else { "" };
+        let report = Report::new()
+            .field("cmp", format!("0x{:08X}", bits))
+            .field("exp", format!("{} (0x{:02X})", fields.exponent, fields.exponent))
+            .field("man", format!("0x{:06X}{}", fields.mantissa, sign));
+        let report = match pow::compact_to_target(bits) {
+            Some(target) => {
+                let hex = format!("{:0>64}", target.to_hex());
+                let report = report
+                    .field("tgt", add_spacers(&hex, ' ', 8))
+                    .field("zer", format!("{} leading zero bits", 256 - target.bits()));
+                match pow::difficulty(&target) {
+                    Some(difficulty) => report.field("dif", format_difficulty(difficulty)),
+                    None => report.field("dif", "undefined (zero target)"),
+                }
+            },
+            None if fields.negative => report.and_error("the sign bit is set."),
+            None => report.and_error("the target overflows 256 bits."),
+        };
+        report.input(arg).print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
+
+
+// Formats a difficulty with thousands separators and up to eight significant decimal places.
+fn format_difficulty(difficulty: f64) -> String {
+    if difficulty >= 1e15 {
+        return format!("{:.6e}", difficulty);
+    }
+    let whole = difficulty.trunc() as u64;
+    let fraction = format!("{:.8}", difficulty.fract());
+    let fraction = fraction.trim_start_matches('0').trim_end_matches('0').trim_end_matches('.');
+    format!("{}{}", add_spacers(&whole.to_string(), ',', 3), fraction)
+}
@@ -0,0 +1,107 @@
+use arguably::ArgParser;
+use crate::babylonian_digit;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::commands::Decoder;
+use crate::maya_digit;
+use crate::mixed_radix_digits;
+use crate::parse_u128;
+use crate::radix_digits;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector numerals [integers]
+
+  Renders non-negative integers in two historical place-value systems:
+
+  - Babylonian sexagesimal (base 60). Each digit, 0 to 59, is written in
+    cuneiform with a wedge for each ten and a wedge for each unit. The
+    Babylonians had no zero digit and left a gap instead, shown as _.
+  - Maya vigesimal (base 20), using the Unicode Mayan numerals for the
+    digits 0 to 19.
+
+  Also shows the integer as a Maya Long Count date, a count of days written
+  as b'ak'tun.k'atun.tun.winal.k'in, in which the winal position counts to
+  18 rather than 20 so that a tun is 360 days.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "numerals"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Show Babylonian and Maya numerals."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to convert.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_numerals(ctx, parser)
+    }
+}
+
+
+fn cmd_numerals(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let value = match parse_u128(arg) {
+            Ok(value) => value,
+            Err(err) => {
+                parse_error(arg, &err).input(arg).print(ctx)?;
+                ctx.termline()?;
+                continue;
+            }
+        };
+        let join = |digits: &[u32], separator: &str| -> String {
+            let digits: Vec<String> = digits.iter().rev().map(|d| d.to_string()).collect();
+            digits.join(separator)
+        };
+        let sexagesimal = radix_digits(value, 60);
+        let vigesimal = radix_digits(value, 20);
+        let cuneiform: Vec<String> =
+            sexagesimal.iter().rev().map(|d| babylonian_digit(*d)).collect();
+        let glyphs: Vec<String> = vigesimal
+            .iter()
+            .rev()
+            .filter_map(|d| maya_digit(*d))
+            .map(|glyph| glyph.to_string())
+            .collect();
+        let long_count: Vec<String> = mixed_radix_digits(value, &[20, 18, 20, 20])
+            .iter()
+            .rev()
+            .map(|d| d.to_string())
+            .collect();
+        Report::new()
+            .field("dec", value)
+            .field("sxg", join(&sexagesimal, ","))
+            .field("bab", cuneiform.join(" "))
+            .field("vig", join(&vigesimal, "."))
+            .field("may", glyphs.join(" "))
+            .field("lct", long_count.join("."))
+            .input(arg)
+            .print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::datetime;
+use crate::ids;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector objectid [ids]
+
+  Decodes MongoDB ObjectIds (24-character hex strings) into their 4-byte
+  timestamp displayed as a UTC datetime, their 5-byte random value (split
+  into the legacy 3-byte machine identifier and 2-byte process ID), and
+  their 3-byte counter.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "objectid"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode MongoDB ObjectIds."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[ids]", "List of ObjectIds.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_objectid(ctx, parser)
+    }
+}
+
+
+fn cmd_objectid(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = ids::objectid_decode(arg).map(|oid| {
+            let random = format!(
+                "0x{:010X} (legacy machine 0x{:06X}, pid {})",
+                oid.random,
+                oid.machine(),
+                oid.pid(),
+            );
+            let time = format!("{} ({})", oid.timestamp, datetime::format_utc(oid.timestamp));
+            Report::new()
+                .field("tim", time)
+                .field("rnd", random)
+                .field("cnt", format!("{} (0x{:06X})", oid.counter, oid.counter))
+        });
+        print_result(ctx, arg, output.ok_or(format!("'{}' is not a valid ObjectId.", arg)))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use arguably::ArgParser;
+use crate::bin_string;
+use crate::cli::Context;
+use crate::cli::display_bits;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::cli::parse_u32_option;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::min_bits;
+use crate::report::Report;
+use crate::std_bits;
+
+
+const HELP: &str = "
+Usage: intspector ops <operator> <a> [b]
+
+  Applies a bitwise operator to integers, showing the operands and the
+  result in binary, hexadecimal, decimal, and octal, with the binary digits
+  aligned.
+
+  Operators:
+
+  - and, or, xor: Bitwise AND, OR, and exclusive OR of a and b.
+  - not: Bitwise complement of a.
+  - shl: a shifted left by b bits. Bits shifted past the width are lost.
+  - shr: a shifted right by b bits, filling with zeros, or with copies of
+    the sign bit if the --arithmetic flag is set.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions. Negative integers are read as two's complement values.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "ops"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Apply bitwise operators to integers."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("<operator>", "Operator to apply."),
+            ("<a>", "First operand."),
+            ("[b]", "Second operand or shift amount."),
+        ]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "bits b",
+                value: Some("n"),
+                help: "Width of the operands and result in bits. Defaults to the width of the \
+                      wider operand, or for shl, of the shifted value.",
+            },
+            Opt {
+                names: "arithmetic a",
+                value: None,
+                help: "Fill with the sign bit when shifting right.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_ops(ctx, parser)
+    }
+}
+
+
+fn cmd_ops(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let (op, operands) = match cmd_parser.args.split_first() {
+        Some((op, operands)) => (op.to_lowercase(), operands),
+        None => {
+            return Err(Error::Invalid("expected an operator and its operands.".to_string()));
+        }
+    };
+    let expected = match op.as_str() {
+        "not" => 1,
+        "and" | "or" | "xor" | "shl" | "shr" => 2,
+        _ => {
+            return Err(Error::Invalid(format!("unknown operator '{}'.", op)));
+        }
+    };
+    if operands.len() != expected {
+        let plural = if expected == 1 { "" } else { "s" };
+        let msg = format!("the {} operator expects {} operand{}.", op, expected, plural);
+        return Err(Error::Invalid(msg));
+    }
+    let user_bits = parse_u32_option(cmd_parser, "bits")?;
+    ctx.termline()?;
+    match ops_info(&op, operands, user_bits, cmd_parser.found("arithmetic")) {
+        Ok(report) => report.print(ctx)?,
+        Err(msg) => Report::error(&msg).print(ctx)?,
+    }
+    ctx.termline()?;
+    Ok(())
+}
+
+
+fn ops_info(
+    op: &str,
+    operands: &[String],
+    user_bits: Option<u32>,
+    arithmetic: bool,
+) -> Result<Report, String> {
+    let lhs = eval_arg(&operands[0])?;
+    let default_bits = |value: i128| if value < 0 { std_bits(value) } else { min_bits(value) };
+    let mut rows = Vec::new();
+    let mut shift = None;
+    let (bits, result) = if op == "shl" || op == "shr" {
+        let amount = eval_arg(&operands[1])?;
+        if !(0..=128).contains(&amount) {
+            return Err("the shift amount must be from 0 to 128.".to_string());
+        }
+        let amount = amount as u32;
+        shift = Some(amount);
+        let bits = match user_bits {
+            Some(bits) => bits,
+            None if op == "shl" => (default_bits(lhs) + amount).min(128),
+            None => default_bits(lhs),
+        };
+        let (lhs_bits, _) = display_bits(lhs, Some(bits))?;
+        rows.push(("lhs", lhs_bits));
+        let result = if op == "shl" {
+            lhs_bits.checked_shl(amount).unwrap_or(0)
+        } else if arithmetic {
+            // Sign-extend the value from its width to 128 bits before shifting.
+            let extended = ((lhs_bits << (128 - bits)) as i128) >> (128 - bits);
+            (extended >> amount.min(127)) as u128
+        } else {
+            lhs_bits.checked_shr(amount).unwrap_or(0)
+        };
+        (bits, result)
+    } else if op == "not" {
+        let (lhs_bits, bits) = display_bits(lhs, user_bits)?;
+        rows.push(("lhs", lhs_bits));
+        (bits, !lhs_bits)
+    } else {
+        let rhs = eval_arg(&operands[1])?;
+        let bits = user_bits.unwrap_or_else(|| default_bits(lhs).max(default_bits(rhs)));
+        let (lhs_bits, _) = display_bits(lhs, Some(bits))?;
+        let (rhs_bits, _) = display_bits(rhs, Some(bits))?;
+        rows.push(("lhs", lhs_bits));
+        rows.push(("rhs", rhs_bits));
+        let result = match op {
+            "and" => lhs_bits & rhs_bits,
+            "or" => lhs_bits | rhs_bits,
+            _ => lhs_bits ^ rhs_bits,
+        };
+        (bits, result)
+    };
+    let mask = if bits == 128 { u128::MAX } else { (1 << bits) - 1 };
+    rows.push((op, result & mask));
+
+    let hex_width = bits.div_ceil(4) as usize;
+    let oct_width = bits.div_ceil(3) as usize;
+    let dec_width = rows.iter().map(|(_, value)| value.to_string().len()).max().unwrap();
+    let mut report = Report::new();
+    for (label, value) in &rows {
+        report = report.field(
+            label,
+            format!(
+                "{}  hex {:0hex_width$X}  dec {:>dec_width$}  oct {:0oct_width$o}",
+                bin_string(*value, bits),
+                value,
+                value,
+                value,
+                hex_width = hex_width,
+                dec_width = dec_width,
+                oct_width = oct_width,
+            ),
+        );
+        if let (Some(amount), "lhs") = (shift, *label) {
+            report = report.field("amt", amount);
+        }
+    }
+    let plural = if bits == 1 { "" } else { "s" };
+    Ok(report.field("wid", format!("{} bit{}", bits, plural)))
+}
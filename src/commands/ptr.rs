@@ -0,0 +1,189 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::alignment;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_int;
+use crate::parse_uint;
+use crate::pointer::{self, Region};
+use crate::poison;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector ptr|pointer [addresses]
+
+  Reports the alignment of each pointer value, the number of low-order bits
+  free for tagging at the specified alignment, and whether the address is
+  canonical on x86-64 (48-bit and 57-bit addressing) and valid on AArch64
+  (48-bit addressing, with and without top-byte-ignore).
+
+  Also classifies each address against the typical process layout regions
+  on Linux and macOS (stack, heap, mmap, shared libraries, kernel) along with
+  the region's typical ASLR entropy where known. If a base address is given,
+  displays the offset of each address from the base, e.g. the slide of a
+  pointer into a loaded image.
+
+  Addresses can be specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "ptr pointer"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Report pointer alignment and canonical status."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[addresses]", "List of pointer values.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "align a",
+                value: Some("n"),
+                help: "Alignment in bytes guaranteed for the pointer. Must be a power of two. \
+                      Defaults to 8.",
+            },
+            Opt {
+                names: "base b",
+                value: Some("addr"),
+                help: "Base address for computing offsets.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_ptr(ctx, parser)
+    }
+}
+
+
+fn cmd_ptr(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let align = match cmd_parser.value("align") {
+        Some(arg) => match parse_uint(&arg) {
+            Ok(value) if value.is_power_of_two() => value,
+            _ => {
+                return Err(Error::Invalid(format!("alignment '{}' is not a power of two.", arg)));
+            }
+        },
+        None => 8,
+    };
+    let base = match cmd_parser.value("base") {
+        Some(arg) => match parse_uint(&arg) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                return Err(Error::Invalid(format!("cannot parse '{}': {}.", arg, err)));
+            }
+        },
+        None => None,
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let negative = parse_int(arg).ok().filter(|v| *v < 0 && *v >= i64::MIN as i128);
+        let output = parse_uint(arg)
+            .ok()
+            .or_else(|| negative.map(|v| v as u64))
+            .map(|addr| ptr_info(addr, align, base))
+            .ok_or_else(|| format!("cannot parse '{}' as a 64-bit address.", arg));
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn ptr_info(addr: u64, align: u64, base: Option<u64>) -> Report {
+    let mut report = Report::new().field("hex", add_spacers(&format!("{:016X}", addr), ' ', 4));
+
+    report = report.field("aln", match alignment(addr) {
+        Some(natural) => format!(
+            "{} byte{} ({} low bit{} zero)",
+            natural,
+            if natural == 1 { "" } else { "s" },
+            natural.trailing_zeros(),
+            if natural.trailing_zeros() == 1 { "" } else { "s" },
+        ),
+        None => "null pointer".to_string(),
+    });
+
+    let free = pointer::tag_bits(align).unwrap_or(0);
+    let tag = format!(
+        "{} bit{} free at {}-byte alignment (mask 0x{:X}){}",
+        free,
+        if free == 1 { "" } else { "s" },
+        align,
+        align - 1,
+        if addr & (align - 1) != 0 { ", pointer is misaligned" } else { "" },
+    );
+    report = report.field("tag", tag);
+
+    let canonical = |va_bits: u32| {
+        if pointer::is_canonical(addr, va_bits) { "canonical" } else { "non-canonical" }
+    };
+    report = report.field("x64", format!("{} (48-bit), {} (57-bit)", canonical(48), canonical(57)));
+
+    let valid = |tbi: bool| {
+        if pointer::is_aarch64_valid(addr, 48, tbi) { "valid" } else { "invalid" }
+    };
+    let a64 = format!(
+        "{} (48-bit), {} with TBI (top byte 0x{:02X})",
+        valid(false),
+        valid(true),
+        addr >> 56,
+    );
+    report = report
+        .field("a64", a64)
+        .field("lnx", region_info(pointer::classify(addr, pointer::LINUX_LAYOUT)))
+        .field("mac", region_info(pointer::classify(addr, pointer::MACOS_LAYOUT)));
+
+    if let Some(desc) = poison::describe(addr) {
+        report = report.field("psn", desc);
+    }
+
+    if let Some(base) = base {
+        let (sign, offset) = if addr >= base { ('+', addr - base) } else { ('-', base - addr) };
+        let offset = format!(
+            "{}0x{:X} ({}{}) from base 0x{:X}",
+            sign,
+            offset,
+            sign,
+            add_spacers(&offset.to_string(), ',', 3),
+            base,
+        );
+        report = report.field("off", offset);
+    }
+
+    report
+}
+
+
+fn region_info(region: Option<&Region>) -> String {
+    match region {
+        Some(Region { name, entropy: Some(bits), .. }) => {
+            format!("{} (typical ASLR entropy {} bits)", name, bits)
+        },
+        Some(region) => region.name.to_string(),
+        None => "unclassified".to_string(),
+    }
+}
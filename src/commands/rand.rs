@@ -0,0 +1,125 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::cli::InfoOpts;
+use crate::cli::int_info;
+use crate::cli::u128_info;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::random;
+
+
+const HELP: &str = "
+Usage: intspector rand [--bits <n>] [--count <n>]
+
+  Generates random integers of the specified width and displays each one
+  in all four bases, e.g. for test vectors or magic numbers. Unsigned by
+  default, with the --signed flag, the bits are read as a two's complement
+  value instead.
+
+  The generator is SplitMix64, seeded randomly unless --seed is given, and
+  isn't suitable for keys, tokens, or other secrets.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "rand"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Generate random integers of a given width."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "bits b",
+                value: Some("n"),
+                help: "Width in bits, from 1 to 128. Defaults to 32.",
+            },
+            Opt {
+                names: "count n",
+                value: Some("n"),
+                help: "Number of integers to generate. Defaults to 1.",
+            },
+            Opt {
+                names: "seed",
+                value: Some("n"),
+                help: "Seed the generator to repeat the same values.",
+            },
+            Opt { names: "signed s", value: None, help: "Generate signed integers." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_rand(ctx, parser)
+    }
+}
+
+
+fn cmd_rand(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let bits = match cmd_parser.value("bits") {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value) if (1..=128).contains(&value) => value,
+            _ => {
+                return Err(Error::Invalid("the width must be from 1 to 128 bits.".to_string()));
+            }
+        },
+        None => 32,
+    };
+    let count = match cmd_parser.value("count") {
+        Some(arg) => match arg.parse::<usize>() {
+            Ok(value) => value,
+            Err(_) => {
+                return Err(Error::Invalid(format!("cannot parse '{}' as a count.", arg)));
+            }
+        },
+        None => 1,
+    };
+    let mut rng = match cmd_parser.value("seed") {
+        Some(arg) => match eval_arg(&arg) {
+            Ok(value) if (0..=u64::MAX as i128).contains(&value) => {
+                random::SplitMix64::new(value as u64)
+            },
+            Ok(_) => {
+                return Err(Error::Invalid("the seed must be from 0 to 2^64 - 1.".to_string()));
+            },
+            Err(msg) => {
+                return Err(Error::Invalid(msg));
+            }
+        },
+        None => random::SplitMix64::new(ctx.env.seed),
+    };
+    let opts = InfoOpts { bits: Some(bits), ..Default::default() };
+    if count > 0 {
+        ctx.termline()?;
+    }
+    for _ in 0..count {
+        let pattern = rng.next_bits(bits);
+        let info = if cmd_parser.found("signed") {
+            // Sign-extend the pattern from the top bit of the width.
+            let shift = 128 - bits;
+            int_info(((pattern << shift) as i128) >> shift, &opts)
+        } else if pattern > i128::MAX as u128 {
+            u128_info(pattern, &opts)
+        } else {
+            int_info(pattern as i128, &opts)
+        };
+        info.print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
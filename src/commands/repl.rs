@@ -0,0 +1,70 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::InfoOpts;
+use crate::cli::parse_u32_option;
+use crate::cli::run_repl;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+
+
+const HELP: &str = "
+Usage: intspector repl
+
+  Starts an interactive prompt that evaluates each line as an integer or
+  expression and prints its info block. Enter quit or exit, or press
+  Ctrl-D, to leave.
+
+  The last result is stored in the variable _. Assign other variables with
+  name = expression. Names can't be valid integer literals, e.g. xff.
+
+  Directives:
+    bits <n>     Set the number of binary digits to display.
+    bits         Go back to the default number of binary digits.
+    history      List the lines entered so far.
+    !<n>         Re-run line n from the history.
+    !!           Re-run the previous line.
+    vars         List the variables.
+    help         List the directives.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "repl"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Evaluate integers interactively."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "bits b", value: Some("n"), help: "Number of binary digits to display." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_repl(ctx, parser)
+    }
+}
+
+
+fn cmd_repl(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let bits = parse_u32_option(cmd_parser, "bits")?;
+    if bits.is_some_and(|bits| !(1..=128).contains(&bits)) {
+        return Err(Error::Invalid("the width must be from 1 to 128 bits.".to_string()));
+    }
+    run_repl(ctx, InfoOpts { bits, hints: true, ..Default::default() })
+}
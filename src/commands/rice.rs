@@ -0,0 +1,117 @@
+use arguably::ArgParser;
+use crate::cli::code_info;
+use crate::cli::Context;
+use crate::cli::decode_stream;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::coding;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_u128;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector rice -k <k> [integers]
+       intspector rice -k <k> --decode <bits>
+
+  Encodes non-negative integers with the Golomb-Rice code with parameter
+  k, showing each code with its parts separated and packed into bytes,
+  most significant bit first. The code is the quotient n >> k in unary,
+  written as ones terminated by a zero, followed by the low k bits of n,
+  e.g. 110 001 for 17 with k = 3. Rice codes suit geometrically
+  distributed values, e.g. prediction residuals in FLAC and lossless JPEG.
+
+  With the --decode flag, the arguments are read as a single stream of
+  binary digits, which can be grouped with spaces or underscores, and
+  decoded into a sequence of integers. Trailing ones are treated as
+  padding.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "rice"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Encode and decode Golomb-Rice codes."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to encode, or binary digits to decode.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "param k", value: Some("k"), help: "Rice parameter, from 0 to 127." },
+            Opt { names: "decode d", value: None, help: "Decode a stream of binary digits." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_rice(ctx, parser)
+    }
+}
+
+
+fn cmd_rice(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let k = match cmd_parser.value("param") {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value) if value < 128 => value,
+            _ => {
+                let msg = "the Rice parameter must be an integer from 0 to 127.";
+                return Err(Error::Invalid(msg.to_string()));
+            }
+        },
+        None => {
+            return Err(Error::Invalid("the --param option is required.".to_string()));
+        }
+    };
+    if cmd_parser.args.is_empty() {
+        return Ok(());
+    }
+    ctx.termline()?;
+    if cmd_parser.found("decode") {
+        let decode = |bits: &str| coding::decode_rice(bits, k);
+        match decode_stream(&cmd_parser.args, &decode, '1') {
+            Ok(report) => report.print(ctx)?,
+            Err(msg) => Report::error(&msg).print(ctx)?,
+        }
+        ctx.termline()?;
+        return Ok(());
+    }
+    for arg in &cmd_parser.args {
+        let value = match parse_u128(arg) {
+            Ok(value) => value,
+            Err(err) => {
+                parse_error(arg, &err).input(arg).print(ctx)?;
+                ctx.termline()?;
+                continue;
+            }
+        };
+        let report = Report::new().field("int", value.to_string());
+        let report = match coding::rice(value, k) {
+            Some(code) => report.field("ric", code_info(code)),
+            None => report
+                .and_error(&format!("the code is longer than {} bits.", coding::MAX_CODE_BITS)),
+        };
+        report.input(arg).print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_uint;
+use crate::report::Report;
+use crate::rns;
+
+
+const HELP: &str = "
+Usage: intspector rns --moduli <list> [integers]
+
+  Represents integers in a residue number system, as their remainders
+  modulo a set of pairwise coprime moduli, then reconstructs each integer
+  from its residues using the Chinese remainder theorem, showing each step.
+
+  A residue number system with moduli m1, m2, ... represents integers
+  uniquely up to the product M of the moduli, so integers of M or above are
+  reconstructed modulo M. With the --crt flag, the arguments are read as a
+  single list of residues, one for each modulus, and the integer with those
+  residues is reconstructed.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "rns"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert integers to residue number systems."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to convert.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "moduli m",
+                value: Some("list"),
+                help: "Comma-separated list of moduli, e.g. 3,5,7.",
+            },
+            Opt {
+                names: "crt c",
+                value: None,
+                help: "Reconstruct an integer from a list of residues.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_rns(ctx, parser)
+    }
+}
+
+
+fn cmd_rns(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let moduli = match cmd_parser.value("moduli") {
+        Some(arg) => match rns::parse_moduli(&arg) {
+            Some(moduli) => moduli,
+            None => {
+                let msg = format!("cannot parse '{}' as a list of moduli of 2 or more.", arg);
+                return Err(Error::Invalid(msg));
+            }
+        },
+        None => {
+            return Err(Error::Invalid("the --moduli option is required.".to_string()));
+        }
+    };
+    if let Err(msg) = rns::moduli_product(&moduli) {
+        return Err(Error::Invalid(format!("{}.", msg)));
+    }
+    if cmd_parser.args.is_empty() {
+        return Ok(());
+    }
+    ctx.termline()?;
+    if cmd_parser.found("crt") {
+        let residues: Result<Vec<u64>, _> = cmd_parser.args.iter().map(|a| parse_uint(a)).collect();
+        match residues {
+            Ok(residues) => match crt_info(&residues, &moduli) {
+                Ok(report) => report.print(ctx)?,
+                Err(msg) => Report::error(&format!("{}.", msg)).print(ctx)?,
+            },
+            Err(err) => Report::error(&format!("cannot parse the residues: {}.", err)).print(ctx)?,
+        }
+        ctx.termline()?;
+        return Ok(());
+    }
+    for arg in &cmd_parser.args {
+        let value = match parse_uint(arg) {
+            Ok(value) => value,
+            Err(err) => {
+                parse_error(arg, &err).input(arg).print(ctx)?;
+                ctx.termline()?;
+                continue;
+            }
+        };
+        let residues = rns::residues(value, &moduli);
+        let list: Vec<String> = residues.iter().map(|r| r.to_string()).collect();
+        let report =
+            Report::new().field("int", value).field("res", format!("({})", list.join(", ")));
+        let report = match crt_info(&residues, &moduli) {
+            Ok(steps) => report.append(steps),
+            Err(msg) => report.and_error(&format!("{}.", msg)),
+        };
+        report.input(arg).print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
+
+
+// Returns the steps of a Chinese remainder theorem reconstruction.
+fn crt_info(residues: &[u64], moduli: &[u64]) -> Result<Report, String> {
+    let (value, terms) = rns::crt(residues, moduli)?;
+    let product = rns::moduli_product(moduli)?;
+    let list: Vec<String> = moduli.iter().map(|m| m.to_string()).collect();
+    let mut steps: Vec<String> = terms.iter().map(|term| format!(
+        "x ≡ {} (mod {}): M/{} = {}, inverse {}, term {} × {} × {} ≡ {}",
+        term.residue, term.modulus, term.modulus, term.partial, term.inverse,
+        term.residue, term.inverse, term.partial, term.term
+    )).collect();
+    let sum: Vec<String> = terms.iter().map(|term| term.term.to_string()).collect();
+    steps.push(format!("x = ({}) mod {} = {}", sum.join(" + "), product, value));
+    Ok(Report::new()
+        .field("mod", format!("{} (M = {})", list.join(", "), product))
+        .field("crt", steps.join("\n")))
+}
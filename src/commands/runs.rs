@@ -0,0 +1,170 @@
+use arguably::ArgParser;
+use crate::bin_string;
+use crate::bit_runs;
+use crate::cli::Context;
+use crate::cli::display_bits;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::cli::eval_input;
+use crate::cli::parse_u32_option;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+use crate::std_bits;
+
+
+const HELP: &str = "
+Usage: intspector runs [integers]
+
+  Shows the run-length encoding of each integer's bit pattern, from the
+  most significant bit down, e.g. 1×3 0×5 for 0b11100000, and the longest
+  runs of set and clear bits with their positions. Useful for checking the
+  regularity of masks and bit-stream captures.
+
+  With the --planes flag, the integers are read as a single set of samples
+  and each bit position is shown as a bit plane: the values of that bit
+  across the samples, with a count of how many are set. Constant planes
+  stand out as unused or stuck bits.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base.
+  Negative integers are shown in two's complement.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "runs"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Show the bit runs and bit planes of integers."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to analyze.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "bits b", value: Some("n"), help: "Number of binary digits to analyze." },
+            Opt {
+                names: "planes p",
+                value: None,
+                help: "Show the bit planes across all the integers.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_runs(ctx, parser)
+    }
+}
+
+
+fn cmd_runs(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let user_bits = parse_u32_option(cmd_parser, "bits")?;
+    if cmd_parser.args.is_empty() {
+        return Ok(());
+    }
+    ctx.termline()?;
+    if cmd_parser.found("planes") {
+        match planes_info(&cmd_parser.args, user_bits) {
+            Ok(report) => report.print(ctx)?,
+            Err(msg) => Report::error(&msg).print(ctx)?,
+        }
+        ctx.termline()?;
+        return Ok(());
+    }
+    for arg in &cmd_parser.args {
+        let output = eval_input(arg).and_then(|value| Ok(runs_info(value, user_bits)?));
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn runs_info(value: i128, user_bits: Option<u32>) -> Result<Report, String> {
+    let (disp_value, num_bits) = display_bits(value, user_bits)?;
+    let runs = bit_runs(disp_value, num_bits);
+    let rle: Vec<String> = runs
+        .iter()
+        .map(|(bit, length)| format!("{}×{}", bit, length))
+        .collect();
+    let mean = num_bits as f64 / runs.len() as f64;
+    let plural = if runs.len() == 1 { "" } else { "s" };
+    let mut report = Report::new()
+        .field("int", value)
+        .field("bin", bin_string(disp_value, num_bits))
+        .field("rle", rle.join(" "))
+        .field("cnt", format!("{} run{}, mean length {:.2}", runs.len(), plural, mean));
+
+    // Find the first longest run of each bit value, tracking the position of each run's top bit.
+    for (bit, label) in [(1, "set"), (0, "clr")] {
+        let mut top = num_bits;
+        let mut longest: Option<(u32, u32)> = None;
+        for (run_bit, length) in &runs {
+            if *run_bit == bit && longest.is_none_or(|(best, _)| *length > best) {
+                longest = Some((*length, top - 1));
+            }
+            top -= length;
+        }
+        let summary = match longest {
+            Some((1, high)) => format!("longest run 1 at bit {}", high),
+            Some((length, high)) => {
+                let low = high + 1 - length;
+                format!("longest run {} at bits {}..{}", length, high, low)
+            },
+            None => "none".to_string(),
+        };
+        report = report.field(label, summary);
+    }
+    Ok(report)
+}
+
+
+// Shows each bit position's values across a set of samples, most significant bit first.
+fn planes_info(args: &[String], user_bits: Option<u32>) -> Result<Report, String> {
+    let mut values = Vec::new();
+    for arg in args {
+        values.push(eval_arg(arg)?);
+    }
+    let num_bits = match user_bits {
+        Some(bits) => bits,
+        None => values.iter().map(|value| std_bits(*value)).max().unwrap(),
+    };
+    let mut samples = Vec::new();
+    for value in &values {
+        samples.push(display_bits(*value, Some(num_bits))?.0);
+    }
+    let label_width = format!("{}", num_bits - 1).len();
+    let mut report = Report::new();
+    for i in (0..num_bits).rev() {
+        let plane: String = samples
+            .iter()
+            .map(|sample| if sample >> i & 1 == 1 { '1' } else { '0' })
+            .collect();
+        let count = plane.matches('1').count();
+        let note = match count {
+            0 => " (always clear)",
+            _ if count == samples.len() => " (always set)",
+            _ => "",
+        };
+        let label = format!("b{:0width$}", i, width = label_width);
+        let value = format!("{}   {}/{} set{}", plane, count, samples.len(), note);
+        report = report.field(&label, value);
+    }
+    Ok(report)
+}
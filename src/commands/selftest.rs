@@ -0,0 +1,99 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+use crate::selftest;
+
+
+const HELP: &str = "
+Usage: intspector selftest
+
+  Runs the golden test vectors built into the binary and reports how many
+  pass in each group: integer parsing and formatting, the CRC catalogue
+  check values and other checksums, hash functions, floating-point fields,
+  timestamps, Unicode encodings, arithmetic codes, and a sample run of
+  every command.
+
+  Each failing vector is shown with its expected and actual results, and
+  the exit status is 1 if any vector fails, so the command can be used to
+  check a build on a new platform.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "selftest"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Run the built-in golden test vectors."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "verbose v", value: None, help: "Show the result of every vector." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_selftest(ctx, parser)
+    }
+}
+
+
+fn cmd_selftest(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let outcomes = selftest::run();
+    let failed = outcomes.iter().filter(|outcome| !outcome.passed()).count();
+    ctx.termline()?;
+    selftest_info(&outcomes, cmd_parser.found("verbose")).print(ctx)?;
+    ctx.termline()?;
+    match failed {
+        0 => Ok(()),
+        _ => Err(Error::Invalid(format!("{} of {} test vectors failed.", failed, outcomes.len()))),
+    }
+}
+
+
+fn selftest_info(outcomes: &[selftest::Outcome], verbose: bool) -> Report {
+    let mut report = Report::new();
+    let mut groups: Vec<&str> = outcomes.iter().map(|outcome| outcome.group).collect();
+    groups.dedup();
+    for group in groups {
+        let members: Vec<&selftest::Outcome> =
+            outcomes.iter().filter(|outcome| outcome.group == group).collect();
+        let passed = members.iter().filter(|outcome| outcome.passed()).count();
+        report = report.field("grp", format!("{}, {} of {} passed", group, passed, members.len()));
+        for outcome in members.iter().filter(|outcome| !outcome.passed()) {
+            let msg = format!(
+                "{}: expected {}, got {}",
+                outcome.name, outcome.expected, outcome.actual
+            );
+            report = report.and_error(&msg);
+        }
+        if verbose {
+            let rows = members
+                .iter()
+                .map(|outcome| {
+                    let result = if outcome.passed() { "pass" } else { "FAIL" };
+                    vec![result.to_string(), outcome.name.clone(), outcome.actual.clone()]
+                })
+                .collect();
+            report = report.table(&["result", "vector", "actual"], rows);
+        }
+    }
+    let passed = outcomes.iter().filter(|outcome| outcome.passed()).count();
+    report.field("sum", format!("{} of {} vectors passed", passed, outcomes.len()))
+}
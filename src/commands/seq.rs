@@ -0,0 +1,128 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_arg;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::report::Report;
+use crate::sequence;
+
+
+const HELP: &str = "
+Usage: intspector seq [integers]
+
+  Tries to recognize a sequence of integers as one of a set of simple
+  generators, then shows the rule and predicts the next terms. The rules
+  are tried in order:
+
+  - Arithmetic progressions, e.g. 3 7 11 15, including constants.
+  - Geometric progressions, e.g. 3 6 12 24, including powers of k.
+  - Powers of consecutive integers, e.g. the squares 1 4 9 16.
+  - Consecutive factorials, e.g. 1 2 6 24.
+  - Fibonacci-like recurrences, where each term is the sum of the two
+    before it, e.g. 2 1 3 4 7, the Lucas numbers.
+  - Polynomials, found by the method of finite differences, e.g. the
+    triangular numbers 1 3 6 10 15. A polynomial of degree d needs at
+    least d + 2 terms, so that one term confirms the fit.
+
+  At least three terms are needed. Formulas are indexed from n = 0 for the
+  first term.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "seq"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Recognize integer sequences and predict terms."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "Terms of the sequence.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "next n",
+                value: Some("n"),
+                help: "Number of terms to predict. Defaults to 3.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_seq(ctx, parser)
+    }
+}
+
+
+fn cmd_seq(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let count = match cmd_parser.value("next") {
+        Some(arg) => match arg.parse::<usize>() {
+            Ok(value) => value,
+            Err(_) => {
+                return Err(Error::Invalid(format!("cannot parse '{}' as a number of terms.", arg)));
+            }
+        },
+        None => 3,
+    };
+    if cmd_parser.args.is_empty() {
+        return Ok(());
+    }
+    ctx.termline()?;
+    match seq_info(&cmd_parser.args, count) {
+        Ok(report) => report.print(ctx)?,
+        Err(msg) => Report::error(&msg).print(ctx)?,
+    }
+    ctx.termline()?;
+    Ok(())
+}
+
+
+fn seq_info(args: &[String], count: usize) -> Result<Report, String> {
+    let mut values = Vec::new();
+    for arg in args {
+        values.push(eval_arg(arg)?);
+    }
+    if values.len() < 3 {
+        return Err("at least three terms are needed to recognize a sequence.".to_string());
+    }
+    let terms: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+    let sequence = format!("{} ({} terms)", terms.join(", "), values.len());
+    let report = Report::new().field("seq", sequence);
+    let rule = match sequence::recognize(&values) {
+        Some(rule) => rule,
+        None => return Ok(report.field("rul", "no simple rule found")),
+    };
+    let report = report.field("rul", rule.name()).field("fml", rule.formula());
+    if count == 0 {
+        return Ok(report);
+    }
+    let next: Vec<String> = (values.len()..values.len() + count)
+        .map_while(|n| rule.term(n))
+        .map(|value| value.to_string())
+        .collect();
+    Ok(match next.len() {
+        0 => report.field("nxt", "overflow"),
+        n if n < count => report.field("nxt", format!("{}, then overflow", next.join(", "))),
+        _ => report.field("nxt", next.join(", ")),
+    })
+}
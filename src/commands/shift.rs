@@ -0,0 +1,193 @@
+use arguably::ArgParser;
+use crate::bin_string;
+use crate::cli::bit_marks;
+use crate::cli::Context;
+use crate::cli::display_bits;
+use crate::cli::Error;
+use crate::cli::eval_input;
+use crate::cli::parse_u32_option;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::extract_field;
+use crate::field_mask;
+use crate::popcount;
+use crate::report::Report;
+use crate::rotate_left;
+use crate::rotate_right;
+use crate::std_bits;
+
+
+const HELP: &str = "
+Usage: intspector shift --left <n> [integers]
+       intspector shift --right <n> [integers]
+
+  Shifts or rotates integers at a fixed width, showing the value before and
+  after in aligned binary. The bits that fall off the end are marked
+  underneath the original value, or with the --rotate flag, the bits that
+  wrap around to the other end.
+
+  Right shifts are logical by default, filling with zeros. With the --arith
+  flag, they fill with copies of the sign bit, as for signed integers.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions. Negative integers are read as two's complement values.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "shift"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Shift and rotate integers at a fixed width."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to shift.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "bits b",
+                value: Some("n"),
+                help: "Width in bits. Defaults to the standard integer size (8, 16, 32, 64, or \
+                      128 bits) that holds the integer.",
+            },
+            Opt { names: "left l", value: Some("n"), help: "Shift left by n bits." },
+            Opt { names: "right r", value: Some("n"), help: "Shift right by n bits." },
+            Opt {
+                names: "arith a",
+                value: None,
+                help: "Fill with the sign bit when shifting right.",
+            },
+            Opt { names: "rotate o", value: None, help: "Rotate instead of shifting." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_shift(ctx, parser)
+    }
+}
+
+
+// A shift or rotation for the shift command.
+struct Shift {
+    left: bool,
+    amount: u32,
+    rotate: bool,
+    arith: bool,
+}
+
+
+fn cmd_shift(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let parse_amount = |name: &str| match cmd_parser.value(name) {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => Err(Error::Invalid(format!("cannot parse '{}' as a shift amount.", arg))),
+        },
+        None => Ok(None),
+    };
+    let (left, amount) = match (parse_amount("left")?, parse_amount("right")?) {
+        (Some(amount), None) => (true, amount),
+        (None, Some(amount)) => (false, amount),
+        _ => {
+            return Err(Error::Invalid("expected exactly one of --left or --right.".to_string()));
+        }
+    };
+    let rotate = cmd_parser.found("rotate");
+    let shift = Shift { left, amount, rotate, arith: cmd_parser.found("arith") };
+    if shift.arith && (shift.left || shift.rotate) {
+        return Err(Error::Invalid("--arith only applies to right shifts.".to_string()));
+    }
+    let user_bits = parse_u32_option(cmd_parser, "bits")?;
+    if cmd_parser.args.is_empty() {
+        return Ok(());
+    }
+    ctx.termline()?;
+    for arg in &cmd_parser.args {
+        let output = eval_input(arg).and_then(|value| Ok(shift_info(value, &shift, user_bits)?));
+        print_result(ctx, arg, output)?;
+    }
+    Ok(())
+}
+
+
+fn shift_info(value: i128, shift: &Shift, user_bits: Option<u32>) -> Result<Report, String> {
+    let bits = user_bits.unwrap_or_else(|| std_bits(value));
+    let (before, _) = display_bits(value, Some(bits))?;
+    let amount = shift.amount;
+    // The positions of the bits in the original value that fall off, or that wrap around.
+    let moved: Vec<u32> = match (shift.left, shift.rotate) {
+        (true, false) => (bits.saturating_sub(amount)..bits).collect(),
+        (false, false) => (0..amount.min(bits)).collect(),
+        (true, true) => (bits - amount % bits..bits).collect(),
+        (false, true) => (0..amount % bits).collect(),
+    };
+    let after = match (shift.left, shift.rotate) {
+        (true, true) => rotate_left(before, amount, bits),
+        (false, true) => rotate_right(before, amount, bits),
+        (true, false) => before.checked_shl(amount).unwrap_or(0) & field_mask(bits - 1, 0),
+        (false, false) if shift.arith => {
+            // Sign-extend the value from its width to 128 bits before shifting.
+            let extended = ((before << (128 - bits)) as i128) >> (128 - bits);
+            (extended >> amount.min(127)) as u128 & field_mask(bits - 1, 0)
+        },
+        (false, false) => before.checked_shr(amount).unwrap_or(0),
+    };
+    let row = |value: u128| {
+        format!(
+            "{}  hex {:0width$X}  dec {}",
+            bin_string(value, bits),
+            value,
+            value,
+            width = bits.div_ceil(4) as usize,
+        )
+    };
+    let kind = match (shift.left, shift.rotate, shift.arith) {
+        (true, true, _) => "rotate left",
+        (false, true, _) => "rotate right",
+        (true, false, _) => "shift left",
+        (false, false, true) => "arithmetic shift right",
+        (false, false, false) => "logical shift right",
+    };
+    let plural = |count: usize| if count == 1 { "" } else { "s" };
+    let mut report = Report::new().field("bef", row(before));
+    if !moved.is_empty() {
+        let label = if shift.rotate { "wrp" } else { "out" };
+        report = report.field(label, bit_marks(&moved, bits));
+    }
+    let operation = format!("{} by {} at {} bit{}", kind, amount, bits, plural(bits as usize));
+    report = report.field("aft", row(after)).field("opr", operation);
+    let top = moved.iter().max().map_or(0, |top| top + 1);
+    let low = moved.iter().min().copied().unwrap_or(0);
+    Ok(match moved.len() {
+        0 if shift.rotate => report.field("cnt", "no bits wrapped around"),
+        0 => report.field("cnt", "no bits shifted out"),
+        count => report.field(
+            "cnt",
+            format!(
+                "{} bit{} {} ({}, {} set)",
+                count,
+                plural(count),
+                if shift.rotate { "wrapped around" } else { "shifted out" },
+                bin_string(extract_field(before, top - 1, low), count as u32),
+                popcount(extract_field(before, top - 1, low), count as u32),
+            ),
+        ),
+    })
+}
@@ -0,0 +1,101 @@
+use arguably::ArgParser;
+use crate::balanced_ternary;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::commands::Decoder;
+use crate::non_adjacent_form;
+use crate::parse_int;
+use crate::report::Report;
+use crate::signed_digit_string;
+
+
+const HELP: &str = "
+Usage: intspector signed-digits [integers]
+
+  Converts integers to signed-digit representations, in which each digit is
+  -1, 0, or 1, explaining the contribution of each non-zero digit. A -1
+  digit is written as T.
+
+  - Balanced ternary uses powers of 3 and represents negative integers
+    without a sign.
+  - Non-adjacent form (NAF) uses powers of 2 with no two adjacent non-zero
+    digits. It has the fewest non-zero digits of any signed binary
+    representation, which minimizes the additions in double-and-add
+    exponentiation and elliptic curve scalar multiplication.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "sd signed-digits"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Show balanced ternary and non-adjacent forms."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to convert.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_signed_digits(ctx, parser)
+    }
+}
+
+
+fn cmd_signed_digits(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let value = match parse_int(arg) {
+            Ok(value) => value,
+            Err(err) => {
+                parse_error(arg, &err).input(arg).print(ctx)?;
+                ctx.termline()?;
+                continue;
+            }
+        };
+        let ternary = balanced_ternary(value);
+        let naf = non_adjacent_form(value);
+        let naf_weight = naf.iter().filter(|digit| **digit != 0).count();
+        let bin_weight = value.unsigned_abs().count_ones();
+        Report::new()
+            .field("dec", value)
+            .field("bt3", signed_digits_info(&ternary, 3))
+            .field("naf", signed_digits_info(&naf, 2))
+            .field("nzd", format!("{} in NAF, {} in binary", naf_weight, bin_weight))
+            .input(arg)
+            .print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
+
+
+// Formats the signed digits followed by a line for the contribution of each non-zero digit, most
+// significant first.
+fn signed_digits_info(digits: &[i8], base: u128) -> String {
+    let mut lines = vec![signed_digit_string(digits)];
+    for (i, digit) in digits.iter().enumerate().rev().filter(|(_, digit)| **digit != 0) {
+        let weight = base.pow(i as u32);
+        let sign = if *digit < 0 { "-" } else { "+" };
+        lines.push(format!("{}1 × {}^{} = {}{}", sign, base, i, sign, weight));
+    }
+    lines.join("\n")
+}
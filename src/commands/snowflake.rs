@@ -0,0 +1,147 @@
+use arguably::ArgParser;
+use crate::bin_string;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::parse_error;
+use crate::cli::parse_u32_option;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::datetime;
+use crate::ids;
+use crate::parse_int;
+use crate::parse_uint;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector snowflake [ids]
+
+  Splits Twitter/Discord-style 64-bit snowflake IDs into their timestamp,
+  worker, and sequence fields and displays the timestamp as a UTC datetime.
+
+  The preset layouts are:
+
+  - twitter: unused sign bit, 41-bit timestamp, 10-bit worker (datacenter/
+    worker), 12-bit sequence, epoch 1288834974657 ms.
+  - discord: 42-bit timestamp, 10-bit worker (worker/process), 12-bit
+    increment, epoch 1420070400000 ms.
+
+  The --epoch, --worker-bits, and --sequence-bits options override the
+  corresponding field of the preset.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "snowflake"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode snowflake IDs."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[ids]", "List of snowflake IDs.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "preset p",
+                value: Some("name"),
+                help: "Preset layout: twitter or discord. Defaults to twitter.",
+            },
+            Opt {
+                names: "epoch e",
+                value: Some("ms"),
+                help: "Custom epoch in milliseconds since 1970.",
+            },
+            Opt { names: "worker-bits w", value: Some("n"), help: "Width of the worker field." },
+            Opt {
+                names: "sequence-bits s",
+                value: Some("n"),
+                help: "Width of the sequence field.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_snowflake(ctx, parser)
+    }
+}
+
+
+fn cmd_snowflake(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let mut layout = match cmd_parser.value("preset").as_deref() {
+        Some("twitter") | None => ids::TWITTER,
+        Some("discord") => ids::DISCORD,
+        Some(other) => {
+            return Err(Error::Invalid(format!("unknown preset '{}'.", other)));
+        }
+    };
+    if let Some(arg) = cmd_parser.value("epoch") {
+        let range = i64::MIN as i128..=i64::MAX as i128;
+        let epoch_ms = parse_int(&arg).ok().filter(|ms| range.contains(ms));
+        layout.epoch_ms = epoch_ms.map(|ms| ms as i64).ok_or_else(|| {
+            Error::Invalid(format!("cannot parse '{}' as a 64-bit signed integer.", arg))
+        })?;
+    }
+    for (name, field) in [
+        ("worker-bits", &mut layout.worker_bits),
+        ("sequence-bits", &mut layout.sequence_bits),
+    ] {
+        if let Some(value) = parse_u32_option(cmd_parser, name)? {
+            *field = value;
+        }
+    }
+    if layout.worker_bits + layout.sequence_bits > 63 {
+        let msg = "the worker and sequence fields cannot exceed 63 bits.";
+        return Err(Error::Invalid(msg.to_string()));
+    }
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = parse_uint(arg).map_err(|err| parse_error(arg, &err));
+        print_result(ctx, arg, output.map(|id| snowflake_info(id, layout)))?;
+    }
+    Ok(())
+}
+
+
+fn snowflake_info(id: u64, layout: ids::SnowflakeLayout) -> Report {
+    let fields = ids::snowflake_decode(id, layout);
+    let ts_bits = 64 - layout.worker_bits - layout.sequence_bits;
+    let time = format!(
+        "{} ({})",
+        fields.timestamp_ms,
+        datetime::format_utc_millis(fields.timestamp_ms)
+    );
+    let worker = match layout.worker_bits {
+        10 => format!("{} ({} / {})", fields.worker, fields.worker >> 5, fields.worker & 0x1F),
+        _ => fields.worker.to_string(),
+    };
+    let layout = format!(
+        "{}-bit timestamp, {}-bit worker, {}-bit sequence, epoch {}",
+        ts_bits, layout.worker_bits, layout.sequence_bits, layout.epoch_ms,
+    );
+    Report::new()
+        .field("bin", bin_string(id as u128, 64))
+        .field("tim", time)
+        .field("wrk", worker)
+        .field("seq", fields.sequence)
+        .field("lay", layout)
+}
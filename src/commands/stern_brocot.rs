@@ -0,0 +1,160 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::ratio;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector sb|stern-brocot [fractions]
+
+  Locates positive fractions in the Stern-Brocot tree, which contains every
+  positive rational exactly once. Each node is the mediant (a + c)/(b + d)
+  of its nearest ancestors a/b and c/d on either side, starting from the
+  root 1/1 between 0/1 and 1/0.
+
+  Shows the path from the root as runs of left (L) and right (R) moves, the
+  mediant reached at every move, and the continued fraction, whose terms are
+  the run lengths. Paths deeper than 64 moves list only the node reached at
+  the end of each run, with each arrow labelled with its run, e.g. →R99.
+  The nearest ancestors of a fraction p/q below 1 are its neighbours in the
+  Farey sequence of order q.
+
+  Fractions are written as num/den, e.g. 355/113, or as decimals, e.g.
+  3.14159. A decimal is read as an approximation: the command locates the
+  simplest fraction that rounds to it, i.e. the fraction with the smallest
+  denominator within half a unit in the last place. Use the --exact flag
+  to locate the decimal's exact value instead.
+
+  Numerators and denominators can be specified in binary, octal, decimal, or
+  hexadecimal base.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "sb stern-brocot"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Locate fractions in the Stern-Brocot tree."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[fractions]", "List of fractions or decimals.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "exact e", value: None, help: "Read decimals as exact values." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_stern_brocot(ctx, parser)
+    }
+}
+
+
+fn cmd_stern_brocot(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        print_result(ctx, arg, stern_brocot_info(arg, cmd_parser.found("exact")))?;
+    }
+    Ok(())
+}
+
+
+fn stern_brocot_info(arg: &str, exact: bool) -> Result<Report, String> {
+    let mut report = Report::new();
+    let (lo, hi) = match ratio::parse_decimal(arg) {
+        Some((digits, places)) if exact => {
+            let value = ratio::Ratio { num: digits, den: 10u64.pow(places) };
+            (value, value)
+        },
+        Some((digits, places)) => {
+            let (lo, hi) = ratio::decimal_interval(digits, places)
+                .ok_or_else(|| format!("'{}' has too many digits.", arg))?;
+            let range = format!(
+                "{} to {}",
+                decimal_string(lo.num as u128 * 5, places + 1),
+                decimal_string(hi.num as u128 * 5, places + 1),
+            );
+            report = report.field("rng", range);
+            (lo, hi)
+        },
+        None => match ratio::parse_ratio(arg) {
+            Some(value) => (value, value),
+            None => return Err(format!("cannot parse '{}' as a fraction.", arg)),
+        },
+    };
+    let location = match ratio::locate(lo, hi) {
+        Some(location) => location,
+        None => return Err(format!("'{}' is not a positive fraction.", arg)),
+    };
+    let runs: Vec<String> = location
+        .runs
+        .iter()
+        .map(|(direction, count)| format!("{}{}", direction, count))
+        .collect();
+    let terms: Vec<String> = location.continued_fraction().iter().map(|t| t.to_string()).collect();
+    report = report.field("val", location.node.to_string()).field("dec", location.node.value());
+    let mut path = match location.runs.is_empty() {
+        true => "(root)".to_string(),
+        false => runs.join(" "),
+    };
+    if location.depth() > 0 && location.depth() <= 64 {
+        let moves: String = location
+            .runs
+            .iter()
+            .flat_map(|(direction, count)| std::iter::repeat_n(*direction, *count as usize))
+            .collect();
+        path += &format!("\n{}", moves);
+    }
+    report = report.field("pth", path).field("dep", location.depth());
+    // Shallow paths list the mediant at every move. Deeper ones list the node at the end of each
+    // run, with each arrow labelled with its run of moves.
+    if location.depth() <= 64 {
+        let nodes: Vec<String> =
+            location.path_nodes().iter().map(|node| node.to_string()).collect();
+        report = report.field("med", nodes.join(" → "));
+    } else {
+        let nodes = location.run_nodes();
+        let mut path = nodes[0].to_string();
+        for ((direction, count), node) in location.runs.iter().zip(&nodes[1..]) {
+            path += &format!(" →{}{} {}", direction, count, node);
+        }
+        report = report.field("med", path);
+    }
+    report = match terms.len() {
+        1 => report.field("cfr", format!("[{}]", terms[0])),
+        _ => report.field("cfr", format!("[{}; {}]", terms[0], terms[1..].join(", "))),
+    };
+    let neighbours = format!("{} < {} < {}", location.left, location.node, location.right);
+    Ok(report.field("nbr", neighbours))
+}
+
+
+// Formats an integer scaled by 10^-places as a decimal, e.g. 314159 with 5 places as 3.14159.
+fn decimal_string(value: u128, places: u32) -> String {
+    let digits = format!("{:0>width$}", value, width = places as usize + 1);
+    let (whole, frac) = digits.split_at(digits.len() - places as usize);
+    format!("{}.{}", whole, frac)
+}
@@ -0,0 +1,101 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::hex_bytes;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_uint;
+use crate::report::Report;
+use crate::synchsafe;
+
+
+const HELP: &str = "
+Usage: intspector synchsafe [integers]
+
+  Converts integers to synchsafe integers, the encoding used for tag and
+  frame sizes in ID3v2 tags. A synchsafe integer stores 7 bits in each byte,
+  leaving the high bit of every byte clear, so a 32-bit synchsafe integer
+  holds values up to 2^28 - 1. With the --decode flag, converts synchsafe
+  integers back to normal integers.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  e.g. 0x0000_0768.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "synchsafe"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Convert integers to and from synchsafe encoding."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of integers to convert.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "decode d", value: None, help: "Decode synchsafe integers." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_synchsafe(ctx, parser)
+    }
+}
+
+
+fn cmd_synchsafe(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let value = match parse_uint(arg).ok().filter(|value| *value <= u32::MAX as u64) {
+            Some(value) => value as u32,
+            None => {
+                Report::error(&format!("cannot parse '{}' as a 32-bit unsigned integer.", arg))
+                    .input(arg)
+                    .print(ctx)?;
+                ctx.termline()?;
+                continue;
+            }
+        };
+        let result = if cmd_parser.found("decode") {
+            synchsafe::decode(value).map(|decoded| (decoded, value))
+        } else {
+            synchsafe::encode(value).map(|encoded| (value, encoded))
+        };
+        let report = match result {
+            Some((plain, encoded)) => Report::new()
+                .field(
+                    "int",
+                    format!("{} (0x{:X})", add_spacers(&plain.to_string(), ',', 3), plain),
+                )
+                .field("syn", format!("0x{}", add_spacers(&format!("{:08X}", encoded), '_', 4)))
+                .field("byt", hex_bytes(&encoded.to_be_bytes())),
+            None if cmd_parser.found("decode") => {
+                Report::error(&format!("'{}' has a byte with the high bit set.", arg))
+            },
+            None => Report::error(&format!("'{}' does not fit in 28 bits.", arg)),
+        };
+        report.input(arg).print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
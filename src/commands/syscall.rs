@@ -0,0 +1,109 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::parse_int;
+use crate::report::Report;
+use crate::syscalls;
+
+
+const HELP: &str = "
+Usage: intspector syscall [numbers | names]
+
+  Maps Linux syscall numbers to names and names to numbers. Numbers can be
+  specified in binary, octal, decimal, or hexadecimal base. By default, each
+  number or name is looked up for all supported architectures.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "syscall"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Look up Linux syscall numbers and names."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[numbers | names]", "List of syscall numbers or names.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt {
+                names: "arch a",
+                value: Some("name"),
+                help: "Architecture: x86-64, aarch64, or riscv64.",
+            },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_syscall(ctx, parser)
+    }
+}
+
+
+fn cmd_syscall(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let arches = match cmd_parser.value("arch") {
+        Some(arg) => match syscalls::table(&arg) {
+            Some(table) => vec![(arch_label(&arg), table)],
+            None => {
+                return Err(Error::Invalid(format!("unsupported architecture '{}'.", arg)));
+            }
+        },
+        None => vec![
+            ("x64", syscalls::X86_64),
+            ("a64", syscalls::AARCH64),
+            ("r64", syscalls::RISCV64),
+        ],
+    };
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let mut report = Report::new();
+        for (label, table) in &arches {
+            let found = match parse_int(arg).ok() {
+                Some(value) if (0..=u32::MAX as i128).contains(&value) => {
+                    syscalls::name(table, value as u32).map(|name| (value as u32, name))
+                },
+                Some(_) => None,
+                None => syscalls::number(table, arg)
+                    .and_then(|number| syscalls::name(table, number).map(|name| (number, name))),
+            };
+            report = match found {
+                Some((number, name)) => {
+                    report.field(label, format!("{} (0x{:X}) {}", number, number, name))
+                },
+                None => report.field(label, format!("no syscall '{}'", arg)),
+            };
+        }
+        report.input(arg).print(ctx)?;
+        ctx.termline()?;
+    }
+    Ok(())
+}
+
+
+fn arch_label(arch: &str) -> &'static str {
+    match arch.to_lowercase().as_str() {
+        "aarch64" | "arm64" => "a64",
+        "riscv64" | "riscv" | "rv64" => "r64",
+        _ => "x64",
+    }
+}
@@ -0,0 +1,90 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_input;
+use crate::cli::print_result;
+use crate::cli::relative_time;
+use crate::commands::Decoder;
+use crate::datetime;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector time [integers]
+
+  Interprets each integer as a Unix timestamp in seconds, milliseconds,
+  microseconds, and nanoseconds since 1970-01-01 00:00:00 UTC, and displays
+  the UTC datetime for each interpretation along with how long ago or how far
+  in the future it is. The interpretation that lands near the present is
+  usually the right one.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions. Negative integers are times before 1970.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "time"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Interpret integers as Unix timestamps."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[integers]", "List of timestamps.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_time(ctx, parser)
+    }
+}
+
+
+fn cmd_time(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    let now = ctx.env.now;
+    for arg in &cmd_parser.args {
+        print_result(ctx, arg, eval_input(arg).map(|value| time_info(value, now)))?;
+    }
+    Ok(())
+}
+
+
+// Shows the timestamp as seconds, milliseconds, microseconds, and nanoseconds since the epoch.
+// Datetimes outside the years 0000 to 9999 are out of range.
+fn time_info(value: i128, now: i64) -> Report {
+    let (min, max) = (-62167219200, 253402300799);
+    let mut report = Report::new().field("val", value);
+    for (label, digits) in [("sec", 0), ("mil", 3), ("mic", 6), ("nan", 9)] {
+        let scale = 10i128.pow(digits);
+        let secs = value.div_euclid(scale);
+        if !(min..=max).contains(&secs) {
+            report = report.field(label, "out of range");
+            continue;
+        }
+        let datetime = match digits {
+            0 => datetime::format_utc(secs as i64),
+            _ => {
+                let fraction = value.rem_euclid(scale) as u64;
+                datetime::format_utc_fraction(secs as i64, fraction, digits as usize)
+            },
+        };
+        report = report.field(label, format!("{}, {}", datetime, relative_time(secs as i64 - now)));
+    }
+    report
+}
@@ -0,0 +1,67 @@
+use arguably::ArgParser;
+use crate::add_spacers;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::print_result;
+use crate::commands::Decoder;
+use crate::datetime;
+use crate::ids;
+use crate::report::Report;
+
+
+const HELP: &str = "
+Usage: intspector ulid [ulids]
+
+  Decodes ULIDs (26-character Crockford base 32 strings) into their 128-bit
+  value, their 48-bit millisecond timestamp displayed as a UTC datetime, and
+  their 80-bit random component.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "ulid"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode ULIDs."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[ulids]", "List of ULIDs.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_ulid(ctx, parser)
+    }
+}
+
+
+fn cmd_ulid(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let output = ids::ulid_decode(arg).map(|value| {
+            let (timestamp_ms, random) = ids::ulid_fields(value);
+            let time = format!("{} ({})", timestamp_ms, datetime::format_utc_millis(timestamp_ms));
+            Report::new()
+                .field("hex", add_spacers(&format!("{:032X}", value), ' ', 2))
+                .field("tim", time)
+                .field("rnd", add_spacers(&format!("{:020X}", random), ' ', 2))
+        });
+        print_result(ctx, arg, output.ok_or(format!("'{}' is not a valid ULID.", arg)))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use arguably::ArgParser;
+use crate::ascii;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::hex_bytes;
+use crate::codec;
+use crate::commands::Decoder;
+use crate::report::Align;
+use crate::report::Report;
+use crate::utf8_bytes;
+
+
+const HELP: &str = "
+Usage: intspector utf8 [bytes]
+
+  Decodes a sequence of bytes as UTF-8, showing the offset, bytes, code
+  point, and literal of each character. Invalid sequences are flagged with
+  the offset of the offending byte and the reason, e.g. a truncated
+  sequence or an overlong encoding, and decoding resumes at the next byte.
+
+  The arguments are joined into a single sequence. Each argument is either
+  hex digits, e.g. e2 9c 93, or a string with escapes, e.g. '\\xe2\\x9c\\x93',
+  in which characters other than escapes stand for their own UTF-8 bytes.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "utf8"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Decode UTF-8 byte sequences into code points."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[bytes]", "Bytes to decode.")]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_utf8(ctx, parser)
+    }
+}
+
+
+fn cmd_utf8(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    let mut bytes = Vec::new();
+    for arg in &cmd_parser.args {
+        match parse_escaped_bytes(arg) {
+            Some(parsed) => bytes.extend(parsed),
+            None => {
+                let msg = format!("cannot parse '{}' as hex or escaped bytes.", arg);
+                return Err(Error::Invalid(msg));
+            }
+        }
+    }
+    if bytes.is_empty() {
+        return Ok(());
+    }
+    let items = codec::decode_utf8(&bytes);
+    let mut errors = 0;
+    let mut rows = Vec::new();
+    for (offset, length, result) in &items {
+        let hex = hex_bytes(&bytes[*offset..offset + length]);
+        let detail = match result {
+            Ok(c) => match ascii(*c as i128) {
+                Some(description) => format!("U+{:04X}  {}", *c as u32, description),
+                None => format!("U+{:04X}  {}", *c as u32, c),
+            },
+            Err(err) => {
+                errors += 1;
+                // A truncated sequence is cut short by the byte after it, if there is one.
+                let culprit = match err {
+                    codec::Utf8Error::Truncated => offset + length,
+                    _ => *offset,
+                };
+                let label = ctx.style.locale.error;
+                match bytes.get(culprit) {
+                    Some(byte) => {
+                        format!("{}: {} at offset {} ({:02X})", label, err, culprit, byte)
+                    },
+                    None => format!("{}: {} at offset {} (end of input)", label, err, culprit),
+                }
+            },
+        };
+        rows.push(vec![offset.to_string(), hex, detail]);
+    }
+    ctx.termline()?;
+    let headings = ["offset", "bytes", "character"];
+    Report::new().aligned_table(&headings, &[Align::Right], rows).print(ctx)?;
+    ctx.termline()?;
+    let chars = items.len() - errors;
+    let count = format!(
+        "{} bytes, {} character{}, {} error{}",
+        bytes.len(),
+        chars,
+        if chars == 1 { "" } else { "s" },
+        errors,
+        if errors == 1 { "" } else { "s" },
+    );
+    Report::new().field("cnt", count).print(ctx)?;
+    ctx.termline()?;
+    Ok(())
+}
+
+
+// Parses a string with `\xNN` escapes into bytes, with other characters standing for their UTF-8
+// bytes, or, if there are no escapes, parses hex digits.
+fn parse_escaped_bytes(arg: &str) -> Option<Vec<u8>> {
+    if !arg.contains("\\x") {
+        return codec::hex_decode(arg);
+    }
+    let mut bytes = Vec::new();
+    let mut rest = arg;
+    while !rest.is_empty() {
+        if let Some(escape) = rest.strip_prefix("\\x") {
+            let digits = escape.get(..2)?;
+            bytes.push(u8::from_str_radix(digits, 16).ok()?);
+            rest = &escape[2..];
+        } else {
+            let c = rest.chars().next().unwrap();
+            bytes.extend(utf8_bytes(c));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    Some(bytes)
+}
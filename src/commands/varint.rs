@@ -0,0 +1,144 @@
+use arguably::ArgParser;
+use crate::cli::Context;
+use crate::cli::Error;
+use crate::cli::eval_input;
+use crate::cli::hex_bytes;
+use crate::cli::print_result;
+use crate::codec;
+use crate::commands::Decoder;
+use crate::commands::Opt;
+use crate::decode_uleb128;
+use crate::encode_uleb128;
+use crate::report::Report;
+use crate::zigzag_decode;
+use crate::zigzag_encode;
+
+
+const HELP: &str = "
+Usage: intspector varint [values]
+
+  Encodes integers as unsigned LEB128, the variable-length encoding used by
+  protobuf, WebAssembly, and DWARF, which stores 7 bits in each byte, least
+  significant first, with the high bit set on every byte but the last:
+
+  - leb: The unsigned LEB128 bytes. Negative integers have none.
+  - var: The protobuf varint for int64 and uint64 fields: the same bytes,
+    but with negative integers sign-extended to 64 bits, which always takes
+    10 bytes.
+  - zzg, zzv: The zigzag encoding used for protobuf sint fields, which maps
+    0, -1, 1, -2, ... to 0, 1, 2, 3, ..., and its varint bytes.
+
+  With the --decode flag, decodes hex bytes, e.g. ac02 or 'ac 02', as
+  varints instead, showing the unsigned and zigzag value of each. Bytes
+  holding several varints back to back, as in a packed protobuf field, are
+  decoded in turn.
+
+  Integers can be specified in binary, octal, decimal, or hexadecimal base,
+  or as expressions.
+";
+
+
+pub struct Command;
+
+
+impl Decoder for Command {
+    fn names(&self) -> &'static str {
+        "varint"
+    }
+
+
+    fn summary(&self) -> &'static str {
+        "Encode and decode LEB128 and protobuf varints."
+    }
+
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+
+
+    fn arguments(&self) -> &'static [(&'static str, &'static str)] {
+        &[("[values]", "List of integers, or hex bytes with --decode.")]
+    }
+
+
+    fn options(&self) -> &'static [Opt] {
+        &[
+            Opt { names: "decode d", value: None, help: "Decode hex bytes as varints." },
+        ]
+    }
+
+
+    fn run(&self, ctx: &mut Context, parser: &ArgParser) -> Result<(), Error> {
+        cmd_varint(ctx, parser)
+    }
+}
+
+
+fn cmd_varint(ctx: &mut Context, cmd_parser: &ArgParser) -> Result<(), Error> {
+    if !cmd_parser.args.is_empty() {
+        ctx.termline()?;
+    }
+    for arg in &cmd_parser.args {
+        let report = if cmd_parser.found("decode") {
+            codec::hex_decode(arg)
+                .ok_or(format!("cannot parse '{}' as hex.", arg))
+                .and_then(|bytes| varint_decode_info(&bytes))
+                .map_err(Report::from)
+        } else {
+            eval_input(arg).map(varint_info)
+        };
+        print_result(ctx, arg, report)?;
+    }
+    Ok(())
+}
+
+
+fn varint_info(value: i128) -> Report {
+    let varint = |value: u128| {
+        let bytes = encode_uleb128(value);
+        let plural = if bytes.len() == 1 { "" } else { "s" };
+        format!("{} ({} byte{})", hex_bytes(&bytes), bytes.len(), plural)
+    };
+    let mut report = Report::new().field("int", value.to_string());
+    report = match value {
+        0.. => report.field("leb", varint(value as u128)),
+        _ => report.field("leb", "not encodable, negative"),
+    };
+    report = match value {
+        0..=0xFFFF_FFFF_FFFF_FFFF => report.field("var", varint(value as u128)),
+        _ if value >= i64::MIN as i128 && value < 0 => {
+            let bytes = encode_uleb128(value as i64 as u64 as u128);
+            report.field("var", format!("{} (10 bytes, sign-extended)", hex_bytes(&bytes)))
+        },
+        _ => report.field("var", "not encodable, outside the 64-bit range"),
+    };
+    let zigzag = zigzag_encode(value);
+    report.field("zzg", zigzag.to_string()).field("zzv", varint(zigzag))
+}
+
+
+// Decodes a run of varints, showing the bytes and values of each in turn.
+fn varint_decode_info(bytes: &[u8]) -> Result<Report, String> {
+    let mut report = Report::new();
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let (value, len) = match decode_uleb128(&bytes[offset..]) {
+            Some(decoded) => decoded,
+            None if bytes[offset..].iter().all(|byte| byte & 0x80 != 0) => {
+                return Err(format!("the varint at offset {} is truncated.", offset));
+            },
+            None => return Err(format!("the varint at offset {} overflows 128 bits.", offset)),
+        };
+        report = report
+            .field("byt", hex_bytes(&bytes[offset..offset + len]))
+            .field("uns", value.to_string())
+            .field("zzg", zigzag_decode(value).to_string());
+        // Negative int64 values are sign-extended to 64 bits, so they decode above i64::MAX.
+        if value > i64::MAX as u128 && value <= u64::MAX as u128 {
+            report = report.field("i64", format!("{} (as a protobuf int64)", value as u64 as i64));
+        }
+        offset += len;
+    }
+    Ok(report)
+}
@@ -0,0 +1,396 @@
+//! Lookup tables for ISO 3166-1 country codes and ITU E.212 mobile country and network codes.
+
+
+/// An ISO 3166-1 country as a `(numeric, alpha-2, alpha-3, name)` tuple.
+pub type Country = (u16, &'static str, &'static str, &'static str);
+
+
+/// ISO 3166-1 countries, sorted by numeric code.
+pub const COUNTRIES: &[Country] = &[
+    (4, "AF", "AFG", "Afghanistan"),
+    (8, "AL", "ALB", "Albania"),
+    (10, "AQ", "ATA", "Antarctica"),
+    (12, "DZ", "DZA", "Algeria"),
+    (16, "AS", "ASM", "American Samoa"),
+    (20, "AD", "AND", "Andorra"),
+    (24, "AO", "AGO", "Angola"),
+    (28, "AG", "ATG", "Antigua and Barbuda"),
+    (31, "AZ", "AZE", "Azerbaijan"),
+    (32, "AR", "ARG", "Argentina"),
+    (36, "AU", "AUS", "Australia"),
+    (40, "AT", "AUT", "Austria"),
+    (44, "BS", "BHS", "Bahamas"),
+    (48, "BH", "BHR", "Bahrain"),
+    (50, "BD", "BGD", "Bangladesh"),
+    (51, "AM", "ARM", "Armenia"),
+    (52, "BB", "BRB", "Barbados"),
+    (56, "BE", "BEL", "Belgium"),
+    (60, "BM", "BMU", "Bermuda"),
+    (64, "BT", "BTN", "Bhutan"),
+    (68, "BO", "BOL", "Bolivia"),
+    (70, "BA", "BIH", "Bosnia and Herzegovina"),
+    (72, "BW", "BWA", "Botswana"),
+    (74, "BV", "BVT", "Bouvet Island"),
+    (76, "BR", "BRA", "Brazil"),
+    (84, "BZ", "BLZ", "Belize"),
+    (86, "IO", "IOT", "British Indian Ocean Territory"),
+    (90, "SB", "SLB", "Solomon Islands"),
+    (92, "VG", "VGB", "Virgin Islands, British"),
+    (96, "BN", "BRN", "Brunei Darussalam"),
+    (100, "BG", "BGR", "Bulgaria"),
+    (104, "MM", "MMR", "Myanmar"),
+    (108, "BI", "BDI", "Burundi"),
+    (112, "BY", "BLR", "Belarus"),
+    (116, "KH", "KHM", "Cambodia"),
+    (120, "CM", "CMR", "Cameroon"),
+    (124, "CA", "CAN", "Canada"),
+    (132, "CV", "CPV", "Cabo Verde"),
+    (136, "KY", "CYM", "Cayman Islands"),
+    (140, "CF", "CAF", "Central African Republic"),
+    (144, "LK", "LKA", "Sri Lanka"),
+    (148, "TD", "TCD", "Chad"),
+    (152, "CL", "CHL", "Chile"),
+    (156, "CN", "CHN", "China"),
+    (158, "TW", "TWN", "Taiwan"),
+    (162, "CX", "CXR", "Christmas Island"),
+    (166, "CC", "CCK", "Cocos (Keeling) Islands"),
+    (170, "CO", "COL", "Colombia"),
+    (174, "KM", "COM", "Comoros"),
+    (175, "YT", "MYT", "Mayotte"),
+    (178, "CG", "COG", "Congo"),
+    (180, "CD", "COD", "Congo, The Democratic Republic of the"),
+    (184, "CK", "COK", "Cook Islands"),
+    (188, "CR", "CRI", "Costa Rica"),
+    (191, "HR", "HRV", "Croatia"),
+    (192, "CU", "CUB", "Cuba"),
+    (196, "CY", "CYP", "Cyprus"),
+    (203, "CZ", "CZE", "Czechia"),
+    (204, "BJ", "BEN", "Benin"),
+    (208, "DK", "DNK", "Denmark"),
+    (212, "DM", "DMA", "Dominica"),
+    (214, "DO", "DOM", "Dominican Republic"),
+    (218, "EC", "ECU", "Ecuador"),
+    (222, "SV", "SLV", "El Salvador"),
+    (226, "GQ", "GNQ", "Equatorial Guinea"),
+    (231, "ET", "ETH", "Ethiopia"),
+    (232, "ER", "ERI", "Eritrea"),
+    (233, "EE", "EST", "Estonia"),
+    (234, "FO", "FRO", "Faroe Islands"),
+    (238, "FK", "FLK", "Falkland Islands (Malvinas)"),
+    (239, "GS", "SGS", "South Georgia and the South Sandwich Islands"),
+    (242, "FJ", "FJI", "Fiji"),
+    (246, "FI", "FIN", "Finland"),
+    (248, "AX", "ALA", "Åland Islands"),
+    (250, "FR", "FRA", "France"),
+    (254, "GF", "GUF", "French Guiana"),
+    (258, "PF", "PYF", "French Polynesia"),
+    (260, "TF", "ATF", "French Southern Territories"),
+    (262, "DJ", "DJI", "Djibouti"),
+    (266, "GA", "GAB", "Gabon"),
+    (268, "GE", "GEO", "Georgia"),
+    (270, "GM", "GMB", "Gambia"),
+    (275, "PS", "PSE", "Palestine, State of"),
+    (276, "DE", "DEU", "Germany"),
+    (288, "GH", "GHA", "Ghana"),
+    (292, "GI", "GIB", "Gibraltar"),
+    (296, "KI", "KIR", "Kiribati"),
+    (300, "GR", "GRC", "Greece"),
+    (304, "GL", "GRL", "Greenland"),
+    (308, "GD", "GRD", "Grenada"),
+    (312, "GP", "GLP", "Guadeloupe"),
+    (316, "GU", "GUM", "Guam"),
+    (320, "GT", "GTM", "Guatemala"),
+    (324, "GN", "GIN", "Guinea"),
+    (328, "GY", "GUY", "Guyana"),
+    (332, "HT", "HTI", "Haiti"),
+    (334, "HM", "HMD", "Heard Island and McDonald Islands"),
+    (336, "VA", "VAT", "Holy See (Vatican City State)"),
+    (340, "HN", "HND", "Honduras"),
+    (344, "HK", "HKG", "Hong Kong"),
+    (348, "HU", "HUN", "Hungary"),
+    (352, "IS", "ISL", "Iceland"),
+    (356, "IN", "IND", "India"),
+    (360, "ID", "IDN", "Indonesia"),
+    (364, "IR", "IRN", "Iran"),
+    (368, "IQ", "IRQ", "Iraq"),
+    (372, "IE", "IRL", "Ireland"),
+    (376, "IL", "ISR", "Israel"),
+    (380, "IT", "ITA", "Italy"),
+    (384, "CI", "CIV", "Côte d'Ivoire"),
+    (388, "JM", "JAM", "Jamaica"),
+    (392, "JP", "JPN", "Japan"),
+    (398, "KZ", "KAZ", "Kazakhstan"),
+    (400, "JO", "JOR", "Jordan"),
+    (404, "KE", "KEN", "Kenya"),
+    (408, "KP", "PRK", "North Korea"),
+    (410, "KR", "KOR", "South Korea"),
+    (414, "KW", "KWT", "Kuwait"),
+    (417, "KG", "KGZ", "Kyrgyzstan"),
+    (418, "LA", "LAO", "Laos"),
+    (422, "LB", "LBN", "Lebanon"),
+    (426, "LS", "LSO", "Lesotho"),
+    (428, "LV", "LVA", "Latvia"),
+    (430, "LR", "LBR", "Liberia"),
+    (434, "LY", "LBY", "Libya"),
+    (438, "LI", "LIE", "Liechtenstein"),
+    (440, "LT", "LTU", "Lithuania"),
+    (442, "LU", "LUX", "Luxembourg"),
+    (446, "MO", "MAC", "Macao"),
+    (450, "MG", "MDG", "Madagascar"),
+    (454, "MW", "MWI", "Malawi"),
+    (458, "MY", "MYS", "Malaysia"),
+    (462, "MV", "MDV", "Maldives"),
+    (466, "ML", "MLI", "Mali"),
+    (470, "MT", "MLT", "Malta"),
+    (474, "MQ", "MTQ", "Martinique"),
+    (478, "MR", "MRT", "Mauritania"),
+    (480, "MU", "MUS", "Mauritius"),
+    (484, "MX", "MEX", "Mexico"),
+    (492, "MC", "MCO", "Monaco"),
+    (496, "MN", "MNG", "Mongolia"),
+    (498, "MD", "MDA", "Moldova"),
+    (499, "ME", "MNE", "Montenegro"),
+    (500, "MS", "MSR", "Montserrat"),
+    (504, "MA", "MAR", "Morocco"),
+    (508, "MZ", "MOZ", "Mozambique"),
+    (512, "OM", "OMN", "Oman"),
+    (516, "NA", "NAM", "Namibia"),
+    (520, "NR", "NRU", "Nauru"),
+    (524, "NP", "NPL", "Nepal"),
+    (528, "NL", "NLD", "Netherlands"),
+    (531, "CW", "CUW", "Curaçao"),
+    (533, "AW", "ABW", "Aruba"),
+    (534, "SX", "SXM", "Sint Maarten (Dutch part)"),
+    (535, "BQ", "BES", "Bonaire, Sint Eustatius and Saba"),
+    (540, "NC", "NCL", "New Caledonia"),
+    (548, "VU", "VUT", "Vanuatu"),
+    (554, "NZ", "NZL", "New Zealand"),
+    (558, "NI", "NIC", "Nicaragua"),
+    (562, "NE", "NER", "Niger"),
+    (566, "NG", "NGA", "Nigeria"),
+    (570, "NU", "NIU", "Niue"),
+    (574, "NF", "NFK", "Norfolk Island"),
+    (578, "NO", "NOR", "Norway"),
+    (580, "MP", "MNP", "Northern Mariana Islands"),
+    (581, "UM", "UMI", "United States Minor Outlying Islands"),
+    (583, "FM", "FSM", "Micronesia, Federated States of"),
+    (584, "MH", "MHL", "Marshall Islands"),
+    (585, "PW", "PLW", "Palau"),
+    (586, "PK", "PAK", "Pakistan"),
+    (591, "PA", "PAN", "Panama"),
+    (598, "PG", "PNG", "Papua New Guinea"),
+    (600, "PY", "PRY", "Paraguay"),
+    (604, "PE", "PER", "Peru"),
+    (608, "PH", "PHL", "Philippines"),
+    (612, "PN", "PCN", "Pitcairn"),
+    (616, "PL", "POL", "Poland"),
+    (620, "PT", "PRT", "Portugal"),
+    (624, "GW", "GNB", "Guinea-Bissau"),
+    (626, "TL", "TLS", "Timor-Leste"),
+    (630, "PR", "PRI", "Puerto Rico"),
+    (634, "QA", "QAT", "Qatar"),
+    (638, "RE", "REU", "Réunion"),
+    (642, "RO", "ROU", "Romania"),
+    (643, "RU", "RUS", "Russian Federation"),
+    (646, "RW", "RWA", "Rwanda"),
+    (652, "BL", "BLM", "Saint Barthélemy"),
+    (654, "SH", "SHN", "Saint Helena, Ascension and Tristan da Cunha"),
+    (659, "KN", "KNA", "Saint Kitts and Nevis"),
+    (660, "AI", "AIA", "Anguilla"),
+    (662, "LC", "LCA", "Saint Lucia"),
+    (663, "MF", "MAF", "Saint Martin (French part)"),
+    (666, "PM", "SPM", "Saint Pierre and Miquelon"),
+    (670, "VC", "VCT", "Saint Vincent and the Grenadines"),
+    (674, "SM", "SMR", "San Marino"),
+    (678, "ST", "STP", "Sao Tome and Principe"),
+    (682, "SA", "SAU", "Saudi Arabia"),
+    (686, "SN", "SEN", "Senegal"),
+    (688, "RS", "SRB", "Serbia"),
+    (690, "SC", "SYC", "Seychelles"),
+    (694, "SL", "SLE", "Sierra Leone"),
+    (702, "SG", "SGP", "Singapore"),
+    (703, "SK", "SVK", "Slovakia"),
+    (704, "VN", "VNM", "Vietnam"),
+    (705, "SI", "SVN", "Slovenia"),
+    (706, "SO", "SOM", "Somalia"),
+    (710, "ZA", "ZAF", "South Africa"),
+    (716, "ZW", "ZWE", "Zimbabwe"),
+    (724, "ES", "ESP", "Spain"),
+    (728, "SS", "SSD", "South Sudan"),
+    (729, "SD", "SDN", "Sudan"),
+    (732, "EH", "ESH", "Western Sahara"),
+    (740, "SR", "SUR", "Suriname"),
+    (744, "SJ", "SJM", "Svalbard and Jan Mayen"),
+    (748, "SZ", "SWZ", "Eswatini"),
+    (752, "SE", "SWE", "Sweden"),
+    (756, "CH", "CHE", "Switzerland"),
+    (760, "SY", "SYR", "Syria"),
+    (762, "TJ", "TJK", "Tajikistan"),
+    (764, "TH", "THA", "Thailand"),
+    (768, "TG", "TGO", "Togo"),
+    (772, "TK", "TKL", "Tokelau"),
+    (776, "TO", "TON", "Tonga"),
+    (780, "TT", "TTO", "Trinidad and Tobago"),
+    (784, "AE", "ARE", "United Arab Emirates"),
+    (788, "TN", "TUN", "Tunisia"),
+    (792, "TR", "TUR", "Türkiye"),
+    (795, "TM", "TKM", "Turkmenistan"),
+    (796, "TC", "TCA", "Turks and Caicos Islands"),
+    (798, "TV", "TUV", "Tuvalu"),
+    (800, "UG", "UGA", "Uganda"),
+    (804, "UA", "UKR", "Ukraine"),
+    (807, "MK", "MKD", "North Macedonia"),
+    (818, "EG", "EGY", "Egypt"),
+    (826, "GB", "GBR", "United Kingdom"),
+    (831, "GG", "GGY", "Guernsey"),
+    (832, "JE", "JEY", "Jersey"),
+    (833, "IM", "IMN", "Isle of Man"),
+    (834, "TZ", "TZA", "Tanzania"),
+    (840, "US", "USA", "United States"),
+    (850, "VI", "VIR", "Virgin Islands, U.S."),
+    (854, "BF", "BFA", "Burkina Faso"),
+    (858, "UY", "URY", "Uruguay"),
+    (860, "UZ", "UZB", "Uzbekistan"),
+    (862, "VE", "VEN", "Venezuela"),
+    (876, "WF", "WLF", "Wallis and Futuna"),
+    (882, "WS", "WSM", "Samoa"),
+    (887, "YE", "YEM", "Yemen"),
+    (894, "ZM", "ZMB", "Zambia"),
+];
+
+
+/// Mobile country codes (MCCs) with the alpha-2 code of their country. Some countries have more
+/// than one MCC.
+pub const MCCS: &[(u16, &str)] = &[
+    (202, "GR"), (204, "NL"), (206, "BE"), (208, "FR"), (212, "MC"), (213, "AD"), (214, "ES"),
+    (216, "HU"), (218, "BA"), (219, "HR"), (220, "RS"), (222, "IT"), (226, "RO"), (228, "CH"),
+    (230, "CZ"), (231, "SK"), (232, "AT"), (234, "GB"), (235, "GB"), (238, "DK"), (240, "SE"),
+    (242, "NO"), (244, "FI"), (246, "LT"), (247, "LV"), (248, "EE"), (250, "RU"), (255, "UA"),
+    (257, "BY"), (259, "MD"), (260, "PL"), (262, "DE"), (266, "GI"), (268, "PT"), (270, "LU"),
+    (272, "IE"), (274, "IS"), (276, "AL"), (278, "MT"), (280, "CY"), (282, "GE"), (283, "AM"),
+    (284, "BG"), (286, "TR"), (288, "FO"), (290, "GL"), (292, "SM"), (293, "SI"), (294, "MK"),
+    (295, "LI"), (297, "ME"), (302, "CA"), (310, "US"), (311, "US"), (312, "US"), (313, "US"),
+    (314, "US"), (315, "US"), (316, "US"), (334, "MX"), (338, "JM"), (350, "BM"), (368, "CU"),
+    (370, "DO"), (400, "AZ"), (401, "KZ"), (404, "IN"), (405, "IN"), (410, "PK"), (412, "AF"),
+    (413, "LK"), (414, "MM"), (415, "LB"), (416, "JO"), (417, "SY"), (418, "IQ"), (419, "KW"),
+    (420, "SA"), (421, "YE"), (422, "OM"), (424, "AE"), (425, "IL"), (426, "BH"), (427, "QA"),
+    (428, "MN"), (429, "NP"), (432, "IR"), (434, "UZ"), (436, "TJ"), (437, "KG"), (438, "TM"),
+    (440, "JP"), (441, "JP"), (450, "KR"), (452, "VN"), (454, "HK"), (455, "MO"), (456, "KH"),
+    (457, "LA"), (460, "CN"), (466, "TW"), (470, "BD"), (502, "MY"), (505, "AU"), (510, "ID"),
+    (515, "PH"), (520, "TH"), (525, "SG"), (528, "BN"), (530, "NZ"), (602, "EG"), (603, "DZ"),
+    (604, "MA"), (605, "TN"), (606, "LY"), (620, "GH"), (621, "NG"), (639, "KE"), (640, "TZ"),
+    (641, "UG"), (655, "ZA"), (704, "GT"), (706, "SV"), (708, "HN"), (710, "NI"), (712, "CR"),
+    (714, "PA"), (716, "PE"), (722, "AR"), (724, "BR"), (730, "CL"), (732, "CO"), (734, "VE"),
+    (736, "BO"), (740, "EC"), (744, "PY"), (748, "UY"),
+];
+
+
+/// Common mobile networks as `(MCC, MNC, operator)` tuples. MNCs are two or three digits
+/// depending on the country, so are stored as strings to preserve leading zeros.
+pub const NETWORKS: &[(&str, &str, &str)] = &[
+    ("001", "01", "Test network"),
+    ("204", "04", "Vodafone"),
+    ("204", "08", "KPN"),
+    ("204", "16", "T-Mobile"),
+    ("208", "01", "Orange"),
+    ("208", "10", "SFR"),
+    ("208", "15", "Free Mobile"),
+    ("208", "20", "Bouygues Telecom"),
+    ("214", "01", "Vodafone"),
+    ("214", "03", "Orange"),
+    ("214", "07", "Movistar"),
+    ("222", "01", "TIM"),
+    ("222", "10", "Vodafone"),
+    ("234", "10", "O2"),
+    ("234", "15", "Vodafone"),
+    ("234", "20", "Three"),
+    ("234", "30", "EE"),
+    ("262", "01", "Telekom"),
+    ("262", "02", "Vodafone"),
+    ("262", "03", "O2"),
+    ("272", "01", "Vodafone"),
+    ("302", "220", "Telus"),
+    ("302", "610", "Bell"),
+    ("302", "720", "Rogers"),
+    ("310", "260", "T-Mobile"),
+    ("310", "410", "AT&T"),
+    ("311", "480", "Verizon"),
+    ("334", "020", "Telcel"),
+    ("440", "10", "NTT Docomo"),
+    ("440", "20", "SoftBank"),
+    ("440", "50", "KDDI"),
+    ("450", "05", "SK Telecom"),
+    ("450", "08", "KT"),
+    ("460", "00", "China Mobile"),
+    ("460", "01", "China Unicom"),
+    ("460", "11", "China Telecom"),
+    ("505", "01", "Telstra"),
+    ("505", "02", "Optus"),
+    ("505", "03", "Vodafone"),
+    ("530", "01", "One NZ"),
+    ("621", "30", "MTN"),
+    ("639", "02", "Safaricom"),
+    ("655", "01", "Vodacom"),
+    ("655", "10", "MTN"),
+    ("724", "05", "Claro"),
+    ("724", "06", "Vivo"),
+];
+
+
+/// Returns the country with the specified ISO 3166-1 numeric code.
+pub fn country_by_numeric(numeric: u16) -> Option<Country> {
+    COUNTRIES.iter().find(|country| country.0 == numeric).copied()
+}
+
+
+/// Returns the country with the specified alpha-2 or alpha-3 code or name. The lookup is
+/// case-insensitive.
+pub fn country_by_code(code: &str) -> Option<Country> {
+    COUNTRIES
+        .iter()
+        .find(|(_, a2, a3, name)| {
+            a2.eq_ignore_ascii_case(code)
+                || a3.eq_ignore_ascii_case(code)
+                || name.eq_ignore_ascii_case(code)
+        })
+        .copied()
+}
+
+
+/// Returns the alpha-2 country code for the specified mobile country code.
+pub fn mcc_country(mcc: u16) -> Option<&'static str> {
+    MCCS.iter().find(|(m, _)| *m == mcc).map(|(_, code)| *code)
+}
+
+
+/// Returns the mobile country codes assigned to the country with the specified alpha-2 code.
+pub fn country_mccs(alpha2: &str) -> Vec<u16> {
+    MCCS.iter()
+        .filter(|(_, code)| code.eq_ignore_ascii_case(alpha2))
+        .map(|(mcc, _)| *mcc)
+        .collect()
+}
+
+
+/// Returns the operator of the mobile network with the specified MCC and MNC. The MNC must be
+/// written with its leading zeros, e.g. `01`.
+pub fn network(mcc: &str, mnc: &str) -> Option<&'static str> {
+    NETWORKS.iter().find(|(m, n, _)| *m == mcc && *n == mnc).map(|(_, _, name)| *name)
+}
+
+
+/// Parses an MCC-MNC pair written as `310-260`, `310/260`, or as five or six bare digits, e.g.
+/// `310260`, into its MCC and MNC. Returns `None` if the MCC is not three digits or the MNC is
+/// not two or three digits.
+pub fn parse_network(arg: &str) -> Option<(&str, &str)> {
+    let (mcc, mnc) = match arg.split_once(['-', '/']) {
+        Some(pair) => pair,
+        None if arg.len() >= 5 => arg.split_at(3),
+        None => return None,
+    };
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if mcc.len() != 3 || !(2..=3).contains(&mnc.len()) || !is_digits(mcc) || !is_digits(mnc) {
+        return None;
+    }
+    Some((mcc, mnc))
+}
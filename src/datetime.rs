@@ -0,0 +1,236 @@
+//! Calendar arithmetic for converting between Unix timestamps and UTC dates. Uses the proleptic
+//! Gregorian calendar throughout.
+
+
+/// Returns the number of days since the Unix epoch for the specified date.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+
+/// Returns the `(year, month, day)` date for the specified number of days since the Unix epoch.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+
+/// Returns true if the specified year is a leap year.
+pub fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+
+/// Returns the number of days in the specified month.
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+
+/// Returns the `(year, month, day)` date encoded by a packed `YYYYMMDD` integer, e.g. `20240315`.
+/// Returns `None` if the integer does not encode a valid date.
+pub fn unpack_date(packed: u64) -> Option<(i64, u32, u32)> {
+    let year = (packed / 10000) as i64;
+    let month = (packed / 100 % 100) as u32;
+    let day = (packed % 100) as u32;
+    if packed > 99991231 || !(1..=12).contains(&month) {
+        return None;
+    }
+    if day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+
+/// Returns the `(year, month, day)` date encoded by a packed `YYYYDDD` ordinal date integer, e.g.
+/// `2024075`. Returns `None` if the integer does not encode a valid ordinal date.
+pub fn unpack_ordinal(packed: u64) -> Option<(i64, u32, u32)> {
+    let year = (packed / 1000) as i64;
+    let ordinal = (packed % 1000) as i64;
+    let length = if is_leap_year(year) { 366 } else { 365 };
+    if packed > 9999366 || ordinal == 0 || ordinal > length {
+        return None;
+    }
+    Some(civil_from_days(days_from_civil(year, 1, 1) + ordinal - 1))
+}
+
+
+/// Returns the day of the year of the specified date, counting January 1st as day 1.
+pub fn day_of_year(year: i64, month: u32, day: u32) -> u32 {
+    (days_from_civil(year, month, day) - days_from_civil(year, 1, 1)) as u32 + 1
+}
+
+
+/// Returns the ISO 8601 day of the week for the specified number of days since the Unix epoch,
+/// from 1 for Monday to 7 for Sunday.
+pub fn iso_weekday(days: i64) -> u32 {
+    (days + 3).rem_euclid(7) as u32 + 1
+}
+
+
+/// Returns the English name of an ISO 8601 day of the week.
+pub fn weekday_name(weekday: u32) -> &'static str {
+    match weekday {
+        1 => "Monday",
+        2 => "Tuesday",
+        3 => "Wednesday",
+        4 => "Thursday",
+        5 => "Friday",
+        6 => "Saturday",
+        _ => "Sunday",
+    }
+}
+
+
+/// Returns the `(year, week)` ISO 8601 week date of the specified date. The ISO week-numbering
+/// year can differ from the calendar year for dates in late December and early January.
+pub fn iso_week(year: i64, month: u32, day: u32) -> (i64, u32) {
+    let weeks_in_year = |year: i64| {
+        let jan1 = iso_weekday(days_from_civil(year, 1, 1));
+        if jan1 == 4 || (jan1 == 3 && is_leap_year(year)) { 53 } else { 52 }
+    };
+    let weekday = iso_weekday(days_from_civil(year, month, day)) as i64;
+    let week = (day_of_year(year, month, day) as i64 - weekday + 10) / 7;
+    if week < 1 {
+        (year - 1, weeks_in_year(year - 1))
+    } else if week > weeks_in_year(year) as i64 {
+        (year + 1, 1)
+    } else {
+        (year, week as u32)
+    }
+}
+
+
+/// Formats a Unix timestamp in seconds as a UTC datetime, e.g. `2001-09-09 01:46:40 UTC`.
+pub fn format_utc(secs: i64) -> String {
+    let days = secs.div_euclid(86400);
+    let rem = secs.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year, month, day, rem / 3600, rem % 3600 / 60, rem % 60
+    )
+}
+
+
+/// Formats a Unix timestamp in milliseconds as a UTC datetime, e.g.
+/// `2001-09-09 01:46:40.123 UTC`.
+pub fn format_utc_millis(millis: i64) -> String {
+    let secs = millis.div_euclid(1000);
+    let datetime = format_utc(secs);
+    format!("{}.{:03} UTC", datetime.trim_end_matches(" UTC"), millis.rem_euclid(1000))
+}
+
+
+/// Formats a Unix timestamp in seconds plus a fraction of a second as a UTC datetime, with the
+/// fraction shown to `digits` decimal places, e.g. `2001-09-09 01:46:40.123456 UTC` for a
+/// fraction of 123456 and 6 digits.
+pub fn format_utc_fraction(secs: i64, fraction: u64, digits: usize) -> String {
+    let datetime = format_utc(secs);
+    format!(
+        "{}.{:0digits$} UTC",
+        datetime.trim_end_matches(" UTC"),
+        fraction,
+        digits = digits
+    )
+}
+
+
+/// Returns the current Unix timestamp in seconds.
+pub fn now() -> i64 {
+    match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(err) => -(err.duration().as_secs() as i64),
+    }
+}
+
+
+/// Duration units with their lengths in nanoseconds, from the largest to the smallest.
+pub const DURATION_UNITS: &[(&str, &str, u128)] = &[
+    ("w", "week", 604_800_000_000_000),
+    ("d", "day", 86_400_000_000_000),
+    ("h", "hour", 3_600_000_000_000),
+    ("m", "minute", 60_000_000_000),
+    ("s", "second", 1_000_000_000),
+    ("ms", "millisecond", 1_000_000),
+    ("us", "microsecond", 1_000),
+    ("ns", "nanosecond", 1),
+];
+
+
+/// Returns the length in nanoseconds of the duration unit with the specified symbol, e.g. `ms`.
+/// Accepts `µs` for microseconds.
+pub fn duration_unit(symbol: &str) -> Option<u128> {
+    let symbol = if symbol == "µs" { "us" } else { symbol };
+    DURATION_UNITS.iter().find(|unit| unit.0 == symbol).map(|unit| unit.2)
+}
+
+
+/// Parses a duration written as a sequence of integers with unit symbols, e.g. `2h30m` or
+/// `1d 12h`, and returns its length in nanoseconds.
+pub fn parse_duration(text: &str) -> Result<u128, String> {
+    let text: String = text.chars().filter(|c| !c.is_whitespace() && *c != '_').collect();
+    if text.is_empty() {
+        return Err("empty duration".to_string());
+    }
+    let mut total: u128 = 0;
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        if digits == 0 {
+            return Err(format!("expected a number at '{}'", rest));
+        }
+        let number: u128 = rest[..digits].parse().map_err(|_| "number too large".to_string())?;
+        rest = &rest[digits..];
+        let symbol_len = rest.find(|c: char| c.is_ascii_digit()).unwrap_or(rest.len());
+        let symbol = &rest[..symbol_len];
+        if symbol.is_empty() {
+            return Err(format!("missing unit after '{}'", number));
+        }
+        let unit = duration_unit(symbol).ok_or_else(|| format!("unknown unit '{}'", symbol))?;
+        total = number
+            .checked_mul(unit)
+            .and_then(|nanos| total.checked_add(nanos))
+            .ok_or("duration too large")?;
+        rest = &rest[symbol_len..];
+    }
+    Ok(total)
+}
+
+
+/// Formats a duration in nanoseconds using unit symbols up to days, omitting zero components,
+/// e.g. `2h 30m` or `1d 5s`.
+pub fn format_duration(nanos: u128) -> String {
+    if nanos == 0 {
+        return "0s".to_string();
+    }
+    let mut parts = Vec::new();
+    let mut rest = nanos;
+    for (symbol, _, length) in &DURATION_UNITS[1..] {
+        if rest >= *length {
+            parts.push(format!("{}{}", rest / length, symbol));
+            rest %= length;
+        }
+    }
+    parts.join(" ")
+}
@@ -0,0 +1,273 @@
+//! Decoding of IEEE 754-2008 decimal floating-point formats in both the densely packed decimal
+//! (DPD) and binary integer decimal (BID) encodings.
+
+
+/// An IEEE 754 decimal interchange format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Decimal32,
+    Decimal64,
+    Decimal128,
+}
+
+
+/// All supported formats.
+pub const FORMATS: [Format; 3] = [Format::Decimal32, Format::Decimal64, Format::Decimal128];
+
+
+impl Format {
+    /// Returns the format's name, e.g. `decimal64`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::Decimal32 => "decimal32",
+            Format::Decimal64 => "decimal64",
+            Format::Decimal128 => "decimal128",
+        }
+    }
+
+    /// Returns the format with the specified name. Accepts short names, e.g. `d64`.
+    pub fn from_name(name: &str) -> Option<Format> {
+        let name = name.to_lowercase();
+        FORMATS.iter().copied().find(|f| f.name() == name || f.name().replace("ecimal", "") == name)
+    }
+
+    /// Returns the width of the format in bits.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Format::Decimal32 => 32,
+            Format::Decimal64 => 64,
+            Format::Decimal128 => 128,
+        }
+    }
+
+    /// Returns the precision of the format in decimal digits.
+    pub fn precision(&self) -> u32 {
+        self.bits() * 9 / 32 - 2
+    }
+
+    /// Returns the width of the exponent continuation field in bits.
+    pub fn continuation_bits(&self) -> u32 {
+        self.bits() / 16 + 4
+    }
+
+    /// Returns the width of the coefficient continuation (trailing significand) field in bits.
+    pub fn trailing_bits(&self) -> u32 {
+        self.bits() - self.continuation_bits() - 6
+    }
+
+    /// Returns the exponent bias.
+    pub fn bias(&self) -> i32 {
+        3 * (1 << (self.continuation_bits() - 1)) + self.precision() as i32 - 2
+    }
+}
+
+
+/// An encoding of the coefficient of a decimal floating-point value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    /// Densely packed decimal: three digits per 10-bit declet.
+    Dpd,
+    /// Binary integer decimal: the coefficient as a binary integer.
+    Bid,
+}
+
+
+/// The raw fields of a decimal floating-point value, in the layout shared by both encodings.
+#[derive(Debug, PartialEq)]
+pub struct Fields {
+    pub sign: u32,
+    /// The 5-bit combination field.
+    pub combination: u32,
+    /// The exponent continuation field.
+    pub continuation: u32,
+    /// The coefficient continuation field.
+    pub trailing: u128,
+}
+
+
+/// Splits a decimal floating-point value into its raw fields.
+pub fn fields(bits: u128, format: Format) -> Fields {
+    let (w, t) = (format.continuation_bits(), format.trailing_bits());
+    Fields {
+        sign: (bits >> (format.bits() - 1)) as u32 & 1,
+        combination: (bits >> (t + w)) as u32 & 0x1F,
+        continuation: (bits >> t) as u32 & ((1 << w) - 1),
+        trailing: bits & ((1 << t) - 1),
+    }
+}
+
+
+/// The class of a decimal floating-point value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Class {
+    Finite,
+    Infinite,
+    QuietNan,
+    SignalingNan,
+}
+
+
+/// A decoded decimal floating-point value: `coefficient × 10^exponent`. For NaNs, `coefficient`
+/// holds the payload and `exponent` is zero.
+#[derive(Debug, PartialEq)]
+pub struct Decimal {
+    pub negative: bool,
+    pub class: Class,
+    pub exponent: i32,
+    pub coefficient: u128,
+    /// False if the encoding is non-canonical, e.g. a BID coefficient above the format's
+    /// maximum, which is read as zero.
+    pub canonical: bool,
+}
+
+
+/// Decodes a decimal floating-point value.
+pub fn decode(bits: u128, format: Format, encoding: Encoding) -> Decimal {
+    let f = fields(bits, format);
+    let (w, t) = (format.continuation_bits(), format.trailing_bits());
+    let negative = f.sign == 1;
+    let max_coefficient = 10u128.pow(format.precision()) - 1;
+
+    if f.combination >> 1 == 0b1111 {
+        let class = if f.combination & 1 == 0 {
+            Class::Infinite
+        } else if f.continuation >> (w - 1) == 1 {
+            Class::SignalingNan
+        } else {
+            Class::QuietNan
+        };
+        let (payload, canonical) = match encoding {
+            Encoding::Dpd => decode_declets(f.trailing, t / 10),
+            Encoding::Bid if f.trailing > max_coefficient / 10 => (0, false),
+            Encoding::Bid => (f.trailing, true),
+        };
+        let payload = if class == Class::Infinite { 0 } else { payload };
+        return Decimal { negative, class, exponent: 0, coefficient: payload, canonical };
+    }
+
+    // The full combination field: the 5-bit combination field plus the exponent continuation.
+    let g = f.combination << w | f.continuation;
+    let (biased, coefficient, canonical) = match encoding {
+        Encoding::Bid => {
+            let (biased, msb) = if f.combination >> 3 != 0b11 {
+                (g >> 3, (g & 0x7) as u128)
+            } else {
+                ((g >> 1) & ((1 << (w + 2)) - 1), (0x8 | g & 0x1) as u128)
+            };
+            let coefficient = msb << t | f.trailing;
+            if coefficient > max_coefficient {
+                (biased, 0, false)
+            } else {
+                (biased, coefficient, true)
+            }
+        },
+        Encoding::Dpd => {
+            let (biased, msd) = if f.combination >> 3 != 0b11 {
+                ((f.combination >> 3) << w | f.continuation, f.combination & 0x7)
+            } else {
+                (((f.combination >> 1) & 0x3) << w | f.continuation, 0x8 | f.combination & 0x1)
+            };
+            let (low, canonical) = decode_declets(f.trailing, t / 10);
+            (biased, msd as u128 * 10u128.pow(3 * (t / 10)) + low, canonical)
+        },
+    };
+    Decimal {
+        negative,
+        class: Class::Finite,
+        exponent: biased as i32 - format.bias(),
+        coefficient,
+        canonical,
+    }
+}
+
+
+/// Decodes a sequence of declets into an integer, also returning false if any declet is
+/// non-canonical.
+fn decode_declets(trailing: u128, count: u32) -> (u128, bool) {
+    let mut value = 0;
+    let mut canonical = true;
+    for i in (0..count).rev() {
+        let declet = (trailing >> (10 * i)) as u32 & 0x3FF;
+        let digits = decode_declet(declet);
+        canonical &= encode_declet(digits) == declet;
+        value = value * 1000 + digits as u128;
+    }
+    (value, canonical)
+}
+
+
+/// Decodes a 10-bit densely packed decimal declet into three decimal digits, 0-999. Each of
+/// the 24 non-canonical declets decodes to the same value as a canonical declet.
+pub fn decode_declet(declet: u32) -> u32 {
+    let b = |i: u32| declet >> i & 1;
+    let (d2, d1, d0) = if b(3) == 0 {
+        (declet >> 7, declet >> 4 & 0x7, declet & 0x7)
+    } else {
+        match (b(2), b(1), b(6), b(5)) {
+            (0, 0, _, _) => (declet >> 7, declet >> 4 & 0x7, 8 + b(0)),
+            (0, 1, _, _) => (declet >> 7, 8 + b(4), (declet >> 4 & 0x6) + b(0)),
+            (1, 0, _, _) => (8 + b(7), declet >> 4 & 0x7, (declet >> 7 & 0x6) + b(0)),
+            (1, 1, 0, 0) => (8 + b(7), 8 + b(4), (declet >> 7 & 0x6) + b(0)),
+            (1, 1, 0, 1) => (8 + b(7), (declet >> 7 & 0x6) + b(4), 8 + b(0)),
+            (1, 1, 1, 0) => (declet >> 7, 8 + b(4), 8 + b(0)),
+            _ => (8 + b(7), 8 + b(4), 8 + b(0)),
+        }
+    };
+    d2 * 100 + d1 * 10 + d0
+}
+
+
+/// Encodes three decimal digits, 0-999, as a canonical densely packed decimal declet.
+pub fn encode_declet(value: u32) -> u32 {
+    let (d2, d1, d0) = (value / 100 % 10, value / 10 % 10, value % 10);
+    // Digits 8 and 9 are stored in 1 bit; other digits in 3 bits.
+    let (hi2, hi1, hi0) = (d2 >> 1 & 0x3, d1 >> 1 & 0x3, d0 >> 1 & 0x3);
+    let low = (d2 & 1) << 7 | (d1 & 1) << 4 | (d0 & 1);
+    let fields = match (d2 > 7, d1 > 7, d0 > 7) {
+        (false, false, false) => (d2 >> 1) << 8 | (d1 >> 1) << 5 | (d0 >> 1) << 1,
+        (false, false, true) => hi2 << 8 | hi1 << 5 | 0b1000,
+        (false, true, false) => hi2 << 8 | hi0 << 5 | 0b1010,
+        (true, false, false) => hi0 << 8 | hi1 << 5 | 0b1100,
+        (true, true, false) => hi0 << 8 | 0b1110,
+        (true, false, true) => hi1 << 8 | 0b0100000 | 0b1110,
+        (false, true, true) => hi2 << 8 | 0b1000000 | 0b1110,
+        (true, true, true) => 0b1100000 | 0b1110,
+    };
+    fields | low
+}
+
+
+impl Decimal {
+    /// Formats the value as a decimal string using the scientific notation of the General
+    /// Decimal Arithmetic specification, e.g. `1.23`, `1.23E+5`, `-Infinity`, or `NaN12`.
+    pub fn to_sci_string(&self) -> String {
+        let sign = if self.negative { "-" } else { "" };
+        let payload = match self.coefficient {
+            0 => String::new(),
+            payload => payload.to_string(),
+        };
+        match self.class {
+            Class::Infinite => return format!("{}Infinity", sign),
+            Class::QuietNan => return format!("{}NaN{}", sign, payload),
+            Class::SignalingNan => return format!("{}sNaN{}", sign, payload),
+            Class::Finite => {},
+        }
+        let digits = self.coefficient.to_string();
+        let adjusted = self.exponent as i64 + digits.len() as i64 - 1;
+        if self.exponent <= 0 && adjusted >= -6 {
+            if self.exponent == 0 {
+                return format!("{}{}", sign, digits);
+            }
+            let point = digits.len() as i64 + self.exponent as i64;
+            if point > 0 {
+                let (whole, frac) = digits.split_at(point as usize);
+                return format!("{}{}.{}", sign, whole, frac);
+            }
+            return format!("{}0.{}{}", sign, "0".repeat(-point as usize), digits);
+        }
+        let (first, rest) = digits.split_at(1);
+        let point = if rest.is_empty() { "" } else { "." };
+        let exp_sign = if adjusted < 0 { "-" } else { "+" };
+        format!("{}{}{}{}E{}{}", sign, first, point, rest, exp_sign, adjusted.abs())
+    }
+}
@@ -0,0 +1,109 @@
+//! Decoding of EXIF orientation values and rational numbers.
+
+
+/// EXIF orientation values 1-8, with the position of the stored image's first row and column and
+/// the transform needed to display the image upright.
+pub const ORIENTATIONS: [(&str, &str); 8] = [
+    ("top-left", "none"),
+    ("top-right", "mirror horizontally"),
+    ("bottom-right", "rotate 180°"),
+    ("bottom-left", "mirror vertically"),
+    ("left-top", "mirror horizontally, then rotate 270° clockwise"),
+    ("right-top", "rotate 90° clockwise"),
+    ("right-bottom", "mirror horizontally, then rotate 90° clockwise"),
+    ("left-bottom", "rotate 270° clockwise"),
+];
+
+
+/// Returns the `(position, transform)` description of an EXIF orientation value.
+pub fn orientation(value: u32) -> Option<(&'static str, &'static str)> {
+    match value {
+        1..=8 => Some(ORIENTATIONS[value as usize - 1]),
+        _ => None,
+    }
+}
+
+
+/// Returns true if displaying an image with the specified orientation swaps its width and height.
+pub fn orientation_swaps_axes(value: u32) -> bool {
+    (5..=8).contains(&value)
+}
+
+
+/// Returns the greatest common divisor of two integers.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+
+/// An EXIF RATIONAL or SRATIONAL value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rational {
+    pub num: i64,
+    pub den: u32,
+}
+
+
+impl Rational {
+    /// Returns the rational reduced to lowest terms. A zero denominator is left unchanged.
+    pub fn reduce(&self) -> Rational {
+        let divisor = gcd(self.num.unsigned_abs(), self.den as u64);
+        if divisor <= 1 {
+            return *self;
+        }
+        Rational { num: self.num / divisor as i64, den: (self.den as u64 / divisor) as u32 }
+    }
+
+    /// Returns the rational as a float. Returns `None` if the denominator is zero, which EXIF
+    /// writers commonly use to mean "unknown".
+    pub fn value(&self) -> Option<f64> {
+        if self.den == 0 {
+            None
+        } else {
+            Some(self.num as f64 / self.den as f64)
+        }
+    }
+}
+
+
+/// Parses a rational written as `num/den`, e.g. `1/250` or `-2/3`. A bare integer is read as
+/// having a denominator of 1. The numerator must fit in 32 bits, signed or unsigned.
+pub fn parse_rational(arg: &str) -> Option<Rational> {
+    let (num, den) = match arg.split_once('/') {
+        Some((num, den)) => (num.trim(), den.trim().parse::<u32>().ok()?),
+        None => (arg.trim(), 1),
+    };
+    let num = num.parse::<i64>().ok()?;
+    if num < i32::MIN as i64 || num > u32::MAX as i64 {
+        return None;
+    }
+    Some(Rational { num, den })
+}
+
+
+/// Formats an exposure time in seconds the way cameras display it, e.g. `1/250 s` or `2.5 s`.
+pub fn format_exposure(seconds: f64) -> String {
+    if seconds > 0.0 && seconds < 0.3 {
+        format!("1/{} s", (1.0 / seconds).round())
+    } else {
+        format!("{} s", trim_float(seconds))
+    }
+}
+
+
+/// Converts a GPS coordinate given as degrees, minutes, and seconds into decimal degrees.
+pub fn dms_to_degrees(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+    degrees + minutes / 60.0 + seconds / 3600.0
+}
+
+
+/// Formats a float with up to four decimal places, omitting trailing zeros.
+pub fn trim_float(value: f64) -> String {
+    let formatted = format!("{:.4}", value);
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
@@ -0,0 +1,142 @@
+//! Prime factorization of 64-bit integers, by trial division, Pollard's rho, and a deterministic
+//! Miller-Rabin primality test.
+
+
+// Miller-Rabin with these bases is exact for every integer below 2^64.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+
+/// Returns true if `n` is prime.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let (mut d, mut s) = (n - 1, 0);
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+    WITNESSES.iter().all(|a| {
+        let mut x = pow_mod(*a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+
+// Returns a non-trivial factor of `n`, which must be an odd composite, using Pollard's rho with
+// Floyd's cycle detection, retrying with a new polynomial constant when a cycle finds no factor.
+fn pollard_rho(n: u64) -> u64 {
+    let gcd = |mut a: u64, mut b: u64| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    for c in 1.. {
+        let f = |x: u64| ((mul_mod(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y, mut divisor) = (2, 2, 1);
+        while divisor == 1 {
+            x = f(x);
+            y = f(f(y));
+            divisor = gcd(x.abs_diff(y), n);
+        }
+        if divisor != n {
+            return divisor;
+        }
+    }
+    unreachable!()
+}
+
+
+/// Returns the prime factorization of `n` as pairs of primes and exponents, in increasing order
+/// of the primes. Returns an empty list for 0 and 1.
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    if n < 2 {
+        return Vec::new();
+    }
+    let mut primes = Vec::new();
+    let mut n = n;
+    for p in [2, 3, 5, 7, 11, 13] {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+    let mut pending = vec![n];
+    while let Some(m) = pending.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            primes.push(m);
+            continue;
+        }
+        let divisor = pollard_rho(m);
+        pending.push(divisor);
+        pending.push(m / divisor);
+    }
+    primes.sort_unstable();
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, exp)) if *last == p => *exp += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+
+/// Returns the number of divisors of the integer with the prime factorization `factors`.
+pub fn divisor_count(factors: &[(u64, u32)]) -> u64 {
+    factors.iter().map(|(_, exp)| *exp as u64 + 1).product()
+}
+
+
+/// Returns the sum of the divisors of the integer with the prime factorization `factors`, or
+/// `None` if it overflows 128 bits.
+pub fn divisor_sum(factors: &[(u64, u32)]) -> Option<u128> {
+    factors.iter().try_fold(1u128, |acc, (p, exp)| {
+        // 1 + p + p^2 + ... + p^exp.
+        let mut term: u128 = 1;
+        let mut sum: u128 = 1;
+        for _ in 0..*exp {
+            term = term.checked_mul(*p as u128)?;
+            sum = sum.checked_add(term)?;
+        }
+        acc.checked_mul(sum)
+    })
+}
@@ -0,0 +1,203 @@
+//! Decoding of IEEE 754 binary16 (`f16`), binary32 (`f32`), and binary64 (`f64`) floating-point
+//! values.
+
+use crate::bigint::BigUint;
+
+
+/// An IEEE 754 binary floating-point format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Width {
+    F16,
+    F32,
+    F64,
+}
+
+
+impl Width {
+    /// Returns the Rust name of the format, e.g. `f64`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Width::F16 => "f16",
+            Width::F32 => "f32",
+            Width::F64 => "f64",
+        }
+    }
+
+    /// Returns the width of the format in bits.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Width::F16 => 16,
+            Width::F32 => 32,
+            Width::F64 => 64,
+        }
+    }
+
+    /// Returns the width of the exponent field in bits.
+    pub fn exponent_bits(&self) -> u32 {
+        match self {
+            Width::F16 => 5,
+            Width::F32 => 8,
+            Width::F64 => 11,
+        }
+    }
+
+    /// Returns the width of the mantissa (trailing significand) field in bits.
+    pub fn mantissa_bits(&self) -> u32 {
+        self.bits() - self.exponent_bits() - 1
+    }
+
+    /// Returns the exponent bias.
+    pub fn bias(&self) -> i32 {
+        (1 << (self.exponent_bits() - 1)) - 1
+    }
+}
+
+
+/// The class of a binary floating-point value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Class {
+    Zero,
+    Subnormal,
+    Normal,
+    Infinite,
+    QuietNan,
+    SignalingNan,
+}
+
+
+/// The raw fields of a binary floating-point value.
+#[derive(Debug, PartialEq)]
+pub struct Fields {
+    pub sign: u32,
+    /// The biased exponent.
+    pub exponent: u32,
+    pub mantissa: u64,
+}
+
+
+/// Splits a binary floating-point value into its raw fields.
+pub fn fields(bits: u64, width: Width) -> Fields {
+    let m = width.mantissa_bits();
+    Fields {
+        sign: (bits >> (width.bits() - 1)) as u32 & 1,
+        exponent: (bits >> m) as u32 & ((1 << width.exponent_bits()) - 1),
+        mantissa: bits & ((1 << m) - 1),
+    }
+}
+
+
+impl Fields {
+    /// Returns the class of the value.
+    pub fn class(&self, width: Width) -> Class {
+        let max_exponent = (1 << width.exponent_bits()) - 1;
+        let quiet_bit = 1 << (width.mantissa_bits() - 1);
+        match (self.exponent, self.mantissa) {
+            (0, 0) => Class::Zero,
+            (0, _) => Class::Subnormal,
+            (e, 0) if e == max_exponent => Class::Infinite,
+            (e, m) if e == max_exponent && m & quiet_bit != 0 => Class::QuietNan,
+            (e, _) if e == max_exponent => Class::SignalingNan,
+            _ => Class::Normal,
+        }
+    }
+
+    /// Returns the unbiased exponent of a finite non-zero value. Subnormals have the minimum
+    /// normal exponent.
+    pub fn unbiased_exponent(&self, width: Width) -> i32 {
+        self.exponent.max(1) as i32 - width.bias()
+    }
+
+    /// Returns the exact decimal value of a finite value, with no rounding, e.g.
+    /// `0.1000000000000000055511151231257827021181583404541015625` for the `f64` nearest to 0.1.
+    /// Returns `None` for infinities and NaNs.
+    pub fn exact_decimal(&self, width: Width) -> Option<String> {
+        let sign = if self.sign == 1 { "-" } else { "" };
+        let significand = match self.class(width) {
+            Class::Zero => return Some(format!("{}0", sign)),
+            Class::Subnormal => self.mantissa,
+            Class::Normal => self.mantissa | 1 << width.mantissa_bits(),
+            _ => return None,
+        };
+        // The value is significand × 2^exponent.
+        let exponent = self.unbiased_exponent(width) - width.mantissa_bits() as i32;
+        let significand = BigUint::from_u128(significand as u128);
+        if exponent >= 0 {
+            return Some(format!("{}{}", sign, significand.shl(exponent as u32)));
+        }
+        // Multiplying by 5^k gives the digits of significand × 10^-k.
+        let k = -exponent as usize;
+        let digits = (0..k).fold(significand, |acc, _| acc.mul_small(5)).to_string();
+        let digits = format!("{:0>width$}", digits, width = k + 1);
+        let (whole, frac) = digits.split_at(digits.len() - k);
+        let frac = frac.trim_end_matches('0');
+        if frac.is_empty() {
+            Some(format!("{}{}", sign, whole))
+        } else {
+            Some(format!("{}{}.{}", sign, whole, frac))
+        }
+    }
+}
+
+
+/// Rounds a value to the nearest `f16`, ties to even, and returns its bits.
+pub fn f16_bits(value: f64) -> u16 {
+    let sign = if value.is_sign_negative() { 0x8000 } else { 0 };
+    let abs = value.abs();
+    if value.is_nan() {
+        return sign | 0x7E00;
+    }
+    // Values from halfway between the largest finite f16, 65504, and 2^16 round to infinity.
+    if abs >= 65520.0 {
+        return sign | 0x7C00;
+    }
+    if abs < 2f64.powi(-14) {
+        return sign | (abs * 2f64.powi(24)).round_ties_even() as u16;
+    }
+    let exponent = (abs.to_bits() >> 52) as i32 - 1023;
+    let significand = (abs * 2f64.powi(10 - exponent)).round_ties_even() as u16;
+    // A significand that rounds up to 2^11 carries into the exponent field.
+    sign | ((((exponent + 15) as u16) << 10) + significand - 1024)
+}
+
+
+/// Returns the value of the `f16` with the specified bits.
+pub fn f16_value(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = (bits >> 10 & 0x1F) as i32;
+    let mantissa = (bits & 0x3FF) as f64;
+    sign * match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        0x1F if mantissa == 0.0 => f64::INFINITY,
+        0x1F => f64::NAN,
+        _ => (mantissa + 1024.0) * 2f64.powi(exponent - 25),
+    }
+}
+
+
+/// Returns the shortest decimal that rounds to the `f16` with the specified bits, e.g. `0.1` for
+/// `0x2E66`.
+pub fn f16_shortest(bits: u16) -> f64 {
+    let value = f16_value(bits);
+    (0..17)
+        .filter_map(|precision| format!("{:.*e}", precision, value).parse::<f64>().ok())
+        .find(|&candidate| f16_bits(candidate) == bits)
+        .unwrap_or(value)
+}
+
+
+/// Parses a decimal float, e.g. `3.14`, `-1e-40`, `inf`, or `nan`.
+pub fn parse_float(arg: &str) -> Option<f64> {
+    let arg = arg.trim().to_lowercase();
+    let (sign, rest) = match arg.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, arg.strip_prefix('+').unwrap_or(&arg)),
+    };
+    match rest {
+        "inf" | "infinity" => Some(sign * f64::INFINITY),
+        "nan" => Some(f64::NAN.copysign(sign)),
+        _ if rest.starts_with(|c: char| c.is_ascii_digit() || c == '.') => {
+            rest.replace('_', "").parse::<f64>().ok().map(|value| sign * value)
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,406 @@
+//! Decoding of geospatial indices: geohash strings and Uber H3 64-bit cell indices, including the
+//! centers of H3 cells, with the icosahedral projection of the reference H3 library.
+
+
+use std::f64::consts::{PI, TAU};
+
+
+const GEOHASH_ALPHABET: &str = "0123456789bcdefghjkmnpqrstuvwxyz";
+
+
+/// Bounding box of a decoded geohash cell, in degrees.
+#[derive(Debug, PartialEq)]
+pub struct Bounds {
+    pub lat_min: f64,
+    pub lat_max: f64,
+    pub lon_min: f64,
+    pub lon_max: f64,
+}
+
+
+/// Returns the integer value of a geohash string, i.e. its 5-bit base 32 digits concatenated,
+/// along with the number of bits. Returns `None` if the string is empty, longer than 12
+/// characters, or contains a character outside the geohash alphabet.
+pub fn geohash_bits(hash: &str) -> Option<(u64, u32)> {
+    if hash.is_empty() || hash.len() > 12 {
+        return None;
+    }
+    let mut value: u64 = 0;
+    for c in hash.to_lowercase().chars() {
+        value = value << 5 | GEOHASH_ALPHABET.find(c)? as u64;
+    }
+    Some((value, hash.len() as u32 * 5))
+}
+
+
+/// Decodes a geohash string into the bounding box of its cell. Bits alternate between longitude
+/// and latitude, starting with longitude.
+pub fn geohash_decode(hash: &str) -> Option<Bounds> {
+    let (value, num_bits) = geohash_bits(hash)?;
+    let mut lat = (-90.0, 90.0);
+    let mut lon = (-180.0, 180.0);
+    for i in 0..num_bits {
+        let bit = (value >> (num_bits - 1 - i)) & 1 == 1;
+        let range: &mut (f64, f64) = if i % 2 == 0 { &mut lon } else { &mut lat };
+        let mid = (range.0 + range.1) / 2.0;
+        if bit {
+            range.0 = mid;
+        } else {
+            range.1 = mid;
+        }
+    }
+    Some(Bounds { lat_min: lat.0, lat_max: lat.1, lon_min: lon.0, lon_max: lon.1 })
+}
+
+
+/// The fields of an H3 index.
+#[derive(Debug, PartialEq)]
+pub struct H3Index {
+    pub mode: u32,
+    pub mode_bits: u32,
+    pub resolution: u32,
+    pub base_cell: u32,
+    /// The index digits for resolutions 1 up to the index's resolution.
+    pub digits: Vec<u32>,
+}
+
+
+/// Base cells whose cells are pentagons.
+pub const H3_PENTAGONS: &[u32] = &[4, 14, 24, 38, 49, 58, 63, 72, 83, 97, 107, 117];
+
+
+/// Splits a 64-bit H3 index into its fields. Returns `None` if the index is malformed: the
+/// reserved high bit is set, the mode is unassigned, the base cell is out of range, or the
+/// digits beyond the index's resolution are not all set to 7.
+pub fn h3_decode(index: u64) -> Option<H3Index> {
+    let mode = (index >> 59 & 0xF) as u32;
+    let resolution = (index >> 52 & 0xF) as u32;
+    let base_cell = (index >> 45 & 0x7F) as u32;
+    if index >> 63 != 0 || !(1..=4).contains(&mode) || base_cell > 121 {
+        return None;
+    }
+    let digits: Vec<u32> = (1..=15).map(|r| (index >> ((15 - r) * 3) & 7) as u32).collect();
+    if digits[resolution as usize..].iter().any(|digit| *digit != 7) {
+        return None;
+    }
+    Some(H3Index {
+        mode,
+        mode_bits: (index >> 56 & 7) as u32,
+        resolution,
+        base_cell,
+        digits: digits[..resolution as usize].to_vec(),
+    })
+}
+
+
+// The H3 grid is a hexagonal grid on each face of an icosahedron, in coordinates along three axes
+// 120° apart, i, j, and k, which are normalized so that none is negative and at least one is 0.
+type Ijk = [i64; 3];
+
+
+// The latitude and longitude of the center of each icosahedron face, in radians.
+const H3_FACE_CENTERS: [(f64, f64); 20] = [
+    (0.80358264971899, 1.2483974196173961),
+    (1.3077478834556382, 2.5369450098779214),
+    (1.054751253523952, -1.3475173589003966),
+    (0.6001915955381868, -0.45060390946975576),
+    (0.49171542819877384, 0.40198820291130694),
+    (0.1727453274156187, 1.6781468852804338),
+    (0.6059293215713507, 2.9539233298124117),
+    (0.42737051832897965, -1.8888762003362853),
+    (-0.07906611854921283, -0.7334295133808677),
+    (-0.23096164445538364, 0.506495587332349),
+    (0.07906611854921283, 2.4081631402089254),
+    (0.23096164445538364, -2.635097066257444),
+    (-0.1727453274156187, -1.4634457683093596),
+    (-0.6059293215713507, -0.18766932377738163),
+    (-0.42737051832897965, 1.2527164532535078),
+    (-0.6001915955381868, 2.6909887441200375),
+    (-0.49171542819877384, -2.7396044506784865),
+    (-0.80358264971899, -1.8931952339723972),
+    (-1.3077478834556382, -0.6046476437118721),
+    (-1.054751253523952, 1.7940752946893965),
+];
+
+
+// The azimuth of each face's i axis from its center, in radians.
+const H3_FACE_AXES: [f64; 20] = [
+    5.6199582685239395,
+    5.7603390817141875,
+    0.78021365439343,
+    0.4304693639799999,
+    6.130269123335111,
+    2.692877706530643,
+    2.982963003477244,
+    3.532912002790141,
+    3.494305004259568,
+    3.0032141694995382,
+    5.930472956509812,
+    0.13837848409025486,
+    0.4487149470591504,
+    0.15862965011254937,
+    5.891865957979238,
+    2.711123289609793,
+    3.294508837434268,
+    3.80481969224544,
+    3.6644388790551923,
+    2.361378999196363,
+];
+
+
+// The faces across each face's edges opposite its k, j, and i axes, with the translation of the
+// neighbor's center and the number of 60° counterclockwise rotations that convert coordinates on
+// the face into coordinates on the neighbor, at resolution 0.
+const H3_FACE_NEIGHBORS: [[(usize, Ijk, u32); 3]; 20] = [
+    [(4, [2, 0, 2], 1), (1, [2, 2, 0], 5), (5, [0, 2, 2], 3)],
+    [(0, [2, 0, 2], 1), (2, [2, 2, 0], 5), (6, [0, 2, 2], 3)],
+    [(1, [2, 0, 2], 1), (3, [2, 2, 0], 5), (7, [0, 2, 2], 3)],
+    [(2, [2, 0, 2], 1), (4, [2, 2, 0], 5), (8, [0, 2, 2], 3)],
+    [(3, [2, 0, 2], 1), (0, [2, 2, 0], 5), (9, [0, 2, 2], 3)],
+    [(10, [2, 2, 0], 3), (14, [2, 0, 2], 3), (0, [0, 2, 2], 3)],
+    [(11, [2, 2, 0], 3), (10, [2, 0, 2], 3), (1, [0, 2, 2], 3)],
+    [(12, [2, 2, 0], 3), (11, [2, 0, 2], 3), (2, [0, 2, 2], 3)],
+    [(13, [2, 2, 0], 3), (12, [2, 0, 2], 3), (3, [0, 2, 2], 3)],
+    [(14, [2, 2, 0], 3), (13, [2, 0, 2], 3), (4, [0, 2, 2], 3)],
+    [(5, [2, 2, 0], 3), (6, [2, 0, 2], 3), (15, [0, 2, 2], 3)],
+    [(6, [2, 2, 0], 3), (7, [2, 0, 2], 3), (16, [0, 2, 2], 3)],
+    [(7, [2, 2, 0], 3), (8, [2, 0, 2], 3), (17, [0, 2, 2], 3)],
+    [(8, [2, 2, 0], 3), (9, [2, 0, 2], 3), (18, [0, 2, 2], 3)],
+    [(9, [2, 2, 0], 3), (5, [2, 0, 2], 3), (19, [0, 2, 2], 3)],
+    [(16, [2, 0, 2], 1), (19, [2, 2, 0], 5), (10, [0, 2, 2], 3)],
+    [(17, [2, 0, 2], 1), (15, [2, 2, 0], 5), (11, [0, 2, 2], 3)],
+    [(18, [2, 0, 2], 1), (16, [2, 2, 0], 5), (12, [0, 2, 2], 3)],
+    [(19, [2, 0, 2], 1), (17, [2, 2, 0], 5), (13, [0, 2, 2], 3)],
+    [(15, [2, 0, 2], 1), (18, [2, 2, 0], 5), (14, [0, 2, 2], 3)],
+];
+
+
+// The home face of each base cell and its coordinates on that face, five to a row.
+const H3_BASE_CELLS: [(usize, Ijk); 122] = [
+    (1, [1, 0, 0]), (2, [1, 1, 0]), (1, [0, 0, 0]), (2, [1, 0, 0]), (0, [2, 0, 0]),
+    (1, [1, 1, 0]), (1, [0, 0, 1]), (2, [0, 0, 0]), (0, [1, 0, 0]), (2, [0, 1, 0]),
+    (1, [0, 1, 0]), (1, [0, 1, 1]), (3, [1, 0, 0]), (3, [1, 1, 0]), (11, [2, 0, 0]),
+    (4, [1, 0, 0]), (0, [0, 0, 0]), (6, [0, 1, 0]), (0, [0, 0, 1]), (2, [0, 1, 1]),
+    (7, [0, 0, 1]), (2, [0, 0, 1]), (0, [1, 1, 0]), (6, [0, 0, 1]), (10, [2, 0, 0]),
+    (6, [0, 0, 0]), (3, [0, 0, 0]), (11, [1, 0, 0]), (4, [1, 1, 0]), (3, [0, 1, 0]),
+    (0, [0, 1, 1]), (4, [0, 0, 0]), (5, [0, 1, 0]), (0, [0, 1, 0]), (7, [0, 1, 0]),
+    (11, [1, 1, 0]), (7, [0, 0, 0]), (10, [1, 0, 0]), (12, [2, 0, 0]), (6, [1, 0, 1]),
+    (7, [1, 0, 1]), (4, [0, 0, 1]), (3, [0, 0, 1]), (3, [0, 1, 1]), (4, [0, 1, 0]),
+    (6, [1, 0, 0]), (11, [0, 0, 0]), (8, [0, 0, 1]), (5, [0, 0, 1]), (14, [2, 0, 0]),
+    (5, [0, 0, 0]), (12, [1, 0, 0]), (10, [1, 1, 0]), (4, [0, 1, 1]), (12, [1, 1, 0]),
+    (7, [1, 0, 0]), (11, [0, 1, 0]), (10, [0, 0, 0]), (13, [2, 0, 0]), (10, [0, 0, 1]),
+    (11, [0, 0, 1]), (9, [0, 1, 0]), (8, [0, 1, 0]), (6, [2, 0, 0]), (8, [0, 0, 0]),
+    (9, [0, 0, 1]), (14, [1, 0, 0]), (5, [1, 0, 1]), (16, [0, 1, 1]), (8, [1, 0, 1]),
+    (5, [1, 0, 0]), (12, [0, 0, 0]), (7, [2, 0, 0]), (12, [0, 1, 0]), (10, [0, 1, 0]),
+    (9, [0, 0, 0]), (13, [1, 0, 0]), (16, [0, 0, 1]), (15, [0, 1, 1]), (15, [0, 1, 0]),
+    (16, [0, 1, 0]), (14, [1, 1, 0]), (13, [1, 1, 0]), (5, [2, 0, 0]), (8, [1, 0, 0]),
+    (14, [0, 0, 0]), (9, [1, 0, 1]), (14, [0, 0, 1]), (17, [0, 0, 1]), (12, [0, 0, 1]),
+    (16, [0, 0, 0]), (17, [0, 1, 1]), (15, [0, 0, 1]), (16, [1, 0, 1]), (9, [1, 0, 0]),
+    (15, [0, 0, 0]), (13, [0, 0, 0]), (8, [2, 0, 0]), (13, [0, 1, 0]), (17, [1, 0, 1]),
+    (19, [0, 1, 0]), (14, [0, 1, 0]), (19, [0, 1, 1]), (17, [0, 1, 0]), (13, [0, 0, 1]),
+    (17, [0, 0, 0]), (16, [1, 0, 0]), (9, [2, 0, 0]), (15, [1, 0, 1]), (15, [1, 0, 0]),
+    (18, [0, 1, 1]), (18, [0, 0, 1]), (19, [0, 0, 1]), (17, [1, 0, 0]), (19, [0, 0, 0]),
+    (18, [0, 1, 0]), (18, [1, 0, 1]), (19, [2, 0, 0]), (19, [1, 0, 0]), (18, [0, 0, 0]),
+    (19, [1, 0, 1]), (18, [1, 0, 0]),
+];
+
+
+// The gnomonic distance of a resolution 0 unit vector, and the rotation of the Class III
+// resolutions from the Class II resolutions, in radians.
+const H3_RES0_SCALE: f64 = 0.381966011250105;
+const H3_CLASS_III_ROTATION: f64 = 0.3334731722518321;
+
+
+// The unit vectors of the index digits 0 to 6: none, k, j, jk, i, ik, and ij.
+const H3_DIGIT_VECTORS: [Ijk; 7] =
+    [[0, 0, 0], [0, 0, 1], [0, 1, 0], [0, 1, 1], [1, 0, 0], [1, 0, 1], [1, 1, 0]];
+
+
+/// Returns the latitude and longitude in degrees of the center of an H3 cell, as the reference
+/// library's `cellToLatLng` computes it. Returns `None` if the index isn't a valid cell index.
+pub fn h3_center(index: u64) -> Option<(f64, f64)> {
+    let h3 = h3_decode(index)?;
+    let pentagon = H3_PENTAGONS.contains(&h3.base_cell);
+    let mut digits = h3.digits;
+    let leading = digits.iter().copied().find(|digit| *digit != 0).unwrap_or(0);
+    // Pentagons have no cells in the k direction.
+    if h3.mode != 1 || (pentagon && leading == 1) {
+        return None;
+    }
+    // Cells in the ik direction of a pentagon are rotated onto the i direction.
+    if pentagon && leading == 5 {
+        digits = digits.iter().map(|digit| rotate_digit_cw(*digit)).collect();
+    }
+    let (mut face, mut ijk) = H3_BASE_CELLS[h3.base_cell as usize];
+    // Only cells of pentagons and of hexagons off the centers of their faces can stick out over
+    // the edge of the home face, onto a neighboring face with its own coordinates.
+    let overage = pentagon || (h3.resolution > 0 && ijk != [0, 0, 0]);
+    for (i, digit) in digits.iter().enumerate() {
+        ijk = if i % 2 == 0 { down_ap7(ijk) } else { down_ap7r(ijk) };
+        ijk = ijk_add(ijk, H3_DIGIT_VECTORS[*digit as usize]);
+    }
+    // Overages are found at the next Class II resolution.
+    if overage {
+        let mut res = h3.resolution;
+        if res % 2 == 1 {
+            ijk = down_ap7r(ijk);
+            res += 1;
+        }
+        let moved = adjust_overage(&mut face, &mut ijk, res, pentagon && leading == 4);
+        if moved && pentagon {
+            while adjust_overage(&mut face, &mut ijk, res, false) {}
+        }
+        if res != h3.resolution {
+            ijk = up_ap7r(ijk);
+        }
+    }
+    let (lat, lon) = hex2d_to_geo(ijk, face, h3.resolution);
+    Some((lat.to_degrees(), lon.to_degrees()))
+}
+
+
+// Moves coordinates past the edge of a face onto the neighboring face. Returns true if they
+// were moved. Coordinates in the ik direction of a pentagon are rotated, as that direction
+// is deleted.
+fn adjust_overage(face: &mut usize, ijk: &mut Ijk, res: u32, pentagon_leading_4: bool) -> bool {
+    let max_dim = 2 * 7i64.pow(res / 2);
+    if ijk.iter().sum::<i64>() <= max_dim {
+        return false;
+    }
+    let quadrant = match (ijk[2] > 0, ijk[1] > 0) {
+        (false, _) => 0,
+        (true, false) => 1,
+        (true, true) => 2,
+    };
+    if quadrant == 1 && pentagon_leading_4 {
+        let shifted = rotate60_cw([ijk[0] - max_dim, ijk[1], ijk[2]]);
+        *ijk = ijk_add(shifted, [max_dim, 0, 0]);
+    }
+    let (neighbor, translate, rotations) = H3_FACE_NEIGHBORS[*face][quadrant];
+    *face = neighbor;
+    for _ in 0..rotations {
+        *ijk = rotate60_ccw(*ijk);
+    }
+    let scale = 7i64.pow(res / 2);
+    *ijk = ijk_add(*ijk, translate.map(|coord| coord * scale));
+    true
+}
+
+
+// Projects coordinates on a face onto the sphere with the face's gnomonic projection.
+fn hex2d_to_geo(ijk: Ijk, face: usize, res: u32) -> (f64, f64) {
+    let (i, j) = ((ijk[0] - ijk[2]) as f64, (ijk[1] - ijk[2]) as f64);
+    let (x, y) = (i - 0.5 * j, j * 3f64.sqrt() / 2.0);
+    let center = H3_FACE_CENTERS[face];
+    let r = x.hypot(y);
+    if r < 1e-16 {
+        return center;
+    }
+    let mut theta = y.atan2(x);
+    // Each resolution is an aperture 7 refinement of the one before.
+    let distance = (r / 7f64.sqrt().powi(res as i32) * H3_RES0_SCALE).atan();
+    if res % 2 == 1 {
+        theta += H3_CLASS_III_ROTATION;
+    }
+    let azimuth = (H3_FACE_AXES[face] - theta).rem_euclid(TAU);
+    let (lat, lon) = center;
+    let sin_lat = lat.sin() * distance.cos() + lat.cos() * distance.sin() * azimuth.cos();
+    let lat2 = sin_lat.clamp(-1.0, 1.0).asin();
+    let sin_lon = azimuth.sin() * distance.sin() / lat2.cos();
+    let cos_lon = (distance.cos() - lat.sin() * lat2.sin()) / lat.cos() / lat2.cos();
+    let lon2 = lon + sin_lon.clamp(-1.0, 1.0).atan2(cos_lon.clamp(-1.0, 1.0));
+    (lat2, (lon2 + PI).rem_euclid(TAU) - PI)
+}
+
+
+fn ijk_normalize(mut ijk: Ijk) -> Ijk {
+    for axis in 0..3 {
+        if ijk[axis] < 0 {
+            let offset = ijk[axis];
+            ijk = ijk.map(|coord| coord - offset);
+        }
+    }
+    let min = ijk.iter().copied().min().unwrap();
+    ijk.map(|coord| coord - min)
+}
+
+
+fn ijk_add(a: Ijk, b: Ijk) -> Ijk {
+    ijk_normalize([a[0] + b[0], a[1] + b[1], a[2] + b[2]])
+}
+
+
+// Returns the coordinates in the basis where the unit i, j, and k vectors are `basis`.
+fn ijk_transform(ijk: Ijk, basis: [Ijk; 3]) -> Ijk {
+    let mut result = [0; 3];
+    for (coord, vector) in ijk.iter().zip(basis) {
+        for axis in 0..3 {
+            result[axis] += coord * vector[axis];
+        }
+    }
+    ijk_normalize(result)
+}
+
+
+// The coordinates of the center child at the next resolution, which is Class III, and with
+// down_ap7r(), Class II.
+fn down_ap7(ijk: Ijk) -> Ijk {
+    ijk_transform(ijk, [[3, 0, 1], [1, 3, 0], [0, 1, 3]])
+}
+
+
+fn down_ap7r(ijk: Ijk) -> Ijk {
+    ijk_transform(ijk, [[3, 1, 0], [0, 3, 1], [1, 0, 3]])
+}
+
+
+// The coordinates of the parent of a Class II cell at the Class III resolution above it.
+fn up_ap7r(ijk: Ijk) -> Ijk {
+    let (i, j) = ((ijk[0] - ijk[2]) as f64, (ijk[1] - ijk[2]) as f64);
+    ijk_normalize([((2.0 * i + j) / 7.0).round() as i64, ((3.0 * j - i) / 7.0).round() as i64, 0])
+}
+
+
+fn rotate60_ccw(ijk: Ijk) -> Ijk {
+    ijk_transform(ijk, [[1, 1, 0], [0, 1, 1], [1, 0, 1]])
+}
+
+
+fn rotate60_cw(ijk: Ijk) -> Ijk {
+    ijk_transform(ijk, [[1, 0, 1], [1, 1, 0], [0, 1, 1]])
+}
+
+
+fn rotate_digit_cw(digit: u32) -> u32 {
+    match digit {
+        1 => 3,
+        3 => 2,
+        2 => 6,
+        6 => 4,
+        4 => 5,
+        5 => 1,
+        _ => digit,
+    }
+}
+
+
+/// Average hexagon edge length in kilometres and area in square kilometres for each H3
+/// resolution.
+pub const H3_CELL_SIZES: [(f64, f64); 16] = [
+    (1281.256, 4357449.416),
+    (483.057, 609788.442),
+    (182.513, 86801.780),
+    (68.979, 12393.435),
+    (26.072, 1770.348),
+    (9.854, 252.904),
+    (3.725, 36.129),
+    (1.406, 5.161),
+    (0.531, 0.737),
+    (0.201, 0.105),
+    (0.076, 0.015),
+    (0.029, 0.002),
+    (0.011, 0.0003),
+    (0.004, 0.00004),
+    (0.0015, 0.000006),
+    (0.0006, 0.0000009),
+];
@@ -0,0 +1,84 @@
+//! Non-cryptographic hash functions commonly used in hash tables.
+
+use crate::checksum;
+
+
+/// The names of the algorithms supported by `hash()`, each with its supported widths in bits.
+pub const ALGORITHMS: &[(&str, &[u32])] = &[
+    ("fnv1a", &[32, 64]),
+    ("djb2", &[32, 64]),
+    ("murmur3", &[32]),
+    ("crc32", &[32]),
+];
+
+
+/// Returns the 32-bit FNV-1a hash of the bytes.
+pub fn fnv1a_32(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811C9DC5, |hash, byte| (hash ^ *byte as u32).wrapping_mul(0x01000193))
+}
+
+
+/// Returns the 64-bit FNV-1a hash of the bytes.
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF29CE484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x00000100000001B3)
+    })
+}
+
+
+/// Returns Daniel J. Bernstein's djb2 hash of the bytes, `hash * 33 + byte`, modulo 2^32.
+pub fn djb2_32(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(5381, |hash: u32, byte| hash.wrapping_mul(33).wrapping_add(*byte as u32))
+}
+
+
+/// Returns the djb2 hash of the bytes modulo 2^64.
+pub fn djb2_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(5381, |hash: u64, byte| hash.wrapping_mul(33).wrapping_add(*byte as u64))
+}
+
+
+/// Returns the 32-bit MurmurHash3 hash of the bytes, i.e. MurmurHash3_x86_32.
+pub fn murmur3_32(bytes: &[u8], seed: u32) -> u32 {
+    let mix = |k: u32| k.wrapping_mul(0xCC9E2D51).rotate_left(15).wrapping_mul(0x1B873593);
+    let mut hash = seed;
+    let mut chunks = bytes.chunks_exact(4);
+    for chunk in &mut chunks {
+        let k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        hash ^= mix(k);
+        hash = hash.rotate_left(13).wrapping_mul(5).wrapping_add(0xE6546B64);
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let k = tail.iter().rev().fold(0, |k, byte| k << 8 | *byte as u32);
+        hash ^= mix(k);
+    }
+    hash ^= bytes.len() as u32;
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85EBCA6B);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xC2B2AE35);
+    hash ^ hash >> 16
+}
+
+
+/// Returns the hash of the bytes computed by the named algorithm at the specified width. The
+/// seed is used by murmur3 only. Returns an error if the algorithm is unknown or doesn't support
+/// the width.
+pub fn hash(algo: &str, bytes: &[u8], width: u32, seed: u32) -> Result<u64, String> {
+    let (name, widths) = ALGORITHMS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(algo))
+        .ok_or_else(|| format!("unknown algorithm '{}'", algo))?;
+    if !widths.contains(&width) {
+        return Err(format!("{} does not have a {}-bit variant", name, width));
+    }
+    Ok(match (*name, width) {
+        ("fnv1a", 32) => fnv1a_32(bytes) as u64,
+        ("fnv1a", _) => fnv1a_64(bytes),
+        ("djb2", 32) => djb2_32(bytes) as u64,
+        ("djb2", _) => djb2_64(bytes),
+        ("murmur3", _) => murmur3_32(bytes, seed) as u64,
+        _ => checksum::compute_named("crc32", bytes).unwrap().2,
+    })
+}
@@ -0,0 +1,60 @@
+//! Construction of Huffman codes from symbol frequencies, in canonical form.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+
+/// Returns the Huffman code length for each symbol, given the symbols' frequencies. A lone
+/// symbol gets a 1-bit code. Ties are broken by symbol order, so the result is deterministic.
+pub fn code_lengths(frequencies: &[u64]) -> Vec<u32> {
+    let mut lengths = vec![0; frequencies.len()];
+    if frequencies.len() == 1 {
+        lengths[0] = 1;
+        return lengths;
+    }
+    // Each node is a list of the symbols beneath it; merging two nodes adds a bit to each.
+    let mut nodes: Vec<Vec<usize>> = (0..frequencies.len()).map(|i| vec![i]).collect();
+    let mut heap: BinaryHeap<Reverse<(u128, usize)>> = frequencies
+        .iter()
+        .enumerate()
+        .map(|(i, frequency)| Reverse((*frequency as u128, i)))
+        .collect();
+    while heap.len() > 1 {
+        let Reverse((weight_a, a)) = heap.pop().unwrap();
+        let Reverse((weight_b, b)) = heap.pop().unwrap();
+        let mut symbols = std::mem::take(&mut nodes[a]);
+        symbols.append(&mut nodes[b]);
+        for symbol in &symbols {
+            lengths[*symbol] += 1;
+        }
+        nodes.push(symbols);
+        heap.push(Reverse((weight_a + weight_b, nodes.len() - 1)));
+    }
+    lengths
+}
+
+
+/// Returns the canonical Huffman codewords for a set of code lengths. Symbols are ordered by
+/// code length, then by position, and each is assigned the next codeword in sequence, shifted
+/// left whenever the length increases. Returns `None` if a length exceeds 128 bits.
+pub fn canonical_codes(lengths: &[u32]) -> Option<Vec<u128>> {
+    if lengths.iter().any(|length| *length > 128) {
+        return None;
+    }
+    let mut order: Vec<usize> = (0..lengths.len()).collect();
+    order.sort_by_key(|i| (lengths[*i], *i));
+    let mut codes = vec![0; lengths.len()];
+    let mut code: u128 = 0;
+    let mut prev_length = match order.first() {
+        Some(first) => lengths[*first],
+        None => return Some(codes),
+    };
+    for (n, i) in order.iter().enumerate() {
+        if n > 0 {
+            code = (code + 1) << (lengths[*i] - prev_length);
+        }
+        codes[*i] = code;
+        prev_length = lengths[*i];
+    }
+    Some(codes)
+}
@@ -0,0 +1,159 @@
+//! Decoding of opaque, timestamp-bearing identifiers.
+
+
+/// The bit layout of a snowflake ID: a timestamp in milliseconds since a custom epoch in the
+/// high-order bits, followed by a worker field and a sequence field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnowflakeLayout {
+    pub epoch_ms: i64,
+    pub worker_bits: u32,
+    pub sequence_bits: u32,
+}
+
+
+/// Twitter's layout: an unused sign bit, a 41-bit timestamp, a 10-bit worker field (5-bit
+/// datacenter and 5-bit worker), and a 12-bit sequence number.
+pub const TWITTER: SnowflakeLayout = SnowflakeLayout {
+    epoch_ms: 1288834974657,
+    worker_bits: 10,
+    sequence_bits: 12,
+};
+
+
+/// Discord's layout: a 42-bit timestamp, a 10-bit worker field (5-bit worker and 5-bit process),
+/// and a 12-bit increment.
+pub const DISCORD: SnowflakeLayout = SnowflakeLayout {
+    epoch_ms: 1420070400000,
+    worker_bits: 10,
+    sequence_bits: 12,
+};
+
+
+/// The fields of a decoded snowflake ID.
+#[derive(Debug, PartialEq)]
+pub struct Snowflake {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp_ms: i64,
+    pub worker: u64,
+    pub sequence: u64,
+}
+
+
+/// Splits a snowflake ID into its fields according to the specified layout. The timestamp field
+/// takes all the bits above the worker field. Will panic if the worker and sequence fields
+/// together exceed 63 bits.
+pub fn snowflake_decode(id: u64, layout: SnowflakeLayout) -> Snowflake {
+    assert!(layout.worker_bits + layout.sequence_bits < 64);
+    let sequence = id & ((1 << layout.sequence_bits) - 1);
+    let worker = (id >> layout.sequence_bits) & ((1 << layout.worker_bits) - 1);
+    let offset = id >> (layout.sequence_bits + layout.worker_bits);
+    Snowflake {
+        timestamp_ms: layout.epoch_ms + offset as i64,
+        worker,
+        sequence,
+    }
+}
+
+
+/// Decodes a 26-character ULID string (Crockford base 32) into its 128-bit value. Decoding is
+/// case-insensitive and maps the ambiguous letters I and L to 1 and O to 0. Returns `None` if
+/// the string is malformed or encodes a value wider than 128 bits.
+pub fn ulid_decode(ulid: &str) -> Option<u128> {
+    if ulid.len() != 26 {
+        return None;
+    }
+    let mut value: u128 = 0;
+    for c in ulid.to_uppercase().chars() {
+        let digit = match c {
+            'I' | 'L' => 1,
+            'O' => 0,
+            _ => "0123456789ABCDEFGHJKMNPQRSTVWXYZ".find(c)? as u128,
+        };
+        value = value.checked_mul(32)? | digit;
+    }
+    Some(value)
+}
+
+
+/// Splits a 128-bit ULID value into its 48-bit timestamp in milliseconds since the Unix epoch
+/// and its 80-bit random component.
+pub fn ulid_fields(value: u128) -> (i64, u128) {
+    ((value >> 80) as i64, value & ((1 << 80) - 1))
+}
+
+
+/// The KSUID epoch, in seconds since the Unix epoch.
+pub const KSUID_EPOCH: i64 = 1400000000;
+
+
+/// Decodes a 27-character KSUID string (base 62) into its 20 bytes. Returns `None` if the string
+/// is malformed or encodes a value wider than 160 bits.
+pub fn ksuid_decode(ksuid: &str) -> Option<[u8; 20]> {
+    if ksuid.len() != 27 {
+        return None;
+    }
+    let alphabet = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let mut bytes = [0u8; 20];
+    for c in ksuid.chars() {
+        let mut carry = alphabet.find(c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            let product = *byte as u32 * 62 + carry;
+            *byte = product as u8;
+            carry = product >> 8;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+    Some(bytes)
+}
+
+
+/// Splits the bytes of a KSUID into its timestamp in seconds since the Unix epoch and its 16-byte
+/// random payload.
+pub fn ksuid_fields(bytes: &[u8; 20]) -> (i64, [u8; 16]) {
+    let offset = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let mut payload = [0u8; 16];
+    payload.copy_from_slice(&bytes[4..]);
+    (KSUID_EPOCH + offset as i64, payload)
+}
+
+
+/// The fields of a MongoDB ObjectId.
+#[derive(Debug, PartialEq)]
+pub struct ObjectId {
+    /// Seconds since the Unix epoch.
+    pub timestamp: i64,
+    /// The 5-byte random value, which older drivers split into a 3-byte machine identifier and a
+    /// 2-byte process ID.
+    pub random: u64,
+    pub counter: u32,
+}
+
+
+impl ObjectId {
+    /// Returns the legacy 3-byte machine identifier.
+    pub fn machine(&self) -> u32 {
+        (self.random >> 16) as u32
+    }
+
+    /// Returns the legacy 2-byte process ID.
+    pub fn pid(&self) -> u16 {
+        self.random as u16
+    }
+}
+
+
+/// Decodes a 24-character hex ObjectId string into its fields. Returns `None` if the string is
+/// not exactly 24 hex digits.
+pub fn objectid_decode(oid: &str) -> Option<ObjectId> {
+    if oid.len() != 24 || !oid.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = u128::from_str_radix(oid, 16).ok()?;
+    Some(ObjectId {
+        timestamp: (value >> 64) as u32 as i64,
+        random: (value >> 24) as u64 & 0xFF_FFFF_FFFF,
+        counter: value as u32 & 0xFF_FFFF,
+    })
+}
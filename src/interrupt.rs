@@ -0,0 +1,64 @@
+// Ctrl-C handling for long-running commands. While an interrupt guard is held, the first Ctrl-C
+// sets a flag instead of killing the process, so the command can stop reading its input and print
+// the results it has so far. A second Ctrl-C exits immediately. Without a guard, Ctrl-C kills the
+// process as usual, so commands that never check the flag aren't affected. Only the binary
+// catches Ctrl-C: run() gives commands a flag of their own that's never set.
+//
+// Signal handling needs libc, so on other platforms Ctrl-C always kills the process.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+
+// The exit status for a process stopped by SIGINT, as reported by shells.
+pub const EXIT_STATUS: i32 = 130;
+
+
+// The flag set by a caught Ctrl-C.
+pub fn flag() -> &'static AtomicBool {
+    &INTERRUPTED
+}
+
+
+// Catches Ctrl-C until the guard is dropped.
+pub struct Guard {
+    _private: (),
+}
+
+
+pub fn catch() -> Guard {
+    INTERRUPTED.store(false, Ordering::SeqCst);
+    // The handler is installed without SA_RESTART, so a read blocked waiting for input fails with
+    // EINTR rather than waiting on after the Ctrl-C.
+    #[cfg(unix)]
+    // Safety: the handler only touches an atomic and calls _exit(), both async-signal-safe.
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        action.sa_sigaction = on_interrupt as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        libc::sigemptyset(&mut action.sa_mask);
+        libc::sigaction(libc::SIGINT, &action, std::ptr::null_mut());
+    }
+    Guard { _private: () }
+}
+
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        // Safety: restores the default disposition.
+        unsafe {
+            libc::signal(libc::SIGINT, libc::SIG_DFL);
+        }
+    }
+}
+
+
+#[cfg(unix)]
+extern "C" fn on_interrupt(_signal: libc::c_int) {
+    if INTERRUPTED.swap(true, Ordering::SeqCst) {
+        // Safety: _exit() is async-signal-safe, unlike std::process::exit().
+        unsafe { libc::_exit(EXIT_STATUS) };
+    }
+}
@@ -0,0 +1,191 @@
+//! Parsing, formatting, and classification of IPv4 and IPv6 addresses.
+
+
+/// Parses an IPv4 address in dotted-quad notation, e.g. `192.168.1.1`.
+pub fn parse_v4(arg: &str) -> Option<u32> {
+    let octets: Vec<&str> = arg.split('.').collect();
+    if octets.len() != 4 {
+        return None;
+    }
+    let mut addr = 0;
+    for octet in octets {
+        if octet.is_empty() || octet.len() > 3 || !octet.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        addr = addr << 8 | octet.parse::<u8>().ok()? as u32;
+    }
+    Some(addr)
+}
+
+
+/// Parses an IPv6 address, e.g. `2001:db8::1`. Accepts a `::` for a run of zero groups and a
+/// trailing IPv4 address in dotted-quad notation, e.g. `::ffff:192.168.1.1`.
+pub fn parse_v6(arg: &str) -> Option<u128> {
+    let groups = match arg.split_once("::") {
+        Some((head, tail)) => {
+            let (head, tail) = (groups(head, false)?, groups(tail, true)?);
+            if head.len() + tail.len() > 7 {
+                return None;
+            }
+            let zeros = vec![0; 8 - head.len() - tail.len()];
+            [head, zeros, tail].concat()
+        },
+        None => groups(arg, true)?,
+    };
+    match groups.len() {
+        8 => Some(groups.iter().fold(0, |addr, group| addr << 16 | *group as u128)),
+        _ => None,
+    }
+}
+
+
+// Parses colon-separated hex groups. If `last` is true, the groups end the address, so the final
+// group can be an IPv4 address, which counts as two groups.
+fn groups(text: &str, last: bool) -> Option<Vec<u16>> {
+    let mut groups = Vec::new();
+    if text.is_empty() {
+        return Some(groups);
+    }
+    let parts: Vec<&str> = text.split(':').collect();
+    for (index, part) in parts.iter().enumerate() {
+        if last && index == parts.len() - 1 && part.contains('.') {
+            let addr = parse_v4(part)?;
+            groups.push((addr >> 16) as u16);
+            groups.push(addr as u16);
+        } else if part.is_empty() || part.len() > 4 {
+            return None;
+        } else {
+            groups.push(u16::from_str_radix(part, 16).ok()?);
+        }
+    }
+    Some(groups)
+}
+
+
+/// Formats an IPv4 address in dotted-quad notation.
+pub fn format_v4(addr: u32) -> String {
+    let octets: Vec<String> = addr.to_be_bytes().iter().map(|octet| octet.to_string()).collect();
+    octets.join(".")
+}
+
+
+/// Formats an IPv6 address in the canonical form of RFC 5952: lowercase hex groups without
+/// leading zeros, with the longest run of two or more zero groups, the first if tied, replaced by
+/// `::`. IPv4-mapped addresses end with the IPv4 address in dotted-quad notation.
+pub fn format_v6(addr: u128) -> String {
+    if let Some(v4) = mapped_v4(addr) {
+        return format!("::ffff:{}", format_v4(v4));
+    }
+    let groups: Vec<u16> = (0..8).rev().map(|i| (addr >> (i * 16)) as u16).collect();
+    let mut run = (0, 0);
+    let mut start = 0;
+    for (index, group) in groups.iter().enumerate() {
+        if *group != 0 {
+            start = index + 1;
+        } else if index + 1 - start > run.1 {
+            run = (start, index + 1 - start);
+        }
+    }
+    let hex = |groups: &[u16]| {
+        groups.iter().map(|group| format!("{:x}", group)).collect::<Vec<String>>().join(":")
+    };
+    match run {
+        (start, len) if len >= 2 => {
+            format!("{}::{}", hex(&groups[..start]), hex(&groups[start + len..]))
+        },
+        _ => hex(&groups),
+    }
+}
+
+
+/// Formats an IPv6 address with all eight groups written out in full, e.g.
+/// `2001:0db8:0000:0000:0000:0000:0000:0001`.
+pub fn format_v6_full(addr: u128) -> String {
+    let groups: Vec<String> =
+        (0..8).rev().map(|i| format!("{:04x}", (addr >> (i * 16)) as u16)).collect();
+    groups.join(":")
+}
+
+
+/// Returns the IPv4 address embedded in an IPv4-mapped IPv6 address, `::ffff:0:0/96`.
+pub fn mapped_v4(addr: u128) -> Option<u32> {
+    match addr >> 32 {
+        0xFFFF => Some(addr as u32),
+        _ => None,
+    }
+}
+
+
+/// Returns the classful network class of an IPv4 address, from `A` to `E`, as fixed by the
+/// leading bits.
+pub fn v4_class(addr: u32) -> char {
+    match addr.leading_ones() {
+        0 => 'A',
+        1 => 'B',
+        2 => 'C',
+        3 => 'D',
+        _ => 'E',
+    }
+}
+
+
+// Special-purpose IPv4 blocks, as (network, prefix length, description). Addresses outside
+// them are public.
+const V4_BLOCKS: &[(u32, u32, &str)] = &[
+    (0x00000000, 32, "unspecified"),
+    (0xFFFFFFFF, 32, "limited broadcast"),
+    (0x00000000, 8, "this network (RFC 1122)"),
+    (0x0A000000, 8, "private (RFC 1918)"),
+    (0x64400000, 10, "shared address space for carrier-grade NAT (RFC 6598)"),
+    (0x7F000000, 8, "loopback"),
+    (0xA9FE0000, 16, "link-local (RFC 3927)"),
+    (0xAC100000, 12, "private (RFC 1918)"),
+    (0xC0000000, 24, "IETF protocol assignments (RFC 6890)"),
+    (0xC0000200, 24, "documentation, TEST-NET-1 (RFC 5737)"),
+    (0xC0A80000, 16, "private (RFC 1918)"),
+    (0xC6120000, 15, "benchmarking (RFC 2544)"),
+    (0xC6336400, 24, "documentation, TEST-NET-2 (RFC 5737)"),
+    (0xCB007100, 24, "documentation, TEST-NET-3 (RFC 5737)"),
+    (0xE0000000, 4, "multicast (RFC 5771)"),
+    (0xF0000000, 4, "reserved (RFC 1112)"),
+];
+
+
+// Special-purpose IPv6 blocks, as (network, prefix length, description). Addresses outside
+// them are global unicast if they're in 2000::/3 and reserved otherwise.
+const V6_BLOCKS: &[(u128, u32, &str)] = &[
+    (0, 128, "unspecified"),
+    (1, 128, "loopback"),
+    (0xFFFF << 32, 96, "IPv4-mapped"),
+    (0x0064FF9B << 96, 96, "IPv4/IPv6 translation (RFC 6052)"),
+    (0x0100 << 112, 64, "discard-only (RFC 6666)"),
+    (0x20010000 << 96, 32, "Teredo tunneling (RFC 4380)"),
+    (0x20010DB8 << 96, 32, "documentation (RFC 3849)"),
+    (0x2002 << 112, 16, "6to4 (RFC 3056)"),
+    (0xFC00 << 112, 7, "unique local (RFC 4193)"),
+    (0xFE80 << 112, 10, "link-local unicast"),
+    (0xFF00 << 112, 8, "multicast"),
+];
+
+
+/// Describes the special-purpose block containing an IPv4 address, or returns `public`.
+pub fn v4_scope(addr: u32) -> &'static str {
+    V4_BLOCKS
+        .iter()
+        .find(|(network, len, _)| addr >> (32 - len) == network >> (32 - len))
+        .map_or("public", |(_, _, description)| *description)
+}
+
+
+/// Describes the special-purpose block containing an IPv6 address, or returns `global unicast`
+/// for other addresses in 2000::/3.
+pub fn v6_scope(addr: u128) -> &'static str {
+    let found = V6_BLOCKS
+        .iter()
+        .find(|(network, len, _)| addr >> (128 - len) == network >> (128 - len));
+    match found {
+        Some((_, _, description)) => description,
+        None if addr >> 125 == 1 => "global unicast",
+        None => "reserved",
+    }
+}
@@ -0,0 +1,256 @@
+//! A minimal JSON reader and writer, sufficient for inspecting the small documents embedded in
+//! tokens and for emitting machine-readable output.
+
+
+/// A parsed JSON value. Numbers are stored as their source text so integer values survive
+/// without loss of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+
+impl Value {
+    /// Returns the value stored under `key` if this value is an object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a string slice if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `i64` if it is an integer-valued number.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Number(text) => text.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `f64` if it is a number, including numbers with a fraction or an
+    /// exponent, e.g. `1.5e9`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(text) => text.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+
+impl std::fmt::Display for Value {
+    /// Writes the value as compact JSON.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Number(text) => write!(f, "{}", text),
+            Value::String(string) => write!(f, "{}", quote(string)),
+            Value::Array(items) => {
+                let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+                write!(f, "[{}]", items.join(","))
+            },
+            Value::Object(members) => {
+                let members: Vec<String> = members
+                    .iter()
+                    .map(|(key, value)| format!("{}:{}", quote(key), value))
+                    .collect();
+                write!(f, "{{{}}}", members.join(","))
+            },
+        }
+    }
+}
+
+
+/// Returns the string as a quoted JSON string literal, escaping quotes, backslashes, and control
+/// characters.
+pub fn quote(string: &str) -> String {
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+
+/// Parses a JSON document. Returns `None` if the text is not valid JSON.
+pub fn parse(text: &str) -> Option<Value> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut pos = 0;
+    let value = parse_value(&chars, &mut pos)?;
+    skip_whitespace(&chars, &mut pos);
+    if pos == chars.len() {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+
+fn skip_whitespace(chars: &[char], pos: &mut usize) {
+    while *pos < chars.len() && chars[*pos].is_whitespace() {
+        *pos += 1;
+    }
+}
+
+
+fn expect(chars: &[char], pos: &mut usize, word: &str) -> Option<()> {
+    for c in word.chars() {
+        if chars.get(*pos) != Some(&c) {
+            return None;
+        }
+        *pos += 1;
+    }
+    Some(())
+}
+
+
+fn parse_value(chars: &[char], pos: &mut usize) -> Option<Value> {
+    skip_whitespace(chars, pos);
+    match chars.get(*pos)? {
+        '{' => parse_object(chars, pos),
+        '[' => parse_array(chars, pos),
+        '"' => parse_string(chars, pos).map(Value::String),
+        't' => expect(chars, pos, "true").map(|_| Value::Bool(true)),
+        'f' => expect(chars, pos, "false").map(|_| Value::Bool(false)),
+        'n' => expect(chars, pos, "null").map(|_| Value::Null),
+        _ => parse_number(chars, pos),
+    }
+}
+
+
+fn parse_object(chars: &[char], pos: &mut usize) -> Option<Value> {
+    let mut members = Vec::new();
+    *pos += 1;
+    skip_whitespace(chars, pos);
+    if chars.get(*pos) == Some(&'}') {
+        *pos += 1;
+        return Some(Value::Object(members));
+    }
+    loop {
+        skip_whitespace(chars, pos);
+        if chars.get(*pos) != Some(&'"') {
+            return None;
+        }
+        let key = parse_string(chars, pos)?;
+        skip_whitespace(chars, pos);
+        expect(chars, pos, ":")?;
+        let value = parse_value(chars, pos)?;
+        members.push((key, value));
+        skip_whitespace(chars, pos);
+        match chars.get(*pos)? {
+            ',' => *pos += 1,
+            '}' => {
+                *pos += 1;
+                return Some(Value::Object(members));
+            },
+            _ => return None,
+        }
+    }
+}
+
+
+fn parse_array(chars: &[char], pos: &mut usize) -> Option<Value> {
+    let mut elements = Vec::new();
+    *pos += 1;
+    skip_whitespace(chars, pos);
+    if chars.get(*pos) == Some(&']') {
+        *pos += 1;
+        return Some(Value::Array(elements));
+    }
+    loop {
+        elements.push(parse_value(chars, pos)?);
+        skip_whitespace(chars, pos);
+        match chars.get(*pos)? {
+            ',' => *pos += 1,
+            ']' => {
+                *pos += 1;
+                return Some(Value::Array(elements));
+            },
+            _ => return None,
+        }
+    }
+}
+
+
+fn parse_string(chars: &[char], pos: &mut usize) -> Option<String> {
+    let mut string = String::new();
+    *pos += 1;
+    loop {
+        let c = *chars.get(*pos)?;
+        *pos += 1;
+        match c {
+            '"' => return Some(string),
+            '\\' => {
+                let escape = *chars.get(*pos)?;
+                *pos += 1;
+                match escape {
+                    '"' | '\\' | '/' => string.push(escape),
+                    'b' => string.push('\u{8}'),
+                    'f' => string.push('\u{c}'),
+                    'n' => string.push('\n'),
+                    'r' => string.push('\r'),
+                    't' => string.push('\t'),
+                    'u' => {
+                        let mut code = parse_hex4(chars, pos)?;
+                        if (0xD800..0xDC00).contains(&code) {
+                            expect(chars, pos, "\\u")?;
+                            let low = parse_hex4(chars, pos)?;
+                            if !(0xDC00..0xE000).contains(&low) {
+                                return None;
+                            }
+                            code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                        }
+                        string.push(std::char::from_u32(code)?);
+                    },
+                    _ => return None,
+                }
+            },
+            _ => string.push(c),
+        }
+    }
+}
+
+
+fn parse_hex4(chars: &[char], pos: &mut usize) -> Option<u32> {
+    let digits: String = chars.get(*pos..*pos + 4)?.iter().collect();
+    *pos += 4;
+    u32::from_str_radix(&digits, 16).ok()
+}
+
+
+fn parse_number(chars: &[char], pos: &mut usize) -> Option<Value> {
+    let start = *pos;
+    while *pos < chars.len() && "+-0123456789.eE".contains(chars[*pos]) {
+        *pos += 1;
+    }
+    let text: String = chars[start..*pos].iter().collect();
+    if text.parse::<f64>().is_ok() {
+        Some(Value::Number(text))
+    } else {
+        None
+    }
+}
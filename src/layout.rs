@@ -0,0 +1,125 @@
+//! Named bit-field layouts, for decoding hardware registers and instruction encodings.
+
+use crate::extract_field;
+
+
+/// A named field of a layout, covering bits `high` down to `low` inclusive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub high: u32,
+    pub low: u32,
+}
+
+
+/// Parses a layout written as comma-separated `name:range` pairs, e.g.
+/// `mode:0-2,enable:3,prescaler:4-7`. Returns the fields ordered from the highest bit down, or an
+/// error if a range is invalid or two fields overlap.
+pub fn parse(spec: &str) -> Result<Vec<Field>, String> {
+    let mut fields = Vec::new();
+    for item in spec.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+        let (name, range) = item
+            .split_once(':')
+            .ok_or_else(|| format!("expected name:range, found '{}'", item))?;
+        fields.push(field(name, range)?);
+    }
+    sorted(fields)
+}
+
+
+/// Parses a layout file with a `name = range` line for each field, e.g. `mode = "0-2"`, a subset
+/// of TOML. Ranges may be quoted. Blank lines, `#` comments, and `[table]` headers are ignored.
+/// Returns the fields ordered from the highest bit down, or an error if a line can't be parsed,
+/// a range is invalid, or two fields overlap.
+pub fn parse_file(text: &str) -> Result<Vec<Field>, String> {
+    let mut fields = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() || line.starts_with('[') {
+            continue;
+        }
+        let (name, range) = line
+            .split_once('=')
+            .ok_or_else(|| format!("expected name = range on line {}", number + 1))?;
+        let name = name.trim().trim_matches('"');
+        let range = range.trim().trim_matches(|c| c == '"' || c == '\'');
+        fields.push(field(name, range)?);
+    }
+    sorted(fields)
+}
+
+
+/// Parses a bit range: a single bit, e.g. `3`, a range from low to high, e.g. `4-7`, or a range
+/// from high to low, e.g. `7:4`. Returns the high and low bits.
+pub fn parse_range(range: &str) -> Result<(u32, u32), String> {
+    let parse = |text: &str| {
+        text.trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|bit| *bit < 128)
+            .ok_or_else(|| format!("invalid bit range '{}', expected bits from 0 to 127", range))
+    };
+    let (high, low) = if let Some((a, b)) = range.split_once('-') {
+        let (a, b) = (parse(a)?, parse(b)?);
+        (a.max(b), a.min(b))
+    } else if let Some((high, low)) = range.split_once(':') {
+        (parse(high)?, parse(low)?)
+    } else {
+        let bit = parse(range)?;
+        (bit, bit)
+    };
+    if high < low {
+        return Err(format!("invalid bit range '{}', the high bit comes first", range));
+    }
+    Ok((high, low))
+}
+
+
+// Parses a field's name and range.
+fn field(name: &str, range: &str) -> Result<Field, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("missing field name for range '{}'", range.trim()));
+    }
+    let (high, low) = parse_range(range)?;
+    Ok(Field { name: name.to_string(), high, low })
+}
+
+
+// Sorts the fields from the highest bit down and checks that they don't overlap.
+fn sorted(mut fields: Vec<Field>) -> Result<Vec<Field>, String> {
+    if fields.is_empty() {
+        return Err("the layout has no fields".to_string());
+    }
+    fields.sort_by_key(|field| std::cmp::Reverse(field.high));
+    for pair in fields.windows(2) {
+        if pair[1].high >= pair[0].low {
+            return Err(format!("fields '{}' and '{}' overlap", pair[0].name, pair[1].name));
+        }
+    }
+    Ok(fields)
+}
+
+
+/// Returns the value of each field of a layout.
+pub fn decode(value: u128, fields: &[Field]) -> Vec<u128> {
+    fields.iter().map(|field| extract_field(value, field.high, field.low)).collect()
+}
+
+
+/// Returns the bit ranges, from the highest down, that no field covers, up to the top bit of the
+/// highest field.
+pub fn gaps(fields: &[Field]) -> Vec<(u32, u32)> {
+    let mut gaps = Vec::new();
+    let mut next = fields.first().map_or(0, |field| field.high + 1);
+    for field in fields {
+        if field.high + 1 < next {
+            gaps.push((next - 1, field.high + 1));
+        }
+        next = field.low;
+    }
+    if next > 0 {
+        gaps.push((next - 1, 0));
+    }
+    gaps
+}
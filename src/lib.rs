@@ -1,24 +1,378 @@
 use std::iter::FromIterator;
+use std::collections::HashMap;
+
+pub mod arith;
+pub mod asn1;
+pub mod barcode;
+// Shared by the bench command and benches/hot_paths.rs; not part of the library API.
+#[doc(hidden)]
+pub mod bench;
+pub mod bigint;
+pub mod bitmap;
+pub mod check;
+pub mod checksum;
+pub mod codec;
+pub mod color;
+pub mod coding;
+pub mod combinatorics;
+pub mod country;
+pub mod datetime;
+pub mod decimal;
+pub mod dns;
+pub mod exif;
+pub mod factor;
+pub mod float;
+pub mod geo;
+pub mod huffman;
+pub mod ip;
+pub mod ids;
+pub mod json;
+pub mod layout;
+pub mod mac;
+pub mod magic;
+pub mod midi;
+pub mod mode;
+pub mod numbering;
+pub mod pointer;
+pub mod poison;
+pub mod pow;
+pub mod radio;
+pub mod random;
+pub mod ratio;
+pub mod rns;
+pub mod selftest;
+pub mod sequence;
+pub mod synchsafe;
+
+#[cfg(feature = "hashes")]
+pub mod hash;
+
+#[cfg(feature = "oui")]
+pub mod oui;
+
+#[cfg(feature = "syscalls")]
+pub mod syscalls;
+
+#[cfg(feature = "unicode")]
+pub mod unicode;
+
+// The command line interface, which the binary runs with main().
+mod cli;
+mod commands;
+mod interrupt;
+mod locale;
+mod man;
+mod options;
+mod output;
+mod report;
+mod stream;
+
+pub use cli::{run, Error};
+
+#[doc(hidden)]
+pub use cli::main;
+
+
+/// An error from parsing an integer literal, evaluating an expression, or analyzing an integer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntspectorError {
+    /// The input has no digits.
+    Empty,
+    /// The input contains a digit that is not valid for its radix.
+    InvalidDigit { digit: char, radix: u32 },
+    /// The input is negative but only unsigned values are accepted.
+    Negative,
+    /// The value does not fit in the integer type.
+    Overflow { bits: u32, signed: bool },
+    /// The input has a `base:` prefix with a base outside the range 2 to 36.
+    UnsupportedBase(String),
+    /// An expression contains a character that is not part of any token.
+    UnexpectedChar(char),
+    /// An expression contains a token where it is not allowed.
+    UnexpectedToken(String),
+    /// An expression ends where an operand is expected.
+    UnexpectedEnd,
+    /// An expression has an unclosed parenthesis.
+    MissingParen,
+    /// An expression divides by zero.
+    DivisionByZero,
+    /// An expression shifts by a negative amount or by 128 bits or more.
+    ShiftOutOfRange,
+    /// The result of an operator in an expression does not fit in 128 bits.
+    ArithmeticOverflow(String),
+    /// A bit width is 0 or above 128.
+    UnsupportedBits(u32),
+    /// A bit width is too narrow for the value.
+    TooFewBits { value: i128, min_bits: u32 },
+}
+
+
+impl std::fmt::Display for IntspectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            IntspectorError::Empty => write!(f, "no digits found"),
+            IntspectorError::InvalidDigit { digit, radix } => {
+                write!(f, "digit '{}' not valid in {} literal", digit, radix_name(*radix))
+            },
+            IntspectorError::Negative => write!(f, "negative values are not supported"),
+            IntspectorError::Overflow { bits, signed } => {
+                let sign = if *signed { "signed" } else { "unsigned" };
+                write!(f, "value out of range for a {}-bit {} integer", bits, sign)
+            },
+            IntspectorError::UnsupportedBase(base) => {
+                write!(f, "base {} not supported, expected 2 to 36", base)
+            },
+            IntspectorError::UnexpectedChar(c) => write!(f, "unexpected character '{}'", c),
+            IntspectorError::UnexpectedToken(token) => write!(f, "unexpected '{}'", token),
+            IntspectorError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            IntspectorError::MissingParen => write!(f, "missing ')'"),
+            IntspectorError::DivisionByZero => write!(f, "division by zero"),
+            IntspectorError::ShiftOutOfRange => write!(f, "shift out of range"),
+            IntspectorError::ArithmeticOverflow(op) => write!(f, "overflow in '{}'", op),
+            IntspectorError::UnsupportedBits(_) => write!(f, "unsupported bit size"),
+            IntspectorError::TooFewBits { value, min_bits } => {
+                write!(f, "{} requires at least {} bits", value, min_bits)
+            },
+        }
+    }
+}
+
+
+impl std::error::Error for IntspectorError {}
+
+
+impl IntspectorError {
+    /// Returns a stable snake-case identifier for the kind of error, e.g. `invalid_digit`, for
+    /// machine-readable output. Unlike the message, the code doesn't change between releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            IntspectorError::Empty => "empty",
+            IntspectorError::InvalidDigit { .. } => "invalid_digit",
+            IntspectorError::Negative => "negative",
+            IntspectorError::Overflow { .. } => "overflow",
+            IntspectorError::UnsupportedBase(_) => "unsupported_base",
+            IntspectorError::UnexpectedChar(_) => "unexpected_char",
+            IntspectorError::UnexpectedToken(_) => "unexpected_token",
+            IntspectorError::UnexpectedEnd => "unexpected_end",
+            IntspectorError::MissingParen => "missing_paren",
+            IntspectorError::DivisionByZero => "division_by_zero",
+            IntspectorError::ShiftOutOfRange => "shift_out_of_range",
+            IntspectorError::ArithmeticOverflow(_) => "arithmetic_overflow",
+            IntspectorError::UnsupportedBits(_) => "unsupported_bits",
+            IntspectorError::TooFewBits { .. } => "too_few_bits",
+        }
+    }
+
+
+    /// Returns the byte offset in `arg` of the input the error points at, if the error was
+    /// returned for `arg`: the offending character for invalid digits and unexpected characters
+    /// or tokens, and the end of the input for an expression that ends too soon.
+    pub fn position(&self, arg: &str) -> Option<usize> {
+        match self {
+            IntspectorError::InvalidDigit { digit, .. } => {
+                let start = digits_offset(arg);
+                let is_separator = |c: char| "_', ".contains(c);
+                let is_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+                let offset = arg[start..].char_indices().find(|(i, c)| {
+                    if c != digit {
+                        return false;
+                    }
+                    if !is_separator(*c) {
+                        return true;
+                    }
+                    // A separator is only invalid if it doesn't sit between two digits.
+                    let before = arg[start..start + i].chars().rev().find(|c| !is_separator(*c));
+                    let after = arg[start + i + 1..].chars().find(|c| !is_separator(*c));
+                    !is_digit(before) || !is_digit(after)
+                });
+                offset.map(|(i, _)| start + i).or_else(|| arg.find(*digit))
+            },
+            IntspectorError::UnexpectedChar(c) => arg.find(*c),
+            IntspectorError::UnexpectedToken(token) => arg.find(token.as_str()),
+            IntspectorError::UnexpectedEnd | IntspectorError::MissingParen => Some(arg.len()),
+            _ => None,
+        }
+    }
+}
+
+
+// Returns the byte offset of the digits in an integer literal, after any sign and base prefix.
+fn digits_offset(arg: &str) -> usize {
+    let unsigned = arg.strip_prefix(|c| c == '-' || c == '+').unwrap_or(arg);
+    let digits = match unsigned.split_once(':') {
+        Some((base, digits)) if !base.is_empty() && base.chars().all(|c| c.is_ascii_digit()) => {
+            digits
+        },
+        _ => split_radix(unsigned).map_or(unsigned, |(_, digits)| digits),
+    };
+    arg.len() - digits.len().min(arg.len())
+}
+
+
+// Returns the name of a radix as used in error messages.
+fn radix_name(radix: u32) -> String {
+    match radix {
+        2 => "binary".to_string(),
+        8 => "octal".to_string(),
+        10 => "decimal".to_string(),
+        16 => "hexadecimal".to_string(),
+        _ => format!("base-{}", radix),
+    }
+}
 
 
 /// Returns the minimum number of bits required to represent the integer. For positive input, gives
 /// the number of unsigned bits. For negative input, gives the number of two's complement bits.
-pub fn min_bits(value: i64) -> u32 {
+pub fn min_bits(value: i128) -> u32 {
     if value == 0 {
         1
     } else if value > 0 {
-        (value as f64).log2().floor() as u32 + 1
+        128 - value.leading_zeros()
     } else {
-        (value.abs() as f64).log2().ceil() as u32 + 1
+        128 - (!value).leading_zeros() + 1
     }
 }
 
 
+/// Returns the minimum number of bits required to represent the unsigned integer.
+pub fn min_bits_unsigned(value: u128) -> u32 {
+    (128 - value.leading_zeros()).max(1)
+}
+
+
+/// Returns the number of set bits in the low-order `num_bits` bits of the integer.
+pub fn popcount(value: u128, num_bits: u32) -> u32 {
+    low_bits(value, num_bits).count_ones()
+}
+
+
+/// Returns the number of leading zeros in the low-order `num_bits` bits of the integer, i.e.
+/// counting down from bit `num_bits - 1`.
+pub fn clz(value: u128, num_bits: u32) -> u32 {
+    let num_bits = num_bits.min(128);
+    low_bits(value, num_bits).leading_zeros() - (128 - num_bits)
+}
+
+
+/// Returns the number of trailing zeros in the low-order `num_bits` bits of the integer. Gives
+/// `num_bits` if they are all zero.
+pub fn ctz(value: u128, num_bits: u32) -> u32 {
+    low_bits(value, num_bits).trailing_zeros().min(num_bits)
+}
+
+
+/// Returns the parity of the low-order `num_bits` bits of the integer: 1 if an odd number of
+/// bits are set, 0 if an even number are set.
+pub fn parity(value: u128, num_bits: u32) -> u32 {
+    popcount(value, num_bits) & 1
+}
+
+
+/// Returns the integer with the bits at the specified positions set. Will panic if a position is
+/// 128 or more.
+pub fn set_bits(value: u128, positions: &[u32]) -> u128 {
+    value | position_mask(positions)
+}
+
+
+/// Returns the integer with the bits at the specified positions cleared. Will panic if a
+/// position is 128 or more.
+pub fn clear_bits(value: u128, positions: &[u32]) -> u128 {
+    value & !position_mask(positions)
+}
+
+
+/// Returns the integer with the bits at the specified positions flipped. A position listed twice
+/// is flipped once. Will panic if a position is 128 or more.
+pub fn toggle_bits(value: u128, positions: &[u32]) -> u128 {
+    value ^ position_mask(positions)
+}
+
+
+/// Rotates the low-order `num_bits` bits of the integer left by `amount` bits, discarding any
+/// higher bits. Will panic if `num_bits` is 0 or greater than 128.
+pub fn rotate_left(value: u128, amount: u32, num_bits: u32) -> u128 {
+    assert!(num_bits > 0 && num_bits <= 128);
+    let value = low_bits(value, num_bits);
+    let amount = amount % num_bits;
+    if amount == 0 {
+        return value;
+    }
+    low_bits(value << amount | value >> (num_bits - amount), num_bits)
+}
+
+
+/// Rotates the low-order `num_bits` bits of the integer right by `amount` bits, discarding any
+/// higher bits. Will panic if `num_bits` is 0 or greater than 128.
+pub fn rotate_right(value: u128, amount: u32, num_bits: u32) -> u128 {
+    assert!(num_bits > 0 && num_bits <= 128);
+    rotate_left(value, num_bits - amount % num_bits, num_bits)
+}
+
+
+// Returns a mask with the bits at the specified positions set.
+fn position_mask(positions: &[u32]) -> u128 {
+    positions.iter().fold(0, |mask, position| {
+        assert!(*position < 128);
+        mask | 1 << position
+    })
+}
+
+
+/// Returns the run-length encoding of the low-order `num_bits` bits of the integer, as pairs of
+/// a bit value and a run length, starting from the most significant bit.
+pub fn bit_runs(value: u128, num_bits: u32) -> Vec<(u8, u32)> {
+    let mut runs: Vec<(u8, u32)> = Vec::new();
+    for i in (0..num_bits.min(128)).rev() {
+        let bit = (value >> i & 1) as u8;
+        match runs.last_mut() {
+            Some((last, length)) if *last == bit => *length += 1,
+            _ => runs.push((bit, 1)),
+        }
+    }
+    runs
+}
+
+
+// Returns the low-order `num_bits` bits of the integer.
+fn low_bits(value: u128, num_bits: u32) -> u128 {
+    if num_bits >= 128 {
+        value
+    } else {
+        value & ((1 << num_bits) - 1)
+    }
+}
+
+
+/// Returns the mask selecting bits `high` down to `low` inclusive. Will panic if `high < low` or
+/// `high > 127`.
+pub fn field_mask(high: u32, low: u32) -> u128 {
+    assert!(low <= high && high < 128);
+    low_bits(u128::MAX, high - low + 1) << low
+}
+
+
+/// Returns the value of bits `high` down to `low` inclusive, shifted down to bit 0. Will panic if
+/// `high < low` or `high > 127`.
+pub fn extract_field(value: u128, high: u32, low: u32) -> u128 {
+    (value & field_mask(high, low)) >> low
+}
+
+
+/// Returns the number of leading bits, counting down from bit `num_bits - 1`, that all the
+/// values share. Returns `num_bits` for a single value or an empty slice.
+pub fn common_prefix_len(values: &[u128], num_bits: u32) -> u32 {
+    let diff = values.iter().fold(0, |acc, value| acc | (value ^ values[0]));
+    let diff = low_bits(diff, num_bits);
+    num_bits.min(128) - (128 - diff.leading_zeros())
+}
+
+
 /// Returns the output from min_bits() rounded up to a standard integer size - either 8, 16, 32,
-/// or 64 bits.
-pub fn std_bits(value: i64) -> u32 {
+/// 64, or 128 bits.
+pub fn std_bits(value: i128) -> u32 {
     let min_bits = min_bits(value);
-    for std_size in vec![8, 16, 32, 64] {
+    for std_size in [8, 16, 32, 64, 128] {
         if min_bits <= std_size {
             return std_size;
         }
@@ -27,25 +381,35 @@ pub fn std_bits(value: i64) -> u32 {
 }
 
 
-/// Adds spacer characters to a string.
+/// Adds spacer characters to a string, between blocks of characters counted from the right.
 pub fn add_spacers(string: &str, spacer: char, block_len: u32) -> String {
-    let mut chars: Vec<char> = Vec::new();
-    for (i, c) in string.chars().rev().enumerate() {
-        chars.push(c);
-        if i as u32 % block_len == block_len - 1 {
-            chars.push(spacer);
+    let block_len = block_len as usize;
+    let len = string.chars().count();
+    let mut output = String::with_capacity(string.len() + len / block_len * spacer.len_utf8());
+    for (i, c) in string.chars().enumerate() {
+        if i > 0 && (len - i).is_multiple_of(block_len) {
+            output.push(spacer);
         }
+        output.push(c);
     }
-    if chars.last().unwrap() == &spacer {
-        chars.pop();
-    }
-    chars.reverse();
-    String::from_iter(chars)
+    output
+}
+
+
+/// Converts an integer into a string of digits in the specified base, from 2 to 36, using the
+/// lowercase letters a-z for digits above 9. Will panic if the base is out of range.
+pub fn to_base(value: u128, base: u32) -> String {
+    assert!((2..=36).contains(&base));
+    radix_digits(value, base)
+        .iter()
+        .rev()
+        .map(|digit| std::char::from_digit(*digit, base).unwrap())
+        .collect()
 }
 
 
 /// Converts an integer into a binary string, showing the specified number of low-order bits.
-pub fn bin_string(mut value: u64, num_bits: u32) -> String {
+pub fn bin_string(mut value: u128, num_bits: u32) -> String {
     let mut chars: Vec<char> = Vec::new();
 
     for i in 0..num_bits {
@@ -70,30 +434,183 @@ pub fn bin_string(mut value: u64, num_bits: u32) -> String {
 }
 
 
-/// Returns the n-bit two's complement of `value`. Will panic if `n > 64` or `value >= 2^n`.
-pub fn twos_complement(value: u64, num_bits: u32) -> u64 {
-    assert!(num_bits <= 64);
+/// Converts an integer into a binary string, showing the specified number of low-order bits,
+/// with a spacer character between each group of `group` bits, counting from the least
+/// significant bit. A group size of 0 disables grouping.
+pub fn bin_string_with(value: u128, num_bits: u32, group: u32, spacer: char) -> String {
+    let digits = format!("{:0width$b}", value, width = 128);
+    let digits = &digits[(128 - num_bits.min(128)) as usize..];
+    if group == 0 || digits.is_empty() {
+        return digits.to_string();
+    }
+    add_spacers(digits, spacer, group)
+}
+
+
+/// Reverses the order of the low-order `num_bits / 8` bytes of `value`, e.g. converting a 32-bit
+/// value between big-endian and little-endian byte order. Higher-order bytes are discarded. Will
+/// panic if `num_bits` is not a multiple of 8 from 8 to 64.
+pub fn byte_swap(value: u64, num_bits: u32) -> u64 {
+    assert!(num_bits.is_multiple_of(8) && (8..=64).contains(&num_bits));
+    value.swap_bytes() >> (64 - num_bits)
+}
+
+
+/// Returns the n-bit two's complement of `value`. Will panic if `n > 128` or `value >= 2^n`.
+pub fn twos_complement(value: u128, num_bits: u32) -> u128 {
+    assert!(num_bits <= 128);
     if value == 0 {
         return 0;
     }
-    if num_bits < 64 {
-        let cap = (2 as u64).pow(num_bits);
+    if num_bits < 128 {
+        let cap = 2_u128.pow(num_bits);
         assert!(value < cap);
         return cap - value;
     }
-    return (u64::MAX - value) + 1
+    (u128::MAX - value) + 1
+}
+
+
+/// Returns the balanced ternary digits of `value`, least significant first. Each digit is -1, 0,
+/// or 1 with weight 3^i. Zero has no digits.
+pub fn balanced_ternary(mut value: i128) -> Vec<i8> {
+    let mut digits = Vec::new();
+    while value != 0 {
+        let digit = match value.rem_euclid(3) {
+            2 => -1,
+            r => r as i8,
+        };
+        // Computed from the floor quotient so the adjustment never overflows.
+        value = value.div_euclid(3) + if digit == -1 { 1 } else { 0 };
+        digits.push(digit);
+    }
+    digits
+}
+
+
+/// Returns the non-adjacent form of `value`, least significant first: the unique binary
+/// signed-digit representation, with digits -1, 0, or 1 of weight 2^i, in which no two adjacent
+/// digits are non-zero. It has the fewest non-zero digits of any signed binary representation.
+pub fn non_adjacent_form(mut value: i128) -> Vec<i8> {
+    let mut digits = Vec::new();
+    while value != 0 {
+        let digit = if value % 2 == 0 { 0 } else { 2 - value.rem_euclid(4) as i8 };
+        value = value.div_euclid(2) + if digit == -1 { 1 } else { 0 };
+        digits.push(digit);
+    }
+    digits
+}
+
+
+/// Formats signed digits, least significant first, as a string, most significant first, writing
+/// -1 as `T`. Zero is written as `0`.
+pub fn signed_digit_string(digits: &[i8]) -> String {
+    if digits.is_empty() {
+        return "0".to_string();
+    }
+    digits.iter().rev().map(|digit| match digit {
+        -1 => 'T',
+        0 => '0',
+        _ => '1',
+    }).collect()
+}
+
+
+/// Attempts to parse the string as a binary, octal, decimal, or hex integer. Accepts values in
+/// the signed 128-bit range. Accepts an explicit sign before any base prefix, e.g. `-0x10` or
+/// `+b1010`. Any base from 2 to 36 can be given with a `base:` prefix, e.g. `36:zz`. Digits can
+/// be grouped with underscores, apostrophes, commas, or spaces, e.g. `0x1234_5678` or
+/// `1,000,000`.
+pub fn parse_int(arg: &str) -> Result<i128, IntspectorError> {
+    parse_radix(arg, 128, true, i128::from_str_radix)
+}
+
+
+/// Attempts to parse the string as an unsigned binary, octal, decimal, or hex integer in the
+/// full unsigned 128-bit range.
+pub fn parse_u128(arg: &str) -> Result<u128, IntspectorError> {
+    parse_radix(arg, 128, false, u128::from_str_radix)
+}
+
+
+/// Attempts to parse the string as an unsigned binary, octal, decimal, or hex integer. Accepts
+/// values in the full unsigned 64-bit range, e.g. kernel-space pointers.
+pub fn parse_uint(arg: &str) -> Result<u64, IntspectorError> {
+    parse_radix(arg, 64, false, u64::from_str_radix)
+}
+
+
+// Parses the string with the specified `from_str_radix` function, working out why the string
+// was rejected if parsing fails. An explicit sign comes before any base prefix, e.g. `-0x10`.
+fn parse_radix<T>(
+    arg: &str,
+    bits: u32,
+    signed: bool,
+    from_str_radix: fn(&str, u32) -> Result<T, std::num::ParseIntError>,
+) -> Result<T, IntspectorError> {
+    let (negative, unsigned) = match arg.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, arg.strip_prefix('+').unwrap_or(arg)),
+    };
+    if negative && !signed {
+        return Err(IntspectorError::Negative);
+    }
+    if let Some(sign) = unsigned.chars().next().filter(|c| *c == '-' || *c == '+') {
+        return Err(IntspectorError::InvalidDigit { digit: sign, radix: 10 });
+    }
+    let (radix, digits) = match unsigned.split_once(':') {
+        Some((base, digits)) if !base.is_empty() && base.chars().all(|c| c.is_ascii_digit()) => {
+            match base.parse::<u32>() {
+                Ok(radix @ 2..=36) => (radix, digits),
+                _ => return Err(IntspectorError::UnsupportedBase(base.to_string())),
+            }
+        },
+        _ => split_radix(unsigned).ok_or(IntspectorError::Empty)?,
+    };
+    let digits = strip_separators(digits, radix)?;
+    let literal = if negative { format!("-{}", digits) } else { digits.clone() };
+    from_str_radix(&literal, radix).map_err(|_| {
+        match digits.chars().find(|c| !c.is_digit(radix)) {
+            Some(digit) => IntspectorError::InvalidDigit { digit, radix },
+            None if digits.is_empty() => IntspectorError::Empty,
+            None => IntspectorError::Overflow { bits, signed },
+        }
+    })
+}
+
+
+// Removes digit separators from a string of digits: underscores, as in Rust, apostrophes, as in
+// C++, and commas or spaces, as in written numbers. A separator must sit between two digits.
+fn strip_separators(digits: &str, radix: u32) -> Result<String, IntspectorError> {
+    let is_separator = |c: &char| "_', ".contains(*c);
+    let is_digit = |c: Option<&char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+    let chars: Vec<char> = digits.chars().collect();
+    let mut stripped = String::with_capacity(digits.len());
+    for (i, c) in chars.iter().enumerate() {
+        if !is_separator(c) {
+            stripped.push(*c);
+            continue;
+        }
+        let before = chars[..i].iter().rev().find(|c| !is_separator(c));
+        let after = chars[i + 1..].iter().find(|c| !is_separator(c));
+        if !is_digit(before) || !is_digit(after) {
+            return Err(IntspectorError::InvalidDigit { digit: *c, radix });
+        }
+    }
+    Ok(stripped)
 }
 
 
-/// Attempts to parse the string as a binary, octal, decimal, or hex integer.
-pub fn parse_int(arg: &str) -> Option<i64> {
+// Strips leading zeros and any base prefix from the string, returning the radix and the string
+// of digits to be parsed. A string consisting only of zeros is returned as the digit "0".
+fn split_radix(arg: &str) -> Option<(u32, &str)> {
     if arg.is_empty() {
         return None;
     }
 
     let mut trimmed = arg.trim_start_matches('0');
     if trimmed.is_empty() {
-        return Some(0);
+        return Some((10, "0"));
     }
 
     let mut radix: u32 = 10;
@@ -111,17 +628,165 @@ pub fn parse_int(arg: &str) -> Option<i64> {
         trimmed = trimmed.trim_start_matches('x');
     }
 
-    match i64::from_str_radix(trimmed, radix) {
-        Ok(value) => Some(value),
-        Err(_) => None,
+    Some((radix, trimmed))
+}
+
+
+/// Evaluates an integer expression, e.g. `(x3C | 0o17) + 1`. Literals are parsed as by
+/// `parse_int()`. Supports the binary operators `* / % + - << >> & ^ |`, with their C
+/// precedence, the unary operators `-` and `~`, and parentheses. Arithmetic is on signed 128-bit
+/// integers; overflow, division by zero, and shifting out significant bits are errors.
+pub fn eval_expr(expr: &str) -> Result<i128, IntspectorError> {
+    eval_expr_with(expr, &HashMap::new())
+}
+
+
+/// Evaluates an integer expression as `eval_expr()` does, looking up names in `vars` before
+/// parsing them as literals, e.g. `_ + 1`.
+pub fn eval_expr_with(expr: &str, vars: &HashMap<String, i128>) -> Result<i128, IntspectorError> {
+    let tokens = tokenize_expr(expr)?;
+    let mut pos = 0;
+    let value = eval_binary(&tokens, &mut pos, 1, vars)?;
+    match tokens.get(pos) {
+        None => Ok(value),
+        Some(token) => Err(IntspectorError::UnexpectedToken(token.clone())),
+    }
+}
+
+
+// Splits an expression into literals, operators, and parentheses.
+fn tokenize_expr(expr: &str) -> Result<Vec<String>, IntspectorError> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        if c.is_ascii_alphanumeric() || c == '_' {
+            let mut literal = c.to_string();
+            while let Some(&next) = chars.peek() {
+                if !next.is_ascii_alphanumeric() && next != '_' && next != ':' {
+                    break;
+                }
+                literal.push(next);
+                chars.next();
+            }
+            tokens.push(literal);
+        } else if c == '<' || c == '>' {
+            if chars.next() != Some(c) {
+                return Err(IntspectorError::UnexpectedChar(c));
+            }
+            tokens.push(format!("{}{}", c, c));
+        } else if "+-*/%&^|~()".contains(c) {
+            tokens.push(c.to_string());
+        } else {
+            return Err(IntspectorError::UnexpectedChar(c));
+        }
     }
+    Ok(tokens)
+}
+
+
+// Returns the precedence of a binary operator, from 1 (lowest) to 6.
+fn binary_precedence(op: &str) -> Option<u32> {
+    match op {
+        "|" => Some(1),
+        "^" => Some(2),
+        "&" => Some(3),
+        "<<" | ">>" => Some(4),
+        "+" | "-" => Some(5),
+        "*" | "/" | "%" => Some(6),
+        _ => None,
+    }
+}
+
+
+// Evaluates a sequence of binary operations whose operators have at least the specified
+// precedence, using precedence climbing.
+fn eval_binary(
+    tokens: &[String],
+    pos: &mut usize,
+    min_prec: u32,
+    vars: &HashMap<String, i128>,
+) -> Result<i128, IntspectorError> {
+    let mut lhs = eval_unary(tokens, pos, vars)?;
+    while let Some(op) = tokens.get(*pos) {
+        let prec = match binary_precedence(op) {
+            Some(prec) if prec >= min_prec => prec,
+            _ => break,
+        };
+        *pos += 1;
+        let rhs = eval_binary(tokens, pos, prec + 1, vars)?;
+        lhs = apply_binary(op, lhs, rhs)?;
+    }
+    Ok(lhs)
+}
+
+
+// Evaluates a variable, a literal, a parenthesized expression, or a unary operator applied to
+// any of these.
+fn eval_unary(
+    tokens: &[String],
+    pos: &mut usize,
+    vars: &HashMap<String, i128>,
+) -> Result<i128, IntspectorError> {
+    let token = tokens.get(*pos).ok_or(IntspectorError::UnexpectedEnd)?;
+    *pos += 1;
+    match token.as_str() {
+        "-" => {
+            let value = eval_unary(tokens, pos, vars)?;
+            value.checked_neg().ok_or_else(|| IntspectorError::ArithmeticOverflow("-".to_string()))
+        },
+        "~" => Ok(!eval_unary(tokens, pos, vars)?),
+        "(" => {
+            let value = eval_binary(tokens, pos, 1, vars)?;
+            if tokens.get(*pos).map(String::as_str) != Some(")") {
+                return Err(IntspectorError::MissingParen);
+            }
+            *pos += 1;
+            Ok(value)
+        },
+        _ if binary_precedence(token).is_some() || token == ")" => {
+            Err(IntspectorError::UnexpectedToken(token.clone()))
+        },
+        _ => match vars.get(token) {
+            Some(value) => Ok(*value),
+            None => parse_int(token),
+        },
+    }
+}
+
+
+// Applies a binary operator.
+fn apply_binary(op: &str, lhs: i128, rhs: i128) -> Result<i128, IntspectorError> {
+    let shift = || match rhs {
+        0..=127 => Ok(rhs as u32),
+        _ => Err(IntspectorError::ShiftOutOfRange),
+    };
+    let result = match op {
+        "|" => Some(lhs | rhs),
+        "^" => Some(lhs ^ rhs),
+        "&" => Some(lhs & rhs),
+        "<<" => {
+            let shifted = lhs << shift()?;
+            Some(shifted).filter(|shifted| shifted >> rhs == lhs)
+        },
+        ">>" => Some(lhs >> shift()?),
+        "+" => lhs.checked_add(rhs),
+        "-" => lhs.checked_sub(rhs),
+        "*" => lhs.checked_mul(rhs),
+        "/" | "%" if rhs == 0 => return Err(IntspectorError::DivisionByZero),
+        "/" => lhs.checked_div(rhs),
+        _ => lhs.checked_rem(rhs),
+    };
+    result.ok_or_else(|| IntspectorError::ArithmeticOverflow(op.to_string()))
 }
 
 
 /// If `value` is a valid ASCII code, returns a string representation - either the character itself
 /// or a description if the character is in the unprintable range.
-pub fn ascii(value: i64) -> Option<String> {
-    if value < 0 || value > 127 {
+pub fn ascii(value: i128) -> Option<String> {
+    if !(0..=127).contains(&value) {
         return None;
     }
     if value > 32 && value < 127 {
@@ -167,3 +832,337 @@ pub fn ascii(value: i64) -> Option<String> {
     Some(name.to_string())
 }
 
+
+/// Returns the UTF-8 encoding of a character, from 1 to 4 bytes.
+pub fn utf8_bytes(c: char) -> Vec<u8> {
+    let mut buffer = [0; 4];
+    c.encode_utf8(&mut buffer).as_bytes().to_vec()
+}
+
+
+/// Returns the UTF-16 encoding of a character: a single code unit, or a surrogate pair for a
+/// character outside the Basic Multilingual Plane.
+pub fn utf16_units(c: char) -> Vec<u16> {
+    let mut buffer = [0; 2];
+    c.encode_utf16(&mut buffer).to_vec()
+}
+
+
+/// The properties of an integer that the default command displays, computed once so they can be
+/// rendered as text or JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntInfo {
+    /// The integer itself.
+    pub value: i128,
+    /// The minimum number of bits required to represent the integer, as returned by min_bits().
+    pub min_bits: u32,
+    /// The minimum bits rounded up to a standard integer size, as returned by std_bits().
+    pub std_bits: u32,
+    /// The width of the displayed bit pattern.
+    pub num_bits: u32,
+    /// The displayed bit pattern: the value itself if it's non-negative, or its two's complement
+    /// if it's negative.
+    pub bits: u128,
+    /// The two's complement bit pattern, for negative values only.
+    pub twos_complement: Option<u128>,
+    /// The bit pattern in hexadecimal, using uppercase digits.
+    pub hex: String,
+    /// The bit pattern in decimal.
+    pub dec: String,
+    /// The bit pattern in octal.
+    pub oct: String,
+    /// The bit pattern in binary, zero-padded to `num_bits` digits.
+    pub bin: String,
+    /// The ASCII description, as returned by ascii().
+    pub ascii: Option<String>,
+}
+
+
+/// Analyzes an integer as a bit pattern of the specified width. The width defaults to the
+/// minimum number of bits for non-negative values and the standard integer size for negative
+/// values, which are shown in two's complement. Returns an error if the width is 0, above 128, or
+/// too narrow for the value.
+pub fn analyze(value: i128, bits: Option<u32>) -> Result<IntInfo, IntspectorError> {
+    let min_bits = min_bits(value);
+    let std_bits = std_bits(value);
+    let num_bits = bits.unwrap_or(if value >= 0 { min_bits } else { std_bits });
+    if num_bits == 0 || num_bits > 128 {
+        return Err(IntspectorError::UnsupportedBits(num_bits));
+    }
+    if num_bits < min_bits {
+        return Err(IntspectorError::TooFewBits { value, min_bits });
+    }
+    let twos = match value {
+        0.. => None,
+        _ => Some(twos_complement(value.unsigned_abs(), num_bits)),
+    };
+    let pattern = twos.unwrap_or(value as u128);
+    Ok(IntInfo {
+        value,
+        min_bits,
+        std_bits,
+        num_bits,
+        bits: pattern,
+        twos_complement: twos,
+        hex: format!("{:X}", pattern),
+        dec: pattern.to_string(),
+        oct: format!("{:o}", pattern),
+        bin: format!("{:0width$b}", pattern, width = num_bits as usize),
+        ascii: ascii(value),
+    })
+}
+
+
+/// Returns the natural alignment of `value`, i.e. the largest power of two that divides it.
+/// Returns `None` for zero, which is aligned to every power of two.
+pub fn alignment(value: u64) -> Option<u64> {
+    if value == 0 {
+        None
+    } else {
+        Some(1 << value.trailing_zeros())
+    }
+}
+
+
+/// Returns true if `value` is a power of two. Zero is not.
+pub fn is_power_of_two(value: u128) -> bool {
+    value.is_power_of_two()
+}
+
+
+/// Returns the smallest power of two greater than or equal to `value`, or `None` if it doesn't
+/// fit in 128 bits.
+pub fn next_power_of_two(value: u128) -> Option<u128> {
+    value.checked_next_power_of_two()
+}
+
+
+/// Returns the largest power of two less than or equal to `value`, or `None` for zero.
+pub fn prev_power_of_two(value: u128) -> Option<u128> {
+    if value == 0 {
+        None
+    } else {
+        Some(1 << (127 - value.leading_zeros()))
+    }
+}
+
+
+/// Returns the largest power of two that divides every value, i.e. the alignment they all share.
+/// Returns `None` if the values are all zero.
+pub fn common_alignment(values: &[u128]) -> Option<u128> {
+    match values.iter().fold(0, |acc, value| acc | value) {
+        0 => None,
+        bits => Some(1 << bits.trailing_zeros()),
+    }
+}
+
+
+/// Returns the greatest common divisor of the differences between the values, i.e. the largest
+/// stride that steps from any of the values to any other. Returns 0 if the values are all equal.
+pub fn common_stride(values: &[u128]) -> u128 {
+    values.iter().fold(0, |acc, value| {
+        let diff = if *value > values[0] { value - values[0] } else { values[0] - value };
+        ratio::gcd_u128(acc, diff)
+    })
+}
+
+
+/// Returns the binary-reflected Gray code of `value`, in which consecutive integers differ in
+/// exactly one bit.
+pub fn to_gray(value: u64) -> u64 {
+    value ^ (value >> 1)
+}
+
+
+/// Returns the integer with the binary-reflected Gray code `gray`, the inverse of `to_gray()`.
+pub fn from_gray(mut gray: u64) -> u64 {
+    let mut shift = 1;
+    while shift < 64 {
+        gray ^= gray >> shift;
+        shift <<= 1;
+    }
+    gray
+}
+
+
+/// Encodes an integer as unsigned LEB128, the varint used by protobuf, WebAssembly, and DWARF:
+/// 7 bits per byte, least significant group first, with the high bit set on every byte but the
+/// last.
+pub fn encode_uleb128(mut value: u128) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let group = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(group);
+            return bytes;
+        }
+        bytes.push(group | 0x80);
+    }
+}
+
+
+/// Decodes an unsigned LEB128 integer from the start of `bytes`, returning the value and the
+/// number of bytes read. Returns `None` if the bytes end before the last byte of the integer or
+/// the value does not fit in 128 bits.
+pub fn decode_uleb128(bytes: &[u8]) -> Option<(u128, usize)> {
+    let mut value: u128 = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        let shift = index as u32 * 7;
+        let group = (byte & 0x7F) as u128;
+        if shift >= 128 || (shift > 121 && group >> (128 - shift) != 0) {
+            return None;
+        }
+        value |= group << shift;
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+    None
+}
+
+
+/// Returns the zigzag encoding of a signed integer, which maps 0, -1, 1, -2, ... to 0, 1, 2,
+/// 3, ... so that small negative values have short varint encodings, as for protobuf `sint`
+/// fields.
+pub fn zigzag_encode(value: i128) -> u128 {
+    ((value << 1) ^ (value >> 127)) as u128
+}
+
+
+/// Reverses the zigzag encoding, mapping 0, 1, 2, 3, ... back to 0, -1, 1, -2, ...
+pub fn zigzag_decode(value: u128) -> i128 {
+    (value >> 1) as i128 ^ -((value & 1) as i128)
+}
+
+
+/// Returns the factorial number system (factoradic) digits of `value`, least significant first.
+/// Digit `i` has weight `i!` and is at most `i`, so the first digit is always 0.
+pub fn factoradic(mut value: u128) -> Vec<u32> {
+    let mut digits = vec![0];
+    let mut radix = 2;
+    while value != 0 {
+        digits.push((value % radix) as u32);
+        value /= radix;
+        radix += 1;
+    }
+    digits
+}
+
+
+/// Returns the integer with the specified factoradic digits, least significant first. Returns
+/// `None` if digit `i` is greater than `i` or the value overflows 128 bits.
+pub fn from_factoradic(digits: &[u32]) -> Option<u128> {
+    let mut value: u128 = 0;
+    for (i, digit) in digits.iter().enumerate().rev() {
+        if *digit as usize > i {
+            return None;
+        }
+        value = value.checked_mul(i as u128 + 1)?.checked_add(*digit as u128)?;
+    }
+    Some(value)
+}
+
+
+/// Returns the permutation of `0..n` with the specified Lehmer code, where `n` is the length of
+/// the code. Each code digit, most significant first, is the index of the next element among
+/// those not yet used, so a factoradic number read most significant first is the Lehmer code of
+/// the permutation at that rank in lexicographic order. Returns `None` if a digit is out of
+/// range.
+pub fn lehmer_permutation(code: &[u32]) -> Option<Vec<u32>> {
+    let mut remaining: Vec<u32> = (0..code.len() as u32).collect();
+    let mut permutation = Vec::new();
+    for digit in code {
+        if *digit as usize >= remaining.len() {
+            return None;
+        }
+        permutation.push(remaining.remove(*digit as usize));
+    }
+    Some(permutation)
+}
+
+
+/// Returns the Lehmer code of a permutation of `0..n`, most significant first: for each element,
+/// the number of later elements that are smaller. Returns `None` if the input is not a
+/// permutation.
+pub fn permutation_lehmer(permutation: &[u32]) -> Option<Vec<u32>> {
+    let mut sorted = permutation.to_vec();
+    sorted.sort_unstable();
+    if sorted.iter().enumerate().any(|(i, element)| *element as usize != i) {
+        return None;
+    }
+    let code = permutation
+        .iter()
+        .enumerate()
+        .map(|(i, element)| permutation[i + 1..].iter().filter(|later| *later < element).count())
+        .map(|count| count as u32)
+        .collect();
+    Some(code)
+}
+
+
+/// Returns the digits of `value` in the specified radix, least significant first. Zero has the
+/// single digit 0. Will panic if the radix is less than 2.
+pub fn radix_digits(mut value: u128, radix: u32) -> Vec<u32> {
+    assert!(radix >= 2);
+    let mut digits = vec![(value % radix as u128) as u32];
+    value /= radix as u128;
+    while value != 0 {
+        digits.push((value % radix as u128) as u32);
+        value /= radix as u128;
+    }
+    digits
+}
+
+
+/// Returns the digits of `value` in a mixed radix system, least significant first. Digit `i` is
+/// taken modulo `radices[i]` and the final digit, one past the end of `radices`, holds the rest of
+/// the value, so the result always has `radices.len() + 1` digits.
+pub fn mixed_radix_digits(mut value: u128, radices: &[u32]) -> Vec<u128> {
+    let mut digits = Vec::with_capacity(radices.len() + 1);
+    for radix in radices {
+        digits.push(value % *radix as u128);
+        value /= *radix as u128;
+    }
+    digits.push(value);
+    digits
+}
+
+
+/// Renders a sexagesimal digit, 0-59, as Babylonian cuneiform: a wedge for each ten (𒌋)
+/// followed by a wedge for each unit (𒁹). The Babylonians left a gap for zero; it's rendered
+/// as `_`.
+pub fn babylonian_digit(digit: u32) -> String {
+    if digit == 0 {
+        return "_".to_string();
+    }
+    let tens = "\u{1230B}".repeat(digit as usize / 10);
+    tens + &"\u{12079}".repeat(digit as usize % 10)
+}
+
+
+/// Returns the Unicode Mayan numeral for a vigesimal digit, 0-19, e.g. `𝋠` for zero.
+pub fn maya_digit(digit: u32) -> Option<char> {
+    if digit < 20 {
+        std::char::from_u32(0x1D2E0 + digit)
+    } else {
+        None
+    }
+}
+
+
+/// Returns the Shannon entropy in bits per symbol of a distribution given as symbol counts.
+/// Symbols with a count of zero are ignored. Returns 0 for an empty distribution.
+pub fn shannon_entropy(counts: &[u64]) -> f64 {
+    let total: f64 = counts.iter().map(|count| *count as f64).sum();
+    let entropy: f64 = counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let p = *count as f64 / total;
+            -p * p.log2()
+        })
+        .sum();
+    // A single symbol gives -0.0, which would print with a minus sign.
+    entropy + 0.0
+}
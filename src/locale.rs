@@ -0,0 +1,269 @@
+// Translations of the info block labels and the help text headings. The locale is decided once at
+// startup from the --lang argument, which works with any command, or the INTSPECTOR_LANG
+// environment variable. Only the labels and headings of text and Markdown output are translated:
+// the values, e.g. `3 set bits`, and the error messages stay in English, and JSON and CSV keep the
+// English labels so scripts don't depend on the locale.
+//
+// Most labels are translated for the commands they belong to, as the same short label can mean
+// different things in different commands, e.g. `cnt` is the number of values in --stats but the
+// counter field of an ObjectId. The labels of the integer info block, which many commands
+// append to their own fields, mean the same everywhere and are translated for every command.
+
+pub struct Locale {
+    pub code: &'static str,
+    // Replaces `Error` at the start of error lines.
+    pub error: &'static str,
+    // The integer info block labels and the headings of Markdown field tables, paired with their
+    // translations.
+    pub labels: &'static [(&'static str, &'static str)],
+    // The labels of the commands' own fields, keyed by the names of the commands they belong to,
+    // with `intspector` for the default command.
+    pub commands: &'static [(&'static str, &'static [(&'static str, &'static str)])],
+    // Help text headings and stock phrases, paired with their translations.
+    pub help: &'static [(&'static str, &'static str)],
+}
+
+
+pub const LOCALES: &[Locale] = &[
+    Locale {
+        code: "en",
+        error: "Error",
+        labels: &[],
+        commands: &[],
+        help: &[],
+    },
+    Locale {
+        code: "de",
+        error: "Fehler",
+        labels: &[
+            ("req", "Breite"),
+            ("hex", "Hex"),
+            ("dec", "Dezimal"),
+            ("oct", "Oktal"),
+            ("bin", "Binär"),
+            ("pop", "Bitzahl"),
+            ("asc", "ASCII"),
+            ("pw2", "Zweierpotenz"),
+            ("lg2", "Log2"),
+            ("aln", "Ausrichtung"),
+            ("hnt", "Hinweis"),
+            ("psn", "Giftwert"),
+            ("Field", "Feld"),
+            ("Value", "Wert"),
+        ],
+        commands: &[
+            (
+                "intspector",
+                &[
+                    ("cnt", "Anzahl"),
+                    ("min", "Minimum"),
+                    ("max", "Maximum"),
+                    ("avg", "Mittelwert"),
+                    ("med", "Median"),
+                ],
+            ),
+            ("asn1 check checksum crc entropy hash", &[("len", "Länge")]),
+            ("bit decimal egyptian elias factor float ident rice sb time", &[("val", "Wert")]),
+            ("barcode diff elias rice runs shift utf8", &[("cnt", "Anzahl")]),
+            ("checksum ident", &[("mat", "Treffer")]),
+            ("checksum nbits", &[("tgt", "Ziel")]),
+            ("crc", &[("alg", "Algorithmus")]),
+            ("factor", &[("fac", "Faktoren"), ("pri", "Primzahl"), ("div", "Teiler")]),
+            ("factoradic", &[("fac", "Fakultätsbasis")]),
+            ("hash", &[("key", "Schlüssel"), ("bkt", "Bucket")]),
+            ("huffman", &[("avg", "Mittelwert")]),
+            ("objectid", &[("cnt", "Zähler")]),
+            ("sb", &[("med", "Medianten")]),
+        ],
+        help: &[
+            ("Usage:", "Aufruf:"),
+            ("Arguments:", "Argumente:"),
+            ("Options:", "Optionen:"),
+            ("Flags:", "Schalter:"),
+            ("Commands:", "Befehle:"),
+            ("Command Help:", "Hilfe zu Befehlen:"),
+            ("Plugins:", "Erweiterungen:"),
+            ("Print this help text.", "Diese Hilfe anzeigen."),
+            ("Print the application's version number.", "Die Versionsnummer anzeigen."),
+        ],
+    },
+    Locale {
+        code: "es",
+        error: "Error",
+        labels: &[
+            ("req", "Ancho"),
+            ("hex", "Hex"),
+            ("dec", "Decimal"),
+            ("oct", "Octal"),
+            ("bin", "Binario"),
+            ("pop", "Unos"),
+            ("asc", "ASCII"),
+            ("pw2", "Potencia2"),
+            ("lg2", "Log2"),
+            ("aln", "Alineación"),
+            ("hnt", "Pista"),
+            ("psn", "Veneno"),
+            ("Field", "Campo"),
+            ("Value", "Valor"),
+        ],
+        commands: &[
+            (
+                "intspector",
+                &[
+                    ("cnt", "Cuenta"),
+                    ("min", "Mínimo"),
+                    ("max", "Máximo"),
+                    ("avg", "Media"),
+                    ("med", "Mediana"),
+                ],
+            ),
+            ("asn1 check checksum crc entropy hash", &[("len", "Longitud")]),
+            ("bit decimal egyptian elias factor float ident rice sb time", &[("val", "Valor")]),
+            ("barcode diff elias rice runs shift utf8", &[("cnt", "Cuenta")]),
+            ("checksum ident", &[("mat", "Coincidencia")]),
+            ("checksum nbits", &[("tgt", "Objetivo")]),
+            ("crc", &[("alg", "Algoritmo")]),
+            ("factor", &[("fac", "Factores"), ("pri", "Primo"), ("div", "Divisores")]),
+            ("factoradic", &[("fac", "Base factorial")]),
+            ("hash", &[("key", "Clave"), ("bkt", "Cubeta")]),
+            ("huffman", &[("avg", "Media")]),
+            ("objectid", &[("cnt", "Contador")]),
+            ("sb", &[("med", "Mediantes")]),
+        ],
+        help: &[
+            ("Usage:", "Uso:"),
+            ("Arguments:", "Argumentos:"),
+            ("Options:", "Opciones:"),
+            ("Flags:", "Indicadores:"),
+            ("Commands:", "Comandos:"),
+            ("Command Help:", "Ayuda de comandos:"),
+            ("Plugins:", "Complementos:"),
+            ("Print this help text.", "Muestra esta ayuda."),
+            ("Print the application's version number.", "Muestra el número de versión."),
+        ],
+    },
+    Locale {
+        code: "fr",
+        error: "Erreur",
+        labels: &[
+            ("req", "Largeur"),
+            ("hex", "Hex"),
+            ("dec", "Décimal"),
+            ("oct", "Octal"),
+            ("bin", "Binaire"),
+            ("pop", "Uns"),
+            ("asc", "ASCII"),
+            ("pw2", "Puissance2"),
+            ("lg2", "Log2"),
+            ("aln", "Alignement"),
+            ("hnt", "Indice"),
+            ("psn", "Poison"),
+            ("Field", "Champ"),
+            ("Value", "Valeur"),
+        ],
+        commands: &[
+            (
+                "intspector",
+                &[
+                    ("cnt", "Nombre"),
+                    ("min", "Minimum"),
+                    ("max", "Maximum"),
+                    ("avg", "Moyenne"),
+                    ("med", "Médiane"),
+                ],
+            ),
+            ("asn1 check checksum crc entropy hash", &[("len", "Longueur")]),
+            ("bit decimal egyptian elias factor float ident rice sb time", &[("val", "Valeur")]),
+            ("barcode diff elias rice runs shift utf8", &[("cnt", "Nombre")]),
+            ("checksum ident", &[("mat", "Correspondance")]),
+            ("checksum nbits", &[("tgt", "Cible")]),
+            ("crc", &[("alg", "Algorithme")]),
+            ("factor", &[("fac", "Facteurs"), ("pri", "Premier"), ("div", "Diviseurs")]),
+            ("factoradic", &[("fac", "Base factorielle")]),
+            ("hash", &[("key", "Clé"), ("bkt", "Alvéole")]),
+            ("huffman", &[("avg", "Moyenne")]),
+            ("objectid", &[("cnt", "Compteur")]),
+            ("sb", &[("med", "Médiantes")]),
+        ],
+        help: &[
+            ("Usage:", "Utilisation:"),
+            ("Arguments:", "Arguments:"),
+            ("Options:", "Options:"),
+            ("Flags:", "Indicateurs:"),
+            ("Commands:", "Commandes:"),
+            ("Command Help:", "Aide des commandes:"),
+            ("Plugins:", "Extensions:"),
+            ("Print this help text.", "Affiche cette aide."),
+            ("Print the application's version number.", "Affiche le numéro de version."),
+        ],
+    },
+];
+
+
+// Returns the locale for the --lang argument, or else for `env_lang`, the INTSPECTOR_LANG
+// environment variable, e.g. `de` or `de_DE.UTF-8`. An unknown --lang is an error, but an unknown
+// INTSPECTOR_LANG, which may be set for other programs, only returns a warning with English.
+pub fn select(
+    lang: Option<&str>,
+    env_lang: Option<&str>,
+) -> Result<(&'static Locale, Option<String>), String> {
+    match (lang, env_lang) {
+        (Some(lang), _) => Ok((find(lang)?, None)),
+        (None, Some(env_lang)) if !env_lang.is_empty() => match find(env_lang) {
+            Ok(locale) => Ok((locale, None)),
+            Err(msg) => Ok((&LOCALES[0], Some(format!("INTSPECTOR_LANG: {}", msg)))),
+        },
+        _ => Ok((&LOCALES[0], None)),
+    }
+}
+
+
+// Finds a locale by its language code, ignoring any territory or encoding suffix.
+fn find(lang: &str) -> Result<&'static Locale, String> {
+    let code = lang.split(['_', '-', '.']).next().unwrap_or(lang);
+    LOCALES.iter().find(|locale| locale.code.eq_ignore_ascii_case(code)).ok_or_else(|| {
+        let codes: Vec<&str> = LOCALES.iter().map(|locale| locale.code).collect();
+        format!("unknown language '{}', expected one of {}.", lang, codes.join(", "))
+    })
+}
+
+
+impl Locale {
+    pub fn is_english(&self) -> bool {
+        self.code == "en"
+    }
+
+
+    // Returns the translation of a label in the output of the named command, or the label itself
+    // if it has none.
+    pub fn label<'a>(&self, command: &str, label: &'a str) -> &'a str {
+        self.commands
+            .iter()
+            .filter(|(names, _)| names.split(' ').any(|name| name == command))
+            .flat_map(|(_, labels)| labels.iter())
+            .chain(self.labels)
+            .find(|(english, _)| *english == label)
+            .map_or(label, |(_, translated)| *translated)
+    }
+
+
+    // Translates the headings at the start of help text lines and the stock phrases at the end.
+    pub fn help(&self, text: &str) -> String {
+        if self.is_english() {
+            return text.to_string();
+        }
+        let lines: Vec<String> = text
+            .split('\n')
+            .map(|line| {
+                let mut line = line.to_string();
+                for (english, translated) in self.help {
+                    if line.trim_start().starts_with(english) || line.ends_with(english) {
+                        line = line.replacen(english, translated, 1);
+                    }
+                }
+                line
+            })
+            .collect();
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,71 @@
+//! Parsing and formatting of 48-bit IEEE 802 MAC addresses.
+
+
+/// Parses a MAC address written as six colon- or hyphen-separated octets, as three dot-separated
+/// groups of four hex digits (Cisco style), or as twelve bare hex digits. Also accepts any
+/// integer literal in the 48-bit range.
+pub fn parse(arg: &str) -> Option<u64> {
+    let digits: String = if arg.contains(':') || arg.contains('-') {
+        let octets: Vec<&str> = arg.split([':', '-']).collect();
+        if octets.len() != 6 || octets.iter().any(|o| o.is_empty() || o.len() > 2) {
+            return None;
+        }
+        octets.iter().map(|o| format!("{:0>2}", o)).collect()
+    } else if arg.contains('.') {
+        let groups: Vec<&str> = arg.split('.').collect();
+        if groups.len() != 3 || groups.iter().any(|g| g.len() != 4) {
+            return None;
+        }
+        groups.concat()
+    } else if arg.len() == 12 && arg.chars().all(|c| c.is_ascii_hexdigit()) {
+        arg.to_string()
+    } else {
+        return crate::parse_uint(arg).ok().filter(|value| *value < 1 << 48);
+    };
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u64::from_str_radix(&digits, 16).ok()
+}
+
+
+/// Formats a MAC address as six octets separated by `sep`, e.g. `00:1A:2B:3C:4D:5E`.
+pub fn format(addr: u64, sep: char) -> String {
+    (0..6)
+        .rev()
+        .map(|i| format!("{:02X}", (addr >> (i * 8)) & 0xFF))
+        .collect::<Vec<String>>()
+        .join(&sep.to_string())
+}
+
+
+/// Formats a MAC address as three dot-separated groups of four hex digits, e.g. `001a.2b3c.4d5e`.
+pub fn format_cisco(addr: u64) -> String {
+    (0..3)
+        .rev()
+        .map(|i| format!("{:04x}", (addr >> (i * 16)) & 0xFFFF))
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+
+/// Returns true if the address is a group (multicast) address, i.e. if the I/G bit is set.
+pub fn is_multicast(addr: u64) -> bool {
+    (addr >> 40) & 0x01 == 1
+}
+
+
+/// Returns true if the address is locally administered, i.e. if the U/L bit is set.
+pub fn is_local(addr: u64) -> bool {
+    (addr >> 40) & 0x02 == 2
+}
+
+
+/// Returns the modified EUI-64 interface identifier derived from the address, as used for IPv6
+/// stateless address autoconfiguration: `FF:FE` is inserted in the middle and the U/L bit is
+/// inverted.
+pub fn modified_eui64(addr: u64) -> u64 {
+    let oui = addr >> 24;
+    let nic = addr & 0xFF_FFFF;
+    ((oui << 40) | (0xFFFE << 24) | nic) ^ (0x02 << 56)
+}
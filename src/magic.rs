@@ -0,0 +1,541 @@
+//! A database of well-known magic constants - hash and PRNG constants, file signatures, byte
+//! order marks, and special float and integer values - for naming an unknown number.
+
+use crate::checksum::CRC_PRESETS;
+
+
+/// A well-known constant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constant {
+    pub value: u64,
+    /// The size of the constant in bytes, as it's usually stored.
+    pub size: u32,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+
+/// The constants, grouped by kind. CRC polynomials aren't listed here as they're generated from
+/// the CRC presets, see `identify()`.
+pub const CONSTANTS: &[Constant] = &[
+    // Bit hacks.
+    Constant {
+        value: 0x5F3759DF,
+        size: 4,
+        name: "fast inverse square root",
+        description: "Quake III's magic number for approximating 1/sqrt(x) with f32 bits",
+    },
+    Constant {
+        value: 0x5FE6EB50C7B537A9,
+        size: 8,
+        name: "fast inverse square root (f64)",
+        description: "the f64 counterpart of 0x5F3759DF",
+    },
+    // Hash functions and PRNGs.
+    Constant {
+        value: 0x9E3779B9,
+        size: 4,
+        name: "golden ratio (32-bit)",
+        description: "2^32/φ, used by TEA, boost::hash_combine, and Fibonacci hashing",
+    },
+    Constant {
+        value: 0x9E3779B97F4A7C15,
+        size: 8,
+        name: "golden ratio (64-bit)",
+        description: "2^64/φ, the SplitMix64 increment, also used for Fibonacci hashing",
+    },
+    Constant {
+        value: 0xBF58476D1CE4E5B9,
+        size: 8,
+        name: "SplitMix64 multiplier 1",
+        description: "the first multiplier of the SplitMix64 and MurmurHash3-style mixer",
+    },
+    Constant {
+        value: 0x94D049BB133111EB,
+        size: 8,
+        name: "SplitMix64 multiplier 2",
+        description: "the second multiplier of the SplitMix64 mixer",
+    },
+    Constant {
+        value: 0x811C9DC5,
+        size: 4,
+        name: "FNV offset basis (32-bit)",
+        description: "the initial hash value of 32-bit FNV-1 and FNV-1a",
+    },
+    Constant {
+        value: 0x01000193,
+        size: 4,
+        name: "FNV prime (32-bit)",
+        description: "the multiplier of 32-bit FNV-1 and FNV-1a, 16777619",
+    },
+    Constant {
+        value: 0xCBF29CE484222325,
+        size: 8,
+        name: "FNV offset basis (64-bit)",
+        description: "the initial hash value of 64-bit FNV-1 and FNV-1a",
+    },
+    Constant {
+        value: 0x00000100000001B3,
+        size: 8,
+        name: "FNV prime (64-bit)",
+        description: "the multiplier of 64-bit FNV-1 and FNV-1a, 2^40 + 2^8 + 0xB3",
+    },
+    Constant {
+        value: 0xCC9E2D51,
+        size: 4,
+        name: "MurmurHash3 c1",
+        description: "the first block multiplier of 32-bit MurmurHash3",
+    },
+    Constant {
+        value: 0x1B873593,
+        size: 4,
+        name: "MurmurHash3 c2",
+        description: "the second block multiplier of 32-bit MurmurHash3",
+    },
+    Constant {
+        value: 0x85EBCA6B,
+        size: 4,
+        name: "MurmurHash3 fmix32 multiplier 1",
+        description: "the first multiplier of MurmurHash3's 32-bit finalizer",
+    },
+    Constant {
+        value: 0xC2B2AE35,
+        size: 4,
+        name: "MurmurHash3 fmix32 multiplier 2",
+        description: "the second multiplier of MurmurHash3's 32-bit finalizer",
+    },
+    Constant {
+        value: 0x87C37B91114253D5,
+        size: 8,
+        name: "MurmurHash3 x64 c1",
+        description: "the first block multiplier of 128-bit MurmurHash3 for x64",
+    },
+    Constant {
+        value: 0x4CF5AD432745937F,
+        size: 8,
+        name: "MurmurHash3 x64 c2",
+        description: "the second block multiplier of 128-bit MurmurHash3 for x64",
+    },
+    Constant {
+        value: 0x9E3779B1,
+        size: 4,
+        name: "xxHash32 PRIME32_1",
+        description: "the first prime of xxHash32, close to 2^32/φ",
+    },
+    Constant {
+        value: 0x85EBCA77,
+        size: 4,
+        name: "xxHash32 PRIME32_2",
+        description: "the second prime of xxHash32",
+    },
+    Constant {
+        value: 0xC2B2AE3D,
+        size: 4,
+        name: "xxHash32 PRIME32_3",
+        description: "the third prime of xxHash32",
+    },
+    Constant {
+        value: 0x9E3779B185EBCA87,
+        size: 8,
+        name: "xxHash64 PRIME64_1",
+        description: "the first prime of xxHash64",
+    },
+    Constant {
+        value: 0x67452301,
+        size: 4,
+        name: "MD5/SHA-1 initial A",
+        description: "the first word of the MD5 and SHA-1 initial state",
+    },
+    Constant {
+        value: 0xEFCDAB89,
+        size: 4,
+        name: "MD5/SHA-1 initial B",
+        description: "the second word of the MD5 and SHA-1 initial state",
+    },
+    Constant {
+        value: 0x98BADCFE,
+        size: 4,
+        name: "MD5/SHA-1 initial C",
+        description: "the third word of the MD5 and SHA-1 initial state",
+    },
+    Constant {
+        value: 0x10325476,
+        size: 4,
+        name: "MD5/SHA-1 initial D",
+        description: "the fourth word of the MD5 and SHA-1 initial state",
+    },
+    Constant {
+        value: 0xC3D2E1F0,
+        size: 4,
+        name: "SHA-1 initial E",
+        description: "the fifth word of the SHA-1 initial state",
+    },
+    Constant {
+        value: 0x6A09E667,
+        size: 4,
+        name: "SHA-256 initial H0",
+        description: "the first word of the SHA-256 initial state, from the square root of 2",
+    },
+    Constant {
+        value: 0x6A09E667F3BCC908,
+        size: 8,
+        name: "SHA-512 initial H0",
+        description: "the first word of the SHA-512 initial state, from the square root of 2",
+    },
+    Constant {
+        value: 0x428A2F98,
+        size: 4,
+        name: "SHA-256 K[0]",
+        description: "the first SHA-256 round constant, from the cube root of 2",
+    },
+    Constant {
+        value: 0x5851F42D4C957F2D,
+        size: 8,
+        name: "PCG/Knuth MMIX multiplier",
+        description: "the 64-bit LCG multiplier of PCG and Knuth's MMIX, 6364136223846793005",
+    },
+    Constant {
+        value: 0x41C64E6D,
+        size: 4,
+        name: "ANSI C rand() multiplier",
+        description: "the LCG multiplier 1103515245 of the C standard's sample rand() and glibc",
+    },
+    Constant {
+        value: 0x0019660D,
+        size: 4,
+        name: "Numerical Recipes LCG multiplier",
+        description: "the LCG multiplier 1664525 from Numerical Recipes",
+    },
+    Constant {
+        value: 0x000343FD,
+        size: 4,
+        name: "MSVC rand() multiplier",
+        description: "the LCG multiplier 214013 of the Microsoft C runtime's rand()",
+    },
+    Constant {
+        value: 0x9908B0DF,
+        size: 4,
+        name: "Mersenne Twister matrix A",
+        description: "the twist matrix coefficient of MT19937",
+    },
+    Constant {
+        value: 0x6C078965,
+        size: 4,
+        name: "Mersenne Twister initialization multiplier",
+        description: "the multiplier 1812433253 used to seed the MT19937 state",
+    },
+    // Byte order marks.
+    Constant {
+        value: 0xEFBBBF,
+        size: 3,
+        name: "UTF-8 BOM",
+        description: "the byte order mark U+FEFF encoded as UTF-8",
+    },
+    Constant {
+        value: 0xFEFF,
+        size: 2,
+        name: "UTF-16BE BOM",
+        description: "the byte order mark U+FEFF as big-endian UTF-16",
+    },
+    Constant {
+        value: 0xFFFE,
+        size: 2,
+        name: "UTF-16LE BOM",
+        description: "the byte order mark U+FEFF as little-endian UTF-16",
+    },
+    Constant {
+        value: 0x0000FEFF,
+        size: 4,
+        name: "UTF-32BE BOM",
+        description: "the byte order mark U+FEFF as big-endian UTF-32",
+    },
+    Constant {
+        value: 0xFFFE0000,
+        size: 4,
+        name: "UTF-32LE BOM",
+        description: "the byte order mark U+FEFF as little-endian UTF-32",
+    },
+    // File and format signatures, as the big-endian value of their leading bytes.
+    Constant {
+        value: 0x7F454C46,
+        size: 4,
+        name: "ELF magic",
+        description: "the signature \\x7FELF at the start of ELF executables and objects",
+    },
+    Constant {
+        value: 0xCAFEBABE,
+        size: 4,
+        name: "Java class / Mach-O fat binary magic",
+        description: "the signature of Java class files and universal Mach-O binaries",
+    },
+    Constant {
+        value: 0xFEEDFACE,
+        size: 4,
+        name: "Mach-O magic (32-bit)",
+        description: "the signature of 32-bit Mach-O binaries",
+    },
+    Constant {
+        value: 0xFEEDFACF,
+        size: 4,
+        name: "Mach-O magic (64-bit)",
+        description: "the signature of 64-bit Mach-O binaries",
+    },
+    Constant {
+        value: 0x4D5A,
+        size: 2,
+        name: "MZ magic",
+        description: "the signature MZ at the start of DOS and Windows PE executables",
+    },
+    Constant {
+        value: 0x0061736D,
+        size: 4,
+        name: "WebAssembly magic",
+        description: "the signature \\0asm at the start of WebAssembly modules",
+    },
+    Constant {
+        value: 0x89504E47,
+        size: 4,
+        name: "PNG magic",
+        description: "the first four bytes of the PNG signature, \\x89PNG",
+    },
+    Constant {
+        value: 0xFFD8FF,
+        size: 3,
+        name: "JPEG magic",
+        description: "the start of image marker and first marker byte of JPEG files",
+    },
+    Constant {
+        value: 0x47494638,
+        size: 4,
+        name: "GIF magic",
+        description: "the signature GIF8 at the start of GIF files",
+    },
+    Constant {
+        value: 0x25504446,
+        size: 4,
+        name: "PDF magic",
+        description: "the signature %PDF at the start of PDF files",
+    },
+    Constant {
+        value: 0x504B0304,
+        size: 4,
+        name: "ZIP local file header",
+        description: "the signature PK\\x03\\x04 of ZIP archives, JARs, and Office documents",
+    },
+    Constant {
+        value: 0x1F8B,
+        size: 2,
+        name: "gzip magic",
+        description: "the signature at the start of gzip streams",
+    },
+    Constant {
+        value: 0x28B52FFD,
+        size: 4,
+        name: "Zstandard magic",
+        description: "the signature of Zstandard frames",
+    },
+    Constant {
+        value: 0x425A68,
+        size: 3,
+        name: "bzip2 magic",
+        description: "the signature BZh at the start of bzip2 streams",
+    },
+    Constant {
+        value: 0xFD377A585A00,
+        size: 6,
+        name: "xz magic",
+        description: "the signature \\xFD7zXZ\\0 at the start of xz streams",
+    },
+    Constant {
+        value: 0xD00DFEED,
+        size: 4,
+        name: "devicetree magic",
+        description: "the signature of flattened devicetree blobs",
+    },
+    Constant {
+        value: 0x1BADB002,
+        size: 4,
+        name: "Multiboot header magic",
+        description: "the magic field of a Multiboot header in an OS kernel image",
+    },
+    Constant {
+        value: 0x2BADB002,
+        size: 4,
+        name: "Multiboot bootloader magic",
+        description: "the value a Multiboot bootloader passes to the kernel in EAX",
+    },
+    Constant {
+        value: 0xAA55,
+        size: 2,
+        name: "MBR boot signature",
+        description: "the bytes 0x55 0xAA that end a boot sector, read as a little-endian u16",
+    },
+    Constant {
+        value: 0xEF53,
+        size: 2,
+        name: "ext2/3/4 superblock magic",
+        description: "the s_magic field of ext2, ext3, and ext4 superblocks",
+    },
+    // Special float values.
+    Constant {
+        value: 0x3F800000,
+        size: 4,
+        name: "f32 1.0",
+        description: "the bits of 1.0 as an IEEE 754 single",
+    },
+    Constant {
+        value: 0x7F800000,
+        size: 4,
+        name: "f32 infinity",
+        description: "the bits of positive infinity as an IEEE 754 single",
+    },
+    Constant {
+        value: 0x7FC00000,
+        size: 4,
+        name: "f32 quiet NaN",
+        description: "the canonical quiet NaN as an IEEE 754 single",
+    },
+    Constant {
+        value: 0x3FF0000000000000,
+        size: 8,
+        name: "f64 1.0",
+        description: "the bits of 1.0 as an IEEE 754 double",
+    },
+    Constant {
+        value: 0x7FF0000000000000,
+        size: 8,
+        name: "f64 infinity",
+        description: "the bits of positive infinity as an IEEE 754 double",
+    },
+    Constant {
+        value: 0x7FF8000000000000,
+        size: 8,
+        name: "f64 quiet NaN",
+        description: "the canonical quiet NaN as an IEEE 754 double",
+    },
+    // Integer limits.
+    Constant {
+        value: 0x7FFFFFFF,
+        size: 4,
+        name: "i32::MAX",
+        description: "the largest signed 32-bit integer, 2^31 - 1, a Mersenne prime",
+    },
+    Constant {
+        value: 0x80000000,
+        size: 4,
+        name: "i32::MIN",
+        description: "the smallest signed 32-bit integer, -2^31",
+    },
+    Constant {
+        value: 0xFFFFFFFF,
+        size: 4,
+        name: "u32::MAX",
+        description: "the largest unsigned 32-bit integer, or -1 as a signed 32-bit integer",
+    },
+    Constant {
+        value: 0x7FFFFFFFFFFFFFFF,
+        size: 8,
+        name: "i64::MAX",
+        description: "the largest signed 64-bit integer, 2^63 - 1",
+    },
+    Constant {
+        value: 0x8000000000000000,
+        size: 8,
+        name: "i64::MIN",
+        description: "the smallest signed 64-bit integer, -2^63",
+    },
+    Constant {
+        value: 0xFFFFFFFFFFFFFFFF,
+        size: 8,
+        name: "u64::MAX",
+        description: "the largest unsigned 64-bit integer, or -1 as a signed 64-bit integer",
+    },
+];
+
+
+/// A constant matching a value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    pub name: String,
+    pub description: String,
+    /// True if the match is with the bytes of the value in reverse order, i.e. the constant was
+    /// stored with the other endianness.
+    pub swapped: bool,
+}
+
+
+/// Returns the constants matching `value`, including the CRC polynomials in their normal and
+/// reflected forms. Matches with the bytes of the value in reverse order are only tried if
+/// nothing matches exactly.
+pub fn identify(value: u64) -> Vec<Match> {
+    let exact = lookup(|_| Some(value));
+    if !exact.is_empty() {
+        return exact;
+    }
+    lookup(|size| reversed(value, size))
+        .into_iter()
+        .map(|found| Match { swapped: true, ..found })
+        .collect()
+}
+
+
+// Returns the value with its low `size` bytes in reverse order, or `None` if it doesn't fit in
+// `size` bytes or is a single byte.
+fn reversed(value: u64, size: u32) -> Option<u64> {
+    if size < 2 || (size < 8 && value >> (8 * size) != 0) {
+        return None;
+    }
+    Some(value.swap_bytes() >> (64 - 8 * size))
+}
+
+
+// Returns the constants whose value equals `key(size)`, where `size` is the constant's size.
+fn lookup(key: impl Fn(u32) -> Option<u64>) -> Vec<Match> {
+    let listed = CONSTANTS.iter().map(|constant| {
+        let found = Match {
+            name: constant.name.to_string(),
+            description: constant.description.to_string(),
+            swapped: false,
+        };
+        (constant.size, constant.value, found)
+    });
+    listed
+        .chain(crc_polynomials())
+        .filter(|(size, value, _)| key(*size) == Some(*value))
+        .map(|(_, _, found)| found)
+        .collect()
+}
+
+
+// Returns the distinct generator polynomials of the CRC presets, in normal form and in the
+// bit-reversed form used by implementations that process the least significant bit first, with
+// their sizes in bytes.
+fn crc_polynomials() -> Vec<(u32, u64, Match)> {
+    let mut polys: Vec<(u32, u64)> = Vec::new();
+    for crc in CRC_PRESETS {
+        if !polys.contains(&(crc.width, crc.poly)) {
+            polys.push((crc.width, crc.poly));
+        }
+    }
+    let mut output = Vec::new();
+    for (width, poly) in polys {
+        let names: Vec<&str> = CRC_PRESETS
+            .iter()
+            .filter(|crc| crc.width == width && crc.poly == poly)
+            .map(|crc| crc.name)
+            .collect();
+        let normal = Match {
+            name: format!("CRC-{} polynomial", width),
+            description: format!("the generator polynomial of {}", names.join(", ")),
+            swapped: false,
+        };
+        let reflected = Match {
+            name: format!("CRC-{} polynomial (reflected)", width),
+            description: format!("the bit-reversed generator polynomial of {}", names.join(", ")),
+            swapped: false,
+        };
+        output.push((width / 8, poly, normal));
+        output.push((width / 8, poly.reverse_bits() >> (64 - width), reflected));
+    }
+    output
+}
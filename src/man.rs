@@ -0,0 +1,108 @@
+// Converts help texts into roff man pages. A help text starts with its usage lines, followed by
+// paragraphs of description and sections with a `Heading:` line. Section lines with a signature
+// and a description separated by two or more spaces become tagged paragraphs, lines starting
+// with `- ` become bullets, and paragraphs indented by four or more spaces are kept as they are.
+
+
+// Returns the man page in section 1 for the command with the specified name, summary, and help
+// text, with links to the see-also pages.
+pub fn page(name: &str, summary: &str, help: &str, see_also: &[String]) -> String {
+    let version = format!("intspector {}", env!("CARGO_PKG_VERSION"));
+    let mut output = format!(
+        ".TH \"{}\" \"1\" \"\" \"{}\" \"User Commands\"\n",
+        name.to_uppercase(),
+        version
+    );
+    output += &format!(".SH NAME\n{} \\- {}\n", escape(name), escape(summary));
+
+    let mut lines = help.trim_start_matches('\n').lines().peekable();
+    output += ".SH SYNOPSIS\n";
+    while let Some(line) = lines.next_if(|line| !line.is_empty()) {
+        let usage = line.strip_prefix("Usage:").unwrap_or(line).trim();
+        output += &format!("{}\n.br\n", escape(usage));
+    }
+
+    output += ".SH DESCRIPTION\n";
+    let mut in_row = false;
+    let mut in_verbatim = false;
+    let mut paragraph_start = true;
+    for line in lines {
+        if line.is_empty() {
+            if in_verbatim {
+                output += ".fi\n";
+                in_verbatim = false;
+            }
+            in_row = false;
+            paragraph_start = true;
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        if indent == 0 {
+            if let Some(heading) = line.strip_suffix(':') {
+                output += &format!(".SH {}\n", escape(&heading.to_uppercase()));
+                paragraph_start = false;
+                continue;
+            }
+        }
+        if in_verbatim {
+            output += &format!("{}\n", escape(line));
+            continue;
+        }
+        if in_row && indent > 6 {
+            output += &format!("{}\n", escape(line.trim()));
+            continue;
+        }
+        if let Some((signature, description)) = row(line) {
+            output += &format!(".TP\n.B {}\n{}\n", escape(signature), escape(description));
+            in_row = true;
+            paragraph_start = false;
+            continue;
+        }
+        in_row = false;
+        if let Some(item) = line.trim_start().strip_prefix("- ") {
+            output += &format!(".IP \\(bu 2\n{}\n", escape(item));
+        } else if paragraph_start && indent >= 4 {
+            output += &format!(".PP\n.nf\n{}\n", escape(line));
+            in_verbatim = true;
+        } else if paragraph_start {
+            output += &format!(".PP\n{}\n", escape(line.trim()));
+        } else {
+            output += &format!("{}\n", escape(line.trim()));
+        }
+        paragraph_start = false;
+    }
+    if in_verbatim {
+        output += ".fi\n";
+    }
+
+    if !see_also.is_empty() {
+        let links: Vec<String> =
+            see_also.iter().map(|page| format!(".BR {} (1)", escape(page))).collect();
+        output += &format!(".SH SEE ALSO\n{}\n", links.join(",\n"));
+    }
+    output
+}
+
+
+// Splits a line with a signature and a description separated by two or more spaces, indented by
+// two to six spaces, e.g. `  -b, --bits <n>        Number of binary digits to display.`
+fn row(line: &str) -> Option<(&str, &str)> {
+    let text = line.trim_start();
+    let indent = line.len() - text.len();
+    if !(2..=6).contains(&indent) {
+        return None;
+    }
+    let (signature, description) = text.split_once("  ")?;
+    Some((signature, description.trim_start())).filter(|(_, description)| !description.is_empty())
+}
+
+
+// Escapes backslashes and hyphens, and protects lines starting with a control character.
+fn escape(text: &str) -> String {
+    let text = text.replace('\\', "\\e").replace('-', "\\-");
+    if text.starts_with('.') || text.starts_with('\'') {
+        format!("\\&{}", text)
+    } else {
+        text
+    }
+}
@@ -0,0 +1,79 @@
+//! Conversion between MIDI note numbers, note names, and frequencies in equal temperament.
+
+
+const NOTE_NAMES: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+
+
+/// The standard concert pitch of A4 (MIDI note 69) in Hz.
+pub const A440: f64 = 440.0;
+
+
+/// Returns the name of a MIDI note in scientific pitch notation using sharps, e.g. `C4` for 60
+/// or `C-1` for 0. Returns `None` if the note is outside the MIDI range 0-127.
+pub fn note_name(note: u32) -> Option<String> {
+    if note > 127 {
+        return None;
+    }
+    Some(format!("{}{}", NOTE_NAMES[note as usize % 12], note as i32 / 12 - 1))
+}
+
+
+/// Parses a note name in scientific pitch notation, e.g. `A4`, `C#3`, `Bb-1`, into its MIDI note
+/// number. Accepts sharps (`#`) and flats (`b`). Returns `None` if the name is malformed or
+/// outside the MIDI range 0-127.
+pub fn parse_note(name: &str) -> Option<u32> {
+    let mut chars = name.chars();
+    let letter = chars.next()?.to_ascii_uppercase();
+    let mut semitone = match letter {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+    let rest = chars.as_str();
+    let octave = if let Some(rest) = rest.strip_prefix('#') {
+        semitone += 1;
+        rest
+    } else if let Some(rest) = rest.strip_prefix('b') {
+        semitone -= 1;
+        rest
+    } else {
+        rest
+    };
+    if octave.is_empty() || octave.starts_with('+') {
+        return None;
+    }
+    let octave: i32 = octave.parse().ok()?;
+    let note = (octave + 1).checked_mul(12)? + semitone;
+    if (0..=127).contains(&note) {
+        Some(note as u32)
+    } else {
+        None
+    }
+}
+
+
+/// Returns the frequency in Hz of a MIDI note in twelve-tone equal temperament, where `a4` is the
+/// frequency of A4.
+pub fn frequency(note: u32, a4: f64) -> f64 {
+    a4 * 2f64.powf((note as f64 - 69.0) / 12.0)
+}
+
+
+/// Returns the MIDI note nearest to the specified frequency in Hz, along with the frequency's
+/// offset from that note in cents. Returns `None` if the nearest note is outside the MIDI range.
+pub fn nearest_note(freq: f64, a4: f64) -> Option<(u32, f64)> {
+    if freq.is_nan() || freq <= 0.0 || a4.is_nan() || a4 <= 0.0 {
+        return None;
+    }
+    let exact = 69.0 + 12.0 * (freq / a4).log2();
+    let note = exact.round();
+    if !(0.0..=127.0).contains(&note) {
+        return None;
+    }
+    Some((note as u32, (exact - note) * 100.0))
+}
@@ -0,0 +1,116 @@
+//! Unix file modes: the permission bits, the setuid, setgid, and sticky bits, and the file type
+//! bits of `st_mode`.
+
+
+/// The file types of the `S_IFMT` bits, as (bits, symbolic character, description).
+pub const FILE_TYPES: &[(u32, char, &str)] = &[
+    (0o140000, 's', "socket"),
+    (0o120000, 'l', "symbolic link"),
+    (0o100000, '-', "regular file"),
+    (0o060000, 'b', "block device"),
+    (0o040000, 'd', "directory"),
+    (0o020000, 'c', "character device"),
+    (0o010000, 'p', "FIFO"),
+];
+
+
+/// The mask of the file type bits.
+pub const S_IFMT: u32 = 0o170000;
+
+
+/// Parses a mode written in octal, with or without a leading `0` or `0o`, e.g. `755`, `4755`, or
+/// `100644`.
+pub fn parse_octal(arg: &str) -> Option<u32> {
+    let digits = arg.strip_prefix("0o").unwrap_or(arg);
+    if digits.is_empty() || digits.len() > 7 {
+        return None;
+    }
+    u32::from_str_radix(digits, 8).ok().filter(|mode| *mode <= S_IFMT | 0o7777)
+}
+
+
+/// Parses a mode written in symbolic notation as shown by `ls -l`, e.g. `rwxr-xr-x`, optionally
+/// preceded by a file type character, e.g. `drwxr-xr-t`. The execute positions can hold `s` or
+/// `S` for setuid and setgid and `t` or `T` for the sticky bit, lowercase if the execute bit is
+/// also set.
+pub fn parse_symbolic(arg: &str) -> Option<u32> {
+    let chars: Vec<char> = arg.chars().collect();
+    let (file_type, perms) = match chars.len() {
+        9 => (0, &chars[..]),
+        10 => {
+            let (bits, _, _) = FILE_TYPES.iter().find(|(_, c, _)| *c == chars[0])?;
+            (*bits, &chars[1..])
+        },
+        _ => return None,
+    };
+    let mut mode = file_type;
+    for (index, c) in perms.iter().enumerate() {
+        let bit = 1 << (8 - index);
+        // The special bit shown in this position, if it's an execute position.
+        let special = match index {
+            2 => Some(('s', 0o4000)),
+            5 => Some(('s', 0o2000)),
+            8 => Some(('t', 0o1000)),
+            _ => None,
+        };
+        let expected = ['r', 'w', 'x'][index % 3];
+        match (*c, special) {
+            ('-', _) => {},
+            (c, _) if c == expected => mode |= bit,
+            (c, Some((letter, special))) if c == letter => mode |= bit | special,
+            (c, Some((letter, special))) if c == letter.to_ascii_uppercase() => mode |= special,
+            _ => return None,
+        }
+    }
+    Some(mode)
+}
+
+
+/// Formats the permission and special bits in symbolic notation, e.g. `rwsr-xr-x`, preceded by
+/// the file type character if the mode has file type bits.
+pub fn symbolic(mode: u32) -> String {
+    let mut output = String::new();
+    if let Some((_, c, _)) = file_type(mode) {
+        output.push(c);
+    }
+    for (index, special) in [(0, 0o4000), (1, 0o2000), (2, 0o1000)] {
+        let perms = mode >> (6 - index * 3) & 0o7;
+        output.push(if perms & 4 != 0 { 'r' } else { '-' });
+        output.push(if perms & 2 != 0 { 'w' } else { '-' });
+        let letter = if index == 2 { 't' } else { 's' };
+        output.push(match (perms & 1 != 0, mode & special != 0) {
+            (true, true) => letter,
+            (false, true) => letter.to_ascii_uppercase(),
+            (true, false) => 'x',
+            (false, false) => '-',
+        });
+    }
+    output
+}
+
+
+/// Returns the file type of a mode with file type bits, as (bits, symbolic character,
+/// description).
+pub fn file_type(mode: u32) -> Option<(u32, char, &'static str)> {
+    FILE_TYPES.iter().find(|(bits, _, _)| mode & S_IFMT == *bits).copied()
+}
+
+
+/// Returns the names of the permissions in a three-bit group, e.g. `["read", "execute"]` for 5.
+pub fn permissions(bits: u32) -> Vec<&'static str> {
+    [(4, "read"), (2, "write"), (1, "execute")]
+        .iter()
+        .filter(|(bit, _)| bits & bit != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+
+/// Returns the names of the setuid, setgid, and sticky bits set in the mode.
+pub fn specials(mode: u32) -> Vec<&'static str> {
+    [(0o4000, "setuid"), (0o2000, "setgid"), (0o1000, "sticky")]
+        .iter()
+        .filter(|(bit, _)| mode & bit != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
@@ -0,0 +1,54 @@
+//! Conversion between ASCII digits and the decimal digits of other numbering systems.
+
+
+/// Numbering systems with contiguous decimal digits, identified by their CLDR names and paired
+/// with the code point of their zero digit.
+pub const SYSTEMS: &[(&str, u32)] = &[
+    ("latn", 0x0030),
+    ("arab", 0x0660),
+    ("arabext", 0x06F0),
+    ("deva", 0x0966),
+    ("beng", 0x09E6),
+    ("guru", 0x0A66),
+    ("gujr", 0x0AE6),
+    ("orya", 0x0B66),
+    ("tamldec", 0x0BE6),
+    ("telu", 0x0C66),
+    ("knda", 0x0CE6),
+    ("mlym", 0x0D66),
+    ("thai", 0x0E50),
+    ("laoo", 0x0ED0),
+    ("tibt", 0x0F20),
+    ("mymr", 0x1040),
+    ("khmr", 0x17E0),
+    ("mong", 0x1810),
+    ("fullwide", 0xFF10),
+];
+
+
+/// Replaces the ASCII digits in `text` with the corresponding digits of the numbering system
+/// whose zero digit has the code point `zero`. Other characters are left unchanged.
+pub fn to_native(text: &str, zero: u32) -> String {
+    text.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(digit) => std::char::from_u32(zero + digit).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
+
+
+/// Replaces digits from any of the supported numbering systems in `text` with the corresponding
+/// ASCII digits. Other characters are left unchanged.
+pub fn to_ascii(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            for (_, zero) in SYSTEMS {
+                if (*zero..*zero + 10).contains(&(c as u32)) {
+                    return std::char::from_u32('0' as u32 + c as u32 - zero).unwrap_or(c);
+                }
+            }
+            c
+        })
+        .collect()
+}
@@ -0,0 +1,63 @@
+// The global options, which set up the output for every command. They're read from the front of
+// the command line, up to the command name, so a command's own options can share their names,
+// e.g. `intspector --format json decimal --format d32 ...`. With no command name, the default
+// command's arguments are scanned through to the end, or to a `--`.
+
+#[derive(Debug, Default, PartialEq)]
+pub struct GlobalOptions {
+    pub color: Option<String>,
+    pub theme: Option<String>,
+    pub format: Option<String>,
+    pub lang: Option<String>,
+}
+
+
+const OPTIONS: &[&str] = &["color", "theme", "format", "lang"];
+
+
+// Removes the global options from the command line, returning them and the remaining arguments.
+// The `is_command` function identifies the command names, including plugins.
+pub fn parse(
+    args: Vec<String>,
+    is_command: impl Fn(&str) -> bool,
+) -> Result<(GlobalOptions, Vec<String>), String> {
+    let mut options = GlobalOptions::default();
+    let mut kept = Vec::new();
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--" || (kept.is_empty() && is_command(&arg)) {
+            kept.push(arg);
+            kept.extend(iter);
+            break;
+        }
+        if arg == "--no-color" {
+            options.color = Some("never".to_string());
+            continue;
+        }
+        if arg == "--json" {
+            options.format = Some("json".to_string());
+            continue;
+        }
+        let (name, inline) = match arg.strip_prefix("--").map(|rest| rest.split_once('=')) {
+            Some(Some((name, value))) => (name, Some(value.to_string())),
+            Some(None) => (&arg[2..], None),
+            None => ("", None),
+        };
+        if !OPTIONS.contains(&name) {
+            kept.push(arg);
+            continue;
+        }
+        let value = match inline {
+            Some(value) => value,
+            None => iter.next().ok_or_else(|| format!("missing argument for --{}.", name))?,
+        };
+        let slot = match name {
+            "color" => &mut options.color,
+            "theme" => &mut options.theme,
+            "format" => &mut options.format,
+            _ => &mut options.lang,
+        };
+        *slot = Some(value);
+    }
+    Ok((options, kept))
+}
@@ -0,0 +1,182 @@
+//! An embedded selection of IEEE organizationally unique identifiers (OUIs) covering common
+//! network equipment, virtualization, and embedded vendors. Available with the `oui` feature.
+
+
+/// Vendor names indexed by 24-bit OUI, sorted by OUI.
+pub const VENDORS: &[(u32, &str)] = &[
+    (0x00000C, "Cisco"),
+    (0x00001D, "Cabletron"),
+    (0x000039, "Toshiba"),
+    (0x00005E, "IANA"),
+    (0x0000AA, "Xerox"),
+    (0x0000F0, "Samsung"),
+    (0x0000F8, "Digital Equipment"),
+    (0x000102, "3Com"),
+    (0x000255, "IBM"),
+    (0x0002B3, "Intel"),
+    (0x000393, "Apple"),
+    (0x0003BA, "Sun Microsystems"),
+    (0x0003FF, "Microsoft"),
+    (0x000496, "Extreme Networks"),
+    (0x0004AC, "IBM"),
+    (0x0004F2, "Polycom"),
+    (0x00055D, "D-Link"),
+    (0x000569, "VMware"),
+    (0x000585, "Juniper Networks"),
+    (0x000625, "Linksys"),
+    (0x00080D, "Toshiba"),
+    (0x000874, "Dell"),
+    (0x00090F, "Fortinet"),
+    (0x00096B, "IBM"),
+    (0x000A95, "Apple"),
+    (0x000AF7, "Broadcom"),
+    (0x000B82, "Grandstream"),
+    (0x000B86, "Aruba Networks"),
+    (0x000C29, "VMware"),
+    (0x000C41, "Linksys"),
+    (0x000D3A, "Microsoft"),
+    (0x000D60, "IBM"),
+    (0x000D93, "Apple"),
+    (0x000E08, "Cisco-Linksys"),
+    (0x000E0C, "Intel"),
+    (0x000F20, "Hewlett-Packard"),
+    (0x000F66, "Cisco-Linksys"),
+    (0x000FB5, "Netgear"),
+    (0x001018, "Broadcom"),
+    (0x001083, "Hewlett-Packard"),
+    (0x0010DB, "Juniper Networks"),
+    (0x00110A, "Hewlett-Packard"),
+    (0x001124, "Apple"),
+    (0x001125, "IBM"),
+    (0x001132, "Synology"),
+    (0x001185, "Hewlett-Packard"),
+    (0x00123F, "Dell"),
+    (0x001279, "Hewlett-Packard"),
+    (0x0012FB, "Samsung"),
+    (0x001320, "Intel"),
+    (0x001321, "Hewlett-Packard"),
+    (0x001422, "Dell"),
+    (0x001438, "Hewlett-Packard"),
+    (0x00144F, "Sun Microsystems"),
+    (0x00145E, "IBM"),
+    (0x00146C, "Netgear"),
+    (0x001517, "Intel"),
+    (0x00155D, "Microsoft"),
+    (0x001560, "Hewlett-Packard"),
+    (0x001599, "Samsung"),
+    (0x001632, "Samsung"),
+    (0x001635, "Hewlett-Packard"),
+    (0x00163E, "Xensource"),
+    (0x0016CB, "Apple"),
+    (0x001788, "Philips Lighting"),
+    (0x0017A4, "Hewlett-Packard"),
+    (0x0017F2, "Apple"),
+    (0x00180A, "Cisco Meraki"),
+    (0x001839, "Cisco-Linksys"),
+    (0x00188B, "Dell"),
+    (0x0018FE, "Hewlett-Packard"),
+    (0x0019B9, "Dell"),
+    (0x0019BB, "Hewlett-Packard"),
+    (0x0019E3, "Apple"),
+    (0x001A11, "Google"),
+    (0x001A1E, "Aruba Networks"),
+    (0x001A4B, "Hewlett-Packard"),
+    (0x001A64, "IBM"),
+    (0x001A70, "Cisco-Linksys"),
+    (0x001AA0, "Dell"),
+    (0x001B17, "Palo Alto Networks"),
+    (0x001B21, "Intel"),
+    (0x001B2F, "Netgear"),
+    (0x001B63, "Apple"),
+    (0x001B78, "Hewlett-Packard"),
+    (0x001C14, "VMware"),
+    (0x001C23, "Dell"),
+    (0x001C42, "Parallels"),
+    (0x001C7E, "Toshiba"),
+    (0x001CB3, "Apple"),
+    (0x001CC4, "Hewlett-Packard"),
+    (0x001D25, "Samsung"),
+    (0x001DAA, "DrayTek"),
+    (0x001DD8, "Microsoft"),
+    (0x001E0B, "Hewlett-Packard"),
+    (0x001E4F, "Dell"),
+    (0x001E58, "D-Link"),
+    (0x001E7D, "Samsung"),
+    (0x001EC2, "Apple"),
+    (0x001EE5, "Cisco-Linksys"),
+    (0x001F29, "Hewlett-Packard"),
+    (0x001FF3, "Apple"),
+    (0x0020AF, "3Com"),
+    (0x002129, "Cisco-Linksys"),
+    (0x00215A, "Hewlett-Packard"),
+    (0x00215E, "IBM"),
+    (0x002170, "Dell"),
+    (0x002219, "Dell"),
+    (0x002264, "Hewlett-Packard"),
+    (0x00237D, "Hewlett-Packard"),
+    (0x0023AE, "Dell"),
+    (0x0023DF, "Apple"),
+    (0x002481, "Hewlett-Packard"),
+    (0x0024E8, "Dell"),
+    (0x002500, "Apple"),
+    (0x002590, "Super Micro Computer"),
+    (0x0025B3, "Hewlett-Packard"),
+    (0x002608, "Apple"),
+    (0x002655, "Hewlett-Packard"),
+    (0x0026B9, "Dell"),
+    (0x0026BB, "Apple"),
+    (0x004096, "Cisco"),
+    (0x005043, "Marvell"),
+    (0x005056, "VMware"),
+    (0x0050F2, "Microsoft"),
+    (0x006008, "3Com"),
+    (0x0080C2, "IEEE 802.1"),
+    (0x009027, "Intel"),
+    (0x00904C, "Broadcom"),
+    (0x0090A9, "Western Digital"),
+    (0x00A024, "3Com"),
+    (0x00A0C9, "Intel"),
+    (0x00AA00, "Intel"),
+    (0x00D0B7, "Intel"),
+    (0x00E04C, "Realtek"),
+    (0x00E081, "Tyan Computer"),
+    (0x080009, "Hewlett-Packard"),
+    (0x080020, "Sun Microsystems"),
+    (0x080027, "Oracle VirtualBox"),
+    (0x08002B, "Digital Equipment"),
+    (0x0CC47A, "Super Micro Computer"),
+    (0x18FE34, "Espressif"),
+    (0x240AC4, "Espressif"),
+    (0x246F28, "Espressif"),
+    (0x28CDC1, "Raspberry Pi"),
+    (0x30AEA4, "Espressif"),
+    (0x3C0754, "Apple"),
+    (0x3C5AB4, "Google"),
+    (0x3CD92B, "Hewlett-Packard"),
+    (0x50C7BF, "TP-Link"),
+    (0x525400, "QEMU/KVM"),
+    (0x5C0A5B, "Samsung"),
+    (0x5CCF7F, "Espressif"),
+    (0x600194, "Espressif"),
+    (0xA4CF12, "Espressif"),
+    (0xAC1F6B, "Super Micro Computer"),
+    (0xB4E62D, "Espressif"),
+    (0xB827EB, "Raspberry Pi"),
+    (0xB8AC6F, "Dell"),
+    (0xDCA632, "Raspberry Pi"),
+    (0xE45F01, "Raspberry Pi"),
+    (0xF4EC38, "TP-Link"),
+    (0xF4F5D8, "Google"),
+    (0xF8B156, "Dell"),
+];
+
+
+/// Returns the name of the vendor registered for the OUI of the specified MAC address. The I/G
+/// bit is ignored so group addresses map to the OUI's owner, e.g. `01:00:5E` to IANA.
+pub fn vendor(addr: u64) -> Option<&'static str> {
+    let oui = (addr >> 24) as u32 & !0x01_0000;
+    VENDORS
+        .binary_search_by_key(&oui, |(key, _)| *key)
+        .ok()
+        .map(|index| VENDORS[index].1)
+}
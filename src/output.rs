@@ -0,0 +1,136 @@
+// Terminal colors for the info blocks, and the style of the output as a whole. The style is
+// decided once at startup from the --color, --no-color, --format, --lang, and --theme arguments,
+// which work with any command, and the NO_COLOR environment variable.
+
+use crate::locale::Locale;
+use crate::report::Format;
+
+
+// The SGR escape sequences used for each part of the output.
+pub struct Theme {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub set_bit: &'static str,
+    pub clear_bit: &'static str,
+    pub spacer: &'static str,
+    pub error: &'static str,
+    pub rule: &'static str,
+}
+
+
+pub const THEMES: &[Theme] = &[
+    Theme {
+        name: "dark",
+        label: "\u{1B}[36m",
+        set_bit: "\u{1B}[1;93m",
+        clear_bit: "\u{1B}[37m",
+        spacer: "\u{1B}[90m",
+        error: "\u{1B}[1;31m",
+        rule: "\u{1B}[90m",
+    },
+    Theme {
+        name: "light",
+        label: "\u{1B}[34m",
+        set_bit: "\u{1B}[1;35m",
+        clear_bit: "\u{1B}[90m",
+        spacer: "\u{1B}[37m",
+        error: "\u{1B}[1;31m",
+        rule: "\u{1B}[37m",
+    },
+];
+
+
+const RESET: &str = "\u{1B}[0m";
+
+
+// How reports are shown: their format, the color theme, or None for no color, the language of
+// the labels and the command whose labels they are, and the width of the rules between info
+// blocks, or None for no rules.
+#[derive(Clone, Copy)]
+pub struct Style {
+    pub format: Format,
+    pub theme: Option<&'static Theme>,
+    pub locale: &'static Locale,
+    pub command: &'static str,
+    pub width: Option<usize>,
+}
+
+
+impl Style {
+    // Colors an info block label, e.g. `hex:`. Returns the label unchanged if color is off.
+    pub fn paint_label(&self, label: &str) -> String {
+        match self.theme {
+            Some(theme) => format!("{}{}{}", theme.label, label, RESET),
+            None => label.to_string(),
+        }
+    }
+
+
+    // Colors an error line. Returns the line unchanged if color is off.
+    pub fn paint_error(&self, line: &str) -> String {
+        match self.theme {
+            Some(theme) => format!("{}{}{}", theme.error, line, RESET),
+            None => line.to_string(),
+        }
+    }
+
+
+    // Colors the digits of a binary string, with dimmed spacers, leaving any text after the
+    // digits, e.g. a note in parentheses, as it is. Returns the string unchanged if color is off.
+    pub fn paint_bits(&self, value: &str) -> String {
+        let theme = match self.theme {
+            Some(theme) => theme,
+            None => return value.to_string(),
+        };
+        let mut output = String::new();
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            let color = match c {
+                '1' => theme.set_bit,
+                '0' => theme.clear_bit,
+                '(' => {
+                    output.push(c);
+                    output.extend(chars.by_ref());
+                    break;
+                },
+                _ => theme.spacer,
+            };
+            output += &format!("{}{}{}", color, c, RESET);
+        }
+        output
+    }
+
+
+    // Returns the rule between info blocks. Only text output on a terminal has rules.
+    pub fn termline(&self) -> Option<String> {
+        let width = self.width.filter(|_| self.format == Format::Text)?;
+        let line = "─".repeat(width);
+        match self.theme {
+            Some(theme) => Some(format!("{}{}{}", theme.rule, line, RESET)),
+            None => Some(line),
+        }
+    }
+}
+
+
+// Returns the theme for the --color and --theme arguments, or None for no color. Auto mode, the
+// default, uses color only if `auto` is true, i.e. stdout is a terminal and NO_COLOR is unset
+// or empty.
+pub fn theme(
+    color: Option<&str>,
+    theme: Option<&str>,
+    auto: bool,
+) -> Result<Option<&'static Theme>, String> {
+    let (mode, theme) = (color.unwrap_or("auto"), theme.unwrap_or("dark"));
+    let enabled = match mode {
+        "always" => true,
+        "never" => false,
+        "auto" => auto,
+        _ => return Err(format!("expected always, never, or auto for --color, found '{}'.", mode)),
+    };
+    let theme = THEMES
+        .iter()
+        .find(|candidate| candidate.name == theme)
+        .ok_or_else(|| format!("unknown theme '{}', expected dark or light.", theme))?;
+    Ok(Some(theme).filter(|_| enabled))
+}
@@ -0,0 +1,96 @@
+//! Pointer tagging, canonical address checks, and the typical virtual memory layouts of Linux
+//! and macOS processes.
+
+
+/// Returns the number of low-order bits guaranteed to be zero in a pointer with the specified
+/// alignment, i.e. the number of bits available for tagging. Returns `None` if `align` is not a
+/// power of two.
+pub fn tag_bits(align: u64) -> Option<u32> {
+    if align.is_power_of_two() {
+        Some(align.trailing_zeros())
+    } else {
+        None
+    }
+}
+
+
+/// Sign-extends the low `va_bits` bits of `addr` to 64 bits, giving the canonical form of the
+/// address for a virtual address space of the specified size. Will panic if `va_bits` is zero
+/// or greater than 64.
+pub fn canonicalize(addr: u64, va_bits: u32) -> u64 {
+    assert!(va_bits > 0 && va_bits <= 64);
+    let shift = 64 - va_bits;
+    (((addr << shift) as i64) >> shift) as u64
+}
+
+
+/// Returns true if `addr` is canonical for a virtual address space of `va_bits` bits, i.e. if
+/// all the bits above bit `va_bits - 1` are copies of that bit.
+pub fn is_canonical(addr: u64, va_bits: u32) -> bool {
+    canonicalize(addr, va_bits) == addr
+}
+
+
+/// Returns true if `addr` is a valid AArch64 virtual address for a `va_bits`-bit address space,
+/// i.e. if the bits above the address field are either all zeros (TTBR0, user space) or all
+/// ones (TTBR1, kernel space). If `tbi` is true the top byte is ignored, as it is for tagged
+/// pointers when top-byte-ignore is enabled. Will panic if `va_bits` is zero or greater than 56.
+pub fn is_aarch64_valid(addr: u64, va_bits: u32, tbi: bool) -> bool {
+    assert!(va_bits > 0 && va_bits <= 56);
+    let top = if tbi { 56 } else { 64 };
+    let high = if top == 64 { u64::MAX } else { (1 << top) - 1 };
+    let mask = high & !((1 << va_bits) - 1);
+    addr & mask == 0 || addr & mask == mask
+}
+
+
+/// A region of a typical process's virtual address space.
+pub struct Region {
+    pub start: u64,
+    pub end: u64,
+    pub name: &'static str,
+
+    /// Typical ASLR entropy for addresses in the region, in bits, where well known.
+    pub entropy: Option<u32>,
+}
+
+
+/// Typical virtual memory layout of a Linux process on x86-64 with 4-level paging. Regions are
+/// listed in lookup order, with more specific regions preceding the catch-all ranges.
+pub const LINUX_LAYOUT: &[Region] = &[
+    Region { start: 0x0000000000000000, end: 0x000000000000FFFF, name: "null guard (below mmap_min_addr)", entropy: None },
+    Region { start: 0x0000000000010000, end: 0x000000007FFFFFFF, name: "non-PIE executable or brk heap", entropy: None },
+    Region { start: 0x0000550000000000, end: 0x000056FFFFFFFFFF, name: "PIE executable or brk heap", entropy: Some(28) },
+    Region { start: 0x00007FFC00000000, end: 0x00007FFFFFFFFFFF, name: "stack", entropy: Some(22) },
+    Region { start: 0x00007F0000000000, end: 0x00007FFBFFFFFFFF, name: "mmap region or shared libraries", entropy: Some(28) },
+    Region { start: 0x0000000000000000, end: 0x00007FFFFFFFFFFF, name: "user space", entropy: None },
+    Region { start: 0x0000800000000000, end: 0xFFFF7FFFFFFFFFFF, name: "non-canonical hole", entropy: None },
+    Region { start: 0xFFFFFFFFFF600000, end: 0xFFFFFFFFFF600FFF, name: "kernel vsyscall page", entropy: None },
+    Region { start: 0xFFFFFFFFA0000000, end: 0xFFFFFFFFFEFFFFFF, name: "kernel modules", entropy: None },
+    Region { start: 0xFFFFFFFF80000000, end: 0xFFFFFFFF9FFFFFFF, name: "kernel text", entropy: Some(9) },
+    Region { start: 0xFFFFEA0000000000, end: 0xFFFFEAFFFFFFFFFF, name: "kernel vmemmap", entropy: None },
+    Region { start: 0xFFFFC90000000000, end: 0xFFFFE8FFFFFFFFFF, name: "kernel vmalloc/ioremap", entropy: None },
+    Region { start: 0xFFFF888000000000, end: 0xFFFFC87FFFFFFFFF, name: "kernel direct map", entropy: None },
+    Region { start: 0xFFFF800000000000, end: 0xFFFFFFFFFFFFFFFF, name: "kernel space", entropy: None },
+];
+
+
+/// Typical virtual memory layout of a macOS process on arm64 and x86-64. Regions are listed in
+/// lookup order, with more specific regions preceding the catch-all ranges.
+pub const MACOS_LAYOUT: &[Region] = &[
+    Region { start: 0x0000000000000000, end: 0x00000000FFFFFFFF, name: "__PAGEZERO (null region)", entropy: None },
+    Region { start: 0x0000000100000000, end: 0x000000015FFFFFFF, name: "main executable", entropy: None },
+    Region { start: 0x0000000160000000, end: 0x000000017FFFFFFF, name: "stack (arm64)", entropy: None },
+    Region { start: 0x0000000180000000, end: 0x00000002FFFFFFFF, name: "dyld shared cache (arm64)", entropy: None },
+    Region { start: 0x0000600000000000, end: 0x00006FFFFFFFFFFF, name: "malloc heap (nano zone)", entropy: None },
+    Region { start: 0x00007FF000000000, end: 0x00007FFFFFFFFFFF, name: "stack or dyld shared cache (x86-64)", entropy: None },
+    Region { start: 0x0000000000000000, end: 0x00007FFFFFFFFFFF, name: "user space (heap or mmap)", entropy: None },
+    Region { start: 0x0000800000000000, end: 0xFFFF7FFFFFFFFFFF, name: "non-canonical hole", entropy: None },
+    Region { start: 0xFFFF800000000000, end: 0xFFFFFFFFFFFFFFFF, name: "kernel space", entropy: None },
+];
+
+
+/// Returns the first region in `layout` containing `addr`, if any.
+pub fn classify(addr: u64, layout: &[Region]) -> Option<&Region> {
+    layout.iter().find(|region| region.start <= addr && addr <= region.end)
+}
@@ -0,0 +1,42 @@
+//! Well-known poison and sentinel values written by allocators, runtimes, and debuggers.
+
+
+// Well-known poison and sentinel values, paired with a description of what typically writes them.
+const POISON_VALUES: &[(u64, &str)] = &[
+    (0xDEADBEEF, "generic debug marker, used by many allocators and firmwares for uninitialized or freed memory"),
+    (0xFEEEFEEE, "freed heap memory (Windows HeapFree with the debug heap)"),
+    (0xCCCCCCCC, "uninitialized stack memory (MSVC /RTC debug builds)"),
+    (0xCDCDCDCD, "uninitialized heap memory (MSVC debug CRT)"),
+    (0xDDDDDDDD, "freed heap memory (MSVC debug CRT)"),
+    (0xFDFDFDFD, "guard bytes around heap allocations (MSVC debug CRT)"),
+    (0xBAADF00D, "uninitialized heap memory (Windows LocalAlloc)"),
+    (0xABABABAB, "guard bytes after a heap block (Windows HeapAlloc)"),
+    (0xA5A5A5A5, "newly allocated memory (jemalloc junk fill), slab red zone end (Linux), or stack fill (FreeRTOS)"),
+    (0x5A5A5A5A, "freed memory (jemalloc junk fill) or allocated slab object (Linux POISON_INUSE)"),
+    (0x6B6B6B6B, "freed slab object (Linux POISON_FREE)"),
+    (0xBBBBBBBB, "inactive slab red zone (Linux SLUB_RED_INACTIVE)"),
+    (0xBEBEBEBE, "newly allocated memory (AddressSanitizer malloc fill)"),
+    (0xAAAAAAAA, "uninitialized variable (clang -ftrivial-auto-var-init=pattern)"),
+    (0xDBDBDBDB, "newly allocated memory (OpenBSD malloc junk)"),
+    (0xDFDFDFDF, "freed memory (OpenBSD malloc junk)"),
+    (0xBADDCAFE, "uninitialized memory (Solaris libumem)"),
+    (0xFEEDFACE, "heap red zone (Solaris libumem)"),
+    (0xDEADDEAD, "manually initiated crash (Windows bug check 0xDEADDEAD)"),
+    (0x8BADF00D, "watchdog timeout termination (iOS crash report)"),
+    (0xDEADFA11, "force quit by the user (iOS crash report)"),
+    (0xC00010FF, "thermal event termination (iOS crash report)"),
+    (0x00100100, "deleted list entry, next pointer (Linux LIST_POISON1)"),
+    (0x00200200, "deleted list entry, prev pointer (Linux LIST_POISON2)"),
+    (0xDEAD000000000100, "deleted list entry, next pointer (Linux LIST_POISON1 on x86-64)"),
+    (0xDEAD000000000122, "deleted list entry, prev pointer (Linux LIST_POISON2 on x86-64)"),
+];
+
+
+/// If `value` matches a well-known poison or sentinel constant, returns a description of what
+/// typically writes it. 32-bit patterns also match when repeated across 64 bits, e.g.
+/// `0xCCCCCCCCCCCCCCCC`.
+pub fn describe(value: u64) -> Option<&'static str> {
+    let low = value & 0xFFFF_FFFF;
+    let key = if value >> 32 == low { low } else { value };
+    POISON_VALUES.iter().find(|(poison, _)| *poison == key).map(|(_, desc)| *desc)
+}
@@ -0,0 +1,75 @@
+//! Conversion between Bitcoin's compact difficulty encoding (nBits) and 256-bit targets.
+
+use crate::bigint::BigUint;
+
+
+/// The compact encoding of the maximum target, i.e. the target at difficulty 1.
+pub const MAX_TARGET_BITS: u32 = 0x1D00FFFF;
+
+
+/// The fields of a compact target: a base-256 exponent giving the length of the target in bytes,
+/// a 23-bit mantissa holding its most significant bytes, and a sign bit.
+#[derive(Debug, PartialEq)]
+pub struct Compact {
+    pub exponent: u32,
+    pub mantissa: u32,
+    pub negative: bool,
+}
+
+
+/// Splits a compact target into its fields. As in Bitcoin Core's `SetCompact()`, the value is
+/// only negative if the sign bit is set and the mantissa is non-zero once shifted into place, so
+/// e.g. `0x04800000` is zero rather than negative.
+pub fn compact_fields(bits: u32) -> Compact {
+    let exponent = bits >> 24;
+    let mantissa = bits & 0x007F_FFFF;
+    let shifted = if exponent <= 3 { mantissa >> (8 * (3 - exponent)) } else { mantissa };
+    Compact { exponent, mantissa, negative: bits & 0x0080_0000 != 0 && shifted != 0 }
+}
+
+
+/// Expands a compact target into the full target: `mantissa × 256^(exponent - 3)`. Returns
+/// `None` if the target is negative or does not fit in 256 bits, as Bitcoin rejects both.
+pub fn compact_to_target(bits: u32) -> Option<BigUint> {
+    let fields = compact_fields(bits);
+    if fields.negative {
+        return None;
+    }
+    let mantissa = BigUint::from_u128(fields.mantissa as u128);
+    let target = if fields.exponent <= 3 {
+        mantissa.shr(8 * (3 - fields.exponent))
+    } else {
+        mantissa.shl(8 * (fields.exponent - 3))
+    };
+    if target.bits() > 256 {
+        return None;
+    }
+    Some(target)
+}
+
+
+/// Encodes a target in compact form, truncating it to its three most significant bytes. A byte
+/// is dropped from the mantissa if its high bit would otherwise be read as the sign bit.
+pub fn target_to_compact(target: &BigUint) -> u32 {
+    let mut size = target.bits().div_ceil(8);
+    let mut mantissa = if size <= 3 {
+        (target.low_u128() as u32) << (8 * (3 - size))
+    } else {
+        target.shr(8 * (size - 3)).low_u128() as u32
+    };
+    if mantissa & 0x0080_0000 != 0 {
+        mantissa >>= 8;
+        size += 1;
+    }
+    size << 24 | mantissa
+}
+
+
+/// Returns the difficulty of a target, i.e. the maximum target divided by the target. Returns
+/// `None` for a zero target.
+pub fn difficulty(target: &BigUint) -> Option<f64> {
+    if target.is_zero() {
+        return None;
+    }
+    Some(compact_to_target(MAX_TARGET_BITS)?.to_f64() / target.to_f64())
+}
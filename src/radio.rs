@@ -0,0 +1,133 @@
+//! Conversion between radio channel numbers and centre frequencies.
+
+
+/// A radio band with a channel numbering scheme.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Band {
+    /// Wi-Fi 2.4 GHz, channels 1-14.
+    Wifi2g,
+    /// Wi-Fi 5 GHz, channels 1-196.
+    Wifi5g,
+    /// Wi-Fi 6 GHz, channels 1-233.
+    Wifi6g,
+    /// Bluetooth BR/EDR, channels 0-78.
+    Bluetooth,
+    /// Bluetooth Low Energy, channel indices 0-39.
+    Ble,
+    /// LoRaWAN US915 uplink, channels 0-71.
+    LoraUs915,
+}
+
+
+/// All supported bands.
+pub const BANDS: [Band; 6] = [
+    Band::Wifi2g,
+    Band::Wifi5g,
+    Band::Wifi6g,
+    Band::Bluetooth,
+    Band::Ble,
+    Band::LoraUs915,
+];
+
+
+impl Band {
+    /// Returns the band's short name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Band::Wifi2g => "wifi2g",
+            Band::Wifi5g => "wifi5g",
+            Band::Wifi6g => "wifi6g",
+            Band::Bluetooth => "bt",
+            Band::Ble => "ble",
+            Band::LoraUs915 => "us915",
+        }
+    }
+
+    /// Returns the band with the specified short name.
+    pub fn from_name(name: &str) -> Option<Band> {
+        BANDS.iter().copied().find(|band| band.name() == name.to_lowercase())
+    }
+
+    fn channels(&self) -> std::ops::RangeInclusive<u32> {
+        match self {
+            Band::Wifi2g => 1..=14,
+            Band::Wifi5g => 1..=196,
+            Band::Wifi6g => 1..=233,
+            Band::Bluetooth => 0..=78,
+            Band::Ble => 0..=39,
+            Band::LoraUs915 => 0..=71,
+        }
+    }
+}
+
+
+/// Returns the centre frequency in kHz of the specified channel, with the arithmetic used to
+/// compute it in MHz, e.g. `2407 + 5 × 6`. Returns `None` if the band has no such channel.
+pub fn channel_to_khz(band: Band, channel: u32) -> Option<(u64, String)> {
+    if !band.channels().contains(&channel) {
+        return None;
+    }
+    let ch = channel as u64;
+    let result = match band {
+        Band::Wifi2g if channel == 14 => {
+            (2_484_000, "2484 (channel 14 is a special case)".to_string())
+        },
+        Band::Wifi2g => (2_407_000 + 5000 * ch, format!("2407 + 5 × {}", ch)),
+        Band::Wifi5g => (5_000_000 + 5000 * ch, format!("5000 + 5 × {}", ch)),
+        Band::Wifi6g if channel == 2 => {
+            (5_935_000, "5935 (channel 2 is a special case)".to_string())
+        },
+        Band::Wifi6g => (5_950_000 + 5000 * ch, format!("5950 + 5 × {}", ch)),
+        Band::Bluetooth => (2_402_000 + 1000 * ch, format!("2402 + {}", ch)),
+        Band::Ble => {
+            // Advertising channels 37-39 sit at the edges and middle of the band; data channels
+            // fill the gaps in between.
+            let rf = match channel {
+                37 => 0,
+                38 => 12,
+                39 => 39,
+                0..=10 => ch + 1,
+                _ => ch + 2,
+            };
+            (2_402_000 + 2000 * rf, format!("2402 + 2 × {} (RF channel {})", rf, rf))
+        },
+        Band::LoraUs915 if channel < 64 => {
+            (902_300 + 200 * ch, format!("902.3 + 0.2 × {}", ch))
+        },
+        Band::LoraUs915 => {
+            (903_000 + 1600 * (ch - 64), format!("903.0 + 1.6 × ({} - 64)", ch))
+        },
+    };
+    Some(result)
+}
+
+
+/// Returns the channel of the specified band whose centre frequency is `khz`, if any.
+pub fn khz_to_channel(band: Band, khz: u64) -> Option<u32> {
+    band.channels()
+        .find(|channel| matches!(channel_to_khz(band, *channel), Some((f, _)) if f == khz))
+}
+
+
+/// Formats a frequency in kHz as MHz, omitting trailing zeros, e.g. `902.3`.
+pub fn format_mhz(khz: u64) -> String {
+    let mhz = format!("{}.{:03}", khz / 1000, khz % 1000);
+    mhz.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+
+/// Parses a frequency in MHz with up to three decimal places, e.g. `902.3` or `2437MHz`, into
+/// kHz.
+pub fn parse_mhz(arg: &str) -> Option<u64> {
+    let arg = arg.trim_end_matches("MHz").trim_end_matches("mhz");
+    let (whole, frac) = match arg.split_once('.') {
+        Some((whole, frac)) => (whole, frac),
+        None => (arg, ""),
+    };
+    if whole.is_empty() || frac.len() > 3 || !frac.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let whole: u64 = whole.parse().ok()?;
+    let frac: u64 = format!("{:0<3}", frac).parse().ok()?;
+    whole.checked_mul(1000)?.checked_add(frac)
+}
@@ -0,0 +1,46 @@
+//! A small seedable pseudorandom number generator, for generating test values. Not suitable for
+//! cryptographic use.
+
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+
+/// Sebastiano Vigna's SplitMix64 generator.
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+
+impl SplitMix64 {
+    /// Returns a generator with the specified seed. The same seed always gives the same values.
+    pub fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+
+    /// Returns a generator seeded from the per-process random keys of the standard library's
+    /// hash maps.
+    pub fn from_entropy() -> SplitMix64 {
+        SplitMix64::new(RandomState::new().hash_one(std::process::id()))
+    }
+
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+
+    /// Returns `num_bits` random bits in the low-order bits of the result. Will panic if
+    /// `num_bits` is 0 or greater than 128.
+    pub fn next_bits(&mut self, num_bits: u32) -> u128 {
+        assert!(num_bits > 0 && num_bits <= 128);
+        let bits = (self.next_u64() as u128) << 64 | self.next_u64() as u128;
+        bits >> (128 - num_bits)
+    }
+}
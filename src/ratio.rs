@@ -0,0 +1,224 @@
+//! Exact arithmetic on positive rationals: locating fractions in the Stern–Brocot tree and
+//! decomposing them into Egyptian fractions.
+
+use crate::exif::gcd;
+
+
+/// A non-negative rational number. A denominator of zero is used only for the Stern–Brocot
+/// tree's right boundary, `1/0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ratio {
+    pub num: u64,
+    pub den: u64,
+}
+
+
+impl Ratio {
+    /// Returns the rational reduced to lowest terms.
+    pub fn reduce(&self) -> Ratio {
+        match gcd(self.num, self.den) {
+            0 | 1 => *self,
+            divisor => Ratio { num: self.num / divisor, den: self.den / divisor },
+        }
+    }
+
+    /// Returns the rational as a float.
+    pub fn value(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+}
+
+
+impl std::fmt::Display for Ratio {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+
+/// Parses a rational written as `num/den`, e.g. `355/113`. A bare integer is read as having a
+/// denominator of 1. Both parts can be specified in binary, octal, decimal, or hexadecimal base.
+pub fn parse_ratio(arg: &str) -> Option<Ratio> {
+    let (num, den) = match arg.split_once('/') {
+        Some((num, den)) => (num.trim(), crate::parse_uint(den.trim()).ok()?),
+        None => (arg.trim(), 1),
+    };
+    let num = crate::parse_uint(num).ok()?;
+    if den == 0 {
+        return None;
+    }
+    Some(Ratio { num, den })
+}
+
+
+/// Parses a decimal fraction, e.g. `3.14159`, returning its digits as an integer and the number
+/// of digits after the decimal point.
+pub fn parse_decimal(arg: &str) -> Option<(u64, u32)> {
+    let (whole, frac) = arg.trim().split_once('.')?;
+    let digits = format!("{}{}", whole, frac);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((digits.parse().ok()?, frac.len() as u32))
+}
+
+
+/// Returns the interval of values that round to the decimal `digits × 10^-places`, i.e. the
+/// decimal plus or minus half a unit in its last place.
+pub fn decimal_interval(digits: u64, places: u32) -> Option<(Ratio, Ratio)> {
+    let den = 10u64.checked_pow(places)?.checked_mul(2)?;
+    let num = digits.checked_mul(2)?;
+    Some((Ratio { num: num.saturating_sub(1), den }, Ratio { num: num.checked_add(1)?, den }))
+}
+
+
+/// The location of a fraction in the Stern–Brocot tree, which contains every positive rational
+/// exactly once, in lowest terms. Each node is the mediant of its nearest ancestors on either
+/// side, `(a + c)/(b + d)` for `a/b` and `c/d`, starting from the root `1/1` between `0/1` and
+/// `1/0`.
+#[derive(Debug, PartialEq)]
+pub struct Location {
+    pub node: Ratio,
+    /// The path from the root as runs of left (`L`) and right (`R`) moves.
+    pub runs: Vec<(char, u64)>,
+    /// The nearest ancestor to the left. For a node `p/q` below 1, this and `right` are the
+    /// neighbours of `p/q` in the Farey sequence of order `q`.
+    pub left: Ratio,
+    /// The nearest ancestor to the right, `1/0` if the path is all right moves.
+    pub right: Ratio,
+}
+
+
+/// Locates the simplest fraction in the closed interval `[lo, hi]` in the Stern–Brocot tree,
+/// i.e. the first node on the path that falls inside the interval. Pass the same value for both
+/// bounds to locate an exact fraction. Returns `None` if the interval is empty or not positive.
+pub fn locate(lo: Ratio, hi: Ratio) -> Option<Location> {
+    let (lp, lq, hp, hq) = (lo.num as u128, lo.den as u128, hi.num as u128, hi.den as u128);
+    if lp == 0 || lq == 0 || hq == 0 || lp * hq > hp * lq {
+        return None;
+    }
+    let (mut a, mut b, mut c, mut d) = (0u128, 1u128, 1u128, 0u128);
+    let mut runs = Vec::new();
+    loop {
+        let (p, q) = (a + c, b + d);
+        if p * lq < lp * q {
+            // Move right as many times as the mediant stays below the interval.
+            let k = (lp * b - a * lq - 1) / (c * lq - lp * d);
+            a += k * c;
+            b += k * d;
+            runs.push(('R', k as u64));
+        } else if p * hq > hp * q {
+            // Move left as many times as the mediant stays above the interval.
+            let k = (c * hq - hp * d - 1) / (hp * b - a * hq);
+            c += k * a;
+            d += k * b;
+            runs.push(('L', k as u64));
+        } else {
+            return Some(Location {
+                node: Ratio { num: p as u64, den: q as u64 },
+                runs,
+                left: Ratio { num: a as u64, den: b as u64 },
+                right: Ratio { num: c as u64, den: d as u64 },
+            });
+        }
+    }
+}
+
+
+impl Location {
+    /// Returns the depth of the node, the number of moves from the root.
+    pub fn depth(&self) -> u64 {
+        self.runs.iter().map(|(_, count)| count).sum()
+    }
+
+    /// Returns the node reached at the end of each run of the path, starting with the root.
+    pub fn run_nodes(&self) -> Vec<Ratio> {
+        let (mut a, mut b, mut c, mut d) = (0, 1, 1, 0);
+        let mut nodes = vec![Ratio { num: 1, den: 1 }];
+        for (direction, count) in &self.runs {
+            if *direction == 'R' {
+                a += count * c;
+                b += count * d;
+            } else {
+                c += count * a;
+                d += count * b;
+            }
+            nodes.push(Ratio { num: a + c, den: b + d });
+        }
+        nodes
+    }
+
+    /// Returns every node on the path, one per move, starting with the root and ending with the
+    /// node itself. The path has `depth() + 1` nodes, so this is only practical for shallow nodes.
+    pub fn path_nodes(&self) -> Vec<Ratio> {
+        let (mut a, mut b, mut c, mut d) = (0, 1, 1, 0);
+        let mut nodes = vec![Ratio { num: 1, den: 1 }];
+        for (direction, count) in &self.runs {
+            for _ in 0..*count {
+                if *direction == 'R' {
+                    a += c;
+                    b += d;
+                } else {
+                    c += a;
+                    d += b;
+                }
+                nodes.push(Ratio { num: a + c, den: b + d });
+            }
+        }
+        nodes
+    }
+
+    /// Returns the terms of the node's continued fraction, `[a0; a1, a2, ...]`. The run lengths
+    /// of the path are the terms, with the last term one greater.
+    pub fn continued_fraction(&self) -> Vec<u64> {
+        let mut terms = Vec::new();
+        if self.runs.first().map(|run| run.0) == Some('L') {
+            terms.push(0);
+        }
+        terms.extend(self.runs.iter().map(|(_, count)| count));
+        match terms.last_mut() {
+            Some(last) => *last += 1,
+            None => terms.push(1),
+        }
+        terms
+    }
+}
+
+
+/// Decomposes a rational into its integer part and a sum of distinct unit fractions using the
+/// greedy algorithm, which repeatedly takes the largest unit fraction not exceeding the
+/// remainder. Returns the integer part and the denominators of the unit fractions, or `None` if
+/// a denominator overflows 128 bits. Greedy denominators can grow doubly exponentially, e.g.
+/// 5/121 needs a 25-digit denominator.
+pub fn egyptian(value: Ratio) -> Option<(u64, Vec<u128>)> {
+    if value.den == 0 {
+        return None;
+    }
+    let whole = value.num / value.den;
+    let (mut num, mut den) = ((value.num % value.den) as u128, value.den as u128);
+    let mut denominators = Vec::new();
+    while num != 0 {
+        if den % num == 0 {
+            denominators.push(den / num);
+            break;
+        }
+        let unit = den / num + 1;
+        denominators.push(unit);
+        // num/den - 1/unit = (num × unit - den)/(den × unit), which is smaller than 1/unit.
+        num = num.checked_mul(unit)? - den;
+        den = den.checked_mul(unit)?;
+        let divisor = gcd_u128(num, den);
+        num /= divisor;
+        den /= divisor;
+    }
+    Some((whole, denominators))
+}
+
+
+/// Returns the greatest common divisor of two 128-bit integers, or 0 if both are zero.
+pub fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
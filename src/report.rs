@@ -0,0 +1,550 @@
+// Structured command output. A report is a list of labelled fields, lines of text, tables, and
+// errors, rendered as a text info block, JSON, CSV, or Markdown according to the --format
+// argument. Every command builds its output as reports, so every command supports every format.
+
+use crate::cli::{Context, Error};
+use crate::json;
+use crate::output::Style;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+
+const FORMATS: &[(&str, Format)] = &[
+    ("text", Format::Text),
+    ("json", Format::Json),
+    ("csv", Format::Csv),
+    ("markdown", Format::Markdown),
+];
+
+
+// Returns the output format for the --format argument, text by default.
+pub fn format(format: Option<&str>) -> Result<Format, String> {
+    let format = format.unwrap_or("text");
+    FORMATS.iter().find(|(name, _)| *name == format).map(|(_, format)| *format).ok_or_else(|| {
+        format!("unknown format '{}', expected text, json, csv, or markdown.", format)
+    })
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    // A labelled value, shown as `label: value`, and its JSON value, e.g. a number.
+    Field(String, String, json::Value),
+    // A member shown only in JSON, e.g. a value the text fields already show in another form.
+    Data(String, json::Value),
+    // A line of text without a label.
+    Line(String),
+    // A table. The headers label the columns in the structured formats, while text tables show
+    // only the rows, with each column aligned as specified. A row shorter than the headers is an
+    // error row, with an error message in place of the rest of its values.
+    Table { headers: Vec<String>, align: Vec<Align>, rows: Vec<Vec<String>> },
+    // An error, with a stable code, e.g. `overflow`, and the byte offset of the offending
+    // character in the input, if known.
+    Error { msg: String, code: String, position: Option<usize> },
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    // The argument the report describes, if it describes a single one. Only JSON shows it.
+    pub input: Option<String>,
+    pub items: Vec<Item>,
+}
+
+
+// A field value: its text, and its JSON value, a number for integers and a string otherwise.
+pub trait FieldValue {
+    fn text(&self) -> String;
+
+    fn json(&self) -> json::Value {
+        json::Value::String(self.text())
+    }
+}
+
+
+impl FieldValue for str {
+    fn text(&self) -> String {
+        self.to_string()
+    }
+}
+
+
+impl FieldValue for String {
+    fn text(&self) -> String {
+        self.clone()
+    }
+}
+
+
+impl<T: FieldValue + ?Sized> FieldValue for &T {
+    fn text(&self) -> String {
+        (**self).text()
+    }
+
+    fn json(&self) -> json::Value {
+        (**self).json()
+    }
+}
+
+
+macro_rules! integer_field_value {
+    ($($type:ty),*) => {
+        $(impl FieldValue for $type {
+            fn text(&self) -> String {
+                self.to_string()
+            }
+
+            fn json(&self) -> json::Value {
+                json::Value::Number(self.to_string())
+            }
+        })*
+    };
+}
+
+
+integer_field_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128);
+
+
+// Not-a-number and the infinities have no JSON number, so they stay strings.
+impl FieldValue for f64 {
+    fn text(&self) -> String {
+        self.to_string()
+    }
+
+    fn json(&self) -> json::Value {
+        match self.is_finite() {
+            true => json::Value::Number(self.to_string()),
+            false => json::Value::String(self.to_string()),
+        }
+    }
+}
+
+
+impl FieldValue for char {
+    fn text(&self) -> String {
+        self.to_string()
+    }
+}
+
+
+impl Report {
+    pub fn new() -> Report {
+        Report::default()
+    }
+
+
+    // An error without a more specific code than `invalid_input`.
+    pub fn error(msg: &str) -> Report {
+        Report::new().and_error(msg)
+    }
+
+
+    pub fn error_at(msg: &str, code: &str, position: Option<usize>) -> Report {
+        let (msg, code) = (msg.to_string(), code.to_string());
+        Report { input: None, items: vec![Item::Error { msg, code, position }] }
+    }
+
+
+    // Adds an error after the items so far, for an input that's only partly decoded.
+    pub fn and_error(mut self, msg: &str) -> Report {
+        let (msg, code) = (msg.to_string(), "invalid_input".to_string());
+        self.items.push(Item::Error { msg, code, position: None });
+        self
+    }
+
+
+    pub fn input(mut self, arg: &str) -> Report {
+        self.input = Some(arg.to_string());
+        self
+    }
+
+
+    pub fn field(mut self, label: &str, value: impl FieldValue) -> Report {
+        self.items.push(Item::Field(label.to_string(), value.text(), value.json()));
+        self
+    }
+
+
+    // Adds a field with a JSON value that isn't just its text, e.g. an object of its parts.
+    pub fn typed(mut self, label: &str, text: impl ToString, json: json::Value) -> Report {
+        self.items.push(Item::Field(label.to_string(), text.to_string(), json));
+        self
+    }
+
+
+    // Adds a member that only the JSON output shows.
+    pub fn data(mut self, key: &str, json: json::Value) -> Report {
+        self.items.push(Item::Data(key.to_string(), json));
+        self
+    }
+
+
+    pub fn table(self, headers: &[&str], rows: Vec<Vec<String>>) -> Report {
+        self.aligned_table(headers, &[], rows)
+    }
+
+
+    // Adds a table with the specified alignment for each column. Columns without one are
+    // aligned to the left.
+    pub fn aligned_table(
+        mut self,
+        headers: &[&str],
+        align: &[Align],
+        rows: Vec<Vec<String>>,
+    ) -> Report {
+        let headers = headers.iter().map(|header| header.to_string()).collect();
+        let align = align.to_vec();
+        self.items.push(Item::Table { headers, align, rows });
+        self
+    }
+
+
+    pub fn line(mut self, text: impl ToString) -> Report {
+        self.items.push(Item::Line(text.to_string()));
+        self
+    }
+
+
+    // Appends the items of another report, e.g. the base conversions shared by several commands.
+    pub fn append(mut self, other: Report) -> Report {
+        self.items.extend(other.items);
+        self
+    }
+
+
+    // Labels are translated into the selected language and padded to the width of the widest so
+    // the values line up. Labels, binary digits, and errors are colored if color is on.
+    pub fn to_text(&self, style: &Style) -> String {
+        let width = self.labels(style).map(|label| label.chars().count()).max().unwrap_or(0);
+        let mut lines = Vec::new();
+        for item in &self.items {
+            match item {
+                Item::Field(key, value, _) => {
+                    let label = style.locale.label(style.command, key);
+                    let padding = " ".repeat(width.saturating_sub(label.chars().count()));
+                    let indent = format!("\n{}", " ".repeat(width.max(label.chars().count()) + 2));
+                    let value = match key.as_str() {
+                        "bin" => style.paint_bits(value),
+                        _ => value.clone(),
+                    };
+                    let label = style.paint_label(&format!("{}:", label));
+                    lines.push(format!("{}{} {}", label, padding, value.replace('\n', &indent)));
+                },
+                Item::Data(..) => {},
+                Item::Line(line) => lines.push(line.clone()),
+                Item::Table { headers, align, rows } => {
+                    let rows: Vec<Vec<String>> = rows
+                        .iter()
+                        .map(|row| match split_error(row, headers) {
+                            Some((cells, msg)) => {
+                                let msg = format!("{}: {}", style.locale.error, msg);
+                                let mut row = cells.to_vec();
+                                row.push(style.paint_error(&msg));
+                                row
+                            },
+                            None => row.clone(),
+                        })
+                        .collect();
+                    lines.extend(aligned(&rows, align));
+                },
+                Item::Error { msg, .. } => {
+                    let line = format!("{}: {}", style.locale.error, msg);
+                    lines.push(style.paint_error(&line));
+                },
+            }
+        }
+        lines.join("\n")
+    }
+
+
+    // The translated labels of the report's fields.
+    fn labels<'a>(&'a self, style: &'a Style) -> impl Iterator<Item = &'a str> {
+        self.items.iter().filter_map(move |item| match item {
+            Item::Field(label, ..) => Some(style.locale.label(style.command, label)),
+            _ => None,
+        })
+    }
+
+
+    // The input comes first, then fields and JSON-only data become members of a JSON object, with
+    // the values of repeated labels collected in an array. Lines are collected under "text",
+    // tables become arrays of objects keyed by their headers, with an "error" member for an error
+    // row, and errors become "error", "code", and "position" members.
+    pub fn to_json(&self) -> json::Value {
+        let mut members: Vec<(String, Vec<json::Value>)> = Vec::new();
+        if let Some(input) = &self.input {
+            members.push(("input".to_string(), vec![json::Value::String(input.clone())]));
+        }
+        let mut add = |key: &str, value: json::Value| {
+            match members.iter_mut().find(|(k, _)| k == key) {
+                Some((_, values)) => values.push(value),
+                None => members.push((key.to_string(), vec![value])),
+            }
+        };
+        for item in &self.items {
+            match item {
+                Item::Field(label, _, value) | Item::Data(label, value) => {
+                    add(label, value.clone())
+                },
+                Item::Line(line) => add("text", json::Value::String(line.clone())),
+                Item::Table { headers, rows, .. } => {
+                    let rows = rows
+                        .iter()
+                        .map(|row| {
+                            let (cells, msg) = match split_error(row, headers) {
+                                Some((cells, msg)) => (cells, Some(msg)),
+                                None => (&row[..], None),
+                            };
+                            let mut cells: Vec<(String, json::Value)> = headers
+                                .iter()
+                                .zip(cells)
+                                .map(|(header, cell)| {
+                                    (header.clone(), json::Value::String(cell.clone()))
+                                })
+                                .collect();
+                            if let Some(msg) = msg {
+                                cells.push(("error".to_string(), json::Value::String(msg.clone())));
+                            }
+                            json::Value::Object(cells)
+                        })
+                        .collect();
+                    add("table", json::Value::Array(rows));
+                },
+                Item::Error { msg, code, position } => {
+                    add("error", json::Value::String(msg.clone()));
+                    add("code", json::Value::String(code.clone()));
+                    let position = position.map(|offset| json::Value::Number(offset.to_string()));
+                    add("position", position.unwrap_or(json::Value::Null));
+                },
+            }
+        }
+        let members = members
+            .into_iter()
+            .map(|(key, mut values)| match values.len() {
+                1 => (key, values.remove(0)),
+                _ => (key, json::Value::Array(values)),
+            })
+            .collect();
+        json::Value::Object(members)
+    }
+
+
+    // Every row starts with the input, then fields, lines, and errors become `field,value` rows,
+    // and tables their own rows, with an `error` column if they have error rows. A header row
+    // names the columns whenever they change from the `previous` header, so a batch of reports
+    // of the same shape has a single header.
+    pub fn to_csv(&self, previous: &mut Option<Vec<String>>) -> String {
+        let input = self.input.clone().unwrap_or_default();
+        let field_header = || ["input", "field", "value"].map(String::from).to_vec();
+        let mut sections: Vec<(Vec<String>, Vec<Vec<String>>)> = Vec::new();
+        let mut add = |header: Vec<String>, row: Vec<String>| match sections.last_mut() {
+            Some((last, rows)) if *last == header => rows.push(row),
+            _ => sections.push((header, vec![row])),
+        };
+        for item in &self.items {
+            let (label, value) = match item {
+                Item::Field(label, value, _) => (label.as_str(), value),
+                Item::Data(..) => continue,
+                Item::Line(line) => ("text", line),
+                Item::Error { msg, .. } => ("error", msg),
+                Item::Table { headers, rows, .. } => {
+                    let errors = rows.iter().any(|row| split_error(row, headers).is_some());
+                    let mut header = vec!["input".to_string()];
+                    header.extend(headers.iter().cloned());
+                    if errors {
+                        header.push("error".to_string());
+                    }
+                    for row in rows {
+                        let mut cells = vec![input.clone()];
+                        match split_error(row, headers) {
+                            Some((values, msg)) => {
+                                cells.extend(values.iter().cloned());
+                                cells.resize(headers.len() + 1, String::new());
+                                cells.push(msg.clone());
+                            },
+                            None => {
+                                cells.extend(row.iter().cloned());
+                                if errors {
+                                    cells.push(String::new());
+                                }
+                            },
+                        }
+                        add(header.clone(), cells);
+                    }
+                    continue;
+                },
+            };
+            add(field_header(), vec![input.clone(), label.to_string(), value.clone()]);
+        }
+        let mut lines = Vec::new();
+        for (header, rows) in sections {
+            if previous.as_ref() != Some(&header) {
+                lines.push(csv_row(&header));
+                *previous = Some(header);
+            }
+            lines.extend(rows.iter().map(|row| csv_row(row)));
+        }
+        lines.join("\n")
+    }
+
+
+    // Runs of fields become a two-column table of translated labels and values, lines become
+    // paragraphs, and tables keep their headers.
+    pub fn to_markdown(&self, style: &Style) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let mut fields: Vec<Vec<String>> = Vec::new();
+        let label = |label| style.locale.label(style.command, label);
+        let flush = |fields: &mut Vec<Vec<String>>, parts: &mut Vec<String>| {
+            if !fields.is_empty() {
+                let headers = [label("Field"), label("Value")];
+                parts.push(markdown_table(&headers, fields));
+                fields.clear();
+            }
+        };
+        for item in &self.items {
+            if let Item::Field(key, value, _) = item {
+                fields.push(vec![label(key).to_string(), value.clone()]);
+                continue;
+            }
+            flush(&mut fields, &mut parts);
+            match item {
+                Item::Data(..) => {},
+                Item::Line(line) => parts.push(line.clone()),
+                Item::Table { headers, rows, .. } => {
+                    let rows: Vec<Vec<String>> = rows
+                        .iter()
+                        .map(|row| match split_error(row, headers) {
+                            Some((cells, msg)) => {
+                                let mut row = cells.to_vec();
+                                row.push(format!("**{}:** {}", style.locale.error, msg));
+                                row
+                            },
+                            None => row.clone(),
+                        })
+                        .collect();
+                    let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+                    parts.push(markdown_table(&headers, &rows));
+                },
+                Item::Error { msg, .. } => {
+                    parts.push(format!("**{}:** {}", style.locale.error, msg))
+                },
+                Item::Field(..) => unreachable!(),
+            }
+        }
+        flush(&mut fields, &mut parts);
+        parts.join("\n\n")
+    }
+
+
+    // Returns the report in the specified style, ending with a newline.
+    pub fn render(&self, style: &Style) -> String {
+        match style.format {
+            Format::Text => format!("{}\n", self.to_text(style)),
+            Format::Json => format!("{}\n", self.to_json()),
+            Format::Csv => format!("{}\n", self.to_csv(&mut None)),
+            Format::Markdown => format!("{}\n\n", self.to_markdown(style)),
+        }
+    }
+
+
+    // Writes the report to the context's output in its style. CSV header rows are only repeated
+    // when the columns change.
+    pub fn print(&self, ctx: &mut Context) -> Result<(), Error> {
+        let text = match ctx.style.format {
+            Format::Csv => format!("{}\n", self.to_csv(&mut ctx.csv_header)),
+            _ => self.render(&ctx.style),
+        };
+        ctx.out.write_all(text.as_bytes())?;
+        Ok(())
+    }
+}
+
+
+// A message is an error without a more specific code than `invalid_input`.
+impl From<String> for Report {
+    fn from(msg: String) -> Report {
+        Report::error(&msg)
+    }
+}
+
+
+// Pads the cells of each row to the width of the widest cell in its column, with two spaces
+// between columns. The last cell of a row shorter than the others, e.g. an error message in
+// place of a row's values, runs on without widening its column.
+fn aligned(rows: &[Vec<String>], align: &[Align]) -> Vec<String> {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let mut widths: Vec<usize> = vec![0; columns];
+    for row in rows {
+        let measured = if row.len() < columns { row.len().saturating_sub(1) } else { row.len() };
+        for (index, cell) in row[..measured].iter().enumerate() {
+            widths[index] = widths[index].max(cell.chars().count());
+        }
+    }
+    rows.iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(index, (cell, width))| match align.get(index) {
+                    _ if index + 1 == row.len() && row.len() < columns => cell.clone(),
+                    Some(Align::Right) => format!("{:>width$}", cell, width = width),
+                    _ => format!("{:<width$}", cell, width = width),
+                })
+                .collect();
+            cells.join("  ").trim_end().to_string()
+        })
+        .collect()
+}
+
+
+// Splits an error row, shorter than the table's headers, into its values and its error message.
+fn split_error<'a>(row: &'a [String], headers: &[String]) -> Option<(&'a [String], &'a String)> {
+    match row.split_last() {
+        Some((msg, cells)) if row.len() < headers.len() => Some((cells, msg)),
+        _ => None,
+    }
+}
+
+
+fn csv_row(cells: &[String]) -> String {
+    cells.iter().map(|cell| csv_cell(cell)).collect::<Vec<_>>().join(",")
+}
+
+
+// Quotes a CSV cell if it contains a comma, quote, or line break, doubling any quotes.
+fn csv_cell(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+
+fn markdown_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let escape = |cell: &str| cell.replace('|', "\\|").replace('\n', "<br>");
+    let mut lines = vec![
+        format!("| {} |", headers.join(" | ")),
+        format!("|{}", " --- |".repeat(headers.len())),
+    ];
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(|cell| escape(cell)).collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,92 @@
+//! Residue number system representation and reconstruction via the Chinese remainder theorem.
+
+use crate::exif::gcd;
+
+
+/// Parses a comma-separated list of moduli, e.g. `3,5,7`. Each modulus must be at least 2.
+pub fn parse_moduli(arg: &str) -> Option<Vec<u64>> {
+    let moduli = arg
+        .split(',')
+        .map(|m| crate::parse_uint(m.trim()).ok().filter(|m| *m >= 2))
+        .collect::<Option<Vec<u64>>>()?;
+    if moduli.is_empty() {
+        None
+    } else {
+        Some(moduli)
+    }
+}
+
+
+/// Returns the product of a set of moduli, the dynamic range of the residue number system they
+/// define. Returns an error if two moduli share a factor or the product overflows 64 bits.
+pub fn moduli_product(moduli: &[u64]) -> Result<u64, String> {
+    for (i, a) in moduli.iter().enumerate() {
+        for b in &moduli[i + 1..] {
+            if gcd(*a, *b) != 1 {
+                return Err(format!("moduli {} and {} are not coprime", a, b));
+            }
+        }
+    }
+    moduli
+        .iter()
+        .try_fold(1u64, |acc, m| acc.checked_mul(*m))
+        .ok_or_else(|| "the product of the moduli overflows 64 bits".to_string())
+}
+
+
+/// Returns the residues of a value modulo each of a set of moduli.
+pub fn residues(value: u64, moduli: &[u64]) -> Vec<u64> {
+    moduli.iter().map(|m| value % m).collect()
+}
+
+
+/// Returns the multiplicative inverse of `a` modulo `m`, if it exists, using the extended
+/// Euclidean algorithm.
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (a as i128 % m as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    if old_r != 1 {
+        return None;
+    }
+    Some(old_s.rem_euclid(m as i128) as u64)
+}
+
+
+/// One term of a Chinese remainder theorem reconstruction, for the modulus `m` with residue `r`:
+/// `partial` is the product of the other moduli, `M / m`, and `inverse` is its inverse modulo
+/// `m`. The term contributed to the sum is `r × inverse × partial`, reduced modulo `M`.
+#[derive(Debug, PartialEq)]
+pub struct CrtTerm {
+    pub modulus: u64,
+    pub residue: u64,
+    pub partial: u64,
+    pub inverse: u64,
+    pub term: u64,
+}
+
+
+/// Reconstructs the unique value modulo the product of a set of pairwise coprime moduli with
+/// the specified residues, returning the value and the terms of the sum. Residues are reduced
+/// modulo their moduli.
+pub fn crt(residues: &[u64], moduli: &[u64]) -> Result<(u64, Vec<CrtTerm>), String> {
+    if residues.len() != moduli.len() {
+        return Err(format!("expected {} residues, found {}", moduli.len(), residues.len()));
+    }
+    let product = moduli_product(moduli)?;
+    let mut terms = Vec::new();
+    let mut sum: u128 = 0;
+    for (r, m) in residues.iter().zip(moduli) {
+        let partial = product / m;
+        let inverse = mod_inverse(partial % m, *m).unwrap();
+        // Reducing r × inverse modulo m first keeps the term below the product.
+        let term = ((*r % m) as u128 * inverse as u128 % *m as u128 * partial as u128) as u64;
+        sum += term as u128;
+        terms.push(CrtTerm { modulus: *m, residue: *r % m, partial, inverse, term });
+    }
+    Ok(((sum % product as u128) as u64, terms))
+}
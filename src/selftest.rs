@@ -0,0 +1,293 @@
+//! Golden test vectors for the decoders, embedded in the library so an installed binary can check
+//! its own results, e.g. on an unusual platform or after a cross-compile. Every command has at
+//! least one vector, which runs it on a sample input and checks a line of its output.
+
+use crate::{checksum, cli, coding, commands, datetime, factor, float, ids};
+use std::fmt::Debug;
+
+
+/// The result of a single test vector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    /// The group of related vectors, e.g. `checksums`.
+    pub group: &'static str,
+    /// The computation, e.g. `CRC-32/ISO-HDLC("123456789")`.
+    pub name: String,
+    /// The expected result, in debug format.
+    pub expected: String,
+    /// The actual result, in debug format.
+    pub actual: String,
+}
+
+
+impl Outcome {
+    /// Returns true if the actual result matches the expected result.
+    pub fn passed(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+
+fn debug(value: impl Debug) -> String {
+    format!("{:?}", value)
+}
+
+
+// Converts rows of names, expected results, and actual results into outcomes.
+fn group<T: ToString>(group: &'static str, rows: Vec<(T, &str, String)>) -> Vec<Outcome> {
+    rows.into_iter()
+        .map(|(name, expected, actual)| Outcome {
+            group,
+            name: name.to_string(),
+            expected: expected.to_string(),
+            actual,
+        })
+        .collect()
+}
+
+
+fn integers() -> Vec<Outcome> {
+    let rows = vec![
+        ("parse_int(\"0x1F\")", "Ok(31)", debug(crate::parse_int("0x1F"))),
+        ("parse_int(\"-b1010\")", "Ok(-10)", debug(crate::parse_int("-b1010"))),
+        ("eval_expr(\"1 << 20\")", "Ok(1048576)", debug(crate::eval_expr("1 << 20"))),
+        ("bin_string(255, 12)", "\"0000 1111_1111\"", debug(crate::bin_string(255, 12))),
+        ("twos_complement(1, 8)", "255", debug(crate::twos_complement(1, 8))),
+        ("byte_swap(0x1234, 16)", "13330", debug(crate::byte_swap(0x1234, 16))),
+        ("popcount(u128::MAX, 128)", "128", debug(crate::popcount(u128::MAX, 128))),
+        ("to_base(35, 36)", "\"z\"", debug(crate::to_base(35, 36))),
+    ];
+    group("integers", rows)
+}
+
+
+fn checksums() -> Vec<Outcome> {
+    let mut outcomes: Vec<Outcome> = checksum::CRC_PRESETS
+        .iter()
+        .map(|crc| Outcome {
+            group: "checksums",
+            name: format!("{}(\"123456789\")", crc.name),
+            expected: debug(crc.check),
+            actual: debug(crc.compute(b"123456789")),
+        })
+        .collect();
+    let header = [0x00, 0x01, 0xF2, 0x03, 0xF4, 0xF5, 0xF6, 0xF7];
+    let others = vec![
+        ("adler32(\"123456789\")", "152961502", debug(checksum::adler32(b"123456789"))),
+        ("fletcher16(\"abcde\")", "51440", debug(checksum::fletcher16(b"abcde"))),
+        ("internet_checksum(header)", "8717", debug(checksum::internet_checksum(&header))),
+    ];
+    outcomes.extend(group("checksums", others));
+    #[cfg(feature = "hashes")]
+    {
+        use crate::hash;
+        let hashes = vec![
+            ("fnv1a_32(\"a\")", "3826002220", debug(hash::fnv1a_32(b"a"))),
+            ("fnv1a_64(\"a\")", "12638187200555641996", debug(hash::fnv1a_64(b"a"))),
+            ("djb2_32(\"a\")", "177670", debug(hash::djb2_32(b"a"))),
+            ("murmur3_32(\"hello\", 0)", "613153351", debug(hash::murmur3_32(b"hello", 0))),
+        ];
+        outcomes.extend(group("checksums", hashes));
+    }
+    outcomes
+}
+
+
+fn floats() -> Vec<Outcome> {
+    let (f32, f64) = (float::Width::F32, float::Width::F64);
+    let class = |bits: u64| debug(float::fields(bits, f32).class(f32));
+    let exact = |value: f64| debug(float::fields(value.to_bits(), f64).exact_decimal(f64));
+    let fields = float::fields(1.5f64.to_bits(), f64);
+    let fields = (fields.sign, fields.exponent, fields.mantissa);
+    let rows = vec![
+        ("fields(1.5f64)", "(0, 1023, 2251799813685248)", debug(fields)),
+        ("class(0x80000000f32)", "Zero", class(0x80000000)),
+        ("class(0x00000001f32)", "Subnormal", class(0x00000001)),
+        ("class(0x3F800000f32)", "Normal", class(0x3F800000)),
+        ("class(0x7F800000f32)", "Infinite", class(0x7F800000)),
+        ("class(0x7FC00000f32)", "QuietNan", class(0x7FC00000)),
+        ("class(0x7FA00000f32)", "SignalingNan", class(0x7FA00000)),
+        ("exact_decimal(0.5f64)", "Some(\"0.5\")", exact(0.5)),
+        ("exact_decimal(1e16f64)", "Some(\"10000000000000000\")", exact(1e16)),
+        ("parse_float(\"-1e-3\")", "Some(-0.001)", debug(float::parse_float("-1e-3"))),
+    ];
+    group("floats", rows)
+}
+
+
+fn timestamps() -> Vec<Outcome> {
+    let utc = |secs: i64| debug(datetime::format_utc(secs));
+    let ulid = ids::ulid_decode("01ARYZ6S41TSV4RRFFQ69G5FAV");
+    let ulid = ulid.map(|value| ids::ulid_fields(value).0);
+    let duration = debug(datetime::parse_duration("2h30m"));
+    let rows = vec![
+        ("format_utc(0)", "\"1970-01-01 00:00:00 UTC\"", utc(0)),
+        ("format_utc(1000000000)", "\"2001-09-09 01:46:40 UTC\"", utc(1_000_000_000)),
+        ("format_utc(-1)", "\"1969-12-31 23:59:59 UTC\"", utc(-1)),
+        ("format_utc(951782400)", "\"2000-02-29 00:00:00 UTC\"", utc(951_782_400)),
+        ("format_utc(4107542399)", "\"2100-02-28 23:59:59 UTC\"", utc(4_107_542_399)),
+        ("days_from_civil(2024, 3, 15)", "19797", debug(datetime::days_from_civil(2024, 3, 15))),
+        ("iso_week(2021, 1, 3)", "(2020, 53)", debug(datetime::iso_week(2021, 1, 3))),
+        ("parse_duration(\"2h30m\")", "Ok(9000000000000)", duration),
+        ("ulid_fields(01ARYZ6S41TSV4RRFFQ69G5FAV)", "Some(1469918176385)", debug(ulid)),
+    ];
+    group("timestamps", rows)
+}
+
+
+fn unicode() -> Vec<Outcome> {
+    let samples = [
+        ('A', "[65]", "[65]"),
+        ('é', "[195, 169]", "[233]"),
+        ('€', "[226, 130, 172]", "[8364]"),
+        ('😀', "[240, 159, 152, 128]", "[55357, 56832]"),
+    ];
+    let mut rows = Vec::new();
+    for (c, utf8, utf16) in samples {
+        let name = format!("U+{:04X}", c as u32);
+        rows.push((format!("utf8_bytes({})", name), utf8, debug(crate::utf8_bytes(c))));
+        rows.push((format!("utf16_units({})", name), utf16, debug(crate::utf16_units(c))));
+    }
+    #[cfg(feature = "unicode")]
+    {
+        let name = |c: char| debug(crate::unicode::name(c));
+        rows.push(("name(U+20AC)".to_string(), "Some(\"EURO SIGN\")", name('€')));
+        rows.push(("name(U+AC00)".to_string(), "Some(\"HANGUL SYLLABLE GA\")", name('가')));
+    }
+    group("unicode", rows)
+}
+
+
+fn coding() -> Vec<Outcome> {
+    let gamma = coding::elias_gamma(9).map(|(prefix, digits)| prefix + &digits);
+    let factors = debug(factor::factorize(600851475143));
+    let rows = vec![
+        ("factorize(600851475143)", "[(71, 1), (839, 1), (1471, 1), (6857, 1)]", factors),
+        ("is_prime(2^61 - 1)", "true", debug(factor::is_prime((1 << 61) - 1))),
+        ("zigzag(-1)", "1", debug(coding::zigzag(-1))),
+        ("elias_gamma(9)", "Some(\"0001001\")", debug(gamma)),
+    ];
+    group("coding", rows)
+}
+
+
+// A sample command line for each command, with its piped stdin, and a line of its output. The
+// relative times are measured from the epoch, as the library's clock reads 0.
+const COMMANDS: &[(&[&str], &str, &str)] = &[
+    (&["arith", "-m", "abc", "a:1", "b:2", "c:1"], "", "cod: 00101 (5 bits)"),
+    (&["asn1", "1.2.840.113549"], "", "der: 06 06 2A 86 48 86 F7 0D"),
+    (&["barcode", "01234567"], "", "chk: 40 (weighted sum mod 103)"),
+    (&["bit", "set", "0", "3"], "", "aft: 1000  hex 8  dec 8"),
+    (&["bswap", "0x1234"], "", "swp: 1234 → 3412"),
+    (&["bytes", "0x01020304"], "", "le:  04 03 02 01   \\x04\\x03\\x02\\x01"),
+    (&["channel", "6"], "", "wifi2g: 2407 + 5 × 6 = 2437 MHz"),
+    (&["check", "4111111111111111"], "", "lnh: 0 (valid)"),
+    (&["checksum", "123456789"], "", "CRC-16/MODBUS    4B37"),
+    (&["chmod", "755"], "", "sym: rwxr-xr-x"),
+    (&["choose", "52", "5"], "", "cmb: 2,598,960"),
+    (&["color", "0xFF8000"], "", "enc: 0xFC00 (rgb565)"),
+    (&["cost", "300"], "", "zzv: 2 bytes (zigzag 600)"),
+    (&["country", "826"], "", "al3: GBR"),
+    (&["cp2l", "0x20AC"], "", "u8:  E2 82 AC"),
+    (&["crc", "123456789"], "", "hex: CB F4 39 26"),
+    (&["date", "20240315"], "", "iso: 2024-W11-5"),
+    (&["decimal", "0x2238000000000001"], "", "fmt: decimal64 (16 digits, bias 398)"),
+    (&["diff", "5", "6"], "", "cnt: 2 bits differ"),
+    (&["digits", "42"], "", "deva:     ४२"),
+    (&["dns", "28"], "", "typ: 28 (0x001C) AAAA"),
+    (&["dump", "-w", "3"], "abc", "00000000  61 62 63  01100001 01100010 01100011  abc"),
+    (&["duration", "2h30m"], "", "sec: 9,000"),
+    (&["egyptian", "4/13"], "", "egy: 1/4 + 1/18 + 1/468"),
+    (&["elias", "9"], "", "gam: 000 1001"),
+    (&["encode", "0x4D616E"], "", "b64: AE1hbg=="),
+    (&["entropy", "abc"], "", "ent: 1.5850 bits per byte (19.8% of 8)"),
+    (&["exif", "6"], "", "ori: 6 (right-top)"),
+    (&["factor", "360"], "", "fac: 2^3 × 3^2 × 5"),
+    (&["factoradic", "463"], "", "fac: 3:4:1:0:1:0"),
+    (&["field", "0xABCD", "7:4"], "", "hex: C"),
+    (&["float", "--f32", "0x3FC00000"], "", "val: 1.5"),
+    (&["geo", "85283473fffffff"], "", "lat: 37.345793 (cell center)"),
+    (&["geo", "u4pruydqqvj"], "", "lat: 57.649111 ± 0.000001 [57.649110, 57.649111]"),
+    (&["gray", "5"], "", "gry: 7 (0x7)"),
+    (&["hash", "abc"], "", "hex: 1A 47 E9 0B"),
+    (&["huffman", "a:5", "b:2", "c:1"], "", "tot: 11 bits for 8 symbols"),
+    (&["ident", "0x9E3779B9"], "", "mat: golden ratio (32-bit)"),
+    (&["ip", "192.168.0.1"], "", "typ: private (RFC 1918)"),
+    (&["jwt", "eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiIxIn0.c2ln"], "", "pld: {\"sub\":\"1\"}"),
+    (&["ksuid", "0ujtsYcgvSTl8PAuAdqWYSMnLOv"], "", "tim: 1507608047 (2017-10-10 04:00:47 UTC, \
+        offset 107608047 from the KSUID epoch)"),
+    (&["l2cp", "€"], "", "uni: U+20AC"),
+    (&["mac", "00:1A:2B:3C:4D:5E"], "", "e64: 021a:2bff:fe3c:4d5e"),
+    (&["midi", "69"], "", "frq: 440.00 Hz"),
+    (&["nbits", "0x1d00ffff"], "", "dif: 1"),
+    (&["numerals", "1999"], "", "vig: 4.19.19"),
+    (&["objectid", "507f1f77bcf86cd799439011"], "", "tim: 1350508407 (2012-10-17 21:13:27 UTC)"),
+    (&["ops", "and", "12", "10"], "", "and: 1000  hex 8  dec  8  oct 10"),
+    (&["ptr", "0x7ffe0010"], "", "aln: 16 bytes (4 low bits zero)"),
+    (&["rand", "--seed", "1", "--bits", "16"], "", "hex: 91 0A"),
+    (&["repl"], "0x2A\n", "dec: 42"),
+    (&["rice", "-k", "2", "9"], "", "ric: 110 01"),
+    (&["rns", "--moduli", "3,5,7", "23"], "", "res: (2, 3, 2)"),
+    (&["runs", "0xF0"], "", "rle: 1×4 0×4"),
+    (&["signed-digits", "7"], "", "naf: 100T"),
+    (&["selftest", "--help"], "", "Usage: intspector selftest"),
+    (&["seq", "1", "2", "4", "8"], "", "rul: powers of 2"),
+    (&["shift", "--left", "4", "1"], "", "aft: 0001_0000  hex 10  dec 16"),
+    (&["snowflake", "175928847299117063"], "", "tim: 1330779680453 (2012-03-03 13:01:20.453 UTC)"),
+    (&["sb", "3/7"], "", "pth: L2 R2"),
+    (&["synchsafe", "255"], "", "syn: 0x0000_017F"),
+    (&["syscall", "1"], "", "x64: 1 (0x1) write"),
+    (&["time", "1000000000"], "", "sec: 2001-09-09 01:46:40 UTC, in 31 years"),
+    (&["ulid", "01ARYZ6S41TSV4RRFFQ69G5FAV"], "", "rnd: D6 76 4C 61 EF B9 93 02 BD 5B"),
+    (&["utf8", "E2", "82", "AC"], "", "cnt: 3 bytes, 1 character, 0 errors"),
+    (&["varint", "300"], "", "leb: AC 02 (2 bytes)"),
+];
+
+
+// Runs each command's vectors. The actual result is the output line with the same first word as
+// the expected line, e.g. its label, and a command without a vector fails.
+fn commands() -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+    for decoder in commands::DECODERS {
+        let names: Vec<&str> = decoder.names().split(' ').collect();
+        let vectors: Vec<_> =
+            COMMANDS.iter().filter(|(args, ..)| names.contains(&args[0])).collect();
+        if vectors.is_empty() {
+            outcomes.push(Outcome {
+                group: "commands",
+                name: decoder.name().to_string(),
+                expected: "a test vector".to_string(),
+                actual: "none".to_string(),
+            });
+        }
+        for (args, stdin, expected) in vectors {
+            let mut out = Vec::new();
+            let output = match cli::run_with_stdin(args, stdin.as_bytes(), &mut out) {
+                Ok(()) => String::from_utf8_lossy(&out).into_owned(),
+                Err(err) => format!("Error: {}", err),
+            };
+            let word = |line: &str| line.split_whitespace().next().map(str::to_string);
+            let actual = output
+                .lines()
+                .map(str::trim_end)
+                .find(|line| word(line) == word(expected))
+                .or_else(|| output.lines().next())
+                .unwrap_or("");
+            outcomes.push(Outcome {
+                group: "commands",
+                name: format!("intspector {}", args.join(" ")),
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            });
+        }
+    }
+    outcomes
+}
+
+
+/// Runs every test vector and returns the outcomes, in groups.
+pub fn run() -> Vec<Outcome> {
+    let groups =
+        vec![integers(), checksums(), floats(), timestamps(), unicode(), coding(), commands()];
+    groups.into_iter().flatten().collect()
+}
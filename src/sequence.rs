@@ -0,0 +1,319 @@
+//! Recognition of simple integer sequences - progressions, powers, factorials, Fibonacci-like
+//! recurrences, and polynomials - for predicting the next terms.
+
+use crate::ratio::gcd_u128;
+use std::convert::TryFrom;
+
+
+/// A rule generating a sequence from its index `n`, starting at 0 for the first term.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    /// `a(n) = first + n × difference`.
+    Arithmetic { first: i128, difference: i128 },
+    /// `a(n) = first × ratio^n`, with a ratio other than 0 or 1.
+    Geometric { first: i128, ratio: i128 },
+    /// `a(n) = (n + offset)^exponent`, with an exponent of 2 or more.
+    Power { offset: i128, exponent: u32 },
+    /// `a(n) = (n + offset)!`.
+    Factorial { offset: u32 },
+    /// `a(n) = a(n - 1) + a(n - 2)`, starting from the first two terms.
+    Fibonacci { first: i128, second: i128 },
+    /// A polynomial of degree 2 or more, given by the first entry of each row of its difference
+    /// table, so that `a(n)` is the sum of `differences[k] × C(n, k)`.
+    Polynomial { differences: Vec<i128> },
+}
+
+
+/// Returns the simplest rule that generates all the values, trying each kind of rule in the
+/// order of the `Rule` variants. A polynomial of degree `d` is only accepted if there are at
+/// least `d + 2` values, so that at least one value confirms the fit. Returns `None` if there are
+/// fewer than 3 values or no rule fits.
+pub fn recognize(values: &[i128]) -> Option<Rule> {
+    if values.len() < 3 {
+        return None;
+    }
+    let candidates = [
+        arithmetic(values),
+        geometric(values),
+        power(values),
+        factorial(values),
+        Some(Rule::Fibonacci { first: values[0], second: values[1] }),
+        polynomial(values),
+    ];
+    candidates
+        .iter()
+        .flatten()
+        .find(|rule| matches(rule, values))
+        .cloned()
+}
+
+
+// Returns true if the rule generates every one of the values.
+fn matches(rule: &Rule, values: &[i128]) -> bool {
+    values.iter().enumerate().all(|(n, value)| rule.term(n) == Some(*value))
+}
+
+
+fn arithmetic(values: &[i128]) -> Option<Rule> {
+    let difference = values[1].checked_sub(values[0])?;
+    Some(Rule::Arithmetic { first: values[0], difference })
+}
+
+
+fn geometric(values: &[i128]) -> Option<Rule> {
+    if values[0] == 0 || values[1] % values[0] != 0 {
+        return None;
+    }
+    let ratio = values[1] / values[0];
+    match ratio {
+        0 | 1 => None,
+        _ => Some(Rule::Geometric { first: values[0], ratio }),
+    }
+}
+
+
+fn power(values: &[i128]) -> Option<Rule> {
+    for exponent in 2..128 {
+        let base = match int_root(values[0], exponent) {
+            Some(base) => base,
+            None => continue,
+        };
+        // Try the positive root, then the negative root for even exponents.
+        for base in [base, -base] {
+            let rule = Rule::Power { offset: base, exponent };
+            if matches(&rule, values) {
+                return Some(rule);
+            }
+        }
+    }
+    None
+}
+
+
+// Returns the integer `exponent`-th root of `value` if it's exact. Negative values have roots only
+// for odd exponents.
+fn int_root(value: i128, exponent: u32) -> Option<i128> {
+    if value < 0 && exponent.is_multiple_of(2) {
+        return None;
+    }
+    let magnitude = value.unsigned_abs();
+    let estimate = (magnitude as f64).powf(1.0 / exponent as f64).round() as u128;
+    let root = (estimate.saturating_sub(1)..=estimate + 1)
+        .find(|root| root.checked_pow(exponent) == Some(magnitude))? as i128;
+    Some(if value < 0 { -root } else { root })
+}
+
+
+fn factorial(values: &[i128]) -> Option<Rule> {
+    (0..34)
+        .map(|offset| Rule::Factorial { offset })
+        .find(|rule| matches(rule, values))
+}
+
+
+fn polynomial(values: &[i128]) -> Option<Rule> {
+    let mut row = values.to_vec();
+    let mut differences = Vec::new();
+    while row.len() > 1 {
+        differences.push(row[0]);
+        if row.iter().all(|value| *value == row[0]) {
+            break;
+        }
+        row = row.windows(2).map(|pair| pair[1].checked_sub(pair[0])).collect::<Option<_>>()?;
+    }
+    let degree = differences.len() - 1;
+    if degree < 2 || values.len() < degree + 2 {
+        return None;
+    }
+    Some(Rule::Polynomial { differences })
+}
+
+
+impl Rule {
+    /// Returns the term at index `n`, or `None` if it overflows 128 bits.
+    pub fn term(&self, n: usize) -> Option<i128> {
+        let index = i128::try_from(n).ok()?;
+        match self {
+            Rule::Arithmetic { first, difference } => {
+                first.checked_add(difference.checked_mul(index)?)
+            },
+            Rule::Geometric { first, ratio } => {
+                first.checked_mul(ratio.checked_pow(u32::try_from(n).ok()?)?)
+            },
+            Rule::Power { offset, exponent } => offset.checked_add(index)?.checked_pow(*exponent),
+            Rule::Factorial { offset } => {
+                (1..=*offset as i128 + index).try_fold(1i128, |acc, i| acc.checked_mul(i))
+            },
+            Rule::Fibonacci { first, second } => {
+                let (mut a, mut b) = (*first, *second);
+                for _ in 0..n {
+                    (a, b) = (b, a.checked_add(b)?);
+                }
+                Some(a)
+            },
+            Rule::Polynomial { differences } => {
+                let mut sum: i128 = 0;
+                let mut binomial: i128 = 1;
+                for (k, difference) in differences.iter().enumerate() {
+                    if k > 0 {
+                        // C(n, k) = C(n, k - 1) × (n - k + 1) / k, exactly.
+                        binomial = binomial.checked_mul(index - k as i128 + 1)? / k as i128;
+                    }
+                    sum = sum.checked_add(difference.checked_mul(binomial)?)?;
+                }
+                Some(sum)
+            },
+        }
+    }
+
+
+    /// Returns the name of the sequence, e.g. `squares` or `Fibonacci numbers`.
+    pub fn name(&self) -> String {
+        match self {
+            Rule::Arithmetic { difference: 0, .. } => "constant".to_string(),
+            Rule::Arithmetic { .. } => "arithmetic progression".to_string(),
+            Rule::Geometric { first: 1, ratio } => format!("powers of {}", ratio),
+            Rule::Geometric { .. } => "geometric progression".to_string(),
+            Rule::Power { exponent: 2, .. } => "squares".to_string(),
+            Rule::Power { exponent: 3, .. } => "cubes".to_string(),
+            Rule::Power { exponent, .. } => format!("{}th powers", exponent),
+            Rule::Factorial { .. } => "factorials".to_string(),
+            Rule::Fibonacci { first, second } => {
+                // Check whether the start is a pair of consecutive Fibonacci or Lucas numbers.
+                let start = Some((*first, *second));
+                let fibonacci = Rule::Fibonacci { first: 0, second: 1 };
+                let lucas = Rule::Fibonacci { first: 2, second: 1 };
+                for n in 0.. {
+                    let pair = |rule: &Rule| Some((rule.term(n)?, rule.term(n + 1)?));
+                    if pair(&fibonacci) == start {
+                        return "Fibonacci numbers".to_string();
+                    }
+                    if pair(&lucas) == start {
+                        return "Lucas numbers".to_string();
+                    }
+                    if pair(&fibonacci).is_none_or(|(a, _)| a > first.abs()) {
+                        break;
+                    }
+                }
+                "Fibonacci-like recurrence".to_string()
+            },
+            Rule::Polynomial { differences } => {
+                format!("polynomial of degree {}", differences.len() - 1)
+            },
+        }
+    }
+
+
+    /// Returns the rule as a formula, e.g. `a(n) = (n + 1)^2`.
+    pub fn formula(&self) -> String {
+        match self {
+            Rule::Arithmetic { first, difference } => {
+                format!("a(n) = {}", poly_string(&[*first, *difference], 1))
+            },
+            Rule::Geometric { first, ratio } => {
+                let power = match ratio {
+                    ratio if *ratio < 0 => format!("({})^n", ratio),
+                    ratio => format!("{}^n", ratio),
+                };
+                match first {
+                    1 => format!("a(n) = {}", power),
+                    -1 => format!("a(n) = -{}", power),
+                    first => format!("a(n) = {} × {}", first, power),
+                }
+            },
+            Rule::Power { offset, exponent } => match offset {
+                0 => format!("a(n) = n^{}", exponent),
+                offset => format!("a(n) = ({})^{}", poly_string(&[*offset, 1], 1), exponent),
+            },
+            Rule::Factorial { offset } => match offset {
+                0 => "a(n) = n!".to_string(),
+                offset => format!("a(n) = (n + {})!", offset),
+            },
+            Rule::Fibonacci { first, second } => {
+                format!("a(n) = a(n-1) + a(n-2), a(0) = {}, a(1) = {}", first, second)
+            },
+            Rule::Polynomial { differences } => match poly_coefficients(differences) {
+                Some((coefficients, denominator)) => {
+                    format!("a(n) = {}", poly_string(&coefficients, denominator))
+                },
+                None => {
+                    let terms: Vec<String> = differences
+                        .iter()
+                        .enumerate()
+                        .map(|(k, difference)| format!("{} × C(n, {})", difference, k))
+                        .collect();
+                    format!("a(n) = {}", terms.join(" + "))
+                },
+            },
+        }
+    }
+}
+
+
+// Converts a polynomial from the first entries of its difference table to coefficients in
+// powers of n, lowest first, over a common denominator. Returns `None` on overflow.
+fn poly_coefficients(differences: &[i128]) -> Option<(Vec<i128>, i128)> {
+    let degree = differences.len() - 1;
+    let denominator = (1..=degree as i128).try_fold(1i128, |acc, i| acc.checked_mul(i))?;
+    let mut coefficients = vec![0i128; degree + 1];
+    // The falling factorial n(n - 1)...(n - k + 1) in powers of n, built up one factor at a time.
+    let mut falling = vec![1i128];
+    let mut k_factorial: i128 = 1;
+    for (k, difference) in differences.iter().enumerate() {
+        if k > 0 {
+            let mut next = vec![0i128; falling.len() + 1];
+            for (power, coefficient) in falling.iter().enumerate() {
+                next[power + 1] = next[power + 1].checked_add(*coefficient)?;
+                let shifted = coefficient.checked_mul(k as i128 - 1)?;
+                next[power] = next[power].checked_sub(shifted)?;
+            }
+            falling = next;
+            k_factorial = k_factorial.checked_mul(k as i128)?;
+        }
+        let scale = difference.checked_mul(denominator / k_factorial)?;
+        for (power, coefficient) in falling.iter().enumerate() {
+            let term = coefficient.checked_mul(scale)?;
+            coefficients[power] = coefficients[power].checked_add(term)?;
+        }
+    }
+    let divisor = coefficients
+        .iter()
+        .fold(denominator.unsigned_abs(), |acc, c| gcd_u128(acc, c.unsigned_abs()));
+    let divisor = divisor as i128;
+    Some((coefficients.iter().map(|c| c / divisor).collect(), denominator / divisor))
+}
+
+
+// Formats a polynomial in n from its coefficients, lowest power first, e.g. `n^2 + 2n + 1`.
+fn poly_string(coefficients: &[i128], denominator: i128) -> String {
+    let mut output = String::new();
+    for (power, coefficient) in coefficients.iter().enumerate().rev() {
+        if *coefficient == 0 {
+            continue;
+        }
+        let magnitude = coefficient.unsigned_abs();
+        let variable = match power {
+            0 => String::new(),
+            1 => "n".to_string(),
+            _ => format!("n^{}", power),
+        };
+        let term = match (magnitude, power) {
+            (1, 1..) => variable,
+            _ => format!("{}{}", magnitude, variable),
+        };
+        output += &match (output.is_empty(), *coefficient < 0) {
+            (true, false) => term,
+            (true, true) => format!("-{}", term),
+            (false, false) => format!(" + {}", term),
+            (false, true) => format!(" - {}", term),
+        };
+    }
+    if output.is_empty() {
+        output = "0".to_string();
+    }
+    match (denominator, output.contains(' ')) {
+        (1, _) => output,
+        (_, true) => format!("({})/{}", output, denominator),
+        (_, false) => format!("{}/{}", output, denominator),
+    }
+}
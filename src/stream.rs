@@ -0,0 +1,336 @@
+// Streaming input for files and stdin too large to load into memory. Input is read in fixed-size
+// chunks and split into tokens in place, so memory use doesn't grow with the input, and only
+// tokens spanning two chunks are copied. The tokens are borrowed from the chunk buffer.
+//
+// On Unix, regular files are mapped into memory instead, so their chunks are borrowed from the
+// mapping without being copied, and pages are only read as they're used. Pipes, devices, and
+// stdin can't be mapped, so they're read in chunks.
+//
+// While reading, a progress line can be shown on stderr, e.g. when the output is redirected to
+// a file or only printed at the end. Streaming stops early when the `interrupted` flag is set by
+// a Ctrl-C caught by an interrupt guard, after the chunk being read, so the caller can report the
+// results so far.
+
+use std::io::{Read, Write};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+
+const CHUNK_SIZE: usize = 1 << 16;
+
+
+// An error that stops streaming: reading the input failed, or the callback returned an error,
+// e.g. from writing the output.
+pub enum Error<E> {
+    Read(std::io::Error),
+    Stopped(E),
+}
+
+
+impl<E> From<std::io::Error> for Error<E> {
+    fn from(err: std::io::Error) -> Error<E> {
+        Error::Read(err)
+    }
+}
+
+
+// Calls `f` for each whitespace-separated token read from `reader`, stopping at the first error.
+// Tokens that aren't valid UTF-8 are passed with their invalid bytes replaced.
+pub fn for_each_token<E>(
+    mut reader: impl Read,
+    interrupted: &AtomicBool,
+    mut f: impl FnMut(&str) -> Result<(), E>,
+) -> Result<(), Error<E>> {
+    let mut buffer = vec![0; CHUNK_SIZE];
+    // The length of an unfinished token kept at the start of the buffer from the previous chunk.
+    let mut kept = 0;
+    loop {
+        if kept == buffer.len() {
+            buffer.resize(buffer.len() * 2, 0);
+        }
+        let count = match reader.read(&mut buffer[kept..]) {
+            Ok(count) => count,
+            // A read interrupted by a caught Ctrl-C ends the input.
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {
+                match interrupted.load(Ordering::SeqCst) {
+                    true => 0,
+                    false => continue,
+                }
+            },
+            Err(err) => return Err(Error::Read(err)),
+        };
+        let end = kept + count;
+        let chunk = &buffer[..end];
+        // At the end of the input, the last token is finished even without trailing whitespace.
+        let finished = match count {
+            0 => end,
+            _ => chunk.iter().rposition(|byte| byte.is_ascii_whitespace()).map_or(0, |i| i + 1),
+        };
+        for token in chunk[..finished].split(|byte| byte.is_ascii_whitespace()) {
+            if !token.is_empty() {
+                f(&String::from_utf8_lossy(token)).map_err(Error::Stopped)?;
+            }
+        }
+        if count == 0 || interrupted.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        buffer.copy_within(finished..end, 0);
+        kept = end - finished;
+    }
+}
+
+
+// Calls `f` with consecutive chunks of the bytes from `reader` and the offset of each chunk from
+// the start of the input, stopping at the first error. Every chunk but the last is a whole
+// multiple of `multiple` bytes, so rows of that many bytes never span two chunks.
+pub fn for_each_chunk<E>(
+    mut reader: impl Read,
+    multiple: usize,
+    interrupted: &AtomicBool,
+    mut f: impl FnMut(&[u8], u64) -> Result<(), E>,
+) -> Result<(), Error<E>> {
+    let mut buffer = vec![0; CHUNK_SIZE / multiple.max(1) * multiple.max(1)];
+    let mut offset = 0;
+    loop {
+        let mut len = 0;
+        while len < buffer.len() {
+            match reader.read(&mut buffer[len..]) {
+                Ok(0) => break,
+                Ok(count) => len += count,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {
+                    if interrupted.load(Ordering::SeqCst) {
+                        break;
+                    }
+                },
+                Err(err) => return Err(Error::Read(err)),
+            }
+        }
+        if len > 0 {
+            f(&buffer[..len], offset).map_err(Error::Stopped)?;
+            offset += len as u64;
+        }
+        if len < buffer.len() || interrupted.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+    }
+}
+
+
+// Calls `f` with consecutive chunks of `bytes`, e.g. a mapped file, and the offset of each chunk
+// from the start of `bytes`, like `for_each_chunk()` but without copying. Shows progress through
+// the bytes if `progress` is true.
+pub fn for_each_slice<E>(
+    bytes: &[u8],
+    multiple: usize,
+    progress: bool,
+    interrupted: &AtomicBool,
+    mut f: impl FnMut(&[u8], u64) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut progress = Progress::new((), Some(bytes.len() as u64), progress);
+    let mut offset = 0;
+    for chunk in bytes.chunks(CHUNK_SIZE / multiple.max(1) * multiple.max(1)) {
+        f(chunk, offset)?;
+        offset += chunk.len() as u64;
+        progress.advance(chunk.len());
+        if interrupted.load(Ordering::SeqCst) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+
+// The bytes of a whole file, mapped or read into memory.
+pub enum Bytes {
+    Mapped(Mmap),
+    Read(Vec<u8>),
+}
+
+
+impl Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Bytes::Mapped(map) => map,
+            Bytes::Read(bytes) => bytes,
+        }
+    }
+}
+
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Bytes {
+        Bytes::Read(bytes)
+    }
+}
+
+
+// Reads a whole file, or maps it if it's a regular file. A mapped file is only read as its bytes
+// are used, so progress is shown only if `progress` is true and the file has to be read.
+pub fn read_file(path: &str, progress: bool) -> std::io::Result<Bytes> {
+    let file = std::fs::File::open(path)?;
+    if let Some(map) = Mmap::map(&file) {
+        return Ok(Bytes::Mapped(map));
+    }
+    let metadata = file.metadata()?;
+    let size = Some(metadata.len()).filter(|_| metadata.is_file());
+    let mut bytes = Vec::with_capacity(size.unwrap_or(0) as usize);
+    Progress::new(file, size, progress).read_to_end(&mut bytes)?;
+    Ok(Bytes::Read(bytes))
+}
+
+
+// A read-only memory mapping of a whole file. Only available on Unix, elsewhere files are never
+// mapped. The file shouldn't be truncated while it's mapped, as reading past its new end is fatal.
+pub struct Mmap {
+    ptr: *const u8,
+    len: usize,
+}
+
+
+impl Mmap {
+    // Maps the file if it's a non-empty regular file, or returns None so the caller can read it
+    // instead, e.g. for a pipe or a device, or if the mapping fails.
+    #[cfg(unix)]
+    pub fn map(file: &std::fs::File) -> Option<Mmap> {
+        use std::convert::TryFrom;
+        use std::os::unix::io::AsRawFd;
+        let metadata = file.metadata().ok()?;
+        let len = usize::try_from(metadata.len()).ok().filter(|len| *len > 0)?;
+        if !metadata.is_file() {
+            return None;
+        }
+        // Safety: a private read-only mapping of a file open for reading. The mapping is owned by
+        // the returned value and unmapped when it's dropped.
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return None;
+        }
+        Some(Mmap { ptr: ptr as *const u8, len })
+    }
+
+
+    #[cfg(not(unix))]
+    pub fn map(_file: &std::fs::File) -> Option<Mmap> {
+        None
+    }
+}
+
+
+impl Deref for Mmap {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // Safety: the mapping is `len` readable bytes and lives as long as `self`.
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        // Safety: unmaps the mapping made by map(), which nothing borrows once `self` is dropped.
+        unsafe {
+            libc::munmap(self.ptr as *mut libc::c_void, self.len);
+        }
+    }
+}
+
+
+// Wraps a reader, showing the number of bytes read so far on stderr, with a percentage if the
+// total is known. Wrapping `()` shows progress through bytes already in memory instead. The line
+// is redrawn at most four times a second, starting after the first quarter second so quick reads
+// show nothing, and cleared when the reader is dropped.
+pub struct Progress<R> {
+    inner: R,
+    read: u64,
+    total: Option<u64>,
+    enabled: bool,
+    shown: bool,
+    last: Instant,
+}
+
+
+const REDRAW_INTERVAL: Duration = Duration::from_millis(250);
+
+
+impl<R> Progress<R> {
+    // Shows progress only if `enabled` is true, which should be only if stderr is a terminal.
+    pub fn new(inner: R, total: Option<u64>, enabled: bool) -> Progress<R> {
+        Progress {
+            inner,
+            read: 0,
+            total,
+            enabled,
+            shown: false,
+            last: Instant::now(),
+        }
+    }
+
+
+    fn draw(&mut self) {
+        let mut line = format!("reading: {}", byte_size(self.read));
+        if let Some(total) = self.total.filter(|total| *total > 0) {
+            let percent = self.read.min(total) as f64 * 100.0 / total as f64;
+            line += &format!(" of {} ({:.0}%)", byte_size(total), percent);
+        }
+        eprint!("\r{}\x1B[K", line);
+        let _ = std::io::stderr().flush();
+        self.shown = true;
+        self.last = Instant::now();
+    }
+
+
+    fn advance(&mut self, count: usize) {
+        self.read += count as u64;
+        if self.enabled && self.last.elapsed() >= REDRAW_INTERVAL {
+            self.draw();
+        }
+    }
+}
+
+
+impl<R: Read> Read for Progress<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.advance(count);
+        Ok(count)
+    }
+}
+
+
+impl<R> Drop for Progress<R> {
+    fn drop(&mut self) {
+        if self.shown {
+            eprint!("\r\x1B[K");
+        }
+    }
+}
+
+
+// Formats a number of bytes in binary units, e.g. 1.5 GiB.
+fn byte_size(bytes: u64) -> String {
+    let units = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, units[unit])
+}
@@ -0,0 +1,22 @@
+//! Synchsafe integers, which store 28 bits in four bytes with the high bit of each byte clear.
+
+
+/// Encodes an integer as a 32-bit synchsafe integer, as used for tag and frame sizes in ID3v2:
+/// the value is split into 7-bit groups and each group is stored in a byte with the high bit
+/// clear. Returns `None` if the value does not fit in 28 bits.
+pub fn encode(value: u32) -> Option<u32> {
+    if value >= 1 << 28 {
+        return None;
+    }
+    Some((0..4).map(|i| (value >> (i * 7) & 0x7F) << (i * 8)).sum())
+}
+
+
+/// Decodes a 32-bit synchsafe integer. Returns `None` if the high bit of any byte is set, as the
+/// value is then not a valid synchsafe integer.
+pub fn decode(value: u32) -> Option<u32> {
+    if value & 0x8080_8080 != 0 {
+        return None;
+    }
+    Some((0..4).map(|i| (value >> (i * 8) & 0x7F) << (i * 7)).sum())
+}
@@ -0,0 +1,3589 @@
+//! Unicode character names, general categories, and blocks, from Unicode 14.0. Names are
+//! embedded for a selection of common blocks and computed for CJK unified ideographs and Hangul
+//! syllables. Available with the `unicode` feature.
+
+
+/// Blocks as inclusive code point ranges, sorted by code point.
+pub const BLOCKS: &[(u32, u32, &str)] = &[
+    (0x0000, 0x007F, "Basic Latin"),
+    (0x0080, 0x00FF, "Latin-1 Supplement"),
+    (0x0100, 0x017F, "Latin Extended-A"),
+    (0x0180, 0x024F, "Latin Extended-B"),
+    (0x0250, 0x02AF, "IPA Extensions"),
+    (0x02B0, 0x02FF, "Spacing Modifier Letters"),
+    (0x0300, 0x036F, "Combining Diacritical Marks"),
+    (0x0370, 0x03FF, "Greek and Coptic"),
+    (0x0400, 0x04FF, "Cyrillic"),
+    (0x0500, 0x052F, "Cyrillic Supplement"),
+    (0x0530, 0x058F, "Armenian"),
+    (0x0590, 0x05FF, "Hebrew"),
+    (0x0600, 0x06FF, "Arabic"),
+    (0x0700, 0x074F, "Syriac"),
+    (0x0750, 0x077F, "Arabic Supplement"),
+    (0x0780, 0x07BF, "Thaana"),
+    (0x07C0, 0x07FF, "NKo"),
+    (0x0800, 0x083F, "Samaritan"),
+    (0x0840, 0x085F, "Mandaic"),
+    (0x0860, 0x086F, "Syriac Supplement"),
+    (0x0870, 0x089F, "Arabic Extended-B"),
+    (0x08A0, 0x08FF, "Arabic Extended-A"),
+    (0x0900, 0x097F, "Devanagari"),
+    (0x0980, 0x09FF, "Bengali"),
+    (0x0A00, 0x0A7F, "Gurmukhi"),
+    (0x0A80, 0x0AFF, "Gujarati"),
+    (0x0B00, 0x0B7F, "Oriya"),
+    (0x0B80, 0x0BFF, "Tamil"),
+    (0x0C00, 0x0C7F, "Telugu"),
+    (0x0C80, 0x0CFF, "Kannada"),
+    (0x0D00, 0x0D7F, "Malayalam"),
+    (0x0D80, 0x0DFF, "Sinhala"),
+    (0x0E00, 0x0E7F, "Thai"),
+    (0x0E80, 0x0EFF, "Lao"),
+    (0x0F00, 0x0FFF, "Tibetan"),
+    (0x1000, 0x109F, "Myanmar"),
+    (0x10A0, 0x10FF, "Georgian"),
+    (0x1100, 0x11FF, "Hangul Jamo"),
+    (0x1200, 0x137F, "Ethiopic"),
+    (0x1380, 0x139F, "Ethiopic Supplement"),
+    (0x13A0, 0x13FF, "Cherokee"),
+    (0x1400, 0x167F, "Unified Canadian Aboriginal Syllabics"),
+    (0x1680, 0x169F, "Ogham"),
+    (0x16A0, 0x16FF, "Runic"),
+    (0x1700, 0x171F, "Tagalog"),
+    (0x1720, 0x173F, "Hanunoo"),
+    (0x1740, 0x175F, "Buhid"),
+    (0x1760, 0x177F, "Tagbanwa"),
+    (0x1780, 0x17FF, "Khmer"),
+    (0x1800, 0x18AF, "Mongolian"),
+    (0x18B0, 0x18FF, "Unified Canadian Aboriginal Syllabics Extended"),
+    (0x1900, 0x194F, "Limbu"),
+    (0x1950, 0x197F, "Tai Le"),
+    (0x1980, 0x19DF, "New Tai Lue"),
+    (0x19E0, 0x19FF, "Khmer Symbols"),
+    (0x1A00, 0x1A1F, "Buginese"),
+    (0x1A20, 0x1AAF, "Tai Tham"),
+    (0x1AB0, 0x1AFF, "Combining Diacritical Marks Extended"),
+    (0x1B00, 0x1B7F, "Balinese"),
+    (0x1B80, 0x1BBF, "Sundanese"),
+    (0x1BC0, 0x1BFF, "Batak"),
+    (0x1C00, 0x1C4F, "Lepcha"),
+    (0x1C50, 0x1C7F, "Ol Chiki"),
+    (0x1C80, 0x1C8F, "Cyrillic Extended-C"),
+    (0x1C90, 0x1CBF, "Georgian Extended"),
+    (0x1CC0, 0x1CCF, "Sundanese Supplement"),
+    (0x1CD0, 0x1CFF, "Vedic Extensions"),
+    (0x1D00, 0x1D7F, "Phonetic Extensions"),
+    (0x1D80, 0x1DBF, "Phonetic Extensions Supplement"),
+    (0x1DC0, 0x1DFF, "Combining Diacritical Marks Supplement"),
+    (0x1E00, 0x1EFF, "Latin Extended Additional"),
+    (0x1F00, 0x1FFF, "Greek Extended"),
+    (0x2000, 0x206F, "General Punctuation"),
+    (0x2070, 0x209F, "Superscripts and Subscripts"),
+    (0x20A0, 0x20CF, "Currency Symbols"),
+    (0x20D0, 0x20FF, "Combining Diacritical Marks for Symbols"),
+    (0x2100, 0x214F, "Letterlike Symbols"),
+    (0x2150, 0x218F, "Number Forms"),
+    (0x2190, 0x21FF, "Arrows"),
+    (0x2200, 0x22FF, "Mathematical Operators"),
+    (0x2300, 0x23FF, "Miscellaneous Technical"),
+    (0x2400, 0x243F, "Control Pictures"),
+    (0x2440, 0x245F, "Optical Character Recognition"),
+    (0x2460, 0x24FF, "Enclosed Alphanumerics"),
+    (0x2500, 0x257F, "Box Drawing"),
+    (0x2580, 0x259F, "Block Elements"),
+    (0x25A0, 0x25FF, "Geometric Shapes"),
+    (0x2600, 0x26FF, "Miscellaneous Symbols"),
+    (0x2700, 0x27BF, "Dingbats"),
+    (0x27C0, 0x27EF, "Miscellaneous Mathematical Symbols-A"),
+    (0x27F0, 0x27FF, "Supplemental Arrows-A"),
+    (0x2800, 0x28FF, "Braille Patterns"),
+    (0x2900, 0x297F, "Supplemental Arrows-B"),
+    (0x2980, 0x29FF, "Miscellaneous Mathematical Symbols-B"),
+    (0x2A00, 0x2AFF, "Supplemental Mathematical Operators"),
+    (0x2B00, 0x2BFF, "Miscellaneous Symbols and Arrows"),
+    (0x2C00, 0x2C5F, "Glagolitic"),
+    (0x2C60, 0x2C7F, "Latin Extended-C"),
+    (0x2C80, 0x2CFF, "Coptic"),
+    (0x2D00, 0x2D2F, "Georgian Supplement"),
+    (0x2D30, 0x2D7F, "Tifinagh"),
+    (0x2D80, 0x2DDF, "Ethiopic Extended"),
+    (0x2DE0, 0x2DFF, "Cyrillic Extended-A"),
+    (0x2E00, 0x2E7F, "Supplemental Punctuation"),
+    (0x2E80, 0x2EFF, "CJK Radicals Supplement"),
+    (0x2F00, 0x2FDF, "Kangxi Radicals"),
+    (0x2FF0, 0x2FFF, "Ideographic Description Characters"),
+    (0x3000, 0x303F, "CJK Symbols and Punctuation"),
+    (0x3040, 0x309F, "Hiragana"),
+    (0x30A0, 0x30FF, "Katakana"),
+    (0x3100, 0x312F, "Bopomofo"),
+    (0x3130, 0x318F, "Hangul Compatibility Jamo"),
+    (0x3190, 0x319F, "Kanbun"),
+    (0x31A0, 0x31BF, "Bopomofo Extended"),
+    (0x31C0, 0x31EF, "CJK Strokes"),
+    (0x31F0, 0x31FF, "Katakana Phonetic Extensions"),
+    (0x3200, 0x32FF, "Enclosed CJK Letters and Months"),
+    (0x3300, 0x33FF, "CJK Compatibility"),
+    (0x3400, 0x4DBF, "CJK Unified Ideographs Extension A"),
+    (0x4DC0, 0x4DFF, "Yijing Hexagram Symbols"),
+    (0x4E00, 0x9FFF, "CJK Unified Ideographs"),
+    (0xA000, 0xA48F, "Yi Syllables"),
+    (0xA490, 0xA4CF, "Yi Radicals"),
+    (0xA4D0, 0xA4FF, "Lisu"),
+    (0xA500, 0xA63F, "Vai"),
+    (0xA640, 0xA69F, "Cyrillic Extended-B"),
+    (0xA6A0, 0xA6FF, "Bamum"),
+    (0xA700, 0xA71F, "Modifier Tone Letters"),
+    (0xA720, 0xA7FF, "Latin Extended-D"),
+    (0xA800, 0xA82F, "Syloti Nagri"),
+    (0xA830, 0xA83F, "Common Indic Number Forms"),
+    (0xA840, 0xA87F, "Phags-pa"),
+    (0xA880, 0xA8DF, "Saurashtra"),
+    (0xA8E0, 0xA8FF, "Devanagari Extended"),
+    (0xA900, 0xA92F, "Kayah Li"),
+    (0xA930, 0xA95F, "Rejang"),
+    (0xA960, 0xA97F, "Hangul Jamo Extended-A"),
+    (0xA980, 0xA9DF, "Javanese"),
+    (0xA9E0, 0xA9FF, "Myanmar Extended-B"),
+    (0xAA00, 0xAA5F, "Cham"),
+    (0xAA60, 0xAA7F, "Myanmar Extended-A"),
+    (0xAA80, 0xAADF, "Tai Viet"),
+    (0xAAE0, 0xAAFF, "Meetei Mayek Extensions"),
+    (0xAB00, 0xAB2F, "Ethiopic Extended-A"),
+    (0xAB30, 0xAB6F, "Latin Extended-E"),
+    (0xAB70, 0xABBF, "Cherokee Supplement"),
+    (0xABC0, 0xABFF, "Meetei Mayek"),
+    (0xAC00, 0xD7AF, "Hangul Syllables"),
+    (0xD7B0, 0xD7FF, "Hangul Jamo Extended-B"),
+    (0xD800, 0xDB7F, "High Surrogates"),
+    (0xDB80, 0xDBFF, "High Private Use Surrogates"),
+    (0xDC00, 0xDFFF, "Low Surrogates"),
+    (0xE000, 0xF8FF, "Private Use Area"),
+    (0xF900, 0xFAFF, "CJK Compatibility Ideographs"),
+    (0xFB00, 0xFB4F, "Alphabetic Presentation Forms"),
+    (0xFB50, 0xFDFF, "Arabic Presentation Forms-A"),
+    (0xFE00, 0xFE0F, "Variation Selectors"),
+    (0xFE10, 0xFE1F, "Vertical Forms"),
+    (0xFE20, 0xFE2F, "Combining Half Marks"),
+    (0xFE30, 0xFE4F, "CJK Compatibility Forms"),
+    (0xFE50, 0xFE6F, "Small Form Variants"),
+    (0xFE70, 0xFEFF, "Arabic Presentation Forms-B"),
+    (0xFF00, 0xFFEF, "Halfwidth and Fullwidth Forms"),
+    (0xFFF0, 0xFFFF, "Specials"),
+    (0x10000, 0x1007F, "Linear B Syllabary"),
+    (0x10080, 0x100FF, "Linear B Ideograms"),
+    (0x10100, 0x1013F, "Aegean Numbers"),
+    (0x10140, 0x1018F, "Ancient Greek Numbers"),
+    (0x10190, 0x101CF, "Ancient Symbols"),
+    (0x101D0, 0x101FF, "Phaistos Disc"),
+    (0x10280, 0x1029F, "Lycian"),
+    (0x102A0, 0x102DF, "Carian"),
+    (0x102E0, 0x102FF, "Coptic Epact Numbers"),
+    (0x10300, 0x1032F, "Old Italic"),
+    (0x10330, 0x1034F, "Gothic"),
+    (0x10350, 0x1037F, "Old Permic"),
+    (0x10380, 0x1039F, "Ugaritic"),
+    (0x103A0, 0x103DF, "Old Persian"),
+    (0x10400, 0x1044F, "Deseret"),
+    (0x10450, 0x1047F, "Shavian"),
+    (0x10480, 0x104AF, "Osmanya"),
+    (0x104B0, 0x104FF, "Osage"),
+    (0x10500, 0x1052F, "Elbasan"),
+    (0x10530, 0x1056F, "Caucasian Albanian"),
+    (0x10570, 0x105BF, "Vithkuqi"),
+    (0x10600, 0x1077F, "Linear A"),
+    (0x10780, 0x107BF, "Latin Extended-F"),
+    (0x10800, 0x1083F, "Cypriot Syllabary"),
+    (0x10840, 0x1085F, "Imperial Aramaic"),
+    (0x10860, 0x1087F, "Palmyrene"),
+    (0x10880, 0x108AF, "Nabataean"),
+    (0x108E0, 0x108FF, "Hatran"),
+    (0x10900, 0x1091F, "Phoenician"),
+    (0x10920, 0x1093F, "Lydian"),
+    (0x10980, 0x1099F, "Meroitic Hieroglyphs"),
+    (0x109A0, 0x109FF, "Meroitic Cursive"),
+    (0x10A00, 0x10A5F, "Kharoshthi"),
+    (0x10A60, 0x10A7F, "Old South Arabian"),
+    (0x10A80, 0x10A9F, "Old North Arabian"),
+    (0x10AC0, 0x10AFF, "Manichaean"),
+    (0x10B00, 0x10B3F, "Avestan"),
+    (0x10B40, 0x10B5F, "Inscriptional Parthian"),
+    (0x10B60, 0x10B7F, "Inscriptional Pahlavi"),
+    (0x10B80, 0x10BAF, "Psalter Pahlavi"),
+    (0x10C00, 0x10C4F, "Old Turkic"),
+    (0x10C80, 0x10CFF, "Old Hungarian"),
+    (0x10D00, 0x10D3F, "Hanifi Rohingya"),
+    (0x10E60, 0x10E7F, "Rumi Numeral Symbols"),
+    (0x10E80, 0x10EBF, "Yezidi"),
+    (0x10F00, 0x10F2F, "Old Sogdian"),
+    (0x10F30, 0x10F6F, "Sogdian"),
+    (0x10F70, 0x10FAF, "Old Uyghur"),
+    (0x10FB0, 0x10FDF, "Chorasmian"),
+    (0x10FE0, 0x10FFF, "Elymaic"),
+    (0x11000, 0x1107F, "Brahmi"),
+    (0x11080, 0x110CF, "Kaithi"),
+    (0x110D0, 0x110FF, "Sora Sompeng"),
+    (0x11100, 0x1114F, "Chakma"),
+    (0x11150, 0x1117F, "Mahajani"),
+    (0x11180, 0x111DF, "Sharada"),
+    (0x111E0, 0x111FF, "Sinhala Archaic Numbers"),
+    (0x11200, 0x1124F, "Khojki"),
+    (0x11280, 0x112AF, "Multani"),
+    (0x112B0, 0x112FF, "Khudawadi"),
+    (0x11300, 0x1137F, "Grantha"),
+    (0x11400, 0x1147F, "Newa"),
+    (0x11480, 0x114DF, "Tirhuta"),
+    (0x11580, 0x115FF, "Siddham"),
+    (0x11600, 0x1165F, "Modi"),
+    (0x11660, 0x1167F, "Mongolian Supplement"),
+    (0x11680, 0x116CF, "Takri"),
+    (0x11700, 0x1174F, "Ahom"),
+    (0x11800, 0x1184F, "Dogra"),
+    (0x118A0, 0x118FF, "Warang Citi"),
+    (0x11900, 0x1195F, "Dives Akuru"),
+    (0x119A0, 0x119FF, "Nandinagari"),
+    (0x11A00, 0x11A4F, "Zanabazar Square"),
+    (0x11A50, 0x11AAF, "Soyombo"),
+    (0x11AB0, 0x11ABF, "Unified Canadian Aboriginal Syllabics Extended-A"),
+    (0x11AC0, 0x11AFF, "Pau Cin Hau"),
+    (0x11C00, 0x11C6F, "Bhaiksuki"),
+    (0x11C70, 0x11CBF, "Marchen"),
+    (0x11D00, 0x11D5F, "Masaram Gondi"),
+    (0x11D60, 0x11DAF, "Gunjala Gondi"),
+    (0x11EE0, 0x11EFF, "Makasar"),
+    (0x11FB0, 0x11FBF, "Lisu Supplement"),
+    (0x11FC0, 0x11FFF, "Tamil Supplement"),
+    (0x12000, 0x123FF, "Cuneiform"),
+    (0x12400, 0x1247F, "Cuneiform Numbers and Punctuation"),
+    (0x12480, 0x1254F, "Early Dynastic Cuneiform"),
+    (0x12F90, 0x12FFF, "Cypro-Minoan"),
+    (0x13000, 0x1342F, "Egyptian Hieroglyphs"),
+    (0x13430, 0x1343F, "Egyptian Hieroglyph Format Controls"),
+    (0x14400, 0x1467F, "Anatolian Hieroglyphs"),
+    (0x16800, 0x16A3F, "Bamum Supplement"),
+    (0x16A40, 0x16A6F, "Mro"),
+    (0x16A70, 0x16ACF, "Tangsa"),
+    (0x16AD0, 0x16AFF, "Bassa Vah"),
+    (0x16B00, 0x16B8F, "Pahawh Hmong"),
+    (0x16E40, 0x16E9F, "Medefaidrin"),
+    (0x16F00, 0x16F9F, "Miao"),
+    (0x16FE0, 0x16FFF, "Ideographic Symbols and Punctuation"),
+    (0x17000, 0x187FF, "Tangut"),
+    (0x18800, 0x18AFF, "Tangut Components"),
+    (0x18B00, 0x18CFF, "Khitan Small Script"),
+    (0x18D00, 0x18D7F, "Tangut Supplement"),
+    (0x1AFF0, 0x1AFFF, "Kana Extended-B"),
+    (0x1B000, 0x1B0FF, "Kana Supplement"),
+    (0x1B100, 0x1B12F, "Kana Extended-A"),
+    (0x1B130, 0x1B16F, "Small Kana Extension"),
+    (0x1B170, 0x1B2FF, "Nushu"),
+    (0x1BC00, 0x1BC9F, "Duployan"),
+    (0x1BCA0, 0x1BCAF, "Shorthand Format Controls"),
+    (0x1CF00, 0x1CFCF, "Znamenny Musical Notation"),
+    (0x1D000, 0x1D0FF, "Byzantine Musical Symbols"),
+    (0x1D100, 0x1D1FF, "Musical Symbols"),
+    (0x1D200, 0x1D24F, "Ancient Greek Musical Notation"),
+    (0x1D2E0, 0x1D2FF, "Mayan Numerals"),
+    (0x1D300, 0x1D35F, "Tai Xuan Jing Symbols"),
+    (0x1D360, 0x1D37F, "Counting Rod Numerals"),
+    (0x1D400, 0x1D7FF, "Mathematical Alphanumeric Symbols"),
+    (0x1D800, 0x1DAAF, "Sutton SignWriting"),
+    (0x1DF00, 0x1DFFF, "Latin Extended-G"),
+    (0x1E000, 0x1E02F, "Glagolitic Supplement"),
+    (0x1E100, 0x1E14F, "Nyiakeng Puachue Hmong"),
+    (0x1E290, 0x1E2BF, "Toto"),
+    (0x1E2C0, 0x1E2FF, "Wancho"),
+    (0x1E7E0, 0x1E7FF, "Ethiopic Extended-B"),
+    (0x1E800, 0x1E8DF, "Mende Kikakui"),
+    (0x1E900, 0x1E95F, "Adlam"),
+    (0x1EC70, 0x1ECBF, "Indic Siyaq Numbers"),
+    (0x1ED00, 0x1ED4F, "Ottoman Siyaq Numbers"),
+    (0x1EE00, 0x1EEFF, "Arabic Mathematical Alphabetic Symbols"),
+    (0x1F000, 0x1F02F, "Mahjong Tiles"),
+    (0x1F030, 0x1F09F, "Domino Tiles"),
+    (0x1F0A0, 0x1F0FF, "Playing Cards"),
+    (0x1F100, 0x1F1FF, "Enclosed Alphanumeric Supplement"),
+    (0x1F200, 0x1F2FF, "Enclosed Ideographic Supplement"),
+    (0x1F300, 0x1F5FF, "Miscellaneous Symbols and Pictographs"),
+    (0x1F600, 0x1F64F, "Emoticons"),
+    (0x1F650, 0x1F67F, "Ornamental Dingbats"),
+    (0x1F680, 0x1F6FF, "Transport and Map Symbols"),
+    (0x1F700, 0x1F77F, "Alchemical Symbols"),
+    (0x1F780, 0x1F7FF, "Geometric Shapes Extended"),
+    (0x1F800, 0x1F8FF, "Supplemental Arrows-C"),
+    (0x1F900, 0x1F9FF, "Supplemental Symbols and Pictographs"),
+    (0x1FA00, 0x1FA6F, "Chess Symbols"),
+    (0x1FA70, 0x1FAFF, "Symbols and Pictographs Extended-A"),
+    (0x1FB00, 0x1FBFF, "Symbols for Legacy Computing"),
+    (0x20000, 0x2A6DF, "CJK Unified Ideographs Extension B"),
+    (0x2A700, 0x2B73F, "CJK Unified Ideographs Extension C"),
+    (0x2B740, 0x2B81F, "CJK Unified Ideographs Extension D"),
+    (0x2B820, 0x2CEAF, "CJK Unified Ideographs Extension E"),
+    (0x2CEB0, 0x2EBEF, "CJK Unified Ideographs Extension F"),
+    (0x2F800, 0x2FA1F, "CJK Compatibility Ideographs Supplement"),
+    (0x30000, 0x3134F, "CJK Unified Ideographs Extension G"),
+    (0xE0000, 0xE007F, "Tags"),
+    (0xE0100, 0xE01EF, "Variation Selectors Supplement"),
+    (0xF0000, 0xFFFFF, "Supplementary Private Use Area-A"),
+    (0x100000, 0x10FFFF, "Supplementary Private Use Area-B"),
+];
+
+
+/// The general category of every code point, as the first code point of each run of code
+/// points with the same category, sorted by code point.
+pub const CATEGORIES: &[(u32, &str)] = &[
+    (0x0000, "Cc"), (0x0020, "Zs"), (0x0021, "Po"), (0x0024, "Sc"), (0x0025, "Po"), (0x0028, "Ps"),
+    (0x0029, "Pe"), (0x002A, "Po"), (0x002B, "Sm"), (0x002C, "Po"), (0x002D, "Pd"), (0x002E, "Po"),
+    (0x0030, "Nd"), (0x003A, "Po"), (0x003C, "Sm"), (0x003F, "Po"), (0x0041, "Lu"), (0x005B, "Ps"),
+    (0x005C, "Po"), (0x005D, "Pe"), (0x005E, "Sk"), (0x005F, "Pc"), (0x0060, "Sk"), (0x0061, "Ll"),
+    (0x007B, "Ps"), (0x007C, "Sm"), (0x007D, "Pe"), (0x007E, "Sm"), (0x007F, "Cc"), (0x00A0, "Zs"),
+    (0x00A1, "Po"), (0x00A2, "Sc"), (0x00A6, "So"), (0x00A7, "Po"), (0x00A8, "Sk"), (0x00A9, "So"),
+    (0x00AA, "Lo"), (0x00AB, "Pi"), (0x00AC, "Sm"), (0x00AD, "Cf"), (0x00AE, "So"), (0x00AF, "Sk"),
+    (0x00B0, "So"), (0x00B1, "Sm"), (0x00B2, "No"), (0x00B4, "Sk"), (0x00B5, "Ll"), (0x00B6, "Po"),
+    (0x00B8, "Sk"), (0x00B9, "No"), (0x00BA, "Lo"), (0x00BB, "Pf"), (0x00BC, "No"), (0x00BF, "Po"),
+    (0x00C0, "Lu"), (0x00D7, "Sm"), (0x00D8, "Lu"), (0x00DF, "Ll"), (0x00F7, "Sm"), (0x00F8, "Ll"),
+    (0x0100, "Lu"), (0x0101, "Ll"), (0x0102, "Lu"), (0x0103, "Ll"), (0x0104, "Lu"), (0x0105, "Ll"),
+    (0x0106, "Lu"), (0x0107, "Ll"), (0x0108, "Lu"), (0x0109, "Ll"), (0x010A, "Lu"), (0x010B, "Ll"),
+    (0x010C, "Lu"), (0x010D, "Ll"), (0x010E, "Lu"), (0x010F, "Ll"), (0x0110, "Lu"), (0x0111, "Ll"),
+    (0x0112, "Lu"), (0x0113, "Ll"), (0x0114, "Lu"), (0x0115, "Ll"), (0x0116, "Lu"), (0x0117, "Ll"),
+    (0x0118, "Lu"), (0x0119, "Ll"), (0x011A, "Lu"), (0x011B, "Ll"), (0x011C, "Lu"), (0x011D, "Ll"),
+    (0x011E, "Lu"), (0x011F, "Ll"), (0x0120, "Lu"), (0x0121, "Ll"), (0x0122, "Lu"), (0x0123, "Ll"),
+    (0x0124, "Lu"), (0x0125, "Ll"), (0x0126, "Lu"), (0x0127, "Ll"), (0x0128, "Lu"), (0x0129, "Ll"),
+    (0x012A, "Lu"), (0x012B, "Ll"), (0x012C, "Lu"), (0x012D, "Ll"), (0x012E, "Lu"), (0x012F, "Ll"),
+    (0x0130, "Lu"), (0x0131, "Ll"), (0x0132, "Lu"), (0x0133, "Ll"), (0x0134, "Lu"), (0x0135, "Ll"),
+    (0x0136, "Lu"), (0x0137, "Ll"), (0x0139, "Lu"), (0x013A, "Ll"), (0x013B, "Lu"), (0x013C, "Ll"),
+    (0x013D, "Lu"), (0x013E, "Ll"), (0x013F, "Lu"), (0x0140, "Ll"), (0x0141, "Lu"), (0x0142, "Ll"),
+    (0x0143, "Lu"), (0x0144, "Ll"), (0x0145, "Lu"), (0x0146, "Ll"), (0x0147, "Lu"), (0x0148, "Ll"),
+    (0x014A, "Lu"), (0x014B, "Ll"), (0x014C, "Lu"), (0x014D, "Ll"), (0x014E, "Lu"), (0x014F, "Ll"),
+    (0x0150, "Lu"), (0x0151, "Ll"), (0x0152, "Lu"), (0x0153, "Ll"), (0x0154, "Lu"), (0x0155, "Ll"),
+    (0x0156, "Lu"), (0x0157, "Ll"), (0x0158, "Lu"), (0x0159, "Ll"), (0x015A, "Lu"), (0x015B, "Ll"),
+    (0x015C, "Lu"), (0x015D, "Ll"), (0x015E, "Lu"), (0x015F, "Ll"), (0x0160, "Lu"), (0x0161, "Ll"),
+    (0x0162, "Lu"), (0x0163, "Ll"), (0x0164, "Lu"), (0x0165, "Ll"), (0x0166, "Lu"), (0x0167, "Ll"),
+    (0x0168, "Lu"), (0x0169, "Ll"), (0x016A, "Lu"), (0x016B, "Ll"), (0x016C, "Lu"), (0x016D, "Ll"),
+    (0x016E, "Lu"), (0x016F, "Ll"), (0x0170, "Lu"), (0x0171, "Ll"), (0x0172, "Lu"), (0x0173, "Ll"),
+    (0x0174, "Lu"), (0x0175, "Ll"), (0x0176, "Lu"), (0x0177, "Ll"), (0x0178, "Lu"), (0x017A, "Ll"),
+    (0x017B, "Lu"), (0x017C, "Ll"), (0x017D, "Lu"), (0x017E, "Ll"), (0x0181, "Lu"), (0x0183, "Ll"),
+    (0x0184, "Lu"), (0x0185, "Ll"), (0x0186, "Lu"), (0x0188, "Ll"), (0x0189, "Lu"), (0x018C, "Ll"),
+    (0x018E, "Lu"), (0x0192, "Ll"), (0x0193, "Lu"), (0x0195, "Ll"), (0x0196, "Lu"), (0x0199, "Ll"),
+    (0x019C, "Lu"), (0x019E, "Ll"), (0x019F, "Lu"), (0x01A1, "Ll"), (0x01A2, "Lu"), (0x01A3, "Ll"),
+    (0x01A4, "Lu"), (0x01A5, "Ll"), (0x01A6, "Lu"), (0x01A8, "Ll"), (0x01A9, "Lu"), (0x01AA, "Ll"),
+    (0x01AC, "Lu"), (0x01AD, "Ll"), (0x01AE, "Lu"), (0x01B0, "Ll"), (0x01B1, "Lu"), (0x01B4, "Ll"),
+    (0x01B5, "Lu"), (0x01B6, "Ll"), (0x01B7, "Lu"), (0x01B9, "Ll"), (0x01BB, "Lo"), (0x01BC, "Lu"),
+    (0x01BD, "Ll"), (0x01C0, "Lo"), (0x01C4, "Lu"), (0x01C5, "Lt"), (0x01C6, "Ll"), (0x01C7, "Lu"),
+    (0x01C8, "Lt"), (0x01C9, "Ll"), (0x01CA, "Lu"), (0x01CB, "Lt"), (0x01CC, "Ll"), (0x01CD, "Lu"),
+    (0x01CE, "Ll"), (0x01CF, "Lu"), (0x01D0, "Ll"), (0x01D1, "Lu"), (0x01D2, "Ll"), (0x01D3, "Lu"),
+    (0x01D4, "Ll"), (0x01D5, "Lu"), (0x01D6, "Ll"), (0x01D7, "Lu"), (0x01D8, "Ll"), (0x01D9, "Lu"),
+    (0x01DA, "Ll"), (0x01DB, "Lu"), (0x01DC, "Ll"), (0x01DE, "Lu"), (0x01DF, "Ll"), (0x01E0, "Lu"),
+    (0x01E1, "Ll"), (0x01E2, "Lu"), (0x01E3, "Ll"), (0x01E4, "Lu"), (0x01E5, "Ll"), (0x01E6, "Lu"),
+    (0x01E7, "Ll"), (0x01E8, "Lu"), (0x01E9, "Ll"), (0x01EA, "Lu"), (0x01EB, "Ll"), (0x01EC, "Lu"),
+    (0x01ED, "Ll"), (0x01EE, "Lu"), (0x01EF, "Ll"), (0x01F1, "Lu"), (0x01F2, "Lt"), (0x01F3, "Ll"),
+    (0x01F4, "Lu"), (0x01F5, "Ll"), (0x01F6, "Lu"), (0x01F9, "Ll"), (0x01FA, "Lu"), (0x01FB, "Ll"),
+    (0x01FC, "Lu"), (0x01FD, "Ll"), (0x01FE, "Lu"), (0x01FF, "Ll"), (0x0200, "Lu"), (0x0201, "Ll"),
+    (0x0202, "Lu"), (0x0203, "Ll"), (0x0204, "Lu"), (0x0205, "Ll"), (0x0206, "Lu"), (0x0207, "Ll"),
+    (0x0208, "Lu"), (0x0209, "Ll"), (0x020A, "Lu"), (0x020B, "Ll"), (0x020C, "Lu"), (0x020D, "Ll"),
+    (0x020E, "Lu"), (0x020F, "Ll"), (0x0210, "Lu"), (0x0211, "Ll"), (0x0212, "Lu"), (0x0213, "Ll"),
+    (0x0214, "Lu"), (0x0215, "Ll"), (0x0216, "Lu"), (0x0217, "Ll"), (0x0218, "Lu"), (0x0219, "Ll"),
+    (0x021A, "Lu"), (0x021B, "Ll"), (0x021C, "Lu"), (0x021D, "Ll"), (0x021E, "Lu"), (0x021F, "Ll"),
+    (0x0220, "Lu"), (0x0221, "Ll"), (0x0222, "Lu"), (0x0223, "Ll"), (0x0224, "Lu"), (0x0225, "Ll"),
+    (0x0226, "Lu"), (0x0227, "Ll"), (0x0228, "Lu"), (0x0229, "Ll"), (0x022A, "Lu"), (0x022B, "Ll"),
+    (0x022C, "Lu"), (0x022D, "Ll"), (0x022E, "Lu"), (0x022F, "Ll"), (0x0230, "Lu"), (0x0231, "Ll"),
+    (0x0232, "Lu"), (0x0233, "Ll"), (0x023A, "Lu"), (0x023C, "Ll"), (0x023D, "Lu"), (0x023F, "Ll"),
+    (0x0241, "Lu"), (0x0242, "Ll"), (0x0243, "Lu"), (0x0247, "Ll"), (0x0248, "Lu"), (0x0249, "Ll"),
+    (0x024A, "Lu"), (0x024B, "Ll"), (0x024C, "Lu"), (0x024D, "Ll"), (0x024E, "Lu"), (0x024F, "Ll"),
+    (0x0294, "Lo"), (0x0295, "Ll"), (0x02B0, "Lm"), (0x02C2, "Sk"), (0x02C6, "Lm"), (0x02D2, "Sk"),
+    (0x02E0, "Lm"), (0x02E5, "Sk"), (0x02EC, "Lm"), (0x02ED, "Sk"), (0x02EE, "Lm"), (0x02EF, "Sk"),
+    (0x0300, "Mn"), (0x0370, "Lu"), (0x0371, "Ll"), (0x0372, "Lu"), (0x0373, "Ll"), (0x0374, "Lm"),
+    (0x0375, "Sk"), (0x0376, "Lu"), (0x0377, "Ll"), (0x0378, "Cn"), (0x037A, "Lm"), (0x037B, "Ll"),
+    (0x037E, "Po"), (0x037F, "Lu"), (0x0380, "Cn"), (0x0384, "Sk"), (0x0386, "Lu"), (0x0387, "Po"),
+    (0x0388, "Lu"), (0x038B, "Cn"), (0x038C, "Lu"), (0x038D, "Cn"), (0x038E, "Lu"), (0x0390, "Ll"),
+    (0x0391, "Lu"), (0x03A2, "Cn"), (0x03A3, "Lu"), (0x03AC, "Ll"), (0x03CF, "Lu"), (0x03D0, "Ll"),
+    (0x03D2, "Lu"), (0x03D5, "Ll"), (0x03D8, "Lu"), (0x03D9, "Ll"), (0x03DA, "Lu"), (0x03DB, "Ll"),
+    (0x03DC, "Lu"), (0x03DD, "Ll"), (0x03DE, "Lu"), (0x03DF, "Ll"), (0x03E0, "Lu"), (0x03E1, "Ll"),
+    (0x03E2, "Lu"), (0x03E3, "Ll"), (0x03E4, "Lu"), (0x03E5, "Ll"), (0x03E6, "Lu"), (0x03E7, "Ll"),
+    (0x03E8, "Lu"), (0x03E9, "Ll"), (0x03EA, "Lu"), (0x03EB, "Ll"), (0x03EC, "Lu"), (0x03ED, "Ll"),
+    (0x03EE, "Lu"), (0x03EF, "Ll"), (0x03F4, "Lu"), (0x03F5, "Ll"), (0x03F6, "Sm"), (0x03F7, "Lu"),
+    (0x03F8, "Ll"), (0x03F9, "Lu"), (0x03FB, "Ll"), (0x03FD, "Lu"), (0x0430, "Ll"), (0x0460, "Lu"),
+    (0x0461, "Ll"), (0x0462, "Lu"), (0x0463, "Ll"), (0x0464, "Lu"), (0x0465, "Ll"), (0x0466, "Lu"),
+    (0x0467, "Ll"), (0x0468, "Lu"), (0x0469, "Ll"), (0x046A, "Lu"), (0x046B, "Ll"), (0x046C, "Lu"),
+    (0x046D, "Ll"), (0x046E, "Lu"), (0x046F, "Ll"), (0x0470, "Lu"), (0x0471, "Ll"), (0x0472, "Lu"),
+    (0x0473, "Ll"), (0x0474, "Lu"), (0x0475, "Ll"), (0x0476, "Lu"), (0x0477, "Ll"), (0x0478, "Lu"),
+    (0x0479, "Ll"), (0x047A, "Lu"), (0x047B, "Ll"), (0x047C, "Lu"), (0x047D, "Ll"), (0x047E, "Lu"),
+    (0x047F, "Ll"), (0x0480, "Lu"), (0x0481, "Ll"), (0x0482, "So"), (0x0483, "Mn"), (0x0488, "Me"),
+    (0x048A, "Lu"), (0x048B, "Ll"), (0x048C, "Lu"), (0x048D, "Ll"), (0x048E, "Lu"), (0x048F, "Ll"),
+    (0x0490, "Lu"), (0x0491, "Ll"), (0x0492, "Lu"), (0x0493, "Ll"), (0x0494, "Lu"), (0x0495, "Ll"),
+    (0x0496, "Lu"), (0x0497, "Ll"), (0x0498, "Lu"), (0x0499, "Ll"), (0x049A, "Lu"), (0x049B, "Ll"),
+    (0x049C, "Lu"), (0x049D, "Ll"), (0x049E, "Lu"), (0x049F, "Ll"), (0x04A0, "Lu"), (0x04A1, "Ll"),
+    (0x04A2, "Lu"), (0x04A3, "Ll"), (0x04A4, "Lu"), (0x04A5, "Ll"), (0x04A6, "Lu"), (0x04A7, "Ll"),
+    (0x04A8, "Lu"), (0x04A9, "Ll"), (0x04AA, "Lu"), (0x04AB, "Ll"), (0x04AC, "Lu"), (0x04AD, "Ll"),
+    (0x04AE, "Lu"), (0x04AF, "Ll"), (0x04B0, "Lu"), (0x04B1, "Ll"), (0x04B2, "Lu"), (0x04B3, "Ll"),
+    (0x04B4, "Lu"), (0x04B5, "Ll"), (0x04B6, "Lu"), (0x04B7, "Ll"), (0x04B8, "Lu"), (0x04B9, "Ll"),
+    (0x04BA, "Lu"), (0x04BB, "Ll"), (0x04BC, "Lu"), (0x04BD, "Ll"), (0x04BE, "Lu"), (0x04BF, "Ll"),
+    (0x04C0, "Lu"), (0x04C2, "Ll"), (0x04C3, "Lu"), (0x04C4, "Ll"), (0x04C5, "Lu"), (0x04C6, "Ll"),
+    (0x04C7, "Lu"), (0x04C8, "Ll"), (0x04C9, "Lu"), (0x04CA, "Ll"), (0x04CB, "Lu"), (0x04CC, "Ll"),
+    (0x04CD, "Lu"), (0x04CE, "Ll"), (0x04D0, "Lu"), (0x04D1, "Ll"), (0x04D2, "Lu"), (0x04D3, "Ll"),
+    (0x04D4, "Lu"), (0x04D5, "Ll"), (0x04D6, "Lu"), (0x04D7, "Ll"), (0x04D8, "Lu"), (0x04D9, "Ll"),
+    (0x04DA, "Lu"), (0x04DB, "Ll"), (0x04DC, "Lu"), (0x04DD, "Ll"), (0x04DE, "Lu"), (0x04DF, "Ll"),
+    (0x04E0, "Lu"), (0x04E1, "Ll"), (0x04E2, "Lu"), (0x04E3, "Ll"), (0x04E4, "Lu"), (0x04E5, "Ll"),
+    (0x04E6, "Lu"), (0x04E7, "Ll"), (0x04E8, "Lu"), (0x04E9, "Ll"), (0x04EA, "Lu"), (0x04EB, "Ll"),
+    (0x04EC, "Lu"), (0x04ED, "Ll"), (0x04EE, "Lu"), (0x04EF, "Ll"), (0x04F0, "Lu"), (0x04F1, "Ll"),
+    (0x04F2, "Lu"), (0x04F3, "Ll"), (0x04F4, "Lu"), (0x04F5, "Ll"), (0x04F6, "Lu"), (0x04F7, "Ll"),
+    (0x04F8, "Lu"), (0x04F9, "Ll"), (0x04FA, "Lu"), (0x04FB, "Ll"), (0x04FC, "Lu"), (0x04FD, "Ll"),
+    (0x04FE, "Lu"), (0x04FF, "Ll"), (0x0500, "Lu"), (0x0501, "Ll"), (0x0502, "Lu"), (0x0503, "Ll"),
+    (0x0504, "Lu"), (0x0505, "Ll"), (0x0506, "Lu"), (0x0507, "Ll"), (0x0508, "Lu"), (0x0509, "Ll"),
+    (0x050A, "Lu"), (0x050B, "Ll"), (0x050C, "Lu"), (0x050D, "Ll"), (0x050E, "Lu"), (0x050F, "Ll"),
+    (0x0510, "Lu"), (0x0511, "Ll"), (0x0512, "Lu"), (0x0513, "Ll"), (0x0514, "Lu"), (0x0515, "Ll"),
+    (0x0516, "Lu"), (0x0517, "Ll"), (0x0518, "Lu"), (0x0519, "Ll"), (0x051A, "Lu"), (0x051B, "Ll"),
+    (0x051C, "Lu"), (0x051D, "Ll"), (0x051E, "Lu"), (0x051F, "Ll"), (0x0520, "Lu"), (0x0521, "Ll"),
+    (0x0522, "Lu"), (0x0523, "Ll"), (0x0524, "Lu"), (0x0525, "Ll"), (0x0526, "Lu"), (0x0527, "Ll"),
+    (0x0528, "Lu"), (0x0529, "Ll"), (0x052A, "Lu"), (0x052B, "Ll"), (0x052C, "Lu"), (0x052D, "Ll"),
+    (0x052E, "Lu"), (0x052F, "Ll"), (0x0530, "Cn"), (0x0531, "Lu"), (0x0557, "Cn"), (0x0559, "Lm"),
+    (0x055A, "Po"), (0x0560, "Ll"), (0x0589, "Po"), (0x058A, "Pd"), (0x058B, "Cn"), (0x058D, "So"),
+    (0x058F, "Sc"), (0x0590, "Cn"), (0x0591, "Mn"), (0x05BE, "Pd"), (0x05BF, "Mn"), (0x05C0, "Po"),
+    (0x05C1, "Mn"), (0x05C3, "Po"), (0x05C4, "Mn"), (0x05C6, "Po"), (0x05C7, "Mn"), (0x05C8, "Cn"),
+    (0x05D0, "Lo"), (0x05EB, "Cn"), (0x05EF, "Lo"), (0x05F3, "Po"), (0x05F5, "Cn"), (0x0600, "Cf"),
+    (0x0606, "Sm"), (0x0609, "Po"), (0x060B, "Sc"), (0x060C, "Po"), (0x060E, "So"), (0x0610, "Mn"),
+    (0x061B, "Po"), (0x061C, "Cf"), (0x061D, "Po"), (0x0620, "Lo"), (0x0640, "Lm"), (0x0641, "Lo"),
+    (0x064B, "Mn"), (0x0660, "Nd"), (0x066A, "Po"), (0x066E, "Lo"), (0x0670, "Mn"), (0x0671, "Lo"),
+    (0x06D4, "Po"), (0x06D5, "Lo"), (0x06D6, "Mn"), (0x06DD, "Cf"), (0x06DE, "So"), (0x06DF, "Mn"),
+    (0x06E5, "Lm"), (0x06E7, "Mn"), (0x06E9, "So"), (0x06EA, "Mn"), (0x06EE, "Lo"), (0x06F0, "Nd"),
+    (0x06FA, "Lo"), (0x06FD, "So"), (0x06FF, "Lo"), (0x0700, "Po"), (0x070E, "Cn"), (0x070F, "Cf"),
+    (0x0710, "Lo"), (0x0711, "Mn"), (0x0712, "Lo"), (0x0730, "Mn"), (0x074B, "Cn"), (0x074D, "Lo"),
+    (0x07A6, "Mn"), (0x07B1, "Lo"), (0x07B2, "Cn"), (0x07C0, "Nd"), (0x07CA, "Lo"), (0x07EB, "Mn"),
+    (0x07F4, "Lm"), (0x07F6, "So"), (0x07F7, "Po"), (0x07FA, "Lm"), (0x07FB, "Cn"), (0x07FD, "Mn"),
+    (0x07FE, "Sc"), (0x0800, "Lo"), (0x0816, "Mn"), (0x081A, "Lm"), (0x081B, "Mn"), (0x0824, "Lm"),
+    (0x0825, "Mn"), (0x0828, "Lm"), (0x0829, "Mn"), (0x082E, "Cn"), (0x0830, "Po"), (0x083F, "Cn"),
+    (0x0840, "Lo"), (0x0859, "Mn"), (0x085C, "Cn"), (0x085E, "Po"), (0x085F, "Cn"), (0x0860, "Lo"),
+    (0x086B, "Cn"), (0x0870, "Lo"), (0x0888, "Sk"), (0x0889, "Lo"), (0x088F, "Cn"), (0x0890, "Cf"),
+    (0x0892, "Cn"), (0x0898, "Mn"), (0x08A0, "Lo"), (0x08C9, "Lm"), (0x08CA, "Mn"), (0x08E2, "Cf"),
+    (0x08E3, "Mn"), (0x0903, "Mc"), (0x0904, "Lo"), (0x093A, "Mn"), (0x093B, "Mc"), (0x093C, "Mn"),
+    (0x093D, "Lo"), (0x093E, "Mc"), (0x0941, "Mn"), (0x0949, "Mc"), (0x094D, "Mn"), (0x094E, "Mc"),
+    (0x0950, "Lo"), (0x0951, "Mn"), (0x0958, "Lo"), (0x0962, "Mn"), (0x0964, "Po"), (0x0966, "Nd"),
+    (0x0970, "Po"), (0x0971, "Lm"), (0x0972, "Lo"), (0x0981, "Mn"), (0x0982, "Mc"), (0x0984, "Cn"),
+    (0x0985, "Lo"), (0x098D, "Cn"), (0x098F, "Lo"), (0x0991, "Cn"), (0x0993, "Lo"), (0x09A9, "Cn"),
+    (0x09AA, "Lo"), (0x09B1, "Cn"), (0x09B2, "Lo"), (0x09B3, "Cn"), (0x09B6, "Lo"), (0x09BA, "Cn"),
+    (0x09BC, "Mn"), (0x09BD, "Lo"), (0x09BE, "Mc"), (0x09C1, "Mn"), (0x09C5, "Cn"), (0x09C7, "Mc"),
+    (0x09C9, "Cn"), (0x09CB, "Mc"), (0x09CD, "Mn"), (0x09CE, "Lo"), (0x09CF, "Cn"), (0x09D7, "Mc"),
+    (0x09D8, "Cn"), (0x09DC, "Lo"), (0x09DE, "Cn"), (0x09DF, "Lo"), (0x09E2, "Mn"), (0x09E4, "Cn"),
+    (0x09E6, "Nd"), (0x09F0, "Lo"), (0x09F2, "Sc"), (0x09F4, "No"), (0x09FA, "So"), (0x09FB, "Sc"),
+    (0x09FC, "Lo"), (0x09FD, "Po"), (0x09FE, "Mn"), (0x09FF, "Cn"), (0x0A01, "Mn"), (0x0A03, "Mc"),
+    (0x0A04, "Cn"), (0x0A05, "Lo"), (0x0A0B, "Cn"), (0x0A0F, "Lo"), (0x0A11, "Cn"), (0x0A13, "Lo"),
+    (0x0A29, "Cn"), (0x0A2A, "Lo"), (0x0A31, "Cn"), (0x0A32, "Lo"), (0x0A34, "Cn"), (0x0A35, "Lo"),
+    (0x0A37, "Cn"), (0x0A38, "Lo"), (0x0A3A, "Cn"), (0x0A3C, "Mn"), (0x0A3D, "Cn"), (0x0A3E, "Mc"),
+    (0x0A41, "Mn"), (0x0A43, "Cn"), (0x0A47, "Mn"), (0x0A49, "Cn"), (0x0A4B, "Mn"), (0x0A4E, "Cn"),
+    (0x0A51, "Mn"), (0x0A52, "Cn"), (0x0A59, "Lo"), (0x0A5D, "Cn"), (0x0A5E, "Lo"), (0x0A5F, "Cn"),
+    (0x0A66, "Nd"), (0x0A70, "Mn"), (0x0A72, "Lo"), (0x0A75, "Mn"), (0x0A76, "Po"), (0x0A77, "Cn"),
+    (0x0A81, "Mn"), (0x0A83, "Mc"), (0x0A84, "Cn"), (0x0A85, "Lo"), (0x0A8E, "Cn"), (0x0A8F, "Lo"),
+    (0x0A92, "Cn"), (0x0A93, "Lo"), (0x0AA9, "Cn"), (0x0AAA, "Lo"), (0x0AB1, "Cn"), (0x0AB2, "Lo"),
+    (0x0AB4, "Cn"), (0x0AB5, "Lo"), (0x0ABA, "Cn"), (0x0ABC, "Mn"), (0x0ABD, "Lo"), (0x0ABE, "Mc"),
+    (0x0AC1, "Mn"), (0x0AC6, "Cn"), (0x0AC7, "Mn"), (0x0AC9, "Mc"), (0x0ACA, "Cn"), (0x0ACB, "Mc"),
+    (0x0ACD, "Mn"), (0x0ACE, "Cn"), (0x0AD0, "Lo"), (0x0AD1, "Cn"), (0x0AE0, "Lo"), (0x0AE2, "Mn"),
+    (0x0AE4, "Cn"), (0x0AE6, "Nd"), (0x0AF0, "Po"), (0x0AF1, "Sc"), (0x0AF2, "Cn"), (0x0AF9, "Lo"),
+    (0x0AFA, "Mn"), (0x0B00, "Cn"), (0x0B01, "Mn"), (0x0B02, "Mc"), (0x0B04, "Cn"), (0x0B05, "Lo"),
+    (0x0B0D, "Cn"), (0x0B0F, "Lo"), (0x0B11, "Cn"), (0x0B13, "Lo"), (0x0B29, "Cn"), (0x0B2A, "Lo"),
+    (0x0B31, "Cn"), (0x0B32, "Lo"), (0x0B34, "Cn"), (0x0B35, "Lo"), (0x0B3A, "Cn"), (0x0B3C, "Mn"),
+    (0x0B3D, "Lo"), (0x0B3E, "Mc"), (0x0B3F, "Mn"), (0x0B40, "Mc"), (0x0B41, "Mn"), (0x0B45, "Cn"),
+    (0x0B47, "Mc"), (0x0B49, "Cn"), (0x0B4B, "Mc"), (0x0B4D, "Mn"), (0x0B4E, "Cn"), (0x0B55, "Mn"),
+    (0x0B57, "Mc"), (0x0B58, "Cn"), (0x0B5C, "Lo"), (0x0B5E, "Cn"), (0x0B5F, "Lo"), (0x0B62, "Mn"),
+    (0x0B64, "Cn"), (0x0B66, "Nd"), (0x0B70, "So"), (0x0B71, "Lo"), (0x0B72, "No"), (0x0B78, "Cn"),
+    (0x0B82, "Mn"), (0x0B83, "Lo"), (0x0B84, "Cn"), (0x0B85, "Lo"), (0x0B8B, "Cn"), (0x0B8E, "Lo"),
+    (0x0B91, "Cn"), (0x0B92, "Lo"), (0x0B96, "Cn"), (0x0B99, "Lo"), (0x0B9B, "Cn"), (0x0B9C, "Lo"),
+    (0x0B9D, "Cn"), (0x0B9E, "Lo"), (0x0BA0, "Cn"), (0x0BA3, "Lo"), (0x0BA5, "Cn"), (0x0BA8, "Lo"),
+    (0x0BAB, "Cn"), (0x0BAE, "Lo"), (0x0BBA, "Cn"), (0x0BBE, "Mc"), (0x0BC0, "Mn"), (0x0BC1, "Mc"),
+    (0x0BC3, "Cn"), (0x0BC6, "Mc"), (0x0BC9, "Cn"), (0x0BCA, "Mc"), (0x0BCD, "Mn"), (0x0BCE, "Cn"),
+    (0x0BD0, "Lo"), (0x0BD1, "Cn"), (0x0BD7, "Mc"), (0x0BD8, "Cn"), (0x0BE6, "Nd"), (0x0BF0, "No"),
+    (0x0BF3, "So"), (0x0BF9, "Sc"), (0x0BFA, "So"), (0x0BFB, "Cn"), (0x0C00, "Mn"), (0x0C01, "Mc"),
+    (0x0C04, "Mn"), (0x0C05, "Lo"), (0x0C0D, "Cn"), (0x0C0E, "Lo"), (0x0C11, "Cn"), (0x0C12, "Lo"),
+    (0x0C29, "Cn"), (0x0C2A, "Lo"), (0x0C3A, "Cn"), (0x0C3C, "Mn"), (0x0C3D, "Lo"), (0x0C3E, "Mn"),
+    (0x0C41, "Mc"), (0x0C45, "Cn"), (0x0C46, "Mn"), (0x0C49, "Cn"), (0x0C4A, "Mn"), (0x0C4E, "Cn"),
+    (0x0C55, "Mn"), (0x0C57, "Cn"), (0x0C58, "Lo"), (0x0C5B, "Cn"), (0x0C5D, "Lo"), (0x0C5E, "Cn"),
+    (0x0C60, "Lo"), (0x0C62, "Mn"), (0x0C64, "Cn"), (0x0C66, "Nd"), (0x0C70, "Cn"), (0x0C77, "Po"),
+    (0x0C78, "No"), (0x0C7F, "So"), (0x0C80, "Lo"), (0x0C81, "Mn"), (0x0C82, "Mc"), (0x0C84, "Po"),
+    (0x0C85, "Lo"), (0x0C8D, "Cn"), (0x0C8E, "Lo"), (0x0C91, "Cn"), (0x0C92, "Lo"), (0x0CA9, "Cn"),
+    (0x0CAA, "Lo"), (0x0CB4, "Cn"), (0x0CB5, "Lo"), (0x0CBA, "Cn"), (0x0CBC, "Mn"), (0x0CBD, "Lo"),
+    (0x0CBE, "Mc"), (0x0CBF, "Mn"), (0x0CC0, "Mc"), (0x0CC5, "Cn"), (0x0CC6, "Mn"), (0x0CC7, "Mc"),
+    (0x0CC9, "Cn"), (0x0CCA, "Mc"), (0x0CCC, "Mn"), (0x0CCE, "Cn"), (0x0CD5, "Mc"), (0x0CD7, "Cn"),
+    (0x0CDD, "Lo"), (0x0CDF, "Cn"), (0x0CE0, "Lo"), (0x0CE2, "Mn"), (0x0CE4, "Cn"), (0x0CE6, "Nd"),
+    (0x0CF0, "Cn"), (0x0CF1, "Lo"), (0x0CF3, "Cn"), (0x0D00, "Mn"), (0x0D02, "Mc"), (0x0D04, "Lo"),
+    (0x0D0D, "Cn"), (0x0D0E, "Lo"), (0x0D11, "Cn"), (0x0D12, "Lo"), (0x0D3B, "Mn"), (0x0D3D, "Lo"),
+    (0x0D3E, "Mc"), (0x0D41, "Mn"), (0x0D45, "Cn"), (0x0D46, "Mc"), (0x0D49, "Cn"), (0x0D4A, "Mc"),
+    (0x0D4D, "Mn"), (0x0D4E, "Lo"), (0x0D4F, "So"), (0x0D50, "Cn"), (0x0D54, "Lo"), (0x0D57, "Mc"),
+    (0x0D58, "No"), (0x0D5F, "Lo"), (0x0D62, "Mn"), (0x0D64, "Cn"), (0x0D66, "Nd"), (0x0D70, "No"),
+    (0x0D79, "So"), (0x0D7A, "Lo"), (0x0D80, "Cn"), (0x0D81, "Mn"), (0x0D82, "Mc"), (0x0D84, "Cn"),
+    (0x0D85, "Lo"), (0x0D97, "Cn"), (0x0D9A, "Lo"), (0x0DB2, "Cn"), (0x0DB3, "Lo"), (0x0DBC, "Cn"),
+    (0x0DBD, "Lo"), (0x0DBE, "Cn"), (0x0DC0, "Lo"), (0x0DC7, "Cn"), (0x0DCA, "Mn"), (0x0DCB, "Cn"),
+    (0x0DCF, "Mc"), (0x0DD2, "Mn"), (0x0DD5, "Cn"), (0x0DD6, "Mn"), (0x0DD7, "Cn"), (0x0DD8, "Mc"),
+    (0x0DE0, "Cn"), (0x0DE6, "Nd"), (0x0DF0, "Cn"), (0x0DF2, "Mc"), (0x0DF4, "Po"), (0x0DF5, "Cn"),
+    (0x0E01, "Lo"), (0x0E31, "Mn"), (0x0E32, "Lo"), (0x0E34, "Mn"), (0x0E3B, "Cn"), (0x0E3F, "Sc"),
+    (0x0E40, "Lo"), (0x0E46, "Lm"), (0x0E47, "Mn"), (0x0E4F, "Po"), (0x0E50, "Nd"), (0x0E5A, "Po"),
+    (0x0E5C, "Cn"), (0x0E81, "Lo"), (0x0E83, "Cn"), (0x0E84, "Lo"), (0x0E85, "Cn"), (0x0E86, "Lo"),
+    (0x0E8B, "Cn"), (0x0E8C, "Lo"), (0x0EA4, "Cn"), (0x0EA5, "Lo"), (0x0EA6, "Cn"), (0x0EA7, "Lo"),
+    (0x0EB1, "Mn"), (0x0EB2, "Lo"), (0x0EB4, "Mn"), (0x0EBD, "Lo"), (0x0EBE, "Cn"), (0x0EC0, "Lo"),
+    (0x0EC5, "Cn"), (0x0EC6, "Lm"), (0x0EC7, "Cn"), (0x0EC8, "Mn"), (0x0ECE, "Cn"), (0x0ED0, "Nd"),
+    (0x0EDA, "Cn"), (0x0EDC, "Lo"), (0x0EE0, "Cn"), (0x0F00, "Lo"), (0x0F01, "So"), (0x0F04, "Po"),
+    (0x0F13, "So"), (0x0F14, "Po"), (0x0F15, "So"), (0x0F18, "Mn"), (0x0F1A, "So"), (0x0F20, "Nd"),
+    (0x0F2A, "No"), (0x0F34, "So"), (0x0F35, "Mn"), (0x0F36, "So"), (0x0F37, "Mn"), (0x0F38, "So"),
+    (0x0F39, "Mn"), (0x0F3A, "Ps"), (0x0F3B, "Pe"), (0x0F3C, "Ps"), (0x0F3D, "Pe"), (0x0F3E, "Mc"),
+    (0x0F40, "Lo"), (0x0F48, "Cn"), (0x0F49, "Lo"), (0x0F6D, "Cn"), (0x0F71, "Mn"), (0x0F7F, "Mc"),
+    (0x0F80, "Mn"), (0x0F85, "Po"), (0x0F86, "Mn"), (0x0F88, "Lo"), (0x0F8D, "Mn"), (0x0F98, "Cn"),
+    (0x0F99, "Mn"), (0x0FBD, "Cn"), (0x0FBE, "So"), (0x0FC6, "Mn"), (0x0FC7, "So"), (0x0FCD, "Cn"),
+    (0x0FCE, "So"), (0x0FD0, "Po"), (0x0FD5, "So"), (0x0FD9, "Po"), (0x0FDB, "Cn"), (0x1000, "Lo"),
+    (0x102B, "Mc"), (0x102D, "Mn"), (0x1031, "Mc"), (0x1032, "Mn"), (0x1038, "Mc"), (0x1039, "Mn"),
+    (0x103B, "Mc"), (0x103D, "Mn"), (0x103F, "Lo"), (0x1040, "Nd"), (0x104A, "Po"), (0x1050, "Lo"),
+    (0x1056, "Mc"), (0x1058, "Mn"), (0x105A, "Lo"), (0x105E, "Mn"), (0x1061, "Lo"), (0x1062, "Mc"),
+    (0x1065, "Lo"), (0x1067, "Mc"), (0x106E, "Lo"), (0x1071, "Mn"), (0x1075, "Lo"), (0x1082, "Mn"),
+    (0x1083, "Mc"), (0x1085, "Mn"), (0x1087, "Mc"), (0x108D, "Mn"), (0x108E, "Lo"), (0x108F, "Mc"),
+    (0x1090, "Nd"), (0x109A, "Mc"), (0x109D, "Mn"), (0x109E, "So"), (0x10A0, "Lu"), (0x10C6, "Cn"),
+    (0x10C7, "Lu"), (0x10C8, "Cn"), (0x10CD, "Lu"), (0x10CE, "Cn"), (0x10D0, "Ll"), (0x10FB, "Po"),
+    (0x10FC, "Lm"), (0x10FD, "Ll"), (0x1100, "Lo"), (0x1249, "Cn"), (0x124A, "Lo"), (0x124E, "Cn"),
+    (0x1250, "Lo"), (0x1257, "Cn"), (0x1258, "Lo"), (0x1259, "Cn"), (0x125A, "Lo"), (0x125E, "Cn"),
+    (0x1260, "Lo"), (0x1289, "Cn"), (0x128A, "Lo"), (0x128E, "Cn"), (0x1290, "Lo"), (0x12B1, "Cn"),
+    (0x12B2, "Lo"), (0x12B6, "Cn"), (0x12B8, "Lo"), (0x12BF, "Cn"), (0x12C0, "Lo"), (0x12C1, "Cn"),
+    (0x12C2, "Lo"), (0x12C6, "Cn"), (0x12C8, "Lo"), (0x12D7, "Cn"), (0x12D8, "Lo"), (0x1311, "Cn"),
+    (0x1312, "Lo"), (0x1316, "Cn"), (0x1318, "Lo"), (0x135B, "Cn"), (0x135D, "Mn"), (0x1360, "Po"),
+    (0x1369, "No"), (0x137D, "Cn"), (0x1380, "Lo"), (0x1390, "So"), (0x139A, "Cn"), (0x13A0, "Lu"),
+    (0x13F6, "Cn"), (0x13F8, "Ll"), (0x13FE, "Cn"), (0x1400, "Pd"), (0x1401, "Lo"), (0x166D, "So"),
+    (0x166E, "Po"), (0x166F, "Lo"), (0x1680, "Zs"), (0x1681, "Lo"), (0x169B, "Ps"), (0x169C, "Pe"),
+    (0x169D, "Cn"), (0x16A0, "Lo"), (0x16EB, "Po"), (0x16EE, "Nl"), (0x16F1, "Lo"), (0x16F9, "Cn"),
+    (0x1700, "Lo"), (0x1712, "Mn"), (0x1715, "Mc"), (0x1716, "Cn"), (0x171F, "Lo"), (0x1732, "Mn"),
+    (0x1734, "Mc"), (0x1735, "Po"), (0x1737, "Cn"), (0x1740, "Lo"), (0x1752, "Mn"), (0x1754, "Cn"),
+    (0x1760, "Lo"), (0x176D, "Cn"), (0x176E, "Lo"), (0x1771, "Cn"), (0x1772, "Mn"), (0x1774, "Cn"),
+    (0x1780, "Lo"), (0x17B4, "Mn"), (0x17B6, "Mc"), (0x17B7, "Mn"), (0x17BE, "Mc"), (0x17C6, "Mn"),
+    (0x17C7, "Mc"), (0x17C9, "Mn"), (0x17D4, "Po"), (0x17D7, "Lm"), (0x17D8, "Po"), (0x17DB, "Sc"),
+    (0x17DC, "Lo"), (0x17DD, "Mn"), (0x17DE, "Cn"), (0x17E0, "Nd"), (0x17EA, "Cn"), (0x17F0, "No"),
+    (0x17FA, "Cn"), (0x1800, "Po"), (0x1806, "Pd"), (0x1807, "Po"), (0x180B, "Mn"), (0x180E, "Cf"),
+    (0x180F, "Mn"), (0x1810, "Nd"), (0x181A, "Cn"), (0x1820, "Lo"), (0x1843, "Lm"), (0x1844, "Lo"),
+    (0x1879, "Cn"), (0x1880, "Lo"), (0x1885, "Mn"), (0x1887, "Lo"), (0x18A9, "Mn"), (0x18AA, "Lo"),
+    (0x18AB, "Cn"), (0x18B0, "Lo"), (0x18F6, "Cn"), (0x1900, "Lo"), (0x191F, "Cn"), (0x1920, "Mn"),
+    (0x1923, "Mc"), (0x1927, "Mn"), (0x1929, "Mc"), (0x192C, "Cn"), (0x1930, "Mc"), (0x1932, "Mn"),
+    (0x1933, "Mc"), (0x1939, "Mn"), (0x193C, "Cn"), (0x1940, "So"), (0x1941, "Cn"), (0x1944, "Po"),
+    (0x1946, "Nd"), (0x1950, "Lo"), (0x196E, "Cn"), (0x1970, "Lo"), (0x1975, "Cn"), (0x1980, "Lo"),
+    (0x19AC, "Cn"), (0x19B0, "Lo"), (0x19CA, "Cn"), (0x19D0, "Nd"), (0x19DA, "No"), (0x19DB, "Cn"),
+    (0x19DE, "So"), (0x1A00, "Lo"), (0x1A17, "Mn"), (0x1A19, "Mc"), (0x1A1B, "Mn"), (0x1A1C, "Cn"),
+    (0x1A1E, "Po"), (0x1A20, "Lo"), (0x1A55, "Mc"), (0x1A56, "Mn"), (0x1A57, "Mc"), (0x1A58, "Mn"),
+    (0x1A5F, "Cn"), (0x1A60, "Mn"), (0x1A61, "Mc"), (0x1A62, "Mn"), (0x1A63, "Mc"), (0x1A65, "Mn"),
+    (0x1A6D, "Mc"), (0x1A73, "Mn"), (0x1A7D, "Cn"), (0x1A7F, "Mn"), (0x1A80, "Nd"), (0x1A8A, "Cn"),
+    (0x1A90, "Nd"), (0x1A9A, "Cn"), (0x1AA0, "Po"), (0x1AA7, "Lm"), (0x1AA8, "Po"), (0x1AAE, "Cn"),
+    (0x1AB0, "Mn"), (0x1ABE, "Me"), (0x1ABF, "Mn"), (0x1ACF, "Cn"), (0x1B00, "Mn"), (0x1B04, "Mc"),
+    (0x1B05, "Lo"), (0x1B34, "Mn"), (0x1B35, "Mc"), (0x1B36, "Mn"), (0x1B3B, "Mc"), (0x1B3C, "Mn"),
+    (0x1B3D, "Mc"), (0x1B42, "Mn"), (0x1B43, "Mc"), (0x1B45, "Lo"), (0x1B4D, "Cn"), (0x1B50, "Nd"),
+    (0x1B5A, "Po"), (0x1B61, "So"), (0x1B6B, "Mn"), (0x1B74, "So"), (0x1B7D, "Po"), (0x1B7F, "Cn"),
+    (0x1B80, "Mn"), (0x1B82, "Mc"), (0x1B83, "Lo"), (0x1BA1, "Mc"), (0x1BA2, "Mn"), (0x1BA6, "Mc"),
+    (0x1BA8, "Mn"), (0x1BAA, "Mc"), (0x1BAB, "Mn"), (0x1BAE, "Lo"), (0x1BB0, "Nd"), (0x1BBA, "Lo"),
+    (0x1BE6, "Mn"), (0x1BE7, "Mc"), (0x1BE8, "Mn"), (0x1BEA, "Mc"), (0x1BED, "Mn"), (0x1BEE, "Mc"),
+    (0x1BEF, "Mn"), (0x1BF2, "Mc"), (0x1BF4, "Cn"), (0x1BFC, "Po"), (0x1C00, "Lo"), (0x1C24, "Mc"),
+    (0x1C2C, "Mn"), (0x1C34, "Mc"), (0x1C36, "Mn"), (0x1C38, "Cn"), (0x1C3B, "Po"), (0x1C40, "Nd"),
+    (0x1C4A, "Cn"), (0x1C4D, "Lo"), (0x1C50, "Nd"), (0x1C5A, "Lo"), (0x1C78, "Lm"), (0x1C7E, "Po"),
+    (0x1C80, "Ll"), (0x1C89, "Cn"), (0x1C90, "Lu"), (0x1CBB, "Cn"), (0x1CBD, "Lu"), (0x1CC0, "Po"),
+    (0x1CC8, "Cn"), (0x1CD0, "Mn"), (0x1CD3, "Po"), (0x1CD4, "Mn"), (0x1CE1, "Mc"), (0x1CE2, "Mn"),
+    (0x1CE9, "Lo"), (0x1CED, "Mn"), (0x1CEE, "Lo"), (0x1CF4, "Mn"), (0x1CF5, "Lo"), (0x1CF7, "Mc"),
+    (0x1CF8, "Mn"), (0x1CFA, "Lo"), (0x1CFB, "Cn"), (0x1D00, "Ll"), (0x1D2C, "Lm"), (0x1D6B, "Ll"),
+    (0x1D78, "Lm"), (0x1D79, "Ll"), (0x1D9B, "Lm"), (0x1DC0, "Mn"), (0x1E00, "Lu"), (0x1E01, "Ll"),
+    (0x1E02, "Lu"), (0x1E03, "Ll"), (0x1E04, "Lu"), (0x1E05, "Ll"), (0x1E06, "Lu"), (0x1E07, "Ll"),
+    (0x1E08, "Lu"), (0x1E09, "Ll"), (0x1E0A, "Lu"), (0x1E0B, "Ll"), (0x1E0C, "Lu"), (0x1E0D, "Ll"),
+    (0x1E0E, "Lu"), (0x1E0F, "Ll"), (0x1E10, "Lu"), (0x1E11, "Ll"), (0x1E12, "Lu"), (0x1E13, "Ll"),
+    (0x1E14, "Lu"), (0x1E15, "Ll"), (0x1E16, "Lu"), (0x1E17, "Ll"), (0x1E18, "Lu"), (0x1E19, "Ll"),
+    (0x1E1A, "Lu"), (0x1E1B, "Ll"), (0x1E1C, "Lu"), (0x1E1D, "Ll"), (0x1E1E, "Lu"), (0x1E1F, "Ll"),
+    (0x1E20, "Lu"), (0x1E21, "Ll"), (0x1E22, "Lu"), (0x1E23, "Ll"), (0x1E24, "Lu"), (0x1E25, "Ll"),
+    (0x1E26, "Lu"), (0x1E27, "Ll"), (0x1E28, "Lu"), (0x1E29, "Ll"), (0x1E2A, "Lu"), (0x1E2B, "Ll"),
+    (0x1E2C, "Lu"), (0x1E2D, "Ll"), (0x1E2E, "Lu"), (0x1E2F, "Ll"), (0x1E30, "Lu"), (0x1E31, "Ll"),
+    (0x1E32, "Lu"), (0x1E33, "Ll"), (0x1E34, "Lu"), (0x1E35, "Ll"), (0x1E36, "Lu"), (0x1E37, "Ll"),
+    (0x1E38, "Lu"), (0x1E39, "Ll"), (0x1E3A, "Lu"), (0x1E3B, "Ll"), (0x1E3C, "Lu"), (0x1E3D, "Ll"),
+    (0x1E3E, "Lu"), (0x1E3F, "Ll"), (0x1E40, "Lu"), (0x1E41, "Ll"), (0x1E42, "Lu"), (0x1E43, "Ll"),
+    (0x1E44, "Lu"), (0x1E45, "Ll"), (0x1E46, "Lu"), (0x1E47, "Ll"), (0x1E48, "Lu"), (0x1E49, "Ll"),
+    (0x1E4A, "Lu"), (0x1E4B, "Ll"), (0x1E4C, "Lu"), (0x1E4D, "Ll"), (0x1E4E, "Lu"), (0x1E4F, "Ll"),
+    (0x1E50, "Lu"), (0x1E51, "Ll"), (0x1E52, "Lu"), (0x1E53, "Ll"), (0x1E54, "Lu"), (0x1E55, "Ll"),
+    (0x1E56, "Lu"), (0x1E57, "Ll"), (0x1E58, "Lu"), (0x1E59, "Ll"), (0x1E5A, "Lu"), (0x1E5B, "Ll"),
+    (0x1E5C, "Lu"), (0x1E5D, "Ll"), (0x1E5E, "Lu"), (0x1E5F, "Ll"), (0x1E60, "Lu"), (0x1E61, "Ll"),
+    (0x1E62, "Lu"), (0x1E63, "Ll"), (0x1E64, "Lu"), (0x1E65, "Ll"), (0x1E66, "Lu"), (0x1E67, "Ll"),
+    (0x1E68, "Lu"), (0x1E69, "Ll"), (0x1E6A, "Lu"), (0x1E6B, "Ll"), (0x1E6C, "Lu"), (0x1E6D, "Ll"),
+    (0x1E6E, "Lu"), (0x1E6F, "Ll"), (0x1E70, "Lu"), (0x1E71, "Ll"), (0x1E72, "Lu"), (0x1E73, "Ll"),
+    (0x1E74, "Lu"), (0x1E75, "Ll"), (0x1E76, "Lu"), (0x1E77, "Ll"), (0x1E78, "Lu"), (0x1E79, "Ll"),
+    (0x1E7A, "Lu"), (0x1E7B, "Ll"), (0x1E7C, "Lu"), (0x1E7D, "Ll"), (0x1E7E, "Lu"), (0x1E7F, "Ll"),
+    (0x1E80, "Lu"), (0x1E81, "Ll"), (0x1E82, "Lu"), (0x1E83, "Ll"), (0x1E84, "Lu"), (0x1E85, "Ll"),
+    (0x1E86, "Lu"), (0x1E87, "Ll"), (0x1E88, "Lu"), (0x1E89, "Ll"), (0x1E8A, "Lu"), (0x1E8B, "Ll"),
+    (0x1E8C, "Lu"), (0x1E8D, "Ll"), (0x1E8E, "Lu"), (0x1E8F, "Ll"), (0x1E90, "Lu"), (0x1E91, "Ll"),
+    (0x1E92, "Lu"), (0x1E93, "Ll"), (0x1E94, "Lu"), (0x1E95, "Ll"), (0x1E9E, "Lu"), (0x1E9F, "Ll"),
+    (0x1EA0, "Lu"), (0x1EA1, "Ll"), (0x1EA2, "Lu"), (0x1EA3, "Ll"), (0x1EA4, "Lu"), (0x1EA5, "Ll"),
+    (0x1EA6, "Lu"), (0x1EA7, "Ll"), (0x1EA8, "Lu"), (0x1EA9, "Ll"), (0x1EAA, "Lu"), (0x1EAB, "Ll"),
+    (0x1EAC, "Lu"), (0x1EAD, "Ll"), (0x1EAE, "Lu"), (0x1EAF, "Ll"), (0x1EB0, "Lu"), (0x1EB1, "Ll"),
+    (0x1EB2, "Lu"), (0x1EB3, "Ll"), (0x1EB4, "Lu"), (0x1EB5, "Ll"), (0x1EB6, "Lu"), (0x1EB7, "Ll"),
+    (0x1EB8, "Lu"), (0x1EB9, "Ll"), (0x1EBA, "Lu"), (0x1EBB, "Ll"), (0x1EBC, "Lu"), (0x1EBD, "Ll"),
+    (0x1EBE, "Lu"), (0x1EBF, "Ll"), (0x1EC0, "Lu"), (0x1EC1, "Ll"), (0x1EC2, "Lu"), (0x1EC3, "Ll"),
+    (0x1EC4, "Lu"), (0x1EC5, "Ll"), (0x1EC6, "Lu"), (0x1EC7, "Ll"), (0x1EC8, "Lu"), (0x1EC9, "Ll"),
+    (0x1ECA, "Lu"), (0x1ECB, "Ll"), (0x1ECC, "Lu"), (0x1ECD, "Ll"), (0x1ECE, "Lu"), (0x1ECF, "Ll"),
+    (0x1ED0, "Lu"), (0x1ED1, "Ll"), (0x1ED2, "Lu"), (0x1ED3, "Ll"), (0x1ED4, "Lu"), (0x1ED5, "Ll"),
+    (0x1ED6, "Lu"), (0x1ED7, "Ll"), (0x1ED8, "Lu"), (0x1ED9, "Ll"), (0x1EDA, "Lu"), (0x1EDB, "Ll"),
+    (0x1EDC, "Lu"), (0x1EDD, "Ll"), (0x1EDE, "Lu"), (0x1EDF, "Ll"), (0x1EE0, "Lu"), (0x1EE1, "Ll"),
+    (0x1EE2, "Lu"), (0x1EE3, "Ll"), (0x1EE4, "Lu"), (0x1EE5, "Ll"), (0x1EE6, "Lu"), (0x1EE7, "Ll"),
+    (0x1EE8, "Lu"), (0x1EE9, "Ll"), (0x1EEA, "Lu"), (0x1EEB, "Ll"), (0x1EEC, "Lu"), (0x1EED, "Ll"),
+    (0x1EEE, "Lu"), (0x1EEF, "Ll"), (0x1EF0, "Lu"), (0x1EF1, "Ll"), (0x1EF2, "Lu"), (0x1EF3, "Ll"),
+    (0x1EF4, "Lu"), (0x1EF5, "Ll"), (0x1EF6, "Lu"), (0x1EF7, "Ll"), (0x1EF8, "Lu"), (0x1EF9, "Ll"),
+    (0x1EFA, "Lu"), (0x1EFB, "Ll"), (0x1EFC, "Lu"), (0x1EFD, "Ll"), (0x1EFE, "Lu"), (0x1EFF, "Ll"),
+    (0x1F08, "Lu"), (0x1F10, "Ll"), (0x1F16, "Cn"), (0x1F18, "Lu"), (0x1F1E, "Cn"), (0x1F20, "Ll"),
+    (0x1F28, "Lu"), (0x1F30, "Ll"), (0x1F38, "Lu"), (0x1F40, "Ll"), (0x1F46, "Cn"), (0x1F48, "Lu"),
+    (0x1F4E, "Cn"), (0x1F50, "Ll"), (0x1F58, "Cn"), (0x1F59, "Lu"), (0x1F5A, "Cn"), (0x1F5B, "Lu"),
+    (0x1F5C, "Cn"), (0x1F5D, "Lu"), (0x1F5E, "Cn"), (0x1F5F, "Lu"), (0x1F60, "Ll"), (0x1F68, "Lu"),
+    (0x1F70, "Ll"), (0x1F7E, "Cn"), (0x1F80, "Ll"), (0x1F88, "Lt"), (0x1F90, "Ll"), (0x1F98, "Lt"),
+    (0x1FA0, "Ll"), (0x1FA8, "Lt"), (0x1FB0, "Ll"), (0x1FB5, "Cn"), (0x1FB6, "Ll"), (0x1FB8, "Lu"),
+    (0x1FBC, "Lt"), (0x1FBD, "Sk"), (0x1FBE, "Ll"), (0x1FBF, "Sk"), (0x1FC2, "Ll"), (0x1FC5, "Cn"),
+    (0x1FC6, "Ll"), (0x1FC8, "Lu"), (0x1FCC, "Lt"), (0x1FCD, "Sk"), (0x1FD0, "Ll"), (0x1FD4, "Cn"),
+    (0x1FD6, "Ll"), (0x1FD8, "Lu"), (0x1FDC, "Cn"), (0x1FDD, "Sk"), (0x1FE0, "Ll"), (0x1FE8, "Lu"),
+    (0x1FED, "Sk"), (0x1FF0, "Cn"), (0x1FF2, "Ll"), (0x1FF5, "Cn"), (0x1FF6, "Ll"), (0x1FF8, "Lu"),
+    (0x1FFC, "Lt"), (0x1FFD, "Sk"), (0x1FFF, "Cn"), (0x2000, "Zs"), (0x200B, "Cf"), (0x2010, "Pd"),
+    (0x2016, "Po"), (0x2018, "Pi"), (0x2019, "Pf"), (0x201A, "Ps"), (0x201B, "Pi"), (0x201D, "Pf"),
+    (0x201E, "Ps"), (0x201F, "Pi"), (0x2020, "Po"), (0x2028, "Zl"), (0x2029, "Zp"), (0x202A, "Cf"),
+    (0x202F, "Zs"), (0x2030, "Po"), (0x2039, "Pi"), (0x203A, "Pf"), (0x203B, "Po"), (0x203F, "Pc"),
+    (0x2041, "Po"), (0x2044, "Sm"), (0x2045, "Ps"), (0x2046, "Pe"), (0x2047, "Po"), (0x2052, "Sm"),
+    (0x2053, "Po"), (0x2054, "Pc"), (0x2055, "Po"), (0x205F, "Zs"), (0x2060, "Cf"), (0x2065, "Cn"),
+    (0x2066, "Cf"), (0x2070, "No"), (0x2071, "Lm"), (0x2072, "Cn"), (0x2074, "No"), (0x207A, "Sm"),
+    (0x207D, "Ps"), (0x207E, "Pe"), (0x207F, "Lm"), (0x2080, "No"), (0x208A, "Sm"), (0x208D, "Ps"),
+    (0x208E, "Pe"), (0x208F, "Cn"), (0x2090, "Lm"), (0x209D, "Cn"), (0x20A0, "Sc"), (0x20C1, "Cn"),
+    (0x20D0, "Mn"), (0x20DD, "Me"), (0x20E1, "Mn"), (0x20E2, "Me"), (0x20E5, "Mn"), (0x20F1, "Cn"),
+    (0x2100, "So"), (0x2102, "Lu"), (0x2103, "So"), (0x2107, "Lu"), (0x2108, "So"), (0x210A, "Ll"),
+    (0x210B, "Lu"), (0x210E, "Ll"), (0x2110, "Lu"), (0x2113, "Ll"), (0x2114, "So"), (0x2115, "Lu"),
+    (0x2116, "So"), (0x2118, "Sm"), (0x2119, "Lu"), (0x211E, "So"), (0x2124, "Lu"), (0x2125, "So"),
+    (0x2126, "Lu"), (0x2127, "So"), (0x2128, "Lu"), (0x2129, "So"), (0x212A, "Lu"), (0x212E, "So"),
+    (0x212F, "Ll"), (0x2130, "Lu"), (0x2134, "Ll"), (0x2135, "Lo"), (0x2139, "Ll"), (0x213A, "So"),
+    (0x213C, "Ll"), (0x213E, "Lu"), (0x2140, "Sm"), (0x2145, "Lu"), (0x2146, "Ll"), (0x214A, "So"),
+    (0x214B, "Sm"), (0x214C, "So"), (0x214E, "Ll"), (0x214F, "So"), (0x2150, "No"), (0x2160, "Nl"),
+    (0x2183, "Lu"), (0x2184, "Ll"), (0x2185, "Nl"), (0x2189, "No"), (0x218A, "So"), (0x218C, "Cn"),
+    (0x2190, "Sm"), (0x2195, "So"), (0x219A, "Sm"), (0x219C, "So"), (0x21A0, "Sm"), (0x21A1, "So"),
+    (0x21A3, "Sm"), (0x21A4, "So"), (0x21A6, "Sm"), (0x21A7, "So"), (0x21AE, "Sm"), (0x21AF, "So"),
+    (0x21CE, "Sm"), (0x21D0, "So"), (0x21D2, "Sm"), (0x21D3, "So"), (0x21D4, "Sm"), (0x21D5, "So"),
+    (0x21F4, "Sm"), (0x2300, "So"), (0x2308, "Ps"), (0x2309, "Pe"), (0x230A, "Ps"), (0x230B, "Pe"),
+    (0x230C, "So"), (0x2320, "Sm"), (0x2322, "So"), (0x2329, "Ps"), (0x232A, "Pe"), (0x232B, "So"),
+    (0x237C, "Sm"), (0x237D, "So"), (0x239B, "Sm"), (0x23B4, "So"), (0x23DC, "Sm"), (0x23E2, "So"),
+    (0x2427, "Cn"), (0x2440, "So"), (0x244B, "Cn"), (0x2460, "No"), (0x249C, "So"), (0x24EA, "No"),
+    (0x2500, "So"), (0x25B7, "Sm"), (0x25B8, "So"), (0x25C1, "Sm"), (0x25C2, "So"), (0x25F8, "Sm"),
+    (0x2600, "So"), (0x266F, "Sm"), (0x2670, "So"), (0x2768, "Ps"), (0x2769, "Pe"), (0x276A, "Ps"),
+    (0x276B, "Pe"), (0x276C, "Ps"), (0x276D, "Pe"), (0x276E, "Ps"), (0x276F, "Pe"), (0x2770, "Ps"),
+    (0x2771, "Pe"), (0x2772, "Ps"), (0x2773, "Pe"), (0x2774, "Ps"), (0x2775, "Pe"), (0x2776, "No"),
+    (0x2794, "So"), (0x27C0, "Sm"), (0x27C5, "Ps"), (0x27C6, "Pe"), (0x27C7, "Sm"), (0x27E6, "Ps"),
+    (0x27E7, "Pe"), (0x27E8, "Ps"), (0x27E9, "Pe"), (0x27EA, "Ps"), (0x27EB, "Pe"), (0x27EC, "Ps"),
+    (0x27ED, "Pe"), (0x27EE, "Ps"), (0x27EF, "Pe"), (0x27F0, "Sm"), (0x2800, "So"), (0x2900, "Sm"),
+    (0x2983, "Ps"), (0x2984, "Pe"), (0x2985, "Ps"), (0x2986, "Pe"), (0x2987, "Ps"), (0x2988, "Pe"),
+    (0x2989, "Ps"), (0x298A, "Pe"), (0x298B, "Ps"), (0x298C, "Pe"), (0x298D, "Ps"), (0x298E, "Pe"),
+    (0x298F, "Ps"), (0x2990, "Pe"), (0x2991, "Ps"), (0x2992, "Pe"), (0x2993, "Ps"), (0x2994, "Pe"),
+    (0x2995, "Ps"), (0x2996, "Pe"), (0x2997, "Ps"), (0x2998, "Pe"), (0x2999, "Sm"), (0x29D8, "Ps"),
+    (0x29D9, "Pe"), (0x29DA, "Ps"), (0x29DB, "Pe"), (0x29DC, "Sm"), (0x29FC, "Ps"), (0x29FD, "Pe"),
+    (0x29FE, "Sm"), (0x2B00, "So"), (0x2B30, "Sm"), (0x2B45, "So"), (0x2B47, "Sm"), (0x2B4D, "So"),
+    (0x2B74, "Cn"), (0x2B76, "So"), (0x2B96, "Cn"), (0x2B97, "So"), (0x2C00, "Lu"), (0x2C30, "Ll"),
+    (0x2C60, "Lu"), (0x2C61, "Ll"), (0x2C62, "Lu"), (0x2C65, "Ll"), (0x2C67, "Lu"), (0x2C68, "Ll"),
+    (0x2C69, "Lu"), (0x2C6A, "Ll"), (0x2C6B, "Lu"), (0x2C6C, "Ll"), (0x2C6D, "Lu"), (0x2C71, "Ll"),
+    (0x2C72, "Lu"), (0x2C73, "Ll"), (0x2C75, "Lu"), (0x2C76, "Ll"), (0x2C7C, "Lm"), (0x2C7E, "Lu"),
+    (0x2C81, "Ll"), (0x2C82, "Lu"), (0x2C83, "Ll"), (0x2C84, "Lu"), (0x2C85, "Ll"), (0x2C86, "Lu"),
+    (0x2C87, "Ll"), (0x2C88, "Lu"), (0x2C89, "Ll"), (0x2C8A, "Lu"), (0x2C8B, "Ll"), (0x2C8C, "Lu"),
+    (0x2C8D, "Ll"), (0x2C8E, "Lu"), (0x2C8F, "Ll"), (0x2C90, "Lu"), (0x2C91, "Ll"), (0x2C92, "Lu"),
+    (0x2C93, "Ll"), (0x2C94, "Lu"), (0x2C95, "Ll"), (0x2C96, "Lu"), (0x2C97, "Ll"), (0x2C98, "Lu"),
+    (0x2C99, "Ll"), (0x2C9A, "Lu"), (0x2C9B, "Ll"), (0x2C9C, "Lu"), (0x2C9D, "Ll"), (0x2C9E, "Lu"),
+    (0x2C9F, "Ll"), (0x2CA0, "Lu"), (0x2CA1, "Ll"), (0x2CA2, "Lu"), (0x2CA3, "Ll"), (0x2CA4, "Lu"),
+    (0x2CA5, "Ll"), (0x2CA6, "Lu"), (0x2CA7, "Ll"), (0x2CA8, "Lu"), (0x2CA9, "Ll"), (0x2CAA, "Lu"),
+    (0x2CAB, "Ll"), (0x2CAC, "Lu"), (0x2CAD, "Ll"), (0x2CAE, "Lu"), (0x2CAF, "Ll"), (0x2CB0, "Lu"),
+    (0x2CB1, "Ll"), (0x2CB2, "Lu"), (0x2CB3, "Ll"), (0x2CB4, "Lu"), (0x2CB5, "Ll"), (0x2CB6, "Lu"),
+    (0x2CB7, "Ll"), (0x2CB8, "Lu"), (0x2CB9, "Ll"), (0x2CBA, "Lu"), (0x2CBB, "Ll"), (0x2CBC, "Lu"),
+    (0x2CBD, "Ll"), (0x2CBE, "Lu"), (0x2CBF, "Ll"), (0x2CC0, "Lu"), (0x2CC1, "Ll"), (0x2CC2, "Lu"),
+    (0x2CC3, "Ll"), (0x2CC4, "Lu"), (0x2CC5, "Ll"), (0x2CC6, "Lu"), (0x2CC7, "Ll"), (0x2CC8, "Lu"),
+    (0x2CC9, "Ll"), (0x2CCA, "Lu"), (0x2CCB, "Ll"), (0x2CCC, "Lu"), (0x2CCD, "Ll"), (0x2CCE, "Lu"),
+    (0x2CCF, "Ll"), (0x2CD0, "Lu"), (0x2CD1, "Ll"), (0x2CD2, "Lu"), (0x2CD3, "Ll"), (0x2CD4, "Lu"),
+    (0x2CD5, "Ll"), (0x2CD6, "Lu"), (0x2CD7, "Ll"), (0x2CD8, "Lu"), (0x2CD9, "Ll"), (0x2CDA, "Lu"),
+    (0x2CDB, "Ll"), (0x2CDC, "Lu"), (0x2CDD, "Ll"), (0x2CDE, "Lu"), (0x2CDF, "Ll"), (0x2CE0, "Lu"),
+    (0x2CE1, "Ll"), (0x2CE2, "Lu"), (0x2CE3, "Ll"), (0x2CE5, "So"), (0x2CEB, "Lu"), (0x2CEC, "Ll"),
+    (0x2CED, "Lu"), (0x2CEE, "Ll"), (0x2CEF, "Mn"), (0x2CF2, "Lu"), (0x2CF3, "Ll"), (0x2CF4, "Cn"),
+    (0x2CF9, "Po"), (0x2CFD, "No"), (0x2CFE, "Po"), (0x2D00, "Ll"), (0x2D26, "Cn"), (0x2D27, "Ll"),
+    (0x2D28, "Cn"), (0x2D2D, "Ll"), (0x2D2E, "Cn"), (0x2D30, "Lo"), (0x2D68, "Cn"), (0x2D6F, "Lm"),
+    (0x2D70, "Po"), (0x2D71, "Cn"), (0x2D7F, "Mn"), (0x2D80, "Lo"), (0x2D97, "Cn"), (0x2DA0, "Lo"),
+    (0x2DA7, "Cn"), (0x2DA8, "Lo"), (0x2DAF, "Cn"), (0x2DB0, "Lo"), (0x2DB7, "Cn"), (0x2DB8, "Lo"),
+    (0x2DBF, "Cn"), (0x2DC0, "Lo"), (0x2DC7, "Cn"), (0x2DC8, "Lo"), (0x2DCF, "Cn"), (0x2DD0, "Lo"),
+    (0x2DD7, "Cn"), (0x2DD8, "Lo"), (0x2DDF, "Cn"), (0x2DE0, "Mn"), (0x2E00, "Po"), (0x2E02, "Pi"),
+    (0x2E03, "Pf"), (0x2E04, "Pi"), (0x2E05, "Pf"), (0x2E06, "Po"), (0x2E09, "Pi"), (0x2E0A, "Pf"),
+    (0x2E0B, "Po"), (0x2E0C, "Pi"), (0x2E0D, "Pf"), (0x2E0E, "Po"), (0x2E17, "Pd"), (0x2E18, "Po"),
+    (0x2E1A, "Pd"), (0x2E1B, "Po"), (0x2E1C, "Pi"), (0x2E1D, "Pf"), (0x2E1E, "Po"), (0x2E20, "Pi"),
+    (0x2E21, "Pf"), (0x2E22, "Ps"), (0x2E23, "Pe"), (0x2E24, "Ps"), (0x2E25, "Pe"), (0x2E26, "Ps"),
+    (0x2E27, "Pe"), (0x2E28, "Ps"), (0x2E29, "Pe"), (0x2E2A, "Po"), (0x2E2F, "Lm"), (0x2E30, "Po"),
+    (0x2E3A, "Pd"), (0x2E3C, "Po"), (0x2E40, "Pd"), (0x2E41, "Po"), (0x2E42, "Ps"), (0x2E43, "Po"),
+    (0x2E50, "So"), (0x2E52, "Po"), (0x2E55, "Ps"), (0x2E56, "Pe"), (0x2E57, "Ps"), (0x2E58, "Pe"),
+    (0x2E59, "Ps"), (0x2E5A, "Pe"), (0x2E5B, "Ps"), (0x2E5C, "Pe"), (0x2E5D, "Pd"), (0x2E5E, "Cn"),
+    (0x2E80, "So"), (0x2E9A, "Cn"), (0x2E9B, "So"), (0x2EF4, "Cn"), (0x2F00, "So"), (0x2FD6, "Cn"),
+    (0x2FF0, "So"), (0x2FFC, "Cn"), (0x3000, "Zs"), (0x3001, "Po"), (0x3004, "So"), (0x3005, "Lm"),
+    (0x3006, "Lo"), (0x3007, "Nl"), (0x3008, "Ps"), (0x3009, "Pe"), (0x300A, "Ps"), (0x300B, "Pe"),
+    (0x300C, "Ps"), (0x300D, "Pe"), (0x300E, "Ps"), (0x300F, "Pe"), (0x3010, "Ps"), (0x3011, "Pe"),
+    (0x3012, "So"), (0x3014, "Ps"), (0x3015, "Pe"), (0x3016, "Ps"), (0x3017, "Pe"), (0x3018, "Ps"),
+    (0x3019, "Pe"), (0x301A, "Ps"), (0x301B, "Pe"), (0x301C, "Pd"), (0x301D, "Ps"), (0x301E, "Pe"),
+    (0x3020, "So"), (0x3021, "Nl"), (0x302A, "Mn"), (0x302E, "Mc"), (0x3030, "Pd"), (0x3031, "Lm"),
+    (0x3036, "So"), (0x3038, "Nl"), (0x303B, "Lm"), (0x303C, "Lo"), (0x303D, "Po"), (0x303E, "So"),
+    (0x3040, "Cn"), (0x3041, "Lo"), (0x3097, "Cn"), (0x3099, "Mn"), (0x309B, "Sk"), (0x309D, "Lm"),
+    (0x309F, "Lo"), (0x30A0, "Pd"), (0x30A1, "Lo"), (0x30FB, "Po"), (0x30FC, "Lm"), (0x30FF, "Lo"),
+    (0x3100, "Cn"), (0x3105, "Lo"), (0x3130, "Cn"), (0x3131, "Lo"), (0x318F, "Cn"), (0x3190, "So"),
+    (0x3192, "No"), (0x3196, "So"), (0x31A0, "Lo"), (0x31C0, "So"), (0x31E4, "Cn"), (0x31F0, "Lo"),
+    (0x3200, "So"), (0x321F, "Cn"), (0x3220, "No"), (0x322A, "So"), (0x3248, "No"), (0x3250, "So"),
+    (0x3251, "No"), (0x3260, "So"), (0x3280, "No"), (0x328A, "So"), (0x32B1, "No"), (0x32C0, "So"),
+    (0x3400, "Lo"), (0x4DC0, "So"), (0x4E00, "Lo"), (0xA015, "Lm"), (0xA016, "Lo"), (0xA48D, "Cn"),
+    (0xA490, "So"), (0xA4C7, "Cn"), (0xA4D0, "Lo"), (0xA4F8, "Lm"), (0xA4FE, "Po"), (0xA500, "Lo"),
+    (0xA60C, "Lm"), (0xA60D, "Po"), (0xA610, "Lo"), (0xA620, "Nd"), (0xA62A, "Lo"), (0xA62C, "Cn"),
+    (0xA640, "Lu"), (0xA641, "Ll"), (0xA642, "Lu"), (0xA643, "Ll"), (0xA644, "Lu"), (0xA645, "Ll"),
+    (0xA646, "Lu"), (0xA647, "Ll"), (0xA648, "Lu"), (0xA649, "Ll"), (0xA64A, "Lu"), (0xA64B, "Ll"),
+    (0xA64C, "Lu"), (0xA64D, "Ll"), (0xA64E, "Lu"), (0xA64F, "Ll"), (0xA650, "Lu"), (0xA651, "Ll"),
+    (0xA652, "Lu"), (0xA653, "Ll"), (0xA654, "Lu"), (0xA655, "Ll"), (0xA656, "Lu"), (0xA657, "Ll"),
+    (0xA658, "Lu"), (0xA659, "Ll"), (0xA65A, "Lu"), (0xA65B, "Ll"), (0xA65C, "Lu"), (0xA65D, "Ll"),
+    (0xA65E, "Lu"), (0xA65F, "Ll"), (0xA660, "Lu"), (0xA661, "Ll"), (0xA662, "Lu"), (0xA663, "Ll"),
+    (0xA664, "Lu"), (0xA665, "Ll"), (0xA666, "Lu"), (0xA667, "Ll"), (0xA668, "Lu"), (0xA669, "Ll"),
+    (0xA66A, "Lu"), (0xA66B, "Ll"), (0xA66C, "Lu"), (0xA66D, "Ll"), (0xA66E, "Lo"), (0xA66F, "Mn"),
+    (0xA670, "Me"), (0xA673, "Po"), (0xA674, "Mn"), (0xA67E, "Po"), (0xA67F, "Lm"), (0xA680, "Lu"),
+    (0xA681, "Ll"), (0xA682, "Lu"), (0xA683, "Ll"), (0xA684, "Lu"), (0xA685, "Ll"), (0xA686, "Lu"),
+    (0xA687, "Ll"), (0xA688, "Lu"), (0xA689, "Ll"), (0xA68A, "Lu"), (0xA68B, "Ll"), (0xA68C, "Lu"),
+    (0xA68D, "Ll"), (0xA68E, "Lu"), (0xA68F, "Ll"), (0xA690, "Lu"), (0xA691, "Ll"), (0xA692, "Lu"),
+    (0xA693, "Ll"), (0xA694, "Lu"), (0xA695, "Ll"), (0xA696, "Lu"), (0xA697, "Ll"), (0xA698, "Lu"),
+    (0xA699, "Ll"), (0xA69A, "Lu"), (0xA69B, "Ll"), (0xA69C, "Lm"), (0xA69E, "Mn"), (0xA6A0, "Lo"),
+    (0xA6E6, "Nl"), (0xA6F0, "Mn"), (0xA6F2, "Po"), (0xA6F8, "Cn"), (0xA700, "Sk"), (0xA717, "Lm"),
+    (0xA720, "Sk"), (0xA722, "Lu"), (0xA723, "Ll"), (0xA724, "Lu"), (0xA725, "Ll"), (0xA726, "Lu"),
+    (0xA727, "Ll"), (0xA728, "Lu"), (0xA729, "Ll"), (0xA72A, "Lu"), (0xA72B, "Ll"), (0xA72C, "Lu"),
+    (0xA72D, "Ll"), (0xA72E, "Lu"), (0xA72F, "Ll"), (0xA732, "Lu"), (0xA733, "Ll"), (0xA734, "Lu"),
+    (0xA735, "Ll"), (0xA736, "Lu"), (0xA737, "Ll"), (0xA738, "Lu"), (0xA739, "Ll"), (0xA73A, "Lu"),
+    (0xA73B, "Ll"), (0xA73C, "Lu"), (0xA73D, "Ll"), (0xA73E, "Lu"), (0xA73F, "Ll"), (0xA740, "Lu"),
+    (0xA741, "Ll"), (0xA742, "Lu"), (0xA743, "Ll"), (0xA744, "Lu"), (0xA745, "Ll"), (0xA746, "Lu"),
+    (0xA747, "Ll"), (0xA748, "Lu"), (0xA749, "Ll"), (0xA74A, "Lu"), (0xA74B, "Ll"), (0xA74C, "Lu"),
+    (0xA74D, "Ll"), (0xA74E, "Lu"), (0xA74F, "Ll"), (0xA750, "Lu"), (0xA751, "Ll"), (0xA752, "Lu"),
+    (0xA753, "Ll"), (0xA754, "Lu"), (0xA755, "Ll"), (0xA756, "Lu"), (0xA757, "Ll"), (0xA758, "Lu"),
+    (0xA759, "Ll"), (0xA75A, "Lu"), (0xA75B, "Ll"), (0xA75C, "Lu"), (0xA75D, "Ll"), (0xA75E, "Lu"),
+    (0xA75F, "Ll"), (0xA760, "Lu"), (0xA761, "Ll"), (0xA762, "Lu"), (0xA763, "Ll"), (0xA764, "Lu"),
+    (0xA765, "Ll"), (0xA766, "Lu"), (0xA767, "Ll"), (0xA768, "Lu"), (0xA769, "Ll"), (0xA76A, "Lu"),
+    (0xA76B, "Ll"), (0xA76C, "Lu"), (0xA76D, "Ll"), (0xA76E, "Lu"), (0xA76F, "Ll"), (0xA770, "Lm"),
+    (0xA771, "Ll"), (0xA779, "Lu"), (0xA77A, "Ll"), (0xA77B, "Lu"), (0xA77C, "Ll"), (0xA77D, "Lu"),
+    (0xA77F, "Ll"), (0xA780, "Lu"), (0xA781, "Ll"), (0xA782, "Lu"), (0xA783, "Ll"), (0xA784, "Lu"),
+    (0xA785, "Ll"), (0xA786, "Lu"), (0xA787, "Ll"), (0xA788, "Lm"), (0xA789, "Sk"), (0xA78B, "Lu"),
+    (0xA78C, "Ll"), (0xA78D, "Lu"), (0xA78E, "Ll"), (0xA78F, "Lo"), (0xA790, "Lu"), (0xA791, "Ll"),
+    (0xA792, "Lu"), (0xA793, "Ll"), (0xA796, "Lu"), (0xA797, "Ll"), (0xA798, "Lu"), (0xA799, "Ll"),
+    (0xA79A, "Lu"), (0xA79B, "Ll"), (0xA79C, "Lu"), (0xA79D, "Ll"), (0xA79E, "Lu"), (0xA79F, "Ll"),
+    (0xA7A0, "Lu"), (0xA7A1, "Ll"), (0xA7A2, "Lu"), (0xA7A3, "Ll"), (0xA7A4, "Lu"), (0xA7A5, "Ll"),
+    (0xA7A6, "Lu"), (0xA7A7, "Ll"), (0xA7A8, "Lu"), (0xA7A9, "Ll"), (0xA7AA, "Lu"), (0xA7AF, "Ll"),
+    (0xA7B0, "Lu"), (0xA7B5, "Ll"), (0xA7B6, "Lu"), (0xA7B7, "Ll"), (0xA7B8, "Lu"), (0xA7B9, "Ll"),
+    (0xA7BA, "Lu"), (0xA7BB, "Ll"), (0xA7BC, "Lu"), (0xA7BD, "Ll"), (0xA7BE, "Lu"), (0xA7BF, "Ll"),
+    (0xA7C0, "Lu"), (0xA7C1, "Ll"), (0xA7C2, "Lu"), (0xA7C3, "Ll"), (0xA7C4, "Lu"), (0xA7C8, "Ll"),
+    (0xA7C9, "Lu"), (0xA7CA, "Ll"), (0xA7CB, "Cn"), (0xA7D0, "Lu"), (0xA7D1, "Ll"), (0xA7D2, "Cn"),
+    (0xA7D3, "Ll"), (0xA7D4, "Cn"), (0xA7D5, "Ll"), (0xA7D6, "Lu"), (0xA7D7, "Ll"), (0xA7D8, "Lu"),
+    (0xA7D9, "Ll"), (0xA7DA, "Cn"), (0xA7F2, "Lm"), (0xA7F5, "Lu"), (0xA7F6, "Ll"), (0xA7F7, "Lo"),
+    (0xA7F8, "Lm"), (0xA7FA, "Ll"), (0xA7FB, "Lo"), (0xA802, "Mn"), (0xA803, "Lo"), (0xA806, "Mn"),
+    (0xA807, "Lo"), (0xA80B, "Mn"), (0xA80C, "Lo"), (0xA823, "Mc"), (0xA825, "Mn"), (0xA827, "Mc"),
+    (0xA828, "So"), (0xA82C, "Mn"), (0xA82D, "Cn"), (0xA830, "No"), (0xA836, "So"), (0xA838, "Sc"),
+    (0xA839, "So"), (0xA83A, "Cn"), (0xA840, "Lo"), (0xA874, "Po"), (0xA878, "Cn"), (0xA880, "Mc"),
+    (0xA882, "Lo"), (0xA8B4, "Mc"), (0xA8C4, "Mn"), (0xA8C6, "Cn"), (0xA8CE, "Po"), (0xA8D0, "Nd"),
+    (0xA8DA, "Cn"), (0xA8E0, "Mn"), (0xA8F2, "Lo"), (0xA8F8, "Po"), (0xA8FB, "Lo"), (0xA8FC, "Po"),
+    (0xA8FD, "Lo"), (0xA8FF, "Mn"), (0xA900, "Nd"), (0xA90A, "Lo"), (0xA926, "Mn"), (0xA92E, "Po"),
+    (0xA930, "Lo"), (0xA947, "Mn"), (0xA952, "Mc"), (0xA954, "Cn"), (0xA95F, "Po"), (0xA960, "Lo"),
+    (0xA97D, "Cn"), (0xA980, "Mn"), (0xA983, "Mc"), (0xA984, "Lo"), (0xA9B3, "Mn"), (0xA9B4, "Mc"),
+    (0xA9B6, "Mn"), (0xA9BA, "Mc"), (0xA9BC, "Mn"), (0xA9BE, "Mc"), (0xA9C1, "Po"), (0xA9CE, "Cn"),
+    (0xA9CF, "Lm"), (0xA9D0, "Nd"), (0xA9DA, "Cn"), (0xA9DE, "Po"), (0xA9E0, "Lo"), (0xA9E5, "Mn"),
+    (0xA9E6, "Lm"), (0xA9E7, "Lo"), (0xA9F0, "Nd"), (0xA9FA, "Lo"), (0xA9FF, "Cn"), (0xAA00, "Lo"),
+    (0xAA29, "Mn"), (0xAA2F, "Mc"), (0xAA31, "Mn"), (0xAA33, "Mc"), (0xAA35, "Mn"), (0xAA37, "Cn"),
+    (0xAA40, "Lo"), (0xAA43, "Mn"), (0xAA44, "Lo"), (0xAA4C, "Mn"), (0xAA4D, "Mc"), (0xAA4E, "Cn"),
+    (0xAA50, "Nd"), (0xAA5A, "Cn"), (0xAA5C, "Po"), (0xAA60, "Lo"), (0xAA70, "Lm"), (0xAA71, "Lo"),
+    (0xAA77, "So"), (0xAA7A, "Lo"), (0xAA7B, "Mc"), (0xAA7C, "Mn"), (0xAA7D, "Mc"), (0xAA7E, "Lo"),
+    (0xAAB0, "Mn"), (0xAAB1, "Lo"), (0xAAB2, "Mn"), (0xAAB5, "Lo"), (0xAAB7, "Mn"), (0xAAB9, "Lo"),
+    (0xAABE, "Mn"), (0xAAC0, "Lo"), (0xAAC1, "Mn"), (0xAAC2, "Lo"), (0xAAC3, "Cn"), (0xAADB, "Lo"),
+    (0xAADD, "Lm"), (0xAADE, "Po"), (0xAAE0, "Lo"), (0xAAEB, "Mc"), (0xAAEC, "Mn"), (0xAAEE, "Mc"),
+    (0xAAF0, "Po"), (0xAAF2, "Lo"), (0xAAF3, "Lm"), (0xAAF5, "Mc"), (0xAAF6, "Mn"), (0xAAF7, "Cn"),
+    (0xAB01, "Lo"), (0xAB07, "Cn"), (0xAB09, "Lo"), (0xAB0F, "Cn"), (0xAB11, "Lo"), (0xAB17, "Cn"),
+    (0xAB20, "Lo"), (0xAB27, "Cn"), (0xAB28, "Lo"), (0xAB2F, "Cn"), (0xAB30, "Ll"), (0xAB5B, "Sk"),
+    (0xAB5C, "Lm"), (0xAB60, "Ll"), (0xAB69, "Lm"), (0xAB6A, "Sk"), (0xAB6C, "Cn"), (0xAB70, "Ll"),
+    (0xABC0, "Lo"), (0xABE3, "Mc"), (0xABE5, "Mn"), (0xABE6, "Mc"), (0xABE8, "Mn"), (0xABE9, "Mc"),
+    (0xABEB, "Po"), (0xABEC, "Mc"), (0xABED, "Mn"), (0xABEE, "Cn"), (0xABF0, "Nd"), (0xABFA, "Cn"),
+    (0xAC00, "Lo"), (0xD7A4, "Cn"), (0xD7B0, "Lo"), (0xD7C7, "Cn"), (0xD7CB, "Lo"), (0xD7FC, "Cn"),
+    (0xD800, "Cs"), (0xE000, "Co"), (0xF900, "Lo"), (0xFA6E, "Cn"), (0xFA70, "Lo"), (0xFADA, "Cn"),
+    (0xFB00, "Ll"), (0xFB07, "Cn"), (0xFB13, "Ll"), (0xFB18, "Cn"), (0xFB1D, "Lo"), (0xFB1E, "Mn"),
+    (0xFB1F, "Lo"), (0xFB29, "Sm"), (0xFB2A, "Lo"), (0xFB37, "Cn"), (0xFB38, "Lo"), (0xFB3D, "Cn"),
+    (0xFB3E, "Lo"), (0xFB3F, "Cn"), (0xFB40, "Lo"), (0xFB42, "Cn"), (0xFB43, "Lo"), (0xFB45, "Cn"),
+    (0xFB46, "Lo"), (0xFBB2, "Sk"), (0xFBC3, "Cn"), (0xFBD3, "Lo"), (0xFD3E, "Pe"), (0xFD3F, "Ps"),
+    (0xFD40, "So"), (0xFD50, "Lo"), (0xFD90, "Cn"), (0xFD92, "Lo"), (0xFDC8, "Cn"), (0xFDCF, "So"),
+    (0xFDD0, "Cn"), (0xFDF0, "Lo"), (0xFDFC, "Sc"), (0xFDFD, "So"), (0xFE00, "Mn"), (0xFE10, "Po"),
+    (0xFE17, "Ps"), (0xFE18, "Pe"), (0xFE19, "Po"), (0xFE1A, "Cn"), (0xFE20, "Mn"), (0xFE30, "Po"),
+    (0xFE31, "Pd"), (0xFE33, "Pc"), (0xFE35, "Ps"), (0xFE36, "Pe"), (0xFE37, "Ps"), (0xFE38, "Pe"),
+    (0xFE39, "Ps"), (0xFE3A, "Pe"), (0xFE3B, "Ps"), (0xFE3C, "Pe"), (0xFE3D, "Ps"), (0xFE3E, "Pe"),
+    (0xFE3F, "Ps"), (0xFE40, "Pe"), (0xFE41, "Ps"), (0xFE42, "Pe"), (0xFE43, "Ps"), (0xFE44, "Pe"),
+    (0xFE45, "Po"), (0xFE47, "Ps"), (0xFE48, "Pe"), (0xFE49, "Po"), (0xFE4D, "Pc"), (0xFE50, "Po"),
+    (0xFE53, "Cn"), (0xFE54, "Po"), (0xFE58, "Pd"), (0xFE59, "Ps"), (0xFE5A, "Pe"), (0xFE5B, "Ps"),
+    (0xFE5C, "Pe"), (0xFE5D, "Ps"), (0xFE5E, "Pe"), (0xFE5F, "Po"), (0xFE62, "Sm"), (0xFE63, "Pd"),
+    (0xFE64, "Sm"), (0xFE67, "Cn"), (0xFE68, "Po"), (0xFE69, "Sc"), (0xFE6A, "Po"), (0xFE6C, "Cn"),
+    (0xFE70, "Lo"), (0xFE75, "Cn"), (0xFE76, "Lo"), (0xFEFD, "Cn"), (0xFEFF, "Cf"), (0xFF00, "Cn"),
+    (0xFF01, "Po"), (0xFF04, "Sc"), (0xFF05, "Po"), (0xFF08, "Ps"), (0xFF09, "Pe"), (0xFF0A, "Po"),
+    (0xFF0B, "Sm"), (0xFF0C, "Po"), (0xFF0D, "Pd"), (0xFF0E, "Po"), (0xFF10, "Nd"), (0xFF1A, "Po"),
+    (0xFF1C, "Sm"), (0xFF1F, "Po"), (0xFF21, "Lu"), (0xFF3B, "Ps"), (0xFF3C, "Po"), (0xFF3D, "Pe"),
+    (0xFF3E, "Sk"), (0xFF3F, "Pc"), (0xFF40, "Sk"), (0xFF41, "Ll"), (0xFF5B, "Ps"), (0xFF5C, "Sm"),
+    (0xFF5D, "Pe"), (0xFF5E, "Sm"), (0xFF5F, "Ps"), (0xFF60, "Pe"), (0xFF61, "Po"), (0xFF62, "Ps"),
+    (0xFF63, "Pe"), (0xFF64, "Po"), (0xFF66, "Lo"), (0xFF70, "Lm"), (0xFF71, "Lo"), (0xFF9E, "Lm"),
+    (0xFFA0, "Lo"), (0xFFBF, "Cn"), (0xFFC2, "Lo"), (0xFFC8, "Cn"), (0xFFCA, "Lo"), (0xFFD0, "Cn"),
+    (0xFFD2, "Lo"), (0xFFD8, "Cn"), (0xFFDA, "Lo"), (0xFFDD, "Cn"), (0xFFE0, "Sc"), (0xFFE2, "Sm"),
+    (0xFFE3, "Sk"), (0xFFE4, "So"), (0xFFE5, "Sc"), (0xFFE7, "Cn"), (0xFFE8, "So"), (0xFFE9, "Sm"),
+    (0xFFED, "So"), (0xFFEF, "Cn"), (0xFFF9, "Cf"), (0xFFFC, "So"), (0xFFFE, "Cn"), (0x10000, "Lo"),
+    (0x1000C, "Cn"), (0x1000D, "Lo"), (0x10027, "Cn"), (0x10028, "Lo"), (0x1003B, "Cn"),
+    (0x1003C, "Lo"), (0x1003E, "Cn"), (0x1003F, "Lo"), (0x1004E, "Cn"), (0x10050, "Lo"),
+    (0x1005E, "Cn"), (0x10080, "Lo"), (0x100FB, "Cn"), (0x10100, "Po"), (0x10103, "Cn"),
+    (0x10107, "No"), (0x10134, "Cn"), (0x10137, "So"), (0x10140, "Nl"), (0x10175, "No"),
+    (0x10179, "So"), (0x1018A, "No"), (0x1018C, "So"), (0x1018F, "Cn"), (0x10190, "So"),
+    (0x1019D, "Cn"), (0x101A0, "So"), (0x101A1, "Cn"), (0x101D0, "So"), (0x101FD, "Mn"),
+    (0x101FE, "Cn"), (0x10280, "Lo"), (0x1029D, "Cn"), (0x102A0, "Lo"), (0x102D1, "Cn"),
+    (0x102E0, "Mn"), (0x102E1, "No"), (0x102FC, "Cn"), (0x10300, "Lo"), (0x10320, "No"),
+    (0x10324, "Cn"), (0x1032D, "Lo"), (0x10341, "Nl"), (0x10342, "Lo"), (0x1034A, "Nl"),
+    (0x1034B, "Cn"), (0x10350, "Lo"), (0x10376, "Mn"), (0x1037B, "Cn"), (0x10380, "Lo"),
+    (0x1039E, "Cn"), (0x1039F, "Po"), (0x103A0, "Lo"), (0x103C4, "Cn"), (0x103C8, "Lo"),
+    (0x103D0, "Po"), (0x103D1, "Nl"), (0x103D6, "Cn"), (0x10400, "Lu"), (0x10428, "Ll"),
+    (0x10450, "Lo"), (0x1049E, "Cn"), (0x104A0, "Nd"), (0x104AA, "Cn"), (0x104B0, "Lu"),
+    (0x104D4, "Cn"), (0x104D8, "Ll"), (0x104FC, "Cn"), (0x10500, "Lo"), (0x10528, "Cn"),
+    (0x10530, "Lo"), (0x10564, "Cn"), (0x1056F, "Po"), (0x10570, "Lu"), (0x1057B, "Cn"),
+    (0x1057C, "Lu"), (0x1058B, "Cn"), (0x1058C, "Lu"), (0x10593, "Cn"), (0x10594, "Lu"),
+    (0x10596, "Cn"), (0x10597, "Ll"), (0x105A2, "Cn"), (0x105A3, "Ll"), (0x105B2, "Cn"),
+    (0x105B3, "Ll"), (0x105BA, "Cn"), (0x105BB, "Ll"), (0x105BD, "Cn"), (0x10600, "Lo"),
+    (0x10737, "Cn"), (0x10740, "Lo"), (0x10756, "Cn"), (0x10760, "Lo"), (0x10768, "Cn"),
+    (0x10780, "Lm"), (0x10786, "Cn"), (0x10787, "Lm"), (0x107B1, "Cn"), (0x107B2, "Lm"),
+    (0x107BB, "Cn"), (0x10800, "Lo"), (0x10806, "Cn"), (0x10808, "Lo"), (0x10809, "Cn"),
+    (0x1080A, "Lo"), (0x10836, "Cn"), (0x10837, "Lo"), (0x10839, "Cn"), (0x1083C, "Lo"),
+    (0x1083D, "Cn"), (0x1083F, "Lo"), (0x10856, "Cn"), (0x10857, "Po"), (0x10858, "No"),
+    (0x10860, "Lo"), (0x10877, "So"), (0x10879, "No"), (0x10880, "Lo"), (0x1089F, "Cn"),
+    (0x108A7, "No"), (0x108B0, "Cn"), (0x108E0, "Lo"), (0x108F3, "Cn"), (0x108F4, "Lo"),
+    (0x108F6, "Cn"), (0x108FB, "No"), (0x10900, "Lo"), (0x10916, "No"), (0x1091C, "Cn"),
+    (0x1091F, "Po"), (0x10920, "Lo"), (0x1093A, "Cn"), (0x1093F, "Po"), (0x10940, "Cn"),
+    (0x10980, "Lo"), (0x109B8, "Cn"), (0x109BC, "No"), (0x109BE, "Lo"), (0x109C0, "No"),
+    (0x109D0, "Cn"), (0x109D2, "No"), (0x10A00, "Lo"), (0x10A01, "Mn"), (0x10A04, "Cn"),
+    (0x10A05, "Mn"), (0x10A07, "Cn"), (0x10A0C, "Mn"), (0x10A10, "Lo"), (0x10A14, "Cn"),
+    (0x10A15, "Lo"), (0x10A18, "Cn"), (0x10A19, "Lo"), (0x10A36, "Cn"), (0x10A38, "Mn"),
+    (0x10A3B, "Cn"), (0x10A3F, "Mn"), (0x10A40, "No"), (0x10A49, "Cn"), (0x10A50, "Po"),
+    (0x10A59, "Cn"), (0x10A60, "Lo"), (0x10A7D, "No"), (0x10A7F, "Po"), (0x10A80, "Lo"),
+    (0x10A9D, "No"), (0x10AA0, "Cn"), (0x10AC0, "Lo"), (0x10AC8, "So"), (0x10AC9, "Lo"),
+    (0x10AE5, "Mn"), (0x10AE7, "Cn"), (0x10AEB, "No"), (0x10AF0, "Po"), (0x10AF7, "Cn"),
+    (0x10B00, "Lo"), (0x10B36, "Cn"), (0x10B39, "Po"), (0x10B40, "Lo"), (0x10B56, "Cn"),
+    (0x10B58, "No"), (0x10B60, "Lo"), (0x10B73, "Cn"), (0x10B78, "No"), (0x10B80, "Lo"),
+    (0x10B92, "Cn"), (0x10B99, "Po"), (0x10B9D, "Cn"), (0x10BA9, "No"), (0x10BB0, "Cn"),
+    (0x10C00, "Lo"), (0x10C49, "Cn"), (0x10C80, "Lu"), (0x10CB3, "Cn"), (0x10CC0, "Ll"),
+    (0x10CF3, "Cn"), (0x10CFA, "No"), (0x10D00, "Lo"), (0x10D24, "Mn"), (0x10D28, "Cn"),
+    (0x10D30, "Nd"), (0x10D3A, "Cn"), (0x10E60, "No"), (0x10E7F, "Cn"), (0x10E80, "Lo"),
+    (0x10EAA, "Cn"), (0x10EAB, "Mn"), (0x10EAD, "Pd"), (0x10EAE, "Cn"), (0x10EB0, "Lo"),
+    (0x10EB2, "Cn"), (0x10F00, "Lo"), (0x10F1D, "No"), (0x10F27, "Lo"), (0x10F28, "Cn"),
+    (0x10F30, "Lo"), (0x10F46, "Mn"), (0x10F51, "No"), (0x10F55, "Po"), (0x10F5A, "Cn"),
+    (0x10F70, "Lo"), (0x10F82, "Mn"), (0x10F86, "Po"), (0x10F8A, "Cn"), (0x10FB0, "Lo"),
+    (0x10FC5, "No"), (0x10FCC, "Cn"), (0x10FE0, "Lo"), (0x10FF7, "Cn"), (0x11000, "Mc"),
+    (0x11001, "Mn"), (0x11002, "Mc"), (0x11003, "Lo"), (0x11038, "Mn"), (0x11047, "Po"),
+    (0x1104E, "Cn"), (0x11052, "No"), (0x11066, "Nd"), (0x11070, "Mn"), (0x11071, "Lo"),
+    (0x11073, "Mn"), (0x11075, "Lo"), (0x11076, "Cn"), (0x1107F, "Mn"), (0x11082, "Mc"),
+    (0x11083, "Lo"), (0x110B0, "Mc"), (0x110B3, "Mn"), (0x110B7, "Mc"), (0x110B9, "Mn"),
+    (0x110BB, "Po"), (0x110BD, "Cf"), (0x110BE, "Po"), (0x110C2, "Mn"), (0x110C3, "Cn"),
+    (0x110CD, "Cf"), (0x110CE, "Cn"), (0x110D0, "Lo"), (0x110E9, "Cn"), (0x110F0, "Nd"),
+    (0x110FA, "Cn"), (0x11100, "Mn"), (0x11103, "Lo"), (0x11127, "Mn"), (0x1112C, "Mc"),
+    (0x1112D, "Mn"), (0x11135, "Cn"), (0x11136, "Nd"), (0x11140, "Po"), (0x11144, "Lo"),
+    (0x11145, "Mc"), (0x11147, "Lo"), (0x11148, "Cn"), (0x11150, "Lo"), (0x11173, "Mn"),
+    (0x11174, "Po"), (0x11176, "Lo"), (0x11177, "Cn"), (0x11180, "Mn"), (0x11182, "Mc"),
+    (0x11183, "Lo"), (0x111B3, "Mc"), (0x111B6, "Mn"), (0x111BF, "Mc"), (0x111C1, "Lo"),
+    (0x111C5, "Po"), (0x111C9, "Mn"), (0x111CD, "Po"), (0x111CE, "Mc"), (0x111CF, "Mn"),
+    (0x111D0, "Nd"), (0x111DA, "Lo"), (0x111DB, "Po"), (0x111DC, "Lo"), (0x111DD, "Po"),
+    (0x111E0, "Cn"), (0x111E1, "No"), (0x111F5, "Cn"), (0x11200, "Lo"), (0x11212, "Cn"),
+    (0x11213, "Lo"), (0x1122C, "Mc"), (0x1122F, "Mn"), (0x11232, "Mc"), (0x11234, "Mn"),
+    (0x11235, "Mc"), (0x11236, "Mn"), (0x11238, "Po"), (0x1123E, "Mn"), (0x1123F, "Cn"),
+    (0x11280, "Lo"), (0x11287, "Cn"), (0x11288, "Lo"), (0x11289, "Cn"), (0x1128A, "Lo"),
+    (0x1128E, "Cn"), (0x1128F, "Lo"), (0x1129E, "Cn"), (0x1129F, "Lo"), (0x112A9, "Po"),
+    (0x112AA, "Cn"), (0x112B0, "Lo"), (0x112DF, "Mn"), (0x112E0, "Mc"), (0x112E3, "Mn"),
+    (0x112EB, "Cn"), (0x112F0, "Nd"), (0x112FA, "Cn"), (0x11300, "Mn"), (0x11302, "Mc"),
+    (0x11304, "Cn"), (0x11305, "Lo"), (0x1130D, "Cn"), (0x1130F, "Lo"), (0x11311, "Cn"),
+    (0x11313, "Lo"), (0x11329, "Cn"), (0x1132A, "Lo"), (0x11331, "Cn"), (0x11332, "Lo"),
+    (0x11334, "Cn"), (0x11335, "Lo"), (0x1133A, "Cn"), (0x1133B, "Mn"), (0x1133D, "Lo"),
+    (0x1133E, "Mc"), (0x11340, "Mn"), (0x11341, "Mc"), (0x11345, "Cn"), (0x11347, "Mc"),
+    (0x11349, "Cn"), (0x1134B, "Mc"), (0x1134E, "Cn"), (0x11350, "Lo"), (0x11351, "Cn"),
+    (0x11357, "Mc"), (0x11358, "Cn"), (0x1135D, "Lo"), (0x11362, "Mc"), (0x11364, "Cn"),
+    (0x11366, "Mn"), (0x1136D, "Cn"), (0x11370, "Mn"), (0x11375, "Cn"), (0x11400, "Lo"),
+    (0x11435, "Mc"), (0x11438, "Mn"), (0x11440, "Mc"), (0x11442, "Mn"), (0x11445, "Mc"),
+    (0x11446, "Mn"), (0x11447, "Lo"), (0x1144B, "Po"), (0x11450, "Nd"), (0x1145A, "Po"),
+    (0x1145C, "Cn"), (0x1145D, "Po"), (0x1145E, "Mn"), (0x1145F, "Lo"), (0x11462, "Cn"),
+    (0x11480, "Lo"), (0x114B0, "Mc"), (0x114B3, "Mn"), (0x114B9, "Mc"), (0x114BA, "Mn"),
+    (0x114BB, "Mc"), (0x114BF, "Mn"), (0x114C1, "Mc"), (0x114C2, "Mn"), (0x114C4, "Lo"),
+    (0x114C6, "Po"), (0x114C7, "Lo"), (0x114C8, "Cn"), (0x114D0, "Nd"), (0x114DA, "Cn"),
+    (0x11580, "Lo"), (0x115AF, "Mc"), (0x115B2, "Mn"), (0x115B6, "Cn"), (0x115B8, "Mc"),
+    (0x115BC, "Mn"), (0x115BE, "Mc"), (0x115BF, "Mn"), (0x115C1, "Po"), (0x115D8, "Lo"),
+    (0x115DC, "Mn"), (0x115DE, "Cn"), (0x11600, "Lo"), (0x11630, "Mc"), (0x11633, "Mn"),
+    (0x1163B, "Mc"), (0x1163D, "Mn"), (0x1163E, "Mc"), (0x1163F, "Mn"), (0x11641, "Po"),
+    (0x11644, "Lo"), (0x11645, "Cn"), (0x11650, "Nd"), (0x1165A, "Cn"), (0x11660, "Po"),
+    (0x1166D, "Cn"), (0x11680, "Lo"), (0x116AB, "Mn"), (0x116AC, "Mc"), (0x116AD, "Mn"),
+    (0x116AE, "Mc"), (0x116B0, "Mn"), (0x116B6, "Mc"), (0x116B7, "Mn"), (0x116B8, "Lo"),
+    (0x116B9, "Po"), (0x116BA, "Cn"), (0x116C0, "Nd"), (0x116CA, "Cn"), (0x11700, "Lo"),
+    (0x1171B, "Cn"), (0x1171D, "Mn"), (0x11720, "Mc"), (0x11722, "Mn"), (0x11726, "Mc"),
+    (0x11727, "Mn"), (0x1172C, "Cn"), (0x11730, "Nd"), (0x1173A, "No"), (0x1173C, "Po"),
+    (0x1173F, "So"), (0x11740, "Lo"), (0x11747, "Cn"), (0x11800, "Lo"), (0x1182C, "Mc"),
+    (0x1182F, "Mn"), (0x11838, "Mc"), (0x11839, "Mn"), (0x1183B, "Po"), (0x1183C, "Cn"),
+    (0x118A0, "Lu"), (0x118C0, "Ll"), (0x118E0, "Nd"), (0x118EA, "No"), (0x118F3, "Cn"),
+    (0x118FF, "Lo"), (0x11907, "Cn"), (0x11909, "Lo"), (0x1190A, "Cn"), (0x1190C, "Lo"),
+    (0x11914, "Cn"), (0x11915, "Lo"), (0x11917, "Cn"), (0x11918, "Lo"), (0x11930, "Mc"),
+    (0x11936, "Cn"), (0x11937, "Mc"), (0x11939, "Cn"), (0x1193B, "Mn"), (0x1193D, "Mc"),
+    (0x1193E, "Mn"), (0x1193F, "Lo"), (0x11940, "Mc"), (0x11941, "Lo"), (0x11942, "Mc"),
+    (0x11943, "Mn"), (0x11944, "Po"), (0x11947, "Cn"), (0x11950, "Nd"), (0x1195A, "Cn"),
+    (0x119A0, "Lo"), (0x119A8, "Cn"), (0x119AA, "Lo"), (0x119D1, "Mc"), (0x119D4, "Mn"),
+    (0x119D8, "Cn"), (0x119DA, "Mn"), (0x119DC, "Mc"), (0x119E0, "Mn"), (0x119E1, "Lo"),
+    (0x119E2, "Po"), (0x119E3, "Lo"), (0x119E4, "Mc"), (0x119E5, "Cn"), (0x11A00, "Lo"),
+    (0x11A01, "Mn"), (0x11A0B, "Lo"), (0x11A33, "Mn"), (0x11A39, "Mc"), (0x11A3A, "Lo"),
+    (0x11A3B, "Mn"), (0x11A3F, "Po"), (0x11A47, "Mn"), (0x11A48, "Cn"), (0x11A50, "Lo"),
+    (0x11A51, "Mn"), (0x11A57, "Mc"), (0x11A59, "Mn"), (0x11A5C, "Lo"), (0x11A8A, "Mn"),
+    (0x11A97, "Mc"), (0x11A98, "Mn"), (0x11A9A, "Po"), (0x11A9D, "Lo"), (0x11A9E, "Po"),
+    (0x11AA3, "Cn"), (0x11AB0, "Lo"), (0x11AF9, "Cn"), (0x11C00, "Lo"), (0x11C09, "Cn"),
+    (0x11C0A, "Lo"), (0x11C2F, "Mc"), (0x11C30, "Mn"), (0x11C37, "Cn"), (0x11C38, "Mn"),
+    (0x11C3E, "Mc"), (0x11C3F, "Mn"), (0x11C40, "Lo"), (0x11C41, "Po"), (0x11C46, "Cn"),
+    (0x11C50, "Nd"), (0x11C5A, "No"), (0x11C6D, "Cn"), (0x11C70, "Po"), (0x11C72, "Lo"),
+    (0x11C90, "Cn"), (0x11C92, "Mn"), (0x11CA8, "Cn"), (0x11CA9, "Mc"), (0x11CAA, "Mn"),
+    (0x11CB1, "Mc"), (0x11CB2, "Mn"), (0x11CB4, "Mc"), (0x11CB5, "Mn"), (0x11CB7, "Cn"),
+    (0x11D00, "Lo"), (0x11D07, "Cn"), (0x11D08, "Lo"), (0x11D0A, "Cn"), (0x11D0B, "Lo"),
+    (0x11D31, "Mn"), (0x11D37, "Cn"), (0x11D3A, "Mn"), (0x11D3B, "Cn"), (0x11D3C, "Mn"),
+    (0x11D3E, "Cn"), (0x11D3F, "Mn"), (0x11D46, "Lo"), (0x11D47, "Mn"), (0x11D48, "Cn"),
+    (0x11D50, "Nd"), (0x11D5A, "Cn"), (0x11D60, "Lo"), (0x11D66, "Cn"), (0x11D67, "Lo"),
+    (0x11D69, "Cn"), (0x11D6A, "Lo"), (0x11D8A, "Mc"), (0x11D8F, "Cn"), (0x11D90, "Mn"),
+    (0x11D92, "Cn"), (0x11D93, "Mc"), (0x11D95, "Mn"), (0x11D96, "Mc"), (0x11D97, "Mn"),
+    (0x11D98, "Lo"), (0x11D99, "Cn"), (0x11DA0, "Nd"), (0x11DAA, "Cn"), (0x11EE0, "Lo"),
+    (0x11EF3, "Mn"), (0x11EF5, "Mc"), (0x11EF7, "Po"), (0x11EF9, "Cn"), (0x11FB0, "Lo"),
+    (0x11FB1, "Cn"), (0x11FC0, "No"), (0x11FD5, "So"), (0x11FDD, "Sc"), (0x11FE1, "So"),
+    (0x11FF2, "Cn"), (0x11FFF, "Po"), (0x12000, "Lo"), (0x1239A, "Cn"), (0x12400, "Nl"),
+    (0x1246F, "Cn"), (0x12470, "Po"), (0x12475, "Cn"), (0x12480, "Lo"), (0x12544, "Cn"),
+    (0x12F90, "Lo"), (0x12FF1, "Po"), (0x12FF3, "Cn"), (0x13000, "Lo"), (0x1342F, "Cn"),
+    (0x13430, "Cf"), (0x13439, "Cn"), (0x14400, "Lo"), (0x14647, "Cn"), (0x16800, "Lo"),
+    (0x16A39, "Cn"), (0x16A40, "Lo"), (0x16A5F, "Cn"), (0x16A60, "Nd"), (0x16A6A, "Cn"),
+    (0x16A6E, "Po"), (0x16A70, "Lo"), (0x16ABF, "Cn"), (0x16AC0, "Nd"), (0x16ACA, "Cn"),
+    (0x16AD0, "Lo"), (0x16AEE, "Cn"), (0x16AF0, "Mn"), (0x16AF5, "Po"), (0x16AF6, "Cn"),
+    (0x16B00, "Lo"), (0x16B30, "Mn"), (0x16B37, "Po"), (0x16B3C, "So"), (0x16B40, "Lm"),
+    (0x16B44, "Po"), (0x16B45, "So"), (0x16B46, "Cn"), (0x16B50, "Nd"), (0x16B5A, "Cn"),
+    (0x16B5B, "No"), (0x16B62, "Cn"), (0x16B63, "Lo"), (0x16B78, "Cn"), (0x16B7D, "Lo"),
+    (0x16B90, "Cn"), (0x16E40, "Lu"), (0x16E60, "Ll"), (0x16E80, "No"), (0x16E97, "Po"),
+    (0x16E9B, "Cn"), (0x16F00, "Lo"), (0x16F4B, "Cn"), (0x16F4F, "Mn"), (0x16F50, "Lo"),
+    (0x16F51, "Mc"), (0x16F88, "Cn"), (0x16F8F, "Mn"), (0x16F93, "Lm"), (0x16FA0, "Cn"),
+    (0x16FE0, "Lm"), (0x16FE2, "Po"), (0x16FE3, "Lm"), (0x16FE4, "Mn"), (0x16FE5, "Cn"),
+    (0x16FF0, "Mc"), (0x16FF2, "Cn"), (0x17000, "Lo"), (0x187F8, "Cn"), (0x18800, "Lo"),
+    (0x18CD6, "Cn"), (0x18D00, "Lo"), (0x18D09, "Cn"), (0x1AFF0, "Lm"), (0x1AFF4, "Cn"),
+    (0x1AFF5, "Lm"), (0x1AFFC, "Cn"), (0x1AFFD, "Lm"), (0x1AFFF, "Cn"), (0x1B000, "Lo"),
+    (0x1B123, "Cn"), (0x1B150, "Lo"), (0x1B153, "Cn"), (0x1B164, "Lo"), (0x1B168, "Cn"),
+    (0x1B170, "Lo"), (0x1B2FC, "Cn"), (0x1BC00, "Lo"), (0x1BC6B, "Cn"), (0x1BC70, "Lo"),
+    (0x1BC7D, "Cn"), (0x1BC80, "Lo"), (0x1BC89, "Cn"), (0x1BC90, "Lo"), (0x1BC9A, "Cn"),
+    (0x1BC9C, "So"), (0x1BC9D, "Mn"), (0x1BC9F, "Po"), (0x1BCA0, "Cf"), (0x1BCA4, "Cn"),
+    (0x1CF00, "Mn"), (0x1CF2E, "Cn"), (0x1CF30, "Mn"), (0x1CF47, "Cn"), (0x1CF50, "So"),
+    (0x1CFC4, "Cn"), (0x1D000, "So"), (0x1D0F6, "Cn"), (0x1D100, "So"), (0x1D127, "Cn"),
+    (0x1D129, "So"), (0x1D165, "Mc"), (0x1D167, "Mn"), (0x1D16A, "So"), (0x1D16D, "Mc"),
+    (0x1D173, "Cf"), (0x1D17B, "Mn"), (0x1D183, "So"), (0x1D185, "Mn"), (0x1D18C, "So"),
+    (0x1D1AA, "Mn"), (0x1D1AE, "So"), (0x1D1EB, "Cn"), (0x1D200, "So"), (0x1D242, "Mn"),
+    (0x1D245, "So"), (0x1D246, "Cn"), (0x1D2E0, "No"), (0x1D2F4, "Cn"), (0x1D300, "So"),
+    (0x1D357, "Cn"), (0x1D360, "No"), (0x1D379, "Cn"), (0x1D400, "Lu"), (0x1D41A, "Ll"),
+    (0x1D434, "Lu"), (0x1D44E, "Ll"), (0x1D455, "Cn"), (0x1D456, "Ll"), (0x1D468, "Lu"),
+    (0x1D482, "Ll"), (0x1D49C, "Lu"), (0x1D49D, "Cn"), (0x1D49E, "Lu"), (0x1D4A0, "Cn"),
+    (0x1D4A2, "Lu"), (0x1D4A3, "Cn"), (0x1D4A5, "Lu"), (0x1D4A7, "Cn"), (0x1D4A9, "Lu"),
+    (0x1D4AD, "Cn"), (0x1D4AE, "Lu"), (0x1D4B6, "Ll"), (0x1D4BA, "Cn"), (0x1D4BB, "Ll"),
+    (0x1D4BC, "Cn"), (0x1D4BD, "Ll"), (0x1D4C4, "Cn"), (0x1D4C5, "Ll"), (0x1D4D0, "Lu"),
+    (0x1D4EA, "Ll"), (0x1D504, "Lu"), (0x1D506, "Cn"), (0x1D507, "Lu"), (0x1D50B, "Cn"),
+    (0x1D50D, "Lu"), (0x1D515, "Cn"), (0x1D516, "Lu"), (0x1D51D, "Cn"), (0x1D51E, "Ll"),
+    (0x1D538, "Lu"), (0x1D53A, "Cn"), (0x1D53B, "Lu"), (0x1D53F, "Cn"), (0x1D540, "Lu"),
+    (0x1D545, "Cn"), (0x1D546, "Lu"), (0x1D547, "Cn"), (0x1D54A, "Lu"), (0x1D551, "Cn"),
+    (0x1D552, "Ll"), (0x1D56C, "Lu"), (0x1D586, "Ll"), (0x1D5A0, "Lu"), (0x1D5BA, "Ll"),
+    (0x1D5D4, "Lu"), (0x1D5EE, "Ll"), (0x1D608, "Lu"), (0x1D622, "Ll"), (0x1D63C, "Lu"),
+    (0x1D656, "Ll"), (0x1D670, "Lu"), (0x1D68A, "Ll"), (0x1D6A6, "Cn"), (0x1D6A8, "Lu"),
+    (0x1D6C1, "Sm"), (0x1D6C2, "Ll"), (0x1D6DB, "Sm"), (0x1D6DC, "Ll"), (0x1D6E2, "Lu"),
+    (0x1D6FB, "Sm"), (0x1D6FC, "Ll"), (0x1D715, "Sm"), (0x1D716, "Ll"), (0x1D71C, "Lu"),
+    (0x1D735, "Sm"), (0x1D736, "Ll"), (0x1D74F, "Sm"), (0x1D750, "Ll"), (0x1D756, "Lu"),
+    (0x1D76F, "Sm"), (0x1D770, "Ll"), (0x1D789, "Sm"), (0x1D78A, "Ll"), (0x1D790, "Lu"),
+    (0x1D7A9, "Sm"), (0x1D7AA, "Ll"), (0x1D7C3, "Sm"), (0x1D7C4, "Ll"), (0x1D7CA, "Lu"),
+    (0x1D7CB, "Ll"), (0x1D7CC, "Cn"), (0x1D7CE, "Nd"), (0x1D800, "So"), (0x1DA00, "Mn"),
+    (0x1DA37, "So"), (0x1DA3B, "Mn"), (0x1DA6D, "So"), (0x1DA75, "Mn"), (0x1DA76, "So"),
+    (0x1DA84, "Mn"), (0x1DA85, "So"), (0x1DA87, "Po"), (0x1DA8C, "Cn"), (0x1DA9B, "Mn"),
+    (0x1DAA0, "Cn"), (0x1DAA1, "Mn"), (0x1DAB0, "Cn"), (0x1DF00, "Ll"), (0x1DF0A, "Lo"),
+    (0x1DF0B, "Ll"), (0x1DF1F, "Cn"), (0x1E000, "Mn"), (0x1E007, "Cn"), (0x1E008, "Mn"),
+    (0x1E019, "Cn"), (0x1E01B, "Mn"), (0x1E022, "Cn"), (0x1E023, "Mn"), (0x1E025, "Cn"),
+    (0x1E026, "Mn"), (0x1E02B, "Cn"), (0x1E100, "Lo"), (0x1E12D, "Cn"), (0x1E130, "Mn"),
+    (0x1E137, "Lm"), (0x1E13E, "Cn"), (0x1E140, "Nd"), (0x1E14A, "Cn"), (0x1E14E, "Lo"),
+    (0x1E14F, "So"), (0x1E150, "Cn"), (0x1E290, "Lo"), (0x1E2AE, "Mn"), (0x1E2AF, "Cn"),
+    (0x1E2C0, "Lo"), (0x1E2EC, "Mn"), (0x1E2F0, "Nd"), (0x1E2FA, "Cn"), (0x1E2FF, "Sc"),
+    (0x1E300, "Cn"), (0x1E7E0, "Lo"), (0x1E7E7, "Cn"), (0x1E7E8, "Lo"), (0x1E7EC, "Cn"),
+    (0x1E7ED, "Lo"), (0x1E7EF, "Cn"), (0x1E7F0, "Lo"), (0x1E7FF, "Cn"), (0x1E800, "Lo"),
+    (0x1E8C5, "Cn"), (0x1E8C7, "No"), (0x1E8D0, "Mn"), (0x1E8D7, "Cn"), (0x1E900, "Lu"),
+    (0x1E922, "Ll"), (0x1E944, "Mn"), (0x1E94B, "Lm"), (0x1E94C, "Cn"), (0x1E950, "Nd"),
+    (0x1E95A, "Cn"), (0x1E95E, "Po"), (0x1E960, "Cn"), (0x1EC71, "No"), (0x1ECAC, "So"),
+    (0x1ECAD, "No"), (0x1ECB0, "Sc"), (0x1ECB1, "No"), (0x1ECB5, "Cn"), (0x1ED01, "No"),
+    (0x1ED2E, "So"), (0x1ED2F, "No"), (0x1ED3E, "Cn"), (0x1EE00, "Lo"), (0x1EE04, "Cn"),
+    (0x1EE05, "Lo"), (0x1EE20, "Cn"), (0x1EE21, "Lo"), (0x1EE23, "Cn"), (0x1EE24, "Lo"),
+    (0x1EE25, "Cn"), (0x1EE27, "Lo"), (0x1EE28, "Cn"), (0x1EE29, "Lo"), (0x1EE33, "Cn"),
+    (0x1EE34, "Lo"), (0x1EE38, "Cn"), (0x1EE39, "Lo"), (0x1EE3A, "Cn"), (0x1EE3B, "Lo"),
+    (0x1EE3C, "Cn"), (0x1EE42, "Lo"), (0x1EE43, "Cn"), (0x1EE47, "Lo"), (0x1EE48, "Cn"),
+    (0x1EE49, "Lo"), (0x1EE4A, "Cn"), (0x1EE4B, "Lo"), (0x1EE4C, "Cn"), (0x1EE4D, "Lo"),
+    (0x1EE50, "Cn"), (0x1EE51, "Lo"), (0x1EE53, "Cn"), (0x1EE54, "Lo"), (0x1EE55, "Cn"),
+    (0x1EE57, "Lo"), (0x1EE58, "Cn"), (0x1EE59, "Lo"), (0x1EE5A, "Cn"), (0x1EE5B, "Lo"),
+    (0x1EE5C, "Cn"), (0x1EE5D, "Lo"), (0x1EE5E, "Cn"), (0x1EE5F, "Lo"), (0x1EE60, "Cn"),
+    (0x1EE61, "Lo"), (0x1EE63, "Cn"), (0x1EE64, "Lo"), (0x1EE65, "Cn"), (0x1EE67, "Lo"),
+    (0x1EE6B, "Cn"), (0x1EE6C, "Lo"), (0x1EE73, "Cn"), (0x1EE74, "Lo"), (0x1EE78, "Cn"),
+    (0x1EE79, "Lo"), (0x1EE7D, "Cn"), (0x1EE7E, "Lo"), (0x1EE7F, "Cn"), (0x1EE80, "Lo"),
+    (0x1EE8A, "Cn"), (0x1EE8B, "Lo"), (0x1EE9C, "Cn"), (0x1EEA1, "Lo"), (0x1EEA4, "Cn"),
+    (0x1EEA5, "Lo"), (0x1EEAA, "Cn"), (0x1EEAB, "Lo"), (0x1EEBC, "Cn"), (0x1EEF0, "Sm"),
+    (0x1EEF2, "Cn"), (0x1F000, "So"), (0x1F02C, "Cn"), (0x1F030, "So"), (0x1F094, "Cn"),
+    (0x1F0A0, "So"), (0x1F0AF, "Cn"), (0x1F0B1, "So"), (0x1F0C0, "Cn"), (0x1F0C1, "So"),
+    (0x1F0D0, "Cn"), (0x1F0D1, "So"), (0x1F0F6, "Cn"), (0x1F100, "No"), (0x1F10D, "So"),
+    (0x1F1AE, "Cn"), (0x1F1E6, "So"), (0x1F203, "Cn"), (0x1F210, "So"), (0x1F23C, "Cn"),
+    (0x1F240, "So"), (0x1F249, "Cn"), (0x1F250, "So"), (0x1F252, "Cn"), (0x1F260, "So"),
+    (0x1F266, "Cn"), (0x1F300, "So"), (0x1F3FB, "Sk"), (0x1F400, "So"), (0x1F6D8, "Cn"),
+    (0x1F6DD, "So"), (0x1F6ED, "Cn"), (0x1F6F0, "So"), (0x1F6FD, "Cn"), (0x1F700, "So"),
+    (0x1F774, "Cn"), (0x1F780, "So"), (0x1F7D9, "Cn"), (0x1F7E0, "So"), (0x1F7EC, "Cn"),
+    (0x1F7F0, "So"), (0x1F7F1, "Cn"), (0x1F800, "So"), (0x1F80C, "Cn"), (0x1F810, "So"),
+    (0x1F848, "Cn"), (0x1F850, "So"), (0x1F85A, "Cn"), (0x1F860, "So"), (0x1F888, "Cn"),
+    (0x1F890, "So"), (0x1F8AE, "Cn"), (0x1F8B0, "So"), (0x1F8B2, "Cn"), (0x1F900, "So"),
+    (0x1FA54, "Cn"), (0x1FA60, "So"), (0x1FA6E, "Cn"), (0x1FA70, "So"), (0x1FA75, "Cn"),
+    (0x1FA78, "So"), (0x1FA7D, "Cn"), (0x1FA80, "So"), (0x1FA87, "Cn"), (0x1FA90, "So"),
+    (0x1FAAD, "Cn"), (0x1FAB0, "So"), (0x1FABB, "Cn"), (0x1FAC0, "So"), (0x1FAC6, "Cn"),
+    (0x1FAD0, "So"), (0x1FADA, "Cn"), (0x1FAE0, "So"), (0x1FAE8, "Cn"), (0x1FAF0, "So"),
+    (0x1FAF7, "Cn"), (0x1FB00, "So"), (0x1FB93, "Cn"), (0x1FB94, "So"), (0x1FBCB, "Cn"),
+    (0x1FBF0, "Nd"), (0x1FBFA, "Cn"), (0x20000, "Lo"), (0x2A6E0, "Cn"), (0x2A700, "Lo"),
+    (0x2B739, "Cn"), (0x2B740, "Lo"), (0x2B81E, "Cn"), (0x2B820, "Lo"), (0x2CEA2, "Cn"),
+    (0x2CEB0, "Lo"), (0x2EBE1, "Cn"), (0x2F800, "Lo"), (0x2FA1E, "Cn"), (0x30000, "Lo"),
+    (0x3134B, "Cn"), (0xE0001, "Cf"), (0xE0002, "Cn"), (0xE0020, "Cf"), (0xE0080, "Cn"),
+    (0xE0100, "Mn"), (0xE01F0, "Cn"), (0xF0000, "Co"), (0xFFFFE, "Cn"), (0x100000, "Co"),
+    (0x10FFFE, "Cn"),
+];
+
+
+/// Ranges of CJK unified ideographs, which are named for their code points, e.g.
+/// `CJK UNIFIED IDEOGRAPH-4E00`.
+pub const CJK_UNIFIED_IDEOGRAPHS: &[(u32, u32)] = &[
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0x20000, 0x2A6DF),
+    (0x2A700, 0x2B738),
+    (0x2B740, 0x2B81D),
+    (0x2B820, 0x2CEA1),
+    (0x2CEB0, 0x2EBE0),
+    (0x30000, 0x3134A),
+];
+
+
+/// Names of the characters in a selection of common blocks, sorted by code point.
+pub const NAMES: &[(u32, &str)] = &[
+    (0x0020, "SPACE"),
+    (0x0021, "EXCLAMATION MARK"),
+    (0x0022, "QUOTATION MARK"),
+    (0x0023, "NUMBER SIGN"),
+    (0x0024, "DOLLAR SIGN"),
+    (0x0025, "PERCENT SIGN"),
+    (0x0026, "AMPERSAND"),
+    (0x0027, "APOSTROPHE"),
+    (0x0028, "LEFT PARENTHESIS"),
+    (0x0029, "RIGHT PARENTHESIS"),
+    (0x002A, "ASTERISK"),
+    (0x002B, "PLUS SIGN"),
+    (0x002C, "COMMA"),
+    (0x002D, "HYPHEN-MINUS"),
+    (0x002E, "FULL STOP"),
+    (0x002F, "SOLIDUS"),
+    (0x0030, "DIGIT ZERO"),
+    (0x0031, "DIGIT ONE"),
+    (0x0032, "DIGIT TWO"),
+    (0x0033, "DIGIT THREE"),
+    (0x0034, "DIGIT FOUR"),
+    (0x0035, "DIGIT FIVE"),
+    (0x0036, "DIGIT SIX"),
+    (0x0037, "DIGIT SEVEN"),
+    (0x0038, "DIGIT EIGHT"),
+    (0x0039, "DIGIT NINE"),
+    (0x003A, "COLON"),
+    (0x003B, "SEMICOLON"),
+    (0x003C, "LESS-THAN SIGN"),
+    (0x003D, "EQUALS SIGN"),
+    (0x003E, "GREATER-THAN SIGN"),
+    (0x003F, "QUESTION MARK"),
+    (0x0040, "COMMERCIAL AT"),
+    (0x0041, "LATIN CAPITAL LETTER A"),
+    (0x0042, "LATIN CAPITAL LETTER B"),
+    (0x0043, "LATIN CAPITAL LETTER C"),
+    (0x0044, "LATIN CAPITAL LETTER D"),
+    (0x0045, "LATIN CAPITAL LETTER E"),
+    (0x0046, "LATIN CAPITAL LETTER F"),
+    (0x0047, "LATIN CAPITAL LETTER G"),
+    (0x0048, "LATIN CAPITAL LETTER H"),
+    (0x0049, "LATIN CAPITAL LETTER I"),
+    (0x004A, "LATIN CAPITAL LETTER J"),
+    (0x004B, "LATIN CAPITAL LETTER K"),
+    (0x004C, "LATIN CAPITAL LETTER L"),
+    (0x004D, "LATIN CAPITAL LETTER M"),
+    (0x004E, "LATIN CAPITAL LETTER N"),
+    (0x004F, "LATIN CAPITAL LETTER O"),
+    (0x0050, "LATIN CAPITAL LETTER P"),
+    (0x0051, "LATIN CAPITAL LETTER Q"),
+    (0x0052, "LATIN CAPITAL LETTER R"),
+    (0x0053, "LATIN CAPITAL LETTER S"),
+    (0x0054, "LATIN CAPITAL LETTER T"),
+    (0x0055, "LATIN CAPITAL LETTER U"),
+    (0x0056, "LATIN CAPITAL LETTER V"),
+    (0x0057, "LATIN CAPITAL LETTER W"),
+    (0x0058, "LATIN CAPITAL LETTER X"),
+    (0x0059, "LATIN CAPITAL LETTER Y"),
+    (0x005A, "LATIN CAPITAL LETTER Z"),
+    (0x005B, "LEFT SQUARE BRACKET"),
+    (0x005C, "REVERSE SOLIDUS"),
+    (0x005D, "RIGHT SQUARE BRACKET"),
+    (0x005E, "CIRCUMFLEX ACCENT"),
+    (0x005F, "LOW LINE"),
+    (0x0060, "GRAVE ACCENT"),
+    (0x0061, "LATIN SMALL LETTER A"),
+    (0x0062, "LATIN SMALL LETTER B"),
+    (0x0063, "LATIN SMALL LETTER C"),
+    (0x0064, "LATIN SMALL LETTER D"),
+    (0x0065, "LATIN SMALL LETTER E"),
+    (0x0066, "LATIN SMALL LETTER F"),
+    (0x0067, "LATIN SMALL LETTER G"),
+    (0x0068, "LATIN SMALL LETTER H"),
+    (0x0069, "LATIN SMALL LETTER I"),
+    (0x006A, "LATIN SMALL LETTER J"),
+    (0x006B, "LATIN SMALL LETTER K"),
+    (0x006C, "LATIN SMALL LETTER L"),
+    (0x006D, "LATIN SMALL LETTER M"),
+    (0x006E, "LATIN SMALL LETTER N"),
+    (0x006F, "LATIN SMALL LETTER O"),
+    (0x0070, "LATIN SMALL LETTER P"),
+    (0x0071, "LATIN SMALL LETTER Q"),
+    (0x0072, "LATIN SMALL LETTER R"),
+    (0x0073, "LATIN SMALL LETTER S"),
+    (0x0074, "LATIN SMALL LETTER T"),
+    (0x0075, "LATIN SMALL LETTER U"),
+    (0x0076, "LATIN SMALL LETTER V"),
+    (0x0077, "LATIN SMALL LETTER W"),
+    (0x0078, "LATIN SMALL LETTER X"),
+    (0x0079, "LATIN SMALL LETTER Y"),
+    (0x007A, "LATIN SMALL LETTER Z"),
+    (0x007B, "LEFT CURLY BRACKET"),
+    (0x007C, "VERTICAL LINE"),
+    (0x007D, "RIGHT CURLY BRACKET"),
+    (0x007E, "TILDE"),
+    (0x00A0, "NO-BREAK SPACE"),
+    (0x00A1, "INVERTED EXCLAMATION MARK"),
+    (0x00A2, "CENT SIGN"),
+    (0x00A3, "POUND SIGN"),
+    (0x00A4, "CURRENCY SIGN"),
+    (0x00A5, "YEN SIGN"),
+    (0x00A6, "BROKEN BAR"),
+    (0x00A7, "SECTION SIGN"),
+    (0x00A8, "DIAERESIS"),
+    (0x00A9, "COPYRIGHT SIGN"),
+    (0x00AA, "FEMININE ORDINAL INDICATOR"),
+    (0x00AB, "LEFT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    (0x00AC, "NOT SIGN"),
+    (0x00AD, "SOFT HYPHEN"),
+    (0x00AE, "REGISTERED SIGN"),
+    (0x00AF, "MACRON"),
+    (0x00B0, "DEGREE SIGN"),
+    (0x00B1, "PLUS-MINUS SIGN"),
+    (0x00B2, "SUPERSCRIPT TWO"),
+    (0x00B3, "SUPERSCRIPT THREE"),
+    (0x00B4, "ACUTE ACCENT"),
+    (0x00B5, "MICRO SIGN"),
+    (0x00B6, "PILCROW SIGN"),
+    (0x00B7, "MIDDLE DOT"),
+    (0x00B8, "CEDILLA"),
+    (0x00B9, "SUPERSCRIPT ONE"),
+    (0x00BA, "MASCULINE ORDINAL INDICATOR"),
+    (0x00BB, "RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    (0x00BC, "VULGAR FRACTION ONE QUARTER"),
+    (0x00BD, "VULGAR FRACTION ONE HALF"),
+    (0x00BE, "VULGAR FRACTION THREE QUARTERS"),
+    (0x00BF, "INVERTED QUESTION MARK"),
+    (0x00C0, "LATIN CAPITAL LETTER A WITH GRAVE"),
+    (0x00C1, "LATIN CAPITAL LETTER A WITH ACUTE"),
+    (0x00C2, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX"),
+    (0x00C3, "LATIN CAPITAL LETTER A WITH TILDE"),
+    (0x00C4, "LATIN CAPITAL LETTER A WITH DIAERESIS"),
+    (0x00C5, "LATIN CAPITAL LETTER A WITH RING ABOVE"),
+    (0x00C6, "LATIN CAPITAL LETTER AE"),
+    (0x00C7, "LATIN CAPITAL LETTER C WITH CEDILLA"),
+    (0x00C8, "LATIN CAPITAL LETTER E WITH GRAVE"),
+    (0x00C9, "LATIN CAPITAL LETTER E WITH ACUTE"),
+    (0x00CA, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX"),
+    (0x00CB, "LATIN CAPITAL LETTER E WITH DIAERESIS"),
+    (0x00CC, "LATIN CAPITAL LETTER I WITH GRAVE"),
+    (0x00CD, "LATIN CAPITAL LETTER I WITH ACUTE"),
+    (0x00CE, "LATIN CAPITAL LETTER I WITH CIRCUMFLEX"),
+    (0x00CF, "LATIN CAPITAL LETTER I WITH DIAERESIS"),
+    (0x00D0, "LATIN CAPITAL LETTER ETH"),
+    (0x00D1, "LATIN CAPITAL LETTER N WITH TILDE"),
+    (0x00D2, "LATIN CAPITAL LETTER O WITH GRAVE"),
+    (0x00D3, "LATIN CAPITAL LETTER O WITH ACUTE"),
+    (0x00D4, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX"),
+    (0x00D5, "LATIN CAPITAL LETTER O WITH TILDE"),
+    (0x00D6, "LATIN CAPITAL LETTER O WITH DIAERESIS"),
+    (0x00D7, "MULTIPLICATION SIGN"),
+    (0x00D8, "LATIN CAPITAL LETTER O WITH STROKE"),
+    (0x00D9, "LATIN CAPITAL LETTER U WITH GRAVE"),
+    (0x00DA, "LATIN CAPITAL LETTER U WITH ACUTE"),
+    (0x00DB, "LATIN CAPITAL LETTER U WITH CIRCUMFLEX"),
+    (0x00DC, "LATIN CAPITAL LETTER U WITH DIAERESIS"),
+    (0x00DD, "LATIN CAPITAL LETTER Y WITH ACUTE"),
+    (0x00DE, "LATIN CAPITAL LETTER THORN"),
+    (0x00DF, "LATIN SMALL LETTER SHARP S"),
+    (0x00E0, "LATIN SMALL LETTER A WITH GRAVE"),
+    (0x00E1, "LATIN SMALL LETTER A WITH ACUTE"),
+    (0x00E2, "LATIN SMALL LETTER A WITH CIRCUMFLEX"),
+    (0x00E3, "LATIN SMALL LETTER A WITH TILDE"),
+    (0x00E4, "LATIN SMALL LETTER A WITH DIAERESIS"),
+    (0x00E5, "LATIN SMALL LETTER A WITH RING ABOVE"),
+    (0x00E6, "LATIN SMALL LETTER AE"),
+    (0x00E7, "LATIN SMALL LETTER C WITH CEDILLA"),
+    (0x00E8, "LATIN SMALL LETTER E WITH GRAVE"),
+    (0x00E9, "LATIN SMALL LETTER E WITH ACUTE"),
+    (0x00EA, "LATIN SMALL LETTER E WITH CIRCUMFLEX"),
+    (0x00EB, "LATIN SMALL LETTER E WITH DIAERESIS"),
+    (0x00EC, "LATIN SMALL LETTER I WITH GRAVE"),
+    (0x00ED, "LATIN SMALL LETTER I WITH ACUTE"),
+    (0x00EE, "LATIN SMALL LETTER I WITH CIRCUMFLEX"),
+    (0x00EF, "LATIN SMALL LETTER I WITH DIAERESIS"),
+    (0x00F0, "LATIN SMALL LETTER ETH"),
+    (0x00F1, "LATIN SMALL LETTER N WITH TILDE"),
+    (0x00F2, "LATIN SMALL LETTER O WITH GRAVE"),
+    (0x00F3, "LATIN SMALL LETTER O WITH ACUTE"),
+    (0x00F4, "LATIN SMALL LETTER O WITH CIRCUMFLEX"),
+    (0x00F5, "LATIN SMALL LETTER O WITH TILDE"),
+    (0x00F6, "LATIN SMALL LETTER O WITH DIAERESIS"),
+    (0x00F7, "DIVISION SIGN"),
+    (0x00F8, "LATIN SMALL LETTER O WITH STROKE"),
+    (0x00F9, "LATIN SMALL LETTER U WITH GRAVE"),
+    (0x00FA, "LATIN SMALL LETTER U WITH ACUTE"),
+    (0x00FB, "LATIN SMALL LETTER U WITH CIRCUMFLEX"),
+    (0x00FC, "LATIN SMALL LETTER U WITH DIAERESIS"),
+    (0x00FD, "LATIN SMALL LETTER Y WITH ACUTE"),
+    (0x00FE, "LATIN SMALL LETTER THORN"),
+    (0x00FF, "LATIN SMALL LETTER Y WITH DIAERESIS"),
+    (0x0100, "LATIN CAPITAL LETTER A WITH MACRON"),
+    (0x0101, "LATIN SMALL LETTER A WITH MACRON"),
+    (0x0102, "LATIN CAPITAL LETTER A WITH BREVE"),
+    (0x0103, "LATIN SMALL LETTER A WITH BREVE"),
+    (0x0104, "LATIN CAPITAL LETTER A WITH OGONEK"),
+    (0x0105, "LATIN SMALL LETTER A WITH OGONEK"),
+    (0x0106, "LATIN CAPITAL LETTER C WITH ACUTE"),
+    (0x0107, "LATIN SMALL LETTER C WITH ACUTE"),
+    (0x0108, "LATIN CAPITAL LETTER C WITH CIRCUMFLEX"),
+    (0x0109, "LATIN SMALL LETTER C WITH CIRCUMFLEX"),
+    (0x010A, "LATIN CAPITAL LETTER C WITH DOT ABOVE"),
+    (0x010B, "LATIN SMALL LETTER C WITH DOT ABOVE"),
+    (0x010C, "LATIN CAPITAL LETTER C WITH CARON"),
+    (0x010D, "LATIN SMALL LETTER C WITH CARON"),
+    (0x010E, "LATIN CAPITAL LETTER D WITH CARON"),
+    (0x010F, "LATIN SMALL LETTER D WITH CARON"),
+    (0x0110, "LATIN CAPITAL LETTER D WITH STROKE"),
+    (0x0111, "LATIN SMALL LETTER D WITH STROKE"),
+    (0x0112, "LATIN CAPITAL LETTER E WITH MACRON"),
+    (0x0113, "LATIN SMALL LETTER E WITH MACRON"),
+    (0x0114, "LATIN CAPITAL LETTER E WITH BREVE"),
+    (0x0115, "LATIN SMALL LETTER E WITH BREVE"),
+    (0x0116, "LATIN CAPITAL LETTER E WITH DOT ABOVE"),
+    (0x0117, "LATIN SMALL LETTER E WITH DOT ABOVE"),
+    (0x0118, "LATIN CAPITAL LETTER E WITH OGONEK"),
+    (0x0119, "LATIN SMALL LETTER E WITH OGONEK"),
+    (0x011A, "LATIN CAPITAL LETTER E WITH CARON"),
+    (0x011B, "LATIN SMALL LETTER E WITH CARON"),
+    (0x011C, "LATIN CAPITAL LETTER G WITH CIRCUMFLEX"),
+    (0x011D, "LATIN SMALL LETTER G WITH CIRCUMFLEX"),
+    (0x011E, "LATIN CAPITAL LETTER G WITH BREVE"),
+    (0x011F, "LATIN SMALL LETTER G WITH BREVE"),
+    (0x0120, "LATIN CAPITAL LETTER G WITH DOT ABOVE"),
+    (0x0121, "LATIN SMALL LETTER G WITH DOT ABOVE"),
+    (0x0122, "LATIN CAPITAL LETTER G WITH CEDILLA"),
+    (0x0123, "LATIN SMALL LETTER G WITH CEDILLA"),
+    (0x0124, "LATIN CAPITAL LETTER H WITH CIRCUMFLEX"),
+    (0x0125, "LATIN SMALL LETTER H WITH CIRCUMFLEX"),
+    (0x0126, "LATIN CAPITAL LETTER H WITH STROKE"),
+    (0x0127, "LATIN SMALL LETTER H WITH STROKE"),
+    (0x0128, "LATIN CAPITAL LETTER I WITH TILDE"),
+    (0x0129, "LATIN SMALL LETTER I WITH TILDE"),
+    (0x012A, "LATIN CAPITAL LETTER I WITH MACRON"),
+    (0x012B, "LATIN SMALL LETTER I WITH MACRON"),
+    (0x012C, "LATIN CAPITAL LETTER I WITH BREVE"),
+    (0x012D, "LATIN SMALL LETTER I WITH BREVE"),
+    (0x012E, "LATIN CAPITAL LETTER I WITH OGONEK"),
+    (0x012F, "LATIN SMALL LETTER I WITH OGONEK"),
+    (0x0130, "LATIN CAPITAL LETTER I WITH DOT ABOVE"),
+    (0x0131, "LATIN SMALL LETTER DOTLESS I"),
+    (0x0132, "LATIN CAPITAL LIGATURE IJ"),
+    (0x0133, "LATIN SMALL LIGATURE IJ"),
+    (0x0134, "LATIN CAPITAL LETTER J WITH CIRCUMFLEX"),
+    (0x0135, "LATIN SMALL LETTER J WITH CIRCUMFLEX"),
+    (0x0136, "LATIN CAPITAL LETTER K WITH CEDILLA"),
+    (0x0137, "LATIN SMALL LETTER K WITH CEDILLA"),
+    (0x0138, "LATIN SMALL LETTER KRA"),
+    (0x0139, "LATIN CAPITAL LETTER L WITH ACUTE"),
+    (0x013A, "LATIN SMALL LETTER L WITH ACUTE"),
+    (0x013B, "LATIN CAPITAL LETTER L WITH CEDILLA"),
+    (0x013C, "LATIN SMALL LETTER L WITH CEDILLA"),
+    (0x013D, "LATIN CAPITAL LETTER L WITH CARON"),
+    (0x013E, "LATIN SMALL LETTER L WITH CARON"),
+    (0x013F, "LATIN CAPITAL LETTER L WITH MIDDLE DOT"),
+    (0x0140, "LATIN SMALL LETTER L WITH MIDDLE DOT"),
+    (0x0141, "LATIN CAPITAL LETTER L WITH STROKE"),
+    (0x0142, "LATIN SMALL LETTER L WITH STROKE"),
+    (0x0143, "LATIN CAPITAL LETTER N WITH ACUTE"),
+    (0x0144, "LATIN SMALL LETTER N WITH ACUTE"),
+    (0x0145, "LATIN CAPITAL LETTER N WITH CEDILLA"),
+    (0x0146, "LATIN SMALL LETTER N WITH CEDILLA"),
+    (0x0147, "LATIN CAPITAL LETTER N WITH CARON"),
+    (0x0148, "LATIN SMALL LETTER N WITH CARON"),
+    (0x0149, "LATIN SMALL LETTER N PRECEDED BY APOSTROPHE"),
+    (0x014A, "LATIN CAPITAL LETTER ENG"),
+    (0x014B, "LATIN SMALL LETTER ENG"),
+    (0x014C, "LATIN CAPITAL LETTER O WITH MACRON"),
+    (0x014D, "LATIN SMALL LETTER O WITH MACRON"),
+    (0x014E, "LATIN CAPITAL LETTER O WITH BREVE"),
+    (0x014F, "LATIN SMALL LETTER O WITH BREVE"),
+    (0x0150, "LATIN CAPITAL LETTER O WITH DOUBLE ACUTE"),
+    (0x0151, "LATIN SMALL LETTER O WITH DOUBLE ACUTE"),
+    (0x0152, "LATIN CAPITAL LIGATURE OE"),
+    (0x0153, "LATIN SMALL LIGATURE OE"),
+    (0x0154, "LATIN CAPITAL LETTER R WITH ACUTE"),
+    (0x0155, "LATIN SMALL LETTER R WITH ACUTE"),
+    (0x0156, "LATIN CAPITAL LETTER R WITH CEDILLA"),
+    (0x0157, "LATIN SMALL LETTER R WITH CEDILLA"),
+    (0x0158, "LATIN CAPITAL LETTER R WITH CARON"),
+    (0x0159, "LATIN SMALL LETTER R WITH CARON"),
+    (0x015A, "LATIN CAPITAL LETTER S WITH ACUTE"),
+    (0x015B, "LATIN SMALL LETTER S WITH ACUTE"),
+    (0x015C, "LATIN CAPITAL LETTER S WITH CIRCUMFLEX"),
+    (0x015D, "LATIN SMALL LETTER S WITH CIRCUMFLEX"),
+    (0x015E, "LATIN CAPITAL LETTER S WITH CEDILLA"),
+    (0x015F, "LATIN SMALL LETTER S WITH CEDILLA"),
+    (0x0160, "LATIN CAPITAL LETTER S WITH CARON"),
+    (0x0161, "LATIN SMALL LETTER S WITH CARON"),
+    (0x0162, "LATIN CAPITAL LETTER T WITH CEDILLA"),
+    (0x0163, "LATIN SMALL LETTER T WITH CEDILLA"),
+    (0x0164, "LATIN CAPITAL LETTER T WITH CARON"),
+    (0x0165, "LATIN SMALL LETTER T WITH CARON"),
+    (0x0166, "LATIN CAPITAL LETTER T WITH STROKE"),
+    (0x0167, "LATIN SMALL LETTER T WITH STROKE"),
+    (0x0168, "LATIN CAPITAL LETTER U WITH TILDE"),
+    (0x0169, "LATIN SMALL LETTER U WITH TILDE"),
+    (0x016A, "LATIN CAPITAL LETTER U WITH MACRON"),
+    (0x016B, "LATIN SMALL LETTER U WITH MACRON"),
+    (0x016C, "LATIN CAPITAL LETTER U WITH BREVE"),
+    (0x016D, "LATIN SMALL LETTER U WITH BREVE"),
+    (0x016E, "LATIN CAPITAL LETTER U WITH RING ABOVE"),
+    (0x016F, "LATIN SMALL LETTER U WITH RING ABOVE"),
+    (0x0170, "LATIN CAPITAL LETTER U WITH DOUBLE ACUTE"),
+    (0x0171, "LATIN SMALL LETTER U WITH DOUBLE ACUTE"),
+    (0x0172, "LATIN CAPITAL LETTER U WITH OGONEK"),
+    (0x0173, "LATIN SMALL LETTER U WITH OGONEK"),
+    (0x0174, "LATIN CAPITAL LETTER W WITH CIRCUMFLEX"),
+    (0x0175, "LATIN SMALL LETTER W WITH CIRCUMFLEX"),
+    (0x0176, "LATIN CAPITAL LETTER Y WITH CIRCUMFLEX"),
+    (0x0177, "LATIN SMALL LETTER Y WITH CIRCUMFLEX"),
+    (0x0178, "LATIN CAPITAL LETTER Y WITH DIAERESIS"),
+    (0x0179, "LATIN CAPITAL LETTER Z WITH ACUTE"),
+    (0x017A, "LATIN SMALL LETTER Z WITH ACUTE"),
+    (0x017B, "LATIN CAPITAL LETTER Z WITH DOT ABOVE"),
+    (0x017C, "LATIN SMALL LETTER Z WITH DOT ABOVE"),
+    (0x017D, "LATIN CAPITAL LETTER Z WITH CARON"),
+    (0x017E, "LATIN SMALL LETTER Z WITH CARON"),
+    (0x017F, "LATIN SMALL LETTER LONG S"),
+    (0x0370, "GREEK CAPITAL LETTER HETA"),
+    (0x0371, "GREEK SMALL LETTER HETA"),
+    (0x0372, "GREEK CAPITAL LETTER ARCHAIC SAMPI"),
+    (0x0373, "GREEK SMALL LETTER ARCHAIC SAMPI"),
+    (0x0374, "GREEK NUMERAL SIGN"),
+    (0x0375, "GREEK LOWER NUMERAL SIGN"),
+    (0x0376, "GREEK CAPITAL LETTER PAMPHYLIAN DIGAMMA"),
+    (0x0377, "GREEK SMALL LETTER PAMPHYLIAN DIGAMMA"),
+    (0x037A, "GREEK YPOGEGRAMMENI"),
+    (0x037B, "GREEK SMALL REVERSED LUNATE SIGMA SYMBOL"),
+    (0x037C, "GREEK SMALL DOTTED LUNATE SIGMA SYMBOL"),
+    (0x037D, "GREEK SMALL REVERSED DOTTED LUNATE SIGMA SYMBOL"),
+    (0x037E, "GREEK QUESTION MARK"),
+    (0x037F, "GREEK CAPITAL LETTER YOT"),
+    (0x0384, "GREEK TONOS"),
+    (0x0385, "GREEK DIALYTIKA TONOS"),
+    (0x0386, "GREEK CAPITAL LETTER ALPHA WITH TONOS"),
+    (0x0387, "GREEK ANO TELEIA"),
+    (0x0388, "GREEK CAPITAL LETTER EPSILON WITH TONOS"),
+    (0x0389, "GREEK CAPITAL LETTER ETA WITH TONOS"),
+    (0x038A, "GREEK CAPITAL LETTER IOTA WITH TONOS"),
+    (0x038C, "GREEK CAPITAL LETTER OMICRON WITH TONOS"),
+    (0x038E, "GREEK CAPITAL LETTER UPSILON WITH TONOS"),
+    (0x038F, "GREEK CAPITAL LETTER OMEGA WITH TONOS"),
+    (0x0390, "GREEK SMALL LETTER IOTA WITH DIALYTIKA AND TONOS"),
+    (0x0391, "GREEK CAPITAL LETTER ALPHA"),
+    (0x0392, "GREEK CAPITAL LETTER BETA"),
+    (0x0393, "GREEK CAPITAL LETTER GAMMA"),
+    (0x0394, "GREEK CAPITAL LETTER DELTA"),
+    (0x0395, "GREEK CAPITAL LETTER EPSILON"),
+    (0x0396, "GREEK CAPITAL LETTER ZETA"),
+    (0x0397, "GREEK CAPITAL LETTER ETA"),
+    (0x0398, "GREEK CAPITAL LETTER THETA"),
+    (0x0399, "GREEK CAPITAL LETTER IOTA"),
+    (0x039A, "GREEK CAPITAL LETTER KAPPA"),
+    (0x039B, "GREEK CAPITAL LETTER LAMDA"),
+    (0x039C, "GREEK CAPITAL LETTER MU"),
+    (0x039D, "GREEK CAPITAL LETTER NU"),
+    (0x039E, "GREEK CAPITAL LETTER XI"),
+    (0x039F, "GREEK CAPITAL LETTER OMICRON"),
+    (0x03A0, "GREEK CAPITAL LETTER PI"),
+    (0x03A1, "GREEK CAPITAL LETTER RHO"),
+    (0x03A3, "GREEK CAPITAL LETTER SIGMA"),
+    (0x03A4, "GREEK CAPITAL LETTER TAU"),
+    (0x03A5, "GREEK CAPITAL LETTER UPSILON"),
+    (0x03A6, "GREEK CAPITAL LETTER PHI"),
+    (0x03A7, "GREEK CAPITAL LETTER CHI"),
+    (0x03A8, "GREEK CAPITAL LETTER PSI"),
+    (0x03A9, "GREEK CAPITAL LETTER OMEGA"),
+    (0x03AA, "GREEK CAPITAL LETTER IOTA WITH DIALYTIKA"),
+    (0x03AB, "GREEK CAPITAL LETTER UPSILON WITH DIALYTIKA"),
+    (0x03AC, "GREEK SMALL LETTER ALPHA WITH TONOS"),
+    (0x03AD, "GREEK SMALL LETTER EPSILON WITH TONOS"),
+    (0x03AE, "GREEK SMALL LETTER ETA WITH TONOS"),
+    (0x03AF, "GREEK SMALL LETTER IOTA WITH TONOS"),
+    (0x03B0, "GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS"),
+    (0x03B1, "GREEK SMALL LETTER ALPHA"),
+    (0x03B2, "GREEK SMALL LETTER BETA"),
+    (0x03B3, "GREEK SMALL LETTER GAMMA"),
+    (0x03B4, "GREEK SMALL LETTER DELTA"),
+    (0x03B5, "GREEK SMALL LETTER EPSILON"),
+    (0x03B6, "GREEK SMALL LETTER ZETA"),
+    (0x03B7, "GREEK SMALL LETTER ETA"),
+    (0x03B8, "GREEK SMALL LETTER THETA"),
+    (0x03B9, "GREEK SMALL LETTER IOTA"),
+    (0x03BA, "GREEK SMALL LETTER KAPPA"),
+    (0x03BB, "GREEK SMALL LETTER LAMDA"),
+    (0x03BC, "GREEK SMALL LETTER MU"),
+    (0x03BD, "GREEK SMALL LETTER NU"),
+    (0x03BE, "GREEK SMALL LETTER XI"),
+    (0x03BF, "GREEK SMALL LETTER OMICRON"),
+    (0x03C0, "GREEK SMALL LETTER PI"),
+    (0x03C1, "GREEK SMALL LETTER RHO"),
+    (0x03C2, "GREEK SMALL LETTER FINAL SIGMA"),
+    (0x03C3, "GREEK SMALL LETTER SIGMA"),
+    (0x03C4, "GREEK SMALL LETTER TAU"),
+    (0x03C5, "GREEK SMALL LETTER UPSILON"),
+    (0x03C6, "GREEK SMALL LETTER PHI"),
+    (0x03C7, "GREEK SMALL LETTER CHI"),
+    (0x03C8, "GREEK SMALL LETTER PSI"),
+    (0x03C9, "GREEK SMALL LETTER OMEGA"),
+    (0x03CA, "GREEK SMALL LETTER IOTA WITH DIALYTIKA"),
+    (0x03CB, "GREEK SMALL LETTER UPSILON WITH DIALYTIKA"),
+    (0x03CC, "GREEK SMALL LETTER OMICRON WITH TONOS"),
+    (0x03CD, "GREEK SMALL LETTER UPSILON WITH TONOS"),
+    (0x03CE, "GREEK SMALL LETTER OMEGA WITH TONOS"),
+    (0x03CF, "GREEK CAPITAL KAI SYMBOL"),
+    (0x03D0, "GREEK BETA SYMBOL"),
+    (0x03D1, "GREEK THETA SYMBOL"),
+    (0x03D2, "GREEK UPSILON WITH HOOK SYMBOL"),
+    (0x03D3, "GREEK UPSILON WITH ACUTE AND HOOK SYMBOL"),
+    (0x03D4, "GREEK UPSILON WITH DIAERESIS AND HOOK SYMBOL"),
+    (0x03D5, "GREEK PHI SYMBOL"),
+    (0x03D6, "GREEK PI SYMBOL"),
+    (0x03D7, "GREEK KAI SYMBOL"),
+    (0x03D8, "GREEK LETTER ARCHAIC KOPPA"),
+    (0x03D9, "GREEK SMALL LETTER ARCHAIC KOPPA"),
+    (0x03DA, "GREEK LETTER STIGMA"),
+    (0x03DB, "GREEK SMALL LETTER STIGMA"),
+    (0x03DC, "GREEK LETTER DIGAMMA"),
+    (0x03DD, "GREEK SMALL LETTER DIGAMMA"),
+    (0x03DE, "GREEK LETTER KOPPA"),
+    (0x03DF, "GREEK SMALL LETTER KOPPA"),
+    (0x03E0, "GREEK LETTER SAMPI"),
+    (0x03E1, "GREEK SMALL LETTER SAMPI"),
+    (0x03E2, "COPTIC CAPITAL LETTER SHEI"),
+    (0x03E3, "COPTIC SMALL LETTER SHEI"),
+    (0x03E4, "COPTIC CAPITAL LETTER FEI"),
+    (0x03E5, "COPTIC SMALL LETTER FEI"),
+    (0x03E6, "COPTIC CAPITAL LETTER KHEI"),
+    (0x03E7, "COPTIC SMALL LETTER KHEI"),
+    (0x03E8, "COPTIC CAPITAL LETTER HORI"),
+    (0x03E9, "COPTIC SMALL LETTER HORI"),
+    (0x03EA, "COPTIC CAPITAL LETTER GANGIA"),
+    (0x03EB, "COPTIC SMALL LETTER GANGIA"),
+    (0x03EC, "COPTIC CAPITAL LETTER SHIMA"),
+    (0x03ED, "COPTIC SMALL LETTER SHIMA"),
+    (0x03EE, "COPTIC CAPITAL LETTER DEI"),
+    (0x03EF, "COPTIC SMALL LETTER DEI"),
+    (0x03F0, "GREEK KAPPA SYMBOL"),
+    (0x03F1, "GREEK RHO SYMBOL"),
+    (0x03F2, "GREEK LUNATE SIGMA SYMBOL"),
+    (0x03F3, "GREEK LETTER YOT"),
+    (0x03F4, "GREEK CAPITAL THETA SYMBOL"),
+    (0x03F5, "GREEK LUNATE EPSILON SYMBOL"),
+    (0x03F6, "GREEK REVERSED LUNATE EPSILON SYMBOL"),
+    (0x03F7, "GREEK CAPITAL LETTER SHO"),
+    (0x03F8, "GREEK SMALL LETTER SHO"),
+    (0x03F9, "GREEK CAPITAL LUNATE SIGMA SYMBOL"),
+    (0x03FA, "GREEK CAPITAL LETTER SAN"),
+    (0x03FB, "GREEK SMALL LETTER SAN"),
+    (0x03FC, "GREEK RHO WITH STROKE SYMBOL"),
+    (0x03FD, "GREEK CAPITAL REVERSED LUNATE SIGMA SYMBOL"),
+    (0x03FE, "GREEK CAPITAL DOTTED LUNATE SIGMA SYMBOL"),
+    (0x03FF, "GREEK CAPITAL REVERSED DOTTED LUNATE SIGMA SYMBOL"),
+    (0x0400, "CYRILLIC CAPITAL LETTER IE WITH GRAVE"),
+    (0x0401, "CYRILLIC CAPITAL LETTER IO"),
+    (0x0402, "CYRILLIC CAPITAL LETTER DJE"),
+    (0x0403, "CYRILLIC CAPITAL LETTER GJE"),
+    (0x0404, "CYRILLIC CAPITAL LETTER UKRAINIAN IE"),
+    (0x0405, "CYRILLIC CAPITAL LETTER DZE"),
+    (0x0406, "CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I"),
+    (0x0407, "CYRILLIC CAPITAL LETTER YI"),
+    (0x0408, "CYRILLIC CAPITAL LETTER JE"),
+    (0x0409, "CYRILLIC CAPITAL LETTER LJE"),
+    (0x040A, "CYRILLIC CAPITAL LETTER NJE"),
+    (0x040B, "CYRILLIC CAPITAL LETTER TSHE"),
+    (0x040C, "CYRILLIC CAPITAL LETTER KJE"),
+    (0x040D, "CYRILLIC CAPITAL LETTER I WITH GRAVE"),
+    (0x040E, "CYRILLIC CAPITAL LETTER SHORT U"),
+    (0x040F, "CYRILLIC CAPITAL LETTER DZHE"),
+    (0x0410, "CYRILLIC CAPITAL LETTER A"),
+    (0x0411, "CYRILLIC CAPITAL LETTER BE"),
+    (0x0412, "CYRILLIC CAPITAL LETTER VE"),
+    (0x0413, "CYRILLIC CAPITAL LETTER GHE"),
+    (0x0414, "CYRILLIC CAPITAL LETTER DE"),
+    (0x0415, "CYRILLIC CAPITAL LETTER IE"),
+    (0x0416, "CYRILLIC CAPITAL LETTER ZHE"),
+    (0x0417, "CYRILLIC CAPITAL LETTER ZE"),
+    (0x0418, "CYRILLIC CAPITAL LETTER I"),
+    (0x0419, "CYRILLIC CAPITAL LETTER SHORT I"),
+    (0x041A, "CYRILLIC CAPITAL LETTER KA"),
+    (0x041B, "CYRILLIC CAPITAL LETTER EL"),
+    (0x041C, "CYRILLIC CAPITAL LETTER EM"),
+    (0x041D, "CYRILLIC CAPITAL LETTER EN"),
+    (0x041E, "CYRILLIC CAPITAL LETTER O"),
+    (0x041F, "CYRILLIC CAPITAL LETTER PE"),
+    (0x0420, "CYRILLIC CAPITAL LETTER ER"),
+    (0x0421, "CYRILLIC CAPITAL LETTER ES"),
+    (0x0422, "CYRILLIC CAPITAL LETTER TE"),
+    (0x0423, "CYRILLIC CAPITAL LETTER U"),
+    (0x0424, "CYRILLIC CAPITAL LETTER EF"),
+    (0x0425, "CYRILLIC CAPITAL LETTER HA"),
+    (0x0426, "CYRILLIC CAPITAL LETTER TSE"),
+    (0x0427, "CYRILLIC CAPITAL LETTER CHE"),
+    (0x0428, "CYRILLIC CAPITAL LETTER SHA"),
+    (0x0429, "CYRILLIC CAPITAL LETTER SHCHA"),
+    (0x042A, "CYRILLIC CAPITAL LETTER HARD SIGN"),
+    (0x042B, "CYRILLIC CAPITAL LETTER YERU"),
+    (0x042C, "CYRILLIC CAPITAL LETTER SOFT SIGN"),
+    (0x042D, "CYRILLIC CAPITAL LETTER E"),
+    (0x042E, "CYRILLIC CAPITAL LETTER YU"),
+    (0x042F, "CYRILLIC CAPITAL LETTER YA"),
+    (0x0430, "CYRILLIC SMALL LETTER A"),
+    (0x0431, "CYRILLIC SMALL LETTER BE"),
+    (0x0432, "CYRILLIC SMALL LETTER VE"),
+    (0x0433, "CYRILLIC SMALL LETTER GHE"),
+    (0x0434, "CYRILLIC SMALL LETTER DE"),
+    (0x0435, "CYRILLIC SMALL LETTER IE"),
+    (0x0436, "CYRILLIC SMALL LETTER ZHE"),
+    (0x0437, "CYRILLIC SMALL LETTER ZE"),
+    (0x0438, "CYRILLIC SMALL LETTER I"),
+    (0x0439, "CYRILLIC SMALL LETTER SHORT I"),
+    (0x043A, "CYRILLIC SMALL LETTER KA"),
+    (0x043B, "CYRILLIC SMALL LETTER EL"),
+    (0x043C, "CYRILLIC SMALL LETTER EM"),
+    (0x043D, "CYRILLIC SMALL LETTER EN"),
+    (0x043E, "CYRILLIC SMALL LETTER O"),
+    (0x043F, "CYRILLIC SMALL LETTER PE"),
+    (0x0440, "CYRILLIC SMALL LETTER ER"),
+    (0x0441, "CYRILLIC SMALL LETTER ES"),
+    (0x0442, "CYRILLIC SMALL LETTER TE"),
+    (0x0443, "CYRILLIC SMALL LETTER U"),
+    (0x0444, "CYRILLIC SMALL LETTER EF"),
+    (0x0445, "CYRILLIC SMALL LETTER HA"),
+    (0x0446, "CYRILLIC SMALL LETTER TSE"),
+    (0x0447, "CYRILLIC SMALL LETTER CHE"),
+    (0x0448, "CYRILLIC SMALL LETTER SHA"),
+    (0x0449, "CYRILLIC SMALL LETTER SHCHA"),
+    (0x044A, "CYRILLIC SMALL LETTER HARD SIGN"),
+    (0x044B, "CYRILLIC SMALL LETTER YERU"),
+    (0x044C, "CYRILLIC SMALL LETTER SOFT SIGN"),
+    (0x044D, "CYRILLIC SMALL LETTER E"),
+    (0x044E, "CYRILLIC SMALL LETTER YU"),
+    (0x044F, "CYRILLIC SMALL LETTER YA"),
+    (0x0450, "CYRILLIC SMALL LETTER IE WITH GRAVE"),
+    (0x0451, "CYRILLIC SMALL LETTER IO"),
+    (0x0452, "CYRILLIC SMALL LETTER DJE"),
+    (0x0453, "CYRILLIC SMALL LETTER GJE"),
+    (0x0454, "CYRILLIC SMALL LETTER UKRAINIAN IE"),
+    (0x0455, "CYRILLIC SMALL LETTER DZE"),
+    (0x0456, "CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I"),
+    (0x0457, "CYRILLIC SMALL LETTER YI"),
+    (0x0458, "CYRILLIC SMALL LETTER JE"),
+    (0x0459, "CYRILLIC SMALL LETTER LJE"),
+    (0x045A, "CYRILLIC SMALL LETTER NJE"),
+    (0x045B, "CYRILLIC SMALL LETTER TSHE"),
+    (0x045C, "CYRILLIC SMALL LETTER KJE"),
+    (0x045D, "CYRILLIC SMALL LETTER I WITH GRAVE"),
+    (0x045E, "CYRILLIC SMALL LETTER SHORT U"),
+    (0x045F, "CYRILLIC SMALL LETTER DZHE"),
+    (0x0460, "CYRILLIC CAPITAL LETTER OMEGA"),
+    (0x0461, "CYRILLIC SMALL LETTER OMEGA"),
+    (0x0462, "CYRILLIC CAPITAL LETTER YAT"),
+    (0x0463, "CYRILLIC SMALL LETTER YAT"),
+    (0x0464, "CYRILLIC CAPITAL LETTER IOTIFIED E"),
+    (0x0465, "CYRILLIC SMALL LETTER IOTIFIED E"),
+    (0x0466, "CYRILLIC CAPITAL LETTER LITTLE YUS"),
+    (0x0467, "CYRILLIC SMALL LETTER LITTLE YUS"),
+    (0x0468, "CYRILLIC CAPITAL LETTER IOTIFIED LITTLE YUS"),
+    (0x0469, "CYRILLIC SMALL LETTER IOTIFIED LITTLE YUS"),
+    (0x046A, "CYRILLIC CAPITAL LETTER BIG YUS"),
+    (0x046B, "CYRILLIC SMALL LETTER BIG YUS"),
+    (0x046C, "CYRILLIC CAPITAL LETTER IOTIFIED BIG YUS"),
+    (0x046D, "CYRILLIC SMALL LETTER IOTIFIED BIG YUS"),
+    (0x046E, "CYRILLIC CAPITAL LETTER KSI"),
+    (0x046F, "CYRILLIC SMALL LETTER KSI"),
+    (0x0470, "CYRILLIC CAPITAL LETTER PSI"),
+    (0x0471, "CYRILLIC SMALL LETTER PSI"),
+    (0x0472, "CYRILLIC CAPITAL LETTER FITA"),
+    (0x0473, "CYRILLIC SMALL LETTER FITA"),
+    (0x0474, "CYRILLIC CAPITAL LETTER IZHITSA"),
+    (0x0475, "CYRILLIC SMALL LETTER IZHITSA"),
+    (0x0476, "CYRILLIC CAPITAL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT"),
+    (0x0477, "CYRILLIC SMALL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT"),
+    (0x0478, "CYRILLIC CAPITAL LETTER UK"),
+    (0x0479, "CYRILLIC SMALL LETTER UK"),
+    (0x047A, "CYRILLIC CAPITAL LETTER ROUND OMEGA"),
+    (0x047B, "CYRILLIC SMALL LETTER ROUND OMEGA"),
+    (0x047C, "CYRILLIC CAPITAL LETTER OMEGA WITH TITLO"),
+    (0x047D, "CYRILLIC SMALL LETTER OMEGA WITH TITLO"),
+    (0x047E, "CYRILLIC CAPITAL LETTER OT"),
+    (0x047F, "CYRILLIC SMALL LETTER OT"),
+    (0x0480, "CYRILLIC CAPITAL LETTER KOPPA"),
+    (0x0481, "CYRILLIC SMALL LETTER KOPPA"),
+    (0x0482, "CYRILLIC THOUSANDS SIGN"),
+    (0x0483, "COMBINING CYRILLIC TITLO"),
+    (0x0484, "COMBINING CYRILLIC PALATALIZATION"),
+    (0x0485, "COMBINING CYRILLIC DASIA PNEUMATA"),
+    (0x0486, "COMBINING CYRILLIC PSILI PNEUMATA"),
+    (0x0487, "COMBINING CYRILLIC POKRYTIE"),
+    (0x0488, "COMBINING CYRILLIC HUNDRED THOUSANDS SIGN"),
+    (0x0489, "COMBINING CYRILLIC MILLIONS SIGN"),
+    (0x048A, "CYRILLIC CAPITAL LETTER SHORT I WITH TAIL"),
+    (0x048B, "CYRILLIC SMALL LETTER SHORT I WITH TAIL"),
+    (0x048C, "CYRILLIC CAPITAL LETTER SEMISOFT SIGN"),
+    (0x048D, "CYRILLIC SMALL LETTER SEMISOFT SIGN"),
+    (0x048E, "CYRILLIC CAPITAL LETTER ER WITH TICK"),
+    (0x048F, "CYRILLIC SMALL LETTER ER WITH TICK"),
+    (0x0490, "CYRILLIC CAPITAL LETTER GHE WITH UPTURN"),
+    (0x0491, "CYRILLIC SMALL LETTER GHE WITH UPTURN"),
+    (0x0492, "CYRILLIC CAPITAL LETTER GHE WITH STROKE"),
+    (0x0493, "CYRILLIC SMALL LETTER GHE WITH STROKE"),
+    (0x0494, "CYRILLIC CAPITAL LETTER GHE WITH MIDDLE HOOK"),
+    (0x0495, "CYRILLIC SMALL LETTER GHE WITH MIDDLE HOOK"),
+    (0x0496, "CYRILLIC CAPITAL LETTER ZHE WITH DESCENDER"),
+    (0x0497, "CYRILLIC SMALL LETTER ZHE WITH DESCENDER"),
+    (0x0498, "CYRILLIC CAPITAL LETTER ZE WITH DESCENDER"),
+    (0x0499, "CYRILLIC SMALL LETTER ZE WITH DESCENDER"),
+    (0x049A, "CYRILLIC CAPITAL LETTER KA WITH DESCENDER"),
+    (0x049B, "CYRILLIC SMALL LETTER KA WITH DESCENDER"),
+    (0x049C, "CYRILLIC CAPITAL LETTER KA WITH VERTICAL STROKE"),
+    (0x049D, "CYRILLIC SMALL LETTER KA WITH VERTICAL STROKE"),
+    (0x049E, "CYRILLIC CAPITAL LETTER KA WITH STROKE"),
+    (0x049F, "CYRILLIC SMALL LETTER KA WITH STROKE"),
+    (0x04A0, "CYRILLIC CAPITAL LETTER BASHKIR KA"),
+    (0x04A1, "CYRILLIC SMALL LETTER BASHKIR KA"),
+    (0x04A2, "CYRILLIC CAPITAL LETTER EN WITH DESCENDER"),
+    (0x04A3, "CYRILLIC SMALL LETTER EN WITH DESCENDER"),
+    (0x04A4, "CYRILLIC CAPITAL LIGATURE EN GHE"),
+    (0x04A5, "CYRILLIC SMALL LIGATURE EN GHE"),
+    (0x04A6, "CYRILLIC CAPITAL LETTER PE WITH MIDDLE HOOK"),
+    (0x04A7, "CYRILLIC SMALL LETTER PE WITH MIDDLE HOOK"),
+    (0x04A8, "CYRILLIC CAPITAL LETTER ABKHASIAN HA"),
+    (0x04A9, "CYRILLIC SMALL LETTER ABKHASIAN HA"),
+    (0x04AA, "CYRILLIC CAPITAL LETTER ES WITH DESCENDER"),
+    (0x04AB, "CYRILLIC SMALL LETTER ES WITH DESCENDER"),
+    (0x04AC, "CYRILLIC CAPITAL LETTER TE WITH DESCENDER"),
+    (0x04AD, "CYRILLIC SMALL LETTER TE WITH DESCENDER"),
+    (0x04AE, "CYRILLIC CAPITAL LETTER STRAIGHT U"),
+    (0x04AF, "CYRILLIC SMALL LETTER STRAIGHT U"),
+    (0x04B0, "CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE"),
+    (0x04B1, "CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE"),
+    (0x04B2, "CYRILLIC CAPITAL LETTER HA WITH DESCENDER"),
+    (0x04B3, "CYRILLIC SMALL LETTER HA WITH DESCENDER"),
+    (0x04B4, "CYRILLIC CAPITAL LIGATURE TE TSE"),
+    (0x04B5, "CYRILLIC SMALL LIGATURE TE TSE"),
+    (0x04B6, "CYRILLIC CAPITAL LETTER CHE WITH DESCENDER"),
+    (0x04B7, "CYRILLIC SMALL LETTER CHE WITH DESCENDER"),
+    (0x04B8, "CYRILLIC CAPITAL LETTER CHE WITH VERTICAL STROKE"),
+    (0x04B9, "CYRILLIC SMALL LETTER CHE WITH VERTICAL STROKE"),
+    (0x04BA, "CYRILLIC CAPITAL LETTER SHHA"),
+    (0x04BB, "CYRILLIC SMALL LETTER SHHA"),
+    (0x04BC, "CYRILLIC CAPITAL LETTER ABKHASIAN CHE"),
+    (0x04BD, "CYRILLIC SMALL LETTER ABKHASIAN CHE"),
+    (0x04BE, "CYRILLIC CAPITAL LETTER ABKHASIAN CHE WITH DESCENDER"),
+    (0x04BF, "CYRILLIC SMALL LETTER ABKHASIAN CHE WITH DESCENDER"),
+    (0x04C0, "CYRILLIC LETTER PALOCHKA"),
+    (0x04C1, "CYRILLIC CAPITAL LETTER ZHE WITH BREVE"),
+    (0x04C2, "CYRILLIC SMALL LETTER ZHE WITH BREVE"),
+    (0x04C3, "CYRILLIC CAPITAL LETTER KA WITH HOOK"),
+    (0x04C4, "CYRILLIC SMALL LETTER KA WITH HOOK"),
+    (0x04C5, "CYRILLIC CAPITAL LETTER EL WITH TAIL"),
+    (0x04C6, "CYRILLIC SMALL LETTER EL WITH TAIL"),
+    (0x04C7, "CYRILLIC CAPITAL LETTER EN WITH HOOK"),
+    (0x04C8, "CYRILLIC SMALL LETTER EN WITH HOOK"),
+    (0x04C9, "CYRILLIC CAPITAL LETTER EN WITH TAIL"),
+    (0x04CA, "CYRILLIC SMALL LETTER EN WITH TAIL"),
+    (0x04CB, "CYRILLIC CAPITAL LETTER KHAKASSIAN CHE"),
+    (0x04CC, "CYRILLIC SMALL LETTER KHAKASSIAN CHE"),
+    (0x04CD, "CYRILLIC CAPITAL LETTER EM WITH TAIL"),
+    (0x04CE, "CYRILLIC SMALL LETTER EM WITH TAIL"),
+    (0x04CF, "CYRILLIC SMALL LETTER PALOCHKA"),
+    (0x04D0, "CYRILLIC CAPITAL LETTER A WITH BREVE"),
+    (0x04D1, "CYRILLIC SMALL LETTER A WITH BREVE"),
+    (0x04D2, "CYRILLIC CAPITAL LETTER A WITH DIAERESIS"),
+    (0x04D3, "CYRILLIC SMALL LETTER A WITH DIAERESIS"),
+    (0x04D4, "CYRILLIC CAPITAL LIGATURE A IE"),
+    (0x04D5, "CYRILLIC SMALL LIGATURE A IE"),
+    (0x04D6, "CYRILLIC CAPITAL LETTER IE WITH BREVE"),
+    (0x04D7, "CYRILLIC SMALL LETTER IE WITH BREVE"),
+    (0x04D8, "CYRILLIC CAPITAL LETTER SCHWA"),
+    (0x04D9, "CYRILLIC SMALL LETTER SCHWA"),
+    (0x04DA, "CYRILLIC CAPITAL LETTER SCHWA WITH DIAERESIS"),
+    (0x04DB, "CYRILLIC SMALL LETTER SCHWA WITH DIAERESIS"),
+    (0x04DC, "CYRILLIC CAPITAL LETTER ZHE WITH DIAERESIS"),
+    (0x04DD, "CYRILLIC SMALL LETTER ZHE WITH DIAERESIS"),
+    (0x04DE, "CYRILLIC CAPITAL LETTER ZE WITH DIAERESIS"),
+    (0x04DF, "CYRILLIC SMALL LETTER ZE WITH DIAERESIS"),
+    (0x04E0, "CYRILLIC CAPITAL LETTER ABKHASIAN DZE"),
+    (0x04E1, "CYRILLIC SMALL LETTER ABKHASIAN DZE"),
+    (0x04E2, "CYRILLIC CAPITAL LETTER I WITH MACRON"),
+    (0x04E3, "CYRILLIC SMALL LETTER I WITH MACRON"),
+    (0x04E4, "CYRILLIC CAPITAL LETTER I WITH DIAERESIS"),
+    (0x04E5, "CYRILLIC SMALL LETTER I WITH DIAERESIS"),
+    (0x04E6, "CYRILLIC CAPITAL LETTER O WITH DIAERESIS"),
+    (0x04E7, "CYRILLIC SMALL LETTER O WITH DIAERESIS"),
+    (0x04E8, "CYRILLIC CAPITAL LETTER BARRED O"),
+    (0x04E9, "CYRILLIC SMALL LETTER BARRED O"),
+    (0x04EA, "CYRILLIC CAPITAL LETTER BARRED O WITH DIAERESIS"),
+    (0x04EB, "CYRILLIC SMALL LETTER BARRED O WITH DIAERESIS"),
+    (0x04EC, "CYRILLIC CAPITAL LETTER E WITH DIAERESIS"),
+    (0x04ED, "CYRILLIC SMALL LETTER E WITH DIAERESIS"),
+    (0x04EE, "CYRILLIC CAPITAL LETTER U WITH MACRON"),
+    (0x04EF, "CYRILLIC SMALL LETTER U WITH MACRON"),
+    (0x04F0, "CYRILLIC CAPITAL LETTER U WITH DIAERESIS"),
+    (0x04F1, "CYRILLIC SMALL LETTER U WITH DIAERESIS"),
+    (0x04F2, "CYRILLIC CAPITAL LETTER U WITH DOUBLE ACUTE"),
+    (0x04F3, "CYRILLIC SMALL LETTER U WITH DOUBLE ACUTE"),
+    (0x04F4, "CYRILLIC CAPITAL LETTER CHE WITH DIAERESIS"),
+    (0x04F5, "CYRILLIC SMALL LETTER CHE WITH DIAERESIS"),
+    (0x04F6, "CYRILLIC CAPITAL LETTER GHE WITH DESCENDER"),
+    (0x04F7, "CYRILLIC SMALL LETTER GHE WITH DESCENDER"),
+    (0x04F8, "CYRILLIC CAPITAL LETTER YERU WITH DIAERESIS"),
+    (0x04F9, "CYRILLIC SMALL LETTER YERU WITH DIAERESIS"),
+    (0x04FA, "CYRILLIC CAPITAL LETTER GHE WITH STROKE AND HOOK"),
+    (0x04FB, "CYRILLIC SMALL LETTER GHE WITH STROKE AND HOOK"),
+    (0x04FC, "CYRILLIC CAPITAL LETTER HA WITH HOOK"),
+    (0x04FD, "CYRILLIC SMALL LETTER HA WITH HOOK"),
+    (0x04FE, "CYRILLIC CAPITAL LETTER HA WITH STROKE"),
+    (0x04FF, "CYRILLIC SMALL LETTER HA WITH STROKE"),
+    (0x2000, "EN QUAD"),
+    (0x2001, "EM QUAD"),
+    (0x2002, "EN SPACE"),
+    (0x2003, "EM SPACE"),
+    (0x2004, "THREE-PER-EM SPACE"),
+    (0x2005, "FOUR-PER-EM SPACE"),
+    (0x2006, "SIX-PER-EM SPACE"),
+    (0x2007, "FIGURE SPACE"),
+    (0x2008, "PUNCTUATION SPACE"),
+    (0x2009, "THIN SPACE"),
+    (0x200A, "HAIR SPACE"),
+    (0x200B, "ZERO WIDTH SPACE"),
+    (0x200C, "ZERO WIDTH NON-JOINER"),
+    (0x200D, "ZERO WIDTH JOINER"),
+    (0x200E, "LEFT-TO-RIGHT MARK"),
+    (0x200F, "RIGHT-TO-LEFT MARK"),
+    (0x2010, "HYPHEN"),
+    (0x2011, "NON-BREAKING HYPHEN"),
+    (0x2012, "FIGURE DASH"),
+    (0x2013, "EN DASH"),
+    (0x2014, "EM DASH"),
+    (0x2015, "HORIZONTAL BAR"),
+    (0x2016, "DOUBLE VERTICAL LINE"),
+    (0x2017, "DOUBLE LOW LINE"),
+    (0x2018, "LEFT SINGLE QUOTATION MARK"),
+    (0x2019, "RIGHT SINGLE QUOTATION MARK"),
+    (0x201A, "SINGLE LOW-9 QUOTATION MARK"),
+    (0x201B, "SINGLE HIGH-REVERSED-9 QUOTATION MARK"),
+    (0x201C, "LEFT DOUBLE QUOTATION MARK"),
+    (0x201D, "RIGHT DOUBLE QUOTATION MARK"),
+    (0x201E, "DOUBLE LOW-9 QUOTATION MARK"),
+    (0x201F, "DOUBLE HIGH-REVERSED-9 QUOTATION MARK"),
+    (0x2020, "DAGGER"),
+    (0x2021, "DOUBLE DAGGER"),
+    (0x2022, "BULLET"),
+    (0x2023, "TRIANGULAR BULLET"),
+    (0x2024, "ONE DOT LEADER"),
+    (0x2025, "TWO DOT LEADER"),
+    (0x2026, "HORIZONTAL ELLIPSIS"),
+    (0x2027, "HYPHENATION POINT"),
+    (0x2028, "LINE SEPARATOR"),
+    (0x2029, "PARAGRAPH SEPARATOR"),
+    (0x202A, "LEFT-TO-RIGHT EMBEDDING"),
+    (0x202B, "RIGHT-TO-LEFT EMBEDDING"),
+    (0x202C, "POP DIRECTIONAL FORMATTING"),
+    (0x202D, "LEFT-TO-RIGHT OVERRIDE"),
+    (0x202E, "RIGHT-TO-LEFT OVERRIDE"),
+    (0x202F, "NARROW NO-BREAK SPACE"),
+    (0x2030, "PER MILLE SIGN"),
+    (0x2031, "PER TEN THOUSAND SIGN"),
+    (0x2032, "PRIME"),
+    (0x2033, "DOUBLE PRIME"),
+    (0x2034, "TRIPLE PRIME"),
+    (0x2035, "REVERSED PRIME"),
+    (0x2036, "REVERSED DOUBLE PRIME"),
+    (0x2037, "REVERSED TRIPLE PRIME"),
+    (0x2038, "CARET"),
+    (0x2039, "SINGLE LEFT-POINTING ANGLE QUOTATION MARK"),
+    (0x203A, "SINGLE RIGHT-POINTING ANGLE QUOTATION MARK"),
+    (0x203B, "REFERENCE MARK"),
+    (0x203C, "DOUBLE EXCLAMATION MARK"),
+    (0x203D, "INTERROBANG"),
+    (0x203E, "OVERLINE"),
+    (0x203F, "UNDERTIE"),
+    (0x2040, "CHARACTER TIE"),
+    (0x2041, "CARET INSERTION POINT"),
+    (0x2042, "ASTERISM"),
+    (0x2043, "HYPHEN BULLET"),
+    (0x2044, "FRACTION SLASH"),
+    (0x2045, "LEFT SQUARE BRACKET WITH QUILL"),
+    (0x2046, "RIGHT SQUARE BRACKET WITH QUILL"),
+    (0x2047, "DOUBLE QUESTION MARK"),
+    (0x2048, "QUESTION EXCLAMATION MARK"),
+    (0x2049, "EXCLAMATION QUESTION MARK"),
+    (0x204A, "TIRONIAN SIGN ET"),
+    (0x204B, "REVERSED PILCROW SIGN"),
+    (0x204C, "BLACK LEFTWARDS BULLET"),
+    (0x204D, "BLACK RIGHTWARDS BULLET"),
+    (0x204E, "LOW ASTERISK"),
+    (0x204F, "REVERSED SEMICOLON"),
+    (0x2050, "CLOSE UP"),
+    (0x2051, "TWO ASTERISKS ALIGNED VERTICALLY"),
+    (0x2052, "COMMERCIAL MINUS SIGN"),
+    (0x2053, "SWUNG DASH"),
+    (0x2054, "INVERTED UNDERTIE"),
+    (0x2055, "FLOWER PUNCTUATION MARK"),
+    (0x2056, "THREE DOT PUNCTUATION"),
+    (0x2057, "QUADRUPLE PRIME"),
+    (0x2058, "FOUR DOT PUNCTUATION"),
+    (0x2059, "FIVE DOT PUNCTUATION"),
+    (0x205A, "TWO DOT PUNCTUATION"),
+    (0x205B, "FOUR DOT MARK"),
+    (0x205C, "DOTTED CROSS"),
+    (0x205D, "TRICOLON"),
+    (0x205E, "VERTICAL FOUR DOTS"),
+    (0x205F, "MEDIUM MATHEMATICAL SPACE"),
+    (0x2060, "WORD JOINER"),
+    (0x2061, "FUNCTION APPLICATION"),
+    (0x2062, "INVISIBLE TIMES"),
+    (0x2063, "INVISIBLE SEPARATOR"),
+    (0x2064, "INVISIBLE PLUS"),
+    (0x2066, "LEFT-TO-RIGHT ISOLATE"),
+    (0x2067, "RIGHT-TO-LEFT ISOLATE"),
+    (0x2068, "FIRST STRONG ISOLATE"),
+    (0x2069, "POP DIRECTIONAL ISOLATE"),
+    (0x206A, "INHIBIT SYMMETRIC SWAPPING"),
+    (0x206B, "ACTIVATE SYMMETRIC SWAPPING"),
+    (0x206C, "INHIBIT ARABIC FORM SHAPING"),
+    (0x206D, "ACTIVATE ARABIC FORM SHAPING"),
+    (0x206E, "NATIONAL DIGIT SHAPES"),
+    (0x206F, "NOMINAL DIGIT SHAPES"),
+    (0x2070, "SUPERSCRIPT ZERO"),
+    (0x2071, "SUPERSCRIPT LATIN SMALL LETTER I"),
+    (0x2074, "SUPERSCRIPT FOUR"),
+    (0x2075, "SUPERSCRIPT FIVE"),
+    (0x2076, "SUPERSCRIPT SIX"),
+    (0x2077, "SUPERSCRIPT SEVEN"),
+    (0x2078, "SUPERSCRIPT EIGHT"),
+    (0x2079, "SUPERSCRIPT NINE"),
+    (0x207A, "SUPERSCRIPT PLUS SIGN"),
+    (0x207B, "SUPERSCRIPT MINUS"),
+    (0x207C, "SUPERSCRIPT EQUALS SIGN"),
+    (0x207D, "SUPERSCRIPT LEFT PARENTHESIS"),
+    (0x207E, "SUPERSCRIPT RIGHT PARENTHESIS"),
+    (0x207F, "SUPERSCRIPT LATIN SMALL LETTER N"),
+    (0x2080, "SUBSCRIPT ZERO"),
+    (0x2081, "SUBSCRIPT ONE"),
+    (0x2082, "SUBSCRIPT TWO"),
+    (0x2083, "SUBSCRIPT THREE"),
+    (0x2084, "SUBSCRIPT FOUR"),
+    (0x2085, "SUBSCRIPT FIVE"),
+    (0x2086, "SUBSCRIPT SIX"),
+    (0x2087, "SUBSCRIPT SEVEN"),
+    (0x2088, "SUBSCRIPT EIGHT"),
+    (0x2089, "SUBSCRIPT NINE"),
+    (0x208A, "SUBSCRIPT PLUS SIGN"),
+    (0x208B, "SUBSCRIPT MINUS"),
+    (0x208C, "SUBSCRIPT EQUALS SIGN"),
+    (0x208D, "SUBSCRIPT LEFT PARENTHESIS"),
+    (0x208E, "SUBSCRIPT RIGHT PARENTHESIS"),
+    (0x2090, "LATIN SUBSCRIPT SMALL LETTER A"),
+    (0x2091, "LATIN SUBSCRIPT SMALL LETTER E"),
+    (0x2092, "LATIN SUBSCRIPT SMALL LETTER O"),
+    (0x2093, "LATIN SUBSCRIPT SMALL LETTER X"),
+    (0x2094, "LATIN SUBSCRIPT SMALL LETTER SCHWA"),
+    (0x2095, "LATIN SUBSCRIPT SMALL LETTER H"),
+    (0x2096, "LATIN SUBSCRIPT SMALL LETTER K"),
+    (0x2097, "LATIN SUBSCRIPT SMALL LETTER L"),
+    (0x2098, "LATIN SUBSCRIPT SMALL LETTER M"),
+    (0x2099, "LATIN SUBSCRIPT SMALL LETTER N"),
+    (0x209A, "LATIN SUBSCRIPT SMALL LETTER P"),
+    (0x209B, "LATIN SUBSCRIPT SMALL LETTER S"),
+    (0x209C, "LATIN SUBSCRIPT SMALL LETTER T"),
+    (0x20A0, "EURO-CURRENCY SIGN"),
+    (0x20A1, "COLON SIGN"),
+    (0x20A2, "CRUZEIRO SIGN"),
+    (0x20A3, "FRENCH FRANC SIGN"),
+    (0x20A4, "LIRA SIGN"),
+    (0x20A5, "MILL SIGN"),
+    (0x20A6, "NAIRA SIGN"),
+    (0x20A7, "PESETA SIGN"),
+    (0x20A8, "RUPEE SIGN"),
+    (0x20A9, "WON SIGN"),
+    (0x20AA, "NEW SHEQEL SIGN"),
+    (0x20AB, "DONG SIGN"),
+    (0x20AC, "EURO SIGN"),
+    (0x20AD, "KIP SIGN"),
+    (0x20AE, "TUGRIK SIGN"),
+    (0x20AF, "DRACHMA SIGN"),
+    (0x20B0, "GERMAN PENNY SIGN"),
+    (0x20B1, "PESO SIGN"),
+    (0x20B2, "GUARANI SIGN"),
+    (0x20B3, "AUSTRAL SIGN"),
+    (0x20B4, "HRYVNIA SIGN"),
+    (0x20B5, "CEDI SIGN"),
+    (0x20B6, "LIVRE TOURNOIS SIGN"),
+    (0x20B7, "SPESMILO SIGN"),
+    (0x20B8, "TENGE SIGN"),
+    (0x20B9, "INDIAN RUPEE SIGN"),
+    (0x20BA, "TURKISH LIRA SIGN"),
+    (0x20BB, "NORDIC MARK SIGN"),
+    (0x20BC, "MANAT SIGN"),
+    (0x20BD, "RUBLE SIGN"),
+    (0x20BE, "LARI SIGN"),
+    (0x20BF, "BITCOIN SIGN"),
+    (0x20C0, "SOM SIGN"),
+    (0x2100, "ACCOUNT OF"),
+    (0x2101, "ADDRESSED TO THE SUBJECT"),
+    (0x2102, "DOUBLE-STRUCK CAPITAL C"),
+    (0x2103, "DEGREE CELSIUS"),
+    (0x2104, "CENTRE LINE SYMBOL"),
+    (0x2105, "CARE OF"),
+    (0x2106, "CADA UNA"),
+    (0x2107, "EULER CONSTANT"),
+    (0x2108, "SCRUPLE"),
+    (0x2109, "DEGREE FAHRENHEIT"),
+    (0x210A, "SCRIPT SMALL G"),
+    (0x210B, "SCRIPT CAPITAL H"),
+    (0x210C, "BLACK-LETTER CAPITAL H"),
+    (0x210D, "DOUBLE-STRUCK CAPITAL H"),
+    (0x210E, "PLANCK CONSTANT"),
+    (0x210F, "PLANCK CONSTANT OVER TWO PI"),
+    (0x2110, "SCRIPT CAPITAL I"),
+    (0x2111, "BLACK-LETTER CAPITAL I"),
+    (0x2112, "SCRIPT CAPITAL L"),
+    (0x2113, "SCRIPT SMALL L"),
+    (0x2114, "L B BAR SYMBOL"),
+    (0x2115, "DOUBLE-STRUCK CAPITAL N"),
+    (0x2116, "NUMERO SIGN"),
+    (0x2117, "SOUND RECORDING COPYRIGHT"),
+    (0x2118, "SCRIPT CAPITAL P"),
+    (0x2119, "DOUBLE-STRUCK CAPITAL P"),
+    (0x211A, "DOUBLE-STRUCK CAPITAL Q"),
+    (0x211B, "SCRIPT CAPITAL R"),
+    (0x211C, "BLACK-LETTER CAPITAL R"),
+    (0x211D, "DOUBLE-STRUCK CAPITAL R"),
+    (0x211E, "PRESCRIPTION TAKE"),
+    (0x211F, "RESPONSE"),
+    (0x2120, "SERVICE MARK"),
+    (0x2121, "TELEPHONE SIGN"),
+    (0x2122, "TRADE MARK SIGN"),
+    (0x2123, "VERSICLE"),
+    (0x2124, "DOUBLE-STRUCK CAPITAL Z"),
+    (0x2125, "OUNCE SIGN"),
+    (0x2126, "OHM SIGN"),
+    (0x2127, "INVERTED OHM SIGN"),
+    (0x2128, "BLACK-LETTER CAPITAL Z"),
+    (0x2129, "TURNED GREEK SMALL LETTER IOTA"),
+    (0x212A, "KELVIN SIGN"),
+    (0x212B, "ANGSTROM SIGN"),
+    (0x212C, "SCRIPT CAPITAL B"),
+    (0x212D, "BLACK-LETTER CAPITAL C"),
+    (0x212E, "ESTIMATED SYMBOL"),
+    (0x212F, "SCRIPT SMALL E"),
+    (0x2130, "SCRIPT CAPITAL E"),
+    (0x2131, "SCRIPT CAPITAL F"),
+    (0x2132, "TURNED CAPITAL F"),
+    (0x2133, "SCRIPT CAPITAL M"),
+    (0x2134, "SCRIPT SMALL O"),
+    (0x2135, "ALEF SYMBOL"),
+    (0x2136, "BET SYMBOL"),
+    (0x2137, "GIMEL SYMBOL"),
+    (0x2138, "DALET SYMBOL"),
+    (0x2139, "INFORMATION SOURCE"),
+    (0x213A, "ROTATED CAPITAL Q"),
+    (0x213B, "FACSIMILE SIGN"),
+    (0x213C, "DOUBLE-STRUCK SMALL PI"),
+    (0x213D, "DOUBLE-STRUCK SMALL GAMMA"),
+    (0x213E, "DOUBLE-STRUCK CAPITAL GAMMA"),
+    (0x213F, "DOUBLE-STRUCK CAPITAL PI"),
+    (0x2140, "DOUBLE-STRUCK N-ARY SUMMATION"),
+    (0x2141, "TURNED SANS-SERIF CAPITAL G"),
+    (0x2142, "TURNED SANS-SERIF CAPITAL L"),
+    (0x2143, "REVERSED SANS-SERIF CAPITAL L"),
+    (0x2144, "TURNED SANS-SERIF CAPITAL Y"),
+    (0x2145, "DOUBLE-STRUCK ITALIC CAPITAL D"),
+    (0x2146, "DOUBLE-STRUCK ITALIC SMALL D"),
+    (0x2147, "DOUBLE-STRUCK ITALIC SMALL E"),
+    (0x2148, "DOUBLE-STRUCK ITALIC SMALL I"),
+    (0x2149, "DOUBLE-STRUCK ITALIC SMALL J"),
+    (0x214A, "PROPERTY LINE"),
+    (0x214B, "TURNED AMPERSAND"),
+    (0x214C, "PER SIGN"),
+    (0x214D, "AKTIESELSKAB"),
+    (0x214E, "TURNED SMALL F"),
+    (0x214F, "SYMBOL FOR SAMARITAN SOURCE"),
+    (0x2150, "VULGAR FRACTION ONE SEVENTH"),
+    (0x2151, "VULGAR FRACTION ONE NINTH"),
+    (0x2152, "VULGAR FRACTION ONE TENTH"),
+    (0x2153, "VULGAR FRACTION ONE THIRD"),
+    (0x2154, "VULGAR FRACTION TWO THIRDS"),
+    (0x2155, "VULGAR FRACTION ONE FIFTH"),
+    (0x2156, "VULGAR FRACTION TWO FIFTHS"),
+    (0x2157, "VULGAR FRACTION THREE FIFTHS"),
+    (0x2158, "VULGAR FRACTION FOUR FIFTHS"),
+    (0x2159, "VULGAR FRACTION ONE SIXTH"),
+    (0x215A, "VULGAR FRACTION FIVE SIXTHS"),
+    (0x215B, "VULGAR FRACTION ONE EIGHTH"),
+    (0x215C, "VULGAR FRACTION THREE EIGHTHS"),
+    (0x215D, "VULGAR FRACTION FIVE EIGHTHS"),
+    (0x215E, "VULGAR FRACTION SEVEN EIGHTHS"),
+    (0x215F, "FRACTION NUMERATOR ONE"),
+    (0x2160, "ROMAN NUMERAL ONE"),
+    (0x2161, "ROMAN NUMERAL TWO"),
+    (0x2162, "ROMAN NUMERAL THREE"),
+    (0x2163, "ROMAN NUMERAL FOUR"),
+    (0x2164, "ROMAN NUMERAL FIVE"),
+    (0x2165, "ROMAN NUMERAL SIX"),
+    (0x2166, "ROMAN NUMERAL SEVEN"),
+    (0x2167, "ROMAN NUMERAL EIGHT"),
+    (0x2168, "ROMAN NUMERAL NINE"),
+    (0x2169, "ROMAN NUMERAL TEN"),
+    (0x216A, "ROMAN NUMERAL ELEVEN"),
+    (0x216B, "ROMAN NUMERAL TWELVE"),
+    (0x216C, "ROMAN NUMERAL FIFTY"),
+    (0x216D, "ROMAN NUMERAL ONE HUNDRED"),
+    (0x216E, "ROMAN NUMERAL FIVE HUNDRED"),
+    (0x216F, "ROMAN NUMERAL ONE THOUSAND"),
+    (0x2170, "SMALL ROMAN NUMERAL ONE"),
+    (0x2171, "SMALL ROMAN NUMERAL TWO"),
+    (0x2172, "SMALL ROMAN NUMERAL THREE"),
+    (0x2173, "SMALL ROMAN NUMERAL FOUR"),
+    (0x2174, "SMALL ROMAN NUMERAL FIVE"),
+    (0x2175, "SMALL ROMAN NUMERAL SIX"),
+    (0x2176, "SMALL ROMAN NUMERAL SEVEN"),
+    (0x2177, "SMALL ROMAN NUMERAL EIGHT"),
+    (0x2178, "SMALL ROMAN NUMERAL NINE"),
+    (0x2179, "SMALL ROMAN NUMERAL TEN"),
+    (0x217A, "SMALL ROMAN NUMERAL ELEVEN"),
+    (0x217B, "SMALL ROMAN NUMERAL TWELVE"),
+    (0x217C, "SMALL ROMAN NUMERAL FIFTY"),
+    (0x217D, "SMALL ROMAN NUMERAL ONE HUNDRED"),
+    (0x217E, "SMALL ROMAN NUMERAL FIVE HUNDRED"),
+    (0x217F, "SMALL ROMAN NUMERAL ONE THOUSAND"),
+    (0x2180, "ROMAN NUMERAL ONE THOUSAND C D"),
+    (0x2181, "ROMAN NUMERAL FIVE THOUSAND"),
+    (0x2182, "ROMAN NUMERAL TEN THOUSAND"),
+    (0x2183, "ROMAN NUMERAL REVERSED ONE HUNDRED"),
+    (0x2184, "LATIN SMALL LETTER REVERSED C"),
+    (0x2185, "ROMAN NUMERAL SIX LATE FORM"),
+    (0x2186, "ROMAN NUMERAL FIFTY EARLY FORM"),
+    (0x2187, "ROMAN NUMERAL FIFTY THOUSAND"),
+    (0x2188, "ROMAN NUMERAL ONE HUNDRED THOUSAND"),
+    (0x2189, "VULGAR FRACTION ZERO THIRDS"),
+    (0x218A, "TURNED DIGIT TWO"),
+    (0x218B, "TURNED DIGIT THREE"),
+    (0x2190, "LEFTWARDS ARROW"),
+    (0x2191, "UPWARDS ARROW"),
+    (0x2192, "RIGHTWARDS ARROW"),
+    (0x2193, "DOWNWARDS ARROW"),
+    (0x2194, "LEFT RIGHT ARROW"),
+    (0x2195, "UP DOWN ARROW"),
+    (0x2196, "NORTH WEST ARROW"),
+    (0x2197, "NORTH EAST ARROW"),
+    (0x2198, "SOUTH EAST ARROW"),
+    (0x2199, "SOUTH WEST ARROW"),
+    (0x219A, "LEFTWARDS ARROW WITH STROKE"),
+    (0x219B, "RIGHTWARDS ARROW WITH STROKE"),
+    (0x219C, "LEFTWARDS WAVE ARROW"),
+    (0x219D, "RIGHTWARDS WAVE ARROW"),
+    (0x219E, "LEFTWARDS TWO HEADED ARROW"),
+    (0x219F, "UPWARDS TWO HEADED ARROW"),
+    (0x21A0, "RIGHTWARDS TWO HEADED ARROW"),
+    (0x21A1, "DOWNWARDS TWO HEADED ARROW"),
+    (0x21A2, "LEFTWARDS ARROW WITH TAIL"),
+    (0x21A3, "RIGHTWARDS ARROW WITH TAIL"),
+    (0x21A4, "LEFTWARDS ARROW FROM BAR"),
+    (0x21A5, "UPWARDS ARROW FROM BAR"),
+    (0x21A6, "RIGHTWARDS ARROW FROM BAR"),
+    (0x21A7, "DOWNWARDS ARROW FROM BAR"),
+    (0x21A8, "UP DOWN ARROW WITH BASE"),
+    (0x21A9, "LEFTWARDS ARROW WITH HOOK"),
+    (0x21AA, "RIGHTWARDS ARROW WITH HOOK"),
+    (0x21AB, "LEFTWARDS ARROW WITH LOOP"),
+    (0x21AC, "RIGHTWARDS ARROW WITH LOOP"),
+    (0x21AD, "LEFT RIGHT WAVE ARROW"),
+    (0x21AE, "LEFT RIGHT ARROW WITH STROKE"),
+    (0x21AF, "DOWNWARDS ZIGZAG ARROW"),
+    (0x21B0, "UPWARDS ARROW WITH TIP LEFTWARDS"),
+    (0x21B1, "UPWARDS ARROW WITH TIP RIGHTWARDS"),
+    (0x21B2, "DOWNWARDS ARROW WITH TIP LEFTWARDS"),
+    (0x21B3, "DOWNWARDS ARROW WITH TIP RIGHTWARDS"),
+    (0x21B4, "RIGHTWARDS ARROW WITH CORNER DOWNWARDS"),
+    (0x21B5, "DOWNWARDS ARROW WITH CORNER LEFTWARDS"),
+    (0x21B6, "ANTICLOCKWISE TOP SEMICIRCLE ARROW"),
+    (0x21B7, "CLOCKWISE TOP SEMICIRCLE ARROW"),
+    (0x21B8, "NORTH WEST ARROW TO LONG BAR"),
+    (0x21B9, "LEFTWARDS ARROW TO BAR OVER RIGHTWARDS ARROW TO BAR"),
+    (0x21BA, "ANTICLOCKWISE OPEN CIRCLE ARROW"),
+    (0x21BB, "CLOCKWISE OPEN CIRCLE ARROW"),
+    (0x21BC, "LEFTWARDS HARPOON WITH BARB UPWARDS"),
+    (0x21BD, "LEFTWARDS HARPOON WITH BARB DOWNWARDS"),
+    (0x21BE, "UPWARDS HARPOON WITH BARB RIGHTWARDS"),
+    (0x21BF, "UPWARDS HARPOON WITH BARB LEFTWARDS"),
+    (0x21C0, "RIGHTWARDS HARPOON WITH BARB UPWARDS"),
+    (0x21C1, "RIGHTWARDS HARPOON WITH BARB DOWNWARDS"),
+    (0x21C2, "DOWNWARDS HARPOON WITH BARB RIGHTWARDS"),
+    (0x21C3, "DOWNWARDS HARPOON WITH BARB LEFTWARDS"),
+    (0x21C4, "RIGHTWARDS ARROW OVER LEFTWARDS ARROW"),
+    (0x21C5, "UPWARDS ARROW LEFTWARDS OF DOWNWARDS ARROW"),
+    (0x21C6, "LEFTWARDS ARROW OVER RIGHTWARDS ARROW"),
+    (0x21C7, "LEFTWARDS PAIRED ARROWS"),
+    (0x21C8, "UPWARDS PAIRED ARROWS"),
+    (0x21C9, "RIGHTWARDS PAIRED ARROWS"),
+    (0x21CA, "DOWNWARDS PAIRED ARROWS"),
+    (0x21CB, "LEFTWARDS HARPOON OVER RIGHTWARDS HARPOON"),
+    (0x21CC, "RIGHTWARDS HARPOON OVER LEFTWARDS HARPOON"),
+    (0x21CD, "LEFTWARDS DOUBLE ARROW WITH STROKE"),
+    (0x21CE, "LEFT RIGHT DOUBLE ARROW WITH STROKE"),
+    (0x21CF, "RIGHTWARDS DOUBLE ARROW WITH STROKE"),
+    (0x21D0, "LEFTWARDS DOUBLE ARROW"),
+    (0x21D1, "UPWARDS DOUBLE ARROW"),
+    (0x21D2, "RIGHTWARDS DOUBLE ARROW"),
+    (0x21D3, "DOWNWARDS DOUBLE ARROW"),
+    (0x21D4, "LEFT RIGHT DOUBLE ARROW"),
+    (0x21D5, "UP DOWN DOUBLE ARROW"),
+    (0x21D6, "NORTH WEST DOUBLE ARROW"),
+    (0x21D7, "NORTH EAST DOUBLE ARROW"),
+    (0x21D8, "SOUTH EAST DOUBLE ARROW"),
+    (0x21D9, "SOUTH WEST DOUBLE ARROW"),
+    (0x21DA, "LEFTWARDS TRIPLE ARROW"),
+    (0x21DB, "RIGHTWARDS TRIPLE ARROW"),
+    (0x21DC, "LEFTWARDS SQUIGGLE ARROW"),
+    (0x21DD, "RIGHTWARDS SQUIGGLE ARROW"),
+    (0x21DE, "UPWARDS ARROW WITH DOUBLE STROKE"),
+    (0x21DF, "DOWNWARDS ARROW WITH DOUBLE STROKE"),
+    (0x21E0, "LEFTWARDS DASHED ARROW"),
+    (0x21E1, "UPWARDS DASHED ARROW"),
+    (0x21E2, "RIGHTWARDS DASHED ARROW"),
+    (0x21E3, "DOWNWARDS DASHED ARROW"),
+    (0x21E4, "LEFTWARDS ARROW TO BAR"),
+    (0x21E5, "RIGHTWARDS ARROW TO BAR"),
+    (0x21E6, "LEFTWARDS WHITE ARROW"),
+    (0x21E7, "UPWARDS WHITE ARROW"),
+    (0x21E8, "RIGHTWARDS WHITE ARROW"),
+    (0x21E9, "DOWNWARDS WHITE ARROW"),
+    (0x21EA, "UPWARDS WHITE ARROW FROM BAR"),
+    (0x21EB, "UPWARDS WHITE ARROW ON PEDESTAL"),
+    (0x21EC, "UPWARDS WHITE ARROW ON PEDESTAL WITH HORIZONTAL BAR"),
+    (0x21ED, "UPWARDS WHITE ARROW ON PEDESTAL WITH VERTICAL BAR"),
+    (0x21EE, "UPWARDS WHITE DOUBLE ARROW"),
+    (0x21EF, "UPWARDS WHITE DOUBLE ARROW ON PEDESTAL"),
+    (0x21F0, "RIGHTWARDS WHITE ARROW FROM WALL"),
+    (0x21F1, "NORTH WEST ARROW TO CORNER"),
+    (0x21F2, "SOUTH EAST ARROW TO CORNER"),
+    (0x21F3, "UP DOWN WHITE ARROW"),
+    (0x21F4, "RIGHT ARROW WITH SMALL CIRCLE"),
+    (0x21F5, "DOWNWARDS ARROW LEFTWARDS OF UPWARDS ARROW"),
+    (0x21F6, "THREE RIGHTWARDS ARROWS"),
+    (0x21F7, "LEFTWARDS ARROW WITH VERTICAL STROKE"),
+    (0x21F8, "RIGHTWARDS ARROW WITH VERTICAL STROKE"),
+    (0x21F9, "LEFT RIGHT ARROW WITH VERTICAL STROKE"),
+    (0x21FA, "LEFTWARDS ARROW WITH DOUBLE VERTICAL STROKE"),
+    (0x21FB, "RIGHTWARDS ARROW WITH DOUBLE VERTICAL STROKE"),
+    (0x21FC, "LEFT RIGHT ARROW WITH DOUBLE VERTICAL STROKE"),
+    (0x21FD, "LEFTWARDS OPEN-HEADED ARROW"),
+    (0x21FE, "RIGHTWARDS OPEN-HEADED ARROW"),
+    (0x21FF, "LEFT RIGHT OPEN-HEADED ARROW"),
+    (0x2200, "FOR ALL"),
+    (0x2201, "COMPLEMENT"),
+    (0x2202, "PARTIAL DIFFERENTIAL"),
+    (0x2203, "THERE EXISTS"),
+    (0x2204, "THERE DOES NOT EXIST"),
+    (0x2205, "EMPTY SET"),
+    (0x2206, "INCREMENT"),
+    (0x2207, "NABLA"),
+    (0x2208, "ELEMENT OF"),
+    (0x2209, "NOT AN ELEMENT OF"),
+    (0x220A, "SMALL ELEMENT OF"),
+    (0x220B, "CONTAINS AS MEMBER"),
+    (0x220C, "DOES NOT CONTAIN AS MEMBER"),
+    (0x220D, "SMALL CONTAINS AS MEMBER"),
+    (0x220E, "END OF PROOF"),
+    (0x220F, "N-ARY PRODUCT"),
+    (0x2210, "N-ARY COPRODUCT"),
+    (0x2211, "N-ARY SUMMATION"),
+    (0x2212, "MINUS SIGN"),
+    (0x2213, "MINUS-OR-PLUS SIGN"),
+    (0x2214, "DOT PLUS"),
+    (0x2215, "DIVISION SLASH"),
+    (0x2216, "SET MINUS"),
+    (0x2217, "ASTERISK OPERATOR"),
+    (0x2218, "RING OPERATOR"),
+    (0x2219, "BULLET OPERATOR"),
+    (0x221A, "SQUARE ROOT"),
+    (0x221B, "CUBE ROOT"),
+    (0x221C, "FOURTH ROOT"),
+    (0x221D, "PROPORTIONAL TO"),
+    (0x221E, "INFINITY"),
+    (0x221F, "RIGHT ANGLE"),
+    (0x2220, "ANGLE"),
+    (0x2221, "MEASURED ANGLE"),
+    (0x2222, "SPHERICAL ANGLE"),
+    (0x2223, "DIVIDES"),
+    (0x2224, "DOES NOT DIVIDE"),
+    (0x2225, "PARALLEL TO"),
+    (0x2226, "NOT PARALLEL TO"),
+    (0x2227, "LOGICAL AND"),
+    (0x2228, "LOGICAL OR"),
+    (0x2229, "INTERSECTION"),
+    (0x222A, "UNION"),
+    (0x222B, "INTEGRAL"),
+    (0x222C, "DOUBLE INTEGRAL"),
+    (0x222D, "TRIPLE INTEGRAL"),
+    (0x222E, "CONTOUR INTEGRAL"),
+    (0x222F, "SURFACE INTEGRAL"),
+    (0x2230, "VOLUME INTEGRAL"),
+    (0x2231, "CLOCKWISE INTEGRAL"),
+    (0x2232, "CLOCKWISE CONTOUR INTEGRAL"),
+    (0x2233, "ANTICLOCKWISE CONTOUR INTEGRAL"),
+    (0x2234, "THEREFORE"),
+    (0x2235, "BECAUSE"),
+    (0x2236, "RATIO"),
+    (0x2237, "PROPORTION"),
+    (0x2238, "DOT MINUS"),
+    (0x2239, "EXCESS"),
+    (0x223A, "GEOMETRIC PROPORTION"),
+    (0x223B, "HOMOTHETIC"),
+    (0x223C, "TILDE OPERATOR"),
+    (0x223D, "REVERSED TILDE"),
+    (0x223E, "INVERTED LAZY S"),
+    (0x223F, "SINE WAVE"),
+    (0x2240, "WREATH PRODUCT"),
+    (0x2241, "NOT TILDE"),
+    (0x2242, "MINUS TILDE"),
+    (0x2243, "ASYMPTOTICALLY EQUAL TO"),
+    (0x2244, "NOT ASYMPTOTICALLY EQUAL TO"),
+    (0x2245, "APPROXIMATELY EQUAL TO"),
+    (0x2246, "APPROXIMATELY BUT NOT ACTUALLY EQUAL TO"),
+    (0x2247, "NEITHER APPROXIMATELY NOR ACTUALLY EQUAL TO"),
+    (0x2248, "ALMOST EQUAL TO"),
+    (0x2249, "NOT ALMOST EQUAL TO"),
+    (0x224A, "ALMOST EQUAL OR EQUAL TO"),
+    (0x224B, "TRIPLE TILDE"),
+    (0x224C, "ALL EQUAL TO"),
+    (0x224D, "EQUIVALENT TO"),
+    (0x224E, "GEOMETRICALLY EQUIVALENT TO"),
+    (0x224F, "DIFFERENCE BETWEEN"),
+    (0x2250, "APPROACHES THE LIMIT"),
+    (0x2251, "GEOMETRICALLY EQUAL TO"),
+    (0x2252, "APPROXIMATELY EQUAL TO OR THE IMAGE OF"),
+    (0x2253, "IMAGE OF OR APPROXIMATELY EQUAL TO"),
+    (0x2254, "COLON EQUALS"),
+    (0x2255, "EQUALS COLON"),
+    (0x2256, "RING IN EQUAL TO"),
+    (0x2257, "RING EQUAL TO"),
+    (0x2258, "CORRESPONDS TO"),
+    (0x2259, "ESTIMATES"),
+    (0x225A, "EQUIANGULAR TO"),
+    (0x225B, "STAR EQUALS"),
+    (0x225C, "DELTA EQUAL TO"),
+    (0x225D, "EQUAL TO BY DEFINITION"),
+    (0x225E, "MEASURED BY"),
+    (0x225F, "QUESTIONED EQUAL TO"),
+    (0x2260, "NOT EQUAL TO"),
+    (0x2261, "IDENTICAL TO"),
+    (0x2262, "NOT IDENTICAL TO"),
+    (0x2263, "STRICTLY EQUIVALENT TO"),
+    (0x2264, "LESS-THAN OR EQUAL TO"),
+    (0x2265, "GREATER-THAN OR EQUAL TO"),
+    (0x2266, "LESS-THAN OVER EQUAL TO"),
+    (0x2267, "GREATER-THAN OVER EQUAL TO"),
+    (0x2268, "LESS-THAN BUT NOT EQUAL TO"),
+    (0x2269, "GREATER-THAN BUT NOT EQUAL TO"),
+    (0x226A, "MUCH LESS-THAN"),
+    (0x226B, "MUCH GREATER-THAN"),
+    (0x226C, "BETWEEN"),
+    (0x226D, "NOT EQUIVALENT TO"),
+    (0x226E, "NOT LESS-THAN"),
+    (0x226F, "NOT GREATER-THAN"),
+    (0x2270, "NEITHER LESS-THAN NOR EQUAL TO"),
+    (0x2271, "NEITHER GREATER-THAN NOR EQUAL TO"),
+    (0x2272, "LESS-THAN OR EQUIVALENT TO"),
+    (0x2273, "GREATER-THAN OR EQUIVALENT TO"),
+    (0x2274, "NEITHER LESS-THAN NOR EQUIVALENT TO"),
+    (0x2275, "NEITHER GREATER-THAN NOR EQUIVALENT TO"),
+    (0x2276, "LESS-THAN OR GREATER-THAN"),
+    (0x2277, "GREATER-THAN OR LESS-THAN"),
+    (0x2278, "NEITHER LESS-THAN NOR GREATER-THAN"),
+    (0x2279, "NEITHER GREATER-THAN NOR LESS-THAN"),
+    (0x227A, "PRECEDES"),
+    (0x227B, "SUCCEEDS"),
+    (0x227C, "PRECEDES OR EQUAL TO"),
+    (0x227D, "SUCCEEDS OR EQUAL TO"),
+    (0x227E, "PRECEDES OR EQUIVALENT TO"),
+    (0x227F, "SUCCEEDS OR EQUIVALENT TO"),
+    (0x2280, "DOES NOT PRECEDE"),
+    (0x2281, "DOES NOT SUCCEED"),
+    (0x2282, "SUBSET OF"),
+    (0x2283, "SUPERSET OF"),
+    (0x2284, "NOT A SUBSET OF"),
+    (0x2285, "NOT A SUPERSET OF"),
+    (0x2286, "SUBSET OF OR EQUAL TO"),
+    (0x2287, "SUPERSET OF OR EQUAL TO"),
+    (0x2288, "NEITHER A SUBSET OF NOR EQUAL TO"),
+    (0x2289, "NEITHER A SUPERSET OF NOR EQUAL TO"),
+    (0x228A, "SUBSET OF WITH NOT EQUAL TO"),
+    (0x228B, "SUPERSET OF WITH NOT EQUAL TO"),
+    (0x228C, "MULTISET"),
+    (0x228D, "MULTISET MULTIPLICATION"),
+    (0x228E, "MULTISET UNION"),
+    (0x228F, "SQUARE IMAGE OF"),
+    (0x2290, "SQUARE ORIGINAL OF"),
+    (0x2291, "SQUARE IMAGE OF OR EQUAL TO"),
+    (0x2292, "SQUARE ORIGINAL OF OR EQUAL TO"),
+    (0x2293, "SQUARE CAP"),
+    (0x2294, "SQUARE CUP"),
+    (0x2295, "CIRCLED PLUS"),
+    (0x2296, "CIRCLED MINUS"),
+    (0x2297, "CIRCLED TIMES"),
+    (0x2298, "CIRCLED DIVISION SLASH"),
+    (0x2299, "CIRCLED DOT OPERATOR"),
+    (0x229A, "CIRCLED RING OPERATOR"),
+    (0x229B, "CIRCLED ASTERISK OPERATOR"),
+    (0x229C, "CIRCLED EQUALS"),
+    (0x229D, "CIRCLED DASH"),
+    (0x229E, "SQUARED PLUS"),
+    (0x229F, "SQUARED MINUS"),
+    (0x22A0, "SQUARED TIMES"),
+    (0x22A1, "SQUARED DOT OPERATOR"),
+    (0x22A2, "RIGHT TACK"),
+    (0x22A3, "LEFT TACK"),
+    (0x22A4, "DOWN TACK"),
+    (0x22A5, "UP TACK"),
+    (0x22A6, "ASSERTION"),
+    (0x22A7, "MODELS"),
+    (0x22A8, "TRUE"),
+    (0x22A9, "FORCES"),
+    (0x22AA, "TRIPLE VERTICAL BAR RIGHT TURNSTILE"),
+    (0x22AB, "DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE"),
+    (0x22AC, "DOES NOT PROVE"),
+    (0x22AD, "NOT TRUE"),
+    (0x22AE, "DOES NOT FORCE"),
+    (0x22AF, "NEGATED DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE"),
+    (0x22B0, "PRECEDES UNDER RELATION"),
+    (0x22B1, "SUCCEEDS UNDER RELATION"),
+    (0x22B2, "NORMAL SUBGROUP OF"),
+    (0x22B3, "CONTAINS AS NORMAL SUBGROUP"),
+    (0x22B4, "NORMAL SUBGROUP OF OR EQUAL TO"),
+    (0x22B5, "CONTAINS AS NORMAL SUBGROUP OR EQUAL TO"),
+    (0x22B6, "ORIGINAL OF"),
+    (0x22B7, "IMAGE OF"),
+    (0x22B8, "MULTIMAP"),
+    (0x22B9, "HERMITIAN CONJUGATE MATRIX"),
+    (0x22BA, "INTERCALATE"),
+    (0x22BB, "XOR"),
+    (0x22BC, "NAND"),
+    (0x22BD, "NOR"),
+    (0x22BE, "RIGHT ANGLE WITH ARC"),
+    (0x22BF, "RIGHT TRIANGLE"),
+    (0x22C0, "N-ARY LOGICAL AND"),
+    (0x22C1, "N-ARY LOGICAL OR"),
+    (0x22C2, "N-ARY INTERSECTION"),
+    (0x22C3, "N-ARY UNION"),
+    (0x22C4, "DIAMOND OPERATOR"),
+    (0x22C5, "DOT OPERATOR"),
+    (0x22C6, "STAR OPERATOR"),
+    (0x22C7, "DIVISION TIMES"),
+    (0x22C8, "BOWTIE"),
+    (0x22C9, "LEFT NORMAL FACTOR SEMIDIRECT PRODUCT"),
+    (0x22CA, "RIGHT NORMAL FACTOR SEMIDIRECT PRODUCT"),
+    (0x22CB, "LEFT SEMIDIRECT PRODUCT"),
+    (0x22CC, "RIGHT SEMIDIRECT PRODUCT"),
+    (0x22CD, "REVERSED TILDE EQUALS"),
+    (0x22CE, "CURLY LOGICAL OR"),
+    (0x22CF, "CURLY LOGICAL AND"),
+    (0x22D0, "DOUBLE SUBSET"),
+    (0x22D1, "DOUBLE SUPERSET"),
+    (0x22D2, "DOUBLE INTERSECTION"),
+    (0x22D3, "DOUBLE UNION"),
+    (0x22D4, "PITCHFORK"),
+    (0x22D5, "EQUAL AND PARALLEL TO"),
+    (0x22D6, "LESS-THAN WITH DOT"),
+    (0x22D7, "GREATER-THAN WITH DOT"),
+    (0x22D8, "VERY MUCH LESS-THAN"),
+    (0x22D9, "VERY MUCH GREATER-THAN"),
+    (0x22DA, "LESS-THAN EQUAL TO OR GREATER-THAN"),
+    (0x22DB, "GREATER-THAN EQUAL TO OR LESS-THAN"),
+    (0x22DC, "EQUAL TO OR LESS-THAN"),
+    (0x22DD, "EQUAL TO OR GREATER-THAN"),
+    (0x22DE, "EQUAL TO OR PRECEDES"),
+    (0x22DF, "EQUAL TO OR SUCCEEDS"),
+    (0x22E0, "DOES NOT PRECEDE OR EQUAL"),
+    (0x22E1, "DOES NOT SUCCEED OR EQUAL"),
+    (0x22E2, "NOT SQUARE IMAGE OF OR EQUAL TO"),
+    (0x22E3, "NOT SQUARE ORIGINAL OF OR EQUAL TO"),
+    (0x22E4, "SQUARE IMAGE OF OR NOT EQUAL TO"),
+    (0x22E5, "SQUARE ORIGINAL OF OR NOT EQUAL TO"),
+    (0x22E6, "LESS-THAN BUT NOT EQUIVALENT TO"),
+    (0x22E7, "GREATER-THAN BUT NOT EQUIVALENT TO"),
+    (0x22E8, "PRECEDES BUT NOT EQUIVALENT TO"),
+    (0x22E9, "SUCCEEDS BUT NOT EQUIVALENT TO"),
+    (0x22EA, "NOT NORMAL SUBGROUP OF"),
+    (0x22EB, "DOES NOT CONTAIN AS NORMAL SUBGROUP"),
+    (0x22EC, "NOT NORMAL SUBGROUP OF OR EQUAL TO"),
+    (0x22ED, "DOES NOT CONTAIN AS NORMAL SUBGROUP OR EQUAL"),
+    (0x22EE, "VERTICAL ELLIPSIS"),
+    (0x22EF, "MIDLINE HORIZONTAL ELLIPSIS"),
+    (0x22F0, "UP RIGHT DIAGONAL ELLIPSIS"),
+    (0x22F1, "DOWN RIGHT DIAGONAL ELLIPSIS"),
+    (0x22F2, "ELEMENT OF WITH LONG HORIZONTAL STROKE"),
+    (0x22F3, "ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE"),
+    (0x22F4, "SMALL ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE"),
+    (0x22F5, "ELEMENT OF WITH DOT ABOVE"),
+    (0x22F6, "ELEMENT OF WITH OVERBAR"),
+    (0x22F7, "SMALL ELEMENT OF WITH OVERBAR"),
+    (0x22F8, "ELEMENT OF WITH UNDERBAR"),
+    (0x22F9, "ELEMENT OF WITH TWO HORIZONTAL STROKES"),
+    (0x22FA, "CONTAINS WITH LONG HORIZONTAL STROKE"),
+    (0x22FB, "CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE"),
+    (0x22FC, "SMALL CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE"),
+    (0x22FD, "CONTAINS WITH OVERBAR"),
+    (0x22FE, "SMALL CONTAINS WITH OVERBAR"),
+    (0x22FF, "Z NOTATION BAG MEMBERSHIP"),
+    (0x2300, "DIAMETER SIGN"),
+    (0x2301, "ELECTRIC ARROW"),
+    (0x2302, "HOUSE"),
+    (0x2303, "UP ARROWHEAD"),
+    (0x2304, "DOWN ARROWHEAD"),
+    (0x2305, "PROJECTIVE"),
+    (0x2306, "PERSPECTIVE"),
+    (0x2307, "WAVY LINE"),
+    (0x2308, "LEFT CEILING"),
+    (0x2309, "RIGHT CEILING"),
+    (0x230A, "LEFT FLOOR"),
+    (0x230B, "RIGHT FLOOR"),
+    (0x230C, "BOTTOM RIGHT CROP"),
+    (0x230D, "BOTTOM LEFT CROP"),
+    (0x230E, "TOP RIGHT CROP"),
+    (0x230F, "TOP LEFT CROP"),
+    (0x2310, "REVERSED NOT SIGN"),
+    (0x2311, "SQUARE LOZENGE"),
+    (0x2312, "ARC"),
+    (0x2313, "SEGMENT"),
+    (0x2314, "SECTOR"),
+    (0x2315, "TELEPHONE RECORDER"),
+    (0x2316, "POSITION INDICATOR"),
+    (0x2317, "VIEWDATA SQUARE"),
+    (0x2318, "PLACE OF INTEREST SIGN"),
+    (0x2319, "TURNED NOT SIGN"),
+    (0x231A, "WATCH"),
+    (0x231B, "HOURGLASS"),
+    (0x231C, "TOP LEFT CORNER"),
+    (0x231D, "TOP RIGHT CORNER"),
+    (0x231E, "BOTTOM LEFT CORNER"),
+    (0x231F, "BOTTOM RIGHT CORNER"),
+    (0x2320, "TOP HALF INTEGRAL"),
+    (0x2321, "BOTTOM HALF INTEGRAL"),
+    (0x2322, "FROWN"),
+    (0x2323, "SMILE"),
+    (0x2324, "UP ARROWHEAD BETWEEN TWO HORIZONTAL BARS"),
+    (0x2325, "OPTION KEY"),
+    (0x2326, "ERASE TO THE RIGHT"),
+    (0x2327, "X IN A RECTANGLE BOX"),
+    (0x2328, "KEYBOARD"),
+    (0x2329, "LEFT-POINTING ANGLE BRACKET"),
+    (0x232A, "RIGHT-POINTING ANGLE BRACKET"),
+    (0x232B, "ERASE TO THE LEFT"),
+    (0x232C, "BENZENE RING"),
+    (0x232D, "CYLINDRICITY"),
+    (0x232E, "ALL AROUND-PROFILE"),
+    (0x232F, "SYMMETRY"),
+    (0x2330, "TOTAL RUNOUT"),
+    (0x2331, "DIMENSION ORIGIN"),
+    (0x2332, "CONICAL TAPER"),
+    (0x2333, "SLOPE"),
+    (0x2334, "COUNTERBORE"),
+    (0x2335, "COUNTERSINK"),
+    (0x2336, "APL FUNCTIONAL SYMBOL I-BEAM"),
+    (0x2337, "APL FUNCTIONAL SYMBOL SQUISH QUAD"),
+    (0x2338, "APL FUNCTIONAL SYMBOL QUAD EQUAL"),
+    (0x2339, "APL FUNCTIONAL SYMBOL QUAD DIVIDE"),
+    (0x233A, "APL FUNCTIONAL SYMBOL QUAD DIAMOND"),
+    (0x233B, "APL FUNCTIONAL SYMBOL QUAD JOT"),
+    (0x233C, "APL FUNCTIONAL SYMBOL QUAD CIRCLE"),
+    (0x233D, "APL FUNCTIONAL SYMBOL CIRCLE STILE"),
+    (0x233E, "APL FUNCTIONAL SYMBOL CIRCLE JOT"),
+    (0x233F, "APL FUNCTIONAL SYMBOL SLASH BAR"),
+    (0x2340, "APL FUNCTIONAL SYMBOL BACKSLASH BAR"),
+    (0x2341, "APL FUNCTIONAL SYMBOL QUAD SLASH"),
+    (0x2342, "APL FUNCTIONAL SYMBOL QUAD BACKSLASH"),
+    (0x2343, "APL FUNCTIONAL SYMBOL QUAD LESS-THAN"),
+    (0x2344, "APL FUNCTIONAL SYMBOL QUAD GREATER-THAN"),
+    (0x2345, "APL FUNCTIONAL SYMBOL LEFTWARDS VANE"),
+    (0x2346, "APL FUNCTIONAL SYMBOL RIGHTWARDS VANE"),
+    (0x2347, "APL FUNCTIONAL SYMBOL QUAD LEFTWARDS ARROW"),
+    (0x2348, "APL FUNCTIONAL SYMBOL QUAD RIGHTWARDS ARROW"),
+    (0x2349, "APL FUNCTIONAL SYMBOL CIRCLE BACKSLASH"),
+    (0x234A, "APL FUNCTIONAL SYMBOL DOWN TACK UNDERBAR"),
+    (0x234B, "APL FUNCTIONAL SYMBOL DELTA STILE"),
+    (0x234C, "APL FUNCTIONAL SYMBOL QUAD DOWN CARET"),
+    (0x234D, "APL FUNCTIONAL SYMBOL QUAD DELTA"),
+    (0x234E, "APL FUNCTIONAL SYMBOL DOWN TACK JOT"),
+    (0x234F, "APL FUNCTIONAL SYMBOL UPWARDS VANE"),
+    (0x2350, "APL FUNCTIONAL SYMBOL QUAD UPWARDS ARROW"),
+    (0x2351, "APL FUNCTIONAL SYMBOL UP TACK OVERBAR"),
+    (0x2352, "APL FUNCTIONAL SYMBOL DEL STILE"),
+    (0x2353, "APL FUNCTIONAL SYMBOL QUAD UP CARET"),
+    (0x2354, "APL FUNCTIONAL SYMBOL QUAD DEL"),
+    (0x2355, "APL FUNCTIONAL SYMBOL UP TACK JOT"),
+    (0x2356, "APL FUNCTIONAL SYMBOL DOWNWARDS VANE"),
+    (0x2357, "APL FUNCTIONAL SYMBOL QUAD DOWNWARDS ARROW"),
+    (0x2358, "APL FUNCTIONAL SYMBOL QUOTE UNDERBAR"),
+    (0x2359, "APL FUNCTIONAL SYMBOL DELTA UNDERBAR"),
+    (0x235A, "APL FUNCTIONAL SYMBOL DIAMOND UNDERBAR"),
+    (0x235B, "APL FUNCTIONAL SYMBOL JOT UNDERBAR"),
+    (0x235C, "APL FUNCTIONAL SYMBOL CIRCLE UNDERBAR"),
+    (0x235D, "APL FUNCTIONAL SYMBOL UP SHOE JOT"),
+    (0x235E, "APL FUNCTIONAL SYMBOL QUOTE QUAD"),
+    (0x235F, "APL FUNCTIONAL SYMBOL CIRCLE STAR"),
+    (0x2360, "APL FUNCTIONAL SYMBOL QUAD COLON"),
+    (0x2361, "APL FUNCTIONAL SYMBOL UP TACK DIAERESIS"),
+    (0x2362, "APL FUNCTIONAL SYMBOL DEL DIAERESIS"),
+    (0x2363, "APL FUNCTIONAL SYMBOL STAR DIAERESIS"),
+    (0x2364, "APL FUNCTIONAL SYMBOL JOT DIAERESIS"),
+    (0x2365, "APL FUNCTIONAL SYMBOL CIRCLE DIAERESIS"),
+    (0x2366, "APL FUNCTIONAL SYMBOL DOWN SHOE STILE"),
+    (0x2367, "APL FUNCTIONAL SYMBOL LEFT SHOE STILE"),
+    (0x2368, "APL FUNCTIONAL SYMBOL TILDE DIAERESIS"),
+    (0x2369, "APL FUNCTIONAL SYMBOL GREATER-THAN DIAERESIS"),
+    (0x236A, "APL FUNCTIONAL SYMBOL COMMA BAR"),
+    (0x236B, "APL FUNCTIONAL SYMBOL DEL TILDE"),
+    (0x236C, "APL FUNCTIONAL SYMBOL ZILDE"),
+    (0x236D, "APL FUNCTIONAL SYMBOL STILE TILDE"),
+    (0x236E, "APL FUNCTIONAL SYMBOL SEMICOLON UNDERBAR"),
+    (0x236F, "APL FUNCTIONAL SYMBOL QUAD NOT EQUAL"),
+    (0x2370, "APL FUNCTIONAL SYMBOL QUAD QUESTION"),
+    (0x2371, "APL FUNCTIONAL SYMBOL DOWN CARET TILDE"),
+    (0x2372, "APL FUNCTIONAL SYMBOL UP CARET TILDE"),
+    (0x2373, "APL FUNCTIONAL SYMBOL IOTA"),
+    (0x2374, "APL FUNCTIONAL SYMBOL RHO"),
+    (0x2375, "APL FUNCTIONAL SYMBOL OMEGA"),
+    (0x2376, "APL FUNCTIONAL SYMBOL ALPHA UNDERBAR"),
+    (0x2377, "APL FUNCTIONAL SYMBOL EPSILON UNDERBAR"),
+    (0x2378, "APL FUNCTIONAL SYMBOL IOTA UNDERBAR"),
+    (0x2379, "APL FUNCTIONAL SYMBOL OMEGA UNDERBAR"),
+    (0x237A, "APL FUNCTIONAL SYMBOL ALPHA"),
+    (0x237B, "NOT CHECK MARK"),
+    (0x237C, "RIGHT ANGLE WITH DOWNWARDS ZIGZAG ARROW"),
+    (0x237D, "SHOULDERED OPEN BOX"),
+    (0x237E, "BELL SYMBOL"),
+    (0x237F, "VERTICAL LINE WITH MIDDLE DOT"),
+    (0x2380, "INSERTION SYMBOL"),
+    (0x2381, "CONTINUOUS UNDERLINE SYMBOL"),
+    (0x2382, "DISCONTINUOUS UNDERLINE SYMBOL"),
+    (0x2383, "EMPHASIS SYMBOL"),
+    (0x2384, "COMPOSITION SYMBOL"),
+    (0x2385, "WHITE SQUARE WITH CENTRE VERTICAL LINE"),
+    (0x2386, "ENTER SYMBOL"),
+    (0x2387, "ALTERNATIVE KEY SYMBOL"),
+    (0x2388, "HELM SYMBOL"),
+    (0x2389, "CIRCLED HORIZONTAL BAR WITH NOTCH"),
+    (0x238A, "CIRCLED TRIANGLE DOWN"),
+    (0x238B, "BROKEN CIRCLE WITH NORTHWEST ARROW"),
+    (0x238C, "UNDO SYMBOL"),
+    (0x238D, "MONOSTABLE SYMBOL"),
+    (0x238E, "HYSTERESIS SYMBOL"),
+    (0x238F, "OPEN-CIRCUIT-OUTPUT H-TYPE SYMBOL"),
+    (0x2390, "OPEN-CIRCUIT-OUTPUT L-TYPE SYMBOL"),
+    (0x2391, "PASSIVE-PULL-DOWN-OUTPUT SYMBOL"),
+    (0x2392, "PASSIVE-PULL-UP-OUTPUT SYMBOL"),
+    (0x2393, "DIRECT CURRENT SYMBOL FORM TWO"),
+    (0x2394, "SOFTWARE-FUNCTION SYMBOL"),
+    (0x2395, "APL FUNCTIONAL SYMBOL QUAD"),
+    (0x2396, "DECIMAL SEPARATOR KEY SYMBOL"),
+    (0x2397, "PREVIOUS PAGE"),
+    (0x2398, "NEXT PAGE"),
+    (0x2399, "PRINT SCREEN SYMBOL"),
+    (0x239A, "CLEAR SCREEN SYMBOL"),
+    (0x239B, "LEFT PARENTHESIS UPPER HOOK"),
+    (0x239C, "LEFT PARENTHESIS EXTENSION"),
+    (0x239D, "LEFT PARENTHESIS LOWER HOOK"),
+    (0x239E, "RIGHT PARENTHESIS UPPER HOOK"),
+    (0x239F, "RIGHT PARENTHESIS EXTENSION"),
+    (0x23A0, "RIGHT PARENTHESIS LOWER HOOK"),
+    (0x23A1, "LEFT SQUARE BRACKET UPPER CORNER"),
+    (0x23A2, "LEFT SQUARE BRACKET EXTENSION"),
+    (0x23A3, "LEFT SQUARE BRACKET LOWER CORNER"),
+    (0x23A4, "RIGHT SQUARE BRACKET UPPER CORNER"),
+    (0x23A5, "RIGHT SQUARE BRACKET EXTENSION"),
+    (0x23A6, "RIGHT SQUARE BRACKET LOWER CORNER"),
+    (0x23A7, "LEFT CURLY BRACKET UPPER HOOK"),
+    (0x23A8, "LEFT CURLY BRACKET MIDDLE PIECE"),
+    (0x23A9, "LEFT CURLY BRACKET LOWER HOOK"),
+    (0x23AA, "CURLY BRACKET EXTENSION"),
+    (0x23AB, "RIGHT CURLY BRACKET UPPER HOOK"),
+    (0x23AC, "RIGHT CURLY BRACKET MIDDLE PIECE"),
+    (0x23AD, "RIGHT CURLY BRACKET LOWER HOOK"),
+    (0x23AE, "INTEGRAL EXTENSION"),
+    (0x23AF, "HORIZONTAL LINE EXTENSION"),
+    (0x23B0, "UPPER LEFT OR LOWER RIGHT CURLY BRACKET SECTION"),
+    (0x23B1, "UPPER RIGHT OR LOWER LEFT CURLY BRACKET SECTION"),
+    (0x23B2, "SUMMATION TOP"),
+    (0x23B3, "SUMMATION BOTTOM"),
+    (0x23B4, "TOP SQUARE BRACKET"),
+    (0x23B5, "BOTTOM SQUARE BRACKET"),
+    (0x23B6, "BOTTOM SQUARE BRACKET OVER TOP SQUARE BRACKET"),
+    (0x23B7, "RADICAL SYMBOL BOTTOM"),
+    (0x23B8, "LEFT VERTICAL BOX LINE"),
+    (0x23B9, "RIGHT VERTICAL BOX LINE"),
+    (0x23BA, "HORIZONTAL SCAN LINE-1"),
+    (0x23BB, "HORIZONTAL SCAN LINE-3"),
+    (0x23BC, "HORIZONTAL SCAN LINE-7"),
+    (0x23BD, "HORIZONTAL SCAN LINE-9"),
+    (0x23BE, "DENTISTRY SYMBOL LIGHT VERTICAL AND TOP RIGHT"),
+    (0x23BF, "DENTISTRY SYMBOL LIGHT VERTICAL AND BOTTOM RIGHT"),
+    (0x23C0, "DENTISTRY SYMBOL LIGHT VERTICAL WITH CIRCLE"),
+    (0x23C1, "DENTISTRY SYMBOL LIGHT DOWN AND HORIZONTAL WITH CIRCLE"),
+    (0x23C2, "DENTISTRY SYMBOL LIGHT UP AND HORIZONTAL WITH CIRCLE"),
+    (0x23C3, "DENTISTRY SYMBOL LIGHT VERTICAL WITH TRIANGLE"),
+    (0x23C4, "DENTISTRY SYMBOL LIGHT DOWN AND HORIZONTAL WITH TRIANGLE"),
+    (0x23C5, "DENTISTRY SYMBOL LIGHT UP AND HORIZONTAL WITH TRIANGLE"),
+    (0x23C6, "DENTISTRY SYMBOL LIGHT VERTICAL AND WAVE"),
+    (0x23C7, "DENTISTRY SYMBOL LIGHT DOWN AND HORIZONTAL WITH WAVE"),
+    (0x23C8, "DENTISTRY SYMBOL LIGHT UP AND HORIZONTAL WITH WAVE"),
+    (0x23C9, "DENTISTRY SYMBOL LIGHT DOWN AND HORIZONTAL"),
+    (0x23CA, "DENTISTRY SYMBOL LIGHT UP AND HORIZONTAL"),
+    (0x23CB, "DENTISTRY SYMBOL LIGHT VERTICAL AND TOP LEFT"),
+    (0x23CC, "DENTISTRY SYMBOL LIGHT VERTICAL AND BOTTOM LEFT"),
+    (0x23CD, "SQUARE FOOT"),
+    (0x23CE, "RETURN SYMBOL"),
+    (0x23CF, "EJECT SYMBOL"),
+    (0x23D0, "VERTICAL LINE EXTENSION"),
+    (0x23D1, "METRICAL BREVE"),
+    (0x23D2, "METRICAL LONG OVER SHORT"),
+    (0x23D3, "METRICAL SHORT OVER LONG"),
+    (0x23D4, "METRICAL LONG OVER TWO SHORTS"),
+    (0x23D5, "METRICAL TWO SHORTS OVER LONG"),
+    (0x23D6, "METRICAL TWO SHORTS JOINED"),
+    (0x23D7, "METRICAL TRISEME"),
+    (0x23D8, "METRICAL TETRASEME"),
+    (0x23D9, "METRICAL PENTASEME"),
+    (0x23DA, "EARTH GROUND"),
+    (0x23DB, "FUSE"),
+    (0x23DC, "TOP PARENTHESIS"),
+    (0x23DD, "BOTTOM PARENTHESIS"),
+    (0x23DE, "TOP CURLY BRACKET"),
+    (0x23DF, "BOTTOM CURLY BRACKET"),
+    (0x23E0, "TOP TORTOISE SHELL BRACKET"),
+    (0x23E1, "BOTTOM TORTOISE SHELL BRACKET"),
+    (0x23E2, "WHITE TRAPEZIUM"),
+    (0x23E3, "BENZENE RING WITH CIRCLE"),
+    (0x23E4, "STRAIGHTNESS"),
+    (0x23E5, "FLATNESS"),
+    (0x23E6, "AC CURRENT"),
+    (0x23E7, "ELECTRICAL INTERSECTION"),
+    (0x23E8, "DECIMAL EXPONENT SYMBOL"),
+    (0x23E9, "BLACK RIGHT-POINTING DOUBLE TRIANGLE"),
+    (0x23EA, "BLACK LEFT-POINTING DOUBLE TRIANGLE"),
+    (0x23EB, "BLACK UP-POINTING DOUBLE TRIANGLE"),
+    (0x23EC, "BLACK DOWN-POINTING DOUBLE TRIANGLE"),
+    (0x23ED, "BLACK RIGHT-POINTING DOUBLE TRIANGLE WITH VERTICAL BAR"),
+    (0x23EE, "BLACK LEFT-POINTING DOUBLE TRIANGLE WITH VERTICAL BAR"),
+    (0x23EF, "BLACK RIGHT-POINTING TRIANGLE WITH DOUBLE VERTICAL BAR"),
+    (0x23F0, "ALARM CLOCK"),
+    (0x23F1, "STOPWATCH"),
+    (0x23F2, "TIMER CLOCK"),
+    (0x23F3, "HOURGLASS WITH FLOWING SAND"),
+    (0x23F4, "BLACK MEDIUM LEFT-POINTING TRIANGLE"),
+    (0x23F5, "BLACK MEDIUM RIGHT-POINTING TRIANGLE"),
+    (0x23F6, "BLACK MEDIUM UP-POINTING TRIANGLE"),
+    (0x23F7, "BLACK MEDIUM DOWN-POINTING TRIANGLE"),
+    (0x23F8, "DOUBLE VERTICAL BAR"),
+    (0x23F9, "BLACK SQUARE FOR STOP"),
+    (0x23FA, "BLACK CIRCLE FOR RECORD"),
+    (0x23FB, "POWER SYMBOL"),
+    (0x23FC, "POWER ON-OFF SYMBOL"),
+    (0x23FD, "POWER ON SYMBOL"),
+    (0x23FE, "POWER SLEEP SYMBOL"),
+    (0x23FF, "OBSERVER EYE SYMBOL"),
+    (0x2500, "BOX DRAWINGS LIGHT HORIZONTAL"),
+    (0x2501, "BOX DRAWINGS HEAVY HORIZONTAL"),
+    (0x2502, "BOX DRAWINGS LIGHT VERTICAL"),
+    (0x2503, "BOX DRAWINGS HEAVY VERTICAL"),
+    (0x2504, "BOX DRAWINGS LIGHT TRIPLE DASH HORIZONTAL"),
+    (0x2505, "BOX DRAWINGS HEAVY TRIPLE DASH HORIZONTAL"),
+    (0x2506, "BOX DRAWINGS LIGHT TRIPLE DASH VERTICAL"),
+    (0x2507, "BOX DRAWINGS HEAVY TRIPLE DASH VERTICAL"),
+    (0x2508, "BOX DRAWINGS LIGHT QUADRUPLE DASH HORIZONTAL"),
+    (0x2509, "BOX DRAWINGS HEAVY QUADRUPLE DASH HORIZONTAL"),
+    (0x250A, "BOX DRAWINGS LIGHT QUADRUPLE DASH VERTICAL"),
+    (0x250B, "BOX DRAWINGS HEAVY QUADRUPLE DASH VERTICAL"),
+    (0x250C, "BOX DRAWINGS LIGHT DOWN AND RIGHT"),
+    (0x250D, "BOX DRAWINGS DOWN LIGHT AND RIGHT HEAVY"),
+    (0x250E, "BOX DRAWINGS DOWN HEAVY AND RIGHT LIGHT"),
+    (0x250F, "BOX DRAWINGS HEAVY DOWN AND RIGHT"),
+    (0x2510, "BOX DRAWINGS LIGHT DOWN AND LEFT"),
+    (0x2511, "BOX DRAWINGS DOWN LIGHT AND LEFT HEAVY"),
+    (0x2512, "BOX DRAWINGS DOWN HEAVY AND LEFT LIGHT"),
+    (0x2513, "BOX DRAWINGS HEAVY DOWN AND LEFT"),
+    (0x2514, "BOX DRAWINGS LIGHT UP AND RIGHT"),
+    (0x2515, "BOX DRAWINGS UP LIGHT AND RIGHT HEAVY"),
+    (0x2516, "BOX DRAWINGS UP HEAVY AND RIGHT LIGHT"),
+    (0x2517, "BOX DRAWINGS HEAVY UP AND RIGHT"),
+    (0x2518, "BOX DRAWINGS LIGHT UP AND LEFT"),
+    (0x2519, "BOX DRAWINGS UP LIGHT AND LEFT HEAVY"),
+    (0x251A, "BOX DRAWINGS UP HEAVY AND LEFT LIGHT"),
+    (0x251B, "BOX DRAWINGS HEAVY UP AND LEFT"),
+    (0x251C, "BOX DRAWINGS LIGHT VERTICAL AND RIGHT"),
+    (0x251D, "BOX DRAWINGS VERTICAL LIGHT AND RIGHT HEAVY"),
+    (0x251E, "BOX DRAWINGS UP HEAVY AND RIGHT DOWN LIGHT"),
+    (0x251F, "BOX DRAWINGS DOWN HEAVY AND RIGHT UP LIGHT"),
+    (0x2520, "BOX DRAWINGS VERTICAL HEAVY AND RIGHT LIGHT"),
+    (0x2521, "BOX DRAWINGS DOWN LIGHT AND RIGHT UP HEAVY"),
+    (0x2522, "BOX DRAWINGS UP LIGHT AND RIGHT DOWN HEAVY"),
+    (0x2523, "BOX DRAWINGS HEAVY VERTICAL AND RIGHT"),
+    (0x2524, "BOX DRAWINGS LIGHT VERTICAL AND LEFT"),
+    (0x2525, "BOX DRAWINGS VERTICAL LIGHT AND LEFT HEAVY"),
+    (0x2526, "BOX DRAWINGS UP HEAVY AND LEFT DOWN LIGHT"),
+    (0x2527, "BOX DRAWINGS DOWN HEAVY AND LEFT UP LIGHT"),
+    (0x2528, "BOX DRAWINGS VERTICAL HEAVY AND LEFT LIGHT"),
+    (0x2529, "BOX DRAWINGS DOWN LIGHT AND LEFT UP HEAVY"),
+    (0x252A, "BOX DRAWINGS UP LIGHT AND LEFT DOWN HEAVY"),
+    (0x252B, "BOX DRAWINGS HEAVY VERTICAL AND LEFT"),
+    (0x252C, "BOX DRAWINGS LIGHT DOWN AND HORIZONTAL"),
+    (0x252D, "BOX DRAWINGS LEFT HEAVY AND RIGHT DOWN LIGHT"),
+    (0x252E, "BOX DRAWINGS RIGHT HEAVY AND LEFT DOWN LIGHT"),
+    (0x252F, "BOX DRAWINGS DOWN LIGHT AND HORIZONTAL HEAVY"),
+    (0x2530, "BOX DRAWINGS DOWN HEAVY AND HORIZONTAL LIGHT"),
+    (0x2531, "BOX DRAWINGS RIGHT LIGHT AND LEFT DOWN HEAVY"),
+    (0x2532, "BOX DRAWINGS LEFT LIGHT AND RIGHT DOWN HEAVY"),
+    (0x2533, "BOX DRAWINGS HEAVY DOWN AND HORIZONTAL"),
+    (0x2534, "BOX DRAWINGS LIGHT UP AND HORIZONTAL"),
+    (0x2535, "BOX DRAWINGS LEFT HEAVY AND RIGHT UP LIGHT"),
+    (0x2536, "BOX DRAWINGS RIGHT HEAVY AND LEFT UP LIGHT"),
+    (0x2537, "BOX DRAWINGS UP LIGHT AND HORIZONTAL HEAVY"),
+    (0x2538, "BOX DRAWINGS UP HEAVY AND HORIZONTAL LIGHT"),
+    (0x2539, "BOX DRAWINGS RIGHT LIGHT AND LEFT UP HEAVY"),
+    (0x253A, "BOX DRAWINGS LEFT LIGHT AND RIGHT UP HEAVY"),
+    (0x253B, "BOX DRAWINGS HEAVY UP AND HORIZONTAL"),
+    (0x253C, "BOX DRAWINGS LIGHT VERTICAL AND HORIZONTAL"),
+    (0x253D, "BOX DRAWINGS LEFT HEAVY AND RIGHT VERTICAL LIGHT"),
+    (0x253E, "BOX DRAWINGS RIGHT HEAVY AND LEFT VERTICAL LIGHT"),
+    (0x253F, "BOX DRAWINGS VERTICAL LIGHT AND HORIZONTAL HEAVY"),
+    (0x2540, "BOX DRAWINGS UP HEAVY AND DOWN HORIZONTAL LIGHT"),
+    (0x2541, "BOX DRAWINGS DOWN HEAVY AND UP HORIZONTAL LIGHT"),
+    (0x2542, "BOX DRAWINGS VERTICAL HEAVY AND HORIZONTAL LIGHT"),
+    (0x2543, "BOX DRAWINGS LEFT UP HEAVY AND RIGHT DOWN LIGHT"),
+    (0x2544, "BOX DRAWINGS RIGHT UP HEAVY AND LEFT DOWN LIGHT"),
+    (0x2545, "BOX DRAWINGS LEFT DOWN HEAVY AND RIGHT UP LIGHT"),
+    (0x2546, "BOX DRAWINGS RIGHT DOWN HEAVY AND LEFT UP LIGHT"),
+    (0x2547, "BOX DRAWINGS DOWN LIGHT AND UP HORIZONTAL HEAVY"),
+    (0x2548, "BOX DRAWINGS UP LIGHT AND DOWN HORIZONTAL HEAVY"),
+    (0x2549, "BOX DRAWINGS RIGHT LIGHT AND LEFT VERTICAL HEAVY"),
+    (0x254A, "BOX DRAWINGS LEFT LIGHT AND RIGHT VERTICAL HEAVY"),
+    (0x254B, "BOX DRAWINGS HEAVY VERTICAL AND HORIZONTAL"),
+    (0x254C, "BOX DRAWINGS LIGHT DOUBLE DASH HORIZONTAL"),
+    (0x254D, "BOX DRAWINGS HEAVY DOUBLE DASH HORIZONTAL"),
+    (0x254E, "BOX DRAWINGS LIGHT DOUBLE DASH VERTICAL"),
+    (0x254F, "BOX DRAWINGS HEAVY DOUBLE DASH VERTICAL"),
+    (0x2550, "BOX DRAWINGS DOUBLE HORIZONTAL"),
+    (0x2551, "BOX DRAWINGS DOUBLE VERTICAL"),
+    (0x2552, "BOX DRAWINGS DOWN SINGLE AND RIGHT DOUBLE"),
+    (0x2553, "BOX DRAWINGS DOWN DOUBLE AND RIGHT SINGLE"),
+    (0x2554, "BOX DRAWINGS DOUBLE DOWN AND RIGHT"),
+    (0x2555, "BOX DRAWINGS DOWN SINGLE AND LEFT DOUBLE"),
+    (0x2556, "BOX DRAWINGS DOWN DOUBLE AND LEFT SINGLE"),
+    (0x2557, "BOX DRAWINGS DOUBLE DOWN AND LEFT"),
+    (0x2558, "BOX DRAWINGS UP SINGLE AND RIGHT DOUBLE"),
+    (0x2559, "BOX DRAWINGS UP DOUBLE AND RIGHT SINGLE"),
+    (0x255A, "BOX DRAWINGS DOUBLE UP AND RIGHT"),
+    (0x255B, "BOX DRAWINGS UP SINGLE AND LEFT DOUBLE"),
+    (0x255C, "BOX DRAWINGS UP DOUBLE AND LEFT SINGLE"),
+    (0x255D, "BOX DRAWINGS DOUBLE UP AND LEFT"),
+    (0x255E, "BOX DRAWINGS VERTICAL SINGLE AND RIGHT DOUBLE"),
+    (0x255F, "BOX DRAWINGS VERTICAL DOUBLE AND RIGHT SINGLE"),
+    (0x2560, "BOX DRAWINGS DOUBLE VERTICAL AND RIGHT"),
+    (0x2561, "BOX DRAWINGS VERTICAL SINGLE AND LEFT DOUBLE"),
+    (0x2562, "BOX DRAWINGS VERTICAL DOUBLE AND LEFT SINGLE"),
+    (0x2563, "BOX DRAWINGS DOUBLE VERTICAL AND LEFT"),
+    (0x2564, "BOX DRAWINGS DOWN SINGLE AND HORIZONTAL DOUBLE"),
+    (0x2565, "BOX DRAWINGS DOWN DOUBLE AND HORIZONTAL SINGLE"),
+    (0x2566, "BOX DRAWINGS DOUBLE DOWN AND HORIZONTAL"),
+    (0x2567, "BOX DRAWINGS UP SINGLE AND HORIZONTAL DOUBLE"),
+    (0x2568, "BOX DRAWINGS UP DOUBLE AND HORIZONTAL SINGLE"),
+    (0x2569, "BOX DRAWINGS DOUBLE UP AND HORIZONTAL"),
+    (0x256A, "BOX DRAWINGS VERTICAL SINGLE AND HORIZONTAL DOUBLE"),
+    (0x256B, "BOX DRAWINGS VERTICAL DOUBLE AND HORIZONTAL SINGLE"),
+    (0x256C, "BOX DRAWINGS DOUBLE VERTICAL AND HORIZONTAL"),
+    (0x256D, "BOX DRAWINGS LIGHT ARC DOWN AND RIGHT"),
+    (0x256E, "BOX DRAWINGS LIGHT ARC DOWN AND LEFT"),
+    (0x256F, "BOX DRAWINGS LIGHT ARC UP AND LEFT"),
+    (0x2570, "BOX DRAWINGS LIGHT ARC UP AND RIGHT"),
+    (0x2571, "BOX DRAWINGS LIGHT DIAGONAL UPPER RIGHT TO LOWER LEFT"),
+    (0x2572, "BOX DRAWINGS LIGHT DIAGONAL UPPER LEFT TO LOWER RIGHT"),
+    (0x2573, "BOX DRAWINGS LIGHT DIAGONAL CROSS"),
+    (0x2574, "BOX DRAWINGS LIGHT LEFT"),
+    (0x2575, "BOX DRAWINGS LIGHT UP"),
+    (0x2576, "BOX DRAWINGS LIGHT RIGHT"),
+    (0x2577, "BOX DRAWINGS LIGHT DOWN"),
+    (0x2578, "BOX DRAWINGS HEAVY LEFT"),
+    (0x2579, "BOX DRAWINGS HEAVY UP"),
+    (0x257A, "BOX DRAWINGS HEAVY RIGHT"),
+    (0x257B, "BOX DRAWINGS HEAVY DOWN"),
+    (0x257C, "BOX DRAWINGS LIGHT LEFT AND HEAVY RIGHT"),
+    (0x257D, "BOX DRAWINGS LIGHT UP AND HEAVY DOWN"),
+    (0x257E, "BOX DRAWINGS HEAVY LEFT AND LIGHT RIGHT"),
+    (0x257F, "BOX DRAWINGS HEAVY UP AND LIGHT DOWN"),
+    (0x2580, "UPPER HALF BLOCK"),
+    (0x2581, "LOWER ONE EIGHTH BLOCK"),
+    (0x2582, "LOWER ONE QUARTER BLOCK"),
+    (0x2583, "LOWER THREE EIGHTHS BLOCK"),
+    (0x2584, "LOWER HALF BLOCK"),
+    (0x2585, "LOWER FIVE EIGHTHS BLOCK"),
+    (0x2586, "LOWER THREE QUARTERS BLOCK"),
+    (0x2587, "LOWER SEVEN EIGHTHS BLOCK"),
+    (0x2588, "FULL BLOCK"),
+    (0x2589, "LEFT SEVEN EIGHTHS BLOCK"),
+    (0x258A, "LEFT THREE QUARTERS BLOCK"),
+    (0x258B, "LEFT FIVE EIGHTHS BLOCK"),
+    (0x258C, "LEFT HALF BLOCK"),
+    (0x258D, "LEFT THREE EIGHTHS BLOCK"),
+    (0x258E, "LEFT ONE QUARTER BLOCK"),
+    (0x258F, "LEFT ONE EIGHTH BLOCK"),
+    (0x2590, "RIGHT HALF BLOCK"),
+    (0x2591, "LIGHT SHADE"),
+    (0x2592, "MEDIUM SHADE"),
+    (0x2593, "DARK SHADE"),
+    (0x2594, "UPPER ONE EIGHTH BLOCK"),
+    (0x2595, "RIGHT ONE EIGHTH BLOCK"),
+    (0x2596, "QUADRANT LOWER LEFT"),
+    (0x2597, "QUADRANT LOWER RIGHT"),
+    (0x2598, "QUADRANT UPPER LEFT"),
+    (0x2599, "QUADRANT UPPER LEFT AND LOWER LEFT AND LOWER RIGHT"),
+    (0x259A, "QUADRANT UPPER LEFT AND LOWER RIGHT"),
+    (0x259B, "QUADRANT UPPER LEFT AND UPPER RIGHT AND LOWER LEFT"),
+    (0x259C, "QUADRANT UPPER LEFT AND UPPER RIGHT AND LOWER RIGHT"),
+    (0x259D, "QUADRANT UPPER RIGHT"),
+    (0x259E, "QUADRANT UPPER RIGHT AND LOWER LEFT"),
+    (0x259F, "QUADRANT UPPER RIGHT AND LOWER LEFT AND LOWER RIGHT"),
+    (0x25A0, "BLACK SQUARE"),
+    (0x25A1, "WHITE SQUARE"),
+    (0x25A2, "WHITE SQUARE WITH ROUNDED CORNERS"),
+    (0x25A3, "WHITE SQUARE CONTAINING BLACK SMALL SQUARE"),
+    (0x25A4, "SQUARE WITH HORIZONTAL FILL"),
+    (0x25A5, "SQUARE WITH VERTICAL FILL"),
+    (0x25A6, "SQUARE WITH ORTHOGONAL CROSSHATCH FILL"),
+    (0x25A7, "SQUARE WITH UPPER LEFT TO LOWER RIGHT FILL"),
+    (0x25A8, "SQUARE WITH UPPER RIGHT TO LOWER LEFT FILL"),
+    (0x25A9, "SQUARE WITH DIAGONAL CROSSHATCH FILL"),
+    (0x25AA, "BLACK SMALL SQUARE"),
+    (0x25AB, "WHITE SMALL SQUARE"),
+    (0x25AC, "BLACK RECTANGLE"),
+    (0x25AD, "WHITE RECTANGLE"),
+    (0x25AE, "BLACK VERTICAL RECTANGLE"),
+    (0x25AF, "WHITE VERTICAL RECTANGLE"),
+    (0x25B0, "BLACK PARALLELOGRAM"),
+    (0x25B1, "WHITE PARALLELOGRAM"),
+    (0x25B2, "BLACK UP-POINTING TRIANGLE"),
+    (0x25B3, "WHITE UP-POINTING TRIANGLE"),
+    (0x25B4, "BLACK UP-POINTING SMALL TRIANGLE"),
+    (0x25B5, "WHITE UP-POINTING SMALL TRIANGLE"),
+    (0x25B6, "BLACK RIGHT-POINTING TRIANGLE"),
+    (0x25B7, "WHITE RIGHT-POINTING TRIANGLE"),
+    (0x25B8, "BLACK RIGHT-POINTING SMALL TRIANGLE"),
+    (0x25B9, "WHITE RIGHT-POINTING SMALL TRIANGLE"),
+    (0x25BA, "BLACK RIGHT-POINTING POINTER"),
+    (0x25BB, "WHITE RIGHT-POINTING POINTER"),
+    (0x25BC, "BLACK DOWN-POINTING TRIANGLE"),
+    (0x25BD, "WHITE DOWN-POINTING TRIANGLE"),
+    (0x25BE, "BLACK DOWN-POINTING SMALL TRIANGLE"),
+    (0x25BF, "WHITE DOWN-POINTING SMALL TRIANGLE"),
+    (0x25C0, "BLACK LEFT-POINTING TRIANGLE"),
+    (0x25C1, "WHITE LEFT-POINTING TRIANGLE"),
+    (0x25C2, "BLACK LEFT-POINTING SMALL TRIANGLE"),
+    (0x25C3, "WHITE LEFT-POINTING SMALL TRIANGLE"),
+    (0x25C4, "BLACK LEFT-POINTING POINTER"),
+    (0x25C5, "WHITE LEFT-POINTING POINTER"),
+    (0x25C6, "BLACK DIAMOND"),
+    (0x25C7, "WHITE DIAMOND"),
+    (0x25C8, "WHITE DIAMOND CONTAINING BLACK SMALL DIAMOND"),
+    (0x25C9, "FISHEYE"),
+    (0x25CA, "LOZENGE"),
+    (0x25CB, "WHITE CIRCLE"),
+    (0x25CC, "DOTTED CIRCLE"),
+    (0x25CD, "CIRCLE WITH VERTICAL FILL"),
+    (0x25CE, "BULLSEYE"),
+    (0x25CF, "BLACK CIRCLE"),
+    (0x25D0, "CIRCLE WITH LEFT HALF BLACK"),
+    (0x25D1, "CIRCLE WITH RIGHT HALF BLACK"),
+    (0x25D2, "CIRCLE WITH LOWER HALF BLACK"),
+    (0x25D3, "CIRCLE WITH UPPER HALF BLACK"),
+    (0x25D4, "CIRCLE WITH UPPER RIGHT QUADRANT BLACK"),
+    (0x25D5, "CIRCLE WITH ALL BUT UPPER LEFT QUADRANT BLACK"),
+    (0x25D6, "LEFT HALF BLACK CIRCLE"),
+    (0x25D7, "RIGHT HALF BLACK CIRCLE"),
+    (0x25D8, "INVERSE BULLET"),
+    (0x25D9, "INVERSE WHITE CIRCLE"),
+    (0x25DA, "UPPER HALF INVERSE WHITE CIRCLE"),
+    (0x25DB, "LOWER HALF INVERSE WHITE CIRCLE"),
+    (0x25DC, "UPPER LEFT QUADRANT CIRCULAR ARC"),
+    (0x25DD, "UPPER RIGHT QUADRANT CIRCULAR ARC"),
+    (0x25DE, "LOWER RIGHT QUADRANT CIRCULAR ARC"),
+    (0x25DF, "LOWER LEFT QUADRANT CIRCULAR ARC"),
+    (0x25E0, "UPPER HALF CIRCLE"),
+    (0x25E1, "LOWER HALF CIRCLE"),
+    (0x25E2, "BLACK LOWER RIGHT TRIANGLE"),
+    (0x25E3, "BLACK LOWER LEFT TRIANGLE"),
+    (0x25E4, "BLACK UPPER LEFT TRIANGLE"),
+    (0x25E5, "BLACK UPPER RIGHT TRIANGLE"),
+    (0x25E6, "WHITE BULLET"),
+    (0x25E7, "SQUARE WITH LEFT HALF BLACK"),
+    (0x25E8, "SQUARE WITH RIGHT HALF BLACK"),
+    (0x25E9, "SQUARE WITH UPPER LEFT DIAGONAL HALF BLACK"),
+    (0x25EA, "SQUARE WITH LOWER RIGHT DIAGONAL HALF BLACK"),
+    (0x25EB, "WHITE SQUARE WITH VERTICAL BISECTING LINE"),
+    (0x25EC, "WHITE UP-POINTING TRIANGLE WITH DOT"),
+    (0x25ED, "UP-POINTING TRIANGLE WITH LEFT HALF BLACK"),
+    (0x25EE, "UP-POINTING TRIANGLE WITH RIGHT HALF BLACK"),
+    (0x25EF, "LARGE CIRCLE"),
+    (0x25F0, "WHITE SQUARE WITH UPPER LEFT QUADRANT"),
+    (0x25F1, "WHITE SQUARE WITH LOWER LEFT QUADRANT"),
+    (0x25F2, "WHITE SQUARE WITH LOWER RIGHT QUADRANT"),
+    (0x25F3, "WHITE SQUARE WITH UPPER RIGHT QUADRANT"),
+    (0x25F4, "WHITE CIRCLE WITH UPPER LEFT QUADRANT"),
+    (0x25F5, "WHITE CIRCLE WITH LOWER LEFT QUADRANT"),
+    (0x25F6, "WHITE CIRCLE WITH LOWER RIGHT QUADRANT"),
+    (0x25F7, "WHITE CIRCLE WITH UPPER RIGHT QUADRANT"),
+    (0x25F8, "UPPER LEFT TRIANGLE"),
+    (0x25F9, "UPPER RIGHT TRIANGLE"),
+    (0x25FA, "LOWER LEFT TRIANGLE"),
+    (0x25FB, "WHITE MEDIUM SQUARE"),
+    (0x25FC, "BLACK MEDIUM SQUARE"),
+    (0x25FD, "WHITE MEDIUM SMALL SQUARE"),
+    (0x25FE, "BLACK MEDIUM SMALL SQUARE"),
+    (0x25FF, "LOWER RIGHT TRIANGLE"),
+    (0x2600, "BLACK SUN WITH RAYS"),
+    (0x2601, "CLOUD"),
+    (0x2602, "UMBRELLA"),
+    (0x2603, "SNOWMAN"),
+    (0x2604, "COMET"),
+    (0x2605, "BLACK STAR"),
+    (0x2606, "WHITE STAR"),
+    (0x2607, "LIGHTNING"),
+    (0x2608, "THUNDERSTORM"),
+    (0x2609, "SUN"),
+    (0x260A, "ASCENDING NODE"),
+    (0x260B, "DESCENDING NODE"),
+    (0x260C, "CONJUNCTION"),
+    (0x260D, "OPPOSITION"),
+    (0x260E, "BLACK TELEPHONE"),
+    (0x260F, "WHITE TELEPHONE"),
+    (0x2610, "BALLOT BOX"),
+    (0x2611, "BALLOT BOX WITH CHECK"),
+    (0x2612, "BALLOT BOX WITH X"),
+    (0x2613, "SALTIRE"),
+    (0x2614, "UMBRELLA WITH RAIN DROPS"),
+    (0x2615, "HOT BEVERAGE"),
+    (0x2616, "WHITE SHOGI PIECE"),
+    (0x2617, "BLACK SHOGI PIECE"),
+    (0x2618, "SHAMROCK"),
+    (0x2619, "REVERSED ROTATED FLORAL HEART BULLET"),
+    (0x261A, "BLACK LEFT POINTING INDEX"),
+    (0x261B, "BLACK RIGHT POINTING INDEX"),
+    (0x261C, "WHITE LEFT POINTING INDEX"),
+    (0x261D, "WHITE UP POINTING INDEX"),
+    (0x261E, "WHITE RIGHT POINTING INDEX"),
+    (0x261F, "WHITE DOWN POINTING INDEX"),
+    (0x2620, "SKULL AND CROSSBONES"),
+    (0x2621, "CAUTION SIGN"),
+    (0x2622, "RADIOACTIVE SIGN"),
+    (0x2623, "BIOHAZARD SIGN"),
+    (0x2624, "CADUCEUS"),
+    (0x2625, "ANKH"),
+    (0x2626, "ORTHODOX CROSS"),
+    (0x2627, "CHI RHO"),
+    (0x2628, "CROSS OF LORRAINE"),
+    (0x2629, "CROSS OF JERUSALEM"),
+    (0x262A, "STAR AND CRESCENT"),
+    (0x262B, "FARSI SYMBOL"),
+    (0x262C, "ADI SHAKTI"),
+    (0x262D, "HAMMER AND SICKLE"),
+    (0x262E, "PEACE SYMBOL"),
+    (0x262F, "YIN YANG"),
+    (0x2630, "TRIGRAM FOR HEAVEN"),
+    (0x2631, "TRIGRAM FOR LAKE"),
+    (0x2632, "TRIGRAM FOR FIRE"),
+    (0x2633, "TRIGRAM FOR THUNDER"),
+    (0x2634, "TRIGRAM FOR WIND"),
+    (0x2635, "TRIGRAM FOR WATER"),
+    (0x2636, "TRIGRAM FOR MOUNTAIN"),
+    (0x2637, "TRIGRAM FOR EARTH"),
+    (0x2638, "WHEEL OF DHARMA"),
+    (0x2639, "WHITE FROWNING FACE"),
+    (0x263A, "WHITE SMILING FACE"),
+    (0x263B, "BLACK SMILING FACE"),
+    (0x263C, "WHITE SUN WITH RAYS"),
+    (0x263D, "FIRST QUARTER MOON"),
+    (0x263E, "LAST QUARTER MOON"),
+    (0x263F, "MERCURY"),
+    (0x2640, "FEMALE SIGN"),
+    (0x2641, "EARTH"),
+    (0x2642, "MALE SIGN"),
+    (0x2643, "JUPITER"),
+    (0x2644, "SATURN"),
+    (0x2645, "URANUS"),
+    (0x2646, "NEPTUNE"),
+    (0x2647, "PLUTO"),
+    (0x2648, "ARIES"),
+    (0x2649, "TAURUS"),
+    (0x264A, "GEMINI"),
+    (0x264B, "CANCER"),
+    (0x264C, "LEO"),
+    (0x264D, "VIRGO"),
+    (0x264E, "LIBRA"),
+    (0x264F, "SCORPIUS"),
+    (0x2650, "SAGITTARIUS"),
+    (0x2651, "CAPRICORN"),
+    (0x2652, "AQUARIUS"),
+    (0x2653, "PISCES"),
+    (0x2654, "WHITE CHESS KING"),
+    (0x2655, "WHITE CHESS QUEEN"),
+    (0x2656, "WHITE CHESS ROOK"),
+    (0x2657, "WHITE CHESS BISHOP"),
+    (0x2658, "WHITE CHESS KNIGHT"),
+    (0x2659, "WHITE CHESS PAWN"),
+    (0x265A, "BLACK CHESS KING"),
+    (0x265B, "BLACK CHESS QUEEN"),
+    (0x265C, "BLACK CHESS ROOK"),
+    (0x265D, "BLACK CHESS BISHOP"),
+    (0x265E, "BLACK CHESS KNIGHT"),
+    (0x265F, "BLACK CHESS PAWN"),
+    (0x2660, "BLACK SPADE SUIT"),
+    (0x2661, "WHITE HEART SUIT"),
+    (0x2662, "WHITE DIAMOND SUIT"),
+    (0x2663, "BLACK CLUB SUIT"),
+    (0x2664, "WHITE SPADE SUIT"),
+    (0x2665, "BLACK HEART SUIT"),
+    (0x2666, "BLACK DIAMOND SUIT"),
+    (0x2667, "WHITE CLUB SUIT"),
+    (0x2668, "HOT SPRINGS"),
+    (0x2669, "QUARTER NOTE"),
+    (0x266A, "EIGHTH NOTE"),
+    (0x266B, "BEAMED EIGHTH NOTES"),
+    (0x266C, "BEAMED SIXTEENTH NOTES"),
+    (0x266D, "MUSIC FLAT SIGN"),
+    (0x266E, "MUSIC NATURAL SIGN"),
+    (0x266F, "MUSIC SHARP SIGN"),
+    (0x2670, "WEST SYRIAC CROSS"),
+    (0x2671, "EAST SYRIAC CROSS"),
+    (0x2672, "UNIVERSAL RECYCLING SYMBOL"),
+    (0x2673, "RECYCLING SYMBOL FOR TYPE-1 PLASTICS"),
+    (0x2674, "RECYCLING SYMBOL FOR TYPE-2 PLASTICS"),
+    (0x2675, "RECYCLING SYMBOL FOR TYPE-3 PLASTICS"),
+    (0x2676, "RECYCLING SYMBOL FOR TYPE-4 PLASTICS"),
+    (0x2677, "RECYCLING SYMBOL FOR TYPE-5 PLASTICS"),
+    (0x2678, "RECYCLING SYMBOL FOR TYPE-6 PLASTICS"),
+    (0x2679, "RECYCLING SYMBOL FOR TYPE-7 PLASTICS"),
+    (0x267A, "RECYCLING SYMBOL FOR GENERIC MATERIALS"),
+    (0x267B, "BLACK UNIVERSAL RECYCLING SYMBOL"),
+    (0x267C, "RECYCLED PAPER SYMBOL"),
+    (0x267D, "PARTIALLY-RECYCLED PAPER SYMBOL"),
+    (0x267E, "PERMANENT PAPER SIGN"),
+    (0x267F, "WHEELCHAIR SYMBOL"),
+    (0x2680, "DIE FACE-1"),
+    (0x2681, "DIE FACE-2"),
+    (0x2682, "DIE FACE-3"),
+    (0x2683, "DIE FACE-4"),
+    (0x2684, "DIE FACE-5"),
+    (0x2685, "DIE FACE-6"),
+    (0x2686, "WHITE CIRCLE WITH DOT RIGHT"),
+    (0x2687, "WHITE CIRCLE WITH TWO DOTS"),
+    (0x2688, "BLACK CIRCLE WITH WHITE DOT RIGHT"),
+    (0x2689, "BLACK CIRCLE WITH TWO WHITE DOTS"),
+    (0x268A, "MONOGRAM FOR YANG"),
+    (0x268B, "MONOGRAM FOR YIN"),
+    (0x268C, "DIGRAM FOR GREATER YANG"),
+    (0x268D, "DIGRAM FOR LESSER YIN"),
+    (0x268E, "DIGRAM FOR LESSER YANG"),
+    (0x268F, "DIGRAM FOR GREATER YIN"),
+    (0x2690, "WHITE FLAG"),
+    (0x2691, "BLACK FLAG"),
+    (0x2692, "HAMMER AND PICK"),
+    (0x2693, "ANCHOR"),
+    (0x2694, "CROSSED SWORDS"),
+    (0x2695, "STAFF OF AESCULAPIUS"),
+    (0x2696, "SCALES"),
+    (0x2697, "ALEMBIC"),
+    (0x2698, "FLOWER"),
+    (0x2699, "GEAR"),
+    (0x269A, "STAFF OF HERMES"),
+    (0x269B, "ATOM SYMBOL"),
+    (0x269C, "FLEUR-DE-LIS"),
+    (0x269D, "OUTLINED WHITE STAR"),
+    (0x269E, "THREE LINES CONVERGING RIGHT"),
+    (0x269F, "THREE LINES CONVERGING LEFT"),
+    (0x26A0, "WARNING SIGN"),
+    (0x26A1, "HIGH VOLTAGE SIGN"),
+    (0x26A2, "DOUBLED FEMALE SIGN"),
+    (0x26A3, "DOUBLED MALE SIGN"),
+    (0x26A4, "INTERLOCKED FEMALE AND MALE SIGN"),
+    (0x26A5, "MALE AND FEMALE SIGN"),
+    (0x26A6, "MALE WITH STROKE SIGN"),
+    (0x26A7, "MALE WITH STROKE AND MALE AND FEMALE SIGN"),
+    (0x26A8, "VERTICAL MALE WITH STROKE SIGN"),
+    (0x26A9, "HORIZONTAL MALE WITH STROKE SIGN"),
+    (0x26AA, "MEDIUM WHITE CIRCLE"),
+    (0x26AB, "MEDIUM BLACK CIRCLE"),
+    (0x26AC, "MEDIUM SMALL WHITE CIRCLE"),
+    (0x26AD, "MARRIAGE SYMBOL"),
+    (0x26AE, "DIVORCE SYMBOL"),
+    (0x26AF, "UNMARRIED PARTNERSHIP SYMBOL"),
+    (0x26B0, "COFFIN"),
+    (0x26B1, "FUNERAL URN"),
+    (0x26B2, "NEUTER"),
+    (0x26B3, "CERES"),
+    (0x26B4, "PALLAS"),
+    (0x26B5, "JUNO"),
+    (0x26B6, "VESTA"),
+    (0x26B7, "CHIRON"),
+    (0x26B8, "BLACK MOON LILITH"),
+    (0x26B9, "SEXTILE"),
+    (0x26BA, "SEMISEXTILE"),
+    (0x26BB, "QUINCUNX"),
+    (0x26BC, "SESQUIQUADRATE"),
+    (0x26BD, "SOCCER BALL"),
+    (0x26BE, "BASEBALL"),
+    (0x26BF, "SQUARED KEY"),
+    (0x26C0, "WHITE DRAUGHTS MAN"),
+    (0x26C1, "WHITE DRAUGHTS KING"),
+    (0x26C2, "BLACK DRAUGHTS MAN"),
+    (0x26C3, "BLACK DRAUGHTS KING"),
+    (0x26C4, "SNOWMAN WITHOUT SNOW"),
+    (0x26C5, "SUN BEHIND CLOUD"),
+    (0x26C6, "RAIN"),
+    (0x26C7, "BLACK SNOWMAN"),
+    (0x26C8, "THUNDER CLOUD AND RAIN"),
+    (0x26C9, "TURNED WHITE SHOGI PIECE"),
+    (0x26CA, "TURNED BLACK SHOGI PIECE"),
+    (0x26CB, "WHITE DIAMOND IN SQUARE"),
+    (0x26CC, "CROSSING LANES"),
+    (0x26CD, "DISABLED CAR"),
+    (0x26CE, "OPHIUCHUS"),
+    (0x26CF, "PICK"),
+    (0x26D0, "CAR SLIDING"),
+    (0x26D1, "HELMET WITH WHITE CROSS"),
+    (0x26D2, "CIRCLED CROSSING LANES"),
+    (0x26D3, "CHAINS"),
+    (0x26D4, "NO ENTRY"),
+    (0x26D5, "ALTERNATE ONE-WAY LEFT WAY TRAFFIC"),
+    (0x26D6, "BLACK TWO-WAY LEFT WAY TRAFFIC"),
+    (0x26D7, "WHITE TWO-WAY LEFT WAY TRAFFIC"),
+    (0x26D8, "BLACK LEFT LANE MERGE"),
+    (0x26D9, "WHITE LEFT LANE MERGE"),
+    (0x26DA, "DRIVE SLOW SIGN"),
+    (0x26DB, "HEAVY WHITE DOWN-POINTING TRIANGLE"),
+    (0x26DC, "LEFT CLOSED ENTRY"),
+    (0x26DD, "SQUARED SALTIRE"),
+    (0x26DE, "FALLING DIAGONAL IN WHITE CIRCLE IN BLACK SQUARE"),
+    (0x26DF, "BLACK TRUCK"),
+    (0x26E0, "RESTRICTED LEFT ENTRY-1"),
+    (0x26E1, "RESTRICTED LEFT ENTRY-2"),
+    (0x26E2, "ASTRONOMICAL SYMBOL FOR URANUS"),
+    (0x26E3, "HEAVY CIRCLE WITH STROKE AND TWO DOTS ABOVE"),
+    (0x26E4, "PENTAGRAM"),
+    (0x26E5, "RIGHT-HANDED INTERLACED PENTAGRAM"),
+    (0x26E6, "LEFT-HANDED INTERLACED PENTAGRAM"),
+    (0x26E7, "INVERTED PENTAGRAM"),
+    (0x26E8, "BLACK CROSS ON SHIELD"),
+    (0x26E9, "SHINTO SHRINE"),
+    (0x26EA, "CHURCH"),
+    (0x26EB, "CASTLE"),
+    (0x26EC, "HISTORIC SITE"),
+    (0x26ED, "GEAR WITHOUT HUB"),
+    (0x26EE, "GEAR WITH HANDLES"),
+    (0x26EF, "MAP SYMBOL FOR LIGHTHOUSE"),
+    (0x26F0, "MOUNTAIN"),
+    (0x26F1, "UMBRELLA ON GROUND"),
+    (0x26F2, "FOUNTAIN"),
+    (0x26F3, "FLAG IN HOLE"),
+    (0x26F4, "FERRY"),
+    (0x26F5, "SAILBOAT"),
+    (0x26F6, "SQUARE FOUR CORNERS"),
+    (0x26F7, "SKIER"),
+    (0x26F8, "ICE SKATE"),
+    (0x26F9, "PERSON WITH BALL"),
+    (0x26FA, "TENT"),
+    (0x26FB, "JAPANESE BANK SYMBOL"),
+    (0x26FC, "HEADSTONE GRAVEYARD SYMBOL"),
+    (0x26FD, "FUEL PUMP"),
+    (0x26FE, "CUP ON BLACK SQUARE"),
+    (0x26FF, "WHITE FLAG WITH HORIZONTAL MIDDLE BLACK STRIPE"),
+    (0x2700, "BLACK SAFETY SCISSORS"),
+    (0x2701, "UPPER BLADE SCISSORS"),
+    (0x2702, "BLACK SCISSORS"),
+    (0x2703, "LOWER BLADE SCISSORS"),
+    (0x2704, "WHITE SCISSORS"),
+    (0x2705, "WHITE HEAVY CHECK MARK"),
+    (0x2706, "TELEPHONE LOCATION SIGN"),
+    (0x2707, "TAPE DRIVE"),
+    (0x2708, "AIRPLANE"),
+    (0x2709, "ENVELOPE"),
+    (0x270A, "RAISED FIST"),
+    (0x270B, "RAISED HAND"),
+    (0x270C, "VICTORY HAND"),
+    (0x270D, "WRITING HAND"),
+    (0x270E, "LOWER RIGHT PENCIL"),
+    (0x270F, "PENCIL"),
+    (0x2710, "UPPER RIGHT PENCIL"),
+    (0x2711, "WHITE NIB"),
+    (0x2712, "BLACK NIB"),
+    (0x2713, "CHECK MARK"),
+    (0x2714, "HEAVY CHECK MARK"),
+    (0x2715, "MULTIPLICATION X"),
+    (0x2716, "HEAVY MULTIPLICATION X"),
+    (0x2717, "BALLOT X"),
+    (0x2718, "HEAVY BALLOT X"),
+    (0x2719, "OUTLINED GREEK CROSS"),
+    (0x271A, "HEAVY GREEK CROSS"),
+    (0x271B, "OPEN CENTRE CROSS"),
+    (0x271C, "HEAVY OPEN CENTRE CROSS"),
+    (0x271D, "LATIN CROSS"),
+    (0x271E, "SHADOWED WHITE LATIN CROSS"),
+    (0x271F, "OUTLINED LATIN CROSS"),
+    (0x2720, "MALTESE CROSS"),
+    (0x2721, "STAR OF DAVID"),
+    (0x2722, "FOUR TEARDROP-SPOKED ASTERISK"),
+    (0x2723, "FOUR BALLOON-SPOKED ASTERISK"),
+    (0x2724, "HEAVY FOUR BALLOON-SPOKED ASTERISK"),
+    (0x2725, "FOUR CLUB-SPOKED ASTERISK"),
+    (0x2726, "BLACK FOUR POINTED STAR"),
+    (0x2727, "WHITE FOUR POINTED STAR"),
+    (0x2728, "SPARKLES"),
+    (0x2729, "STRESS OUTLINED WHITE STAR"),
+    (0x272A, "CIRCLED WHITE STAR"),
+    (0x272B, "OPEN CENTRE BLACK STAR"),
+    (0x272C, "BLACK CENTRE WHITE STAR"),
+    (0x272D, "OUTLINED BLACK STAR"),
+    (0x272E, "HEAVY OUTLINED BLACK STAR"),
+    (0x272F, "PINWHEEL STAR"),
+    (0x2730, "SHADOWED WHITE STAR"),
+    (0x2731, "HEAVY ASTERISK"),
+    (0x2732, "OPEN CENTRE ASTERISK"),
+    (0x2733, "EIGHT SPOKED ASTERISK"),
+    (0x2734, "EIGHT POINTED BLACK STAR"),
+    (0x2735, "EIGHT POINTED PINWHEEL STAR"),
+    (0x2736, "SIX POINTED BLACK STAR"),
+    (0x2737, "EIGHT POINTED RECTILINEAR BLACK STAR"),
+    (0x2738, "HEAVY EIGHT POINTED RECTILINEAR BLACK STAR"),
+    (0x2739, "TWELVE POINTED BLACK STAR"),
+    (0x273A, "SIXTEEN POINTED ASTERISK"),
+    (0x273B, "TEARDROP-SPOKED ASTERISK"),
+    (0x273C, "OPEN CENTRE TEARDROP-SPOKED ASTERISK"),
+    (0x273D, "HEAVY TEARDROP-SPOKED ASTERISK"),
+    (0x273E, "SIX PETALLED BLACK AND WHITE FLORETTE"),
+    (0x273F, "BLACK FLORETTE"),
+    (0x2740, "WHITE FLORETTE"),
+    (0x2741, "EIGHT PETALLED OUTLINED BLACK FLORETTE"),
+    (0x2742, "CIRCLED OPEN CENTRE EIGHT POINTED STAR"),
+    (0x2743, "HEAVY TEARDROP-SPOKED PINWHEEL ASTERISK"),
+    (0x2744, "SNOWFLAKE"),
+    (0x2745, "TIGHT TRIFOLIATE SNOWFLAKE"),
+    (0x2746, "HEAVY CHEVRON SNOWFLAKE"),
+    (0x2747, "SPARKLE"),
+    (0x2748, "HEAVY SPARKLE"),
+    (0x2749, "BALLOON-SPOKED ASTERISK"),
+    (0x274A, "EIGHT TEARDROP-SPOKED PROPELLER ASTERISK"),
+    (0x274B, "HEAVY EIGHT TEARDROP-SPOKED PROPELLER ASTERISK"),
+    (0x274C, "CROSS MARK"),
+    (0x274D, "SHADOWED WHITE CIRCLE"),
+    (0x274E, "NEGATIVE SQUARED CROSS MARK"),
+    (0x274F, "LOWER RIGHT DROP-SHADOWED WHITE SQUARE"),
+    (0x2750, "UPPER RIGHT DROP-SHADOWED WHITE SQUARE"),
+    (0x2751, "LOWER RIGHT SHADOWED WHITE SQUARE"),
+    (0x2752, "UPPER RIGHT SHADOWED WHITE SQUARE"),
+    (0x2753, "BLACK QUESTION MARK ORNAMENT"),
+    (0x2754, "WHITE QUESTION MARK ORNAMENT"),
+    (0x2755, "WHITE EXCLAMATION MARK ORNAMENT"),
+    (0x2756, "BLACK DIAMOND MINUS WHITE X"),
+    (0x2757, "HEAVY EXCLAMATION MARK SYMBOL"),
+    (0x2758, "LIGHT VERTICAL BAR"),
+    (0x2759, "MEDIUM VERTICAL BAR"),
+    (0x275A, "HEAVY VERTICAL BAR"),
+    (0x275B, "HEAVY SINGLE TURNED COMMA QUOTATION MARK ORNAMENT"),
+    (0x275C, "HEAVY SINGLE COMMA QUOTATION MARK ORNAMENT"),
+    (0x275D, "HEAVY DOUBLE TURNED COMMA QUOTATION MARK ORNAMENT"),
+    (0x275E, "HEAVY DOUBLE COMMA QUOTATION MARK ORNAMENT"),
+    (0x275F, "HEAVY LOW SINGLE COMMA QUOTATION MARK ORNAMENT"),
+    (0x2760, "HEAVY LOW DOUBLE COMMA QUOTATION MARK ORNAMENT"),
+    (0x2761, "CURVED STEM PARAGRAPH SIGN ORNAMENT"),
+    (0x2762, "HEAVY EXCLAMATION MARK ORNAMENT"),
+    (0x2763, "HEAVY HEART EXCLAMATION MARK ORNAMENT"),
+    (0x2764, "HEAVY BLACK HEART"),
+    (0x2765, "ROTATED HEAVY BLACK HEART BULLET"),
+    (0x2766, "FLORAL HEART"),
+    (0x2767, "ROTATED FLORAL HEART BULLET"),
+    (0x2768, "MEDIUM LEFT PARENTHESIS ORNAMENT"),
+    (0x2769, "MEDIUM RIGHT PARENTHESIS ORNAMENT"),
+    (0x276A, "MEDIUM FLATTENED LEFT PARENTHESIS ORNAMENT"),
+    (0x276B, "MEDIUM FLATTENED RIGHT PARENTHESIS ORNAMENT"),
+    (0x276C, "MEDIUM LEFT-POINTING ANGLE BRACKET ORNAMENT"),
+    (0x276D, "MEDIUM RIGHT-POINTING ANGLE BRACKET ORNAMENT"),
+    (0x276E, "HEAVY LEFT-POINTING ANGLE QUOTATION MARK ORNAMENT"),
+    (0x276F, "HEAVY RIGHT-POINTING ANGLE QUOTATION MARK ORNAMENT"),
+    (0x2770, "HEAVY LEFT-POINTING ANGLE BRACKET ORNAMENT"),
+    (0x2771, "HEAVY RIGHT-POINTING ANGLE BRACKET ORNAMENT"),
+    (0x2772, "LIGHT LEFT TORTOISE SHELL BRACKET ORNAMENT"),
+    (0x2773, "LIGHT RIGHT TORTOISE SHELL BRACKET ORNAMENT"),
+    (0x2774, "MEDIUM LEFT CURLY BRACKET ORNAMENT"),
+    (0x2775, "MEDIUM RIGHT CURLY BRACKET ORNAMENT"),
+    (0x2776, "DINGBAT NEGATIVE CIRCLED DIGIT ONE"),
+    (0x2777, "DINGBAT NEGATIVE CIRCLED DIGIT TWO"),
+    (0x2778, "DINGBAT NEGATIVE CIRCLED DIGIT THREE"),
+    (0x2779, "DINGBAT NEGATIVE CIRCLED DIGIT FOUR"),
+    (0x277A, "DINGBAT NEGATIVE CIRCLED DIGIT FIVE"),
+    (0x277B, "DINGBAT NEGATIVE CIRCLED DIGIT SIX"),
+    (0x277C, "DINGBAT NEGATIVE CIRCLED DIGIT SEVEN"),
+    (0x277D, "DINGBAT NEGATIVE CIRCLED DIGIT EIGHT"),
+    (0x277E, "DINGBAT NEGATIVE CIRCLED DIGIT NINE"),
+    (0x277F, "DINGBAT NEGATIVE CIRCLED NUMBER TEN"),
+    (0x2780, "DINGBAT CIRCLED SANS-SERIF DIGIT ONE"),
+    (0x2781, "DINGBAT CIRCLED SANS-SERIF DIGIT TWO"),
+    (0x2782, "DINGBAT CIRCLED SANS-SERIF DIGIT THREE"),
+    (0x2783, "DINGBAT CIRCLED SANS-SERIF DIGIT FOUR"),
+    (0x2784, "DINGBAT CIRCLED SANS-SERIF DIGIT FIVE"),
+    (0x2785, "DINGBAT CIRCLED SANS-SERIF DIGIT SIX"),
+    (0x2786, "DINGBAT CIRCLED SANS-SERIF DIGIT SEVEN"),
+    (0x2787, "DINGBAT CIRCLED SANS-SERIF DIGIT EIGHT"),
+    (0x2788, "DINGBAT CIRCLED SANS-SERIF DIGIT NINE"),
+    (0x2789, "DINGBAT CIRCLED SANS-SERIF NUMBER TEN"),
+    (0x278A, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT ONE"),
+    (0x278B, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT TWO"),
+    (0x278C, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT THREE"),
+    (0x278D, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT FOUR"),
+    (0x278E, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT FIVE"),
+    (0x278F, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT SIX"),
+    (0x2790, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT SEVEN"),
+    (0x2791, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT EIGHT"),
+    (0x2792, "DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT NINE"),
+    (0x2793, "DINGBAT NEGATIVE CIRCLED SANS-SERIF NUMBER TEN"),
+    (0x2794, "HEAVY WIDE-HEADED RIGHTWARDS ARROW"),
+    (0x2795, "HEAVY PLUS SIGN"),
+    (0x2796, "HEAVY MINUS SIGN"),
+    (0x2797, "HEAVY DIVISION SIGN"),
+    (0x2798, "HEAVY SOUTH EAST ARROW"),
+    (0x2799, "HEAVY RIGHTWARDS ARROW"),
+    (0x279A, "HEAVY NORTH EAST ARROW"),
+    (0x279B, "DRAFTING POINT RIGHTWARDS ARROW"),
+    (0x279C, "HEAVY ROUND-TIPPED RIGHTWARDS ARROW"),
+    (0x279D, "TRIANGLE-HEADED RIGHTWARDS ARROW"),
+    (0x279E, "HEAVY TRIANGLE-HEADED RIGHTWARDS ARROW"),
+    (0x279F, "DASHED TRIANGLE-HEADED RIGHTWARDS ARROW"),
+    (0x27A0, "HEAVY DASHED TRIANGLE-HEADED RIGHTWARDS ARROW"),
+    (0x27A1, "BLACK RIGHTWARDS ARROW"),
+    (0x27A2, "THREE-D TOP-LIGHTED RIGHTWARDS ARROWHEAD"),
+    (0x27A3, "THREE-D BOTTOM-LIGHTED RIGHTWARDS ARROWHEAD"),
+    (0x27A4, "BLACK RIGHTWARDS ARROWHEAD"),
+    (0x27A5, "HEAVY BLACK CURVED DOWNWARDS AND RIGHTWARDS ARROW"),
+    (0x27A6, "HEAVY BLACK CURVED UPWARDS AND RIGHTWARDS ARROW"),
+    (0x27A7, "SQUAT BLACK RIGHTWARDS ARROW"),
+    (0x27A8, "HEAVY CONCAVE-POINTED BLACK RIGHTWARDS ARROW"),
+    (0x27A9, "RIGHT-SHADED WHITE RIGHTWARDS ARROW"),
+    (0x27AA, "LEFT-SHADED WHITE RIGHTWARDS ARROW"),
+    (0x27AB, "BACK-TILTED SHADOWED WHITE RIGHTWARDS ARROW"),
+    (0x27AC, "FRONT-TILTED SHADOWED WHITE RIGHTWARDS ARROW"),
+    (0x27AD, "HEAVY LOWER RIGHT-SHADOWED WHITE RIGHTWARDS ARROW"),
+    (0x27AE, "HEAVY UPPER RIGHT-SHADOWED WHITE RIGHTWARDS ARROW"),
+    (0x27AF, "NOTCHED LOWER RIGHT-SHADOWED WHITE RIGHTWARDS ARROW"),
+    (0x27B0, "CURLY LOOP"),
+    (0x27B1, "NOTCHED UPPER RIGHT-SHADOWED WHITE RIGHTWARDS ARROW"),
+    (0x27B2, "CIRCLED HEAVY WHITE RIGHTWARDS ARROW"),
+    (0x27B3, "WHITE-FEATHERED RIGHTWARDS ARROW"),
+    (0x27B4, "BLACK-FEATHERED SOUTH EAST ARROW"),
+    (0x27B5, "BLACK-FEATHERED RIGHTWARDS ARROW"),
+    (0x27B6, "BLACK-FEATHERED NORTH EAST ARROW"),
+    (0x27B7, "HEAVY BLACK-FEATHERED SOUTH EAST ARROW"),
+    (0x27B8, "HEAVY BLACK-FEATHERED RIGHTWARDS ARROW"),
+    (0x27B9, "HEAVY BLACK-FEATHERED NORTH EAST ARROW"),
+    (0x27BA, "TEARDROP-BARBED RIGHTWARDS ARROW"),
+    (0x27BB, "HEAVY TEARDROP-SHANKED RIGHTWARDS ARROW"),
+    (0x27BC, "WEDGE-TAILED RIGHTWARDS ARROW"),
+    (0x27BD, "HEAVY WEDGE-TAILED RIGHTWARDS ARROW"),
+    (0x27BE, "OPEN-OUTLINED RIGHTWARDS ARROW"),
+    (0x27BF, "DOUBLE CURLY LOOP"),
+    (0x1F600, "GRINNING FACE"),
+    (0x1F601, "GRINNING FACE WITH SMILING EYES"),
+    (0x1F602, "FACE WITH TEARS OF JOY"),
+    (0x1F603, "SMILING FACE WITH OPEN MOUTH"),
+    (0x1F604, "SMILING FACE WITH OPEN MOUTH AND SMILING EYES"),
+    (0x1F605, "SMILING FACE WITH OPEN MOUTH AND COLD SWEAT"),
+    (0x1F606, "SMILING FACE WITH OPEN MOUTH AND TIGHTLY-CLOSED EYES"),
+    (0x1F607, "SMILING FACE WITH HALO"),
+    (0x1F608, "SMILING FACE WITH HORNS"),
+    (0x1F609, "WINKING FACE"),
+    (0x1F60A, "SMILING FACE WITH SMILING EYES"),
+    (0x1F60B, "FACE SAVOURING DELICIOUS FOOD"),
+    (0x1F60C, "RELIEVED FACE"),
+    (0x1F60D, "SMILING FACE WITH HEART-SHAPED EYES"),
+    (0x1F60E, "SMILING FACE WITH SUNGLASSES"),
+    (0x1F60F, "SMIRKING FACE"),
+    (0x1F610, "NEUTRAL FACE"),
+    (0x1F611, "EXPRESSIONLESS FACE"),
+    (0x1F612, "UNAMUSED FACE"),
+    (0x1F613, "FACE WITH COLD SWEAT"),
+    (0x1F614, "PENSIVE FACE"),
+    (0x1F615, "CONFUSED FACE"),
+    (0x1F616, "CONFOUNDED FACE"),
+    (0x1F617, "KISSING FACE"),
+    (0x1F618, "FACE THROWING A KISS"),
+    (0x1F619, "KISSING FACE WITH SMILING EYES"),
+    (0x1F61A, "KISSING FACE WITH CLOSED EYES"),
+    (0x1F61B, "FACE WITH STUCK-OUT TONGUE"),
+    (0x1F61C, "FACE WITH STUCK-OUT TONGUE AND WINKING EYE"),
+    (0x1F61D, "FACE WITH STUCK-OUT TONGUE AND TIGHTLY-CLOSED EYES"),
+    (0x1F61E, "DISAPPOINTED FACE"),
+    (0x1F61F, "WORRIED FACE"),
+    (0x1F620, "ANGRY FACE"),
+    (0x1F621, "POUTING FACE"),
+    (0x1F622, "CRYING FACE"),
+    (0x1F623, "PERSEVERING FACE"),
+    (0x1F624, "FACE WITH LOOK OF TRIUMPH"),
+    (0x1F625, "DISAPPOINTED BUT RELIEVED FACE"),
+    (0x1F626, "FROWNING FACE WITH OPEN MOUTH"),
+    (0x1F627, "ANGUISHED FACE"),
+    (0x1F628, "FEARFUL FACE"),
+    (0x1F629, "WEARY FACE"),
+    (0x1F62A, "SLEEPY FACE"),
+    (0x1F62B, "TIRED FACE"),
+    (0x1F62C, "GRIMACING FACE"),
+    (0x1F62D, "LOUDLY CRYING FACE"),
+    (0x1F62E, "FACE WITH OPEN MOUTH"),
+    (0x1F62F, "HUSHED FACE"),
+    (0x1F630, "FACE WITH OPEN MOUTH AND COLD SWEAT"),
+    (0x1F631, "FACE SCREAMING IN FEAR"),
+    (0x1F632, "ASTONISHED FACE"),
+    (0x1F633, "FLUSHED FACE"),
+    (0x1F634, "SLEEPING FACE"),
+    (0x1F635, "DIZZY FACE"),
+    (0x1F636, "FACE WITHOUT MOUTH"),
+    (0x1F637, "FACE WITH MEDICAL MASK"),
+    (0x1F638, "GRINNING CAT FACE WITH SMILING EYES"),
+    (0x1F639, "CAT FACE WITH TEARS OF JOY"),
+    (0x1F63A, "SMILING CAT FACE WITH OPEN MOUTH"),
+    (0x1F63B, "SMILING CAT FACE WITH HEART-SHAPED EYES"),
+    (0x1F63C, "CAT FACE WITH WRY SMILE"),
+    (0x1F63D, "KISSING CAT FACE WITH CLOSED EYES"),
+    (0x1F63E, "POUTING CAT FACE"),
+    (0x1F63F, "CRYING CAT FACE"),
+    (0x1F640, "WEARY CAT FACE"),
+    (0x1F641, "SLIGHTLY FROWNING FACE"),
+    (0x1F642, "SLIGHTLY SMILING FACE"),
+    (0x1F643, "UPSIDE-DOWN FACE"),
+    (0x1F644, "FACE WITH ROLLING EYES"),
+    (0x1F645, "FACE WITH NO GOOD GESTURE"),
+    (0x1F646, "FACE WITH OK GESTURE"),
+    (0x1F647, "PERSON BOWING DEEPLY"),
+    (0x1F648, "SEE-NO-EVIL MONKEY"),
+    (0x1F649, "HEAR-NO-EVIL MONKEY"),
+    (0x1F64A, "SPEAK-NO-EVIL MONKEY"),
+    (0x1F64B, "HAPPY PERSON RAISING ONE HAND"),
+    (0x1F64C, "PERSON RAISING BOTH HANDS IN CELEBRATION"),
+    (0x1F64D, "PERSON FROWNING"),
+    (0x1F64E, "PERSON WITH POUTING FACE"),
+    (0x1F64F, "PERSON WITH FOLDED HANDS"),
+];
+
+
+// Short names of the leading consonants, vowels, and trailing consonants that make up Hangul
+// syllable names.
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO",
+    "WE", "WI", "YU", "EU", "YI", "I",
+];
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+
+/// Returns the character's name, e.g. `LATIN SMALL LETTER A`, if it's embedded or can be
+/// computed. Control characters have no name.
+pub fn name(c: char) -> Option<String> {
+    let code = c as u32;
+    if let Ok(index) = NAMES.binary_search_by_key(&code, |(key, _)| *key) {
+        return Some(NAMES[index].1.to_string());
+    }
+    if CJK_UNIFIED_IDEOGRAPHS.iter().any(|(start, end)| (*start..=*end).contains(&code)) {
+        return Some(format!("CJK UNIFIED IDEOGRAPH-{:04X}", code));
+    }
+    if (0xAC00..=0xD7A3).contains(&code) {
+        let index = (code - 0xAC00) as usize;
+        let jamo = [JAMO_L[index / 588], JAMO_V[index % 588 / 28], JAMO_T[index % 28]];
+        return Some(format!("HANGUL SYLLABLE {}", jamo.concat()));
+    }
+    None
+}
+
+
+/// Returns the character's two-letter general category, e.g. `Ll`.
+pub fn category(c: char) -> &'static str {
+    let index = match CATEGORIES.binary_search_by_key(&(c as u32), |(start, _)| *start) {
+        Ok(index) => index,
+        Err(index) => index - 1,
+    };
+    CATEGORIES[index].1
+}
+
+
+/// Returns the long name of a general category, e.g. `Lowercase Letter` for `Ll`.
+pub fn category_name(category: &str) -> Option<&'static str> {
+    let name = match category {
+        "Lu" => "Uppercase Letter",
+        "Ll" => "Lowercase Letter",
+        "Lt" => "Titlecase Letter",
+        "Lm" => "Modifier Letter",
+        "Lo" => "Other Letter",
+        "Mn" => "Nonspacing Mark",
+        "Mc" => "Spacing Mark",
+        "Me" => "Enclosing Mark",
+        "Nd" => "Decimal Number",
+        "Nl" => "Letter Number",
+        "No" => "Other Number",
+        "Pc" => "Connector Punctuation",
+        "Pd" => "Dash Punctuation",
+        "Ps" => "Open Punctuation",
+        "Pe" => "Close Punctuation",
+        "Pi" => "Initial Punctuation",
+        "Pf" => "Final Punctuation",
+        "Po" => "Other Punctuation",
+        "Sm" => "Math Symbol",
+        "Sc" => "Currency Symbol",
+        "Sk" => "Modifier Symbol",
+        "So" => "Other Symbol",
+        "Zs" => "Space Separator",
+        "Zl" => "Line Separator",
+        "Zp" => "Paragraph Separator",
+        "Cc" => "Control",
+        "Cf" => "Format",
+        "Cs" => "Surrogate",
+        "Co" => "Private Use",
+        "Cn" => "Unassigned",
+        _ => return None,
+    };
+    Some(name)
+}
+
+
+/// Returns the name of the block containing the character, e.g. `Basic Latin`.
+pub fn block(c: char) -> Option<&'static str> {
+    let code = c as u32;
+    BLOCKS
+        .iter()
+        .find(|(start, end, _)| (*start..=*end).contains(&code))
+        .map(|(_, _, name)| *name)
+}
@@ -0,0 +1,515 @@
+use intspector::json;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+
+// Runs the binary with the specified arguments and returns its standard output.
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_intspector"))
+        .args(args)
+        .env_remove("INTSPECTOR_LANG")
+        .output()
+        .expect("failed to run intspector");
+    String::from_utf8(output.stdout).expect("output is not valid UTF-8")
+}
+
+
+#[test]
+fn duration_overflow() {
+    let ns = run(&["duration", "--unit", "ns", "170141183460469231731687303715884105728"]);
+    assert!(ns.contains("nan: 170,141,183,460,469,231,731,687,303,715,884,105,728"));
+    let s = run(&["duration", "--unit", "s", "170141183460469231731687303715884105728"]);
+    assert!(s.contains("Error: duration too large."));
+    assert!(!s.contains("missing unit"));
+    let too_large = run(&["duration", "--unit", "ns", "340282366920938463463374607431768211456"]);
+    assert!(too_large.contains("Error: duration too large."));
+    let expr = run(&["duration", "(1<<126)*4"]);
+    assert!(expr.contains("overflow in '*'"));
+}
+
+
+// Every command's output is a report, so every command supports every format. Each report is a
+// JSON object on a line of its own.
+#[test]
+fn json_format() {
+    let commands: &[&[&str]] = &[
+        &["42"],
+        &["-1", "0xff", "1+2"],
+        &["--histogram", "1", "2", "3", "4"],
+        &["--stats", "1", "2", "3"],
+        &["--sort", "asc", "3", "1", "2"],
+        &["--dedup", "1", "1", "2"],
+        &["--delta", "1", "4", "9"],
+        &["--fold", "xor", "1", "2", "3"],
+        &["--prefix", "1", "2", "3"],
+        &["--stride", "2", "4", "6"],
+        &["arith", "--message", "ab", "a:1", "b:2"],
+        &["asn1", "06032a0304", "1.2.840.113549"],
+        &["asn1", "--length", "8201f4"],
+        &["asn1", "--serial", "020101"],
+        &["asn1", "--key-usage", "03020186"],
+        &["asn1", "--ext-key-usage", "300a06082b06010505070301"],
+        &["barcode", "0123456"],
+        &["bit", "set", "8", "3"],
+        &["bswap", "0x1234"],
+        &["bytes", "0x12345678"],
+        &["channel", "6"],
+        &["check", "4111111111111111"],
+        &["check", "--iban", "GB82WEST12345698765432"],
+        &["checksum", "--target", "0x1234", "abc"],
+        &["chmod", "755", "rwxr-xr-x"],
+        &["choose", "10", "3"],
+        &["choose", "--multi", "2", "3"],
+        &["color", "0xff8800"],
+        &["cost", "300"],
+        &["country", "gb"],
+        &["country", "--mcc", "234"],
+        &["cp2l", "0x41"],
+        &["crc", "abc"],
+        &["date", "--between", "20240229", "20240301"],
+        &["decimal", "0x2238000000000001"],
+        &["diff", "12", "10"],
+        &["digits", "42"],
+        &["dns", "1"],
+        &["dns", "--flags", "0x8180"],
+        &["dump", "--hex", "48656c6c6f"],
+        &["duration", "90s"],
+        &["egyptian", "3/4"],
+        &["elias", "9"],
+        &["elias", "--decode", "00100"],
+        &["encode", "0x1234"],
+        &["entropy", "hello"],
+        &["exif", "6"],
+        &["exif", "--tag", "exposure", "1/250"],
+        &["factor", "360"],
+        &["factoradic", "100"],
+        &["field", "0xff", "7:4"],
+        &["float", "1.5"],
+        &["geo", "u4pruydqqvj"],
+        &["gray", "5"],
+        &["hash", "42"],
+        &["huffman", "a:5", "b:2", "c:1"],
+        &["ident", "0xcafebabe"],
+        &["ip", "192.168.0.1"],
+        &["jwt", "eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiIxMjMifQ.c2ln"],
+        &["ksuid", "0ujtsYcgvSTl8PAuAdqWYSMnLOv"],
+        &["l2cp", "A"],
+        &["mac", "00:1a:2b:3c:4d:5e"],
+        &["midi", "69"],
+        &["nbits", "0x1d00ffff"],
+        &["numerals", "1000"],
+        &["objectid", "507f1f77bcf86cd799439011"],
+        &["ops", "and", "12", "10"],
+        &["ptr", "0x7fff5fbff8c0"],
+        &["rand", "--bits", "16"],
+        &["repl"],
+        &["rice", "--param", "2", "9"],
+        &["rns", "--moduli", "3,5,7", "23"],
+        &["runs", "0xf0f0"],
+        &["sb", "3/7"],
+        &["sd", "42"],
+        &["selftest"],
+        &["seq", "1", "2", "4", "8"],
+        &["shift", "--left", "2", "0x81"],
+        &["snowflake", "1234567890123456789"],
+        &["synchsafe", "255"],
+        &["syscall", "60"],
+        &["time", "1700000000"],
+        &["ulid", "01ARZ3NDEKTSV4RRFFQ69G5FAV"],
+        &["utf8", "c3a9", "ff"],
+        &["varint", "300"],
+    ];
+    for command in commands {
+        let args: Vec<&str> = ["--format", "json"].iter().chain(command.iter()).copied().collect();
+        let output = run(&args);
+        assert!(command == &["repl"] || !output.is_empty(), "{:?} printed nothing", command);
+        for line in output.lines().filter(|line| !line.is_empty()) {
+            match json::parse(line) {
+                Some(json::Value::Object(_)) => {},
+                _ => panic!("{:?} printed a line that isn't a JSON object: {}", command, line),
+            }
+        }
+    }
+}
+
+
+// The global options end at the command name, so a command's own options can share their names.
+#[test]
+fn float_width_from_hex_digits() {
+    assert!(run(&["float", "0x7fc00000"]).contains("typ: f32\nhex: 0x7FC00000"));
+    assert!(run(&["float", "0x3C00"]).contains("typ: f16\nhex: 0x3C00"));
+    assert!(run(&["float", "0x3FF8_0000_0000_0000"]).contains("typ: f64"));
+    assert!(run(&["float", "0x3C0"]).contains("typ: f64"));
+    assert!(run(&["float", "--f64", "0x7fc00000"]).contains("val: 1.058925634e-314"));
+}
+
+
+#[test]
+fn global_options_stop_at_command() {
+    let text = run(&["decimal", "--format", "d32", "0x22500001"]);
+    assert!(text.contains("decimal32"));
+    assert!(!text.contains("Error"));
+    let json = run(&["--format", "json", "decimal", "--format", "d32", "0x22500001"]);
+    assert!(json.lines().all(|line| json::parse(line).is_some()));
+    assert!(json.contains("decimal32"));
+    let default = run(&["42", "--format", "json"]);
+    assert!(json::parse(default.trim()).is_some());
+}
+
+
+// Runs the binary with the specified arguments and input piped to its stdin, and returns its
+// standard output.
+fn run_piped(args: &[&str], input: &[u8]) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_intspector"))
+        .args(args)
+        .env_remove("INTSPECTOR_LANG")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run intspector");
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_vec();
+    let writer = std::thread::spawn(move || stdin.write_all(&input));
+    let output = child.wait_with_output().expect("failed to run intspector");
+    writer.join().unwrap().expect("failed to write to stdin");
+    String::from_utf8(output.stdout).expect("output is not valid UTF-8")
+}
+
+
+// Regular files are mapped into memory, while pipes are read in chunks. Both give the same output,
+// including for inputs spanning several chunks and rows spanning the end of the mapped range.
+#[test]
+fn mapped_and_piped_input() {
+    let bytes: Vec<u8> = (0..200_000u32).map(|i| (i * 7 % 251) as u8).collect();
+    let path = std::env::temp_dir().join(format!("intspector-{}.bin", std::process::id()));
+    std::fs::write(&path, &bytes).unwrap();
+    let file = path.to_str().unwrap();
+
+    let mapped = run(&["dump", file]);
+    assert_eq!(mapped, run_piped(&["dump"], &bytes));
+    assert_eq!(mapped.lines().count(), 200_000 / 6 + 1);
+    let range = ["--offset", "70001", "--length", "100000", "--width", "16"];
+    let mapped = run(&[&["dump"][..], &range, &[file]].concat());
+    assert_eq!(mapped, run_piped(&[&["dump"][..], &range].concat(), &bytes));
+    let first = format!("{:08X}  {:02X} {:02X}", 70001, bytes[70001], bytes[70002]);
+    assert!(mapped.starts_with(&first));
+    assert!(run(&["dump", "--offset", "200000", file]).contains("Error: no bytes at offset"));
+
+    let mapped = run(&["entropy", "--file", file]);
+    assert!(mapped.starts_with("len: 200000 bytes, 251 distinct values"), "{}", mapped);
+    assert_eq!(mapped, run_piped(&["entropy", "--file", "/dev/stdin"], &bytes));
+    std::fs::remove_file(&path).unwrap();
+}
+
+
+// The histogram bins by powers of two unless --bins gives a number of linear bins.
+#[test]
+fn histogram() {
+    let log2 = run_lib(&["--histogram", "1", "2", "3", "4", "100"]).unwrap();
+    assert!(log2.starts_with("cnt: 5 values\nmin: 1\nmax: 100\navg: 22\nmed: 3\n"));
+    assert!(log2.contains("\n   2..3  2  ██████████"));
+    assert!(log2.contains(" 16..31  0\n"));
+    let linear = run_lib(&["--histogram", "--bins", "2", "1", "2", "3", "4"]).unwrap();
+    assert!(linear.contains("med: 2.5\n1..2  2  "));
+    assert!(linear.contains("\n3..4  2  "));
+    let implied = run_lib(&["--bins", "2", "1", "2"]).unwrap();
+    assert_eq!(implied, run_lib(&["--histogram", "--bins", "2", "1", "2"]).unwrap());
+    match run_lib(&["--histogram", "--bins", "0", "1"]) {
+        Err(intspector::Error::Invalid(msg)) => {
+            assert_eq!(msg, "the number of bins must be a positive integer.")
+        },
+        other => panic!("expected an error, found {:?}", other),
+    }
+}
+
+
+// Unparseable values are reported on stderr and counted, but don't stop the statistics.
+#[test]
+fn stats() {
+    // Without a separate stderr, the library writes warnings to the output.
+    let stats = run_lib(&["--stats", "1", "2", "3", "zz"]).unwrap();
+    assert!(stats.starts_with("Error: cannot parse 'zz'"));
+    assert!(stats.contains("\ncnt: 3 values, 1 unparseable\ndst: 3 distinct\ndup: none\n"));
+    assert!(stats.contains("min: 1\nmax: 3\n"));
+    assert!(stats.contains("wid: all fit in 2 bits"));
+    let dups = run_lib(&["--stats", "5", "5", "0x5", "6"]).unwrap();
+    assert!(dups.contains("dst: 2 distinct\n"));
+    assert!(!dups.contains("dup: none"));
+}
+
+
+// Returns the values of the dec lines of the output, in order.
+fn decimals(output: &str) -> Vec<&str> {
+    output.lines().filter_map(|line| line.strip_prefix("dec: ")).collect()
+}
+
+
+#[test]
+fn sort_and_dedup() {
+    assert_eq!(decimals(&run_lib(&["--sort", "asc", "3", "1", "2"]).unwrap()), ["1", "2", "3"]);
+    assert_eq!(decimals(&run_lib(&["--sort", "desc", "3", "1", "2"]).unwrap()), ["3", "2", "1"]);
+    // -1 is shown in 8-bit two's complement, so it's the smallest signed value but the largest
+    // unsigned one.
+    let signed = run_lib(&["--sort", "signed", "2", "-1"]).unwrap();
+    assert_eq!(decimals(&signed), ["255", "2"]);
+    let unsigned = run_lib(&["--sort", "unsigned", "--", "-1", "2"]).unwrap();
+    assert_eq!(decimals(&unsigned), ["2", "255"]);
+    assert_eq!(decimals(&run_lib(&["--dedup", "1", "1", "2", "1"]).unwrap()), ["1", "2"]);
+    let both = run_lib(&["--sort", "desc", "--dedup", "1", "3", "1", "0x3"]).unwrap();
+    assert_eq!(decimals(&both), ["3", "1"]);
+    match run_lib(&["--sort", "sideways", "1"]) {
+        Err(intspector::Error::Invalid(msg)) => assert!(msg.starts_with("expected asc, desc")),
+        other => panic!("expected an error, found {:?}", other),
+    }
+}
+
+
+#[test]
+fn delta() {
+    let output = run_lib(&["--delta", "1", "4", "9"]).unwrap();
+    assert_eq!(output.lines().next(), Some("1"));
+    assert!(output.contains("\n4  +3  +0x3  +0o3   +0b11\n"));
+    assert!(output.contains("\n9  +5  +0x5  +0o5  +0b101\n"));
+    assert!(output.contains("str: uneven, deltas from 3 to 5, gcd 1"));
+    let constant = run_lib(&["--delta", "10", "8", "6"]).unwrap();
+    assert!(constant.contains("str: constant stride -2"));
+}
+
+
+#[test]
+fn fold() {
+    let xor = run_lib(&["--fold", "xor", "1", "2", "3"]).unwrap();
+    assert!(xor.contains("agg: xor of 3 values\nhex: 0\n"));
+    let or = run_lib(&["--fold", "or", "1", "2", "4"]).unwrap();
+    assert!(or.contains("agg: or of 3 values\nhex: 7\n"));
+    let and = run_lib(&["--fold", "and", "0xff", "0x0f"]).unwrap();
+    assert!(and.contains("agg: and of 2 values\nhex: F\n"));
+    match run_lib(&["--fold", "nand", "1"]) {
+        Err(intspector::Error::Invalid(msg)) => {
+            assert_eq!(msg, "expected and, or, or xor for --fold, found 'nand'.")
+        },
+        other => panic!("expected an error, found {:?}", other),
+    }
+}
+
+
+// Plausible timestamps and other recognisable values get a hint line, unless --no-hints is set.
+#[test]
+fn hints() {
+    let timestamp = run_lib(&["1700000000"]).unwrap();
+    assert!(timestamp.contains("hnt: Unix time in seconds, 2023-11-14 22:13:20 UTC, see the time"));
+    assert!(!run_lib(&["--no-hints", "1700000000"]).unwrap().contains("hnt:"));
+    assert!(!run_lib(&["4096"]).unwrap().contains("hnt:"));
+}
+
+
+// Options taking a 32-bit unsigned integer share one parser and one error message.
+#[test]
+fn u32_options() {
+    let commands: &[&[&str]] = &[
+        &["--bits", "abc", "1"],
+        &["ops", "--bits", "abc", "not", "1"],
+        &["repl", "--bits", "abc"],
+    ];
+    for args in commands {
+        match run_lib(args) {
+            Err(intspector::Error::Invalid(msg)) => {
+                assert_eq!(msg, "cannot parse 'abc' as a 32-bit unsigned integer.", "{:?}", args)
+            },
+            other => panic!("{:?}: expected an error, found {:?}", args, other),
+        }
+    }
+    assert!(run_lib(&["--bits", "16", "1"]).unwrap().contains("bin: 0000_0000 0000_0001\n"));
+}
+
+
+// Batch input from stdin is split into tokens across chunk boundaries.
+#[test]
+fn piped_batch_input() {
+    let input: String = (1..=100_000).map(|n| format!("{}\n", n)).collect();
+    let stats = run_piped(&["--stats"], input.as_bytes());
+    assert!(stats.starts_with("cnt: 100000 values\ndst: 100000 distinct\n"), "{}", stats);
+    assert!(stats.contains("min: 1\nmax: 100000\n"));
+    let delta = run_piped(&["--delta"], b"1 2\n3");
+    assert!(delta.ends_with("str: constant stride 1 (0x1)\n"));
+}
+
+
+// `--json` is `--format json`, with numbers as JSON numbers and errors as typed members.
+#[test]
+fn json_schema() {
+    let output = run(&["--json", "42", "0xzz"]);
+    let lines: Vec<json::Value> = output.lines().filter_map(json::parse).collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].get("input").and_then(json::Value::as_str), Some("42"));
+    assert_eq!(lines[0].get("dec").and_then(json::Value::as_i64), Some(42));
+    assert_eq!(lines[0].get("bin").and_then(json::Value::as_str), Some("101010"));
+    let req = lines[0].get("req").expect("no req member");
+    assert_eq!(req.get("bits").and_then(json::Value::as_i64), Some(6));
+    assert_eq!(lines[0].get("value").and_then(json::Value::as_i64), Some(42));
+    assert_eq!(lines[0].get("std_bits").and_then(json::Value::as_i64), Some(8));
+    assert_eq!(lines[0].get("twos_complement"), Some(&json::Value::Null));
+    assert_eq!(lines[1].get("code").and_then(json::Value::as_str), Some("invalid_digit"));
+    assert_eq!(lines[1].get("position").and_then(json::Value::as_i64), Some(2));
+    let negative = json::parse(run(&["--json", "--", "-5"]).trim()).expect("not JSON");
+    assert_eq!(negative.get("value").and_then(json::Value::as_i64), Some(-5));
+    assert_eq!(negative.get("min_bits").and_then(json::Value::as_i64), Some(4));
+    assert_eq!(negative.get("twos_complement").and_then(json::Value::as_i64), Some(251));
+    assert!(!run(&["--", "-5"]).contains("min_bits"));
+    assert_eq!(run(&["--json", "42"]), run(&["--format", "json", "42"]));
+    // Each input is one record, even for commands that show several sections.
+    let decimal = run(&["--json", "decimal", "0x2238000000000001", "0x7800000000000000"]);
+    let records: Vec<json::Value> = decimal.lines().filter_map(json::parse).collect();
+    assert_eq!(records.len(), 2);
+    let encodings = records[0].get("enc").expect("no enc member");
+    assert_eq!(encodings, &json::parse(r#"["DPD","BID"]"#).unwrap());
+    let check = json::parse(run(&["--json", "check", "79927398713"]).trim()).expect("not JSON");
+    assert_eq!(check.get("input").and_then(json::Value::as_str), Some("79927398713"));
+}
+
+
+// Every command's reports and errors name the input they belong to, and errors from parsing an
+// integer carry the parser's code and position.
+#[test]
+fn json_errors_name_their_input() {
+    let commands: &[&[&str]] = &[
+        &["float", "abc"],
+        &["ptr", "zz"],
+        &["decimal", "zz"],
+        &["time", "zz"],
+        &["mac", "zz"],
+        &["crc", "--hex", "zz"],
+        &["ulid", "zz"],
+    ];
+    for command in commands {
+        let args: Vec<&str> = ["--json"].iter().chain(command.iter()).copied().collect();
+        let output = run(&args);
+        let error = json::parse(output.lines().next().unwrap_or("")).expect("not JSON");
+        let input = error.get("input").and_then(json::Value::as_str);
+        assert_eq!(input, command.last().copied(), "{:?}", command);
+        assert!(error.get("error").is_some(), "{:?}", command);
+    }
+    let gray = run(&["--json", "gray", "0xzz", "5"]);
+    let lines: Vec<json::Value> = gray.lines().filter_map(json::parse).collect();
+    assert_eq!(lines[0].get("code").and_then(json::Value::as_str), Some("invalid_digit"));
+    assert_eq!(lines[0].get("position").and_then(json::Value::as_i64), Some(2));
+    assert_eq!(lines[1].get("input").and_then(json::Value::as_str), Some("5"));
+    let time = json::parse(run(&["--json", "time", "1+"]).trim()).expect("not JSON");
+    assert_eq!(time.get("code").and_then(json::Value::as_str), Some("unexpected_end"));
+}
+
+
+#[test]
+fn csv_rows_and_table_errors() {
+    let csv = run(&["--format", "csv", "1", "2"]);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "input,field,value");
+    assert_eq!(lines.iter().filter(|line| line.starts_with("input,")).count(), 1);
+    assert!(lines.contains(&"1,dec,1") && lines.contains(&"2,dec,2"));
+    let delta = run(&["--format", "csv", "--delta", "1", "zz", "9"]);
+    assert!(delta.starts_with("input,value,dec,hex,oct,bin,error\n"));
+    assert!(delta.contains(",zz,,,,,cannot parse 'zz'"));
+    let json = json::parse(run(&["--json", "--delta", "1", "zz", "9"]).trim()).expect("not JSON");
+    let rows = match json.get("table") {
+        Some(json::Value::Array(rows)) => rows,
+        _ => panic!("no table"),
+    };
+    assert!(rows[1].get("dec").is_none());
+    let error = rows[1].get("error").and_then(json::Value::as_str).unwrap_or("");
+    assert!(error.starts_with("cannot parse 'zz'"));
+    assert!(rows[0].get("error").is_none());
+    let text = run(&["--delta", "1", "zz", "9"]);
+    assert!(text.contains("zz  Error: cannot parse 'zz'"));
+}
+
+
+#[test]
+fn bench_streams_a_batch() {
+    let output = run_lib(&["bench", "-n", "50"]).unwrap();
+    assert!(output.contains("itr: 50"));
+    let streaming = output.lines().find(|line| line.starts_with("batch streaming"));
+    assert!(streaming.is_some_and(|line| line.contains("ns/iter")));
+}
+
+
+// Runs the command line interface in the library, returning its output or its error.
+fn run_lib(args: &[&str]) -> Result<String, intspector::Error> {
+    let mut out = Vec::new();
+    intspector::run(args, &mut out)?;
+    Ok(String::from_utf8(out).expect("output is not valid UTF-8"))
+}
+
+
+// The library entry point writes the same output as the binary, so its output can be checked
+// exactly.
+#[test]
+fn library_golden_output() {
+    let expected = "\
+req: 6 bits (unsigned)
+hex: 2A
+dec: 42
+oct: 52
+bin: 10_1010
+pop: 3 set bits, 0 leading zeros, 1 trailing zero, odd parity
+asc: *
+";
+    assert_eq!(run_lib(&["42"]).unwrap(), expected);
+    for args in [&["42"][..], &["--format", "json", "42", "0xzz"], &["crc", "abc"], &["--help"]] {
+        assert_eq!(run_lib(args).unwrap(), run(args), "{:?}", args);
+    }
+    let json = run_lib(&["--json", "0xzz"]).unwrap();
+    assert!(json.starts_with(r#"{"input":"0xzz","error":"#));
+    // The library's clock reads the epoch and rand has a fixed seed.
+    assert!(run_lib(&["time", "86400"]).unwrap().contains("1970-01-02 00:00:00 UTC, in 1 day"));
+    let rand = run_lib(&["rand", "--count", "3"]).unwrap();
+    assert_eq!(rand, run_lib(&["rand", "--count", "3"]).unwrap());
+    assert_eq!(rand, run_lib(&["rand", "--count", "3", "--seed", "0"]).unwrap());
+}
+
+
+// Errors that stop the binary with a message and a non-zero status are returned instead.
+#[test]
+fn library_errors() {
+    match run_lib(&["crc", "--algo", "nope", "abc"]) {
+        Err(intspector::Error::Invalid(msg)) => assert_eq!(msg, "unknown algorithm 'nope'."),
+        other => panic!("expected an error, found {:?}", other),
+    }
+    match run_lib(&["--bits"]) {
+        Err(intspector::Error::Invalid(msg)) => assert_eq!(msg, "missing value for --bits."),
+        other => panic!("expected an error, found {:?}", other),
+    }
+    assert!(run_lib(&["help", "nope"]).is_err());
+    assert!(run_lib(&["crc", "--help"]).unwrap().starts_with("Usage: intspector crc"));
+    let help = run_lib(&["--help"]).unwrap();
+    let bits = help.find("  -b, --bits <n>").expect("no --bits in the help");
+    assert!(help[..bits].contains("      --bins <n>") && help[bits..].contains("\nFlags:\n"));
+    assert!(help.contains("  -v, --version") && help.contains("\nCommands:\n  arith"));
+    // Without stdin, the default command and the REPL have no input.
+    assert_eq!(run_lib(&[]).unwrap(), "");
+    assert_eq!(run_lib(&["-i"]).unwrap(), "");
+}
+
+
+// Labels are translated for the command they belong to, values stay in English, and an unknown
+// INTSPECTOR_LANG is a warning rather than an error like an unknown --lang.
+#[test]
+fn translated_labels() {
+    let stats = run_lib(&["--lang", "de", "--stats", "1", "2"]).unwrap();
+    assert!(stats.starts_with("Anzahl:  2 values\n"));
+    let oid = run_lib(&["--lang", "de", "objectid", "507f1f77bcf86cd799439011"]).unwrap();
+    assert!(oid.contains("Zähler: 4427793"));
+    assert!(!oid.contains("Anzahl"));
+    let json = run_lib(&["--lang", "de", "--json", "42"]).unwrap();
+    assert!(json.contains(r#""dec":42"#));
+    assert!(run_lib(&["--lang", "xx", "42"]).is_err());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_intspector"))
+        .arg("42")
+        .env("INTSPECTOR_LANG", "xx")
+        .output()
+        .expect("failed to run intspector");
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("Warning: INTSPECTOR_LANG: unknown language 'xx'"));
+    assert!(String::from_utf8(output.stdout).unwrap().contains("dec: 42"));
+}
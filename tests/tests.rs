@@ -1,7 +1,97 @@
 use intspector::min_bits;
+use intspector::min_bits_unsigned;
+use intspector::std_bits;
+use intspector::analyze;
+use intspector::popcount;
+use intspector::clz;
+use intspector::ctz;
+use intspector::parity;
+use intspector::bit_runs;
+use intspector::set_bits;
+use intspector::clear_bits;
+use intspector::toggle_bits;
+use intspector::rotate_left;
+use intspector::rotate_right;
+use intspector::extract_field;
+use intspector::field_mask;
+use intspector::common_prefix_len;
+use intspector::shannon_entropy;
+use intspector::utf8_bytes;
+use intspector::utf16_units;
+use intspector::add_spacers;
 use intspector::bin_string;
+use intspector::bin_string_with;
+use intspector::to_base;
 use intspector::twos_complement;
+use intspector::byte_swap;
+use intspector::balanced_ternary;
+use intspector::non_adjacent_form;
+use intspector::signed_digit_string;
+use intspector::factoradic;
+use intspector::from_factoradic;
+use intspector::lehmer_permutation;
+use intspector::permutation_lehmer;
+use intspector::radix_digits;
+use intspector::mixed_radix_digits;
+use intspector::babylonian_digit;
+use intspector::maya_digit;
 use intspector::parse_int;
+use intspector::parse_uint;
+use intspector::parse_u128;
+use intspector::eval_expr;
+use intspector::eval_expr_with;
+use intspector::IntspectorError;
+use intspector::alignment;
+use intspector::common_alignment;
+use intspector::common_stride;
+use intspector::is_power_of_two;
+use intspector::next_power_of_two;
+use intspector::prev_power_of_two;
+use intspector::pointer;
+use intspector::poison;
+use intspector::dns;
+use intspector::asn1;
+use intspector::bigint::BigUint;
+use intspector::check;
+use intspector::checksum;
+use intspector::codec;
+use intspector::color;
+use intspector::combinatorics;
+use intspector::exif;
+use intspector::factor;
+use intspector::float;
+use intspector::country;
+use intspector::datetime;
+use intspector::decimal;
+use intspector::json;
+use intspector::layout;
+use intspector::magic;
+use intspector::numbering;
+use intspector::bitmap;
+use intspector::barcode;
+use intspector::synchsafe;
+use intspector::encode_uleb128;
+use intspector::decode_uleb128;
+use intspector::zigzag_encode;
+use intspector::zigzag_decode;
+use intspector::to_gray;
+use intspector::from_gray;
+use intspector::mac;
+use intspector::geo;
+use intspector::ids;
+use intspector::ip;
+use intspector::radio;
+use intspector::rns;
+use intspector::selftest;
+use intspector::sequence;
+use intspector::coding;
+use intspector::huffman;
+use intspector::arith;
+use intspector::ratio;
+use intspector::random;
+use intspector::midi;
+use intspector::mode;
+use intspector::pow;
 
 #[test]
 fn min_bits_pos_input() {
@@ -30,6 +120,18 @@ fn min_bits_neg_input() {
     assert_eq!(min_bits(-130), 9);
 }
 
+#[test]
+fn min_bits_128bit_input() {
+    assert_eq!(min_bits(u64::MAX as i128), 64);
+    assert_eq!(min_bits(1 << 64), 65);
+    assert_eq!(min_bits(i128::MAX), 127);
+    assert_eq!(min_bits(i128::MIN), 128);
+    assert_eq!(min_bits(i64::MIN as i128), 64);
+    assert_eq!(min_bits_unsigned(u128::MAX), 128);
+    assert_eq!(min_bits_unsigned(0), 1);
+    assert_eq!(std_bits(1 << 64), 128);
+}
+
 #[test]
 fn twos_complement_3bit() {
     assert_eq!(twos_complement(0, 3), 0);
@@ -51,6 +153,13 @@ fn twos_complement_64bit() {
     assert_eq!(twos_complement(0xFFFFFFFFFFFFFFFE, 64), 2);
 }
 
+#[test]
+fn twos_complement_128bit() {
+    assert_eq!(twos_complement(1, 128), u128::MAX);
+    assert_eq!(twos_complement(u128::MAX, 128), 1);
+    assert_eq!(twos_complement(1, 65), 0x1_FFFF_FFFF_FFFF_FFFF);
+}
+
 #[test]
 fn bin_string_0b() {
     assert_eq!(bin_string(0, 0), "");
@@ -77,6 +186,13 @@ fn bin_string_8b() {
     assert_eq!(bin_string(256, 8), "0000_0000");
 }
 
+#[test]
+fn bin_string_128b() {
+    let expected = ["1111_1111"; 16].join(" ");
+    assert_eq!(bin_string(u128::MAX, 128), expected);
+    assert_eq!(bin_string(1 << 127, 128).chars().next(), Some('1'));
+}
+
 #[test]
 fn bin_string_12b() {
     assert_eq!(bin_string(  0, 12), "0000 0000_0000");
@@ -95,47 +211,1780 @@ fn bin_string_16b() {
 
 #[test]
 fn parse_int_no_prefix() {
-    assert_eq!(parse_int("0"), Some(0));
-    assert_eq!(parse_int("00"), Some(0));
-    assert_eq!(parse_int("1"), Some(1));
-    assert_eq!(parse_int("01"), Some(1));
-    assert_eq!(parse_int("101"), Some(101));
-    assert_eq!(parse_int("-101"), Some(-101));
+    assert_eq!(parse_int("0"), Ok(0));
+    assert_eq!(parse_int("00"), Ok(0));
+    assert_eq!(parse_int("1"), Ok(1));
+    assert_eq!(parse_int("01"), Ok(1));
+    assert_eq!(parse_int("101"), Ok(101));
+    assert_eq!(parse_int("-101"), Ok(-101));
 }
 
 #[test]
 fn parse_int_binary() {
-    assert_eq!(parse_int("b0"), Some(0));
-    assert_eq!(parse_int("b1"), Some(1));
-    assert_eq!(parse_int("b01"), Some(1));
-    assert_eq!(parse_int("b101"), Some(5));
-    assert_eq!(parse_int("0b101"), Some(5));
+    assert_eq!(parse_int("b0"), Ok(0));
+    assert_eq!(parse_int("b1"), Ok(1));
+    assert_eq!(parse_int("b01"), Ok(1));
+    assert_eq!(parse_int("b101"), Ok(5));
+    assert_eq!(parse_int("0b101"), Ok(5));
 }
 
 #[test]
 fn parse_int_octal() {
-    assert_eq!(parse_int("o0"), Some(0));
-    assert_eq!(parse_int("o1"), Some(1));
-    assert_eq!(parse_int("o01"), Some(1));
-    assert_eq!(parse_int("o101"), Some(65));
-    assert_eq!(parse_int("0o101"), Some(65));
+    assert_eq!(parse_int("o0"), Ok(0));
+    assert_eq!(parse_int("o1"), Ok(1));
+    assert_eq!(parse_int("o01"), Ok(1));
+    assert_eq!(parse_int("o101"), Ok(65));
+    assert_eq!(parse_int("0o101"), Ok(65));
 }
 
 #[test]
 fn parse_int_decimal() {
-    assert_eq!(parse_int("d0"), Some(0));
-    assert_eq!(parse_int("d1"), Some(1));
-    assert_eq!(parse_int("d01"), Some(1));
-    assert_eq!(parse_int("d101"), Some(101));
-    assert_eq!(parse_int("0d101"), Some(101));
+    assert_eq!(parse_int("d0"), Ok(0));
+    assert_eq!(parse_int("d1"), Ok(1));
+    assert_eq!(parse_int("d01"), Ok(1));
+    assert_eq!(parse_int("d101"), Ok(101));
+    assert_eq!(parse_int("0d101"), Ok(101));
 }
 
 #[test]
 fn parse_int_hex() {
-    assert_eq!(parse_int("x0"), Some(0));
-    assert_eq!(parse_int("x1"), Some(1));
-    assert_eq!(parse_int("x01"), Some(1));
-    assert_eq!(parse_int("x101"), Some(257));
-    assert_eq!(parse_int("0x101"), Some(257));
+    assert_eq!(parse_int("x0"), Ok(0));
+    assert_eq!(parse_int("x1"), Ok(1));
+    assert_eq!(parse_int("x01"), Ok(1));
+    assert_eq!(parse_int("x101"), Ok(257));
+    assert_eq!(parse_int("0x101"), Ok(257));
+}
+
+#[test]
+fn parse_uint_full_range() {
+    assert_eq!(parse_uint("0"), Ok(0));
+    assert_eq!(parse_uint("x7FFFFFFFFFFFFFFF"), Ok(0x7FFFFFFFFFFFFFFF));
+    assert_eq!(parse_uint("0xFFFFFFFFFFFFFFFF"), Ok(0xFFFFFFFFFFFFFFFF));
+    assert_eq!(parse_uint("18446744073709551615"), Ok(u64::MAX));
+    assert_eq!(parse_uint("-1"), Err(IntspectorError::Negative));
+}
+
+#[test]
+fn parse_128bit() {
+    assert_eq!(parse_int("x10000000000000000"), Ok(1 << 64));
+    assert_eq!(parse_int("-170141183460469231731687303715884105728"), Ok(i128::MIN));
+    let overflow = IntspectorError::Overflow { bits: 128, signed: true };
+    assert_eq!(parse_int("0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"), Err(overflow));
+    assert_eq!(parse_u128("0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"), Ok(u128::MAX));
+    assert_eq!(parse_u128("-1"), Err(IntspectorError::Negative));
+}
+
+#[test]
+fn alignment_values() {
+    assert_eq!(alignment(0), None);
+    assert_eq!(alignment(1), Some(1));
+    assert_eq!(alignment(6), Some(2));
+    assert_eq!(alignment(0x1000), Some(0x1000));
+    assert_eq!(alignment(0x7FFDEADBEE0), Some(32));
+}
+
+#[test]
+fn tag_bits_values() {
+    assert_eq!(pointer::tag_bits(1), Some(0));
+    assert_eq!(pointer::tag_bits(8), Some(3));
+    assert_eq!(pointer::tag_bits(16), Some(4));
+    assert_eq!(pointer::tag_bits(0), None);
+    assert_eq!(pointer::tag_bits(12), None);
+}
+
+#[test]
+fn canonical_addresses() {
+    assert!(pointer::is_canonical(0x00007FFFFFFFFFFF, 48));
+    assert!(pointer::is_canonical(0xFFFF800000000000, 48));
+    assert!(!pointer::is_canonical(0x0000800000000000, 48));
+    assert!(pointer::is_canonical(0x0000800000000000, 57));
+    assert_eq!(pointer::canonicalize(0x0000800000000000, 48), 0xFFFF800000000000);
+}
+
+#[test]
+fn aarch64_addresses() {
+    assert!(pointer::is_aarch64_valid(0x0000FFFFFFFFFFFF, 48, false));
+    assert!(pointer::is_aarch64_valid(0xFFFF000000000000, 48, false));
+    assert!(!pointer::is_aarch64_valid(0x0800000000000000, 48, false));
+    assert!(pointer::is_aarch64_valid(0x0800000000000000, 48, true));
+    assert!(!pointer::is_aarch64_valid(0x0001000000000000, 48, true));
+}
+
+#[test]
+fn classify_address_linux() {
+    let name = |addr| pointer::classify(addr, pointer::LINUX_LAYOUT).map(|region| region.name);
+    assert_eq!(name(0x0), Some("null guard (below mmap_min_addr)"));
+    assert_eq!(name(0x400000), Some("non-PIE executable or brk heap"));
+    assert_eq!(name(0x555555554000), Some("PIE executable or brk heap"));
+    assert_eq!(name(0x7F1234567000), Some("mmap region or shared libraries"));
+    assert_eq!(name(0x7FFDEADBE000), Some("stack"));
+    assert_eq!(name(0x0000800000000000), Some("non-canonical hole"));
+    assert_eq!(name(0xFFFFFFFF81000000), Some("kernel text"));
+}
+
+#[test]
+fn classify_address_macos() {
+    let name = |addr| pointer::classify(addr, pointer::MACOS_LAYOUT).map(|region| region.name);
+    assert_eq!(name(0x1000), Some("__PAGEZERO (null region)"));
+    assert_eq!(name(0x100003F00), Some("main executable"));
+    assert_eq!(name(0x600000004000), Some("malloc heap (nano zone)"));
+    assert_eq!(name(0xFFFFFF8000000000), Some("kernel space"));
+}
+
+#[test]
+fn poison_value_matches() {
+    assert!(poison::describe(0xDEADBEEF).is_some());
+    assert!(poison::describe(0xCCCCCCCC).is_some());
+    assert!(poison::describe(0xCCCCCCCCCCCCCCCC).is_some());
+    assert!(poison::describe(0xDEAD000000000100).is_some());
+    assert!(poison::describe(0xDEADBEEF00000000).is_none());
+    assert!(poison::describe(0x12345678).is_none());
+    assert!(poison::describe(0).is_none());
+}
+
+#[test]
+fn synchsafe_conversions() {
+    assert_eq!(synchsafe::encode(0), Some(0));
+    assert_eq!(synchsafe::encode(127), Some(0x7F));
+    assert_eq!(synchsafe::encode(128), Some(0x0100));
+    assert_eq!(synchsafe::encode(1000), Some(0x0768));
+    assert_eq!(synchsafe::encode(0x0FFF_FFFF), Some(0x7F7F_7F7F));
+    assert_eq!(synchsafe::encode(0x1000_0000), None);
+    assert_eq!(synchsafe::decode(0x0768), Some(1000));
+    assert_eq!(synchsafe::decode(0x7F7F_7F7F), Some(0x0FFF_FFFF));
+    assert_eq!(synchsafe::decode(0x0080), None);
+}
+
+#[cfg(feature = "syscalls")]
+#[test]
+fn syscall_lookup() {
+    use intspector::syscalls;
+    assert_eq!(syscalls::name(syscalls::X86_64, 0), Some("read"));
+    assert_eq!(syscalls::name(syscalls::X86_64, 59), Some("execve"));
+    assert_eq!(syscalls::name(syscalls::AARCH64, 221), Some("execve"));
+    assert_eq!(syscalls::name(syscalls::RISCV64, 259), Some("riscv_flush_icache"));
+    assert_eq!(syscalls::number(syscalls::X86_64, "openat"), Some(257));
+    assert_eq!(syscalls::number(syscalls::AARCH64, "sys_openat"), Some(56));
+    assert_eq!(syscalls::number(syscalls::AARCH64, "renameat"), Some(38));
+    assert_eq!(syscalls::number(syscalls::RISCV64, "renameat"), None);
+    assert_eq!(syscalls::number(syscalls::AARCH64, "open"), None);
+    assert!(syscalls::table("arm64").is_some());
+    assert!(syscalls::table("mips").is_none());
+}
+
+#[test]
+fn dns_type_lookup() {
+    assert_eq!(dns::name(dns::TYPES, 1), Some("A"));
+    assert_eq!(dns::name(dns::TYPES, 28), Some("AAAA"));
+    assert_eq!(dns::name(dns::TYPES, 257), Some("CAA"));
+    assert_eq!(dns::name(dns::TYPES, 54), None);
+    assert_eq!(dns::number(dns::TYPES, "https"), Some(65));
+    assert_eq!(dns::number(dns::CLASSES, "IN"), Some(1));
+}
+
+#[test]
+fn dns_decode_flags() {
+    let flags = dns::decode_flags(0x8180);
+    assert!(flags.qr && flags.rd && flags.ra);
+    assert!(!flags.aa && !flags.tc && !flags.ad && !flags.cd);
+    assert_eq!(flags.opcode, 0);
+    assert_eq!(flags.rcode, 0);
+
+    let flags = dns::decode_flags(0x2803);
+    assert!(!flags.qr);
+    assert_eq!(flags.opcode, 5);
+    assert_eq!(flags.rcode, 3);
+}
+
+#[test]
+fn base64_decode_alphabets() {
+    assert_eq!(codec::base64_decode(""), Some(vec![]));
+    assert_eq!(codec::base64_decode("TWFu"), Some(b"Man".to_vec()));
+    assert_eq!(codec::base64_decode("TWE="), Some(b"Ma".to_vec()));
+    assert_eq!(codec::base64_decode("TWE"), Some(b"Ma".to_vec()));
+    assert_eq!(codec::base64_decode("+/8="), Some(vec![0xFB, 0xFF]));
+    assert_eq!(codec::base64_decode("-_8"), Some(vec![0xFB, 0xFF]));
+    assert_eq!(codec::base64_decode("T"), None);
+    assert_eq!(codec::base64_decode("TW!u"), None);
+}
+
+#[test]
+fn datetime_civil_conversions() {
+    assert_eq!(datetime::days_from_civil(1970, 1, 1), 0);
+    assert_eq!(datetime::days_from_civil(2000, 3, 1), 11017);
+    assert_eq!(datetime::days_from_civil(1969, 12, 31), -1);
+    assert_eq!(datetime::civil_from_days(0), (1970, 1, 1));
+    assert_eq!(datetime::civil_from_days(11017), (2000, 3, 1));
+    assert_eq!(datetime::civil_from_days(-1), (1969, 12, 31));
+}
+
+#[test]
+fn datetime_packed_dates() {
+    assert_eq!(datetime::unpack_date(20240315), Some((2024, 3, 15)));
+    assert_eq!(datetime::unpack_date(20240229), Some((2024, 2, 29)));
+    assert_eq!(datetime::unpack_date(20230229), None);
+    assert_eq!(datetime::unpack_date(20241301), None);
+    assert_eq!(datetime::unpack_date(20240100), None);
+    assert_eq!(datetime::unpack_ordinal(2024075), Some((2024, 3, 15)));
+    assert_eq!(datetime::unpack_ordinal(2024366), Some((2024, 12, 31)));
+    assert_eq!(datetime::unpack_ordinal(2023366), None);
+    assert_eq!(datetime::day_of_year(2024, 3, 15), 75);
+}
+
+#[test]
+fn datetime_weekdays_and_weeks() {
+    assert_eq!(datetime::iso_weekday(0), 4);
+    assert_eq!(datetime::iso_weekday(datetime::days_from_civil(2024, 3, 15)), 5);
+    assert_eq!(datetime::weekday_name(5), "Friday");
+    assert_eq!(datetime::iso_week(2024, 3, 15), (2024, 11));
+    assert_eq!(datetime::iso_week(2021, 1, 3), (2020, 53));
+    assert_eq!(datetime::iso_week(2024, 12, 30), (2025, 1));
+    assert_eq!(datetime::iso_week(2026, 12, 31), (2026, 53));
+}
+
+#[test]
+fn datetime_format_utc() {
+    assert_eq!(datetime::format_utc(0), "1970-01-01 00:00:00 UTC");
+    assert_eq!(datetime::format_utc(1_000_000_000), "2001-09-09 01:46:40 UTC");
+    assert_eq!(datetime::format_utc(-1), "1969-12-31 23:59:59 UTC");
+}
+
+#[test]
+fn json_parse_values() {
+    let value = json::parse(r#"{"alg": "HS256", "iat": 1516239022, "aud": ["a", "b"], "x": null}"#).unwrap();
+    assert_eq!(value.get("alg").and_then(|v| v.as_str()), Some("HS256"));
+    assert_eq!(value.get("iat").and_then(|v| v.as_i64()), Some(1516239022));
+    assert_eq!(value.get("x"), Some(&json::Value::Null));
+    assert_eq!(value.get("missing"), None);
+    assert_eq!(json::parse(r#""\u00e9\ud83d\ude00""#), Some(json::Value::String("é😀".to_string())));
+    assert_eq!(json::parse("{\"a\": 1,}"), None);
+    let value = json::parse(r#"{"exp": 1.5e9, "nbf": 1500000000.5}"#).unwrap();
+    assert_eq!(value.get("exp").and_then(|v| v.as_i64()), None);
+    assert_eq!(value.get("exp").and_then(|v| v.as_f64()), Some(1.5e9));
+    assert_eq!(value.get("nbf").and_then(|v| v.as_f64()), Some(1500000000.5));
+    assert_eq!(value.get("alg").and_then(|v| v.as_f64()), None);
+    assert_eq!(json::parse("[1, 2] 3"), None);
+}
+
+#[test]
+fn json_write_values() {
+    let text = r#"{"alg":"HS256","iat":1516239022,"aud":["a","b"],"x":null,"y":true}"#;
+    assert_eq!(json::parse(text).unwrap().to_string(), text);
+    assert_eq!(json::quote("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+    assert_eq!(json::Value::Array(vec![]).to_string(), "[]");
+    assert_eq!(json::Value::Object(vec![]).to_string(), "{}");
+}
+
+#[test]
+fn native_digits_round_trip() {
+    assert_eq!(numbering::to_native("1234", 0x0660), "١٢٣٤");
+    assert_eq!(numbering::to_native("-90", 0x0966), "-९०");
+    assert_eq!(numbering::to_native("x12", 0xFF10), "x１２");
+    assert_eq!(numbering::to_ascii("١٢٣٤"), "1234");
+    assert_eq!(numbering::to_ascii("۱۲۳"), "123");
+    assert_eq!(numbering::to_ascii("-९०"), "-90");
+    assert_eq!(numbering::to_ascii("１２abc"), "12abc");
+}
+
+#[test]
+fn braille_string_grid() {
+    assert_eq!(bitmap::braille(0, 8), "⠀⠀⠀⠀");
+    assert_eq!(bitmap::braille(0xFF, 8), "⠉⠉⠉⠉");
+    assert_eq!(bitmap::braille(0x80, 8), "⠁⠀⠀⠀");
+    assert_eq!(bitmap::braille(0xFFFFFFFF, 32), "⣿⣿⣿⣿");
+    assert_eq!(bitmap::braille(0x01, 32), "⠀⠀⠀⢀");
+    assert_eq!(bitmap::braille(u64::MAX as u128, 64), "⣿⣿⣿⣿\n⣿⣿⣿⣿");
+}
+
+#[test]
+fn block_string_grid() {
+    assert_eq!(bitmap::blocks(0xF0, 8), "▀▀▀▀····");
+    assert_eq!(bitmap::blocks(0xFF0F, 16), "▀▀▀▀████");
+    assert_eq!(bitmap::blocks(0x81, 4), "·······▀");
+    assert_eq!(bitmap::blocks(0xFFFF_FFFF_0000_0000, 64), "████████\n████████\n········\n········");
+}
+
+#[test]
+fn qr_numeric_packing() {
+    assert_eq!(
+        barcode::qr_numeric_groups("01234567"),
+        Some(vec![("012", 12, 10), ("345", 345, 10), ("67", 67, 7)])
+    );
+    assert_eq!(barcode::qr_numeric_groups("8"), Some(vec![("8", 8, 4)]));
+    assert_eq!(barcode::qr_numeric_groups("12a"), None);
+    assert_eq!(barcode::qr_numeric_count_bits(1), 10);
+    assert_eq!(barcode::qr_numeric_count_bits(10), 12);
+    assert_eq!(barcode::qr_numeric_count_bits(40), 14);
+}
+
+#[test]
+fn code128_digits() {
+    assert_eq!(barcode::code128_digit_symbols("1234"), Some(vec![105, 12, 34]));
+    assert_eq!(barcode::code128_digit_symbols("123"), Some(vec![105, 12, 100, 19]));
+    assert_eq!(barcode::code128_digit_symbols(""), None);
+    assert_eq!(barcode::code128_checksum(&[105, 12, 34]), 82);
+    assert_eq!(barcode::code128_checksum(&[105, 1, 23, 45, 67]), 40);
+}
+
+#[test]
+fn mac_parse_notations() {
+    assert_eq!(mac::parse("00:1a:2b:3c:4d:5e"), Some(0x001A2B3C4D5E));
+    assert_eq!(mac::parse("00-1A-2B-3C-4D-5E"), Some(0x001A2B3C4D5E));
+    assert_eq!(mac::parse("0:1a:2b:3c:4d:5e"), Some(0x001A2B3C4D5E));
+    assert_eq!(mac::parse("001a.2b3c.4d5e"), Some(0x001A2B3C4D5E));
+    assert_eq!(mac::parse("001a2b3c4d5e"), Some(0x001A2B3C4D5E));
+    assert_eq!(mac::parse("0x1234"), Some(0x1234));
+    assert_eq!(mac::parse("x1000000000000"), None);
+    assert_eq!(mac::parse("00:1a:2b:3c:4d"), None);
+    assert_eq!(mac::parse("zz:1a:2b:3c:4d:5e"), None);
+}
+
+#[test]
+fn mac_format_and_bits() {
+    assert_eq!(mac::format(0x001A2B3C4D5E, ':'), "00:1A:2B:3C:4D:5E");
+    assert_eq!(mac::format_cisco(0x001A2B3C4D5E), "001a.2b3c.4d5e");
+    assert!(mac::is_multicast(0x01005E0000FB));
+    assert!(!mac::is_multicast(0x001A2B3C4D5E));
+    assert!(mac::is_local(0x525400ABCDEF));
+    assert_eq!(mac::modified_eui64(0x001A2B3C4D5E), 0x021A2BFFFE3C4D5E);
+}
+
+#[cfg(feature = "oui")]
+#[test]
+fn oui_vendor_lookup() {
+    use intspector::oui;
+    assert_eq!(oui::vendor(0x001B213C4D5E), Some("Intel"));
+    assert_eq!(oui::vendor(0x01005E0000FB), Some("IANA"));
+    assert_eq!(oui::vendor(0xFEFFFF000000), None);
+    assert!(oui::VENDORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn geohash_decoding() {
+    assert_eq!(geo::geohash_bits("ezs42"), Some((0b01101_11111_11000_00100_00010, 25)));
+    assert_eq!(geo::geohash_bits("abc"), None);
+    assert_eq!(geo::geohash_bits(""), None);
+    let bounds = geo::geohash_decode("u4pruydqqvj").unwrap();
+    assert!((bounds.lat_min + bounds.lat_max) / 2.0 - 57.64911 < 1e-5);
+    assert!((bounds.lon_min + bounds.lon_max) / 2.0 - 10.40744 < 1e-5);
+    let bounds = geo::geohash_decode("s").unwrap();
+    assert_eq!(bounds, geo::Bounds { lat_min: 0.0, lat_max: 45.0, lon_min: 0.0, lon_max: 45.0 });
+}
+
+#[test]
+fn h3_decoding() {
+    let h3 = geo::h3_decode(0x8928308280fffff).unwrap();
+    assert_eq!(h3.mode, 1);
+    assert_eq!(h3.resolution, 9);
+    assert_eq!(h3.base_cell, 20);
+    assert_eq!(h3.digits, vec![0, 6, 0, 4, 0, 5, 0, 0, 3]);
+    let h3 = geo::h3_decode(0x8009fffffffffff).unwrap();
+    assert_eq!(h3.resolution, 0);
+    assert_eq!(h3.base_cell, 4);
+    assert!(h3.digits.is_empty());
+    assert_eq!(geo::h3_decode(0x8928308280ffff0), None);
+    assert_eq!(geo::h3_decode(0x0928308280fffff), None);
+}
+
+#[test]
+fn h3_centers() {
+    let near = |index: u64, lat: f64, lon: f64, tolerance: f64| {
+        let (actual_lat, actual_lon) = geo::h3_center(index).unwrap();
+        assert!((actual_lat - lat).abs() < tolerance, "{:x}: {}", index, actual_lat);
+        assert!((actual_lon - lon).abs() < tolerance, "{:x}: {}", index, actual_lon);
+    };
+    near(0x85283473fffffff, 37.34579337536848, -121.97637597255124, 1e-9);
+    near(0x87283472bffffff, 37.3615593, -122.0553238, 0.02);
+    near(0x8a2a1072b59ffff, 40.689167, -74.044444, 0.001);
+    near(0x8001fffffffffff, 79.2423985, 38.0234070, 1e-6);
+    near(0x80f3fffffffffff, -79.2423985, -141.9765930, 1e-6);
+    near(0x8009fffffffffff, 64.7, 10.5361991, 1e-6);
+    // Pentagons have no children in the k direction, and edges have no centers.
+    assert_eq!(geo::h3_center(0x81087ffffffffff), None);
+    assert_eq!(geo::h3_center(0x11928308280fffff), None);
+}
+
+#[test]
+fn snowflake_decoding() {
+    let fields = ids::snowflake_decode(175928847299117063, ids::DISCORD);
+    assert_eq!(fields.timestamp_ms, 1462015105796);
+    assert_eq!(fields.worker, 32);
+    assert_eq!(fields.sequence, 7);
+
+    let layout = ids::SnowflakeLayout { epoch_ms: 0, worker_bits: 4, sequence_bits: 4 };
+    assert_eq!(ids::snowflake_decode(0x1234, layout), ids::Snowflake {
+        timestamp_ms: 0x12,
+        worker: 3,
+        sequence: 4,
+    });
+}
+
+#[test]
+fn datetime_format_utc_millis() {
+    assert_eq!(datetime::format_utc_millis(1462015105796), "2016-04-30 11:18:25.796 UTC");
+    assert_eq!(datetime::format_utc_millis(-1), "1969-12-31 23:59:59.999 UTC");
+}
+
+#[test]
+fn datetime_format_utc_fraction() {
+    let expected = "2016-04-30 11:18:25.000796 UTC";
+    assert_eq!(datetime::format_utc_fraction(1462015105, 796, 6), expected);
+    let expected = "1969-12-31 23:59:59.999999999 UTC";
+    assert_eq!(datetime::format_utc_fraction(-1, 999_999_999, 9), expected);
+}
+
+#[test]
+fn ulid_decoding() {
+    let value = ids::ulid_decode("01ARYZ6S41TSV4RRFFQ69G5FAV").unwrap();
+    assert_eq!(ids::ulid_fields(value).0, 1469918176385);
+    assert_eq!(ids::ulid_decode("01aryz6s41tsv4rrffq69g5fav"), Some(value));
+    assert_eq!(ids::ulid_decode("7ZZZZZZZZZZZZZZZZZZZZZZZZZ"), Some(u128::MAX));
+    assert_eq!(ids::ulid_decode("80000000000000000000000000"), None);
+    assert_eq!(ids::ulid_decode("01ARZ3NDEKTSV4RRFFQ69G5FAU"), None);
+    assert_eq!(ids::ulid_decode("01ARZ3NDEK"), None);
+}
+
+#[test]
+fn ksuid_decoding() {
+    let bytes = ids::ksuid_decode("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+    let (timestamp, payload) = ids::ksuid_fields(&bytes);
+    assert_eq!(timestamp, 1507608047);
+    assert_eq!(payload[..4], [0xB5, 0xA1, 0xCD, 0x34]);
+    assert_eq!(ids::ksuid_decode("000000000000000000000000000"), Some([0; 20]));
+    assert_eq!(ids::ksuid_decode("aWgEPTl1tmebfsQzFP4bxwgy80V"), Some([0xFF; 20]));
+    assert_eq!(ids::ksuid_decode("aWgEPTl1tmebfsQzFP4bxwgy80W"), None);
+    assert_eq!(ids::ksuid_decode("0ujtsYcgvSTl8PAuAdqWYSMnLO!"), None);
+}
+
+#[test]
+fn objectid_decoding() {
+    let oid = ids::objectid_decode("507f1f77bcf86cd799439011").unwrap();
+    assert_eq!(oid.timestamp, 0x507f1f77);
+    assert_eq!(oid.random, 0xbcf86cd799);
+    assert_eq!(oid.machine(), 0xbcf86c);
+    assert_eq!(oid.pid(), 0xd799);
+    assert_eq!(oid.counter, 0x439011);
+    assert_eq!(ids::objectid_decode("507f1f77bcf86cd79943901"), None);
+    assert_eq!(ids::objectid_decode("507f1f77bcf86cd79943901g"), None);
+}
+
+#[test]
+fn radio_channel_to_frequency() {
+    let khz = |band, channel| radio::channel_to_khz(band, channel).map(|(khz, _)| khz);
+    assert_eq!(khz(radio::Band::Wifi2g, 1), Some(2_412_000));
+    assert_eq!(khz(radio::Band::Wifi2g, 14), Some(2_484_000));
+    assert_eq!(khz(radio::Band::Wifi2g, 15), None);
+    assert_eq!(khz(radio::Band::Wifi5g, 36), Some(5_180_000));
+    assert_eq!(khz(radio::Band::Wifi6g, 1), Some(5_955_000));
+    assert_eq!(khz(radio::Band::Bluetooth, 78), Some(2_480_000));
+    assert_eq!(khz(radio::Band::Ble, 37), Some(2_402_000));
+    assert_eq!(khz(radio::Band::Ble, 0), Some(2_404_000));
+    assert_eq!(khz(radio::Band::Ble, 11), Some(2_428_000));
+    assert_eq!(khz(radio::Band::LoraUs915, 0), Some(902_300));
+    assert_eq!(khz(radio::Band::LoraUs915, 64), Some(903_000));
+}
+
+#[test]
+fn radio_frequency_to_channel() {
+    assert_eq!(radio::khz_to_channel(radio::Band::Wifi2g, 2_437_000), Some(6));
+    assert_eq!(radio::khz_to_channel(radio::Band::Ble, 2_426_000), Some(38));
+    assert_eq!(radio::khz_to_channel(radio::Band::Wifi2g, 2_438_000), None);
+    assert_eq!(radio::parse_mhz("902.3"), Some(902_300));
+    assert_eq!(radio::parse_mhz("2437MHz"), Some(2_437_000));
+    assert_eq!(radio::parse_mhz("1.2345"), None);
+    assert_eq!(radio::format_mhz(902_300), "902.3");
+    assert_eq!(radio::format_mhz(2_400_000), "2400");
+}
+
+#[test]
+fn midi_note_names() {
+    assert_eq!(midi::note_name(60), Some("C4".to_string()));
+    assert_eq!(midi::note_name(0), Some("C-1".to_string()));
+    assert_eq!(midi::note_name(70), Some("A#4".to_string()));
+    assert_eq!(midi::note_name(128), None);
+    assert_eq!(midi::parse_note("A4"), Some(69));
+    assert_eq!(midi::parse_note("bb4"), Some(70));
+    assert_eq!(midi::parse_note("C-1"), Some(0));
+    assert_eq!(midi::parse_note("G9"), Some(127));
+    assert_eq!(midi::parse_note("G#9"), None);
+    assert_eq!(midi::parse_note("H4"), None);
+    assert_eq!(midi::parse_note("C"), None);
+}
+
+#[test]
+fn midi_frequencies() {
+    assert_eq!(midi::frequency(69, midi::A440), 440.0);
+    assert!((midi::frequency(60, midi::A440) - 261.6256).abs() < 0.001);
+    assert_eq!(midi::frequency(81, 432.0), 864.0);
+    let (note, cents) = midi::nearest_note(445.0, midi::A440).unwrap();
+    assert_eq!(note, 69);
+    assert!((cents - 19.56).abs() < 0.01);
+    assert_eq!(midi::nearest_note(0.0, midi::A440), None);
+    assert_eq!(midi::nearest_note(20000.0, midi::A440), None);
+}
+
+#[test]
+fn check_iban() {
+    let iban = check::iban_normalize("gb82 west 1234 5698 7654 32").unwrap();
+    assert_eq!(iban, "GB82WEST12345698765432");
+    assert_eq!(check::iban_numeric(&iban), "3214282912345698765432161182");
+    assert_eq!(check::mod97(&check::iban_numeric(&iban)), Some(1));
+    assert_eq!(check::iban_check_digits(&iban), 82);
+    assert_eq!(check::iban_check_digits("GB00WEST12345698765432"), 82);
+    assert_eq!(check::iban_length("gb"), Some(22));
+    assert_eq!(check::iban_normalize("GBXX1234"), None);
+    assert_eq!(check::iban_normalize("1234"), None);
+}
+
+#[test]
+fn check_mod97() {
+    assert_eq!(check::mod97("97"), Some(0));
+    assert_eq!(check::mod97("98"), Some(1));
+    let big: u128 = 123456789012345678901234567890;
+    assert_eq!(check::mod97(&big.to_string()), Some((big % 97) as u32));
+    assert_eq!(check::mod97("12a"), None);
+}
+
+#[test]
+fn check_luhn() {
+    assert_eq!(check::luhn_sum("4111111111111111"), Some(0));
+    assert_eq!(check::luhn_sum("4111111111111112"), Some(1));
+    assert_eq!(check::luhn_sum("79927398713"), Some(0));
+    assert_eq!(check::luhn_check_digit("7992739871"), Some(3));
+    assert_eq!(check::luhn_check_digit("411111111111111"), Some(1));
+    assert_eq!(check::luhn_sum(""), None);
+    assert_eq!(check::luhn_sum("12a4"), None);
+}
+
+#[test]
+fn check_card_network() {
+    let network = |digits| check::card_network(digits).map(|(name, _)| name);
+    assert_eq!(network("4111111111111111"), Some("Visa"));
+    assert_eq!(network("5500000000000004"), Some("Mastercard"));
+    assert_eq!(network("2223000048400011"), Some("Mastercard"));
+    assert_eq!(network("378282246310005"), Some("American Express"));
+    assert_eq!(network("6011111111111117"), Some("Discover"));
+    assert_eq!(network("6221260000000000"), Some("Discover"));
+    assert_eq!(network("6200000000000005"), Some("UnionPay"));
+    assert_eq!(network("3530111333300000"), Some("JCB"));
+    assert_eq!(network("1234567812345670"), None);
+}
+
+#[test]
+fn country_lookups() {
+    assert_eq!(country::country_by_numeric(826), Some((826, "GB", "GBR", "United Kingdom")));
+    assert_eq!(country::country_by_numeric(4).map(|c| c.1), Some("AF"));
+    assert_eq!(country::country_by_numeric(999), None);
+    assert_eq!(country::country_by_code("de").map(|c| c.0), Some(276));
+    assert_eq!(country::country_by_code("FRA").map(|c| c.0), Some(250));
+    assert_eq!(country::country_by_code("japan").map(|c| c.1), Some("JP"));
+    assert_eq!(country::country_by_code("XX"), None);
+}
+
+#[test]
+fn country_mobile_codes() {
+    assert_eq!(country::mcc_country(234), Some("GB"));
+    assert_eq!(country::mcc_country(999), None);
+    assert_eq!(country::country_mccs("GB"), vec![234, 235]);
+    assert_eq!(country::parse_network("310-260"), Some(("310", "260")));
+    assert_eq!(country::parse_network("23415"), Some(("234", "15")));
+    assert_eq!(country::parse_network("234"), None);
+    assert_eq!(country::parse_network("23a-15"), None);
+    assert_eq!(country::network("310", "260"), Some("T-Mobile"));
+    assert_eq!(country::network("234", "015"), None);
+}
+
+#[test]
+fn exif_orientation() {
+    assert_eq!(exif::orientation(1), Some(("top-left", "none")));
+    assert_eq!(exif::orientation(6), Some(("right-top", "rotate 90° clockwise")));
+    assert_eq!(exif::orientation(0), None);
+    assert_eq!(exif::orientation(9), None);
+    assert!(exif::orientation_swaps_axes(6));
+    assert!(!exif::orientation_swaps_axes(3));
+}
+
+#[test]
+fn exif_rationals() {
+    let rational = exif::parse_rational("28/10").unwrap();
+    assert_eq!(rational.reduce(), exif::Rational { num: 14, den: 5 });
+    assert_eq!(rational.value(), Some(2.8));
+    assert_eq!(exif::parse_rational("-2/3").unwrap().reduce(), exif::Rational { num: -2, den: 3 });
+    assert_eq!(exif::parse_rational("5"), Some(exif::Rational { num: 5, den: 1 }));
+    assert_eq!(exif::parse_rational("1/0").unwrap().value(), None);
+    assert_eq!(exif::parse_rational("1/x"), None);
+    assert_eq!(exif::format_exposure(1.0 / 250.0), "1/250 s");
+    assert_eq!(exif::format_exposure(2.5), "2.5 s");
+    assert!((exif::dms_to_degrees(51.0, 30.0, 26.16) - 51.507267).abs() < 1e-6);
+}
+
+#[test]
+fn codec_hex_decode() {
+    assert_eq!(codec::hex_decode("2A8648"), Some(vec![0x2A, 0x86, 0x48]));
+    assert_eq!(codec::hex_decode("0x2a 86:48_01"), Some(vec![0x2A, 0x86, 0x48, 0x01]));
+    assert_eq!(codec::hex_decode("2A8"), None);
+    assert_eq!(codec::hex_decode("zz"), None);
+    assert_eq!(codec::hex_decode(""), None);
+}
+
+#[test]
+fn asn1_lengths() {
+    assert_eq!(asn1::decode_length(&[0x05]), Some(asn1::Length::Definite(5, 1)));
+    assert_eq!(asn1::decode_length(&[0x82, 0x01, 0x2C]), Some(asn1::Length::Definite(300, 3)));
+    assert_eq!(asn1::decode_length(&[0x80]), Some(asn1::Length::Indefinite));
+    assert_eq!(asn1::decode_length(&[0x82, 0x01]), None);
+    assert_eq!(asn1::decode_length(&[0xFF]), None);
+    assert_eq!(asn1::encode_length(127), vec![0x7F]);
+    assert_eq!(asn1::encode_length(128), vec![0x81, 0x80]);
+    assert_eq!(asn1::encode_length(300), vec![0x82, 0x01, 0x2C]);
+    assert!(asn1::is_der_length(&[0x81, 0x80]));
+    assert!(!asn1::is_der_length(&[0x81, 0x05]));
+}
+
+#[test]
+fn asn1_oids() {
+    let bytes = [0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0B];
+    let arcs = vec![1, 2, 840, 113549, 1, 1, 11];
+    assert_eq!(asn1::decode_oid(&bytes), Some(arcs.clone()));
+    assert_eq!(asn1::encode_oid(&arcs), Some(bytes.to_vec()));
+    assert_eq!(asn1::decode_oid(&[0x88, 0x37, 0x03]), Some(vec![2, 999, 3]));
+    assert_eq!(asn1::encode_oid(&[2, 999, 3]), Some(vec![0x88, 0x37, 0x03]));
+    assert_eq!(asn1::decode_oid(&[0x2A, 0x86]), None);
+    assert_eq!(asn1::decode_oid(&[0x2A, 0x80, 0x01]), None);
+    assert_eq!(asn1::encode_oid(&[1, 40]), None);
+    assert_eq!(asn1::parse_oid("1.2.840"), Some(vec![1, 2, 840]));
+    assert_eq!(asn1::format_oid(&arcs), "1.2.840.113549.1.1.11");
+    assert_eq!(asn1::oid_name("2.5.4.3"), Some("commonName"));
+}
+
+#[test]
+fn asn1_x509_fields() {
+    let tlv = asn1::decode_tlv(&[0x03, 0x02, 0x01, 0x86, 0xAA]);
+    assert_eq!(tlv, Some((0x03, &[0x01, 0x86][..], &[0xAA][..])));
+    assert_eq!(asn1::decode_tlv(&[0x03, 0x05, 0x01]), None);
+    assert_eq!(asn1::bit_string_bits(&[0x01, 0x86]), Some(vec![0, 5, 6]));
+    assert_eq!(asn1::bit_string_bits(&[0x08, 0xFF]), None);
+    let usages = asn1::key_usage(&[0x07, 0x80]).unwrap();
+    assert_eq!(usages, vec!["digitalSignature".to_string()]);
+    assert_eq!(asn1::key_usage(&[0x07, 0x00, 0x80]).unwrap(), vec!["decipherOnly".to_string()]);
+    let eku = codec::hex_decode("301406082B0601050507030106082B06010505070302").unwrap();
+    let oids = asn1::ext_key_usage(&eku).unwrap();
+    assert_eq!(asn1::format_oid(&oids[1]), "1.3.6.1.5.5.7.3.2");
+    assert_eq!(asn1::ext_key_usage(&[0x31, 0x00]), None);
+    assert!(asn1::serial_issues(&[0x80]).negative);
+    assert!(asn1::serial_issues(&[0x00, 0x01]).non_minimal);
+    assert!(!asn1::serial_issues(&[0x00, 0x80]).non_minimal);
+    assert!(asn1::serial_issues(&[0x01; 21]).too_long);
+    assert!(asn1::serial_issues(&[0x00]).zero);
+    assert!(!asn1::serial_issues(&[0x00]).negative);
+    assert!(!asn1::serial_issues(&[0x01]).zero);
+    assert_eq!(asn1::integer_magnitude(&[0xFF]), (true, vec![0x01]));
+    assert_eq!(asn1::integer_magnitude(&[0xFF, 0x00]), (true, vec![0x01, 0x00]));
+    assert_eq!(asn1::integer_magnitude(&[0x80]), (true, vec![0x80]));
+    assert_eq!(asn1::integer_magnitude(&[0x7F]), (false, vec![0x7F]));
+}
+
+#[test]
+fn bigint_conversions() {
+    let mut bytes = vec![0x00; 17];
+    bytes[0] = 0x01;
+    let value = BigUint::from_bytes_be(&bytes);
+    assert_eq!(value.to_string(), "340282366920938463463374607431768211456");
+    assert_eq!(value.to_hex(), "100000000000000000000000000000000");
+    assert_eq!(value.bits(), 129);
+    assert_eq!(value.to_bytes_be(), bytes);
+    assert_eq!(BigUint::from_bytes_be(&[0, 0, 1]), BigUint::from_u128(1));
+    assert_eq!(BigUint::from_u128(u128::MAX).to_string(), u128::MAX.to_string());
+    assert_eq!(BigUint::from_u128(1_000_000_000).to_string(), "1000000000");
+    assert_eq!(BigUint::zero().to_string(), "0");
+    assert_eq!(BigUint::zero().to_hex(), "0");
+    assert_eq!(BigUint::from_u128(1234).div_rem_small(10), (BigUint::from_u128(123), 4));
+}
+
+#[test]
+fn bigint_shifts() {
+    let value = BigUint::from_hex("0xFFFF").unwrap();
+    assert_eq!(value.shl(208).to_hex(), format!("FFFF{}", "0".repeat(52)));
+    assert_eq!(value.shl(208).shr(208), value);
+    assert_eq!(value.shl(4).to_hex(), "FFFF0");
+    assert_eq!(value.shr(8).to_hex(), "FF");
+    assert_eq!(value.shr(16), BigUint::zero());
+    assert_eq!(BigUint::from_hex("000000001"), Some(BigUint::from_u128(1)));
+    assert_eq!(BigUint::from_hex(""), None);
+    assert_eq!(BigUint::from_hex("12G4"), None);
+}
+
+#[test]
+fn pow_compact_targets() {
+    let target = pow::compact_to_target(pow::MAX_TARGET_BITS).unwrap();
+    assert_eq!(target.to_hex(), format!("FFFF{}", "0".repeat(52)));
+    assert_eq!(target.bits(), 224);
+    assert_eq!(pow::target_to_compact(&target), pow::MAX_TARGET_BITS);
+    assert_eq!(pow::difficulty(&target), Some(1.0));
+    let fields = pow::compact_fields(0x1D00FFFF);
+    assert_eq!((fields.exponent, fields.mantissa, fields.negative), (29, 0xFFFF, false));
+    assert_eq!(pow::compact_to_target(0x04923456), None);
+    assert!(pow::compact_fields(0x04923456).negative);
+    assert!(!pow::compact_fields(0x04800000).negative);
+    assert_eq!(pow::compact_to_target(0x04800000), Some(BigUint::zero()));
+    assert!(!pow::compact_fields(0x01803456).negative);
+    assert_eq!(pow::compact_to_target(0x01803456), Some(BigUint::zero()));
+    assert_eq!(pow::compact_to_target(0x01003456), Some(BigUint::zero()));
+    assert_eq!(pow::compact_to_target(0x02123456), Some(BigUint::from_u128(0x1234)));
+    assert_eq!(pow::compact_to_target(0x22000001), Some(BigUint::from_u128(1).shl(248)));
+    assert_eq!(pow::compact_to_target(0x22000100), None);
+    assert_eq!(pow::target_to_compact(&BigUint::from_u128(0x80)), 0x02008000);
+    assert_eq!(pow::target_to_compact(&BigUint::from_u128(0x12345678)), 0x04123456);
+    assert_eq!(pow::target_to_compact(&BigUint::zero()), 0);
+    assert_eq!(pow::difficulty(&BigUint::zero()), None);
+}
+
+#[test]
+fn decimal_declets() {
+    for value in 0..1000 {
+        assert_eq!(decimal::decode_declet(decimal::encode_declet(value)), value);
+    }
+    assert_eq!(decimal::encode_declet(999), 0x0FF);
+    assert_eq!(decimal::encode_declet(123), 0x0A3);
+    assert_eq!(decimal::decode_declet(0x3FF), 999);
+}
+
+#[test]
+fn decimal_decoding() {
+    let d32 = decimal::Format::Decimal32;
+    let d64 = decimal::Format::Decimal64;
+    let d128 = decimal::Format::Decimal128;
+    assert_eq!((d32.precision(), d32.bias(), d32.trailing_bits()), (7, 101, 20));
+    assert_eq!((d64.precision(), d64.bias(), d64.trailing_bits()), (16, 398, 50));
+    assert_eq!((d128.precision(), d128.bias(), d128.trailing_bits()), (34, 6176, 110));
+    assert_eq!(decimal::Format::from_name("d128"), Some(d128));
+    let one = decimal::decode(0x22500001, d32, decimal::Encoding::Dpd);
+    assert_eq!((one.exponent, one.coefficient, one.canonical), (0, 1, true));
+    let one = decimal::decode(0x31C0000000000001, d64, decimal::Encoding::Bid);
+    assert_eq!((one.exponent, one.coefficient), (0, 1));
+    let one = decimal::decode(0x3040 << 112 | 1, d128, decimal::Encoding::Bid);
+    assert_eq!(one.to_sci_string(), "1");
+    let max = decimal::decode(0x6CB8967F, d32, decimal::Encoding::Bid);
+    assert_eq!(max.coefficient, 9999999);
+    let over = decimal::decode(0x6CB89680, d32, decimal::Encoding::Bid);
+    assert_eq!((over.coefficient, over.canonical), (0, false));
+    let value = decimal::decode(0xA2300000000000FF, d64, decimal::Encoding::Dpd);
+    assert_eq!(value.to_sci_string(), "-9.99");
+    let nan = decimal::decode(0x7E000005, d32, decimal::Encoding::Dpd);
+    assert_eq!(nan.class, decimal::Class::SignalingNan);
+    assert_eq!(nan.to_sci_string(), "sNaN5");
+    let inf = decimal::decode(0xF8000000, d32, decimal::Encoding::Bid);
+    assert_eq!(inf.to_sci_string(), "-Infinity");
+}
+
+#[test]
+fn decimal_strings() {
+    let value = |coefficient, exponent| decimal::Decimal {
+        negative: false,
+        class: decimal::Class::Finite,
+        exponent,
+        coefficient,
+        canonical: true,
+    };
+    assert_eq!(value(123, 0).to_sci_string(), "123");
+    assert_eq!(value(123, -1).to_sci_string(), "12.3");
+    assert_eq!(value(123, -5).to_sci_string(), "0.00123");
+    assert_eq!(value(123, -10).to_sci_string(), "1.23E-8");
+    assert_eq!(value(123, 1).to_sci_string(), "1.23E+3");
+    assert_eq!(value(5, 2).to_sci_string(), "5E+2");
+    assert_eq!(value(0, -2).to_sci_string(), "0.00");
+}
+
+#[test]
+fn float_fields() {
+    let f32 = float::Width::F32;
+    let f64 = float::Width::F64;
+    assert_eq!((f32.mantissa_bits(), f32.bias()), (23, 127));
+    assert_eq!((f64.mantissa_bits(), f64.bias()), (52, 1023));
+    let fields = float::fields(1.5f64.to_bits(), f64);
+    assert_eq!(fields, float::Fields { sign: 0, exponent: 1023, mantissa: 1 << 51 });
+    assert_eq!(fields.class(f64), float::Class::Normal);
+    assert_eq!(float::fields(0x80000000, f32).class(f32), float::Class::Zero);
+    assert_eq!(float::fields(0x00000001, f32).class(f32), float::Class::Subnormal);
+    assert_eq!(float::fields(0x00000001, f32).unbiased_exponent(f32), -126);
+    assert_eq!(float::fields(0x7F800000, f32).class(f32), float::Class::Infinite);
+    assert_eq!(float::fields(0x7FC00000, f32).class(f32), float::Class::QuietNan);
+    assert_eq!(float::fields(0x7FA00000, f32).class(f32), float::Class::SignalingNan);
+}
+
+#[test]
+fn float_f16() {
+    assert_eq!(float::f16_bits(1.0), 0x3C00);
+    assert_eq!(float::f16_bits(0.1), 0x2E66);
+    assert_eq!(float::f16_bits(-2.0), 0xC000);
+    assert_eq!(float::f16_bits(65519.0), 0x7BFF);
+    assert_eq!(float::f16_bits(65520.0), 0x7C00);
+    assert_eq!(float::f16_bits(2f64.powi(-25)), 0x0000);
+    assert_eq!(float::f16_bits(3.0 * 2f64.powi(-25)), 0x0002);
+    assert_eq!(float::f16_bits(f64::NAN), 0x7E00);
+    assert_eq!(float::f16_value(0x0001), 2f64.powi(-24));
+    assert_eq!(float::f16_value(0xFC00), f64::NEG_INFINITY);
+    assert_eq!(float::f16_shortest(0x2E66), 0.1);
+    assert_eq!(float::f16_shortest(0x7BFF), 65500.0);
+    for bits in (0..=0xFFFFu16).filter(|bits| bits & 0x7C00 != 0x7C00) {
+        assert_eq!(float::f16_bits(float::f16_value(bits)), bits);
+        assert_eq!(float::f16_bits(float::f16_shortest(bits)), bits);
+    }
+    let f16 = float::Width::F16;
+    assert_eq!((f16.mantissa_bits(), f16.bias()), (10, 15));
+    assert_eq!(float::fields(0x7E00, f16).class(f16), float::Class::QuietNan);
+}
+
+
+#[test]
+fn float_exact_decimal() {
+    let exact = |value: f64| float::fields(value.to_bits(), float::Width::F64)
+        .exact_decimal(float::Width::F64);
+    assert_eq!(exact(0.1).unwrap(), "0.1000000000000000055511151231257827021181583404541015625");
+    assert_eq!(exact(-2.5).unwrap(), "-2.5");
+    assert_eq!(exact(1e22).unwrap(), "10000000000000000000000");
+    assert_eq!(exact(-0.0).unwrap(), "-0");
+    assert_eq!(exact(f64::INFINITY), None);
+    let tiny = float::fields(1, float::Width::F32).exact_decimal(float::Width::F32).unwrap();
+    assert!(tiny.starts_with("0.000000000000000000000000000000000000000000001401298464"));
+    let product = BigUint::from_u128(u64::MAX as u128).mul_small(5);
+    assert_eq!(product.to_string(), "92233720368547758075");
+}
+
+#[test]
+fn float_parsing() {
+    assert_eq!(float::parse_float("3.5"), Some(3.5));
+    assert_eq!(float::parse_float("-1e-3"), Some(-0.001));
+    assert_eq!(float::parse_float(".5"), Some(0.5));
+    assert_eq!(float::parse_float("-inf"), Some(f64::NEG_INFINITY));
+    assert!(float::parse_float("NaN").unwrap().is_nan());
+    assert_eq!(float::parse_float("x12"), None);
+    assert_eq!(float::parse_float(""), None);
+}
+
+#[test]
+fn signed_digit_representations() {
+    assert_eq!(signed_digit_string(&balanced_ternary(100)), "11T01");
+    assert_eq!(signed_digit_string(&balanced_ternary(-5)), "T11");
+    assert_eq!(signed_digit_string(&balanced_ternary(0)), "0");
+    assert_eq!(signed_digit_string(&non_adjacent_form(7)), "100T");
+    assert_eq!(signed_digit_string(&non_adjacent_form(-5)), "T0T");
+    for value in [-1000, -1, 1, 2, 3, 255, 1000, i128::MAX, i128::MIN] {
+        let ternary = balanced_ternary(value);
+        let naf = non_adjacent_form(value);
+        // Wrapping arithmetic is exact here as the final sum is in range.
+        let sum = |digits: &[i8], base: i128| digits.iter().enumerate().fold(0i128, |acc, (i, d)| {
+            acc.wrapping_add((*d as i128).wrapping_mul(base.wrapping_pow(i as u32)))
+        });
+        assert_eq!(sum(&ternary, 3), value);
+        assert_eq!(sum(&naf, 2), value);
+        assert!(naf.windows(2).all(|pair| pair[0] == 0 || pair[1] == 0));
+    }
+    assert_eq!(non_adjacent_form(i128::MAX).len(), 128);
+}
+
+#[test]
+fn expression_evaluation() {
+    assert_eq!(eval_expr("0xFF & b1010"), Ok(10));
+    assert_eq!(eval_expr("1 << 20"), Ok(1 << 20));
+    assert_eq!(eval_expr("(x3C | 0o17) + 1"), Ok(64));
+    assert_eq!(eval_expr("1 + 2 * 3"), Ok(7));
+    assert_eq!(eval_expr("6 | 1 ^ 3 & 5 << 1"), Ok(7));
+    assert_eq!(eval_expr("10 - 4 - 3"), Ok(3));
+    assert_eq!(eval_expr("-7 / 2"), Ok(-3));
+    assert_eq!(eval_expr("-7 % 2"), Ok(-1));
+    assert_eq!(eval_expr("~0 >> 4"), Ok(-1));
+    assert_eq!(eval_expr("--5"), Ok(5));
+    assert_eq!(eval_expr("42"), Ok(42));
+    assert_eq!(eval_expr("1 / 0"), Err(IntspectorError::DivisionByZero));
+    assert_eq!(eval_expr("1 << 128"), Err(IntspectorError::ShiftOutOfRange));
+    let overflow = IntspectorError::ArithmeticOverflow("<<".to_string());
+    assert_eq!(eval_expr("3 << 126"), Err(overflow));
+    assert!(eval_expr("170141183460469231731687303715884105727 + 1").is_err());
+    assert_eq!(eval_expr("(1 + 2"), Err(IntspectorError::MissingParen));
+    assert_eq!(eval_expr("1 2"), Err(IntspectorError::UnexpectedToken("2".to_string())));
+    assert_eq!(eval_expr("1 < 2"), Err(IntspectorError::UnexpectedChar('<')));
+    assert_eq!(eval_expr(""), Err(IntspectorError::UnexpectedEnd));
+    let invalid = IntspectorError::InvalidDigit { digit: 'Z', radix: 16 };
+    assert_eq!(eval_expr("0xZZ"), Err(invalid));
+}
+
+#[test]
+fn rns_crt() {
+    assert_eq!(rns::parse_moduli("3, 5,7"), Some(vec![3, 5, 7]));
+    assert_eq!(rns::parse_moduli("3,1"), None);
+    assert_eq!(rns::parse_moduli("3,,5"), None);
+    assert_eq!(rns::moduli_product(&[3, 5, 7]), Ok(105));
+    assert!(rns::moduli_product(&[4, 6]).is_err());
+    assert!(rns::moduli_product(&[u32::MAX as u64, u32::MAX as u64 + 2, 2]).is_err());
+    assert_eq!(rns::residues(52, &[3, 5, 7]), vec![1, 2, 3]);
+    assert_eq!(rns::mod_inverse(35, 3), Some(2));
+    assert_eq!(rns::mod_inverse(3, 7), Some(5));
+    assert_eq!(rns::mod_inverse(4, 6), None);
+    let (value, terms) = rns::crt(&[1, 2, 3], &[3, 5, 7]).unwrap();
+    assert_eq!(value, 52);
+    assert_eq!((terms[0].partial, terms[0].inverse, terms[0].term), (35, 2, 70));
+    let moduli = [4294967291, 4294967279];
+    let value = 0xFFFF_FFFF_FFFF;
+    assert_eq!(rns::crt(&rns::residues(value, &moduli), &moduli).unwrap().0, value);
+    assert!(rns::crt(&[1], &[3, 5]).is_err());
+}
+
+#[test]
+fn factoradic_conversions() {
+    assert_eq!(factoradic(463), vec![0, 1, 0, 1, 4, 3]);
+    assert_eq!(factoradic(0), vec![0]);
+    assert_eq!(from_factoradic(&[0, 1, 0, 1, 4, 3]), Some(463));
+    assert_eq!(from_factoradic(&[0, 2]), None);
+    assert_eq!(from_factoradic(&factoradic(u128::MAX)), Some(u128::MAX));
+    assert_eq!(lehmer_permutation(&[3, 4, 1, 0, 1, 0]), Some(vec![3, 5, 1, 0, 4, 2]));
+    assert_eq!(lehmer_permutation(&[1, 1]), None);
+    assert_eq!(permutation_lehmer(&[3, 5, 1, 0, 4, 2]), Some(vec![3, 4, 1, 0, 1, 0]));
+    assert_eq!(permutation_lehmer(&[0, 0]), None);
+    assert_eq!(permutation_lehmer(&[1, 2]), None);
+    assert_eq!(permutation_lehmer(&[]), Some(vec![]));
+}
+
+#[test]
+fn parse_errors() {
+    let invalid = IntspectorError::InvalidDigit { digit: '2', radix: 2 };
+    assert_eq!(parse_int("b102"), Err(invalid));
+    assert_eq!(parse_int("0xFG"), Err(IntspectorError::InvalidDigit { digit: 'G', radix: 16 }));
+    assert_eq!(parse_int(""), Err(IntspectorError::Empty));
+    assert_eq!(parse_int("0x"), Err(IntspectorError::Empty));
+    assert_eq!(parse_int("-"), Err(IntspectorError::Empty));
+    let overflow = IntspectorError::Overflow { bits: 64, signed: false };
+    assert_eq!(parse_uint("0x10000000000000000"), Err(overflow));
+    let err = parse_uint("b1F").unwrap_err();
+    assert_eq!(err.to_string(), "digit 'F' not valid in binary literal");
+    let err = parse_int("x_10").unwrap_err();
+    assert_eq!(err.to_string(), "digit '_' not valid in hexadecimal literal");
+}
+
+#[test]
+fn combinatorics_results() {
+    assert_eq!(combinatorics::binomial(52, 5), BigUint::from_u128(2_598_960));
+    assert_eq!(combinatorics::binomial(5, 0), BigUint::from_u128(1));
+    assert_eq!(combinatorics::binomial(5, 7), BigUint::zero());
+    let big = combinatorics::binomial(100, 50);
+    assert_eq!(big.to_string(), "100891344545564193334812497256");
+    assert_eq!(combinatorics::permutations(52, 5), BigUint::from_u128(311_875_200));
+    assert_eq!(combinatorics::permutations(5, 0), BigUint::from_u128(1));
+    assert_eq!(combinatorics::permutations(3, 4), BigUint::zero());
+    assert_eq!(combinatorics::multinomial(&[2, 3, 4]), Some(BigUint::from_u128(1260)));
+    assert_eq!(combinatorics::multinomial(&[]), Some(BigUint::from_u128(1)));
+    assert_eq!(combinatorics::multinomial(&[u32::MAX, 1]), None);
+    let product = BigUint::from_u128(u64::MAX as u128).mul(&BigUint::from_u128(u64::MAX as u128));
+    assert_eq!(product, BigUint::from_u128(u64::MAX as u128 * u64::MAX as u128));
+    assert_eq!(BigUint::zero().mul(&BigUint::from_u128(5)), BigUint::zero());
+}
+
+#[test]
+fn parse_int_separators() {
+    assert_eq!(parse_int("b1010_1010"), Ok(0b1010_1010));
+    assert_eq!(parse_int("0b1010_1010"), Ok(0b1010_1010));
+    assert_eq!(parse_int("o7_777"), Ok(0o7777));
+    assert_eq!(parse_int("1_000_000"), Ok(1_000_000));
+    assert_eq!(parse_int("-1,000,000"), Ok(-1_000_000));
+    assert_eq!(parse_int("1'000'000"), Ok(1_000_000));
+    assert_eq!(parse_int("1 000 000"), Ok(1_000_000));
+    assert_eq!(parse_int("0x1234_5678"), Ok(0x1234_5678));
+    assert_eq!(parse_int("xDEAD__BEEF"), Ok(0xDEAD_BEEF));
+    assert_eq!(parse_uint("0xFFFF_FFFF_FFFF_FFFF"), Ok(u64::MAX));
+    let invalid = |digit| Err(IntspectorError::InvalidDigit { digit, radix: 10 });
+    assert_eq!(parse_int("1_"), invalid('_'));
+    assert_eq!(parse_int("-_1"), invalid('_'));
+    assert_eq!(parse_int("1, "), invalid(','));
+    assert_eq!(parse_int("d_1"), invalid('_'));
+}
+
+#[test]
+fn parse_int_signs() {
+    assert_eq!(parse_int("-0x10"), Ok(-16));
+    assert_eq!(parse_int("-x10"), Ok(-16));
+    assert_eq!(parse_int("-b1010"), Ok(-10));
+    assert_eq!(parse_int("-0o17"), Ok(-15));
+    assert_eq!(parse_int("-d25"), Ok(-25));
+    assert_eq!(parse_int("+0x10"), Ok(16));
+    assert_eq!(parse_int("+42"), Ok(42));
+    assert_eq!(parse_int("-0"), Ok(0));
+    assert_eq!(parse_int("-0x8000_0000_0000_0000_0000_0000_0000_0000"), Ok(i128::MIN));
+    assert_eq!(parse_u128("+0xFF"), Ok(255));
+    assert_eq!(parse_uint("-0x10"), Err(IntspectorError::Negative));
+    assert_eq!(parse_int("--5"), Err(IntspectorError::InvalidDigit { digit: '-', radix: 10 }));
+    assert_eq!(parse_int("+-5"), Err(IntspectorError::InvalidDigit { digit: '-', radix: 10 }));
+    assert_eq!(parse_int("-x"), Err(IntspectorError::Empty));
+}
+
+#[test]
+fn stern_brocot_locations() {
+    let pi = ratio::Ratio { num: 355, den: 113 };
+    let location = ratio::locate(pi, pi).unwrap();
+    assert_eq!(location.runs, vec![('R', 3), ('L', 7), ('R', 15)]);
+    assert_eq!(location.depth(), 25);
+    assert_eq!(location.continued_fraction(), vec![3, 7, 16]);
+    assert_eq!(location.left, ratio::Ratio { num: 333, den: 106 });
+    assert_eq!(location.right, ratio::Ratio { num: 22, den: 7 });
+    assert_eq!(location.run_nodes().last(), Some(&pi));
+
+    let three_sevenths = ratio::Ratio { num: 3, den: 7 };
+    let location = ratio::locate(three_sevenths, three_sevenths).unwrap();
+    assert_eq!(location.runs, vec![('L', 2), ('R', 2)]);
+    let path: Vec<String> = location.path_nodes().iter().map(|node| node.to_string()).collect();
+    assert_eq!(path, vec!["1/1", "1/2", "1/3", "2/5", "3/7"]);
+    let ends: Vec<String> = location.run_nodes().iter().map(|node| node.to_string()).collect();
+    assert_eq!(ends, vec!["1/1", "1/3", "3/7"]);
+    let half = ratio::Ratio { num: 2, den: 4 };
+    let location = ratio::locate(half, half).unwrap();
+    assert_eq!(location.node, ratio::Ratio { num: 1, den: 2 });
+    assert_eq!(location.continued_fraction(), vec![0, 2]);
+
+    let one = ratio::Ratio { num: 1, den: 1 };
+    let location = ratio::locate(one, one).unwrap();
+    assert!(location.runs.is_empty());
+    assert_eq!(location.continued_fraction(), vec![1]);
+    assert_eq!(location.right, ratio::Ratio { num: 1, den: 0 });
+
+    let large = ratio::Ratio { num: u64::MAX, den: 1 };
+    assert_eq!(ratio::locate(large, large).unwrap().runs, vec![('R', u64::MAX - 1)]);
+    let zero = ratio::Ratio { num: 0, den: 1 };
+    assert_eq!(ratio::locate(zero, zero), None);
+    assert_eq!(ratio::locate(one, half), None);
+}
+
+#[test]
+fn stern_brocot_approximations() {
+    assert_eq!(ratio::parse_decimal("3.14159"), Some((314159, 5)));
+    assert_eq!(ratio::parse_decimal(".5"), Some((5, 1)));
+    assert_eq!(ratio::parse_decimal("5"), None);
+    assert_eq!(ratio::parse_decimal("1.2.3"), None);
+    let (lo, hi) = ratio::decimal_interval(314159, 5).unwrap();
+    assert_eq!(ratio::locate(lo, hi).unwrap().node, ratio::Ratio { num: 355, den: 113 });
+    let (lo, hi) = ratio::decimal_interval(333, 3).unwrap();
+    assert_eq!(ratio::locate(lo, hi).unwrap().node, ratio::Ratio { num: 1, den: 3 });
+    let (lo, hi) = ratio::decimal_interval(1, 6).unwrap();
+    assert_eq!(ratio::locate(lo, hi).unwrap().node, ratio::Ratio { num: 1, den: 666667 });
+    assert_eq!(ratio::parse_ratio("0x10/3"), Some(ratio::Ratio { num: 16, den: 3 }));
+    assert_eq!(ratio::parse_ratio("7"), Some(ratio::Ratio { num: 7, den: 1 }));
+    assert_eq!(ratio::parse_ratio("1/0"), None);
+}
+
+#[test]
+fn egyptian_fractions() {
+    assert_eq!(ratio::egyptian(ratio::Ratio { num: 4, den: 13 }), Some((0, vec![4, 18, 468])));
+    assert_eq!(ratio::egyptian(ratio::Ratio { num: 7, den: 3 }), Some((2, vec![3])));
+    assert_eq!(ratio::egyptian(ratio::Ratio { num: 6, den: 3 }), Some((2, vec![])));
+    assert_eq!(ratio::egyptian(ratio::Ratio { num: 0, den: 5 }), Some((0, vec![])));
+    let (_, terms) = ratio::egyptian(ratio::Ratio { num: 5, den: 121 }).unwrap();
+    assert_eq!(terms, vec![25, 757, 763309, 873960180913, 1527612795642093418846225]);
+    assert_eq!(ratio::egyptian(ratio::Ratio { num: 31, den: 311 }), None);
+    assert_eq!(ratio::egyptian(ratio::Ratio { num: 1, den: 0 }), None);
 }
 
+#[test]
+fn bin_string_with_grouping() {
+    assert_eq!(bin_string_with(0xAAAA, 16, 8, ' '), "10101010 10101010");
+    assert_eq!(bin_string_with(0xAAAA, 16, 4, '_'), "1010_1010_1010_1010");
+    assert_eq!(bin_string_with(0x1AA, 9, 4, '_'), "1_1010_1010");
+    assert_eq!(bin_string_with(0x5, 8, 0, '_'), "00000101");
+    assert_eq!(bin_string_with(0xFF, 4, 2, '.'), "11.11");
+    assert_eq!(bin_string_with(1, 0, 4, '_'), "");
+    assert_eq!(bin_string_with(u128::MAX, 128, 64, ' ').len(), 129);
+}
+
+#[test]
+fn historical_numerals() {
+    assert_eq!(radix_digits(0, 60), vec![0]);
+    assert_eq!(radix_digits(1234567, 60), vec![7, 56, 42, 5]);
+    assert_eq!(radix_digits(400, 20), vec![0, 0, 1]);
+    assert_eq!(radix_digits(u128::MAX, 2).len(), 128);
+    assert_eq!(mixed_radix_digits(1872000, &[20, 18, 20, 20]), vec![0, 0, 0, 0, 13]);
+    assert_eq!(mixed_radix_digits(359, &[20, 18, 20, 20]), vec![19, 17, 0, 0, 0]);
+    assert_eq!(babylonian_digit(0), "_");
+    assert_eq!(babylonian_digit(23), "\u{1230B}\u{1230B}\u{12079}\u{12079}\u{12079}");
+    assert_eq!(maya_digit(0), Some('\u{1D2E0}'));
+    assert_eq!(maya_digit(19), Some('\u{1D2F3}'));
+    assert_eq!(maya_digit(20), None);
+}
+
+#[test]
+fn coding_sizes() {
+    assert_eq!(coding::zigzag(0), 0);
+    assert_eq!(coding::zigzag(-1), 1);
+    assert_eq!(coding::zigzag(1), 2);
+    assert_eq!(coding::zigzag(-2), 3);
+    assert_eq!(coding::zigzag(i128::MIN), u128::MAX);
+    assert_eq!(coding::varint_len(0), 1);
+    assert_eq!(coding::varint_len(127), 1);
+    assert_eq!(coding::varint_len(128), 2);
+    assert_eq!(coding::varint_len(u64::MAX as u128), 10);
+    assert_eq!(coding::elias_gamma_bits(0), None);
+    assert_eq!(coding::elias_gamma_bits(1), Some(1));
+    assert_eq!(coding::elias_gamma_bits(17), Some(9));
+    assert_eq!(coding::elias_delta_bits(1), Some(1));
+    assert_eq!(coding::elias_delta_bits(17), Some(9));
+    assert_eq!(coding::elias_delta_bits(300), Some(15));
+    assert_eq!(coding::rice_bits(300, 7), 10);
+    assert_eq!(coding::rice_bits(0, 0), 1);
+    assert_eq!(coding::rice_bits(u128::MAX, 0), u128::MAX);
+    assert_eq!(coding::best_rice_parameter(300), 7);
+}
+
+#[test]
+fn bit_counts() {
+    assert_eq!(popcount(0x2C, 8), 3);
+    assert_eq!(popcount(0xFF, 4), 4);
+    assert_eq!(popcount(u128::MAX, 128), 128);
+    assert_eq!(clz(0x2C, 8), 2);
+    assert_eq!(clz(0, 8), 8);
+    assert_eq!(clz(0x1FF, 8), 0);
+    assert_eq!(clz(1, 128), 127);
+    assert_eq!(ctz(0x2C, 8), 2);
+    assert_eq!(ctz(0, 8), 8);
+    assert_eq!(ctz(0x100, 8), 8);
+    assert_eq!(ctz(1 << 127, 128), 127);
+    assert_eq!(parity(0x2C, 8), 1);
+    assert_eq!(parity(0x3C, 8), 0);
+    assert_eq!(parity(0x1, 0), 0);
+}
+
+#[test]
+fn coding_universal_codes() {
+    let join = |(head, tail): (String, String)| head + &tail;
+    assert_eq!(coding::elias_gamma(0), None);
+    assert_eq!(coding::elias_gamma(1).map(join), Some("1".to_string()));
+    assert_eq!(coding::elias_gamma(17).map(join), Some("000010001".to_string()));
+    assert_eq!(coding::elias_delta(1).map(join), Some("1".to_string()));
+    assert_eq!(coding::elias_delta(17).map(join), Some("001010001".to_string()));
+    assert_eq!(coding::rice(17, 3).map(join), Some("110001".to_string()));
+    assert_eq!(coding::rice(2, 0).map(join), Some("110".to_string()));
+    assert_eq!(coding::rice(1 << 20, 0), None);
+    for value in [1, 2, 17, 300, u64::MAX as u128, u128::MAX] {
+        let gamma = join(coding::elias_gamma(value).unwrap());
+        assert_eq!(coding::decode_elias_gamma(&gamma), Some((value, gamma.len())));
+        let delta = join(coding::elias_delta(value).unwrap());
+        assert_eq!(coding::decode_elias_delta(&delta), Some((value, delta.len())));
+        let rice = join(coding::rice(value, 120).unwrap());
+        assert_eq!(coding::decode_rice(&rice, 120), Some((value, rice.len())));
+    }
+    assert_eq!(coding::decode_elias_gamma("000010001111"), Some((17, 9)));
+    assert_eq!(coding::decode_elias_gamma("0001"), None);
+    assert_eq!(coding::decode_elias_gamma("000"), None);
+    assert_eq!(coding::decode_elias_delta("00101"), None);
+    assert_eq!(coding::decode_rice("1100", 3), None);
+    assert_eq!(coding::decode_rice("0", 0), Some((0, 1)));
+    assert_eq!(coding::pack_bits("000010001"), vec![0x08, 0x80]);
+    assert_eq!(coding::pack_bits(""), Vec::<u8>::new());
+}
+
+#[test]
+fn byte_swaps() {
+    assert_eq!(byte_swap(0x1234, 16), 0x3412);
+    assert_eq!(byte_swap(0x12345678, 32), 0x78563412);
+    assert_eq!(byte_swap(0x0102030405060708, 64), 0x0807060504030201);
+    assert_eq!(byte_swap(0xAB, 8), 0xAB);
+    assert_eq!(byte_swap(0x123456, 24), 0x563412);
+    assert_eq!(byte_swap(0xFFFF_1234, 16), 0x3412);
+}
+
+#[test]
+fn huffman_codes() {
+    let lengths = huffman::code_lengths(&[45, 13, 12, 16, 9, 5]);
+    assert_eq!(lengths, vec![1, 3, 3, 3, 4, 4]);
+    let codes = vec![0b0, 0b100, 0b101, 0b110, 0b1110, 0b1111];
+    assert_eq!(huffman::canonical_codes(&lengths), Some(codes));
+    assert_eq!(huffman::code_lengths(&[7]), vec![1]);
+    assert_eq!(huffman::code_lengths(&[1, 1, 1, 1]), vec![2, 2, 2, 2]);
+    assert_eq!(huffman::code_lengths(&[]), Vec::<u32>::new());
+    let fibonacci = [1, 1, 2, 3, 5, 8, 13];
+    assert_eq!(huffman::code_lengths(&fibonacci), vec![6, 6, 5, 4, 3, 2, 1]);
+    assert_eq!(huffman::canonical_codes(&[2, 1, 2]), Some(vec![0b10, 0b0, 0b11]));
+    assert_eq!(huffman::canonical_codes(&[129, 1]), None);
+}
+
+#[test]
+fn arbitrary_bases() {
+    assert_eq!(to_base(0, 36), "0");
+    assert_eq!(to_base(1295, 36), "zz");
+    assert_eq!(to_base(255, 2), "11111111");
+    assert_eq!(to_base(u128::MAX, 16), "f".repeat(32));
+    assert_eq!(to_base(u128::MAX, 36), "f5lxx1zz5pnorynqglhzmsp33");
+    assert_eq!(parse_int("36:zz"), Ok(1295));
+    assert_eq!(parse_int("36:ZZ"), Ok(1295));
+    assert_eq!(parse_int("-7:16"), Ok(-13));
+    assert_eq!(parse_int("2:1010_1010"), Ok(170));
+    assert_eq!(parse_u128("36:f5lxx1zz5pnorynqglhzmsp33"), Ok(u128::MAX));
+    assert_eq!(parse_int("37:1"), Err(IntspectorError::UnsupportedBase("37".to_string())));
+    assert_eq!(parse_int("1:0"), Err(IntspectorError::UnsupportedBase("1".to_string())));
+    assert_eq!(parse_int("3:12a"), Err(IntspectorError::InvalidDigit { digit: 'a', radix: 3 }));
+    assert_eq!(parse_int("36:"), Err(IntspectorError::Empty));
+    assert_eq!(eval_expr("36:zz + 2:1"), Ok(1296));
+    let err = IntspectorError::InvalidDigit { digit: 'a', radix: 3 };
+    assert_eq!(err.to_string(), "digit 'a' not valid in base-3 literal");
+}
+
+#[test]
+fn arithmetic_coding_intervals() {
+    use arith::Interval;
+    let intervals = arith::narrow(&[3, 1, 1], &[0, 1, 0]).unwrap();
+    assert_eq!(intervals.len(), 4);
+    assert_eq!(intervals[0], Interval { low: 0, high: 1, den: 1 });
+    assert_eq!(intervals[1], Interval { low: 0, high: 3, den: 5 });
+    assert_eq!(intervals[2], Interval { low: 9, high: 12, den: 25 });
+    assert_eq!(intervals[3], Interval { low: 45, high: 54, den: 125 });
+    assert_eq!(arith::narrow(&[1, 1], &[1, 0]).unwrap()[2], Interval { low: 2, high: 3, den: 4 });
+    let repeated = arith::narrow(&[1], &[0; 1000]).unwrap();
+    assert_eq!(repeated[1000], Interval { low: 0, high: 1, den: 1 });
+    assert_eq!(arith::narrow(&[1, 2], &[0; 200]), None);
+    assert_eq!(arith::shortest_code(Interval { low: 45, high: 54, den: 125 }), Some((0b011, 3)));
+    assert_eq!(arith::shortest_code(Interval { low: 1, high: 2, den: 4 }), Some((0b01, 2)));
+    assert_eq!(arith::shortest_code(Interval { low: 0, high: 1, den: 1 }), Some((0, 1)));
+    assert_eq!(arith::shortest_code(Interval { low: 2, high: 2, den: 3 }), None);
+    assert_eq!(arith::binary_fraction(2, 5, 8), "0.01100110");
+    assert_eq!(arith::binary_fraction(1, 1, 4), "1.0000");
+    assert_eq!(arith::binary_fraction(0, 3, 0), "0.");
+}
+
+#[test]
+fn eval_expr_variables() {
+    let mut vars = std::collections::HashMap::new();
+    vars.insert("_".to_string(), 0xF0);
+    vars.insert("mask".to_string(), 0b1010);
+    assert_eq!(eval_expr_with("_ | mask", &vars), Ok(0xFA));
+    assert_eq!(eval_expr_with("-_ + 1", &vars), Ok(-239));
+    let err = IntspectorError::UnexpectedChar('=');
+    assert_eq!(eval_expr_with("(mask << 4) == _", &vars), Err(err));
+    assert_eq!(eval_expr_with("x10 + _", &vars), Ok(0x100));
+    assert!(eval_expr_with("_", &std::collections::HashMap::new()).is_err());
+}
+
+#[test]
+fn bit_run_lengths() {
+    assert_eq!(bit_runs(0xE0FF, 16), vec![(1, 3), (0, 5), (1, 8)]);
+    assert_eq!(bit_runs(0b1010, 4), vec![(1, 1), (0, 1), (1, 1), (0, 1)]);
+    assert_eq!(bit_runs(0b1010, 6), vec![(0, 2), (1, 1), (0, 1), (1, 1), (0, 1)]);
+    assert_eq!(bit_runs(0, 8), vec![(0, 8)]);
+    assert_eq!(bit_runs(u128::MAX, 128), vec![(1, 128)]);
+    assert_eq!(bit_runs(0xFF, 0), vec![]);
+}
+
+#[test]
+fn shannon_entropies() {
+    assert_eq!(shannon_entropy(&[]), 0.0);
+    assert_eq!(shannon_entropy(&[7]), 0.0);
+    assert!(shannon_entropy(&[7]).is_sign_positive());
+    assert_eq!(format!("{:.4}", shannon_entropy(&[1])), "0.0000");
+    assert_eq!(shannon_entropy(&[1, 1]), 1.0);
+    assert_eq!(shannon_entropy(&[5, 0, 5, 0]), 1.0);
+    assert_eq!(shannon_entropy(&[1; 256]), 8.0);
+    assert!((shannon_entropy(&[3, 1]) - 0.8112781244591328).abs() < 1e-12);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn unicode_properties() {
+    use intspector::unicode;
+    assert_eq!(unicode::name('a').as_deref(), Some("LATIN SMALL LETTER A"));
+    assert_eq!(unicode::name('☃').as_deref(), Some("SNOWMAN"));
+    assert_eq!(unicode::name('\u{4E00}').as_deref(), Some("CJK UNIFIED IDEOGRAPH-4E00"));
+    assert_eq!(unicode::name('\u{AC00}').as_deref(), Some("HANGUL SYLLABLE GA"));
+    assert_eq!(unicode::name('\u{D7A3}').as_deref(), Some("HANGUL SYLLABLE HIH"));
+    assert_eq!(unicode::name('\u{C544}').as_deref(), Some("HANGUL SYLLABLE A"));
+    assert_eq!(unicode::name('\n'), None);
+    assert_eq!(unicode::category('A'), "Lu");
+    assert_eq!(unicode::category('\0'), "Cc");
+    assert_eq!(unicode::category('€'), "Sc");
+    assert_eq!(unicode::category('\u{10FFFF}'), "Cn");
+    assert_eq!(unicode::category_name("Nd"), Some("Decimal Number"));
+    assert_eq!(unicode::block('é'), Some("Latin-1 Supplement"));
+    assert_eq!(unicode::block('\u{1F642}'), Some("Emoticons"));
+    assert_eq!(unicode::block('\u{2FE0}'), None);
+    assert!(unicode::NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!(unicode::CATEGORIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn utf_encodings() {
+    assert_eq!(utf8_bytes('a'), vec![0x61]);
+    assert_eq!(utf8_bytes('é'), vec![0xC3, 0xA9]);
+    assert_eq!(utf8_bytes('☃'), vec![0xE2, 0x98, 0x83]);
+    assert_eq!(utf8_bytes('🙂'), vec![0xF0, 0x9F, 0x99, 0x82]);
+    assert_eq!(utf8_bytes('\u{10FFFF}'), vec![0xF4, 0x8F, 0xBF, 0xBF]);
+    assert_eq!(utf16_units('a'), vec![0x0061]);
+    assert_eq!(utf16_units('\u{FFFF}'), vec![0xFFFF]);
+    assert_eq!(utf16_units('🙂'), vec![0xD83D, 0xDE42]);
+    assert_eq!(utf16_units('\u{10000}'), vec![0xD800, 0xDC00]);
+    assert_eq!(utf16_units('\u{10FFFF}'), vec![0xDBFF, 0xDFFF]);
+}
+
+#[test]
+fn utf8_decoding() {
+    use codec::Utf8Error;
+    let items = codec::decode_utf8(&[0xE2, 0x9C, 0x93, 0x41]);
+    assert_eq!(items, vec![(0, 3, Ok('✓')), (3, 1, Ok('A'))]);
+    let items = codec::decode_utf8(&[0xE2, 0x9C, 0x41]);
+    assert_eq!(items, vec![(0, 2, Err(Utf8Error::Truncated)), (2, 1, Ok('A'))]);
+    assert_eq!(codec::decode_utf8(&[0x80]), vec![(0, 1, Err(Utf8Error::UnexpectedContinuation))]);
+    assert_eq!(codec::decode_utf8(&[0xC0, 0xAF])[0], (0, 1, Err(Utf8Error::InvalidByte)));
+    assert_eq!(codec::decode_utf8(&[0xE0, 0x80, 0xAF]), vec![(0, 3, Err(Utf8Error::Overlong))]);
+    assert_eq!(codec::decode_utf8(&[0xED, 0xA0, 0x80]), vec![(0, 3, Err(Utf8Error::Surrogate))]);
+    let items = codec::decode_utf8(&[0xF4, 0x90, 0x80, 0x80]);
+    assert_eq!(items, vec![(0, 4, Err(Utf8Error::OutOfRange))]);
+    assert_eq!(codec::decode_utf8(&[0xF4, 0x8F, 0xBF, 0xBF]), vec![(0, 4, Ok('\u{10FFFF}'))]);
+    assert_eq!(codec::decode_utf8(&[]), vec![]);
+}
+
+#[test]
+fn analyze_integers() {
+    let info = analyze(65, None).unwrap();
+    assert_eq!((info.min_bits, info.std_bits, info.num_bits, info.bits), (7, 8, 7, 65));
+    assert_eq!((info.hex.as_str(), info.dec.as_str(), info.oct.as_str()), ("41", "65", "101"));
+    assert_eq!(info.bin, "1000001");
+    assert_eq!(info.twos_complement, None);
+    assert_eq!(info.ascii, Some("A".to_string()));
+    let info = analyze(-1, None).unwrap();
+    assert_eq!((info.min_bits, info.std_bits, info.num_bits, info.bits), (1, 8, 8, 0xFF));
+    assert_eq!(info.twos_complement, Some(0xFF));
+    assert_eq!(info.bin, "11111111");
+    assert_eq!(info.ascii, None);
+    assert_eq!(analyze(5, Some(8)).unwrap().bin, "00000101");
+    assert_eq!(analyze(-2, Some(16)).unwrap().hex, "FFFE");
+    assert_eq!(analyze(5, Some(0)), Err(IntspectorError::UnsupportedBits(0)));
+    assert_eq!(analyze(5, Some(129)), Err(IntspectorError::UnsupportedBits(129)));
+    assert_eq!(analyze(256, Some(8)), Err(IntspectorError::TooFewBits { value: 256, min_bits: 9 }));
+}
+
+#[test]
+fn bit_fields() {
+    assert_eq!(field_mask(31, 28), 0xF000_0000);
+    assert_eq!(field_mask(0, 0), 1);
+    assert_eq!(field_mask(127, 0), u128::MAX);
+    assert_eq!(field_mask(127, 127), 1 << 127);
+    assert_eq!(extract_field(0xE3A0_1005, 31, 28), 0xE);
+    assert_eq!(extract_field(0xE3A0_1005, 27, 20), 0x3A);
+    assert_eq!(extract_field(0xE3A0_1005, 19, 0), 0x1005);
+    assert_eq!(extract_field(u128::MAX, 127, 64), u64::MAX as u128);
+}
+
+#[test]
+fn common_prefix_lengths() {
+    assert_eq!(common_prefix_len(&[0xC0A8_0107, 0xC0A8_01F0, 0xC0A8_0180], 32), 24);
+    assert_eq!(common_prefix_len(&[0b1010, 0b1011], 4), 3);
+    assert_eq!(common_prefix_len(&[0x01, 0xFF], 8), 0);
+    assert_eq!(common_prefix_len(&[42], 8), 8);
+    assert_eq!(common_prefix_len(&[], 16), 16);
+    assert_eq!(common_prefix_len(&[0, 1], 128), 127);
+    assert_eq!(common_prefix_len(&[0, 1 << 127], 128), 0);
+}
+
+#[test]
+fn bit_field_layouts() {
+    let fields = layout::parse("mode:0-2,enable:3,count:8-15").unwrap();
+    let names: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+    assert_eq!(names, vec!["count", "enable", "mode"]);
+    assert_eq!((fields[0].high, fields[0].low), (15, 8));
+    assert_eq!((fields[1].high, fields[1].low), (3, 3));
+    assert_eq!(layout::decode(0x12B4, &fields), vec![0x12, 0, 4]);
+    assert_eq!(layout::gaps(&fields), vec![(7, 4)]);
+    let fields = layout::parse_file("# timer\n[timer]\nmode = \"2:0\"\ncount = '4-9'\n").unwrap();
+    assert_eq!(layout::gaps(&fields), vec![(3, 3)]);
+    assert_eq!(layout::parse_range("7-4"), Ok((7, 4)));
+    assert!(layout::parse_range("4:7").is_err());
+    assert!(layout::parse_range("128").is_err());
+    assert!(layout::parse("a:0-3,b:3-5").is_err());
+    assert!(layout::parse("a").is_err());
+    assert!(layout::parse("").is_err());
+    assert!(layout::parse_file("mode 0-2").is_err());
+}
+
+#[test]
+fn common_alignments_and_strides() {
+    assert_eq!(common_alignment(&[0x1000, 0x1030, 0x1060]), Some(16));
+    assert_eq!(common_alignment(&[0, 8]), Some(8));
+    assert_eq!(common_alignment(&[0, 0]), None);
+    assert_eq!(common_alignment(&[1 << 127]), Some(1 << 127));
+    assert_eq!(common_stride(&[0x1000, 0x1030, 0x10F0, 0x1060]), 48);
+    assert_eq!(common_stride(&[10, 4, 7]), 3);
+    assert_eq!(common_stride(&[5, 5]), 0);
+    assert_eq!(common_stride(&[]), 0);
+    assert_eq!(common_stride(&[0, u128::MAX]), u128::MAX);
+}
+
+#[test]
+fn bit_operations() {
+    assert_eq!(set_bits(0b1100, &[0, 1]), 0b1111);
+    assert_eq!(set_bits(0b1100, &[2]), 0b1100);
+    assert_eq!(set_bits(0, &[127]), 1 << 127);
+    assert_eq!(clear_bits(0b1111, &[0, 3]), 0b0110);
+    assert_eq!(clear_bits(0b1000, &[0]), 0b1000);
+    assert_eq!(toggle_bits(0b1100, &[1, 2]), 0b1010);
+    assert_eq!(toggle_bits(0b1100, &[1, 1]), 0b1110);
+    assert_eq!(toggle_bits(u128::MAX, &[]), u128::MAX);
+}
+
+#[test]
+fn sequence_recognition() {
+    use sequence::Rule;
+    let rule = sequence::recognize(&[3, 7, 11, 15]).unwrap();
+    assert_eq!(rule, Rule::Arithmetic { first: 3, difference: 4 });
+    assert_eq!(rule.term(4), Some(19));
+    assert_eq!(rule.formula(), "a(n) = 4n + 3");
+    let rule = sequence::recognize(&[3, -6, 12]).unwrap();
+    assert_eq!(rule, Rule::Geometric { first: 3, ratio: -2 });
+    assert_eq!(rule.term(3), Some(-24));
+    let rule = sequence::recognize(&[4, 1, 0, 1]).unwrap();
+    assert_eq!(rule, Rule::Power { offset: -2, exponent: 2 });
+    assert_eq!(rule.name(), "squares");
+    let rule = sequence::recognize(&[1, 2, 6, 24]).unwrap();
+    assert_eq!(rule, Rule::Factorial { offset: 1 });
+    assert_eq!(rule.term(4), Some(120));
+    let rule = sequence::recognize(&[2, 1, 3, 4, 7]).unwrap();
+    assert_eq!(rule.name(), "Lucas numbers");
+    assert_eq!(rule.term(5), Some(11));
+    let rule = sequence::recognize(&[0, 1, 5, 14, 30]).unwrap();
+    assert_eq!(rule, Rule::Polynomial { differences: vec![0, 1, 3, 2] });
+    assert_eq!(rule.formula(), "a(n) = (2n^3 + 3n^2 + n)/6");
+    assert_eq!(rule.term(5), Some(55));
+    assert_eq!(sequence::recognize(&[1, 5, 2, 8]), None);
+    assert_eq!(sequence::recognize(&[1, 2]), None);
+    let rule = sequence::recognize(&[1 << 124, 1 << 125, 1 << 126]).unwrap();
+    assert_eq!(rule.term(3), None);
+}
+
+#[test]
+fn bit_rotations() {
+    assert_eq!(rotate_left(0b1011_0001, 3, 8), 0b1000_1101);
+    assert_eq!(rotate_right(0b1011_0001, 3, 8), 0b0011_0110);
+    assert_eq!(rotate_left(0b1011_0001, 11, 8), 0b1000_1101);
+    assert_eq!(rotate_right(0b1011_0001, 8, 8), 0b1011_0001);
+    assert_eq!(rotate_left(0x1FF, 1, 8), 0xFF);
+    assert_eq!(rotate_left(1 << 127, 1, 128), 1);
+    assert_eq!(rotate_right(1, 1, 128), 1 << 127);
+    assert_eq!(rotate_left(1, 1, 1), 1);
+}
+
+#[test]
+fn checksum_algorithms() {
+    for crc in checksum::CRC_PRESETS {
+        assert_eq!(crc.compute(b"123456789"), crc.check, "{}", crc.name);
+    }
+    assert_eq!(checksum::adler32(b"123456789"), 0x091E01DE);
+    assert_eq!(checksum::fletcher16(b"abcde"), 0xC8F0);
+    let header = [0x00, 0x01, 0xF2, 0x03, 0xF4, 0xF5, 0xF6, 0xF7];
+    assert_eq!(checksum::internet_checksum(&header), 0x220D);
+    assert_eq!(checksum::xor_bytes(&[0x01, 0x02, 0x04]), 0x07);
+    let matches = checksum::find_matches(b"123456789", 0xCBF43926);
+    assert_eq!(matches, vec![("CRC-32/ISO-HDLC", false)]);
+    let matches = checksum::find_matches(b"123456789", 0x2639F4CB);
+    assert_eq!(matches, vec![("CRC-32/ISO-HDLC", true)]);
+    assert!(checksum::find_matches(b"123456789", 0x12345678).is_empty());
+}
+
+
+#[test]
+fn powers_of_two() {
+    assert!(is_power_of_two(1));
+    assert!(is_power_of_two(1 << 127));
+    assert!(!is_power_of_two(0));
+    assert!(!is_power_of_two(1000));
+    assert_eq!(next_power_of_two(0), Some(1));
+    assert_eq!(next_power_of_two(1000), Some(1024));
+    assert_eq!(next_power_of_two(1024), Some(1024));
+    assert_eq!(next_power_of_two((1 << 127) + 1), None);
+    assert_eq!(prev_power_of_two(0), None);
+    assert_eq!(prev_power_of_two(1000), Some(512));
+    assert_eq!(prev_power_of_two(1024), Some(1024));
+    assert_eq!(prev_power_of_two(u128::MAX), Some(1 << 127));
+}
+
+
+#[test]
+fn magic_constants() {
+    let names = |value| magic::identify(value).into_iter().map(|m| m.name).collect::<Vec<_>>();
+    assert_eq!(names(0x5F3759DF), vec!["fast inverse square root"]);
+    assert_eq!(names(0x04C11DB7), vec!["CRC-32 polynomial"]);
+    assert_eq!(names(0xEDB88320), vec!["CRC-32 polynomial (reflected)"]);
+    assert_eq!(names(0xA001), vec!["CRC-16 polynomial (reflected)"]);
+    assert!(names(12345).is_empty());
+
+    let swapped = magic::identify(0x464C457F);
+    assert_eq!(swapped.len(), 1);
+    assert_eq!(swapped[0].name, "ELF magic");
+    assert!(swapped[0].swapped);
+    assert!(!magic::identify(0x7F454C46)[0].swapped);
+
+    // A byte-reversed match needs the value to fit in the constant's size.
+    assert!(magic::identify(0x01_464C457F).is_empty());
+}
+
+
+#[test]
+fn prime_factorization() {
+    assert_eq!(factor::factorize(0), vec![]);
+    assert_eq!(factor::factorize(1), vec![]);
+    assert_eq!(factor::factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(factor::factorize(u64::MAX).len(), 7);
+    assert_eq!(
+        factor::factorize(4294967279 * 4294967291),
+        vec![(4294967279, 1), (4294967291, 1)]
+    );
+    assert_eq!(factor::factorize(18446744073709551557), vec![(18446744073709551557, 1)]);
+
+    assert!(factor::is_prime(2));
+    assert!(factor::is_prime(2147483647));
+    assert!(!factor::is_prime(1));
+    assert!(!factor::is_prime(3215031751));
+    assert!(!factor::is_prime(3825123056546413051));
+
+    let factors = factor::factorize(360);
+    assert_eq!(factor::divisor_count(&factors), 24);
+    assert_eq!(factor::divisor_sum(&factors), Some(1170));
+    assert_eq!(factor::divisor_count(&[]), 1);
+}
+
+
+#[test]
+fn splitmix64_values() {
+    let mut rng = random::SplitMix64::new(1234567);
+    assert_eq!(rng.next_u64(), 6457827717110365317);
+    assert_eq!(rng.next_u64(), 3203168211198807973);
+    assert_eq!(rng.next_u64(), 9817491932198370423);
+
+    let mut rng = random::SplitMix64::new(0);
+    assert!(rng.next_bits(1) <= 1);
+    assert!(rng.next_bits(12) < 1 << 12);
+    let mut a = random::SplitMix64::new(42);
+    let mut b = random::SplitMix64::new(42);
+    assert_eq!(a.next_bits(128), b.next_bits(128));
+}
+
+
+#[test]
+fn named_checksums() {
+    let check = b"123456789";
+    assert_eq!(checksum::compute_named("crc32", check), Some(("CRC-32/ISO-HDLC", 32, 0xCBF43926)));
+    assert_eq!(checksum::compute_named("CRC32C", check), Some(("CRC-32/ISCSI", 32, 0xE3069283)));
+    let modbus = checksum::compute_named("crc-16/modbus", check);
+    assert_eq!(modbus, Some(("CRC-16/MODBUS", 16, 0x4B37)));
+    assert_eq!(checksum::compute_named("fletcher16", b"abcde"), Some(("FLETCHER-16", 16, 0xC8F0)));
+    assert_eq!(checksum::compute_named("xor", &[0x0F, 0xF0]), Some(("XOR-8", 8, 0xFF)));
+    assert_eq!(checksum::compute_named("crc7", check), None);
+}
+
+
+#[cfg(feature = "hashes")]
+#[test]
+fn hash_functions() {
+    use intspector::hash;
+    assert_eq!(hash::fnv1a_32(b""), 0x811C9DC5);
+    assert_eq!(hash::fnv1a_32(b"a"), 0xE40C292C);
+    assert_eq!(hash::fnv1a_64(b"a"), 0xAF63DC4C8601EC8C);
+    assert_eq!(hash::djb2_32(b""), 5381);
+    assert_eq!(hash::djb2_32(b"a"), 177670);
+    assert_eq!(hash::murmur3_32(b"", 0), 0);
+    assert_eq!(hash::murmur3_32(b"", 1), 0x514E28B7);
+    assert_eq!(hash::murmur3_32(b"hello", 0), 0x248BFA47);
+    assert_eq!(hash::hash("crc32", b"123456789", 32, 0), Ok(0xCBF43926));
+    assert_eq!(hash::hash("FNV1A", b"a", 64, 0), Ok(0xAF63DC4C8601EC8C));
+    assert!(hash::hash("murmur3", b"a", 64, 0).is_err());
+    assert!(hash::hash("sha1", b"a", 32, 0).is_err());
+}
+
+
+#[test]
+fn durations() {
+    assert_eq!(datetime::parse_duration("2h30m"), Ok(9_000_000_000_000));
+    assert_eq!(datetime::parse_duration("1d 12h"), Ok(129_600_000_000_000));
+    assert_eq!(datetime::parse_duration("1s500ms"), Ok(1_500_000_000));
+    assert_eq!(datetime::parse_duration("5µs"), Ok(5_000));
+    assert!(datetime::parse_duration("2h5").is_err());
+    assert!(datetime::parse_duration("3x").is_err());
+    assert!(datetime::parse_duration("").is_err());
+    assert_eq!(datetime::format_duration(9_000_000_000_000), "2h 30m");
+    assert_eq!(datetime::format_duration(86_405_000_000_000), "1d 5s");
+    assert_eq!(datetime::format_duration(0), "0s");
+}
+
+
+#[test]
+fn golden_vectors() {
+    for outcome in selftest::run() {
+        assert!(outcome.passed(), "{}: {} != {}", outcome.name, outcome.actual, outcome.expected);
+    }
+}
+
+
+#[test]
+fn spacers() {
+    assert_eq!(add_spacers("1234567", ',', 3), "1,234,567");
+    assert_eq!(add_spacers("123456", ',', 3), "123,456");
+    assert_eq!(add_spacers("12", ',', 3), "12");
+    assert_eq!(add_spacers("", ',', 3), "");
+    assert_eq!(add_spacers("11110000", '_', 4), "1111_0000");
+    assert_eq!(add_spacers("ÿÿÿÿ", '·', 2), "ÿÿ·ÿÿ");
+}
+
+
+#[test]
+fn ip_addresses() {
+    assert_eq!(ip::parse_v4("192.168.1.1"), Some(0xC0A80101));
+    assert_eq!(ip::parse_v4("256.0.0.1"), None);
+    assert_eq!(ip::parse_v4("1.2.3"), None);
+    assert_eq!(ip::parse_v4("1.2.3.+4"), None);
+    assert_eq!(ip::format_v4(0x7F000001), "127.0.0.1");
+    assert_eq!(ip::v4_class(0x0A000001), 'A');
+    assert_eq!(ip::v4_class(0xC0A80101), 'C');
+    assert_eq!(ip::v4_class(0xE0000001), 'D');
+    assert_eq!(ip::v4_scope(0xC0A80101), "private (RFC 1918)");
+    assert_eq!(ip::v4_scope(0xAC1F0001), "private (RFC 1918)");
+    assert_eq!(ip::v4_scope(0xAC200001), "public");
+    assert_eq!(ip::v4_scope(0xFFFFFFFF), "limited broadcast");
+    assert_eq!(ip::v4_scope(0x08080808), "public");
+
+    assert_eq!(ip::parse_v6("2001:db8::1"), Some(0x20010DB8 << 96 | 1));
+    assert_eq!(ip::parse_v6("::"), Some(0));
+    assert_eq!(ip::parse_v6("::ffff:192.168.1.1"), Some(0xFFFF_C0A8_0101));
+    assert_eq!(ip::parse_v6("1:2:3:4:5:6:7:8"), Some(0x0001_0002_0003_0004_0005_0006_0007_0008));
+    assert_eq!(ip::parse_v6("1::2::3"), None);
+    assert_eq!(ip::parse_v6("1:2:3:4:5:6:7"), None);
+    assert_eq!(ip::parse_v6("1:2:3:4::5:6:7:8"), None);
+    assert_eq!(ip::parse_v6("12345::"), None);
+    assert_eq!(ip::format_v6(0x20010DB8 << 96 | 1), "2001:db8::1");
+    assert_eq!(ip::format_v6(0x2001_0DB8_0000_0001_0000_0000_0000_0001), "2001:db8:0:1::1");
+    assert_eq!(ip::format_v6(0x2001_0DB8_0000_0000_0001_0000_0000_0001), "2001:db8::1:0:0:1");
+    assert_eq!(ip::format_v6(0x2001_0DB8_0000_0001_0001_0001_0001_0001), "2001:db8:0:1:1:1:1:1");
+    assert_eq!(ip::format_v6(0), "::");
+    assert_eq!(ip::format_v6(0xFFFF_0A00_0001), "::ffff:10.0.0.1");
+    assert_eq!(ip::format_v6_full(1), "0000:0000:0000:0000:0000:0000:0000:0001");
+    assert_eq!(ip::v6_scope(1), "loopback");
+    assert_eq!(ip::v6_scope(0xFE80 << 112 | 1), "link-local unicast");
+    assert_eq!(ip::v6_scope(0x2606_4700 << 96), "global unicast");
+}
+
+
+#[test]
+fn file_modes() {
+    assert_eq!(mode::parse_octal("755"), Some(0o755));
+    assert_eq!(mode::parse_octal("0o4755"), Some(0o4755));
+    assert_eq!(mode::parse_octal("100644"), Some(0o100644));
+    assert_eq!(mode::parse_octal("8"), None);
+    assert_eq!(mode::parse_octal("1000000"), None);
+    assert_eq!(mode::parse_symbolic("rwxr-xr-x"), Some(0o755));
+    assert_eq!(mode::parse_symbolic("rwsr-sr-t"), Some(0o7755));
+    assert_eq!(mode::parse_symbolic("rwSr--r-T"), Some(0o5644));
+    assert_eq!(mode::parse_symbolic("drwxrwxrwt"), Some(0o41777));
+    assert_eq!(mode::parse_symbolic("rwxr-xr-s"), None);
+    assert_eq!(mode::parse_symbolic("xwrr-xr-x"), None);
+    assert_eq!(mode::symbolic(0o644), "rw-r--r--");
+    assert_eq!(mode::symbolic(0o2755), "rwxr-sr-x");
+    assert_eq!(mode::symbolic(0o1666), "rw-rw-rwT");
+    assert_eq!(mode::symbolic(0o120777), "lrwxrwxrwx");
+    assert_eq!(mode::file_type(0o060660).map(|(_, _, name)| name), Some("block device"));
+    assert_eq!(mode::file_type(0o755), None);
+    assert_eq!(mode::permissions(5), vec!["read", "execute"]);
+    assert_eq!(mode::specials(0o6000), vec!["setuid", "setgid"]);
+}
+
+
+#[test]
+fn color_values() {
+    let orange = color::decode(0xFF8000, "rgb888").unwrap();
+    assert_eq!(orange, color::Color { r: 255, g: 128, b: 0, a: None });
+    let white = color::Color { r: 255, g: 255, b: 255, a: None };
+    assert_eq!(color::decode(0xFFFF, "rgb565").unwrap(), white);
+    assert_eq!(color::decode(0xF800, "RGB565").unwrap().r, 255);
+    let gray = color::Color { r: 8, g: 4, b: 8, a: None };
+    assert_eq!(color::decode(0x0821, "rgb565").unwrap(), gray);
+    assert_eq!(color::decode(0x80FF0000, "argb8888").unwrap().a, Some(128));
+    assert!(color::decode(0x1000000, "rgb888").is_err());
+    assert!(color::decode(0, "cmyk").is_err());
+    assert_eq!(color::to_rgb565(orange), 0xFC00);
+    assert_eq!(color::ansi256_rgb(196), (255, 0, 0));
+    assert_eq!(color::ansi256_rgb(244), (128, 128, 128));
+    assert_eq!(color::nearest_ansi256(orange), 208);
+    assert_eq!(color::nearest_ansi256(color::Color { r: 0, g: 0, b: 0, a: None }), 16);
+    assert_eq!(color::nearest_ansi256(color::Color { r: 130, g: 130, b: 130, a: None }), 244);
+}
+
+
+#[test]
+fn error_codes() {
+    let err = parse_int("0xFG").unwrap_err();
+    assert_eq!(err.code(), "invalid_digit");
+    assert_eq!(err.position("0xFG"), Some(3));
+    let err = parse_int("0x1x").unwrap_err();
+    assert_eq!(err.position("0x1x"), Some(3));
+    let err = parse_int("1_000_").unwrap_err();
+    assert_eq!(err.position("1_000_"), Some(5));
+    let err = parse_int("--5").unwrap_err();
+    assert_eq!(err.position("--5"), Some(1));
+    let err = eval_expr("1 $ 2").unwrap_err();
+    assert_eq!((err.code(), err.position("1 $ 2")), ("unexpected_char", Some(2)));
+    let err = eval_expr("(1 + 2").unwrap_err();
+    assert_eq!((err.code(), err.position("(1 + 2")), ("missing_paren", Some(6)));
+    let err = eval_expr("1 / 0").unwrap_err();
+    assert_eq!((err.code(), err.position("1 / 0")), ("division_by_zero", None));
+    assert_eq!(parse_uint("-1").unwrap_err().code(), "negative");
+}
+
+
+#[test]
+fn varints() {
+    assert_eq!(encode_uleb128(0), vec![0x00]);
+    assert_eq!(encode_uleb128(300), vec![0xAC, 0x02]);
+    assert_eq!(encode_uleb128(u64::MAX as u128).len(), 10);
+    assert_eq!(encode_uleb128(u128::MAX).len(), 19);
+    assert_eq!(decode_uleb128(&[0xAC, 0x02, 0x01]), Some((300, 2)));
+    assert_eq!(decode_uleb128(&encode_uleb128(u128::MAX)), Some((u128::MAX, 19)));
+    assert_eq!(decode_uleb128(&[0x80, 0x00]), Some((0, 2)));
+    assert_eq!(decode_uleb128(&[0xAC]), None);
+    let mut too_wide = vec![0xFF; 18];
+    too_wide.push(0x04);
+    assert_eq!(decode_uleb128(&too_wide), None);
+    assert_eq!(zigzag_encode(0), 0);
+    assert_eq!(zigzag_encode(-1), 1);
+    assert_eq!(zigzag_encode(1), 2);
+    assert_eq!(zigzag_encode(-150), 299);
+    assert_eq!(zigzag_decode(299), -150);
+    assert_eq!(zigzag_decode(zigzag_encode(i128::MIN)), i128::MIN);
+    assert_eq!(zigzag_decode(zigzag_encode(i128::MAX)), i128::MAX);
+}
+
+
+#[test]
+fn base64_and_base32() {
+    assert_eq!(codec::base64_encode(&[0xDE, 0xAD, 0xBE, 0xEF], false), "3q2+7w==");
+    assert_eq!(codec::base64_encode(&[0xDE, 0xAD, 0xBE, 0xEF], true), "3q2-7w");
+    assert_eq!(codec::base64_encode(b"foobar", false), "Zm9vYmFy");
+    assert_eq!(codec::base64_encode(&[], false), "");
+    assert_eq!(codec::base32_encode(b"f"), "MY======");
+    assert_eq!(codec::base32_encode(b"foobar"), "MZXW6YTBOI======");
+    assert_eq!(codec::base32_decode("MZXW6YTBOI======"), Some(b"foobar".to_vec()));
+    assert_eq!(codec::base32_decode("mzxw6"), Some(b"foo".to_vec()));
+    assert_eq!(codec::base32_decode("MZX"), None);
+    assert_eq!(codec::base32_decode("MZ1"), None);
+    assert_eq!(codec::base64_decode_with("3q2+7w==", false), Some(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+    assert_eq!(codec::base64_decode_with("3q2+7w==", true), None);
+    assert_eq!(codec::base64_decode_with("3q2-7w", true), Some(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+    assert_eq!(codec::base64_decode_with("3q2-7w", false), None);
+    assert_eq!(codec::base64_decode_with("TWFu", true), Some(b"Man".to_vec()));
+    for len in 0..12 {
+        let bytes: Vec<u8> = (0..len).map(|i: u32| (i * 37 + 5) as u8).collect();
+        assert_eq!(codec::base64_decode(&codec::base64_encode(&bytes, true)), Some(bytes.clone()));
+        assert_eq!(codec::base32_decode(&codec::base32_encode(&bytes)), Some(bytes));
+    }
+}
+
+
+#[test]
+fn gray_codes() {
+    assert_eq!(to_gray(0), 0);
+    assert_eq!(to_gray(13), 0b1011);
+    assert_eq!(to_gray(15), 0b1000);
+    assert_eq!(from_gray(0b1011), 13);
+    assert_eq!(from_gray(to_gray(u64::MAX)), u64::MAX);
+    for value in 0..1000u64 {
+        assert_eq!(from_gray(to_gray(value)), value);
+        assert_eq!((to_gray(value) ^ to_gray(value + 1)).count_ones(), 1);
+    }
+}